@@ -0,0 +1,35 @@
+import { copyFileSync, existsSync, mkdirSync, writeFileSync } from "node:fs";
+import { join } from "node:path";
+import { $ } from "bun";
+
+const bins = ["noro-ssh-sign", "noro-run"];
+const root = join(import.meta.dir, "../src-tauri");
+const dir = join(root, "binaries");
+const profile = process.argv.includes("--debug") ? "debug" : "release";
+
+const host = (await $`rustc -vV`.text()).match(/^host: (\S+)$/m)?.[1];
+if (!host) throw new Error("could not read the rust host triple");
+const triple = process.env.TAURI_ENV_TARGET_TRIPLE ?? host;
+const ext = triple.includes("windows") ? ".exe" : "";
+
+// tauri-build refuses to compile the crate until every externalBin exists
+mkdirSync(dir, { recursive: true });
+for (const bin of bins) {
+	const path = join(dir, `${bin}-${triple}${ext}`);
+	if (!existsSync(path)) writeFileSync(path, "");
+}
+
+const flags = [
+	...(profile === "release" ? ["--release"] : []),
+	...(triple === host ? [] : ["--target", triple]),
+	...bins.flatMap((bin) => ["--bin", bin]),
+];
+await $`cargo build ${flags}`.cwd(root);
+
+const out =
+	triple === host
+		? join(root, "target", profile)
+		: join(root, "target", triple, profile);
+for (const bin of bins) {
+	copyFileSync(join(out, `${bin}${ext}`), join(dir, `${bin}-${triple}${ext}`));
+}
@@ -0,0 +1,609 @@
+use rsa::pkcs1v15::SigningKey;
+use rsa::signature::{SignatureEncoding, Signer};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Sha256, Sha512};
+use ssh_key::private::{KeypairData, RsaKeypair};
+use ssh_key::{HashAlg, PrivateKey};
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+use tauri::{async_runtime::JoinHandle, AppHandle, Emitter, Manager, Runtime, State};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::oneshot;
+
+use crate::crypto;
+use crate::sshkey;
+use crate::storage;
+use crate::sync::{self, RemoteItem, SyncError};
+
+const SOCKET_FILE: &str = "agent.sock";
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(60);
+const MAX_MESSAGE: usize = 256 * 1024;
+
+const SSH_AGENT_FAILURE: u8 = 5;
+const SSH_AGENTC_REQUEST_IDENTITIES: u8 = 11;
+const SSH_AGENT_IDENTITIES_ANSWER: u8 = 12;
+pub const SSH_AGENTC_SIGN_REQUEST: u8 = 13;
+pub const SSH_AGENT_SIGN_RESPONSE: u8 = 14;
+const SSH_AGENT_RSA_SHA2_256: u32 = 0x02;
+pub const SSH_AGENT_RSA_SHA2_512: u32 = 0x04;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AgentError {
+    #[error("vault locked")]
+    Locked,
+    #[cfg(not(unix))]
+    #[error("ssh agent is not supported on this platform")]
+    Unsupported,
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("key error: {0}")]
+    Key(String),
+    #[error(transparent)]
+    Sync(#[from] SyncError),
+}
+
+impl Serialize for AgentError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+fn ioerr(e: impl std::fmt::Display) -> AgentError {
+    AgentError::Io(e.to_string())
+}
+
+#[derive(Clone)]
+pub struct AgentKey {
+    pub id: String,
+    pub title: String,
+    pub key: PrivateKey,
+    pub blob: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgentIdentity {
+    pub id: String,
+    pub title: String,
+    pub algorithm: String,
+    pub fingerprint: String,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkippedKey {
+    pub id: String,
+    pub title: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentStatus {
+    pub running: bool,
+    pub socket: Option<String>,
+    pub identities: Vec<AgentIdentity>,
+    pub skipped: Vec<SkippedKey>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApprovalRequest {
+    pub request: String,
+    pub id: String,
+    pub title: String,
+    pub fingerprint: String,
+}
+
+#[derive(Default)]
+pub struct AgentState {
+    socket: Mutex<Option<PathBuf>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+    skipped: Mutex<Vec<SkippedKey>>,
+    source: Mutex<Option<(String, String)>>,
+    pending: Mutex<HashMap<String, oneshot::Sender<bool>>>,
+}
+
+impl AgentState {
+    fn status(&self) -> AgentStatus {
+        let socket = self.socket.lock().unwrap().clone();
+        AgentStatus {
+            running: socket.is_some(),
+            socket: socket.map(|p| p.display().to_string()),
+            identities: KEYS.read().unwrap().iter().map(identity).collect(),
+            skipped: self.skipped.lock().unwrap().clone(),
+        }
+    }
+
+    fn stop(&self) {
+        if let Some(task) = self.task.lock().unwrap().take() {
+            task.abort();
+        }
+        if let Some(path) = self.socket.lock().unwrap().take() {
+            let _ = std::fs::remove_file(path);
+        }
+        for (_, tx) in self.pending.lock().unwrap().drain() {
+            let _ = tx.send(false);
+        }
+    }
+}
+
+static KEYS: RwLock<Vec<AgentKey>> = RwLock::new(Vec::new());
+
+pub fn clear() {
+    KEYS.write().unwrap().clear();
+}
+
+pub fn reload<R: Runtime>(app: &AppHandle<R>) {
+    let Some((base_url, token)) = app.state::<AgentState>().source.lock().unwrap().clone() else {
+        return;
+    };
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match sync::fetchitems(&base_url, &token).await {
+            Ok(items) if !crypto::islocked() => {
+                let (keys, skipped) = loadkeys(&items);
+                *KEYS.write().unwrap() = keys;
+                *app.state::<AgentState>().skipped.lock().unwrap() = skipped;
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("ssh agent reload failed: {}", e),
+        }
+    });
+}
+
+pub fn loadkey(id: &str, title: &str, data: &str) -> Result<AgentKey, String> {
+    let Ok(Value::Object(map)) = serde_json::from_str::<Value>(data) else {
+        return Err("invalid item data".into());
+    };
+    let field = |name: &str| {
+        map.get(name)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    };
+    let pem = field("privatekey").ok_or("no private key")?;
+    let key = sshkey::open(pem, field("passphrase")).map_err(|e| e.to_string())?;
+    let blob = key.public_key().to_bytes().map_err(|e| e.to_string())?;
+    Ok(AgentKey {
+        id: id.to_string(),
+        title: title.to_string(),
+        key,
+        blob,
+    })
+}
+
+pub fn loadkeys(items: &[RemoteItem]) -> (Vec<AgentKey>, Vec<SkippedKey>) {
+    let mut keys = Vec::new();
+    let mut skipped = Vec::new();
+    for item in items
+        .iter()
+        .filter(|i| !i.deleted && i.item_type.eq_ignore_ascii_case("ssh"))
+    {
+        match loadkey(&item.id, &item.title, &item.data) {
+            Ok(key) if keys.iter().any(|k: &AgentKey| k.blob == key.blob) => {}
+            Ok(key) => keys.push(key),
+            Err(reason) => skipped.push(SkippedKey {
+                id: item.id.clone(),
+                title: item.title.clone(),
+                reason,
+            }),
+        }
+    }
+    (keys, skipped)
+}
+
+pub fn identity(key: &AgentKey) -> AgentIdentity {
+    AgentIdentity {
+        id: key.id.clone(),
+        title: key.title.clone(),
+        algorithm: key.key.algorithm().as_str().to_string(),
+        fingerprint: key.key.fingerprint(HashAlg::Sha256).to_string(),
+        comment: comment(key).to_string(),
+    }
+}
+
+fn comment(key: &AgentKey) -> &str {
+    match key.key.comment() {
+        "" => &key.title,
+        comment => comment,
+    }
+}
+
+pub fn readu32(buf: &mut &[u8]) -> Option<u32> {
+    if buf.len() < 4 {
+        return None;
+    }
+    let (head, rest) = buf.split_at(4);
+    *buf = rest;
+    Some(u32::from_be_bytes(head.try_into().ok()?))
+}
+
+pub fn readstring<'a>(buf: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let len = readu32(buf)? as usize;
+    if buf.len() < len {
+        return None;
+    }
+    let (head, rest) = buf.split_at(len);
+    *buf = rest;
+    Some(head)
+}
+
+pub fn writestring(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(data);
+}
+
+pub fn message(kind: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(5 + payload.len());
+    out.extend_from_slice(&(payload.len() as u32 + 1).to_be_bytes());
+    out.push(kind);
+    out.extend_from_slice(payload);
+    out
+}
+
+fn failure() -> Vec<u8> {
+    message(SSH_AGENT_FAILURE, &[])
+}
+
+pub fn identities(keys: &[AgentKey]) -> Vec<u8> {
+    let mut payload = (keys.len() as u32).to_be_bytes().to_vec();
+    for key in keys {
+        writestring(&mut payload, &key.blob);
+        writestring(&mut payload, comment(key).as_bytes());
+    }
+    message(SSH_AGENT_IDENTITIES_ANSWER, &payload)
+}
+
+fn rsakey(keypair: &RsaKeypair) -> Result<rsa::RsaPrivateKey, AgentError> {
+    let uint = |m: &ssh_key::Mpint| {
+        m.as_positive_bytes()
+            .map(rsa::BigUint::from_bytes_be)
+            .ok_or_else(|| AgentError::Key("invalid rsa key".into()))
+    };
+    rsa::RsaPrivateKey::from_components(
+        uint(&keypair.public.n)?,
+        uint(&keypair.public.e)?,
+        uint(&keypair.private.d)?,
+        vec![uint(&keypair.private.p)?, uint(&keypair.private.q)?],
+    )
+    .map_err(|e| AgentError::Key(e.to_string()))
+}
+
+pub fn sign(key: &AgentKey, data: &[u8], flags: u32) -> Result<Vec<u8>, AgentError> {
+    let (algorithm, signature) = match key.key.key_data() {
+        KeypairData::Rsa(keypair) => {
+            let private = rsakey(keypair)?;
+            let rsaerr = |e: rsa::signature::Error| AgentError::Key(e.to_string());
+            if flags & SSH_AGENT_RSA_SHA2_512 != 0 {
+                let signature = SigningKey::<Sha512>::new(private)
+                    .try_sign(data)
+                    .map_err(rsaerr)?;
+                ("rsa-sha2-512".to_string(), signature.to_vec())
+            } else if flags & SSH_AGENT_RSA_SHA2_256 != 0 {
+                let signature = SigningKey::<Sha256>::new(private)
+                    .try_sign(data)
+                    .map_err(rsaerr)?;
+                ("rsa-sha2-256".to_string(), signature.to_vec())
+            } else {
+                return Err(AgentError::Key(
+                    "ssh-rsa sha1 signatures are refused".into(),
+                ));
+            }
+        }
+        _ => {
+            let signature: ssh_key::Signature = key
+                .key
+                .try_sign(data)
+                .map_err(|e| AgentError::Key(e.to_string()))?;
+            (
+                signature.algorithm().as_str().to_string(),
+                signature.as_bytes().to_vec(),
+            )
+        }
+    };
+    let mut blob = Vec::new();
+    writestring(&mut blob, algorithm.as_bytes());
+    writestring(&mut blob, &signature);
+    let mut payload = Vec::new();
+    writestring(&mut payload, &blob);
+    Ok(message(SSH_AGENT_SIGN_RESPONSE, &payload))
+}
+
+pub async fn respond<F, Fut>(request: &[u8], keys: &[AgentKey], approve: F) -> Vec<u8>
+where
+    F: FnOnce(AgentKey) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let Some((&kind, mut payload)) = request.split_first() else {
+        return failure();
+    };
+    match kind {
+        SSH_AGENTC_REQUEST_IDENTITIES => identities(keys),
+        SSH_AGENTC_SIGN_REQUEST => {
+            let (Some(blob), Some(data)) = (readstring(&mut payload), readstring(&mut payload))
+            else {
+                return failure();
+            };
+            let flags = readu32(&mut payload).unwrap_or(0);
+            let Some(key) = keys.iter().find(|k| k.blob == blob) else {
+                return failure();
+            };
+            if !approve(key.clone()).await {
+                return failure();
+            }
+            sign(key, data, flags).unwrap_or_else(|e| {
+                log::warn!("ssh agent signature failed: {}", e);
+                failure()
+            })
+        }
+        _ => failure(),
+    }
+}
+
+async fn answer<F, Fut>(request: &[u8], approve: F) -> Vec<u8>
+where
+    F: FnOnce(AgentKey) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    if crypto::islocked() {
+        return failure();
+    }
+    let keys = KEYS.read().unwrap().clone();
+    respond(request, &keys, approve).await
+}
+
+async fn approval<R: Runtime>(app: &AppHandle<R>, key: AgentKey) -> bool {
+    let request = format!("{:016x}", rand::random::<u64>());
+    let (tx, rx) = oneshot::channel();
+    app.state::<AgentState>()
+        .pending
+        .lock()
+        .unwrap()
+        .insert(request.clone(), tx);
+    let identity = identity(&key);
+    let _ = app.emit(
+        "agent_approval",
+        ApprovalRequest {
+            request: request.clone(),
+            id: identity.id,
+            title: identity.title,
+            fingerprint: identity.fingerprint,
+        },
+    );
+    let approved = matches!(
+        tokio::time::timeout(APPROVAL_TIMEOUT, rx).await,
+        Ok(Ok(true))
+    );
+    app.state::<AgentState>()
+        .pending
+        .lock()
+        .unwrap()
+        .remove(&request);
+    approved && !crypto::islocked()
+}
+
+async fn serve<R: Runtime, S: AsyncRead + AsyncWrite + Unpin>(app: AppHandle<R>, mut stream: S) {
+    loop {
+        let mut len = [0u8; 4];
+        if stream.read_exact(&mut len).await.is_err() {
+            return;
+        }
+        let len = u32::from_be_bytes(len) as usize;
+        if len == 0 || len > MAX_MESSAGE {
+            return;
+        }
+        let mut request = vec![0u8; len];
+        if stream.read_exact(&mut request).await.is_err() {
+            return;
+        }
+        let response = answer(&request, |key| approval(&app, key)).await;
+        if stream.write_all(&response).await.is_err() {
+            return;
+        }
+    }
+}
+
+pub fn socketpath() -> Result<PathBuf, AgentError> {
+    Ok(storage::get_socket_dir().map_err(ioerr)?.join(SOCKET_FILE))
+}
+
+#[cfg(unix)]
+fn listen<R: Runtime>(app: AppHandle<R>) -> Result<(PathBuf, JoinHandle<()>), AgentError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = socketpath()?;
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path).map_err(ioerr)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).map_err(ioerr)?;
+    let task = tauri::async_runtime::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tauri::async_runtime::spawn(serve(app.clone(), stream));
+        }
+    });
+    Ok((path, task))
+}
+
+#[cfg(not(unix))]
+fn listen<R: Runtime>(_app: AppHandle<R>) -> Result<(PathBuf, JoinHandle<()>), AgentError> {
+    Err(AgentError::Unsupported)
+}
+
+#[tauri::command]
+pub async fn agent_start<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AgentState>,
+    base_url: String,
+    token: String,
+) -> Result<AgentStatus, AgentError> {
+    if crypto::islocked() {
+        return Err(AgentError::Locked);
+    }
+    let items = sync::fetchitems(&base_url, &token).await?;
+    let (keys, skipped) = loadkeys(&items);
+    *KEYS.write().unwrap() = keys;
+    *state.skipped.lock().unwrap() = skipped;
+    *state.source.lock().unwrap() = Some((base_url, token));
+
+    if state.socket.lock().unwrap().is_none() {
+        let (path, task) = listen(app)?;
+        *state.socket.lock().unwrap() = Some(path);
+        *state.task.lock().unwrap() = Some(task);
+    }
+    Ok(state.status())
+}
+
+#[tauri::command]
+pub fn agent_stop(state: State<'_, AgentState>) {
+    state.stop();
+    state.skipped.lock().unwrap().clear();
+    *state.source.lock().unwrap() = None;
+    clear();
+}
+
+#[tauri::command]
+pub fn agent_status(state: State<'_, AgentState>) -> AgentStatus {
+    state.status()
+}
+
+#[tauri::command]
+pub fn agent_approve(state: State<'_, AgentState>, request: String, approved: bool) {
+    if let Some(tx) = state.pending.lock().unwrap().remove(&request) {
+        let _ = tx.send(approved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ssh_key::{Algorithm, EcdsaCurve, Signature};
+
+    fn generate(algorithm: Algorithm) -> AgentKey {
+        let mut key = match algorithm {
+            Algorithm::Rsa { .. } => {
+                PrivateKey::from(RsaKeypair::random(&mut rand::rngs::OsRng, 2048).unwrap())
+            }
+            _ => PrivateKey::random(&mut rand::rngs::OsRng, algorithm).unwrap(),
+        };
+        key.set_comment("ann@laptop");
+        let pem = key.to_openssh(ssh_key::LineEnding::LF).unwrap();
+        let data = serde_json::json!({ "privatekey": pem.as_str() }).to_string();
+        loadkey("k", "laptop", &data).unwrap()
+    }
+
+    fn request(blob: &[u8], data: &[u8], flags: u32) -> Vec<u8> {
+        let mut out = vec![SSH_AGENTC_SIGN_REQUEST];
+        writestring(&mut out, blob);
+        writestring(&mut out, data);
+        out.extend_from_slice(&flags.to_be_bytes());
+        out
+    }
+
+    fn verify(key: &AgentKey, data: &[u8], response: &[u8]) -> String {
+        assert_eq!(response[4], SSH_AGENT_SIGN_RESPONSE);
+        let mut payload = &response[5..];
+        let mut blob = readstring(&mut payload).unwrap();
+        let name = String::from_utf8(readstring(&mut blob).unwrap().to_vec()).unwrap();
+        let bytes = readstring(&mut blob).unwrap();
+        let signature = Signature::new(Algorithm::new(&name).unwrap(), bytes).unwrap();
+        let public = key.key.public_key().key_data();
+        rsa::signature::Verifier::verify(public, data, &signature).unwrap();
+        name
+    }
+
+    #[tokio::test]
+    async fn test_agent_locked_refusal() {
+        let key = generate(Algorithm::Ed25519);
+        *KEYS.write().unwrap() = vec![key.clone()];
+        assert!(crypto::islocked());
+
+        let sign = request(&key.blob, b"challenge", 0);
+        assert_eq!(answer(&sign, |_| async { true }).await, failure());
+        let list = answer(&[SSH_AGENTC_REQUEST_IDENTITIES], |_| async { true }).await;
+        assert_eq!(list, failure());
+        clear();
+    }
+
+    #[tokio::test]
+    async fn test_agent_approval() {
+        let data = b"session-id and userauth request";
+        let keys = vec![
+            generate(Algorithm::Ed25519),
+            generate(Algorithm::Ecdsa {
+                curve: EcdsaCurve::NistP256,
+            }),
+        ];
+
+        let answer = respond(&[SSH_AGENTC_REQUEST_IDENTITIES], &keys, |_| async { true }).await;
+        let mut payload = &answer[5..];
+        assert_eq!(answer[4], SSH_AGENT_IDENTITIES_ANSWER);
+        assert_eq!(readu32(&mut payload), Some(2));
+        assert_eq!(readstring(&mut payload), Some(&keys[0].blob[..]));
+        assert_eq!(readstring(&mut payload), Some(&b"ann@laptop"[..]));
+
+        for key in &keys {
+            let response = respond(&request(&key.blob, data, 0), &keys, |_| async { true }).await;
+            assert_eq!(verify(key, data, &response), key.key.algorithm().as_str());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_agent_denial() {
+        let data = b"session-id and userauth request";
+        let keys = vec![generate(Algorithm::Ed25519)];
+
+        let denied = respond(&request(&keys[0].blob, data, 0), &keys, |k| async move {
+            k.id != "k"
+        })
+        .await;
+        assert_eq!(denied, failure());
+        let unknown = respond(&request(b"nope", data, 0), &keys, |_| async { true }).await;
+        assert_eq!(unknown, failure());
+        assert_eq!(respond(&[99], &keys, |_| async { true }).await, failure());
+    }
+
+    #[tokio::test]
+    async fn test_agent_rsa_sha1_refusal() {
+        let keys = vec![generate(Algorithm::Rsa { hash: None })];
+        let sha1 = respond(&request(&keys[0].blob, b"challenge", 0), &keys, |_| async {
+            true
+        })
+        .await;
+        assert_eq!(sha1, failure());
+        assert!(sign(&keys[0], b"challenge", 0).is_err());
+    }
+
+    #[test]
+    fn test_loadkey_passphrase() {
+        let encrypted = PrivateKey::random(&mut rand::rngs::OsRng, Algorithm::Ed25519)
+            .unwrap()
+            .encrypt(&mut rand::rngs::OsRng, "hunter2")
+            .unwrap()
+            .to_openssh(ssh_key::LineEnding::LF)
+            .unwrap();
+        let item = |passphrase: &str| {
+            serde_json::json!({ "privatekey": encrypted.as_str(), "passphrase": passphrase })
+                .to_string()
+        };
+        assert!(loadkey("e", "encrypted", &item("hunter2")).is_ok());
+        assert_eq!(
+            loadkey("e", "encrypted", &item("wrong")).err(),
+            Some("wrong passphrase".to_string())
+        );
+        assert!(loadkey("e", "empty", "{}").is_err());
+    }
+
+    #[test]
+    fn test_agent_rsa_sha2() {
+        let key = generate(Algorithm::Rsa { hash: None });
+        let data = b"challenge";
+        let sha256 = sign(&key, data, SSH_AGENT_RSA_SHA2_256).unwrap();
+        assert_eq!(verify(&key, data, &sha256), "rsa-sha2-256");
+        let sha512 = sign(&key, data, SSH_AGENT_RSA_SHA2_512).unwrap();
+        assert_eq!(verify(&key, data, &sha512), "rsa-sha2-512");
+    }
+}
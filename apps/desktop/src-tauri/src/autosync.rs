@@ -0,0 +1,836 @@
+use noro_common::time::{formattimestamp, now, parsetimestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{async_runtime::JoinHandle, AppHandle, Emitter, Manager, Runtime, State};
+use tokio::sync::Notify;
+
+use crate::crypto;
+use crate::events::{ChangeEvent, EventStream, VaultEvent};
+use crate::search;
+use crate::sync::{self, RemoteItem, SyncError};
+
+const DEFAULT_INTERVAL: u64 = 300;
+const MIN_INTERVAL: u64 = 15;
+const MAX_BACKOFF: u64 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PendingChange {
+    Create {
+        id: String,
+        item_type: String,
+        title: String,
+        data: String,
+        tags: Vec<String>,
+        favorite: bool,
+    },
+    Update {
+        id: String,
+        revision: i32,
+        title: Option<String>,
+        data: Option<String>,
+        tags: Option<Vec<String>>,
+        favorite: Option<bool>,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+impl PendingChange {
+    fn id(&self) -> &str {
+        match self {
+            PendingChange::Create { id, .. } => id,
+            PendingChange::Update { id, .. } => id,
+            PendingChange::Delete { id } => id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncStarted {
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncProgress {
+    pub phase: String,
+    pub done: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncCompleted {
+    pub changed: Vec<RemoteItem>,
+    pub removed: Vec<String>,
+    pub pushed: usize,
+    pub error: Option<String>,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConflict {
+    pub id: String,
+    pub local_revision: i32,
+    pub server_revision: i32,
+    pub server: RemoteItem,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncRejected {
+    pub change: PendingChange,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConnection {
+    pub connected: bool,
+}
+
+#[derive(Debug, Clone)]
+struct SyncConfig {
+    base_url: String,
+    token: String,
+    interval: u64,
+}
+
+#[derive(Default)]
+pub struct SyncWorker {
+    config: Mutex<Option<SyncConfig>>,
+    pending: Mutex<Vec<PendingChange>>,
+    revisions: Mutex<HashMap<String, i32>>,
+    conflicts: Mutex<HashMap<String, i32>>,
+    revision: Mutex<Option<i64>>,
+    cursor: Mutex<Option<u64>>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+    wake: Notify,
+    retime: Notify,
+}
+
+impl SyncWorker {
+    fn config(&self) -> Option<SyncConfig> {
+        self.config.lock().unwrap().clone()
+    }
+
+    pub fn record(&self, change: PendingChange) {
+        if self.config().is_some() {
+            self.enqueue(change);
+            self.wake.notify_one();
+        }
+    }
+
+    pub fn revision(&self, id: &str) -> i32 {
+        self.revisions
+            .lock()
+            .unwrap()
+            .get(id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn enqueue(&self, change: PendingChange) {
+        let mut pending = self.pending.lock().unwrap();
+        match &change {
+            PendingChange::Update { id, .. } => {
+                if let Some(PendingChange::Create { .. }) = pending.iter().find(|c| c.id() == id) {
+                    mergecreate(&mut pending, change);
+                    return;
+                }
+            }
+            PendingChange::Delete { id } => {
+                let created = pending
+                    .iter()
+                    .any(|c| c.id() == id && matches!(c, PendingChange::Create { .. }));
+                pending.retain(|c| c.id() != id);
+                if created {
+                    return;
+                }
+            }
+            PendingChange::Create { .. } => {}
+        }
+        pending.push(change);
+    }
+
+    fn advance<'a>(&self, items: impl IntoIterator<Item = &'a RemoteItem>) {
+        let latest = items
+            .into_iter()
+            .filter_map(|i| parsetimestamp(&i.updated_at))
+            .max();
+        let mut cursor = self.cursor.lock().unwrap();
+        *cursor = (*cursor).max(latest);
+    }
+
+    fn behind(&self, revision: i64) -> bool {
+        let known = self.revision.lock().unwrap().replace(revision);
+        known != Some(revision) && self.cursor.lock().unwrap().is_some()
+    }
+
+    fn hold(&self, conflict: &SyncConflict) -> bool {
+        let mut conflicts = self.conflicts.lock().unwrap();
+        conflicts.insert(conflict.id.clone(), conflict.server_revision)
+            != Some(conflict.server_revision)
+    }
+
+    fn resolve(&self, id: &str, keep_local: bool) {
+        let Some(server_revision) = self.conflicts.lock().unwrap().remove(id) else {
+            return;
+        };
+        let mut pending = self.pending.lock().unwrap();
+        if keep_local {
+            for change in pending.iter_mut() {
+                if let PendingChange::Update {
+                    id: uid, revision, ..
+                } = change
+                {
+                    if uid == id {
+                        *revision = server_revision;
+                    }
+                }
+            }
+        } else {
+            pending.retain(|c| c.id() != id || !matches!(c, PendingChange::Update { .. }));
+        }
+    }
+}
+
+fn conflict(change: &PendingChange, remote: &HashMap<String, RemoteItem>) -> Option<SyncConflict> {
+    let PendingChange::Update { id, revision, .. } = change else {
+        return None;
+    };
+    let server = remote.get(id).filter(|s| s.revision != *revision)?;
+    Some(SyncConflict {
+        id: id.clone(),
+        local_revision: *revision,
+        server_revision: server.revision,
+        server: server.clone(),
+    })
+}
+
+fn mergecreate(pending: &mut [PendingChange], update: PendingChange) {
+    let PendingChange::Update {
+        id,
+        title,
+        data,
+        tags,
+        favorite,
+        ..
+    } = update
+    else {
+        return;
+    };
+    for change in pending.iter_mut() {
+        if let PendingChange::Create {
+            id: cid,
+            title: ctitle,
+            data: cdata,
+            tags: ctags,
+            favorite: cfavorite,
+            ..
+        } = change
+        {
+            if *cid != id {
+                continue;
+            }
+            if let Some(t) = title.clone() {
+                *ctitle = t;
+            }
+            if let Some(d) = data.clone() {
+                *cdata = d;
+            }
+            if let Some(t) = tags.clone() {
+                *ctags = t;
+            }
+            if let Some(f) = favorite {
+                *cfavorite = f;
+            }
+        }
+    }
+}
+
+async fn push<R: Runtime>(
+    app: &AppHandle<R>,
+    worker: &SyncWorker,
+    config: &SyncConfig,
+    remote: &HashMap<String, RemoteItem>,
+) -> Result<usize, SyncError> {
+    let changes: Vec<PendingChange> = worker.pending.lock().unwrap().drain(..).collect();
+    let total = changes.len();
+    let mut pushed = 0;
+    let mut kept = Vec::new();
+
+    for (i, change) in changes.iter().enumerate() {
+        if let Some(conflict) = conflict(change, remote) {
+            if worker.hold(&conflict) {
+                let _ = app.emit("sync_conflict", conflict);
+            }
+            kept.push(change.clone());
+            let _ = app.emit(
+                "sync_progress",
+                SyncProgress {
+                    phase: "push".into(),
+                    done: i + 1,
+                    total,
+                },
+            );
+            continue;
+        }
+
+        let result = match change.clone() {
+            PendingChange::Create {
+                id,
+                item_type,
+                title,
+                data,
+                tags,
+                favorite,
+            } => sync::createitem(
+                &config.base_url,
+                &config.token,
+                id,
+                item_type,
+                &title,
+                &data,
+                tags,
+                favorite,
+            )
+            .await
+            .map(|_| ()),
+            PendingChange::Update {
+                id,
+                title,
+                data,
+                tags,
+                favorite,
+                ..
+            } => sync::updateitem(
+                &config.base_url,
+                &config.token,
+                &id,
+                title.as_deref(),
+                data.as_deref(),
+                tags,
+                favorite,
+            )
+            .await
+            .map(|_| ()),
+            PendingChange::Delete { id } => {
+                sync::deleteitem(&config.base_url, &config.token, &id).await
+            }
+        };
+
+        match result {
+            Ok(()) => pushed += 1,
+            Err(SyncError::Rejected(error)) => {
+                let _ = app.emit(
+                    "sync_rejected",
+                    SyncRejected {
+                        change: change.clone(),
+                        error,
+                    },
+                );
+            }
+            Err(e) => {
+                let mut pending = worker.pending.lock().unwrap();
+                kept.extend_from_slice(&changes[i..]);
+                pending.splice(0..0, kept);
+                return Err(e);
+            }
+        }
+        let _ = app.emit(
+            "sync_progress",
+            SyncProgress {
+                phase: "push".into(),
+                done: i + 1,
+                total,
+            },
+        );
+    }
+
+    worker.pending.lock().unwrap().splice(0..0, kept);
+    Ok(pushed)
+}
+
+async fn cycle<R: Runtime>(
+    app: &AppHandle<R>,
+    worker: &SyncWorker,
+    config: &SyncConfig,
+) -> Result<SyncCompleted, SyncError> {
+    let hasupdates = worker
+        .pending
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|c| matches!(c, PendingChange::Update { .. }));
+
+    let before: HashMap<String, RemoteItem> = if hasupdates {
+        sync::fetchitems(&config.base_url, &config.token)
+            .await?
+            .into_iter()
+            .map(|i| (i.id.clone(), i))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let pushed = push(app, worker, config, &before).await?;
+
+    let items = sync::fetchall(&config.base_url, &config.token).await?;
+    let _ = app.emit(
+        "sync_progress",
+        SyncProgress {
+            phase: "pull".into(),
+            done: items.len(),
+            total: items.len(),
+        },
+    );
+
+    let mut revisions = worker.revisions.lock().unwrap();
+    let changed: Vec<RemoteItem> = items
+        .iter()
+        .filter(|i| revisions.get(&i.id) != Some(&i.revision))
+        .cloned()
+        .collect();
+    let removed: Vec<String> = revisions
+        .keys()
+        .filter(|id| !items.iter().any(|i| &i.id == *id))
+        .cloned()
+        .collect();
+    *revisions = items.iter().map(|i| (i.id.clone(), i.revision)).collect();
+    worker.advance(&items);
+
+    Ok(SyncCompleted {
+        changed,
+        removed,
+        pushed,
+        error: None,
+        timestamp: now(),
+    })
+}
+
+async fn run<R: Runtime>(app: AppHandle<R>) {
+    let mut reason = "start";
+    let mut retry = None;
+    loop {
+        let worker = app.state::<SyncWorker>();
+        let Some(config) = worker.config() else {
+            return;
+        };
+
+        if !crypto::islocked() {
+            let _ = app.emit(
+                "sync_started",
+                SyncStarted {
+                    reason: reason.into(),
+                },
+            );
+            let completed = match cycle(&app, &worker, &config).await {
+                Ok(completed) => {
+                    retry = None;
+                    completed
+                }
+                Err(e) => {
+                    retry = Some(retry.map_or(1, |r: u64| (r * 2).min(MAX_BACKOFF)));
+                    SyncCompleted {
+                        changed: vec![],
+                        removed: vec![],
+                        pushed: 0,
+                        error: Some(e.to_string()),
+                        timestamp: now(),
+                    }
+                }
+            };
+            let _ = app.emit("sync_completed", completed);
+        }
+
+        let started = tokio::time::Instant::now();
+        reason = loop {
+            let interval = worker.config().map_or(config.interval, |c| c.interval);
+            let interval = retry.map_or(interval, |r| r.min(interval));
+            tokio::select! {
+                _ = tokio::time::sleep_until(started + Duration::from_secs(interval)) => break "interval",
+                _ = worker.wake.notified() => break "change",
+                _ = worker.retime.notified() => {}
+            }
+        };
+    }
+}
+
+async fn delta<R: Runtime>(
+    app: &AppHandle<R>,
+    worker: &SyncWorker,
+    config: &SyncConfig,
+    change: ChangeEvent,
+) -> Result<SyncCompleted, SyncError> {
+    let stale: Vec<_> = {
+        let revisions = worker.revisions.lock().unwrap();
+        change
+            .items
+            .into_iter()
+            .filter(|i| revisions.get(&i.id) != Some(&i.revision))
+            .collect()
+    };
+
+    let total = stale.len();
+    let mut changed = Vec::new();
+    for (i, item) in stale.into_iter().enumerate() {
+        let remote = match sync::fetchitem(&config.base_url, &config.token, &item.id).await {
+            Ok(remote) => remote,
+            Err(SyncError::Rejected(_)) if change.purged.contains(&item.id) => continue,
+            Err(e) => return Err(e),
+        };
+        worker
+            .revisions
+            .lock()
+            .unwrap()
+            .insert(remote.id.clone(), remote.revision);
+        worker.advance([&remote]);
+        changed.push(remote);
+        let _ = app.emit(
+            "sync_progress",
+            SyncProgress {
+                phase: "pull".into(),
+                done: i + 1,
+                total,
+            },
+        );
+    }
+
+    let mut removed = Vec::new();
+    for id in change.purged {
+        worker.revisions.lock().unwrap().remove(&id);
+        search::remove(&id);
+        removed.push(id);
+    }
+
+    Ok(SyncCompleted {
+        changed,
+        removed,
+        pushed: 0,
+        error: None,
+        timestamp: now(),
+    })
+}
+
+async fn catchup(worker: &SyncWorker, config: &SyncConfig) -> Result<SyncCompleted, SyncError> {
+    let since = formattimestamp(worker.cursor.lock().unwrap().unwrap_or(0));
+    let delta = sync::fetchsince(&config.base_url, &config.token, &since).await?;
+    worker.advance(&delta.items);
+
+    let mut revisions = worker.revisions.lock().unwrap();
+    let changed: Vec<RemoteItem> = delta
+        .items
+        .into_iter()
+        .filter(|i| revisions.insert(i.id.clone(), i.revision) != Some(i.revision))
+        .collect();
+    let removed: Vec<String> = delta
+        .purged
+        .into_iter()
+        .filter(|id| revisions.remove(id).is_some())
+        .collect();
+
+    Ok(SyncCompleted {
+        changed,
+        removed,
+        pushed: 0,
+        error: None,
+        timestamp: now(),
+    })
+}
+
+async fn live<R: Runtime>(app: AppHandle<R>) {
+    let mut backoff = 1;
+    loop {
+        let worker = app.state::<SyncWorker>();
+        let Some(config) = worker.config() else {
+            return;
+        };
+
+        match EventStream::connect(&config.base_url, &config.token).await {
+            Ok(mut stream) => {
+                backoff = 1;
+                let _ = app.emit("sync_connection", SyncConnection { connected: true });
+                while let Ok(Some(event)) = stream.next().await {
+                    if crypto::islocked() {
+                        continue;
+                    }
+                    let reason = match &event {
+                        VaultEvent::Ready { revision } if worker.behind(*revision) => "reconnect",
+                        VaultEvent::Ready { .. } => continue,
+                        VaultEvent::Change(change) => {
+                            worker.revision.lock().unwrap().replace(change.revision);
+                            "remote"
+                        }
+                    };
+                    let _ = app.emit(
+                        "sync_started",
+                        SyncStarted {
+                            reason: reason.into(),
+                        },
+                    );
+                    let result = match event {
+                        VaultEvent::Ready { .. } => catchup(&worker, &config).await,
+                        VaultEvent::Change(change) => delta(&app, &worker, &config, change).await,
+                    };
+                    let completed = match result {
+                        Ok(completed) => completed,
+                        Err(e) => SyncCompleted {
+                            changed: vec![],
+                            removed: vec![],
+                            pushed: 0,
+                            error: Some(e.to_string()),
+                            timestamp: now(),
+                        },
+                    };
+                    let _ = app.emit("sync_completed", completed);
+                }
+                let _ = app.emit("sync_connection", SyncConnection { connected: false });
+            }
+            Err(SyncError::Auth(_)) => return,
+            Err(_) => {}
+        }
+
+        tokio::time::sleep(Duration::from_secs(backoff)).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+#[tauri::command]
+pub fn sync_start<R: Runtime>(
+    app: AppHandle<R>,
+    worker: State<'_, SyncWorker>,
+    base_url: String,
+    token: String,
+    interval: Option<u64>,
+) {
+    let interval = interval.unwrap_or(DEFAULT_INTERVAL).max(MIN_INTERVAL);
+    *worker.config.lock().unwrap() = Some(SyncConfig {
+        base_url,
+        token,
+        interval,
+    });
+
+    let mut tasks = worker.tasks.lock().unwrap();
+    for task in tasks.drain(..) {
+        task.abort();
+    }
+    tasks.push(tauri::async_runtime::spawn(run(app.clone())));
+    tasks.push(tauri::async_runtime::spawn(live(app)));
+}
+
+#[tauri::command]
+pub fn sync_stop(worker: State<'_, SyncWorker>) {
+    *worker.config.lock().unwrap() = None;
+    worker.pending.lock().unwrap().clear();
+    worker.revisions.lock().unwrap().clear();
+    worker.conflicts.lock().unwrap().clear();
+    *worker.revision.lock().unwrap() = None;
+    *worker.cursor.lock().unwrap() = None;
+    for task in worker.tasks.lock().unwrap().drain(..) {
+        task.abort();
+    }
+}
+
+#[tauri::command]
+pub fn sync_set_interval(worker: State<'_, SyncWorker>, interval: u64) {
+    if let Some(config) = worker.config.lock().unwrap().as_mut() {
+        config.interval = interval.max(MIN_INTERVAL);
+    }
+    worker.retime.notify_one();
+}
+
+#[tauri::command]
+pub fn sync_now(worker: State<'_, SyncWorker>) {
+    worker.wake.notify_one();
+}
+
+#[tauri::command]
+pub fn sync_push(worker: State<'_, SyncWorker>, change: PendingChange) {
+    worker.enqueue(change);
+    worker.wake.notify_one();
+}
+
+#[tauri::command]
+pub fn sync_resolve_conflict(worker: State<'_, SyncWorker>, id: String, keep_local: bool) {
+    worker.resolve(&id, keep_local);
+    worker.wake.notify_one();
+}
+
+#[tauri::command]
+pub fn sync_pending(worker: State<'_, SyncWorker>) -> Vec<PendingChange> {
+    worker.pending.lock().unwrap().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create(id: &str) -> PendingChange {
+        PendingChange::Create {
+            id: id.into(),
+            item_type: "login".into(),
+            title: "old".into(),
+            data: "a".into(),
+            tags: vec![],
+            favorite: false,
+        }
+    }
+
+    fn update(id: &str, revision: i32, title: &str) -> PendingChange {
+        PendingChange::Update {
+            id: id.into(),
+            revision,
+            title: Some(title.into()),
+            data: None,
+            tags: None,
+            favorite: Some(true),
+        }
+    }
+
+    fn remote(id: &str, revision: i32) -> HashMap<String, RemoteItem> {
+        let item: RemoteItem = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "login",
+            "title": "server",
+            "data": "b",
+            "revision": revision,
+            "favorite": false,
+            "deleted": false,
+            "tags": [],
+            "createdAt": "",
+            "updatedAt": "",
+        }))
+        .unwrap();
+        HashMap::from([(id.to_string(), item)])
+    }
+
+    fn pending(worker: &SyncWorker) -> Vec<PendingChange> {
+        worker.pending.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn test_enqueue_merges_update_into_create() {
+        let worker = SyncWorker::default();
+        worker.enqueue(create("a"));
+        worker.enqueue(update("a", 0, "new"));
+
+        let changes = pending(&worker);
+        assert_eq!(changes.len(), 1);
+        let PendingChange::Create {
+            title,
+            data,
+            favorite,
+            ..
+        } = &changes[0]
+        else {
+            panic!("expected create");
+        };
+        assert_eq!(title, "new");
+        assert_eq!(data, "a");
+        assert!(favorite);
+    }
+
+    #[test]
+    fn test_enqueue_delete_drops_unsent_create() {
+        let worker = SyncWorker::default();
+        worker.enqueue(create("a"));
+        worker.enqueue(update("a", 0, "new"));
+        worker.enqueue(PendingChange::Delete { id: "a".into() });
+        assert!(pending(&worker).is_empty());
+    }
+
+    #[test]
+    fn test_enqueue_delete_replaces_updates() {
+        let worker = SyncWorker::default();
+        worker.enqueue(update("a", 1, "one"));
+        worker.enqueue(update("b", 1, "two"));
+        worker.enqueue(update("a", 1, "three"));
+        worker.enqueue(PendingChange::Delete { id: "a".into() });
+
+        let changes = pending(&worker);
+        let ids: Vec<&str> = changes.iter().map(|c| c.id()).collect();
+        assert_eq!(ids, ["b", "a"]);
+        assert!(matches!(changes[1], PendingChange::Delete { .. }));
+    }
+
+    #[test]
+    fn test_record_only_while_syncing() {
+        let worker = SyncWorker::default();
+        worker.record(create("a"));
+        assert!(pending(&worker).is_empty());
+
+        *worker.config.lock().unwrap() = Some(SyncConfig {
+            base_url: "http://localhost".into(),
+            token: "token".into(),
+            interval: DEFAULT_INTERVAL,
+        });
+        worker.record(create("a"));
+        assert_eq!(pending(&worker).len(), 1);
+    }
+
+    #[test]
+    fn test_catchup_only_when_revision_moves() {
+        let worker = SyncWorker::default();
+        assert!(!worker.behind(3));
+
+        let mut item = remote("a", 1).remove("a").unwrap();
+        item.updated_at = "2025-03-14T09:26:53.589Z".into();
+        worker.advance([&item]);
+        item.updated_at = "2025-03-14T09:00:00.000Z".into();
+        worker.advance([&item]);
+        assert_eq!(*worker.cursor.lock().unwrap(), Some(1741944413));
+
+        assert!(!worker.behind(3));
+        assert!(worker.behind(5));
+        assert!(!worker.behind(5));
+    }
+
+    #[test]
+    fn test_conflict_on_revision_mismatch() {
+        let server = remote("a", 2);
+        assert!(conflict(&update("a", 2, "x"), &server).is_none());
+        assert!(conflict(&update("b", 1, "x"), &server).is_none());
+        assert!(conflict(&create("a"), &server).is_none());
+
+        let found = conflict(&update("a", 1, "x"), &server).unwrap();
+        assert_eq!(found.local_revision, 1);
+        assert_eq!(found.server_revision, 2);
+        assert_eq!(found.server.title, "server");
+    }
+
+    #[test]
+    fn test_conflict_held_until_resolved() {
+        let worker = SyncWorker::default();
+        let found = conflict(&update("a", 1, "x"), &remote("a", 2)).unwrap();
+        assert!(worker.hold(&found));
+        assert!(!worker.hold(&found));
+
+        worker.enqueue(update("a", 1, "x"));
+        worker.resolve("b", true);
+        assert!(matches!(
+            pending(&worker)[0],
+            PendingChange::Update { revision: 1, .. }
+        ));
+
+        worker.resolve("a", true);
+        assert!(matches!(
+            pending(&worker)[0],
+            PendingChange::Update { revision: 2, .. }
+        ));
+        assert!(worker.conflicts.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_conflict_resolved_with_server() {
+        let worker = SyncWorker::default();
+        worker.enqueue(update("a", 1, "x"));
+        worker.enqueue(update("b", 1, "y"));
+        worker.hold(&conflict(&update("a", 1, "x"), &remote("a", 2)).unwrap());
+
+        worker.resolve("a", false);
+        let changes = pending(&worker);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].id(), "b");
+    }
+}
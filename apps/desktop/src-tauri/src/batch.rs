@@ -0,0 +1,558 @@
+use noro_common::pool::pool;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::task::JoinSet;
+
+use crate::autosync::SyncProgress;
+use crate::crypto;
+use crate::search;
+use crate::sync::{self, auth_header, ItemMeta, RemoteItem, SyncError};
+
+const CHUNK_SIZE: usize = 100;
+const MAX_WORKERS: usize = 4;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum BatchOp {
+    Create {
+        id: String,
+        item_type: String,
+        title: String,
+        data: String,
+        tags: Vec<String>,
+        favorite: bool,
+    },
+    Update {
+        id: String,
+        title: Option<String>,
+        data: Option<String>,
+        tags: Option<Vec<String>>,
+        favorite: Option<bool>,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+impl BatchOp {
+    fn id(&self) -> &str {
+        match self {
+            BatchOp::Create { id, .. } => id,
+            BatchOp::Update { id, .. } => id,
+            BatchOp::Delete { id } => id,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            BatchOp::Create { .. } => "create",
+            BatchOp::Update { .. } => "update",
+            BatchOp::Delete { .. } => "delete",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum WireOp {
+    Create {
+        id: String,
+        #[serde(rename = "typeIndex")]
+        type_index: String,
+        title: String,
+        data: String,
+        meta: String,
+        tags: Vec<String>,
+    },
+    Update {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        meta: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tags: Option<Vec<String>>,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+type Sealed = Result<(WireOp, Option<[u8; 32]>), String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemResult {
+    pub id: Option<String>,
+    pub op: String,
+    pub ok: bool,
+    pub error: Option<String>,
+    pub item: Option<RemoteItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchResult {
+    pub results: Vec<BatchItemResult>,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponse {
+    results: Vec<BatchItemResult>,
+}
+
+fn seal(op: BatchOp, current: &HashMap<String, RemoteItem>) -> Sealed {
+    let sealed = match op {
+        BatchOp::Create {
+            id,
+            item_type,
+            title,
+            data,
+            tags,
+            favorite,
+        } => {
+            let key = crypto::itemkey(&id).map_err(|e| e.to_string())?;
+            let op = WireOp::Create {
+                type_index: sync::typeindex(&item_type).map_err(|e| e.to_string())?,
+                title: crypto::sealfield(&key, &title).map_err(|e| e.to_string())?,
+                data: crypto::sealfield(&key, &data).map_err(|e| e.to_string())?,
+                tags: sync::tagindexes(&tags).map_err(|e| e.to_string())?,
+                meta: sync::sealmeta(
+                    &key,
+                    &ItemMeta {
+                        item_type,
+                        tags,
+                        favorite,
+                    },
+                )
+                .map_err(|e| e.to_string())?,
+                id,
+            };
+            (op, Some(key))
+        }
+        BatchOp::Update {
+            id,
+            title,
+            data,
+            tags,
+            favorite,
+        } => {
+            let key = crypto::itemkey(&id).map_err(|e| e.to_string())?;
+            let meta = if tags.is_some() || favorite.is_some() {
+                let mut existing = current.get(&id).cloned().ok_or("item not found")?;
+                sync::openitem(&mut existing, &key).map_err(|e| e.to_string())?;
+                let meta = ItemMeta {
+                    item_type: existing.item_type,
+                    tags: tags
+                        .clone()
+                        .unwrap_or_else(|| existing.tags.into_iter().map(|t| t.name).collect()),
+                    favorite: favorite.unwrap_or(existing.favorite),
+                };
+                Some(sync::sealmeta(&key, &meta).map_err(|e| e.to_string())?)
+            } else {
+                None
+            };
+            let title = title
+                .map(|t| crypto::sealfield(&key, &t))
+                .transpose()
+                .map_err(|e| e.to_string())?;
+            let data = data
+                .map(|d| crypto::sealfield(&key, &d))
+                .transpose()
+                .map_err(|e| e.to_string())?;
+            let tags = tags
+                .as_deref()
+                .map(sync::tagindexes)
+                .transpose()
+                .map_err(|e| e.to_string())?;
+            let op = WireOp::Update {
+                id,
+                title,
+                data,
+                meta,
+                tags,
+            };
+            (op, Some(key))
+        }
+        BatchOp::Delete { id } => (WireOp::Delete { id }, None),
+    };
+    Ok(sealed)
+}
+
+async fn currentitems(
+    base_url: &str,
+    token: &str,
+    ops: &[BatchOp],
+) -> Result<HashMap<String, RemoteItem>, SyncError> {
+    let mut ids: Vec<String> = ops
+        .iter()
+        .filter_map(|op| match op {
+            BatchOp::Update {
+                id, tags, favorite, ..
+            } if tags.is_some() || favorite.is_some() => Some(id.clone()),
+            _ => None,
+        })
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let mut pending = ids.into_iter();
+    let mut fetches = JoinSet::new();
+    let mut current = HashMap::new();
+    loop {
+        while fetches.len() < MAX_WORKERS {
+            let Some(id) = pending.next() else {
+                break;
+            };
+            let (base_url, token) = (base_url.to_string(), token.to_string());
+            fetches.spawn(async move {
+                let item = sync::fetchsealed(&base_url, &token, &id).await;
+                (id, item)
+            });
+        }
+        let Some(fetched) = fetches.join_next().await else {
+            break;
+        };
+        match fetched {
+            Ok((id, Ok(item))) => {
+                current.insert(id, item);
+            }
+            Ok((_, Err(SyncError::Auth(e)))) => return Err(SyncError::Auth(e)),
+            _ => {}
+        }
+    }
+    Ok(current)
+}
+
+async fn send(
+    base_url: &str,
+    token: &str,
+    ops: &[WireOp],
+) -> Result<Vec<BatchItemResult>, SyncError> {
+    #[derive(Serialize)]
+    struct Body<'a> {
+        ops: &'a [WireOp],
+    }
+
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/items/batch", base_url);
+    let res = client
+        .post(&url)
+        .header("cookie", auth_header(token))
+        .json(&Body { ops })
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    if res.status() == 401 {
+        return Err(SyncError::Auth("session expired".into()));
+    }
+    if !res.status().is_success() {
+        let status = res.status();
+        let body = res.text().await.unwrap_or_default();
+        return Err(SyncError::Http(format!("{}: {}", status, body)));
+    }
+
+    let data: BatchResponse = res
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+    Ok(data.results)
+}
+
+pub async fn run<R: Runtime>(
+    app: &AppHandle<R>,
+    base_url: &str,
+    token: &str,
+    ops: Vec<BatchOp>,
+) -> Result<BatchResult, SyncError> {
+    if crypto::islocked() {
+        return Err(SyncError::Crypto("vault locked".into()));
+    }
+
+    let meta: Vec<(String, &'static str)> =
+        ops.iter().map(|o| (o.id().to_string(), o.name())).collect();
+
+    let current = currentitems(base_url, token, &ops).await?;
+
+    let emitter = app.clone();
+    let sealed = tokio::task::spawn_blocking(move || {
+        pool(
+            ops,
+            MAX_WORKERS,
+            |op| seal(op, &current),
+            |done, total| {
+                let _ = emitter.emit(
+                    "sync_progress",
+                    SyncProgress {
+                        phase: "encrypt".into(),
+                        done,
+                        total,
+                    },
+                );
+            },
+        )
+    })
+    .await
+    .map_err(|e| SyncError::Crypto(e.to_string()))?;
+
+    upload(base_url, token, meta, sealed, |done, total| {
+        let _ = app.emit(
+            "sync_progress",
+            SyncProgress {
+                phase: "upload".into(),
+                done,
+                total,
+            },
+        );
+    })
+    .await
+}
+
+async fn upload<P: Fn(usize, usize)>(
+    base_url: &str,
+    token: &str,
+    meta: Vec<(String, &'static str)>,
+    sealed: Vec<Sealed>,
+    progress: P,
+) -> Result<BatchResult, SyncError> {
+    let mut results: Vec<Option<BatchItemResult>> = vec![None; meta.len()];
+    let mut keys: HashMap<String, [u8; 32]> = HashMap::new();
+    let mut outgoing: Vec<(usize, WireOp)> = Vec::new();
+    for (index, sealed) in sealed.into_iter().enumerate() {
+        let (id, op) = &meta[index];
+        match sealed {
+            Ok((wire, key)) => {
+                if let Some(key) = key {
+                    keys.insert(id.clone(), key);
+                }
+                outgoing.push((index, wire));
+            }
+            Err(e) => {
+                results[index] = Some(BatchItemResult {
+                    id: Some(id.clone()),
+                    op: op.to_string(),
+                    ok: false,
+                    error: Some(e),
+                    item: None,
+                });
+            }
+        }
+    }
+
+    let total = outgoing.len();
+    let mut sent = 0;
+    for chunk in outgoing.chunks(CHUNK_SIZE) {
+        let wire: Vec<WireOp> = chunk.iter().map(|(_, w)| w.clone()).collect();
+        match send(base_url, token, &wire).await {
+            Ok(remote) => {
+                for ((index, _), mut result) in chunk.iter().zip(remote) {
+                    if let Some(item) = result.item.as_mut() {
+                        let (id, _) = &meta[*index];
+                        if let Some(key) = keys.get(id) {
+                            if let Err(e) = sync::openitem(item, key) {
+                                result.ok = false;
+                                result.error = Some(e.to_string());
+                                result.item = None;
+                            }
+                        }
+                    }
+                    match (&result.item, result.op.as_str()) {
+                        (Some(item), _) if result.ok => search::upsert(item),
+                        (None, "delete") if result.ok => search::remove(&meta[*index].0),
+                        _ => {}
+                    }
+                    results[*index] = Some(result);
+                }
+            }
+            Err(SyncError::Auth(e)) => return Err(SyncError::Auth(e)),
+            Err(e) => {
+                for (index, _) in chunk {
+                    let (id, op) = &meta[*index];
+                    results[*index] = Some(BatchItemResult {
+                        id: Some(id.clone()),
+                        op: op.to_string(),
+                        ok: false,
+                        error: Some(e.to_string()),
+                        item: None,
+                    });
+                }
+            }
+        }
+        sent += chunk.len();
+        progress(sent, total);
+    }
+
+    let results: Vec<BatchItemResult> = results
+        .into_iter()
+        .zip(meta)
+        .map(|(r, (id, op))| {
+            r.unwrap_or(BatchItemResult {
+                id: Some(id),
+                op: op.to_string(),
+                ok: false,
+                error: Some("no result from server".into()),
+                item: None,
+            })
+        })
+        .collect();
+    let succeeded = results.iter().filter(|r| r.ok).count();
+    let failed = results.len() - succeeded;
+
+    Ok(BatchResult {
+        results,
+        succeeded,
+        failed,
+    })
+}
+
+#[tauri::command]
+pub async fn sync_batch<R: Runtime>(
+    app: AppHandle<R>,
+    base_url: String,
+    token: String,
+    ops: Vec<BatchOp>,
+) -> Result<BatchResult, SyncError> {
+    run(&app, &base_url, &token, ops).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    fn serve(status: u16, body: String) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut request = vec![0u8; length];
+            reader.read_exact(&mut request).unwrap();
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (format!("http://{}", address), server)
+    }
+
+    fn ops() -> (Vec<(String, &'static str)>, Vec<Sealed>) {
+        let meta = ["a", "b", "c", "d"]
+            .iter()
+            .map(|id| (id.to_string(), "delete"))
+            .collect();
+        let sealed = vec![
+            Ok((WireOp::Delete { id: "a".into() }, None)),
+            Ok((WireOp::Delete { id: "b".into() }, None)),
+            Err("item not found".into()),
+            Ok((WireOp::Delete { id: "d".into() }, None)),
+        ];
+        (meta, sealed)
+    }
+
+    #[test]
+    fn test_seal_requires_unlocked_vault() {
+        let current = HashMap::new();
+        let create = BatchOp::Create {
+            id: "item".into(),
+            item_type: "login".into(),
+            title: "github".into(),
+            data: "{}".into(),
+            tags: vec![],
+            favorite: false,
+        };
+        assert!(seal(create, &current).is_err());
+
+        let (wire, key) = seal(BatchOp::Delete { id: "item".into() }, &current).unwrap();
+        assert!(matches!(wire, WireOp::Delete { id } if id == "item"));
+        assert!(key.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upload_partial_failure() {
+        let response = serde_json::json!({
+            "results": [
+                { "id": "a", "op": "delete", "ok": true },
+                { "id": "b", "op": "delete", "ok": false, "error": "not found" },
+            ]
+        });
+        let (base_url, server) = serve(200, response.to_string());
+        let (meta, sealed) = ops();
+        let progress = Cell::new((0, 0));
+        let result = upload(&base_url, "token", meta, sealed, |done, total| {
+            progress.set((done, total))
+        })
+        .await
+        .unwrap();
+
+        let request: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+        let sent: Vec<&str> = request["ops"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|op| op["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(sent, ["a", "b", "d"]);
+        assert_eq!(progress.get(), (3, 3));
+
+        assert_eq!((result.succeeded, result.failed), (1, 3));
+        let errors: Vec<Option<&str>> = result.results.iter().map(|r| r.error.as_deref()).collect();
+        assert_eq!(
+            errors,
+            [
+                None,
+                Some("not found"),
+                Some("item not found"),
+                Some("no result from server"),
+            ]
+        );
+        let ids: Vec<&str> = result
+            .results
+            .iter()
+            .map(|r| r.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, ["a", "b", "c", "d"]);
+    }
+
+    #[tokio::test]
+    async fn test_upload_failed_chunk() {
+        let (base_url, server) = serve(500, "boom".into());
+        let (meta, sealed) = ops();
+        let result = upload(&base_url, "token", meta, sealed, |_, _| {})
+            .await
+            .unwrap();
+        server.join().unwrap();
+
+        assert_eq!((result.succeeded, result.failed), (0, 4));
+        assert_eq!(result.results[2].error.as_deref(), Some("item not found"));
+        for index in [0, 1, 3] {
+            let error = result.results[index].error.as_deref().unwrap();
+            assert!(error.starts_with("http error"), "{}", error);
+            assert!(error.contains("boom"));
+        }
+    }
+}
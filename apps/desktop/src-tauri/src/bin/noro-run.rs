@@ -0,0 +1,3 @@
+fn main() {
+    std::process::exit(app_lib::envrun::main(std::env::args().skip(1).collect()));
+}
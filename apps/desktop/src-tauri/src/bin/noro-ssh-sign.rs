@@ -0,0 +1,3 @@
+fn main() {
+    std::process::exit(app_lib::sshsig::main(std::env::args().skip(1).collect()));
+}
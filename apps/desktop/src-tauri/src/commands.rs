@@ -1,4 +1,12 @@
-use crate::storage::{self, VaultData, VaultEntry};
+use noro_common::time::now;
+use tauri::State;
+
+use crate::autosync::{PendingChange, SyncWorker};
+use crate::folders;
+use crate::import::{ImportedItem, ItemBuilder};
+use crate::storage::{self, VaultData, VaultEntry, VaultFolder};
+
+const TRASH_RETENTION_DAYS: u64 = 30;
 
 #[tauri::command]
 pub fn store_session(token: String) -> Result<(), String> {
@@ -22,7 +30,11 @@ pub fn store_master_key(password: String) -> Result<(), String> {
 
 #[tauri::command]
 pub fn verify_password(password: String) -> Result<bool, String> {
-    storage::verify_password(&password).map_err(|e| e.to_string())
+    let valid = storage::verify_password(&password).map_err(|e| e.to_string())?;
+    if valid {
+        purgetrash()?;
+    }
+    Ok(valid)
 }
 
 #[tauri::command]
@@ -42,7 +54,9 @@ pub fn store_vault(data: VaultData) -> Result<(), String> {
 
 #[tauri::command]
 pub fn get_vault() -> Result<VaultData, String> {
-    storage::get_vault().map_err(|e| e.to_string())
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    vault.entries.retain(|e| e.deleted_at.is_none());
+    Ok(vault)
 }
 
 #[tauri::command]
@@ -51,44 +65,308 @@ pub fn delete_vault() -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn add_vault_entry(entry: VaultEntry) -> Result<(), String> {
+pub fn add_vault_entry(worker: State<'_, SyncWorker>, entry: VaultEntry) -> Result<(), String> {
     let mut vault = storage::get_vault().unwrap_or(VaultData {
         entries: vec![],
+        folders: vec![],
         updated: now(),
     });
+    let change = created(&entry);
     vault.entries.push(entry);
     vault.updated = now();
-    storage::store_vault(&vault).map_err(|e| e.to_string())
+    storage::store_vault(&vault).map_err(|e| e.to_string())?;
+    worker.record(change);
+    Ok(())
 }
 
 #[tauri::command]
-pub fn update_vault_entry(entry: VaultEntry) -> Result<(), String> {
+pub fn update_vault_entry(worker: State<'_, SyncWorker>, entry: VaultEntry) -> Result<(), String> {
     let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
     if let Some(existing) = vault.entries.iter_mut().find(|e| e.id == entry.id) {
+        let change = updated(&entry, worker.revision(&entry.id));
         *existing = entry;
         vault.updated = now();
-        storage::store_vault(&vault).map_err(|e| e.to_string())
+        storage::store_vault(&vault).map_err(|e| e.to_string())?;
+        worker.record(change);
+        Ok(())
     } else {
         Err("entry not found".into())
     }
 }
 
 #[tauri::command]
-pub fn delete_vault_entry(id: String) -> Result<(), String> {
+pub fn delete_vault_entry(worker: State<'_, SyncWorker>, id: String) -> Result<(), String> {
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    if let Some(entry) = vault
+        .entries
+        .iter_mut()
+        .find(|e| e.id == id && e.deleted_at.is_none())
+    {
+        entry.deleted_at = Some(now());
+        entry.updated = now();
+        vault.updated = now();
+        storage::store_vault(&vault).map_err(|e| e.to_string())?;
+        worker.record(PendingChange::Delete { id });
+        Ok(())
+    } else {
+        Err("entry not found".into())
+    }
+}
+
+#[tauri::command]
+pub fn list_vault_trash() -> Result<Vec<VaultEntry>, String> {
+    let vault = storage::get_vault().map_err(|e| e.to_string())?;
+    let mut trash: Vec<VaultEntry> = vault
+        .entries
+        .into_iter()
+        .filter(|e| e.deleted_at.is_some())
+        .collect();
+    trash.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+    Ok(trash)
+}
+
+#[tauri::command]
+pub fn restore_vault_entry(id: String) -> Result<(), String> {
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    if let Some(entry) = vault
+        .entries
+        .iter_mut()
+        .find(|e| e.id == id && e.deleted_at.is_some())
+    {
+        entry.deleted_at = None;
+        entry.updated = now();
+        vault.updated = now();
+        storage::store_vault(&vault).map_err(|e| e.to_string())
+    } else {
+        Err("entry not in trash".into())
+    }
+}
+
+#[tauri::command]
+pub fn purge_vault_entry(id: String) -> Result<(), String> {
     let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
-    vault.entries.retain(|e| e.id != id);
+    let before = vault.entries.len();
+    vault
+        .entries
+        .retain(|e| e.id != id || e.deleted_at.is_none());
+    if vault.entries.len() == before {
+        return Err("entry not in trash".into());
+    }
     vault.updated = now();
     storage::store_vault(&vault).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn empty_vault_trash() -> Result<Vec<String>, String> {
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    let purged = purge(&mut vault, |_| true);
+    if !purged.is_empty() {
+        storage::store_vault(&vault).map_err(|e| e.to_string())?;
+    }
+    Ok(purged)
+}
+
+#[tauri::command]
+pub fn add_vault_folder(folder: VaultFolder) -> Result<(), String> {
+    let mut vault = storage::get_vault().unwrap_or(VaultData {
+        entries: vec![],
+        folders: vec![],
+        updated: now(),
+    });
+    if let Some(parent) = &folder.parent_id {
+        if !vault.folders.iter().any(|f| &f.id == parent) {
+            return Err("parent folder not found".into());
+        }
+    }
+    vault.folders.push(folder);
+    vault.updated = now();
+    storage::store_vault(&vault).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_vault_folder(folder: VaultFolder) -> Result<(), String> {
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    if folders::createscycle(&vault.folders, &folder.id, folder.parent_id.as_deref()) {
+        return Err("circular reference detected".into());
+    }
+    if let Some(existing) = vault.folders.iter_mut().find(|f| f.id == folder.id) {
+        *existing = folder;
+        vault.updated = now();
+        storage::store_vault(&vault).map_err(|e| e.to_string())
+    } else {
+        Err("folder not found".into())
+    }
+}
+
+#[tauri::command]
+pub fn delete_vault_folder(id: String) -> Result<Vec<String>, String> {
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    let parent = match vault.folders.iter().find(|f| f.id == id) {
+        Some(folder) => folder.parent_id.clone(),
+        None => return Err("folder not found".into()),
+    };
+    let mut deleted: Vec<String> = folders::descendants(&vault.folders, &id)
+        .into_iter()
+        .map(|f| f.id.clone())
+        .collect();
+    deleted.insert(0, id);
+
+    vault.folders.retain(|f| !deleted.contains(&f.id));
+    for entry in &mut vault.entries {
+        if entry
+            .folder_id
+            .as_ref()
+            .is_some_and(|f| deleted.contains(f))
+        {
+            entry.folder_id = parent.clone();
+        }
+    }
+    vault.updated = now();
+    storage::store_vault(&vault).map_err(|e| e.to_string())?;
+    Ok(deleted)
+}
+
+#[tauri::command]
+pub fn move_vault_entry(id: String, folder_id: Option<String>) -> Result<(), String> {
+    let mut vault = storage::get_vault().map_err(|e| e.to_string())?;
+    if let Some(folder) = &folder_id {
+        if !vault.folders.iter().any(|f| &f.id == folder) {
+            return Err("folder not found".into());
+        }
+    }
+    if let Some(entry) = vault.entries.iter_mut().find(|e| e.id == id) {
+        entry.folder_id = folder_id;
+        vault.updated = now();
+        storage::store_vault(&vault).map_err(|e| e.to_string())
+    } else {
+        Err("entry not found".into())
+    }
+}
+
 #[tauri::command]
 pub fn clear_all() -> Result<(), String> {
     storage::clear_all().map_err(|e| e.to_string())
 }
 
-fn now() -> u64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
+fn purge<F: Fn(u64) -> bool>(vault: &mut VaultData, expired: F) -> Vec<String> {
+    let purged: Vec<String> = vault
+        .entries
+        .iter()
+        .filter(|e| e.deleted_at.is_some_and(&expired))
+        .map(|e| e.id.clone())
+        .collect();
+    if !purged.is_empty() {
+        vault.entries.retain(|e| !purged.contains(&e.id));
+        vault.updated = now();
+    }
+    purged
+}
+
+fn purgeexpired(vault: &mut VaultData) -> Vec<String> {
+    let cutoff = now().saturating_sub(TRASH_RETENTION_DAYS * 24 * 60 * 60);
+    purge(vault, |deleted| deleted < cutoff)
+}
+
+fn purgetrash() -> Result<(), String> {
+    if let Ok(mut vault) = storage::get_vault() {
+        if !purgeexpired(&mut vault).is_empty() {
+            storage::store_vault(&vault).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn toitem(entry: &VaultEntry) -> ImportedItem {
+    let login = entry.username.is_some() || entry.password.is_some() || entry.url.is_some();
+    ItemBuilder::new(if login { "login" } else { "note" }, &entry.title)
+        .field("username", entry.username.as_deref())
+        .field("password", entry.password.as_deref())
+        .field("url", entry.url.as_deref())
+        .field("notes", entry.notes.as_deref())
+        .build()
+}
+
+fn created(entry: &VaultEntry) -> PendingChange {
+    let item = toitem(entry);
+    PendingChange::Create {
+        id: entry.id.clone(),
+        item_type: item.item_type,
+        title: item.title,
+        data: item.data,
+        tags: item.tags,
+        favorite: item.favorite,
+    }
+}
+
+fn updated(entry: &VaultEntry, revision: i32) -> PendingChange {
+    let item = toitem(entry);
+    PendingChange::Update {
+        id: entry.id.clone(),
+        revision,
+        title: Some(item.title),
+        data: Some(item.data),
+        tags: None,
+        favorite: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn entry(username: Option<&str>, notes: Option<&str>) -> VaultEntry {
+        VaultEntry {
+            id: "a".into(),
+            title: "Example".into(),
+            username: username.map(String::from),
+            password: None,
+            url: None,
+            notes: notes.map(String::from),
+            folder_id: None,
+            deleted_at: None,
+            created: 0,
+            updated: 0,
+        }
+    }
+
+    #[test]
+    fn test_entry_as_item() {
+        let login = toitem(&entry(Some("alice"), Some("hi")));
+        assert_eq!(login.item_type, "login");
+        assert_eq!(login.title, "Example");
+        let data: Value = serde_json::from_str(&login.data).unwrap();
+        assert_eq!(data["username"], "alice");
+        assert_eq!(data["notes"], "hi");
+        assert!(data.get("password").is_none());
+
+        let note = toitem(&entry(None, Some("hi")));
+        assert_eq!(note.item_type, "note");
+        let data: Value = serde_json::from_str(&note.data).unwrap();
+        assert_eq!(data["content"], "hi");
+    }
+
+    #[test]
+    fn test_purgeexpired_keeps_recent_trash() {
+        let day = 24 * 60 * 60;
+        let trashed = |id: &str, deleted_at: Option<u64>| VaultEntry {
+            id: id.into(),
+            deleted_at,
+            ..entry(None, None)
+        };
+        let mut vault = VaultData {
+            entries: vec![
+                trashed("live", None),
+                trashed("recent", Some(now() - day)),
+                trashed("expired", Some(now() - (TRASH_RETENTION_DAYS + 1) * day)),
+            ],
+            folders: vec![],
+            updated: 0,
+        };
+        assert_eq!(purgeexpired(&mut vault), ["expired"]);
+        let ids: Vec<&str> = vault.entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["live", "recent"]);
+        assert!(vault.updated > 0);
+        assert!(purgeexpired(&mut vault).is_empty());
+    }
 }
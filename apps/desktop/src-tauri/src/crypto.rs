@@ -2,8 +2,11 @@ use base64::{engine::general_purpose::STANDARD, Engine};
 use keyring::Entry;
 use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
+use tauri::{AppHandle, Runtime};
 use thiserror::Error;
 
+use crate::agent;
+use crate::search;
 use crate::twoskd;
 
 const SERVICE: &str = "sh.noro.app";
@@ -92,7 +95,11 @@ pub fn crypto_setup(password: String) -> Result<SetupResult, CryptoError> {
 }
 
 #[tauri::command]
-pub fn crypto_unlock(password: String, secretkey: String) -> Result<bool, CryptoError> {
+pub fn crypto_unlock<R: Runtime>(
+    app: AppHandle<R>,
+    password: String,
+    secretkey: String,
+) -> Result<bool, CryptoError> {
     let wrapped_b64 = entry(VAULT_KEY_ENTRY)?
         .get_password()
         .map_err(|_| CryptoError::NotSetup)?;
@@ -114,12 +121,15 @@ pub fn crypto_unlock(password: String, secretkey: String) -> Result<bool, Crypto
         twoskd::unwrapvaultkey(&wrapped, &auk).map_err(|_| CryptoError::InvalidPassword)?;
 
     *VAULT_KEY.write().unwrap() = Some(vaultkey);
+    agent::reload(&app);
     Ok(true)
 }
 
 #[tauri::command]
 pub fn crypto_lock() {
     *VAULT_KEY.write().unwrap() = None;
+    search::clear();
+    agent::clear();
 }
 
 #[tauri::command]
@@ -130,24 +140,31 @@ pub fn crypto_get_secret_key() -> Result<Option<String>, CryptoError> {
     }
 }
 
-pub fn encryptfield(itemid: &str, plaintext: &str) -> Result<String, CryptoError> {
+pub fn itemkey(itemid: &str) -> Result<[u8; 32], CryptoError> {
     let vaultkey = VAULT_KEY.read().unwrap();
     let vaultkey = vaultkey.as_ref().ok_or(CryptoError::Locked)?;
-    let itemkey = twoskd::deriveitemkey(vaultkey, itemid).map_err(|_| CryptoError::Encryption)?;
-    let encrypted =
-        twoskd::encryptitem(plaintext.as_bytes(), &itemkey).map_err(|_| CryptoError::Encryption)?;
-    Ok(STANDARD.encode(&encrypted))
+    twoskd::deriveitemkey(vaultkey, itemid).map_err(|_| CryptoError::Encryption)
 }
 
-pub fn decryptfield(itemid: &str, ciphertext: &str) -> Result<String, CryptoError> {
+pub fn blindindex(kind: &str, value: &str) -> Result<String, CryptoError> {
     let vaultkey = VAULT_KEY.read().unwrap();
     let vaultkey = vaultkey.as_ref().ok_or(CryptoError::Locked)?;
-    let itemkey = twoskd::deriveitemkey(vaultkey, itemid).map_err(|_| CryptoError::Encryption)?;
+    let indexkey = twoskd::deriveindexkey(vaultkey);
+    Ok(twoskd::blindindex(&indexkey, kind, value))
+}
+
+pub fn sealfield(itemkey: &[u8; 32], plaintext: &str) -> Result<String, CryptoError> {
+    let encrypted =
+        twoskd::encryptitem(plaintext.as_bytes(), itemkey).map_err(|_| CryptoError::Encryption)?;
+    Ok(STANDARD.encode(&encrypted))
+}
+
+pub fn openfield(itemkey: &[u8; 32], ciphertext: &str) -> Result<String, CryptoError> {
     let encrypted = STANDARD
         .decode(ciphertext)
         .map_err(|_| CryptoError::Encryption)?;
     let decrypted =
-        twoskd::decryptitem(&encrypted, &itemkey).map_err(|_| CryptoError::Encryption)?;
+        twoskd::decryptitem(&encrypted, itemkey).map_err(|_| CryptoError::Encryption)?;
     String::from_utf8(decrypted).map_err(|_| CryptoError::Encryption)
 }
 
@@ -0,0 +1,993 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{async_runtime::JoinHandle, AppHandle, Emitter, Manager, Runtime, State};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::oneshot;
+
+use crate::crypto;
+use crate::storage;
+use crate::sync::{self, RemoteItem, SyncError};
+
+const SOCKET_FILE: &str = "run.sock";
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(60);
+const MAX_MESSAGE: usize = 1024 * 1024;
+const SCHEME: &str = "noro://";
+const MASK: &str = "<concealed by noro>";
+const FLUSH_AFTER: Duration = Duration::from_millis(200);
+const FAILED: i32 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EnvRunError {
+    #[error("{0}")]
+    Usage(String),
+    #[error("vault locked")]
+    Locked,
+    #[error("runner is not started, start it from the desktop app")]
+    Stopped,
+    #[cfg(not(unix))]
+    #[error("env runner is not supported on this platform")]
+    Unsupported,
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("invalid reference {0}")]
+    Reference(String),
+    #[error("{0}")]
+    Resolve(String),
+    #[error("request denied")]
+    Denied,
+    #[error(transparent)]
+    Sync(#[from] SyncError),
+}
+
+impl Serialize for EnvRunError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+fn ioerr(e: impl std::fmt::Display) -> EnvRunError {
+    EnvRunError::Io(e.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolveRequest {
+    pub command: String,
+    pub references: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResolveResponse {
+    #[serde(default)]
+    pub values: HashMap<String, String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvRunStatus {
+    pub running: bool,
+    pub socket: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvRunApproval {
+    pub request: String,
+    pub command: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Clone)]
+struct EnvRunConfig {
+    base_url: String,
+    token: String,
+}
+
+#[derive(Default)]
+pub struct EnvRunState {
+    socket: Mutex<Option<PathBuf>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+    config: Mutex<Option<EnvRunConfig>>,
+    pending: Mutex<HashMap<String, oneshot::Sender<bool>>>,
+}
+
+impl EnvRunState {
+    fn status(&self) -> EnvRunStatus {
+        let socket = self.socket.lock().unwrap().clone();
+        EnvRunStatus {
+            running: socket.is_some(),
+            socket: socket.map(|p| p.display().to_string()),
+        }
+    }
+
+    fn stop(&self) {
+        if let Some(task) = self.task.lock().unwrap().take() {
+            task.abort();
+        }
+        if let Some(path) = self.socket.lock().unwrap().take() {
+            let _ = std::fs::remove_file(path);
+        }
+        *self.config.lock().unwrap() = None;
+        for (_, tx) in self.pending.lock().unwrap().drain() {
+            let _ = tx.send(false);
+        }
+    }
+}
+
+fn decode(text: &str) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+pub fn parsereference(reference: &str) -> Result<(String, String), EnvRunError> {
+    let invalid = || EnvRunError::Reference(reference.to_string());
+    let path = reference.strip_prefix(SCHEME).ok_or_else(invalid)?;
+    let (item, field) = path.split_once('/').ok_or_else(invalid)?;
+    let item = decode(item).ok_or_else(invalid)?;
+    let field = decode(field).ok_or_else(invalid)?;
+    if item.is_empty() || field.is_empty() {
+        return Err(invalid());
+    }
+    Ok((item, field))
+}
+
+fn finditem<'a>(items: &'a [RemoteItem], name: &str) -> Result<&'a RemoteItem, EnvRunError> {
+    if let Some(item) = items.iter().find(|i| i.id == name) {
+        return Ok(item);
+    }
+    let matches: Vec<_> = items
+        .iter()
+        .filter(|i| i.title.eq_ignore_ascii_case(name))
+        .collect();
+    match matches.as_slice() {
+        [item] => Ok(item),
+        [] => Err(EnvRunError::Resolve(format!("no item named \"{}\"", name))),
+        _ => Err(EnvRunError::Resolve(format!(
+            "{} items are named \"{}\", use the item id",
+            matches.len(),
+            name
+        ))),
+    }
+}
+
+pub fn resolve<'a>(
+    items: &'a [RemoteItem],
+    reference: &str,
+) -> Result<(&'a RemoteItem, String), EnvRunError> {
+    let (name, field) = parsereference(reference)?;
+    let item = finditem(items, &name)?;
+    let data: Value = serde_json::from_str(&item.data).unwrap_or(Value::Null);
+    let fields = data.as_object();
+    let value = fields
+        .and_then(|f| f.get(&field))
+        .or_else(|| {
+            fields.and_then(|f| {
+                f.iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(&field))
+                    .map(|(_, v)| v)
+            })
+        })
+        .ok_or_else(|| {
+            EnvRunError::Resolve(format!("\"{}\" has no field \"{}\"", item.title, field))
+        })?;
+    let value = match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        _ => {
+            return Err(EnvRunError::Resolve(format!(
+                "field \"{}\" of \"{}\" is not a text value",
+                field, item.title
+            )))
+        }
+    };
+    Ok((item, value))
+}
+
+async fn approval<R: Runtime>(app: &AppHandle<R>, command: String, items: Vec<String>) -> bool {
+    let request = format!("{:016x}", rand::random::<u64>());
+    let (tx, rx) = oneshot::channel();
+    app.state::<EnvRunState>()
+        .pending
+        .lock()
+        .unwrap()
+        .insert(request.clone(), tx);
+    let _ = app.emit(
+        "envrun_approval",
+        EnvRunApproval {
+            request: request.clone(),
+            command,
+            items,
+        },
+    );
+    let approved = matches!(
+        tokio::time::timeout(APPROVAL_TIMEOUT, rx).await,
+        Ok(Ok(true))
+    );
+    app.state::<EnvRunState>()
+        .pending
+        .lock()
+        .unwrap()
+        .remove(&request);
+    approved && !crypto::islocked()
+}
+
+async fn handle<R: Runtime>(
+    app: &AppHandle<R>,
+    request: ResolveRequest,
+) -> Result<HashMap<String, String>, EnvRunError> {
+    if crypto::islocked() {
+        return Err(EnvRunError::Locked);
+    }
+    let config = app
+        .state::<EnvRunState>()
+        .config
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or(EnvRunError::Stopped)?;
+    let items = sync::fetchitems(&config.base_url, &config.token).await?;
+
+    let mut values = HashMap::new();
+    let mut titles: Vec<String> = Vec::new();
+    for reference in &request.references {
+        let (item, value) = resolve(&items, reference)?;
+        if !titles.contains(&item.title) {
+            titles.push(item.title.clone());
+        }
+        values.insert(reference.clone(), value);
+    }
+    if values.is_empty() {
+        return Ok(values);
+    }
+    if !approval(app, request.command, titles).await {
+        return Err(EnvRunError::Denied);
+    }
+    Ok(values)
+}
+
+async fn serve<R: Runtime, S: AsyncRead + AsyncWrite + Unpin>(app: AppHandle<R>, mut stream: S) {
+    let mut len = [0u8; 4];
+    if stream.read_exact(&mut len).await.is_err() {
+        return;
+    }
+    let len = u32::from_be_bytes(len) as usize;
+    if len == 0 || len > MAX_MESSAGE {
+        return;
+    }
+    let mut body = vec![0u8; len];
+    if stream.read_exact(&mut body).await.is_err() {
+        return;
+    }
+    let response = match serde_json::from_slice::<ResolveRequest>(&body) {
+        Ok(request) => match handle(&app, request).await {
+            Ok(values) => ResolveResponse {
+                values,
+                error: None,
+            },
+            Err(e) => ResolveResponse {
+                values: HashMap::new(),
+                error: Some(e.to_string()),
+            },
+        },
+        Err(e) => ResolveResponse {
+            values: HashMap::new(),
+            error: Some(format!("invalid request: {}", e)),
+        },
+    };
+    let Ok(body) = serde_json::to_vec(&response) else {
+        return;
+    };
+    let _ = stream.write_all(&frame(&body)).await;
+}
+
+fn frame(body: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(body.len() + 4);
+    out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    out.extend_from_slice(body);
+    out
+}
+
+pub fn socketpath() -> Result<PathBuf, EnvRunError> {
+    Ok(storage::get_socket_dir().map_err(ioerr)?.join(SOCKET_FILE))
+}
+
+#[cfg(unix)]
+fn listen<R: Runtime>(app: AppHandle<R>) -> Result<(PathBuf, JoinHandle<()>), EnvRunError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = socketpath()?;
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path).map_err(ioerr)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).map_err(ioerr)?;
+    let task = tauri::async_runtime::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tauri::async_runtime::spawn(serve(app.clone(), stream));
+        }
+    });
+    Ok((path, task))
+}
+
+#[cfg(not(unix))]
+fn listen<R: Runtime>(_app: AppHandle<R>) -> Result<(PathBuf, JoinHandle<()>), EnvRunError> {
+    Err(EnvRunError::Unsupported)
+}
+
+#[cfg(unix)]
+pub fn request(socket: &Path, request: &ResolveRequest) -> Result<ResolveResponse, EnvRunError> {
+    let mut stream =
+        std::os::unix::net::UnixStream::connect(socket).map_err(|_| EnvRunError::Stopped)?;
+    let body = serde_json::to_vec(request).map_err(ioerr)?;
+    stream.write_all(&frame(&body)).map_err(ioerr)?;
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len).map_err(ioerr)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_MESSAGE {
+        return Err(EnvRunError::Io("response too large".into()));
+    }
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body).map_err(ioerr)?;
+    serde_json::from_slice(&body).map_err(ioerr)
+}
+
+#[cfg(not(unix))]
+pub fn request(_socket: &Path, _request: &ResolveRequest) -> Result<ResolveResponse, EnvRunError> {
+    Err(EnvRunError::Unsupported)
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+pub fn parseenv(text: &str) -> Vec<(String, String)> {
+    let mut vars = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            continue;
+        }
+        let value = value.trim();
+        let value = if let Some(inner) = value.strip_prefix('"') {
+            unescape(inner.rsplit_once('"').map_or(inner, |(v, _)| v))
+        } else if let Some(inner) = value.strip_prefix('\'') {
+            inner
+                .rsplit_once('\'')
+                .map_or(inner, |(v, _)| v)
+                .to_string()
+        } else {
+            value
+                .split_once(" #")
+                .map_or(value, |(v, _)| v)
+                .trim_end()
+                .to_string()
+        };
+        vars.push((key.to_string(), value));
+    }
+    vars
+}
+
+fn segment(text: &str) -> usize {
+    text.find(|c: char| !(c.is_ascii_alphanumeric() || "-._~%".contains(c)))
+        .unwrap_or(text.len())
+}
+
+pub fn references(value: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find(SCHEME) {
+        let tail = &rest[start..];
+        let mut end = SCHEME.len() + segment(&tail[SCHEME.len()..]);
+        if tail[end..].starts_with('/') {
+            end += 1 + segment(&tail[end + 1..]);
+        }
+        found.push(tail[..end].to_string());
+        rest = &tail[end..];
+    }
+    found
+}
+
+pub fn substitute(value: &str, values: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    for reference in references(value) {
+        let Some(start) = rest.find(&reference) else {
+            continue;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(values.get(&reference).map_or(&reference, |v| v));
+        rest = &rest[start + reference.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[derive(Clone)]
+pub struct Masker {
+    secrets: Vec<Vec<u8>>,
+    pending: Vec<u8>,
+}
+
+impl Masker {
+    pub fn new<'a>(secrets: impl IntoIterator<Item = &'a String>) -> Self {
+        let mut secrets: Vec<Vec<u8>> = secrets
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.as_bytes().to_vec())
+            .collect();
+        secrets.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        secrets.dedup();
+        Masker {
+            secrets,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Vec<u8> {
+        self.pending.extend_from_slice(data);
+        self.scan(false)
+    }
+
+    pub fn finish(&mut self) -> Vec<u8> {
+        self.scan(true)
+    }
+
+    fn scan(&mut self, last: bool) -> Vec<u8> {
+        let buf = std::mem::take(&mut self.pending);
+        let mut out = Vec::with_capacity(buf.len());
+        let mut i = 0;
+        while i < buf.len() {
+            let rest = &buf[i..];
+            if !last
+                && self
+                    .secrets
+                    .iter()
+                    .any(|s| s.len() > rest.len() && s.starts_with(rest))
+            {
+                self.pending = rest.to_vec();
+                break;
+            }
+            if let Some(secret) = self.secrets.iter().find(|s| rest.starts_with(s)) {
+                out.extend_from_slice(MASK.as_bytes());
+                i += secret.len();
+            } else {
+                out.push(buf[i]);
+                i += 1;
+            }
+        }
+        out
+    }
+}
+
+fn pipe(mut input: impl Read + Send + 'static, mut output: impl Write, mut masker: Masker) {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 8192];
+        loop {
+            let n = match input.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            if tx.send(buf[..n].to_vec()).is_err() {
+                break;
+            }
+        }
+    });
+    loop {
+        let data = match rx.recv_timeout(FLUSH_AFTER) {
+            Ok(chunk) => masker.push(&chunk),
+            Err(RecvTimeoutError::Timeout) => masker.finish(),
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+        if data.is_empty() {
+            continue;
+        }
+        if output.write_all(&data).is_err() {
+            return;
+        }
+        let _ = output.flush();
+    }
+    let _ = output.write_all(&masker.finish());
+    let _ = output.flush();
+}
+
+#[derive(Default)]
+struct Args {
+    files: Vec<String>,
+    nomask: bool,
+    command: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, EnvRunError> {
+        let mut parsed = Args::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "-e" | "--env-file" => parsed.files.push(
+                    iter.next()
+                        .cloned()
+                        .ok_or_else(|| EnvRunError::Usage(format!("{} needs a value", arg)))?,
+                ),
+                "--no-masking" => parsed.nomask = true,
+                "--" => {
+                    parsed.command = iter.cloned().collect();
+                    break;
+                }
+                flag if flag.starts_with('-') => {
+                    return Err(EnvRunError::Usage(format!("unknown option {}", flag)))
+                }
+                _ => {
+                    parsed.command = std::iter::once(arg).chain(iter).cloned().collect();
+                    break;
+                }
+            }
+        }
+        if parsed.command.is_empty() {
+            return Err(EnvRunError::Usage(
+                "usage: noro-run [-e .env]... [--no-masking] -- command [args...]".into(),
+            ));
+        }
+        Ok(parsed)
+    }
+}
+
+type Vars = Vec<(String, String)>;
+
+fn environment(
+    args: &Args,
+    base: impl IntoIterator<Item = (String, String)>,
+    socket: &Path,
+) -> Result<(Vars, Vec<String>), EnvRunError> {
+    let mut vars: Vars = base.into_iter().collect();
+    for file in &args.files {
+        let text = std::fs::read_to_string(file).map_err(ioerr)?;
+        for (key, value) in parseenv(&text) {
+            vars.retain(|(k, _)| k != &key);
+            vars.push((key, value));
+        }
+    }
+
+    let mut wanted: Vec<String> = Vec::new();
+    for (_, value) in &vars {
+        for reference in references(value) {
+            parsereference(&reference)?;
+            if !wanted.contains(&reference) {
+                wanted.push(reference);
+            }
+        }
+    }
+    if wanted.is_empty() {
+        return Ok((vars, Vec::new()));
+    }
+
+    let response = request(
+        socket,
+        &ResolveRequest {
+            command: args.command.join(" "),
+            references: wanted,
+        },
+    )?;
+    if let Some(error) = response.error {
+        return Err(EnvRunError::Resolve(error));
+    }
+    let vars = vars
+        .into_iter()
+        .map(|(k, v)| {
+            let v = substitute(&v, &response.values);
+            (k, v)
+        })
+        .collect();
+    Ok((vars, response.values.into_values().collect()))
+}
+
+fn exitcode(status: std::process::ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    status.code().unwrap_or(FAILED)
+}
+
+fn run(args: &Args, socket: &Path) -> Result<i32, EnvRunError> {
+    let (vars, secrets) = environment(args, std::env::vars(), socket)?;
+    let mask = !args.nomask && !secrets.is_empty();
+    let output = || match mask {
+        true => Stdio::piped(),
+        false => Stdio::inherit(),
+    };
+    let mut child = Command::new(&args.command[0])
+        .args(&args.command[1..])
+        .env_clear()
+        .envs(vars)
+        .stdin(Stdio::inherit())
+        .stdout(output())
+        .stderr(output())
+        .spawn()
+        .map_err(|e| EnvRunError::Io(format!("{}: {}", args.command[0], e)))?;
+
+    let masker = Masker::new(&secrets);
+    let mut threads = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        let masker = masker.clone();
+        threads.push(std::thread::spawn(move || {
+            pipe(stdout, std::io::stdout(), masker)
+        }));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        threads.push(std::thread::spawn(move || {
+            pipe(stderr, std::io::stderr(), masker)
+        }));
+    }
+    let status = child.wait().map_err(ioerr)?;
+    for thread in threads {
+        let _ = thread.join();
+    }
+    Ok(exitcode(status))
+}
+
+pub fn main(args: Vec<String>) -> i32 {
+    let result = Args::parse(&args).and_then(|args| run(&args, &socketpath()?));
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("noro-run: {}", e);
+            FAILED
+        }
+    }
+}
+
+#[tauri::command]
+pub fn envrun_start<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, EnvRunState>,
+    base_url: String,
+    token: String,
+) -> Result<EnvRunStatus, EnvRunError> {
+    if crypto::islocked() {
+        return Err(EnvRunError::Locked);
+    }
+    *state.config.lock().unwrap() = Some(EnvRunConfig { base_url, token });
+    if state.socket.lock().unwrap().is_none() {
+        let (path, task) = listen(app)?;
+        *state.socket.lock().unwrap() = Some(path);
+        *state.task.lock().unwrap() = Some(task);
+    }
+    Ok(state.status())
+}
+
+#[tauri::command]
+pub fn envrun_stop(state: State<'_, EnvRunState>) {
+    state.stop();
+}
+
+#[tauri::command]
+pub fn envrun_status(state: State<'_, EnvRunState>) -> EnvRunStatus {
+    state.status()
+}
+
+#[tauri::command]
+pub fn envrun_approve(state: State<'_, EnvRunState>, request: String, approved: bool) {
+    if let Some(tx) = state.pending.lock().unwrap().remove(&request) {
+        let _ = tx.send(approved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn item(id: &str, title: &str, data: &str) -> RemoteItem {
+        serde_json::from_value(json!({
+            "id": id,
+            "type": "login",
+            "title": title,
+            "data": data,
+            "revision": 1,
+            "favorite": false,
+            "deleted": false,
+            "tags": [],
+            "createdAt": "2026-01-01T00:00:00Z",
+            "updatedAt": "2026-01-01T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_references() {
+        let items = vec![
+            item(
+                "a1",
+                "GitHub Token",
+                r#"{"password":"ghp_secret","port":5432}"#,
+            ),
+            item("b2", "db", r#"{"password":"one"}"#),
+            item("c3", "db", r#"{"password":"two"}"#),
+        ];
+        let value = |r: &str| resolve(&items, r).map(|(_, v)| v);
+        assert_eq!(
+            value("noro://github%20token/password").unwrap(),
+            "ghp_secret"
+        );
+        assert_eq!(value("noro://a1/Port").unwrap(), "5432");
+        assert_eq!(value("noro://c3/password").unwrap(), "two");
+        assert!(value("noro://db/password").is_err());
+        assert!(value("noro://missing/password").is_err());
+        assert!(value("noro://a1/username").is_err());
+        assert!(parsereference("noro://nofield").is_err());
+        assert!(parsereference("op://vault/item/field").is_err());
+
+        let env = parseenv(
+            "# comment\nexport TOKEN=noro://a1/password\nURL=\"postgres://u:noro://b2/password@h\\n\" \nPLAIN=value # note\nQUOTED='a # b'\n",
+        );
+        assert_eq!(
+            env,
+            vec![
+                ("TOKEN".to_string(), "noro://a1/password".to_string()),
+                (
+                    "URL".to_string(),
+                    "postgres://u:noro://b2/password@h\n".to_string()
+                ),
+                ("PLAIN".to_string(), "value".to_string()),
+                ("QUOTED".to_string(), "a # b".to_string()),
+            ]
+        );
+        assert_eq!(
+            references("postgres://u:noro://b2/password@h"),
+            vec!["noro://b2/password".to_string()]
+        );
+        let values = HashMap::from([("noro://a1/password".to_string(), "ghp_secret".to_string())]);
+        assert_eq!(
+            substitute("Bearer noro://a1/password", &values),
+            "Bearer ghp_secret"
+        );
+    }
+
+    #[test]
+    fn test_masker_across_chunks() {
+        let secrets = vec!["hunter2".to_string(), "hunter22".to_string()];
+        let mut masker = Masker::new(&secrets);
+        let mut out = masker.push(b"pass=hun");
+        out.extend(masker.push(b"ter2 and hunter22!"));
+        out.extend(masker.push(b" hunt"));
+        out.extend(masker.finish());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("pass={} and {}! hunt", MASK, MASK)
+        );
+    }
+
+    #[test]
+    fn test_masker_byte_at_a_time() {
+        let secrets = vec!["ghp_secret".to_string()];
+        let mut masker = Masker::new(&secrets);
+        let mut out = Vec::new();
+        for byte in b"token ghp_secre ghp_secret." {
+            out.extend(masker.push(&[*byte]));
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("token ghp_secre {}.", MASK)
+        );
+        assert!(masker.finish().is_empty());
+    }
+
+    #[test]
+    fn test_pipe_flushes_held_prefix() {
+        struct Prompt(mpsc::Receiver<&'static [u8]>);
+        impl Read for Prompt {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let chunk = self.0.recv().unwrap_or_default();
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Ok(chunk.len())
+            }
+        }
+        #[derive(Clone, Default)]
+        struct Shared(std::sync::Arc<Mutex<Vec<u8>>>);
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let output = Shared::default();
+        let secrets = vec!["hunter2".to_string()];
+        let masker = Masker::new(&secrets);
+        let writer = output.clone();
+        let thread = std::thread::spawn(move || pipe(Prompt(rx), writer, masker));
+
+        tx.send(b"password for hun").unwrap();
+        std::thread::sleep(FLUSH_AFTER * 3);
+        assert_eq!(&output.0.lock().unwrap()[..], b"password for hun");
+
+        tx.send(b"ter2\n").unwrap();
+        drop(tx);
+        thread.join().unwrap();
+        assert_eq!(&output.0.lock().unwrap()[..], b"password for hunter2\n");
+    }
+
+    #[test]
+    fn test_unresolved_references() {
+        let items = vec![item("a1", "GitHub", r#"{"password":"pw","tags":["x"]}"#)];
+        let error = |r: &str| resolve(&items, r).unwrap_err().to_string();
+        assert_eq!(
+            error("noro://missing/password"),
+            "no item named \"missing\""
+        );
+        assert_eq!(
+            error("noro://github/username"),
+            "\"GitHub\" has no field \"username\""
+        );
+        assert_eq!(
+            error("noro://a1/tags"),
+            "field \"tags\" of \"GitHub\" is not a text value"
+        );
+
+        let args = Args::parse(&["env".to_string()]).unwrap();
+        let base = vec![("TOKEN".to_string(), "noro://nofield".to_string())];
+        let socket = std::env::temp_dir().join("noro-envrun-unused.sock");
+        assert!(matches!(
+            environment(&args, base, &socket),
+            Err(EnvRunError::Reference(_))
+        ));
+        let plain = vec![("HOME".to_string(), "/home/ann".to_string())];
+        let (vars, secrets) = environment(&args, plain.clone(), &socket).unwrap();
+        assert_eq!((vars, secrets), (plain, Vec::new()));
+    }
+
+    #[cfg(unix)]
+    fn resolver(
+        name: &str,
+        response: ResolveResponse,
+    ) -> (PathBuf, std::thread::JoinHandle<ResolveRequest>) {
+        let path = std::env::temp_dir().join(format!("noro-{}-{}.sock", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut len = [0u8; 4];
+            stream.read_exact(&mut len).unwrap();
+            let mut body = vec![0u8; u32::from_be_bytes(len) as usize];
+            stream.read_exact(&mut body).unwrap();
+            let reply = serde_json::to_vec(&response).unwrap();
+            stream.write_all(&frame(&reply)).unwrap();
+            serde_json::from_slice(&body).unwrap()
+        });
+        (path, server)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_environment_denied() {
+        let args = Args::parse(&["deploy".to_string(), "--prod".to_string()]).unwrap();
+        let base = vec![("TOKEN".to_string(), "noro://a1/password".to_string())];
+        let (socket, server) = resolver(
+            "denied",
+            ResolveResponse {
+                values: HashMap::new(),
+                error: Some(EnvRunError::Denied.to_string()),
+            },
+        );
+        let result = environment(&args, base, &socket);
+        assert!(matches!(result, Err(EnvRunError::Resolve(e)) if e == "request denied"));
+        assert_eq!(
+            server.join().unwrap(),
+            ResolveRequest {
+                command: "deploy --prod".into(),
+                references: vec!["noro://a1/password".into()],
+            }
+        );
+        std::fs::remove_file(&socket).unwrap();
+
+        let base = vec![("TOKEN".to_string(), "noro://a1/password".to_string())];
+        assert!(matches!(
+            environment(&args, base, &socket),
+            Err(EnvRunError::Stopped)
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_environment_substitutes() {
+        let args = Args::parse(&["env".to_string()]).unwrap();
+        let base = vec![(
+            "URL".to_string(),
+            "postgres://u:noro://b2/password@h".to_string(),
+        )];
+        let values = HashMap::from([("noro://b2/password".to_string(), "s3cr3t".to_string())]);
+        let (socket, server) = resolver(
+            "substitute",
+            ResolveResponse {
+                values,
+                error: None,
+            },
+        );
+        let (vars, secrets) = environment(&args, base, &socket).unwrap();
+        server.join().unwrap();
+        assert_eq!(vars[0].1, "postgres://u:s3cr3t@h");
+        assert_eq!(secrets, ["s3cr3t"]);
+        std::fs::remove_file(&socket).unwrap();
+    }
+
+    #[test]
+    fn test_args_parse() {
+        let args = |list: &[&str]| {
+            let list: Vec<String> = list.iter().map(|a| a.to_string()).collect();
+            Args::parse(&list)
+        };
+        let parsed = args(&[
+            "-e",
+            ".env",
+            "--env-file",
+            "prod.env",
+            "--no-masking",
+            "--",
+            "ls",
+            "-la",
+        ])
+        .unwrap();
+        assert_eq!(parsed.files, [".env", "prod.env"]);
+        assert!(parsed.nomask);
+        assert_eq!(parsed.command, ["ls", "-la"]);
+        assert_eq!(args(&["npm", "-v"]).unwrap().command, ["npm", "-v"]);
+
+        let error = |list: &[&str]| args(list).err().unwrap().to_string();
+        assert_eq!(error(&["-e"]), "-e needs a value");
+        assert_eq!(error(&["--env-file"]), "--env-file needs a value");
+        assert_eq!(error(&["--verbose", "ls"]), "unknown option --verbose");
+        assert!(error(&[]).starts_with("usage: noro-run"));
+        assert!(error(&["-e", ".env", "--"]).starts_with("usage: noro-run"));
+    }
+}
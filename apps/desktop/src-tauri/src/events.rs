@@ -0,0 +1,96 @@
+use noro_common::sse::EventParser;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::sync::{auth_header, SyncError};
+
+const READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangedItem {
+    pub id: String,
+    pub revision: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeEvent {
+    #[serde(default)]
+    pub revision: i64,
+    pub items: Vec<ChangedItem>,
+    #[serde(default)]
+    pub purged: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum VaultEvent {
+    Ready { revision: i64 },
+    Change(ChangeEvent),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ReadyEvent {
+    revision: i64,
+}
+
+pub struct EventStream {
+    res: reqwest::Response,
+    parser: EventParser,
+    queue: Vec<VaultEvent>,
+}
+
+impl EventStream {
+    pub async fn connect(base_url: &str, token: &str) -> Result<Self, SyncError> {
+        let client = reqwest::Client::new();
+        let url = format!("{}/api/v1/vault/events", base_url);
+
+        let res = client
+            .get(&url)
+            .header("cookie", auth_header(token))
+            .header("accept", "text/event-stream")
+            .send()
+            .await
+            .map_err(|e| SyncError::Http(e.to_string()))?;
+
+        if res.status() == 401 {
+            return Err(SyncError::Auth("session expired".into()));
+        }
+        if !res.status().is_success() {
+            return Err(SyncError::Http(res.status().to_string()));
+        }
+
+        Ok(Self {
+            res,
+            parser: EventParser::default(),
+            queue: Vec::new(),
+        })
+    }
+
+    pub async fn next(&mut self) -> Result<Option<VaultEvent>, SyncError> {
+        loop {
+            if !self.queue.is_empty() {
+                return Ok(Some(self.queue.remove(0)));
+            }
+
+            let chunk = tokio::time::timeout(READ_TIMEOUT, self.res.chunk())
+                .await
+                .map_err(|_| SyncError::Http("event stream timed out".into()))?
+                .map_err(|e| SyncError::Http(e.to_string()))?;
+            let Some(chunk) = chunk else {
+                return Ok(None);
+            };
+
+            for (event, data) in self.parser.feed(&chunk) {
+                let parsed = match event.as_str() {
+                    "ready" => serde_json::from_str::<ReadyEvent>(&data)
+                        .map(|ready| VaultEvent::Ready {
+                            revision: ready.revision,
+                        })
+                        .ok(),
+                    "change" => serde_json::from_str(&data).map(VaultEvent::Change).ok(),
+                    _ => None,
+                };
+                self.queue.extend(parsed);
+            }
+        }
+    }
+}
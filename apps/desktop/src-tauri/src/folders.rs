@@ -0,0 +1,622 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use tauri::State;
+
+use crate::crypto;
+use crate::storage::VaultFolder;
+use crate::sync::{auth_header, checkstatus, cryptoerr, SyncError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteFolder {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<String>,
+    pub color: String,
+    pub icon: String,
+    pub order: i32,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FolderList {
+    pub folders: Vec<RemoteFolder>,
+    pub counts: HashMap<String, u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FolderResponse {
+    folder: RemoteFolder,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeleteResponse {
+    deleted: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MoveResponse {
+    moved: u32,
+}
+
+#[derive(Default)]
+pub struct FolderStore {
+    folders: Mutex<Vec<RemoteFolder>>,
+}
+
+pub trait TreeNode {
+    fn id(&self) -> &str;
+    fn parent(&self) -> Option<&str>;
+}
+
+impl TreeNode for RemoteFolder {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn parent(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+}
+
+impl TreeNode for VaultFolder {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn parent(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+}
+
+pub fn children<'a, T: TreeNode>(nodes: &'a [T], parent: Option<&str>) -> Vec<&'a T> {
+    nodes.iter().filter(|n| n.parent() == parent).collect()
+}
+
+pub fn path<'a, T: TreeNode>(nodes: &'a [T], id: &str) -> Vec<&'a T> {
+    let mut path = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = Some(id);
+    while let Some(id) = current {
+        if !seen.insert(id) {
+            break;
+        }
+        let Some(node) = nodes.iter().find(|n| n.id() == id) else {
+            break;
+        };
+        path.push(node);
+        current = node.parent();
+    }
+    path.reverse();
+    path
+}
+
+pub fn descendants<'a, T: TreeNode>(nodes: &'a [T], id: &str) -> Vec<&'a T> {
+    let mut found = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::from([id]);
+    let mut queue = vec![id];
+    while let Some(parent) = queue.pop() {
+        for child in children(nodes, Some(parent)) {
+            if seen.insert(child.id()) {
+                found.push(child);
+                queue.push(child.id());
+            }
+        }
+    }
+    found
+}
+
+pub fn createscycle<T: TreeNode>(nodes: &[T], id: &str, parent: Option<&str>) -> bool {
+    match parent {
+        Some(parent) => parent == id || path(nodes, parent).iter().any(|n| n.id() == id),
+        None => false,
+    }
+}
+
+fn sealname(id: &str, name: &str) -> Result<String, SyncError> {
+    let key = crypto::itemkey(id).map_err(cryptoerr)?;
+    crypto::sealfield(&key, name).map_err(cryptoerr)
+}
+
+fn openname(key: &[u8; 32], name: &str) -> Option<String> {
+    crypto::openfield(key, name).ok()
+}
+
+fn openfolder(folder: &mut RemoteFolder) -> Result<bool, SyncError> {
+    let key = crypto::itemkey(&folder.id).map_err(cryptoerr)?;
+    match openname(&key, &folder.name) {
+        Some(name) => {
+            folder.name = name;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+pub async fn fetchfolders(base_url: &str, token: &str) -> Result<FolderList, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/folders", base_url);
+
+    let res = client
+        .get(&url)
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let mut list: FolderList = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    for folder in &mut list.folders {
+        if openfolder(folder)? {
+            continue;
+        }
+        let migrated = updatefolder(
+            base_url,
+            token,
+            &folder.id,
+            Some(&folder.name),
+            None,
+            None,
+            None,
+        )
+        .await;
+        if let Ok(migrated) = migrated {
+            *folder = migrated;
+        }
+    }
+
+    Ok(list)
+}
+
+pub async fn createfolder(
+    base_url: &str,
+    token: &str,
+    id: &str,
+    name: &str,
+    parent_id: Option<String>,
+    color: Option<String>,
+    icon: Option<String>,
+) -> Result<RemoteFolder, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/folders", base_url);
+
+    #[derive(Serialize)]
+    struct Body {
+        id: String,
+        name: String,
+        #[serde(rename = "parentId")]
+        parent_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        color: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        icon: Option<String>,
+    }
+
+    let body = Body {
+        id: id.to_string(),
+        name: sealname(id, name)?,
+        parent_id,
+        color,
+        icon,
+    };
+
+    let res = client
+        .post(&url)
+        .header("cookie", auth_header(token))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: FolderResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let mut folder = resp.folder;
+    openfolder(&mut folder)?;
+    Ok(folder)
+}
+
+async fn patchfolder<B: Serialize>(
+    base_url: &str,
+    token: &str,
+    id: &str,
+    body: &B,
+) -> Result<RemoteFolder, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/folders/{}", base_url, id);
+
+    let res = client
+        .patch(&url)
+        .header("cookie", auth_header(token))
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: FolderResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let mut folder = resp.folder;
+    openfolder(&mut folder)?;
+    Ok(folder)
+}
+
+pub async fn updatefolder(
+    base_url: &str,
+    token: &str,
+    id: &str,
+    name: Option<&str>,
+    color: Option<String>,
+    icon: Option<String>,
+    order: Option<i32>,
+) -> Result<RemoteFolder, SyncError> {
+    #[derive(Serialize)]
+    struct Body {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        color: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        icon: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        order: Option<i32>,
+    }
+
+    let body = Body {
+        name: name.map(|n| sealname(id, n)).transpose()?,
+        color,
+        icon,
+        order,
+    };
+
+    patchfolder(base_url, token, id, &body).await
+}
+
+pub async fn movefolder(
+    base_url: &str,
+    token: &str,
+    id: &str,
+    parent_id: Option<String>,
+) -> Result<RemoteFolder, SyncError> {
+    #[derive(Serialize)]
+    struct Body {
+        #[serde(rename = "parentId")]
+        parent_id: Option<String>,
+    }
+
+    patchfolder(base_url, token, id, &Body { parent_id }).await
+}
+
+pub async fn deletefolder(base_url: &str, token: &str, id: &str) -> Result<Vec<String>, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/folders/{}", base_url, id);
+
+    let res = client
+        .delete(&url)
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: DeleteResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    Ok(resp.deleted)
+}
+
+pub async fn moveitems(
+    base_url: &str,
+    token: &str,
+    folder_id: Option<&str>,
+    item_ids: Vec<String>,
+) -> Result<u32, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!(
+        "{}/api/v1/folders/{}/items",
+        base_url,
+        folder_id.unwrap_or("null")
+    );
+
+    #[derive(Serialize)]
+    struct Body {
+        #[serde(rename = "itemIds")]
+        item_ids: Vec<String>,
+    }
+
+    let res = client
+        .post(&url)
+        .header("cookie", auth_header(token))
+        .json(&Body { item_ids })
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: MoveResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    Ok(resp.moved)
+}
+
+impl FolderStore {
+    fn replace(&self, folders: Vec<RemoteFolder>) {
+        *self.folders.lock().unwrap() = folders;
+    }
+
+    fn upsert(&self, folder: RemoteFolder) {
+        let mut folders = self.folders.lock().unwrap();
+        match folders.iter_mut().find(|f| f.id == folder.id) {
+            Some(existing) => *existing = folder,
+            None => folders.push(folder),
+        }
+    }
+
+    fn remove(&self, ids: &[String]) {
+        self.folders
+            .lock()
+            .unwrap()
+            .retain(|f| !ids.contains(&f.id));
+    }
+
+    fn query<F>(&self, f: F) -> Vec<RemoteFolder>
+    where
+        F: FnOnce(&[RemoteFolder]) -> Vec<&RemoteFolder>,
+    {
+        let folders = self.folders.lock().unwrap();
+        f(&folders).into_iter().cloned().collect()
+    }
+}
+
+#[tauri::command]
+pub async fn folder_fetch(
+    store: State<'_, FolderStore>,
+    base_url: String,
+    token: String,
+) -> Result<FolderList, SyncError> {
+    let list = fetchfolders(&base_url, &token).await?;
+    store.replace(list.folders.clone());
+    Ok(list)
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn folder_create(
+    store: State<'_, FolderStore>,
+    base_url: String,
+    token: String,
+    id: String,
+    name: String,
+    parent_id: Option<String>,
+    color: Option<String>,
+    icon: Option<String>,
+) -> Result<RemoteFolder, SyncError> {
+    let folder = createfolder(&base_url, &token, &id, &name, parent_id, color, icon).await?;
+    store.upsert(folder.clone());
+    Ok(folder)
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn folder_update(
+    store: State<'_, FolderStore>,
+    base_url: String,
+    token: String,
+    id: String,
+    name: Option<String>,
+    color: Option<String>,
+    icon: Option<String>,
+    order: Option<i32>,
+) -> Result<RemoteFolder, SyncError> {
+    let folder = updatefolder(&base_url, &token, &id, name.as_deref(), color, icon, order).await?;
+    store.upsert(folder.clone());
+    Ok(folder)
+}
+
+#[tauri::command]
+pub async fn folder_move(
+    store: State<'_, FolderStore>,
+    base_url: String,
+    token: String,
+    id: String,
+    parent_id: Option<String>,
+) -> Result<RemoteFolder, SyncError> {
+    let cycle = {
+        let folders = store.folders.lock().unwrap();
+        createscycle(&folders, &id, parent_id.as_deref())
+    };
+    if cycle {
+        return Err(SyncError::Http("circular reference detected".into()));
+    }
+    let folder = movefolder(&base_url, &token, &id, parent_id).await?;
+    store.upsert(folder.clone());
+    Ok(folder)
+}
+
+#[tauri::command]
+pub async fn folder_delete(
+    store: State<'_, FolderStore>,
+    base_url: String,
+    token: String,
+    id: String,
+) -> Result<Vec<String>, SyncError> {
+    let deleted = deletefolder(&base_url, &token, &id).await?;
+    store.remove(&deleted);
+    Ok(deleted)
+}
+
+#[tauri::command]
+pub async fn folder_move_items(
+    base_url: String,
+    token: String,
+    folder_id: Option<String>,
+    item_ids: Vec<String>,
+) -> Result<u32, SyncError> {
+    moveitems(&base_url, &token, folder_id.as_deref(), item_ids).await
+}
+
+#[tauri::command]
+pub fn folder_children(
+    store: State<'_, FolderStore>,
+    parent_id: Option<String>,
+) -> Vec<RemoteFolder> {
+    store.query(|folders| children(folders, parent_id.as_deref()))
+}
+
+#[tauri::command]
+pub fn folder_path(store: State<'_, FolderStore>, id: String) -> Vec<RemoteFolder> {
+    store.query(|folders| path(folders, &id))
+}
+
+#[tauri::command]
+pub fn folder_descendants(store: State<'_, FolderStore>, id: String) -> Vec<RemoteFolder> {
+    store.query(|folders| descendants(folders, &id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(id: &str, parent: Option<&str>) -> VaultFolder {
+        VaultFolder {
+            id: id.into(),
+            name: id.into(),
+            parent_id: parent.map(Into::into),
+            color: "default".into(),
+            icon: "folder".into(),
+            order: 0,
+            created: 0,
+            updated: 0,
+        }
+    }
+
+    fn tree() -> Vec<VaultFolder> {
+        vec![
+            folder("root", None),
+            folder("work", Some("root")),
+            folder("clients", Some("work")),
+            folder("personal", Some("root")),
+            folder("other", None),
+        ]
+    }
+
+    fn ids(nodes: Vec<&VaultFolder>) -> Vec<String> {
+        nodes.iter().map(|n| n.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_children() {
+        let folders = tree();
+        assert_eq!(ids(children(&folders, None)), ["root", "other"]);
+        assert_eq!(ids(children(&folders, Some("root"))), ["work", "personal"]);
+        assert!(children(&folders, Some("clients")).is_empty());
+        assert!(children(&folders, Some("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_path() {
+        let folders = tree();
+        assert_eq!(ids(path(&folders, "clients")), ["root", "work", "clients"]);
+        assert_eq!(ids(path(&folders, "other")), ["other"]);
+        assert!(path(&folders, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_path_stops_at_missing_parent() {
+        let folders = vec![
+            folder("orphan", Some("gone")),
+            folder("child", Some("orphan")),
+        ];
+        assert_eq!(ids(path(&folders, "child")), ["orphan", "child"]);
+    }
+
+    #[test]
+    fn test_path_terminates_on_cycle() {
+        let folders = vec![folder("a", Some("b")), folder("b", Some("a"))];
+        assert_eq!(ids(path(&folders, "a")), ["b", "a"]);
+    }
+
+    #[test]
+    fn test_descendants() {
+        let folders = tree();
+        let mut under = ids(descendants(&folders, "root"));
+        under.sort();
+        assert_eq!(under, ["clients", "personal", "work"]);
+        assert!(descendants(&folders, "clients").is_empty());
+        assert!(descendants(&folders, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_descendants_terminates_on_cycle() {
+        let folders = vec![folder("a", Some("b")), folder("b", Some("a"))];
+        assert_eq!(ids(descendants(&folders, "a")), ["b"]);
+    }
+
+    #[test]
+    fn test_createscycle() {
+        let folders = tree();
+        assert!(createscycle(&folders, "root", Some("clients")));
+        assert!(createscycle(&folders, "work", Some("work")));
+        assert!(createscycle(&folders, "root", Some("work")));
+        assert!(!createscycle(&folders, "clients", Some("personal")));
+        assert!(!createscycle(&folders, "clients", None));
+        assert!(!createscycle(&folders, "new", Some("missing")));
+    }
+
+    #[test]
+    fn test_store_upsert_and_remove() {
+        let remote = |id: &str, name: &str| RemoteFolder {
+            id: id.into(),
+            name: name.into(),
+            parent_id: None,
+            color: "default".into(),
+            icon: "folder".into(),
+            order: 0,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        let store = FolderStore::default();
+        store.replace(vec![remote("a", "one"), remote("b", "two")]);
+        store.upsert(remote("a", "renamed"));
+        store.upsert(remote("c", "three"));
+        let names = |store: &FolderStore| {
+            store
+                .query(|f| f.iter().collect())
+                .into_iter()
+                .map(|f| f.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&store), ["renamed", "two", "three"]);
+
+        store.remove(&["a".into(), "missing".into()]);
+        assert_eq!(names(&store), ["two", "three"]);
+    }
+
+    #[test]
+    fn test_openname_rejects_plaintext() {
+        let key = [7u8; 32];
+        let sealed = crypto::sealfield(&key, "work").unwrap();
+        assert_eq!(openname(&key, &sealed).as_deref(), Some("work"));
+        assert_eq!(openname(&[8u8; 32], &sealed), None);
+        assert_eq!(openname(&key, "personal"), None);
+        assert_eq!(openname(&key, "dGVzdA=="), None);
+    }
+}
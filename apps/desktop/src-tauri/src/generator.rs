@@ -0,0 +1,110 @@
+use noro_common::generator::{
+    generate, sample, Class, GeneratorError, GeneratorOptions, GeneratorResult, AMBIGUOUS,
+    MAX_LENGTH, MIN_LENGTH,
+};
+use serde::Serialize;
+use std::sync::Mutex;
+
+use crate::policy::{PasswordRules, PolicyStore};
+
+#[derive(Debug, Serialize)]
+pub struct GeneratedPassword {
+    #[serde(flatten)]
+    pub result: GeneratorResult,
+    pub policy: Option<String>,
+}
+
+#[derive(Default)]
+pub struct GeneratorState {
+    options: Mutex<GeneratorOptions>,
+}
+
+impl GeneratorState {
+    pub fn options(&self) -> GeneratorOptions {
+        self.options.lock().map(|o| o.clone()).unwrap_or_default()
+    }
+}
+
+pub fn generaterules(
+    rules: &PasswordRules,
+    options: &GeneratorOptions,
+) -> Result<GeneratorResult, GeneratorError> {
+    let allowed = |c: &char| {
+        rules.allowed.contains(*c)
+            && !(options.exclude.contains(*c)
+                || options.exclude_ambiguous && AMBIGUOUS.contains(*c))
+    };
+    let mut classes: Vec<Class> = Vec::new();
+    let mut taken: Vec<char> = Vec::new();
+    for set in &rules.required {
+        let chars: Vec<char> = set.chars().filter(allowed).collect();
+        if chars.is_empty() {
+            return Err(GeneratorError::NoCharacters);
+        }
+        let chars: Vec<char> = chars.into_iter().filter(|c| !taken.contains(c)).collect();
+        taken.extend(&chars);
+        if !chars.is_empty() {
+            classes.push(Class { chars, min: 1 });
+        }
+    }
+    let rest: Vec<char> = rules
+        .allowed
+        .chars()
+        .filter(|c| allowed(c) && !taken.contains(c))
+        .collect();
+    if !rest.is_empty() {
+        classes.push(Class {
+            chars: rest,
+            min: 0,
+        });
+    }
+
+    let lower = rules.min_length.unwrap_or(0).max(MIN_LENGTH);
+    let upper = rules.max_length.unwrap_or(MAX_LENGTH).min(MAX_LENGTH);
+    if lower > upper {
+        return Err(GeneratorError::TooShort);
+    }
+    let options = GeneratorOptions {
+        no_consecutive: options.no_consecutive || rules.max_consecutive == Some(1),
+        ..options.clone()
+    };
+    let limit = rules.max_consecutive.filter(|n| *n > 0);
+    sample(
+        &classes,
+        options.length.clamp(lower, upper),
+        &options,
+        |chars| {
+            let satisfied = rules
+                .required
+                .iter()
+                .all(|set| chars.iter().any(|c| set.contains(*c)));
+            let runs = limit.map_or(true, |n| {
+                chars.windows(n + 1).all(|w| w.iter().any(|c| *c != w[0]))
+            });
+            satisfied && runs
+        },
+    )
+}
+
+#[tauri::command]
+pub fn generate_password(
+    state: tauri::State<'_, GeneratorState>,
+    policies: tauri::State<'_, PolicyStore>,
+    options: GeneratorOptions,
+    url: Option<String>,
+) -> Result<GeneratedPassword, GeneratorError> {
+    let result = match url.as_deref().and_then(|url| policies.forurl(url)) {
+        Some(policy) => GeneratedPassword {
+            result: generaterules(&policy.rules, &options)?,
+            policy: Some(policy.name),
+        },
+        None => GeneratedPassword {
+            result: generate(&options)?,
+            policy: None,
+        },
+    };
+    if let Ok(mut current) = state.options.lock() {
+        *current = options;
+    }
+    Ok(result)
+}
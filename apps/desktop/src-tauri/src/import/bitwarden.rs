@@ -0,0 +1,495 @@
+use aes::Aes256;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cbc::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+use super::{formaterr, ImportError, ImportedItem, ItemBuilder};
+
+const KDF_PBKDF2: u32 = 0;
+const KDF_ARGON2ID: u32 = 1;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope {
+    #[serde(default)]
+    encrypted: bool,
+    #[serde(default)]
+    password_protected: bool,
+    salt: Option<String>,
+    kdf_type: Option<u32>,
+    kdf_iterations: Option<u32>,
+    kdf_memory: Option<u32>,
+    kdf_parallelism: Option<u32>,
+    #[serde(rename = "encKeyValidation_DO_NOT_EDIT")]
+    validation: Option<String>,
+    data: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct Export {
+    #[serde(default)]
+    folders: Vec<Named>,
+    #[serde(default)]
+    collections: Vec<Named>,
+    #[serde(default)]
+    items: Vec<Item>,
+}
+
+#[derive(Deserialize)]
+struct Named {
+    id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Item {
+    id: Option<String>,
+    #[serde(rename = "type")]
+    kind: u32,
+    #[serde(default)]
+    name: String,
+    notes: Option<String>,
+    #[serde(default)]
+    favorite: bool,
+    folder_id: Option<String>,
+    #[serde(default)]
+    collection_ids: Option<Vec<String>>,
+    #[serde(default)]
+    fields: Vec<Field>,
+    login: Option<Login>,
+    card: Option<Card>,
+    identity: Option<Identity>,
+    ssh_key: Option<SshKey>,
+}
+
+#[derive(Deserialize)]
+struct Field {
+    name: Option<String>,
+    value: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Login {
+    username: Option<String>,
+    password: Option<String>,
+    totp: Option<String>,
+    #[serde(default)]
+    uris: Option<Vec<Uri>>,
+}
+
+#[derive(Deserialize)]
+struct Uri {
+    uri: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Card {
+    cardholder_name: Option<String>,
+    number: Option<String>,
+    exp_month: Option<String>,
+    exp_year: Option<String>,
+    code: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Identity {
+    first_name: Option<String>,
+    last_name: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    address1: Option<String>,
+    city: Option<String>,
+    state: Option<String>,
+    postal_code: Option<String>,
+    country: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SshKey {
+    private_key: Option<String>,
+    public_key: Option<String>,
+}
+
+fn derivekey(password: &str, env: &Envelope) -> Result<[u8; 32], ImportError> {
+    let salt = env
+        .salt
+        .as_deref()
+        .ok_or_else(|| ImportError::Format("missing salt".into()))?;
+    let iterations = env.kdf_iterations.unwrap_or(600_000);
+    let mut key = [0u8; 32];
+    match env.kdf_type.unwrap_or(KDF_PBKDF2) {
+        KDF_PBKDF2 => pbkdf2::pbkdf2_hmac::<Sha256>(
+            password.as_bytes(),
+            salt.as_bytes(),
+            iterations,
+            &mut key,
+        ),
+        KDF_ARGON2ID => {
+            let memory = env.kdf_memory.unwrap_or(64) * 1024;
+            let params = Params::new(
+                memory,
+                iterations,
+                env.kdf_parallelism.unwrap_or(4),
+                Some(32),
+            )
+            .map_err(formaterr)?;
+            Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password_into(
+                    password.as_bytes(),
+                    &Sha256::digest(salt.as_bytes()),
+                    &mut key,
+                )
+                .map_err(formaterr)?;
+        }
+        other => return Err(ImportError::Unsupported(format!("kdf type {other}"))),
+    }
+    Ok(key)
+}
+
+fn stretch(key: &[u8; 32]) -> Result<([u8; 32], [u8; 32]), ImportError> {
+    let hkdf = Hkdf::<Sha256>::from_prk(key).map_err(formaterr)?;
+    let mut enc = [0u8; 32];
+    let mut mac = [0u8; 32];
+    hkdf.expand(b"enc", &mut enc).map_err(formaterr)?;
+    hkdf.expand(b"mac", &mut mac).map_err(formaterr)?;
+    Ok((enc, mac))
+}
+
+fn decryptstring(value: &str, enc: &[u8; 32], mac: &[u8; 32]) -> Result<Vec<u8>, ImportError> {
+    let malformed = || ImportError::Format("malformed encrypted string".into());
+    let rest = value
+        .strip_prefix("2.")
+        .ok_or(ImportError::Unsupported("encryption type".into()))?;
+    let parts: Vec<&str> = rest.split('|').collect();
+    let [iv, ct, tag] = parts.as_slice() else {
+        return Err(malformed());
+    };
+    let iv = STANDARD.decode(iv).map_err(|_| malformed())?;
+    let ct = STANDARD.decode(ct).map_err(|_| malformed())?;
+    let tag = STANDARD.decode(tag).map_err(|_| malformed())?;
+
+    let mut hmac = <Hmac<Sha256> as Mac>::new_from_slice(mac).expect("hmac accepts any key length");
+    hmac.update(&iv);
+    hmac.update(&ct);
+    hmac.verify_slice(&tag)
+        .map_err(|_| ImportError::InvalidPassword)?;
+
+    cbc::Decryptor::<Aes256>::new_from_slices(enc, &iv)
+        .map_err(|_| malformed())?
+        .decrypt_padded_vec_mut::<Pkcs7>(&ct)
+        .map_err(|_| ImportError::InvalidPassword)
+}
+
+fn decrypt(env: &Envelope, password: &str) -> Result<Vec<u8>, ImportError> {
+    let (enc, mac) = stretch(&derivekey(password, env)?)?;
+    if let Some(validation) = &env.validation {
+        decryptstring(validation, &enc, &mac)?;
+    }
+    let data = env
+        .data
+        .as_deref()
+        .ok_or_else(|| ImportError::Format("missing data".into()))?;
+    decryptstring(data, &enc, &mac)
+}
+
+fn expiry(month: Option<&str>, year: Option<&str>) -> Option<String> {
+    if month.is_none() && year.is_none() {
+        return None;
+    }
+    Some(format!(
+        "{:0>2}/{}",
+        month.unwrap_or("01"),
+        year.unwrap_or_default()
+    ))
+}
+
+fn convert(
+    item: Item,
+    folders: &HashMap<&str, &str>,
+    collections: &HashMap<&str, &str>,
+) -> Option<ImportedItem> {
+    let name = item.name.as_str();
+    let notes = item.notes.as_deref();
+    let builder = match item.kind {
+        1 => {
+            let login = item.login.as_ref();
+            let uris: Vec<&str> = login
+                .and_then(|l| l.uris.as_ref())
+                .map(|u| u.iter().filter_map(|u| u.uri.as_deref()).collect())
+                .unwrap_or_default();
+            let mut builder = ItemBuilder::new("login", name)
+                .field("username", login.and_then(|l| l.username.as_deref()))
+                .field("password", login.and_then(|l| l.password.as_deref()))
+                .field("url", uris.first().copied())
+                .field("totp", login.and_then(|l| l.totp.as_deref()))
+                .field("notes", notes);
+            for uri in uris.iter().skip(1) {
+                builder = builder.custom("url", uri);
+            }
+            builder
+        }
+        2 => ItemBuilder::new("note", name).field("content", notes),
+        3 => {
+            let card = item.card.as_ref();
+            let exp = expiry(
+                card.and_then(|c| c.exp_month.as_deref()),
+                card.and_then(|c| c.exp_year.as_deref()),
+            );
+            ItemBuilder::new("card", name)
+                .field("holder", card.and_then(|c| c.cardholder_name.as_deref()))
+                .field("number", card.and_then(|c| c.number.as_deref()))
+                .field("expiry", exp.as_deref())
+                .field("cvv", card.and_then(|c| c.code.as_deref()))
+                .field("notes", notes)
+        }
+        4 => {
+            let id = item.identity.as_ref();
+            ItemBuilder::new("identity", name)
+                .field("firstname", id.and_then(|i| i.first_name.as_deref()))
+                .field("lastname", id.and_then(|i| i.last_name.as_deref()))
+                .field("email", id.and_then(|i| i.email.as_deref()))
+                .field("phone", id.and_then(|i| i.phone.as_deref()))
+                .field("address", id.and_then(|i| i.address1.as_deref()))
+                .field("city", id.and_then(|i| i.city.as_deref()))
+                .field("state", id.and_then(|i| i.state.as_deref()))
+                .field("zip", id.and_then(|i| i.postal_code.as_deref()))
+                .field("country", id.and_then(|i| i.country.as_deref()))
+                .field("notes", notes)
+        }
+        5 => {
+            let key = item.ssh_key.as_ref();
+            ItemBuilder::new("ssh", name)
+                .field("privatekey", key.and_then(|k| k.private_key.as_deref()))
+                .field("publickey", key.and_then(|k| k.public_key.as_deref()))
+                .field("notes", notes)
+        }
+        _ => return None,
+    };
+    let builder = item.fields.iter().fold(builder, |b, f| {
+        b.custom(
+            f.name.as_deref().unwrap_or("field"),
+            f.value.as_deref().unwrap_or_default(),
+        )
+    });
+    let tags = item
+        .collection_ids
+        .iter()
+        .flatten()
+        .filter_map(|id| collections.get(id.as_str()).map(|n| n.to_string()));
+    let folder = item
+        .folder_id
+        .as_deref()
+        .and_then(|id| folders.get(id).copied());
+    Some(
+        builder
+            .tags(tags)
+            .favorite(item.favorite)
+            .folder(folder)
+            .build(),
+    )
+}
+
+pub fn parse(
+    bytes: &[u8],
+    password: Option<&str>,
+    skipped: &mut Vec<String>,
+) -> Result<Vec<ImportedItem>, ImportError> {
+    let bytes = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+    let env: Envelope = serde_json::from_slice(bytes).map_err(formaterr)?;
+    let plain;
+    let bytes = if env.encrypted {
+        if !env.password_protected {
+            return Err(ImportError::Unsupported(
+                "account-restricted bitwarden exports cannot be decrypted offline".into(),
+            ));
+        }
+        plain = decrypt(&env, password.ok_or(ImportError::PasswordRequired)?)?;
+        plain.as_slice()
+    } else {
+        bytes
+    };
+    let export: Export = serde_json::from_slice(bytes).map_err(formaterr)?;
+    let folders: HashMap<&str, &str> = export
+        .folders
+        .iter()
+        .map(|f| (f.id.as_str(), f.name.as_str()))
+        .collect();
+    let collections: HashMap<&str, &str> = export
+        .collections
+        .iter()
+        .map(|c| (c.id.as_str(), c.name.as_str()))
+        .collect();
+
+    let mut items = Vec::new();
+    for item in export.items {
+        let label = item.id.clone().unwrap_or_else(|| item.name.clone());
+        let kind = item.kind;
+        match convert(item, &folders, &collections) {
+            Some(item) => items.push(item),
+            None => skipped.push(format!("{label}: unsupported item type {kind}")),
+        }
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cbc::cipher::BlockEncryptMut;
+
+    fn encryptstring(plain: &[u8], enc: &[u8; 32], mac: &[u8; 32]) -> String {
+        let iv = [7u8; 16];
+        let ct = cbc::Encryptor::<Aes256>::new_from_slices(enc, &iv)
+            .unwrap()
+            .encrypt_padded_vec_mut::<Pkcs7>(plain);
+        let mut hmac = <Hmac<Sha256> as Mac>::new_from_slice(mac).unwrap();
+        hmac.update(&iv);
+        hmac.update(&ct);
+        let tag = hmac.finalize().into_bytes();
+        format!(
+            "2.{}|{}|{}",
+            STANDARD.encode(iv),
+            STANDARD.encode(ct),
+            STANDARD.encode(tag)
+        )
+    }
+
+    #[test]
+    fn test_encrypted_export() {
+        let plain = br#"{"encrypted":false,"folders":[{"id":"f1","name":"Work"}],"items":[
+            {"id":"a","type":1,"name":"Example","favorite":true,"folderId":"f1",
+             "login":{"username":"alice","password":"pw","uris":[{"uri":"https://example.com"}]}},
+            {"id":"b","type":3,"name":"Visa","card":{"number":"4111","expMonth":"3","expYear":"2030"}},
+            {"id":"c","type":9,"name":"Unknown"}]}"#;
+        let mut env = Envelope {
+            encrypted: true,
+            password_protected: true,
+            salt: Some("c2FsdHNhbHQ=".into()),
+            kdf_type: Some(KDF_PBKDF2),
+            kdf_iterations: Some(1000),
+            kdf_memory: None,
+            kdf_parallelism: None,
+            validation: None,
+            data: None,
+        };
+        let (enc, mac) = stretch(&derivekey("correct horse", &env).unwrap()).unwrap();
+        env.validation = Some(encryptstring(b"validation", &enc, &mac));
+        env.data = Some(encryptstring(plain, &enc, &mac));
+        let json = format!(
+            r#"{{"encrypted":true,"passwordProtected":true,"salt":"c2FsdHNhbHQ=","kdfType":0,"kdfIterations":1000,"encKeyValidation_DO_NOT_EDIT":"{}","data":"{}"}}"#,
+            env.validation.unwrap(),
+            env.data.unwrap()
+        );
+
+        let mut skipped = Vec::new();
+        assert!(matches!(
+            parse(json.as_bytes(), None, &mut skipped),
+            Err(ImportError::PasswordRequired)
+        ));
+        assert!(matches!(
+            parse(json.as_bytes(), Some("wrong"), &mut skipped),
+            Err(ImportError::InvalidPassword)
+        ));
+        let items = parse(json.as_bytes(), Some("correct horse"), &mut skipped).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].folder.as_deref(), Some("Work"));
+        assert!(items[0].favorite);
+        assert!(items[1].data.contains(r#""expiry":"03/2030""#));
+        assert_eq!(skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_folder_mapping() {
+        let json = br#"{"folders":[{"id":"f1","name":"Work/Email"},{"id":"f2","name":"Home"}],
+            "collections":[{"id":"c1","name":"Team"},{"id":"c2","name":"Ops"}],
+            "items":[
+            {"type":2,"name":"A","notes":"a","folderId":"f1","collectionIds":["c1","c2","c9"]},
+            {"type":2,"name":"B","notes":"b","folderId":"f2"},
+            {"type":2,"name":"C","notes":"c","folderId":"gone"},
+            {"type":2,"name":"D","notes":"d","folderId":null}]}"#;
+        let mut skipped = Vec::new();
+        let items = parse(json, None, &mut skipped).unwrap();
+        assert_eq!(items[0].folder.as_deref(), Some("Work/Email"));
+        assert_eq!(items[0].tags, ["Team", "Ops"]);
+        assert_eq!(items[1].folder.as_deref(), Some("Home"));
+        assert_eq!(items[2].folder, None);
+        assert_eq!(items[3].folder, None);
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let json = br#"{"items":[
+            {"type":1},
+            {"type":3,"name":"Visa","card":{"number":"4111"}},
+            {"type":4,"name":"Me"},
+            {"type":1,"name":"Multi","login":{"uris":[{"uri":"https://a.example"},{"uri":null},{"uri":"https://b.example"}]},
+             "fields":[{"name":null,"value":"x"},{"name":"empty","value":null}]}]}"#;
+        let mut skipped = Vec::new();
+        let items = parse(json, None, &mut skipped).unwrap();
+        assert_eq!(items[0].title, "untitled");
+        assert_eq!(items[0].data, "{}");
+        assert_eq!(items[1].data, r#"{"number":"4111"}"#);
+        assert_eq!(items[2].item_type, "identity");
+        let multi: serde_json::Value = serde_json::from_str(&items[3].data).unwrap();
+        assert_eq!(multi["url"], "https://a.example");
+        assert_eq!(
+            multi["fields"],
+            serde_json::json!([
+                { "name": "url", "value": "https://b.example" },
+                { "name": "field", "value": "x" },
+            ])
+        );
+
+        assert!(matches!(
+            parse(br#"{"items":[{"name":"no type"}]}"#, None, &mut skipped),
+            Err(ImportError::Format(_))
+        ));
+    }
+
+    #[test]
+    fn test_malformed_export() {
+        let mut skipped = Vec::new();
+        assert!(matches!(
+            parse(b"not json", None, &mut skipped),
+            Err(ImportError::Format(_))
+        ));
+        assert!(matches!(
+            parse(br#"{"encrypted":true}"#, Some("pw"), &mut skipped),
+            Err(ImportError::Unsupported(_))
+        ));
+        assert!(matches!(
+            parse(br#"{"encrypted":true,"passwordProtected":true}"#, Some("pw"), &mut skipped),
+            Err(ImportError::Format(e)) if e == "missing salt"
+        ));
+
+        let envelope = |data: &str| {
+            format!(
+                r#"{{"encrypted":true,"passwordProtected":true,"salt":"c2FsdA==","kdfIterations":1,"data":"{data}"}}"#
+            )
+        };
+        assert!(matches!(
+            parse(envelope("2.abc").as_bytes(), Some("pw"), &mut skipped),
+            Err(ImportError::Format(e)) if e == "malformed encrypted string"
+        ));
+        assert!(matches!(
+            parse(envelope("0.abc|def").as_bytes(), Some("pw"), &mut skipped),
+            Err(ImportError::Unsupported(_))
+        ));
+        let kdf = br#"{"encrypted":true,"passwordProtected":true,"salt":"c2FsdA==","kdfType":7}"#;
+        assert!(matches!(
+            parse(kdf, Some("pw"), &mut skipped),
+            Err(ImportError::Unsupported(e)) if e == "kdf type 7"
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+
+use super::{formaterr, ImportError, ImportedItem, ItemBuilder};
+
+const TITLE_COLUMNS: &[&str] = &["title", "name", "account", "site", "service"];
+const USERNAME_COLUMNS: &[&str] = &[
+    "username",
+    "user",
+    "login",
+    "login_username",
+    "email",
+    "user name",
+];
+const PASSWORD_COLUMNS: &[&str] = &["password", "pass", "login_password", "pwd"];
+const URL_COLUMNS: &[&str] = &[
+    "url",
+    "uri",
+    "website",
+    "web site",
+    "login_uri",
+    "address",
+    "origin",
+];
+const NOTES_COLUMNS: &[&str] = &["notes", "note", "extra", "comments", "comment"];
+const TOTP_COLUMNS: &[&str] = &["totp", "otp", "otpauth", "login_totp", "one-time password"];
+const FOLDER_COLUMNS: &[&str] = &["folder", "group", "grouping", "category"];
+const TAGS_COLUMNS: &[&str] = &["tags", "labels"];
+const FAVORITE_COLUMNS: &[&str] = &["favorite", "fav", "starred"];
+const TYPE_COLUMNS: &[&str] = &["type", "item_type"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CsvMapping {
+    #[serde(default)]
+    pub delimiter: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub totp: Option<String>,
+    #[serde(default)]
+    pub folder: Option<String>,
+    #[serde(default)]
+    pub tags: Option<String>,
+    #[serde(default)]
+    pub favorite: Option<String>,
+    #[serde(default)]
+    pub item_type: Option<String>,
+}
+
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn column(&self, names: &[&str]) -> Option<usize> {
+        names
+            .iter()
+            .find_map(|name| self.headers.iter().position(|h| h == name))
+    }
+
+    pub fn mapped(&self, mapped: Option<&String>, aliases: &[&str]) -> Option<usize> {
+        match mapped {
+            Some(name) => self.column(&[name.trim().to_lowercase().as_str()]),
+            None => self.column(aliases),
+        }
+    }
+}
+
+pub fn table(bytes: &[u8], delimiter: u8) -> Result<Table, ImportError> {
+    let bytes = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(bytes);
+    let headers = reader
+        .headers()
+        .map_err(formaterr)?
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    let rows = reader
+        .records()
+        .map(|r| r.map(|r| r.iter().map(String::from).collect()))
+        .collect::<Result<_, _>>()
+        .map_err(formaterr)?;
+    Ok(Table { headers, rows })
+}
+
+pub fn cell(row: &[String], column: Option<usize>) -> Option<&str> {
+    column
+        .and_then(|c| row.get(c))
+        .map(|v| v.as_str())
+        .filter(|v| !v.trim().is_empty())
+}
+
+pub fn truthy(value: Option<&str>) -> bool {
+    matches!(
+        value.map(|v| v.trim().to_lowercase()).as_deref(),
+        Some("1" | "true" | "yes" | "y" | "x")
+    )
+}
+
+pub fn parse(
+    bytes: &[u8],
+    mapping: Option<&CsvMapping>,
+    skipped: &mut Vec<String>,
+) -> Result<Vec<ImportedItem>, ImportError> {
+    let default = CsvMapping::default();
+    let mapping = mapping.unwrap_or(&default);
+    let delimiter = match mapping.delimiter.as_deref() {
+        Some("\\t") | Some("tab") => b'\t',
+        Some(d) if d.len() == 1 => d.as_bytes()[0],
+        Some(d) => return Err(ImportError::Format(format!("invalid delimiter {d:?}"))),
+        None => b',',
+    };
+    let table = table(bytes, delimiter)?;
+
+    let title = table.mapped(mapping.title.as_ref(), TITLE_COLUMNS);
+    let username = table.mapped(mapping.username.as_ref(), USERNAME_COLUMNS);
+    let password = table.mapped(mapping.password.as_ref(), PASSWORD_COLUMNS);
+    let url = table.mapped(mapping.url.as_ref(), URL_COLUMNS);
+    let notes = table.mapped(mapping.notes.as_ref(), NOTES_COLUMNS);
+    let totp = table.mapped(mapping.totp.as_ref(), TOTP_COLUMNS);
+    let folder = table.mapped(mapping.folder.as_ref(), FOLDER_COLUMNS);
+    let tags = table.mapped(mapping.tags.as_ref(), TAGS_COLUMNS);
+    let favorite = table.mapped(mapping.favorite.as_ref(), FAVORITE_COLUMNS);
+    let itemtype = table.mapped(mapping.item_type.as_ref(), TYPE_COLUMNS);
+    if title.is_none() && username.is_none() && password.is_none() && url.is_none() {
+        return Err(ImportError::Format("no recognised columns".into()));
+    }
+    let mapped = [
+        title, username, password, url, notes, totp, folder, tags, favorite, itemtype,
+    ];
+
+    let mut items = Vec::new();
+    for (i, row) in table.rows.iter().enumerate() {
+        if row.iter().all(|v| v.trim().is_empty()) {
+            skipped.push(format!("row {}: empty", i + 2));
+            continue;
+        }
+        let name = cell(row, title)
+            .or_else(|| cell(row, url))
+            .or_else(|| cell(row, username))
+            .unwrap_or("untitled");
+        let isnote = matches!(
+            cell(row, itemtype).map(str::to_lowercase).as_deref(),
+            Some("note")
+        ) || (cell(row, username).is_none()
+            && cell(row, password).is_none()
+            && cell(row, notes).is_some());
+        let mut item = if isnote {
+            ItemBuilder::new("note", name).field("content", cell(row, notes))
+        } else {
+            ItemBuilder::new("login", name)
+                .field("username", cell(row, username))
+                .field("password", cell(row, password))
+                .field("url", cell(row, url))
+                .field("totp", cell(row, totp))
+                .field("notes", cell(row, notes))
+        };
+        for (c, header) in table.headers.iter().enumerate() {
+            if !mapped.contains(&Some(c)) {
+                if let Some(value) = cell(row, Some(c)) {
+                    item = item.custom(header, value);
+                }
+            }
+        }
+        let tags = cell(row, tags)
+            .map(|t| t.split([',', ';']).map(String::from).collect::<Vec<_>>())
+            .unwrap_or_default();
+        items.push(
+            item.tags(tags)
+                .favorite(truthy(cell(row, favorite)))
+                .folder(cell(row, folder))
+                .build(),
+        );
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn data(item: &ImportedItem) -> Value {
+        serde_json::from_str(&item.data).unwrap()
+    }
+
+    #[test]
+    fn test_generic_default_columns() {
+        let csv = b"name,url,username,password,note,tags,favorite,pin\n\
+            Mail,https://mail.example.com,carol,pw2,,\"work;mail\",yes,1234\n\
+            Recipe,,,,two eggs,,,\n";
+        let mut skipped = Vec::new();
+        let items = parse(csv, None, &mut skipped).unwrap();
+        assert!(skipped.is_empty());
+        assert_eq!(items[0].title, "Mail");
+        let login = data(&items[0]);
+        assert_eq!(login["username"], "carol");
+        assert_eq!(login["url"], "https://mail.example.com");
+        assert_eq!(login["fields"], json!([{ "name": "pin", "value": "1234" }]));
+        assert_eq!(items[0].tags, ["work", "mail"]);
+        assert!(items[0].favorite);
+        assert_eq!(items[1].item_type, "note");
+        assert_eq!(data(&items[1])["content"], "two eggs");
+    }
+
+    #[test]
+    fn test_generic_custom_mapping() {
+        let csv = b"Site;Login;Secret;Link\nForum;bob;pw;forum.example.org\n;;;\n";
+        let mapping = CsvMapping {
+            delimiter: Some(";".into()),
+            title: Some("Site".into()),
+            username: Some("Login".into()),
+            password: Some("Secret".into()),
+            url: Some("Link".into()),
+            ..Default::default()
+        };
+        let mut skipped = Vec::new();
+        let items = parse(csv, Some(&mapping), &mut skipped).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(skipped, ["row 3: empty"]);
+        let login = data(&items[0]);
+        assert_eq!(login["username"], "bob");
+        assert_eq!(login["url"], "forum.example.org");
+
+        let tabs = CsvMapping {
+            delimiter: Some("tab".into()),
+            ..Default::default()
+        };
+        let items = parse(
+            b"title\tpassword\nWifi\thunter2\n",
+            Some(&tabs),
+            &mut skipped,
+        )
+        .unwrap();
+        assert_eq!(data(&items[0])["password"], "hunter2");
+    }
+
+    #[test]
+    fn test_generic_folder_mapping() {
+        let csv = b"title,username,folder,group\nMail,ann,/Work/Email/,Other\nBank,ann,,Other\n";
+        let mut skipped = Vec::new();
+        let items = parse(csv, None, &mut skipped).unwrap();
+        assert_eq!(items[0].folder.as_deref(), Some("Work/Email"));
+        assert_eq!(items[1].folder, None);
+        assert_eq!(data(&items[0])["fields"][0]["name"], "group");
+
+        let mapping = CsvMapping {
+            folder: Some("Group".into()),
+            ..Default::default()
+        };
+        let items = parse(csv, Some(&mapping), &mut skipped).unwrap();
+        assert_eq!(items[0].folder.as_deref(), Some("Other"));
+        assert_eq!(items[1].folder.as_deref(), Some("Other"));
+    }
+
+    #[test]
+    fn test_generic_missing_fields() {
+        let mut skipped = Vec::new();
+        let items = parse(
+            b"title,username,password\nOnly\n,bob,\n",
+            None,
+            &mut skipped,
+        )
+        .unwrap();
+        assert_eq!(items[0].title, "Only");
+        assert_eq!(items[0].item_type, "login");
+        assert_eq!(data(&items[0]), json!({}));
+        assert_eq!(items[1].title, "bob");
+
+        let mapping = CsvMapping {
+            title: Some("Site".into()),
+            ..Default::default()
+        };
+        let items = parse(b"url\nexample.com\n", Some(&mapping), &mut skipped).unwrap();
+        assert_eq!(items[0].title, "example.com");
+
+        let result = parse(b"foo,bar\n1,2\n", None, &mut skipped);
+        assert!(matches!(result, Err(ImportError::Format(e)) if e == "no recognised columns"));
+        assert!(parse(b"", None, &mut skipped).is_err());
+    }
+
+    #[test]
+    fn test_generic_malformed() {
+        let mut skipped = Vec::new();
+        let mapping = CsvMapping {
+            delimiter: Some("::".into()),
+            ..Default::default()
+        };
+        let result = parse(b"title\n", Some(&mapping), &mut skipped);
+        assert!(matches!(result, Err(ImportError::Format(e)) if e.contains("invalid delimiter")));
+
+        let result = parse(b"title,password\n\xff\xfe,pw\n", None, &mut skipped);
+        assert!(matches!(result, Err(ImportError::Format(_))));
+    }
+}
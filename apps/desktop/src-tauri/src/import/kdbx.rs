@@ -0,0 +1,1207 @@
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes256;
+use argon2::{Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cbc::cipher::{
+    block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit, StreamCipher,
+};
+use chacha20::ChaCha20;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use hmac::{Hmac, Mac};
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+use rand::{Rng, RngCore};
+use salsa20::Salsa20;
+use sha2::{Digest, Sha256, Sha512};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use super::{formaterr, ImportError, ImportedItem, ItemBuilder};
+
+pub const SIGNATURE: [u8; 8] = [0x03, 0xd9, 0xa2, 0x9a, 0x67, 0xfb, 0x4b, 0xb5];
+pub const CIPHER_AES256: [u8; 16] = [
+    0x31, 0xc1, 0xf2, 0xe6, 0xbf, 0x71, 0x43, 0x50, 0xbe, 0x58, 0x05, 0x21, 0x6a, 0xfc, 0x5a, 0xff,
+];
+pub const CIPHER_CHACHA20: [u8; 16] = [
+    0xd6, 0x03, 0x8a, 0x2b, 0x8b, 0x6f, 0x4c, 0xb5, 0xa5, 0x24, 0x33, 0x9a, 0x31, 0xdb, 0xb5, 0x9a,
+];
+pub const KDF_AES: [u8; 16] = [
+    0xc9, 0xd9, 0xf3, 0x9a, 0x62, 0x8a, 0x44, 0x60, 0xbf, 0x74, 0x0d, 0x08, 0xc1, 0x8a, 0x4f, 0xea,
+];
+pub const KDF_ARGON2D: [u8; 16] = [
+    0xef, 0x63, 0x6d, 0xdf, 0x8c, 0x29, 0x44, 0x4b, 0x91, 0xf7, 0xa9, 0xa4, 0x03, 0xe3, 0x0a, 0x0c,
+];
+pub const KDF_ARGON2ID: [u8; 16] = [
+    0x9e, 0x29, 0x8b, 0x19, 0x56, 0xdb, 0x47, 0x73, 0xb2, 0x3d, 0xfc, 0x3e, 0xc6, 0xf0, 0xa1, 0xe6,
+];
+const SALSA20_NONCE: [u8; 8] = [0xe8, 0x30, 0x09, 0x4b, 0x97, 0x20, 0x5d, 0x2a];
+
+const HEADER_END: u8 = 0;
+const HEADER_CIPHER: u8 = 2;
+const HEADER_COMPRESSION: u8 = 3;
+const HEADER_SEED: u8 = 4;
+const HEADER_IV: u8 = 7;
+const HEADER_KDF: u8 = 11;
+
+const INNER_END: u8 = 0;
+const INNER_STREAM_ID: u8 = 1;
+const INNER_STREAM_KEY: u8 = 2;
+const INNER_BINARY: u8 = 3;
+
+const STREAM_SALSA20: u32 = 2;
+const STREAM_CHACHA20: u32 = 3;
+const BLOCK_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Variant {
+    U32(u32),
+    U64(u64),
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    Str(String),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Header {
+    pub cipher: [u8; 16],
+    pub compressed: bool,
+    pub seed: Vec<u8>,
+    pub iv: Vec<u8>,
+    pub kdf: HashMap<String, Variant>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Entry {
+    pub uuid: String,
+    pub fields: Vec<(String, String)>,
+    pub protected: Vec<String>,
+    pub tags: Vec<String>,
+    pub times: Vec<(String, String)>,
+    pub extra: Vec<String>,
+    pub history: Vec<Entry>,
+}
+
+impl Entry {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn set(&mut self, key: &str, value: &str, protected: bool) {
+        match self.fields.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.fields.push((key.to_string(), value.to_string())),
+        }
+        self.protected.retain(|k| k != key);
+        if protected {
+            self.protected.push(key.to_string());
+        }
+    }
+
+    pub fn time(&self, key: &str) -> Option<u64> {
+        self.times
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, v)| decodetime(v))
+    }
+
+    pub fn settime(&mut self, key: &str, unix: u64) {
+        let value = encodetime(unix);
+        match self.times.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value,
+            None => self.times.push((key.to_string(), value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Group {
+    pub uuid: String,
+    pub name: String,
+    pub extra: Vec<String>,
+    pub groups: Vec<Group>,
+    pub entries: Vec<Entry>,
+}
+
+impl Group {
+    pub fn group(&self, uuid: &str) -> Option<&Group> {
+        if self.uuid == uuid {
+            return Some(self);
+        }
+        self.groups.iter().find_map(|g| g.group(uuid))
+    }
+
+    pub fn group_mut(&mut self, uuid: &str) -> Option<&mut Group> {
+        if self.uuid == uuid {
+            return Some(self);
+        }
+        self.groups.iter_mut().find_map(|g| g.group_mut(uuid))
+    }
+
+    pub fn parent(&self, uuid: &str) -> Option<&Group> {
+        if self.entries.iter().any(|e| e.uuid == uuid) || self.groups.iter().any(|g| g.uuid == uuid)
+        {
+            return Some(self);
+        }
+        self.groups.iter().find_map(|g| g.parent(uuid))
+    }
+
+    pub fn entry_mut(&mut self, uuid: &str) -> Option<&mut Entry> {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.uuid == uuid) {
+            return Some(entry);
+        }
+        self.groups.iter_mut().find_map(|g| g.entry_mut(uuid))
+    }
+
+    pub fn take_entry(&mut self, uuid: &str) -> Option<Entry> {
+        if let Some(idx) = self.entries.iter().position(|e| e.uuid == uuid) {
+            return Some(self.entries.remove(idx));
+        }
+        self.groups.iter_mut().find_map(|g| g.take_entry(uuid))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Database {
+    pub header: Header,
+    pub meta: Vec<(String, String)>,
+    pub binaries: Vec<Vec<u8>>,
+    pub root: Group,
+    pub deleted: Option<String>,
+}
+
+impl Database {
+    pub fn new(name: &str, now: u64) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut kdf = HashMap::new();
+        kdf.insert("$UUID".into(), Variant::Bytes(KDF_ARGON2ID.to_vec()));
+        kdf.insert("S".into(), Variant::Bytes(rng.gen::<[u8; 32]>().to_vec()));
+        kdf.insert("M".into(), Variant::U64(64 * 1024 * 1024));
+        kdf.insert("I".into(), Variant::U64(3));
+        kdf.insert("P".into(), Variant::U32(2));
+        kdf.insert("V".into(), Variant::U32(0x13));
+        let mut db = Self {
+            header: Header {
+                cipher: CIPHER_CHACHA20,
+                compressed: true,
+                seed: Vec::new(),
+                iv: Vec::new(),
+                kdf,
+            },
+            meta: Vec::new(),
+            binaries: Vec::new(),
+            root: Group {
+                uuid: newuuid(),
+                name: name.to_string(),
+                ..Group::default()
+            },
+            deleted: None,
+        };
+        db.setmeta("Generator", "noro");
+        db.setmeta("DatabaseName", name);
+        db.setmeta("RecycleBinEnabled", "True");
+        db.root.extra.push(timesxml(now));
+        db
+    }
+
+    pub fn metatext(&self, name: &str) -> Option<String> {
+        let (_, raw) = self.meta.iter().find(|(k, _)| k == name)?;
+        let mut reader = Reader::from_str(raw);
+        match reader.read_event().ok()? {
+            Event::Start(_) => readtext(&mut reader).ok(),
+            _ => Some(String::new()),
+        }
+    }
+
+    pub fn setmeta(&mut self, name: &str, value: &str) {
+        let raw = format!("<{name}>{}</{name}>", escape(value));
+        match self.meta.iter_mut().find(|(k, _)| k == name) {
+            Some((_, v)) => *v = raw,
+            None => self.meta.push((name.to_string(), raw)),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.metatext("DatabaseName").unwrap_or_default()
+    }
+
+    pub fn recyclebin(&self) -> Option<String> {
+        self.metatext("RecycleBinUUID")
+            .filter(|id| id.trim_end_matches('=').chars().any(|c| c != 'A'))
+    }
+}
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ImportError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(truncated)?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, ImportError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ImportError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, ImportError> {
+        Ok(i32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+fn truncated() -> ImportError {
+    ImportError::Format("truncated database".into())
+}
+
+pub fn parsevariants(bytes: &[u8]) -> Result<HashMap<String, Variant>, ImportError> {
+    let mut cur = Cursor { buf: bytes, pos: 0 };
+    let version = u16::from_le_bytes(cur.take(2)?.try_into().unwrap());
+    if version >> 8 != 1 {
+        return Err(ImportError::Unsupported(
+            "variant dictionary version".into(),
+        ));
+    }
+    let mut map = HashMap::new();
+    loop {
+        let kind = cur.u8()?;
+        if kind == 0 {
+            break;
+        }
+        let keylen = cur.i32()? as usize;
+        let key = String::from_utf8_lossy(cur.take(keylen)?).into_owned();
+        let len = cur.i32()? as usize;
+        let value = cur.take(len)?;
+        let fixed = |n: usize| value.get(..n).ok_or_else(truncated);
+        let variant = match kind {
+            0x04 => Variant::U32(u32::from_le_bytes(fixed(4)?.try_into().unwrap())),
+            0x05 => Variant::U64(u64::from_le_bytes(fixed(8)?.try_into().unwrap())),
+            0x08 => Variant::Bool(value.first().copied().unwrap_or(0) != 0),
+            0x0c => Variant::I32(i32::from_le_bytes(fixed(4)?.try_into().unwrap())),
+            0x0d => Variant::I64(i64::from_le_bytes(fixed(8)?.try_into().unwrap())),
+            0x18 => Variant::Str(String::from_utf8_lossy(value).into_owned()),
+            0x42 => Variant::Bytes(value.to_vec()),
+            _ => continue,
+        };
+        map.insert(key, variant);
+    }
+    Ok(map)
+}
+
+fn keyfilehash(keyfile: &[u8]) -> Vec<u8> {
+    if let Ok(text) = std::str::from_utf8(keyfile) {
+        if text.contains("<KeyFile>") {
+            if let Some(start) = text.find("<Data") {
+                let rest = &text[start..];
+                if let (Some(open), Some(close)) = (rest.find('>'), rest.find("</Data>")) {
+                    let data: String = rest[open + 1..close].split_whitespace().collect();
+                    let decoded = if rest[..open].contains("Hash=") {
+                        hexdecode(&data)
+                    } else {
+                        STANDARD.decode(&data).ok()
+                    };
+                    if let Some(key) = decoded {
+                        return key;
+                    }
+                }
+            }
+        }
+        if keyfile.len() == 64 {
+            if let Some(key) = hexdecode(text) {
+                return key;
+            }
+        }
+    }
+    if keyfile.len() == 32 {
+        return keyfile.to_vec();
+    }
+    Sha256::digest(keyfile).to_vec()
+}
+
+fn hexdecode(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+pub fn compositekey(password: &str, keyfile: Option<&[u8]>) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(Sha256::digest(password.as_bytes()));
+    if let Some(keyfile) = keyfile {
+        hasher.update(keyfilehash(keyfile));
+    }
+    hasher.finalize().into()
+}
+
+pub fn transformkey(
+    composite: &[u8; 32],
+    kdf: &HashMap<String, Variant>,
+) -> Result<[u8; 32], ImportError> {
+    let uuid = match kdf.get("$UUID") {
+        Some(Variant::Bytes(uuid)) => uuid.as_slice(),
+        _ => return Err(ImportError::Format("missing kdf".into())),
+    };
+    let bytes = |key: &str| match kdf.get(key) {
+        Some(Variant::Bytes(b)) => Ok(b.clone()),
+        _ => Err(ImportError::Format(format!("missing kdf parameter {key}"))),
+    };
+    let number = |key: &str| match kdf.get(key) {
+        Some(Variant::U64(n)) => Ok(*n),
+        Some(Variant::U32(n)) => Ok(*n as u64),
+        _ => Err(ImportError::Format(format!("missing kdf parameter {key}"))),
+    };
+
+    if uuid == KDF_AES {
+        let seed = bytes("S")?;
+        let rounds = number("R")?;
+        let cipher = Aes256::new_from_slice(&seed).map_err(formaterr)?;
+        let mut key = *composite;
+        for _ in 0..rounds {
+            for block in key.chunks_exact_mut(16) {
+                cipher.encrypt_block(block.into());
+            }
+        }
+        return Ok(Sha256::digest(key).into());
+    }
+
+    let algorithm = if uuid == KDF_ARGON2D {
+        argon2::Algorithm::Argon2d
+    } else if uuid == KDF_ARGON2ID {
+        argon2::Algorithm::Argon2id
+    } else {
+        return Err(ImportError::Unsupported("key derivation function".into()));
+    };
+    let version = match number("V").unwrap_or(0x13) {
+        0x10 => Version::V0x10,
+        _ => Version::V0x13,
+    };
+    let params = Params::new(
+        (number("M")? / 1024) as u32,
+        number("I")? as u32,
+        number("P")? as u32,
+        Some(32),
+    )
+    .map_err(formaterr)?;
+    let mut key = [0u8; 32];
+    Argon2::new(algorithm, version, params)
+        .hash_password_into(composite, &bytes("S")?, &mut key)
+        .map_err(formaterr)?;
+    Ok(key)
+}
+
+pub fn blockkey(base: &[u8], index: u64) -> Vec<u8> {
+    let mut hasher = Sha512::new();
+    hasher.update(index.to_le_bytes());
+    hasher.update(base);
+    hasher.finalize().to_vec()
+}
+
+pub fn hmacbase(seed: &[u8], transformed: &[u8; 32]) -> Vec<u8> {
+    let mut hasher = Sha512::new();
+    hasher.update(seed);
+    hasher.update(transformed);
+    hasher.update([1u8]);
+    hasher.finalize().to_vec()
+}
+
+pub fn cipherkey(seed: &[u8], transformed: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    hasher.update(transformed);
+    hasher.finalize().into()
+}
+
+pub fn sign(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("hmac accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn readheader(cur: &mut Cursor) -> Result<Header, ImportError> {
+    if cur.take(8)? != SIGNATURE {
+        return Err(ImportError::Format("not a keepass database".into()));
+    }
+    let version = cur.u32()?;
+    if version >> 16 != 4 {
+        return Err(ImportError::Unsupported(format!(
+            "kdbx version {}.{}",
+            version >> 16,
+            version & 0xffff
+        )));
+    }
+    let mut header = Header {
+        cipher: [0; 16],
+        compressed: false,
+        seed: Vec::new(),
+        iv: Vec::new(),
+        kdf: HashMap::new(),
+    };
+    loop {
+        let id = cur.u8()?;
+        let len = cur.u32()? as usize;
+        let data = cur.take(len)?;
+        match id {
+            HEADER_END => break,
+            HEADER_CIPHER => {
+                header.cipher = data
+                    .try_into()
+                    .map_err(|_| ImportError::Format("invalid cipher".into()))?
+            }
+            HEADER_COMPRESSION => header.compressed = data.first().copied().unwrap_or(0) == 1,
+            HEADER_SEED => header.seed = data.to_vec(),
+            HEADER_IV => header.iv = data.to_vec(),
+            HEADER_KDF => header.kdf = parsevariants(data)?,
+            _ => {}
+        }
+    }
+    Ok(header)
+}
+
+fn readblocks(cur: &mut Cursor, base: &[u8]) -> Result<Vec<u8>, ImportError> {
+    let mut data = Vec::new();
+    for index in 0u64.. {
+        let tag = cur.take(32)?;
+        let size = cur.i32()?;
+        let block = cur.take(size.max(0) as usize)?;
+        let expected = sign(
+            &blockkey(base, index),
+            &[&index.to_le_bytes(), &size.to_le_bytes(), block],
+        );
+        if expected != tag {
+            return Err(ImportError::Format("block authentication failed".into()));
+        }
+        if size == 0 {
+            break;
+        }
+        data.extend_from_slice(block);
+    }
+    Ok(data)
+}
+
+fn decrypt(header: &Header, key: &[u8; 32], data: Vec<u8>) -> Result<Vec<u8>, ImportError> {
+    if header.cipher == CIPHER_AES256 {
+        cbc::Decryptor::<Aes256>::new_from_slices(key, &header.iv)
+            .map_err(formaterr)?
+            .decrypt_padded_vec_mut::<Pkcs7>(&data)
+            .map_err(|_| ImportError::Format("decryption failed".into()))
+    } else if header.cipher == CIPHER_CHACHA20 {
+        let mut data = data;
+        ChaCha20::new_from_slices(key, &header.iv)
+            .map_err(formaterr)?
+            .apply_keystream(&mut data);
+        Ok(data)
+    } else {
+        Err(ImportError::Unsupported("cipher".into()))
+    }
+}
+
+pub fn innerstream(id: u32, key: &[u8]) -> Result<Box<dyn StreamCipher>, ImportError> {
+    match id {
+        STREAM_CHACHA20 => {
+            let hash = Sha512::digest(key);
+            Ok(Box::new(
+                ChaCha20::new_from_slices(&hash[..32], &hash[32..44]).map_err(formaterr)?,
+            ))
+        }
+        STREAM_SALSA20 => Ok(Box::new(
+            Salsa20::new_from_slices(&Sha256::digest(key), &SALSA20_NONCE).map_err(formaterr)?,
+        )),
+        _ => Err(ImportError::Unsupported("inner stream cipher".into())),
+    }
+}
+
+pub fn read(bytes: &[u8], composite: &[u8; 32]) -> Result<Database, ImportError> {
+    let mut cur = Cursor { buf: bytes, pos: 0 };
+    let header = readheader(&mut cur)?;
+    let headerbytes = &bytes[..cur.pos];
+    if Sha256::digest(headerbytes).as_slice() != cur.take(32)? {
+        return Err(ImportError::Format("header checksum mismatch".into()));
+    }
+
+    let transformed = transformkey(composite, &header.kdf)?;
+    let base = hmacbase(&header.seed, &transformed);
+    let headermac = cur.take(32)?;
+    if sign(&blockkey(&base, u64::MAX), &[headerbytes]) != headermac {
+        return Err(ImportError::InvalidPassword);
+    }
+
+    let payload = decrypt(
+        &header,
+        &cipherkey(&header.seed, &transformed),
+        readblocks(&mut cur, &base)?,
+    )?;
+    let payload = if header.compressed {
+        let mut out = Vec::new();
+        GzDecoder::new(payload.as_slice())
+            .read_to_end(&mut out)
+            .map_err(formaterr)?;
+        out
+    } else {
+        payload
+    };
+
+    let mut inner = Cursor {
+        buf: &payload,
+        pos: 0,
+    };
+    let mut streamid = 0;
+    let mut streamkey = Vec::new();
+    let mut binaries = Vec::new();
+    loop {
+        let id = inner.u8()?;
+        let len = inner.u32()? as usize;
+        let data = inner.take(len)?;
+        match id {
+            INNER_END => break,
+            INNER_STREAM_ID => {
+                streamid = u32::from_le_bytes(data.try_into().map_err(|_| truncated())?)
+            }
+            INNER_STREAM_KEY => streamkey = data.to_vec(),
+            INNER_BINARY => binaries.push(data.to_vec()),
+            _ => {}
+        }
+    }
+    let xml = std::str::from_utf8(&payload[inner.pos..]).map_err(formaterr)?;
+    let mut stream = innerstream(streamid, &streamkey)?;
+    parsexml(xml, stream.as_mut(), header, binaries)
+}
+
+const EPOCH_OFFSET: i64 = 62_135_596_800;
+
+pub fn encodetime(unix: u64) -> String {
+    STANDARD.encode((unix as i64 + EPOCH_OFFSET).to_le_bytes())
+}
+
+pub fn decodetime(text: &str) -> Option<u64> {
+    let bytes: [u8; 8] = STANDARD.decode(text.trim()).ok()?.try_into().ok()?;
+    u64::try_from(i64::from_le_bytes(bytes) - EPOCH_OFFSET).ok()
+}
+
+pub fn newuuid() -> String {
+    STANDARD.encode(rand::thread_rng().gen::<[u8; 16]>())
+}
+
+fn timesxml(now: u64) -> String {
+    let time = encodetime(now);
+    format!(
+        "<Times><CreationTime>{time}</CreationTime><LastModificationTime>{time}</LastModificationTime>\
+         <LastAccessTime>{time}</LastAccessTime><ExpiryTime>{time}</ExpiryTime><Expires>False</Expires>\
+         <UsageCount>0</UsageCount><LocationChanged>{time}</LocationChanged></Times>"
+    )
+}
+
+fn isprotected(e: &BytesStart) -> bool {
+    e.attributes()
+        .flatten()
+        .any(|a| a.key.as_ref() == b"Protected" && a.value.as_ref().eq_ignore_ascii_case(b"true"))
+}
+
+fn unprotect(value: &str, stream: &mut dyn StreamCipher) -> Result<String, ImportError> {
+    let mut bytes = STANDARD.decode(value.trim()).map_err(formaterr)?;
+    stream.apply_keystream(&mut bytes);
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn protect(value: &str, stream: &mut dyn StreamCipher) -> String {
+    let mut bytes = value.as_bytes().to_vec();
+    stream.apply_keystream(&mut bytes);
+    STANDARD.encode(bytes)
+}
+
+fn tagname(e: &BytesStart) -> String {
+    String::from_utf8_lossy(e.name().as_ref()).into_owned()
+}
+
+fn readtext(reader: &mut Reader<&[u8]>) -> Result<String, ImportError> {
+    let mut text = String::new();
+    loop {
+        match reader.read_event().map_err(formaterr)? {
+            Event::Text(t) => text.push_str(&t.unescape().map_err(formaterr)?),
+            Event::CData(c) => text.push_str(&String::from_utf8_lossy(&c)),
+            Event::Start(e) => {
+                reader.read_to_end(e.name()).map_err(formaterr)?;
+            }
+            Event::End(_) => return Ok(text),
+            Event::Eof => return Err(formaterr("unexpected end of xml")),
+            _ => {}
+        }
+    }
+}
+
+fn capture(reader: &mut Reader<&[u8]>, start: BytesStart) -> Result<String, ImportError> {
+    let name = start.name().as_ref().to_vec();
+    let mut writer = Writer::new(Vec::new());
+    writer.write_event(Event::Start(start)).map_err(formaterr)?;
+    let mut depth = 0usize;
+    loop {
+        let event = reader.read_event().map_err(formaterr)?;
+        match &event {
+            Event::Start(e) if e.name().as_ref() == name => depth += 1,
+            Event::End(e) if e.name().as_ref() == name => {
+                if depth == 0 {
+                    writer.write_event(event).map_err(formaterr)?;
+                    break;
+                }
+                depth -= 1;
+            }
+            Event::Eof => return Err(formaterr("unexpected end of xml")),
+            _ => {}
+        }
+        writer.write_event(event).map_err(formaterr)?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(formaterr)
+}
+
+fn rawempty(e: BytesStart) -> Result<String, ImportError> {
+    let mut writer = Writer::new(Vec::new());
+    writer.write_event(Event::Empty(e)).map_err(formaterr)?;
+    String::from_utf8(writer.into_inner()).map_err(formaterr)
+}
+
+fn parsestring(
+    reader: &mut Reader<&[u8]>,
+    stream: &mut dyn StreamCipher,
+    entry: &mut Entry,
+) -> Result<(), ImportError> {
+    let mut key = String::new();
+    let mut value = String::new();
+    let mut protected = false;
+    loop {
+        match reader.read_event().map_err(formaterr)? {
+            Event::Start(e) if e.name().as_ref() == b"Key" => key = readtext(reader)?,
+            Event::Start(e) if e.name().as_ref() == b"Value" => {
+                protected = isprotected(&e);
+                let text = readtext(reader)?;
+                value = if protected {
+                    unprotect(&text, stream)?
+                } else {
+                    text
+                };
+            }
+            Event::Empty(e) if e.name().as_ref() == b"Value" => protected = isprotected(&e),
+            Event::Start(e) => {
+                reader.read_to_end(e.name()).map_err(formaterr)?;
+            }
+            Event::End(_) => break,
+            Event::Eof => return Err(formaterr("unexpected end of xml")),
+            _ => {}
+        }
+    }
+    entry.set(&key, &value, protected);
+    Ok(())
+}
+
+fn parsetimes(reader: &mut Reader<&[u8]>) -> Result<Vec<(String, String)>, ImportError> {
+    let mut times = Vec::new();
+    loop {
+        match reader.read_event().map_err(formaterr)? {
+            Event::Start(e) => times.push((tagname(&e), readtext(reader)?)),
+            Event::Empty(e) => times.push((tagname(&e), String::new())),
+            Event::End(_) => return Ok(times),
+            Event::Eof => return Err(formaterr("unexpected end of xml")),
+            _ => {}
+        }
+    }
+}
+
+fn parseentry(
+    reader: &mut Reader<&[u8]>,
+    stream: &mut dyn StreamCipher,
+) -> Result<Entry, ImportError> {
+    let mut entry = Entry::default();
+    loop {
+        match reader.read_event().map_err(formaterr)? {
+            Event::Start(e) => match e.name().as_ref() {
+                b"UUID" => entry.uuid = readtext(reader)?,
+                b"Tags" => {
+                    entry.tags = readtext(reader)?
+                        .split([';', ','])
+                        .map(|t| t.trim().to_string())
+                        .filter(|t| !t.is_empty())
+                        .collect()
+                }
+                b"String" => parsestring(reader, stream, &mut entry)?,
+                b"Times" => entry.times = parsetimes(reader)?,
+                b"History" => loop {
+                    match reader.read_event().map_err(formaterr)? {
+                        Event::Start(e) if e.name().as_ref() == b"Entry" => {
+                            entry.history.push(parseentry(reader, stream)?)
+                        }
+                        Event::End(_) => break,
+                        Event::Eof => return Err(formaterr("unexpected end of xml")),
+                        _ => {}
+                    }
+                },
+                _ => entry.extra.push(capture(reader, e)?),
+            },
+            Event::Empty(e)
+                if !matches!(e.name().as_ref(), b"UUID" | b"Tags" | b"Times" | b"History") =>
+            {
+                entry.extra.push(rawempty(e)?)
+            }
+            Event::End(_) => return Ok(entry),
+            Event::Eof => return Err(formaterr("unexpected end of xml")),
+            _ => {}
+        }
+    }
+}
+
+fn parsegroup(
+    reader: &mut Reader<&[u8]>,
+    stream: &mut dyn StreamCipher,
+) -> Result<Group, ImportError> {
+    let mut group = Group::default();
+    loop {
+        match reader.read_event().map_err(formaterr)? {
+            Event::Start(e) => match e.name().as_ref() {
+                b"UUID" => group.uuid = readtext(reader)?,
+                b"Name" => group.name = readtext(reader)?,
+                b"Entry" => group.entries.push(parseentry(reader, stream)?),
+                b"Group" => group.groups.push(parsegroup(reader, stream)?),
+                _ => group.extra.push(capture(reader, e)?),
+            },
+            Event::Empty(e) if !matches!(e.name().as_ref(), b"UUID" | b"Name") => {
+                group.extra.push(rawempty(e)?)
+            }
+            Event::End(_) => return Ok(group),
+            Event::Eof => return Err(formaterr("unexpected end of xml")),
+            _ => {}
+        }
+    }
+}
+
+fn parsexml(
+    xml: &str,
+    stream: &mut dyn StreamCipher,
+    header: Header,
+    binaries: Vec<Vec<u8>>,
+) -> Result<Database, ImportError> {
+    let mut reader = Reader::from_str(xml);
+    let mut meta = Vec::new();
+    let mut root = None;
+    let mut deleted = None;
+    let mut inmeta = false;
+    loop {
+        match reader.read_event().map_err(formaterr)? {
+            Event::Start(e) => match e.name().as_ref() {
+                b"KeePassFile" | b"Root" => {}
+                b"Meta" => inmeta = true,
+                b"Group" => root = Some(parsegroup(&mut reader, stream)?),
+                b"DeletedObjects" => deleted = Some(capture(&mut reader, e)?),
+                _ if inmeta => meta.push((tagname(&e), capture(&mut reader, e)?)),
+                _ => {
+                    reader.read_to_end(e.name()).map_err(formaterr)?;
+                }
+            },
+            Event::Empty(e) if inmeta => meta.push((tagname(&e), rawempty(e)?)),
+            Event::End(e) if e.name().as_ref() == b"Meta" => inmeta = false,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(Database {
+        header,
+        meta,
+        binaries,
+        root: root.ok_or_else(|| ImportError::Format("missing root group".into()))?,
+        deleted,
+    })
+}
+
+fn writeentry(out: &mut String, entry: &Entry, stream: &mut dyn StreamCipher) {
+    out.push_str(&format!("<Entry><UUID>{}</UUID>", escape(&entry.uuid)));
+    for raw in &entry.extra {
+        out.push_str(raw);
+    }
+    if !entry.tags.is_empty() {
+        out.push_str(&format!("<Tags>{}</Tags>", escape(entry.tags.join(";"))));
+    }
+    if !entry.times.is_empty() {
+        out.push_str("<Times>");
+        for (key, value) in &entry.times {
+            out.push_str(&format!("<{key}>{}</{key}>", escape(value)));
+        }
+        out.push_str("</Times>");
+    }
+    for (key, value) in &entry.fields {
+        let value = if entry.protected.contains(key) {
+            format!(
+                "<Value Protected=\"True\">{}</Value>",
+                protect(value, stream)
+            )
+        } else {
+            format!("<Value>{}</Value>", escape(value))
+        };
+        out.push_str(&format!(
+            "<String><Key>{}</Key>{value}</String>",
+            escape(key)
+        ));
+    }
+    if !entry.history.is_empty() {
+        out.push_str("<History>");
+        for old in &entry.history {
+            writeentry(out, old, stream);
+        }
+        out.push_str("</History>");
+    }
+    out.push_str("</Entry>");
+}
+
+fn writegroup(out: &mut String, group: &Group, stream: &mut dyn StreamCipher) {
+    out.push_str(&format!(
+        "<Group><UUID>{}</UUID><Name>{}</Name>",
+        escape(&group.uuid),
+        escape(&group.name)
+    ));
+    for raw in &group.extra {
+        out.push_str(raw);
+    }
+    for entry in &group.entries {
+        writeentry(out, entry, stream);
+    }
+    for child in &group.groups {
+        writegroup(out, child, stream);
+    }
+    out.push_str("</Group>");
+}
+
+fn writexml(db: &Database, stream: &mut dyn StreamCipher) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>\n<KeePassFile><Meta>",
+    );
+    for (_, raw) in &db.meta {
+        out.push_str(raw);
+    }
+    out.push_str("</Meta><Root>");
+    writegroup(&mut out, &db.root, stream);
+    out.push_str(db.deleted.as_deref().unwrap_or("<DeletedObjects/>"));
+    out.push_str("</Root></KeePassFile>");
+    out
+}
+
+fn field(out: &mut Vec<u8>, id: u8, data: &[u8]) {
+    out.push(id);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+}
+
+pub fn writevariants(map: &HashMap<String, Variant>) -> Vec<u8> {
+    let mut out = 0x0100u16.to_le_bytes().to_vec();
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+    for key in keys {
+        let (kind, value) = match &map[key] {
+            Variant::U32(n) => (0x04, n.to_le_bytes().to_vec()),
+            Variant::U64(n) => (0x05, n.to_le_bytes().to_vec()),
+            Variant::Bool(b) => (0x08, vec![*b as u8]),
+            Variant::I32(n) => (0x0c, n.to_le_bytes().to_vec()),
+            Variant::I64(n) => (0x0d, n.to_le_bytes().to_vec()),
+            Variant::Str(s) => (0x18, s.as_bytes().to_vec()),
+            Variant::Bytes(b) => (0x42, b.clone()),
+        };
+        out.push(kind);
+        out.extend_from_slice(&(key.len() as i32).to_le_bytes());
+        out.extend_from_slice(key.as_bytes());
+        out.extend_from_slice(&(value.len() as i32).to_le_bytes());
+        out.extend_from_slice(&value);
+    }
+    out.push(0);
+    out
+}
+
+fn encrypt(header: &Header, key: &[u8; 32], data: Vec<u8>) -> Result<Vec<u8>, ImportError> {
+    if header.cipher == CIPHER_AES256 {
+        Ok(cbc::Encryptor::<Aes256>::new_from_slices(key, &header.iv)
+            .map_err(formaterr)?
+            .encrypt_padded_vec_mut::<Pkcs7>(&data))
+    } else if header.cipher == CIPHER_CHACHA20 {
+        let mut data = data;
+        ChaCha20::new_from_slices(key, &header.iv)
+            .map_err(formaterr)?
+            .apply_keystream(&mut data);
+        Ok(data)
+    } else {
+        Err(ImportError::Unsupported("cipher".into()))
+    }
+}
+
+pub fn write(db: &Database, composite: &[u8; 32]) -> Result<Vec<u8>, ImportError> {
+    let mut rng = rand::thread_rng();
+    let mut header = db.header.clone();
+    header.seed = rng.gen::<[u8; 32]>().to_vec();
+    header.iv = if header.cipher == CIPHER_CHACHA20 {
+        rng.gen::<[u8; 12]>().to_vec()
+    } else {
+        rng.gen::<[u8; 16]>().to_vec()
+    };
+    if let Some(Variant::Bytes(salt)) = header.kdf.get_mut("S") {
+        rng.fill_bytes(salt);
+    }
+
+    let mut out = SIGNATURE.to_vec();
+    out.extend_from_slice(&((4u32 << 16) | 1).to_le_bytes());
+    field(&mut out, HEADER_CIPHER, &header.cipher);
+    field(
+        &mut out,
+        HEADER_COMPRESSION,
+        &(header.compressed as u32).to_le_bytes(),
+    );
+    field(&mut out, HEADER_SEED, &header.seed);
+    field(&mut out, HEADER_IV, &header.iv);
+    field(&mut out, HEADER_KDF, &writevariants(&header.kdf));
+    field(&mut out, HEADER_END, b"\r\n\r\n");
+
+    let transformed = transformkey(composite, &header.kdf)?;
+    let base = hmacbase(&header.seed, &transformed);
+    let headermac = sign(&blockkey(&base, u64::MAX), &[&out]);
+    out.extend_from_slice(&Sha256::digest(&out));
+    out.extend_from_slice(&headermac);
+
+    let mut streamkey = [0u8; 64];
+    rng.fill_bytes(&mut streamkey);
+    let mut payload = Vec::new();
+    field(
+        &mut payload,
+        INNER_STREAM_ID,
+        &STREAM_CHACHA20.to_le_bytes(),
+    );
+    field(&mut payload, INNER_STREAM_KEY, &streamkey);
+    for binary in &db.binaries {
+        field(&mut payload, INNER_BINARY, binary);
+    }
+    field(&mut payload, INNER_END, &[]);
+    let mut stream = innerstream(STREAM_CHACHA20, &streamkey)?;
+    payload.extend_from_slice(writexml(db, stream.as_mut()).as_bytes());
+    if header.compressed {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&payload).map_err(formaterr)?;
+        payload = encoder.finish().map_err(formaterr)?;
+    }
+
+    let encrypted = encrypt(&header, &cipherkey(&header.seed, &transformed), payload)?;
+    for (index, block) in encrypted
+        .chunks(BLOCK_SIZE)
+        .chain(std::iter::once(&[][..]))
+        .enumerate()
+    {
+        let index = index as u64;
+        let size = block.len() as i32;
+        out.extend_from_slice(&sign(
+            &blockkey(&base, index),
+            &[&index.to_le_bytes(), &size.to_le_bytes(), block],
+        ));
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    Ok(out)
+}
+
+const STANDARD_FIELDS: &[&str] = &["Title", "UserName", "Password", "URL", "Notes", "otp"];
+
+fn entryitem(entry: &Entry, folder: &str) -> ItemBuilder {
+    let title = entry.get("Title").unwrap_or_default();
+    let totp = entry
+        .get("otp")
+        .map(String::from)
+        .or_else(|| entry.get("TimeOtp-Secret-Base32").map(String::from));
+    let islogin = entry.get("UserName").is_some_and(|v| !v.is_empty())
+        || entry.get("Password").is_some_and(|v| !v.is_empty())
+        || entry.get("URL").is_some_and(|v| !v.is_empty())
+        || totp.is_some();
+    let item = if islogin {
+        ItemBuilder::new("login", title)
+            .field("username", entry.get("UserName"))
+            .field("password", entry.get("Password"))
+            .field("url", entry.get("URL"))
+            .field("totp", totp.as_deref())
+            .field("notes", entry.get("Notes"))
+    } else {
+        ItemBuilder::new("note", title).field("content", entry.get("Notes"))
+    };
+    entry
+        .fields
+        .iter()
+        .filter(|(k, _)| !STANDARD_FIELDS.contains(&k.as_str()) && !k.starts_with("TimeOtp-"))
+        .fold(item, |item, (k, v)| item.custom(k, v))
+        .tags(entry.tags.clone())
+        .folder(Some(folder))
+}
+
+fn walk(
+    db: &Database,
+    group: &Group,
+    folder: &str,
+    items: &mut Vec<ImportedItem>,
+    skipped: &mut Vec<String>,
+) {
+    if db.recyclebin().as_deref() == Some(group.uuid.as_str()) {
+        if !group.entries.is_empty() {
+            skipped.push(format!(
+                "{}: {} entries in recycle bin",
+                group.name,
+                group.entries.len()
+            ));
+        }
+        return;
+    }
+    for entry in &group.entries {
+        items.push(entryitem(entry, folder).build());
+    }
+    for child in &group.groups {
+        let path = if folder.is_empty() {
+            child.name.clone()
+        } else {
+            format!("{folder}/{}", child.name)
+        };
+        walk(db, child, &path, items, skipped);
+    }
+}
+
+pub fn import(db: &Database, skipped: &mut Vec<String>) -> Vec<ImportedItem> {
+    let mut items = Vec::new();
+    walk(db, &db.root, "", &mut items, skipped);
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = include_bytes!("testdata/sample.kdbx");
+
+    #[test]
+    fn test_read_kdbx4() {
+        let wrong = compositekey("wrong", None);
+        assert!(matches!(
+            read(SAMPLE, &wrong),
+            Err(ImportError::InvalidPassword)
+        ));
+
+        let db = read(SAMPLE, &compositekey("demo", None)).unwrap();
+        assert_eq!(db.name(), "Sample");
+        let mut skipped = Vec::new();
+        let items = import(&db, &mut skipped);
+        assert_eq!(items.len(), 2);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(items[0].folder.as_deref(), Some("Email"));
+        let login: serde_json::Value = serde_json::from_str(&items[0].data).unwrap();
+        assert_eq!(login["username"], "alice");
+        assert_eq!(login["password"], "s3cr&t");
+        assert_eq!(login["fields"][0]["value"], "1234");
+        assert_eq!(items[0].tags, vec!["mail".to_string(), "work".to_string()]);
+        assert_eq!(items[1].item_type, "note");
+    }
+
+    fn entry(fields: &[(&str, &str)]) -> Entry {
+        let mut entry = Entry {
+            uuid: newuuid(),
+            ..Entry::default()
+        };
+        for (key, value) in fields {
+            entry.set(key, value, false);
+        }
+        entry
+    }
+
+    fn group(name: &str, entries: Vec<Entry>, groups: Vec<Group>) -> Group {
+        Group {
+            uuid: newuuid(),
+            name: name.to_string(),
+            entries,
+            groups,
+            ..Group::default()
+        }
+    }
+
+    #[test]
+    fn test_import_folder_mapping() {
+        let mut db = Database::new("Vault", 0);
+        let bin = group("Recycle Bin", vec![entry(&[("Title", "old")])], vec![]);
+        db.setmeta("RecycleBinUUID", &bin.uuid);
+        let work = group(
+            "Work",
+            vec![entry(&[("Title", "vpn"), ("Password", "pw")])],
+            vec![group("Email", vec![entry(&[("Title", "mail")])], vec![])],
+        );
+        db.root.entries.push(entry(&[("Title", "top")]));
+        db.root.groups = vec![work, bin];
+
+        let mut skipped = Vec::new();
+        let items = import(&db, &mut skipped);
+        let folders: Vec<Option<&str>> = items.iter().map(|i| i.folder.as_deref()).collect();
+        assert_eq!(folders, [None, Some("Work"), Some("Work/Email")]);
+        assert_eq!(skipped, ["Recycle Bin: 1 entries in recycle bin"]);
+    }
+
+    #[test]
+    fn test_import_missing_fields() {
+        let mut db = Database::new("Vault", 0);
+        db.root.entries = vec![
+            Entry::default(),
+            entry(&[("Title", "otp"), ("TimeOtp-Secret-Base32", "JBSWY3DP")]),
+            entry(&[("Title", "blank"), ("UserName", ""), ("PIN", "1234")]),
+        ];
+        let mut skipped = Vec::new();
+        let items = import(&db, &mut skipped);
+        assert_eq!(items[0].title, "untitled");
+        assert_eq!(items[0].item_type, "note");
+        assert_eq!(items[1].item_type, "login");
+        assert_eq!(items[1].data, r#"{"totp":"JBSWY3DP"}"#);
+        assert_eq!(items[2].item_type, "note");
+        let blank: serde_json::Value = serde_json::from_str(&items[2].data).unwrap();
+        assert_eq!(blank["fields"][0]["name"], "PIN");
+    }
+
+    #[test]
+    fn test_read_malformed() {
+        let key = compositekey("demo", None);
+        assert!(matches!(
+            read(b"not a keepass database", &key),
+            Err(ImportError::Format(e)) if e == "not a keepass database"
+        ));
+        assert!(matches!(
+            read(&SAMPLE[..100], &key),
+            Err(ImportError::Format(e)) if e == "truncated database"
+        ));
+
+        let mut version = SAMPLE.to_vec();
+        version[10] = 3;
+        assert!(matches!(
+            read(&version, &key),
+            Err(ImportError::Unsupported(e)) if e.starts_with("kdbx version 3")
+        ));
+
+        let mut header = SAMPLE.to_vec();
+        header[20] ^= 0xff;
+        assert!(read(&header, &key).is_err());
+
+        let mut blocks = SAMPLE.to_vec();
+        let last = blocks.len() - 40;
+        blocks[last] ^= 0xff;
+        assert!(matches!(read(&blocks, &key), Err(ImportError::Format(_))));
+    }
+}
@@ -0,0 +1,201 @@
+use super::generic::{cell, table, truthy};
+use super::{ImportError, ImportedItem, ItemBuilder};
+
+fn isnote(url: Option<&str>) -> bool {
+    matches!(url, Some("http://sn" | "http://sn/"))
+}
+
+fn notefields(extra: &str) -> Option<(String, Vec<(String, String)>)> {
+    let mut lines = extra.lines();
+    let kind = lines.next()?.strip_prefix("NoteType:")?.trim().to_string();
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for line in lines {
+        match line.split_once(':') {
+            Some((key, value)) if key.len() < 32 => {
+                fields.push((key.trim().to_string(), value.trim().to_string()))
+            }
+            _ => {
+                if let Some((_, value)) = fields.last_mut() {
+                    value.push('\n');
+                    value.push_str(line);
+                }
+            }
+        }
+    }
+    Some((kind, fields))
+}
+
+fn securenote(name: &str, extra: &str) -> ItemBuilder {
+    let Some((kind, fields)) = notefields(extra) else {
+        return ItemBuilder::new("note", name).field("content", Some(extra));
+    };
+    let get = |key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    };
+    let known: &[&str] = match kind.as_str() {
+        "Credit Card" => &[
+            "Name on Card",
+            "Number",
+            "Security Code",
+            "Expiration Date",
+            "Notes",
+        ],
+        "Server" | "Database" => &["Hostname", "Username", "Password", "Notes"],
+        _ => &["Notes"],
+    };
+    let item = match kind.as_str() {
+        "Credit Card" => ItemBuilder::new("card", name)
+            .field("holder", get("Name on Card"))
+            .field("number", get("Number"))
+            .field("cvv", get("Security Code"))
+            .field("expiry", get("Expiration Date"))
+            .field("notes", get("Notes")),
+        "Server" | "Database" => ItemBuilder::new("login", name)
+            .field("url", get("Hostname"))
+            .field("username", get("Username"))
+            .field("password", get("Password"))
+            .field("notes", get("Notes")),
+        _ => ItemBuilder::new("note", name).field("content", get("Notes")),
+    };
+    fields
+        .iter()
+        .filter(|(k, _)| !known.contains(&k.as_str()))
+        .fold(item, |item, (k, v)| item.custom(k, v))
+}
+
+pub fn parse(bytes: &[u8], skipped: &mut Vec<String>) -> Result<Vec<ImportedItem>, ImportError> {
+    let table = table(bytes, b',')?;
+    let url = table.column(&["url"]);
+    let username = table.column(&["username"]);
+    let password = table.column(&["password"]);
+    let totp = table.column(&["totp"]);
+    let extra = table.column(&["extra"]);
+    let name = table.column(&["name"]);
+    let grouping = table.column(&["grouping"]);
+    let fav = table.column(&["fav"]);
+    if url.is_none() || name.is_none() {
+        return Err(ImportError::Format("missing lastpass columns".into()));
+    }
+
+    let mut items = Vec::new();
+    for (i, row) in table.rows.iter().enumerate() {
+        if row.iter().all(|v| v.trim().is_empty()) {
+            skipped.push(format!("row {}: empty", i + 2));
+            continue;
+        }
+        let title = cell(row, name)
+            .or_else(|| cell(row, url))
+            .unwrap_or("untitled");
+        let item = if isnote(cell(row, url)) {
+            securenote(title, cell(row, extra).unwrap_or_default())
+        } else {
+            ItemBuilder::new("login", title)
+                .field("username", cell(row, username))
+                .field("password", cell(row, password))
+                .field("url", cell(row, url))
+                .field("totp", cell(row, totp))
+                .field("notes", cell(row, extra))
+        };
+        items.push(
+            item.favorite(truthy(cell(row, fav)))
+                .folder(cell(row, grouping).map(|g| g.replace('\\', "/")).as_deref())
+                .build(),
+        );
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    const HEADER: &str = "url,username,password,totp,extra,name,grouping,fav\n";
+
+    fn data(item: &ImportedItem) -> Value {
+        serde_json::from_str(&item.data).unwrap()
+    }
+
+    fn import(rows: &str) -> (Vec<ImportedItem>, Vec<String>) {
+        let mut skipped = Vec::new();
+        let items = parse(format!("{HEADER}{rows}").as_bytes(), &mut skipped).unwrap();
+        (items, skipped)
+    }
+
+    #[test]
+    fn test_lastpass_logins() {
+        let (items, skipped) = import(
+            "https://example.com,alice,hunter2,JBSWY3DP,\"line one\nline two\",Example,,1\n\
+             http://sn,,,,secret note,Note,,0\n",
+        );
+        assert!(skipped.is_empty());
+        let login = data(&items[0]);
+        assert_eq!(login["username"], "alice");
+        assert_eq!(login["totp"], "JBSWY3DP");
+        assert_eq!(login["notes"], "line one\nline two");
+        assert!(items[0].favorite);
+        assert_eq!(items[1].item_type, "note");
+        assert_eq!(data(&items[1])["content"], "secret note");
+        assert!(!items[1].favorite);
+    }
+
+    #[test]
+    fn test_lastpass_secure_notes() {
+        let (items, _) = import(
+            "http://sn,,,,\"NoteType:Credit Card\nName on Card:Ann\nNumber:4111\nSecurity Code:123\nExpiration Date:March,2030\nNotes:first\nsecond\",Visa,,0\n\
+             http://sn,,,,\"NoteType:Server\nHostname:db.example.com\nUsername:root\nPassword:pw\nPort:5432\",DB,,0\n",
+        );
+        assert_eq!(items[0].item_type, "card");
+        let card = data(&items[0]);
+        assert_eq!(card["holder"], "Ann");
+        assert_eq!(card["cvv"], "123");
+        assert_eq!(card["expiry"], "March,2030");
+        assert_eq!(card["notes"], "first\nsecond");
+        assert_eq!(items[1].item_type, "login");
+        let server = data(&items[1]);
+        assert_eq!(server["url"], "db.example.com");
+        assert_eq!(server["fields"][0]["name"], "Port");
+    }
+
+    #[test]
+    fn test_lastpass_folder_mapping() {
+        let (items, _) = import(
+            "https://a.example,ann,pw,,,A,Work\\Email,0\n\
+             https://b.example,ann,pw,,,B,Personal,0\n\
+             https://c.example,ann,pw,,,C,,0\n",
+        );
+        assert_eq!(items[0].folder.as_deref(), Some("Work/Email"));
+        assert_eq!(items[1].folder.as_deref(), Some("Personal"));
+        assert_eq!(items[2].folder, None);
+    }
+
+    #[test]
+    fn test_lastpass_missing_fields() {
+        let (items, skipped) = import("https://a.example\n,,,,,,,\nhttp://sn\n");
+        assert_eq!(items[0].title, "https://a.example");
+        assert_eq!(data(&items[0])["url"], "https://a.example");
+        assert_eq!(skipped, ["row 3: empty"]);
+        assert_eq!(items[1].item_type, "note");
+        assert_eq!(data(&items[1])["content"], "");
+
+        let mut skipped = Vec::new();
+        let result = parse(
+            b"url,username,password\nhttps://a.example,ann,pw\n",
+            &mut skipped,
+        );
+        assert!(matches!(result, Err(ImportError::Format(e)) if e == "missing lastpass columns"));
+    }
+
+    #[test]
+    fn test_lastpass_malformed() {
+        let mut skipped = Vec::new();
+        let bytes = [HEADER.as_bytes(), b"https://a.example,\xff,pw,,,A,,0\n"].concat();
+        assert!(matches!(
+            parse(&bytes, &mut skipped),
+            Err(ImportError::Format(_))
+        ));
+    }
+}
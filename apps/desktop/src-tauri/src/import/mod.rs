@@ -0,0 +1,412 @@
+mod bitwarden;
+mod generic;
+pub mod kdbx;
+mod lastpass;
+mod onepux;
+
+use noro_common::time::now;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+
+use crate::storage::{self, VaultData, VaultEntry, VaultFolder};
+
+pub use generic::CsvMapping;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("invalid export: {0}")]
+    Format(String),
+    #[error("export is password protected")]
+    PasswordRequired,
+    #[error("invalid password")]
+    InvalidPassword,
+    #[error("unsupported export: {0}")]
+    Unsupported(String),
+    #[error("storage error: {0}")]
+    Storage(String),
+}
+
+impl Serialize for ImportError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+pub fn formaterr(e: impl std::fmt::Display) -> ImportError {
+    ImportError::Format(e.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Onepux,
+    Bitwarden,
+    Lastpass,
+    Kdbx,
+    Csv,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportOptions {
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub keyfile: Option<String>,
+    #[serde(default)]
+    pub mapping: Option<CsvMapping>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedItem {
+    pub item_type: String,
+    pub title: String,
+    pub data: String,
+    pub tags: Vec<String>,
+    pub favorite: bool,
+    pub folder: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    pub format: ImportFormat,
+    pub items: Vec<ImportedItem>,
+    pub counts: BTreeMap<String, u32>,
+    pub folders: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+pub struct ItemBuilder {
+    item_type: String,
+    title: String,
+    fields: Map<String, Value>,
+    custom: Vec<Value>,
+    tags: Vec<String>,
+    favorite: bool,
+    folder: Option<String>,
+}
+
+impl ItemBuilder {
+    pub fn new(item_type: &str, title: &str) -> Self {
+        let title = title.trim();
+        ItemBuilder {
+            item_type: item_type.to_string(),
+            title: if title.is_empty() {
+                "untitled".into()
+            } else {
+                title.to_string()
+            },
+            fields: Map::new(),
+            custom: Vec::new(),
+            tags: Vec::new(),
+            favorite: false,
+            folder: None,
+        }
+    }
+
+    pub fn field(mut self, key: &str, value: Option<&str>) -> Self {
+        if let Some(value) = value.filter(|v| !v.trim().is_empty()) {
+            self.fields
+                .insert(key.into(), Value::String(value.to_string()));
+        }
+        self
+    }
+
+    pub fn custom(mut self, name: &str, value: &str) -> Self {
+        if !value.trim().is_empty() {
+            let mut field = Map::new();
+            field.insert("name".into(), Value::String(name.to_string()));
+            field.insert("value".into(), Value::String(value.to_string()));
+            self.custom.push(Value::Object(field));
+        }
+        self
+    }
+
+    pub fn tags(mut self, tags: impl IntoIterator<Item = String>) -> Self {
+        for tag in tags {
+            let tag = tag.trim().to_string();
+            if !tag.is_empty() && !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+        self
+    }
+
+    pub fn favorite(mut self, favorite: bool) -> Self {
+        self.favorite = favorite;
+        self
+    }
+
+    pub fn folder(mut self, folder: Option<&str>) -> Self {
+        self.folder = folder
+            .map(|f| f.trim().trim_matches('/').to_string())
+            .filter(|f| !f.is_empty());
+        self
+    }
+
+    pub fn build(mut self) -> ImportedItem {
+        if self.item_type == "note" && !self.fields.contains_key("content") {
+            let content = self
+                .fields
+                .remove("notes")
+                .unwrap_or(Value::String(String::new()));
+            self.fields.insert("content".into(), content);
+        }
+        if !self.custom.is_empty() {
+            self.fields
+                .insert("fields".into(), Value::Array(self.custom));
+        }
+        ImportedItem {
+            item_type: self.item_type,
+            title: self.title,
+            data: Value::Object(self.fields).to_string(),
+            tags: self.tags,
+            favorite: self.favorite,
+            folder: self.folder,
+        }
+    }
+}
+
+pub fn detect(bytes: &[u8]) -> Option<ImportFormat> {
+    if bytes.starts_with(&kdbx::SIGNATURE) {
+        return Some(ImportFormat::Kdbx);
+    }
+    if bytes.starts_with(b"PK\x03\x04") {
+        return Some(ImportFormat::Onepux);
+    }
+    let text = std::str::from_utf8(bytes).ok()?;
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    if text.starts_with('{') {
+        let json: Value = serde_json::from_str(text).ok()?;
+        if json.get("encrypted").is_some() || json.get("items").is_some_and(Value::is_array) {
+            return Some(ImportFormat::Bitwarden);
+        }
+        return None;
+    }
+    let header = text.lines().next()?.to_lowercase();
+    if !header.contains(',') {
+        return None;
+    }
+    if header.contains("grouping") && header.contains("extra") {
+        return Some(ImportFormat::Lastpass);
+    }
+    Some(ImportFormat::Csv)
+}
+
+pub fn parse(
+    bytes: &[u8],
+    format: ImportFormat,
+    options: &ImportOptions,
+) -> Result<ImportReport, ImportError> {
+    let mut skipped = Vec::new();
+    let items = match format {
+        ImportFormat::Onepux => onepux::parse(bytes, &mut skipped)?,
+        ImportFormat::Bitwarden => {
+            bitwarden::parse(bytes, options.password.as_deref(), &mut skipped)?
+        }
+        ImportFormat::Lastpass => lastpass::parse(bytes, &mut skipped)?,
+        ImportFormat::Kdbx => {
+            let password = options
+                .password
+                .as_deref()
+                .ok_or(ImportError::PasswordRequired)?;
+            let keyfile = match &options.keyfile {
+                Some(path) => Some(fs::read(path).map_err(|e| ImportError::Io(e.to_string()))?),
+                None => None,
+            };
+            let key = kdbx::compositekey(password, keyfile.as_deref());
+            kdbx::import(&kdbx::read(bytes, &key)?, &mut skipped)
+        }
+        ImportFormat::Csv => generic::parse(bytes, options.mapping.as_ref(), &mut skipped)?,
+    };
+
+    let mut counts = BTreeMap::new();
+    let mut folders = BTreeSet::new();
+    for item in &items {
+        *counts.entry(item.item_type.clone()).or_insert(0) += 1;
+        if let Some(folder) = &item.folder {
+            folders.insert(folder.clone());
+        }
+    }
+    Ok(ImportReport {
+        format,
+        items,
+        counts,
+        folders: folders.into_iter().collect(),
+        skipped,
+    })
+}
+
+fn readfile(path: &str) -> Result<Vec<u8>, ImportError> {
+    fs::read(path).map_err(|e| ImportError::Io(e.to_string()))
+}
+
+fn load(
+    path: &str,
+    format: Option<ImportFormat>,
+    options: Option<ImportOptions>,
+) -> Result<ImportReport, ImportError> {
+    let bytes = readfile(path)?;
+    let format = match format {
+        Some(format) => format,
+        None => detect(&bytes).ok_or_else(|| ImportError::Unsupported("unknown format".into()))?,
+    };
+    parse(&bytes, format, &options.unwrap_or_default())
+}
+
+pub fn newid() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn folderid(vault: &mut VaultData, path: &str) -> String {
+    let mut parent: Option<String> = None;
+    for name in path.split('/').map(str::trim).filter(|n| !n.is_empty()) {
+        let existing = vault
+            .folders
+            .iter()
+            .find(|f| f.name == name && f.parent_id == parent)
+            .map(|f| f.id.clone());
+        let id = existing.unwrap_or_else(|| {
+            let folder = VaultFolder {
+                id: newid(),
+                name: name.to_string(),
+                parent_id: parent.clone(),
+                color: "default".into(),
+                icon: "folder".into(),
+                order: vault.folders.len() as i32,
+                created: now(),
+                updated: now(),
+            };
+            let id = folder.id.clone();
+            vault.folders.push(folder);
+            id
+        });
+        parent = Some(id);
+    }
+    parent.unwrap_or_default()
+}
+
+fn toentry(item: &ImportedItem) -> Option<VaultEntry> {
+    let data: Map<String, Value> = serde_json::from_str(&item.data).ok()?;
+    let text = |key: &str| data.get(key).and_then(Value::as_str).map(String::from);
+    let notes = match item.item_type.as_str() {
+        "login" => text("notes"),
+        "note" => text("content"),
+        _ => return None,
+    };
+    Some(VaultEntry {
+        id: newid(),
+        title: item.title.clone(),
+        username: text("username"),
+        password: text("password"),
+        url: text("url"),
+        notes,
+        folder_id: None,
+        deleted_at: None,
+        created: now(),
+        updated: now(),
+    })
+}
+
+#[tauri::command]
+pub fn import_detect(path: String) -> Result<Option<ImportFormat>, ImportError> {
+    Ok(detect(&readfile(&path)?))
+}
+
+#[tauri::command]
+pub fn import_preview(
+    path: String,
+    format: Option<ImportFormat>,
+    options: Option<ImportOptions>,
+) -> Result<ImportReport, ImportError> {
+    load(&path, format, options)
+}
+
+#[tauri::command]
+pub fn import_to_storage(
+    path: String,
+    format: Option<ImportFormat>,
+    options: Option<ImportOptions>,
+) -> Result<ImportReport, ImportError> {
+    let mut report = load(&path, format, options)?;
+    let mut vault = storage::get_vault().unwrap_or(VaultData {
+        entries: vec![],
+        folders: vec![],
+        updated: now(),
+    });
+    let mut stored = Vec::new();
+    for item in report.items.drain(..) {
+        match toentry(&item) {
+            Some(mut entry) => {
+                entry.folder_id = item.folder.as_deref().map(|f| folderid(&mut vault, f));
+                vault.entries.push(entry);
+                stored.push(item);
+            }
+            None => report.skipped.push(format!(
+                "{}: {} items are not stored locally",
+                item.title, item.item_type
+            )),
+        }
+    }
+    report.items = stored;
+    vault.updated = now();
+    storage::store_vault(&vault).map_err(|e| ImportError::Storage(e.to_string()))?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let lastpass = b"url,username,password,totp,extra,name,grouping,fav\n";
+        assert_eq!(detect(lastpass), Some(ImportFormat::Lastpass));
+        assert_eq!(
+            detect(b"\xef\xbb\xbfname,password\n"),
+            Some(ImportFormat::Csv)
+        );
+        assert_eq!(detect(br#"{"items":[]}"#), Some(ImportFormat::Bitwarden));
+        assert_eq!(
+            detect(br#"{"encrypted":true}"#),
+            Some(ImportFormat::Bitwarden)
+        );
+        assert_eq!(detect(b"PK\x03\x04rest"), Some(ImportFormat::Onepux));
+        assert_eq!(detect(&kdbx::SIGNATURE), Some(ImportFormat::Kdbx));
+        assert_eq!(detect(br#"{"other":1}"#), None);
+        assert_eq!(detect(b"just some text"), None);
+        assert_eq!(detect(b"\xff\xfe"), None);
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let csv = b"title,username,type,folder\nMail,ann,,Work/Email\nList,,note,Work\nBank,ann,,Work/Email\n,,,\n";
+        let report = parse(csv, ImportFormat::Csv, &ImportOptions::default()).unwrap();
+        assert_eq!(report.format, ImportFormat::Csv);
+        assert_eq!(report.counts.get("login"), Some(&2));
+        assert_eq!(report.counts.get("note"), Some(&1));
+        assert_eq!(report.folders, ["Work", "Work/Email"]);
+        assert_eq!(report.skipped, ["row 5: empty"]);
+
+        let options = ImportOptions::default();
+        let kdbx = parse(&kdbx::SIGNATURE, ImportFormat::Kdbx, &options);
+        assert!(matches!(kdbx, Err(ImportError::PasswordRequired)));
+    }
+}
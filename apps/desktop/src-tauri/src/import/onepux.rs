@@ -0,0 +1,349 @@
+use serde_json::Value;
+use std::io::{Cursor, Read};
+
+use super::{formaterr, ImportError, ImportedItem, ItemBuilder};
+
+const EXPORT_DATA: &str = "export.data";
+
+fn category(uuid: &str) -> &'static str {
+    match uuid {
+        "001" | "005" | "102" | "109" | "110" | "111" => "login",
+        "002" => "card",
+        "004" => "identity",
+        "112" => "api",
+        "114" => "ssh",
+        "006" => "document",
+        _ => "note",
+    }
+}
+
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Object(map) => {
+            if let Some(email) = map.get("email").or_else(|| map.get("email_address")) {
+                return text(email);
+            }
+            if let Some(key) = map.get("sshKey").or_else(|| map.get("privateKey")) {
+                return text(key);
+            }
+            if let Some(month) = map.get("monthYear").and_then(Value::as_u64) {
+                return Some(format!("{:02}/{}", month % 100, month / 100));
+            }
+            if map.contains_key("street") || map.contains_key("city") {
+                let parts: Vec<String> = ["street", "city", "state", "zip", "country"]
+                    .iter()
+                    .filter_map(|k| map.get(*k).and_then(Value::as_str))
+                    .filter(|v| !v.is_empty())
+                    .map(String::from)
+                    .collect();
+                return Some(parts.join(", "));
+            }
+            map.values().find_map(text)
+        }
+        _ => None,
+    }
+}
+
+struct SectionField {
+    id: String,
+    title: String,
+    kind: String,
+    value: String,
+    raw: Value,
+}
+
+fn sectionfields(details: &Value) -> Vec<SectionField> {
+    let mut found = Vec::new();
+    for section in details["sections"].as_array().into_iter().flatten() {
+        for field in section["fields"].as_array().into_iter().flatten() {
+            let raw = field["value"].clone();
+            let kind = raw
+                .as_object()
+                .and_then(|m| m.keys().next().cloned())
+                .unwrap_or_default();
+            let Some(value) = text(&raw).filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            found.push(SectionField {
+                id: field["id"].as_str().unwrap_or_default().to_string(),
+                title: field["title"].as_str().unwrap_or_default().to_string(),
+                kind,
+                value,
+                raw,
+            });
+        }
+    }
+    found
+}
+
+fn loginfield(details: &Value, designation: &str) -> Option<String> {
+    details["loginFields"]
+        .as_array()?
+        .iter()
+        .find(|f| f["designation"] == designation)
+        .and_then(|f| f["value"].as_str())
+        .map(String::from)
+}
+
+fn convert(item: &Value, vault: &str) -> Result<ImportedItem, String> {
+    let overview = &item["overview"];
+    let details = &item["details"];
+    let title = overview["title"].as_str().unwrap_or_default();
+    let notes = details["notesPlain"].as_str();
+    let kind = category(item["categoryUuid"].as_str().unwrap_or_default());
+    let mut fields = sectionfields(details);
+    let mut take = |ids: &[&str]| {
+        let idx = fields
+            .iter()
+            .position(|f| ids.contains(&f.id.as_str()) || ids.contains(&f.kind.as_str()))?;
+        Some(fields.remove(idx))
+    };
+
+    let builder = match kind {
+        "login" => {
+            let totp = take(&["totp"]).map(|f| f.value);
+            let username = loginfield(details, "username")
+                .or_else(|| take(&["username", "email"]).map(|f| f.value));
+            let password = loginfield(details, "password")
+                .or_else(|| details["password"].as_str().map(String::from))
+                .or_else(|| take(&["password"]).map(|f| f.value));
+            let url = overview["url"]
+                .as_str()
+                .map(String::from)
+                .or_else(|| take(&["url", "hostname", "server"]).map(|f| f.value));
+            ItemBuilder::new("login", title)
+                .field("username", username.as_deref())
+                .field("password", password.as_deref())
+                .field("url", url.as_deref())
+                .field("totp", totp.as_deref())
+                .field("notes", notes)
+        }
+        "card" => {
+            let holder = take(&["cardholder"]).map(|f| f.value);
+            let number = take(&["ccnum", "creditCardNumber"]).map(|f| f.value);
+            let expiry = take(&["expiry"]).map(|f| f.value);
+            let cvv = take(&["cvv"]).map(|f| f.value);
+            let pin = take(&["pin"]).map(|f| f.value);
+            ItemBuilder::new("card", title)
+                .field("holder", holder.as_deref())
+                .field("number", number.as_deref())
+                .field("expiry", expiry.as_deref())
+                .field("cvv", cvv.as_deref())
+                .field("pin", pin.as_deref())
+                .field("notes", notes)
+        }
+        "identity" => {
+            let firstname = take(&["firstname"]).map(|f| f.value);
+            let lastname = take(&["lastname"]).map(|f| f.value);
+            let email = take(&["email"]).map(|f| f.value);
+            let phone = take(&["defphone", "cellphone", "homephone", "phone"]).map(|f| f.value);
+            let address = take(&["address"]).map(|f| f.raw);
+            let part = |key: &str| {
+                address
+                    .as_ref()
+                    .and_then(|a| a["address"][key].as_str().map(String::from))
+            };
+            ItemBuilder::new("identity", title)
+                .field("firstname", firstname.as_deref())
+                .field("lastname", lastname.as_deref())
+                .field("email", email.as_deref())
+                .field("phone", phone.as_deref())
+                .field("address", part("street").as_deref())
+                .field("city", part("city").as_deref())
+                .field("state", part("state").as_deref())
+                .field("zip", part("zip").as_deref())
+                .field("country", part("country").as_deref())
+                .field("notes", notes)
+        }
+        "api" => {
+            let key = take(&["credential"]).map(|f| f.value);
+            let endpoint = take(&["hostname"]).map(|f| f.value);
+            ItemBuilder::new("api", title)
+                .field("key", key.as_deref())
+                .field("endpoint", endpoint.as_deref())
+                .field("notes", notes)
+        }
+        "ssh" => {
+            let key = take(&["private_key", "sshKey"]);
+            let public = key.as_ref().and_then(|k| {
+                k.raw["sshKey"]["metadata"]["publicKey"]
+                    .as_str()
+                    .map(String::from)
+            });
+            ItemBuilder::new("ssh", title)
+                .field("privatekey", key.map(|k| k.value).as_deref())
+                .field("publickey", public.as_deref())
+                .field("notes", notes)
+        }
+        "document" => return Err("documents are not imported".into()),
+        _ => ItemBuilder::new("note", title).field("content", notes),
+    };
+
+    let builder = fields.iter().fold(builder, |b, f| {
+        b.custom(if f.title.is_empty() { &f.id } else { &f.title }, &f.value)
+    });
+    let builder = overview["urls"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|u| u["url"].as_str())
+        .filter(|u| Some(*u) != overview["url"].as_str())
+        .fold(builder, |b, u| b.custom("url", u));
+    let mut tags: Vec<String> = overview["tags"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|t| t.as_str().map(String::from))
+        .collect();
+    if item["state"] == "archived" {
+        tags.push("archived".into());
+    }
+    Ok(builder
+        .tags(tags)
+        .favorite(item["favIndex"].as_u64().unwrap_or(0) > 0)
+        .folder(Some(vault))
+        .build())
+}
+
+pub fn parse(bytes: &[u8], skipped: &mut Vec<String>) -> Result<Vec<ImportedItem>, ImportError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(formaterr)?;
+    let mut data = String::new();
+    archive
+        .by_name(EXPORT_DATA)
+        .map_err(|_| ImportError::Format("missing export.data".into()))?
+        .read_to_string(&mut data)
+        .map_err(formaterr)?;
+    let export: Value = serde_json::from_str(&data).map_err(formaterr)?;
+
+    let mut items = Vec::new();
+    for account in export["accounts"].as_array().into_iter().flatten() {
+        for vault in account["vaults"].as_array().into_iter().flatten() {
+            let name = vault["attrs"]["name"].as_str().unwrap_or_default();
+            for item in vault["items"].as_array().into_iter().flatten() {
+                match convert(item, name) {
+                    Ok(item) => items.push(item),
+                    Err(reason) => skipped.push(format!(
+                        "{}: {reason}",
+                        item["overview"]["title"].as_str().unwrap_or("untitled")
+                    )),
+                }
+            }
+        }
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn archive(name: &str, data: &str) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        zip.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(data.as_bytes()).unwrap();
+        zip.finish().unwrap();
+        buf
+    }
+
+    fn vaults(vaults: &str) -> Vec<u8> {
+        archive(
+            EXPORT_DATA,
+            &format!(r#"{{"accounts":[{{"vaults":[{vaults}]}}]}}"#),
+        )
+    }
+
+    #[test]
+    fn test_onepux_items() {
+        let data = r#"{"accounts":[{"vaults":[{"attrs":{"name":"Private"},"items":[
+            {"uuid":"1","favIndex":1,"categoryUuid":"001","state":"active",
+             "overview":{"title":"GitHub","url":"https://github.com","tags":["dev"]},
+             "details":{"loginFields":[{"designation":"username","value":"octo"},{"designation":"password","value":"pw"}],
+                        "notesPlain":"hi","sections":[{"fields":[
+                          {"id":"TOTP_1","title":"one-time password","value":{"totp":"otpauth://totp/x?secret=JBSWY3DP"}},
+                          {"id":"recovery","title":"Recovery","value":{"concealed":"abcd"}}]}]}},
+            {"uuid":"2","categoryUuid":"002","overview":{"title":"Visa"},
+             "details":{"sections":[{"fields":[
+                {"id":"ccnum","value":{"creditCardNumber":"4111"}},
+                {"id":"expiry","value":{"monthYear":203012}}]}]}},
+            {"uuid":"3","categoryUuid":"006","overview":{"title":"Scan"},"details":{}}]}]}]}"#;
+        let mut skipped = Vec::new();
+        let items = parse(&archive(EXPORT_DATA, data), &mut skipped).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(skipped.len(), 1);
+        let login: Value = serde_json::from_str(&items[0].data).unwrap();
+        assert_eq!(login["username"], "octo");
+        assert_eq!(login["totp"], "otpauth://totp/x?secret=JBSWY3DP");
+        assert_eq!(login["fields"][0]["name"], "Recovery");
+        assert_eq!(items[0].folder.as_deref(), Some("Private"));
+        assert!(items[0].favorite);
+        let card: Value = serde_json::from_str(&items[1].data).unwrap();
+        assert_eq!(card["expiry"], "12/2030");
+    }
+
+    #[test]
+    fn test_onepux_folder_mapping() {
+        let buf = vaults(
+            r#"{"attrs":{"name":"Private"},"items":[
+                {"categoryUuid":"003","overview":{"title":"A"},"details":{"notesPlain":"a"}}]},
+               {"attrs":{"name":"Shared"},"items":[
+                {"categoryUuid":"003","state":"archived","overview":{"title":"B","tags":["x"]},"details":{"notesPlain":"b"}}]},
+               {"items":[
+                {"categoryUuid":"003","overview":{"title":"C"},"details":{"notesPlain":"c"}}]}"#,
+        );
+        let mut skipped = Vec::new();
+        let items = parse(&buf, &mut skipped).unwrap();
+        assert_eq!(items[0].folder.as_deref(), Some("Private"));
+        assert_eq!(items[1].folder.as_deref(), Some("Shared"));
+        assert_eq!(items[1].tags, ["x", "archived"]);
+        assert_eq!(items[2].folder, None);
+    }
+
+    #[test]
+    fn test_onepux_missing_fields() {
+        let buf = vaults(
+            r#"{"attrs":{"name":"Private"},"items":[
+                {},
+                {"categoryUuid":"001","overview":{"title":"Legacy"},"details":{"password":"pw"}},
+                {"categoryUuid":"001","overview":{"title":"Server"},"details":{"sections":[{"fields":[
+                    {"id":"hostname","value":{"string":"db.example.com"}},
+                    {"id":"blank","value":{"string":""}}]}]}},
+                {"categoryUuid":"006","details":{}}]}"#,
+        );
+        let mut skipped = Vec::new();
+        let items = parse(&buf, &mut skipped).unwrap();
+        assert_eq!(items[0].title, "untitled");
+        assert_eq!(items[0].item_type, "note");
+        assert_eq!(items[0].data, r#"{"content":""}"#);
+        let legacy: Value = serde_json::from_str(&items[1].data).unwrap();
+        assert_eq!(legacy["password"], "pw");
+        let server: Value = serde_json::from_str(&items[2].data).unwrap();
+        assert_eq!(server["url"], "db.example.com");
+        assert!(server.get("fields").is_none());
+        assert_eq!(skipped, ["untitled: documents are not imported"]);
+    }
+
+    #[test]
+    fn test_onepux_malformed() {
+        let mut skipped = Vec::new();
+        assert!(matches!(
+            parse(b"PK\x03\x04 not a zip", &mut skipped),
+            Err(ImportError::Format(_))
+        ));
+        assert!(matches!(
+            parse(&archive("other.data", "{}"), &mut skipped),
+            Err(ImportError::Format(e)) if e == "missing export.data"
+        ));
+        assert!(matches!(
+            parse(&archive(EXPORT_DATA, "{not json"), &mut skipped),
+            Err(ImportError::Format(_))
+        ));
+        assert!(parse(&archive(EXPORT_DATA, "{}"), &mut skipped)
+            .unwrap()
+            .is_empty());
+    }
+}
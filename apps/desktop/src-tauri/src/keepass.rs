@@ -0,0 +1,850 @@
+use noro_common::time::now;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::State;
+
+use crate::import::kdbx::{self, Database, Entry, Group};
+use crate::import::ImportError;
+
+const TYPE_FIELD: &str = "NoroType";
+const STANDARD_FIELDS: &[&str] = &[
+    "Title", "UserName", "Password", "URL", "Notes", "otp", TYPE_FIELD,
+];
+const SECRET_KEYS: &[&str] = &[
+    "password",
+    "cvv",
+    "pin",
+    "number",
+    "privatekey",
+    "key",
+    "totp",
+];
+const MAX_HISTORY: usize = 10;
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeepassError {
+    #[error(transparent)]
+    Database(#[from] ImportError),
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("no keepass database is open")]
+    NotOpen,
+    #[error("not found")]
+    NotFound,
+    #[error("database already exists")]
+    Exists,
+    #[error("database was changed by another program")]
+    Conflict,
+    #[error("invalid item data")]
+    InvalidData,
+}
+
+impl Serialize for KeepassError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+fn ioerr(e: std::io::Error) -> KeepassError {
+    KeepassError::Io(e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeepassItem {
+    pub id: String,
+    pub item_type: String,
+    pub title: String,
+    pub data: String,
+    pub tags: Vec<String>,
+    pub favorite: bool,
+    pub folder_id: Option<String>,
+    pub created: u64,
+    pub updated: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeepassFolder {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeepassInfo {
+    pub path: String,
+    pub name: String,
+    pub items: u32,
+    pub folders: u32,
+}
+
+struct Session {
+    path: PathBuf,
+    key: [u8; 32],
+    db: Database,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Default)]
+pub struct KeepassStore {
+    session: Mutex<Option<Session>>,
+}
+
+fn mtime(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn compositekey(password: &str, keyfile: Option<&str>) -> Result<[u8; 32], KeepassError> {
+    let keyfile = keyfile.map(fs::read).transpose().map_err(ioerr)?;
+    Ok(kdbx::compositekey(password, keyfile.as_deref()))
+}
+
+fn islogin(entry: &Entry) -> bool {
+    ["UserName", "Password", "URL", "otp"]
+        .iter()
+        .any(|k| entry.get(k).is_some_and(|v| !v.is_empty()))
+}
+
+pub fn itemtype(entry: &Entry) -> String {
+    match entry.get(TYPE_FIELD) {
+        Some(kind) if !kind.is_empty() => kind.to_string(),
+        _ if islogin(entry) => "login".into(),
+        _ => "note".into(),
+    }
+}
+
+pub fn itemdata(entry: &Entry) -> Value {
+    let kind = itemtype(entry);
+    let mut data = Map::new();
+    let mut put = |key: &str, field: &str| {
+        if let Some(value) = entry.get(field).filter(|v| !v.is_empty()) {
+            data.insert(key.into(), value.into());
+        }
+    };
+    match kind.as_str() {
+        "login" => {
+            put("username", "UserName");
+            put("password", "Password");
+            put("url", "URL");
+            put("totp", "otp");
+            put("notes", "Notes");
+        }
+        "note" => put("content", "Notes"),
+        _ => put("notes", "Notes"),
+    }
+    let custom = entry
+        .fields
+        .iter()
+        .filter(|(k, _)| !STANDARD_FIELDS.contains(&k.as_str()));
+    if matches!(kind.as_str(), "login" | "note") {
+        let fields: Vec<Value> = custom
+            .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+            .collect();
+        if !fields.is_empty() {
+            data.insert("fields".into(), fields.into());
+        }
+    } else {
+        for (name, value) in custom {
+            data.insert(name.clone(), value.clone().into());
+        }
+    }
+    Value::Object(data)
+}
+
+pub fn applyitem(
+    entry: &mut Entry,
+    item_type: &str,
+    title: &str,
+    data: &str,
+) -> Result<(), KeepassError> {
+    let data: Map<String, Value> =
+        serde_json::from_str(data).map_err(|_| KeepassError::InvalidData)?;
+    let text = |key: &str| match data.get(key) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) => n.to_string(),
+        Some(Value::Bool(b)) => b.to_string(),
+        _ => String::new(),
+    };
+    entry.fields.clear();
+    entry.protected.clear();
+    entry.set("Title", title, false);
+    match item_type {
+        "login" => {
+            entry.set("UserName", &text("username"), false);
+            entry.set("Password", &text("password"), true);
+            entry.set("URL", &text("url"), false);
+            entry.set("Notes", &text("notes"), false);
+            if !text("totp").is_empty() {
+                entry.set("otp", &text("totp"), true);
+            }
+        }
+        "note" => entry.set("Notes", &text("content"), false),
+        _ => {
+            entry.set("Notes", &text("notes"), false);
+            entry.set(TYPE_FIELD, item_type, false);
+            for key in data.keys().filter(|k| *k != "notes" && *k != "fields") {
+                let value = text(key);
+                if !value.is_empty() {
+                    entry.set(key, &value, SECRET_KEYS.contains(&key.as_str()));
+                }
+            }
+        }
+    }
+    for field in data
+        .get("fields")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        if let (Some(name), Some(value)) = (field["name"].as_str(), field["value"].as_str()) {
+            if !STANDARD_FIELDS.contains(&name) {
+                entry.set(name, value, false);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn toitem(entry: &Entry, folder: &Group, root: &str) -> KeepassItem {
+    KeepassItem {
+        id: entry.uuid.clone(),
+        item_type: itemtype(entry),
+        title: entry.get("Title").unwrap_or_default().to_string(),
+        data: itemdata(entry).to_string(),
+        tags: entry.tags.clone(),
+        favorite: false,
+        folder_id: (folder.uuid != root).then(|| folder.uuid.clone()),
+        created: entry.time("CreationTime").unwrap_or_default(),
+        updated: entry.time("LastModificationTime").unwrap_or_default(),
+    }
+}
+
+fn collect(
+    db: &Database,
+    group: &Group,
+    items: &mut Vec<KeepassItem>,
+    folders: &mut Vec<KeepassFolder>,
+) {
+    if db.recyclebin().as_deref() == Some(group.uuid.as_str()) {
+        return;
+    }
+    for entry in &group.entries {
+        items.push(toitem(entry, group, &db.root.uuid));
+    }
+    for child in &group.groups {
+        if db.recyclebin().as_deref() == Some(child.uuid.as_str()) {
+            continue;
+        }
+        folders.push(KeepassFolder {
+            id: child.uuid.clone(),
+            name: child.name.clone(),
+            parent_id: (group.uuid != db.root.uuid).then(|| group.uuid.clone()),
+        });
+        collect(db, child, items, folders);
+    }
+}
+
+pub fn listing(db: &Database) -> (Vec<KeepassItem>, Vec<KeepassFolder>) {
+    let (mut items, mut folders) = (Vec::new(), Vec::new());
+    collect(db, &db.root, &mut items, &mut folders);
+    (items, folders)
+}
+
+fn newentry(now: u64) -> Entry {
+    let mut entry = Entry {
+        uuid: kdbx::newuuid(),
+        ..Entry::default()
+    };
+    for key in [
+        "CreationTime",
+        "LastModificationTime",
+        "LastAccessTime",
+        "ExpiryTime",
+        "LocationChanged",
+    ] {
+        entry.settime(key, now);
+    }
+    entry.times.push(("Expires".into(), "False".into()));
+    entry.times.push(("UsageCount".into(), "0".into()));
+    entry
+}
+
+fn snapshot(entry: &mut Entry, now: u64) {
+    let mut old = entry.clone();
+    old.history.clear();
+    entry.history.push(old);
+    if entry.history.len() > MAX_HISTORY {
+        entry.history.remove(0);
+    }
+    entry.settime("LastModificationTime", now);
+}
+
+fn target<'a>(
+    db: &'a mut Database,
+    folder_id: Option<&str>,
+) -> Result<&'a mut Group, KeepassError> {
+    match folder_id {
+        Some(id) => db.root.group_mut(id).ok_or(KeepassError::NotFound),
+        None => Ok(&mut db.root),
+    }
+}
+
+fn recyclebin(db: &mut Database, now: u64) -> String {
+    if let Some(id) = db.recyclebin().filter(|id| db.root.group(id).is_some()) {
+        return id;
+    }
+    let group = Group {
+        uuid: kdbx::newuuid(),
+        name: "Recycle Bin".into(),
+        extra: vec![
+            "<IconID>43</IconID>".into(),
+            "<EnableAutoType>false</EnableAutoType>".into(),
+            "<EnableSearching>false</EnableSearching>".into(),
+        ],
+        ..Group::default()
+    };
+    let id = group.uuid.clone();
+    db.root.groups.push(group);
+    db.setmeta("RecycleBinUUID", &id);
+    db.setmeta("RecycleBinChanged", &kdbx::encodetime(now));
+    id
+}
+
+fn markdeleted(db: &mut Database, uuid: &str, now: u64) {
+    let record = format!(
+        "<DeletedObject><UUID>{uuid}</UUID><DeletionTime>{}</DeletionTime></DeletedObject>",
+        kdbx::encodetime(now)
+    );
+    let deleted = match db.deleted.take() {
+        Some(raw) if raw.contains("</DeletedObjects>") => {
+            raw.replace("</DeletedObjects>", &format!("{record}</DeletedObjects>"))
+        }
+        _ => format!("<DeletedObjects>{record}</DeletedObjects>"),
+    };
+    db.deleted = Some(deleted);
+}
+
+pub fn deleteentry(db: &mut Database, id: &str, now: u64) -> Result<(), KeepassError> {
+    let inbin = db
+        .recyclebin()
+        .and_then(|bin| {
+            db.root
+                .group(&bin)
+                .map(|g| g.entries.iter().any(|e| e.uuid == id))
+        })
+        .unwrap_or(false);
+    let enabled = db
+        .metatext("RecycleBinEnabled")
+        .map_or(true, |v| !v.eq_ignore_ascii_case("false"));
+    let mut entry = db.root.take_entry(id).ok_or(KeepassError::NotFound)?;
+    if inbin || !enabled {
+        markdeleted(db, id, now);
+        return Ok(());
+    }
+    entry.settime("LocationChanged", now);
+    let bin = recyclebin(db, now);
+    target(db, Some(&bin))?.entries.push(entry);
+    Ok(())
+}
+
+impl KeepassStore {
+    fn info(session: &Session) -> KeepassInfo {
+        let (items, folders) = listing(&session.db);
+        KeepassInfo {
+            path: session.path.to_string_lossy().into_owned(),
+            name: session.db.name(),
+            items: items.len() as u32,
+            folders: folders.len() as u32,
+        }
+    }
+
+    fn with<T>(&self, f: impl FnOnce(&Session) -> T) -> Result<T, KeepassError> {
+        let guard = self.session.lock().unwrap();
+        guard.as_ref().map(f).ok_or(KeepassError::NotOpen)
+    }
+
+    fn mutate<T>(
+        &self,
+        f: impl FnOnce(&mut Database, u64) -> Result<T, KeepassError>,
+    ) -> Result<T, KeepassError> {
+        let mut guard = self.session.lock().unwrap();
+        let session = guard.as_mut().ok_or(KeepassError::NotOpen)?;
+        if mtime(&session.path) != session.modified {
+            return Err(KeepassError::Conflict);
+        }
+        let mut db = session.db.clone();
+        let result = f(&mut db, now())?;
+        save(&session.path, &db, &session.key)?;
+        session.modified = mtime(&session.path);
+        session.db = db;
+        Ok(result)
+    }
+}
+
+fn save(path: &Path, db: &Database, key: &[u8; 32]) -> Result<(), KeepassError> {
+    let bytes = kdbx::write(db, key)?;
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, bytes).map_err(ioerr)?;
+    fs::rename(&tmp, path).map_err(ioerr)
+}
+
+#[tauri::command]
+pub fn keepass_open(
+    store: State<'_, KeepassStore>,
+    path: String,
+    password: String,
+    keyfile: Option<String>,
+) -> Result<KeepassInfo, KeepassError> {
+    let key = compositekey(&password, keyfile.as_deref())?;
+    let path = PathBuf::from(path);
+    let db = kdbx::read(&fs::read(&path).map_err(ioerr)?, &key)?;
+    let session = Session {
+        modified: mtime(&path),
+        path,
+        key,
+        db,
+    };
+    let info = KeepassStore::info(&session);
+    *store.session.lock().unwrap() = Some(session);
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn keepass_create(
+    store: State<'_, KeepassStore>,
+    path: String,
+    password: String,
+    keyfile: Option<String>,
+    name: String,
+) -> Result<KeepassInfo, KeepassError> {
+    let path = PathBuf::from(path);
+    if path.exists() {
+        return Err(KeepassError::Exists);
+    }
+    let key = compositekey(&password, keyfile.as_deref())?;
+    let db = Database::new(&name, now());
+    save(&path, &db, &key)?;
+    let session = Session {
+        modified: mtime(&path),
+        path,
+        key,
+        db,
+    };
+    let info = KeepassStore::info(&session);
+    *store.session.lock().unwrap() = Some(session);
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn keepass_reload(store: State<'_, KeepassStore>) -> Result<KeepassInfo, KeepassError> {
+    let mut guard = store.session.lock().unwrap();
+    let session = guard.as_mut().ok_or(KeepassError::NotOpen)?;
+    session.db = kdbx::read(&fs::read(&session.path).map_err(ioerr)?, &session.key)?;
+    session.modified = mtime(&session.path);
+    Ok(KeepassStore::info(session))
+}
+
+#[tauri::command]
+pub fn keepass_close(store: State<'_, KeepassStore>) {
+    *store.session.lock().unwrap() = None;
+}
+
+#[tauri::command]
+pub fn keepass_info(store: State<'_, KeepassStore>) -> Option<KeepassInfo> {
+    store.with(KeepassStore::info).ok()
+}
+
+#[tauri::command]
+pub fn keepass_list_items(
+    store: State<'_, KeepassStore>,
+) -> Result<Vec<KeepassItem>, KeepassError> {
+    store.with(|s| listing(&s.db).0)
+}
+
+#[tauri::command]
+pub fn keepass_list_folders(
+    store: State<'_, KeepassStore>,
+) -> Result<Vec<KeepassFolder>, KeepassError> {
+    store.with(|s| listing(&s.db).1)
+}
+
+#[tauri::command]
+pub fn keepass_create_item(
+    store: State<'_, KeepassStore>,
+    item_type: String,
+    title: String,
+    data: String,
+    tags: Vec<String>,
+    folder_id: Option<String>,
+) -> Result<KeepassItem, KeepassError> {
+    store.mutate(|db, now| {
+        let mut entry = newentry(now);
+        applyitem(&mut entry, &item_type, &title, &data)?;
+        entry.tags = tags;
+        let root = db.root.uuid.clone();
+        let group = target(db, folder_id.as_deref())?;
+        let item = toitem(&entry, group, &root);
+        group.entries.push(entry);
+        Ok(item)
+    })
+}
+
+#[tauri::command]
+pub fn keepass_update_item(
+    store: State<'_, KeepassStore>,
+    id: String,
+    title: Option<String>,
+    data: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<KeepassItem, KeepassError> {
+    store.mutate(|db, now| {
+        let entry = db.root.entry_mut(&id).ok_or(KeepassError::NotFound)?;
+        snapshot(entry, now);
+        let title = title.unwrap_or_else(|| entry.get("Title").unwrap_or_default().to_string());
+        match data {
+            Some(data) => applyitem(entry, &itemtype(entry), &title, &data)?,
+            None => entry.set("Title", &title, false),
+        }
+        if let Some(tags) = tags {
+            entry.tags = tags;
+        }
+        let entry = entry.clone();
+        let folder = db.root.parent(&id).ok_or(KeepassError::NotFound)?;
+        Ok(toitem(&entry, folder, &db.root.uuid))
+    })
+}
+
+#[tauri::command]
+pub fn keepass_delete_item(store: State<'_, KeepassStore>, id: String) -> Result<(), KeepassError> {
+    store.mutate(|db, now| deleteentry(db, &id, now))
+}
+
+#[tauri::command]
+pub fn keepass_move_item(
+    store: State<'_, KeepassStore>,
+    id: String,
+    folder_id: Option<String>,
+) -> Result<(), KeepassError> {
+    store.mutate(|db, now| {
+        target(db, folder_id.as_deref())?;
+        let mut entry = db.root.take_entry(&id).ok_or(KeepassError::NotFound)?;
+        entry.settime("LocationChanged", now);
+        target(db, folder_id.as_deref())?.entries.push(entry);
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub fn keepass_create_folder(
+    store: State<'_, KeepassStore>,
+    name: String,
+    parent_id: Option<String>,
+) -> Result<KeepassFolder, KeepassError> {
+    store.mutate(|db, now| {
+        let root = db.root.uuid.clone();
+        let parent = target(db, parent_id.as_deref())?;
+        let time = kdbx::encodetime(now);
+        let group = Group {
+            uuid: kdbx::newuuid(),
+            name: name.clone(),
+            extra: vec![format!(
+                "<Times><CreationTime>{time}</CreationTime><LastModificationTime>{time}</LastModificationTime></Times>"
+            )],
+            ..Group::default()
+        };
+        let folder = KeepassFolder {
+            id: group.uuid.clone(),
+            name,
+            parent_id: (parent.uuid != root).then(|| parent.uuid.clone()),
+        };
+        parent.groups.push(group);
+        Ok(folder)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::kdbx::Variant;
+
+    const SAMPLE: &[u8] = include_bytes!("import/testdata/sample.kdbx");
+    const NOW: u64 = 1_700_000_000;
+
+    fn sample() -> Database {
+        kdbx::read(SAMPLE, &kdbx::compositekey("demo", None)).unwrap()
+    }
+
+    fn fresh() -> Database {
+        let mut db = Database::new("Team", NOW);
+        db.header.kdf.insert("M".into(), Variant::U64(64 * 1024));
+        db.header.kdf.insert("I".into(), Variant::U64(1));
+        db
+    }
+
+    fn item(db: &mut Database, item_type: &str, title: &str, data: &str) -> String {
+        let mut entry = newentry(NOW);
+        applyitem(&mut entry, item_type, title, data).unwrap();
+        let id = entry.uuid.clone();
+        db.root.entries.push(entry);
+        id
+    }
+
+    fn reopen(db: &Database) -> Database {
+        let key = kdbx::compositekey("demo", None);
+        kdbx::read(&kdbx::write(db, &key).unwrap(), &key).unwrap()
+    }
+
+    #[test]
+    fn test_listing_sample() {
+        let db = sample();
+        let (items, folders) = listing(&db);
+        assert_eq!(items.len(), 2);
+        assert_eq!(folders.len(), 2);
+        let bin = db.recyclebin().unwrap();
+        assert!(folders.iter().all(|f| f.id != bin));
+        assert!(folders.iter().all(|f| f.parent_id.is_none()));
+        assert!(items.iter().all(|i| i.folder_id.is_some()));
+        assert_eq!(items[0].tags, ["mail", "work"]);
+    }
+
+    #[test]
+    fn test_open_wrong_key() {
+        for key in [
+            kdbx::compositekey("wrong", None),
+            kdbx::compositekey("", None),
+            kdbx::compositekey("demo", Some(&[7; 32])),
+        ] {
+            assert!(matches!(
+                kdbx::read(SAMPLE, &key),
+                Err(ImportError::InvalidPassword)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_open_keyfile() {
+        let keyfile = "<KeyFile><Meta><Version>2.0</Version></Meta><Key><Data Hash=\"00000000\">\
+            0102030405060708 090A0B0C0D0E0F10 1112131415161718 191A1B1C1D1E1F20</Data></Key></KeyFile>";
+        let other = keyfile.replace("0102", "0103");
+        let keyfile = keyfile.as_bytes();
+        let key = kdbx::compositekey("team", Some(keyfile));
+        let mut db = fresh();
+        item(&mut db, "note", "Wifi", r#"{"content":"hall"}"#);
+        let bytes = kdbx::write(&db, &key).unwrap();
+
+        for wrong in [
+            kdbx::compositekey("team", None),
+            kdbx::compositekey("", Some(keyfile)),
+            kdbx::compositekey("team", Some(other.as_bytes())),
+            kdbx::compositekey("team", Some(&[0; 32])),
+        ] {
+            assert!(matches!(
+                kdbx::read(&bytes, &wrong),
+                Err(ImportError::InvalidPassword)
+            ));
+        }
+        let (items, _) = listing(&kdbx::read(&bytes, &key).unwrap());
+        assert_eq!(items[0].title, "Wifi");
+    }
+
+    #[test]
+    fn test_open_corrupt_file() {
+        let key = kdbx::compositekey("demo", None);
+        let mut flipped = SAMPLE.to_vec();
+        flipped[20] ^= 1;
+        for bytes in [&[][..], b"not a database", &SAMPLE[..100], &flipped] {
+            assert!(matches!(
+                kdbx::read(bytes, &key),
+                Err(ImportError::Format(_))
+            ));
+        }
+        let mut payload = SAMPLE.to_vec();
+        let last = payload.len() - 40;
+        payload[last] ^= 1;
+        assert!(kdbx::read(&payload, &key).is_err());
+    }
+
+    #[test]
+    fn test_applyitem_login() {
+        let mut entry = newentry(NOW);
+        applyitem(
+            &mut entry,
+            "login",
+            "Mail",
+            r#"{"username":"bob","password":"n&w","totp":"otpauth://totp/x?secret=AA",
+                "fields":[{"name":"PIN","value":"9"},{"name":"Password","value":"ignored"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(entry.get("Title"), Some("Mail"));
+        assert_eq!(entry.get("Password"), Some("n&w"));
+        assert_eq!(entry.get("PIN"), Some("9"));
+        assert_eq!(entry.protected, ["Password", "otp"]);
+        assert_eq!(itemtype(&entry), "login");
+
+        let data = itemdata(&entry);
+        assert_eq!(data["username"], "bob");
+        assert_eq!(data["totp"], "otpauth://totp/x?secret=AA");
+        assert_eq!(
+            data["fields"],
+            serde_json::json!([{ "name": "PIN", "value": "9" }])
+        );
+        assert!(data.get("url").is_none());
+    }
+
+    #[test]
+    fn test_applyitem_replaces_fields() {
+        let mut entry = newentry(NOW);
+        applyitem(&mut entry, "login", "Mail", r#"{"password":"old"}"#).unwrap();
+        applyitem(&mut entry, "note", "Mail", r#"{"content":"text"}"#).unwrap();
+        assert_eq!(entry.get("Password"), None);
+        assert!(entry.protected.is_empty());
+        assert_eq!(itemtype(&entry), "note");
+        assert_eq!(itemdata(&entry), serde_json::json!({ "content": "text" }));
+    }
+
+    #[test]
+    fn test_applyitem_custom_type() {
+        let mut entry = newentry(NOW);
+        applyitem(
+            &mut entry,
+            "card",
+            "Visa",
+            r#"{"number":"4111","cvv":123,"holder":"Jane","expired":false,"notes":"n"}"#,
+        )
+        .unwrap();
+        assert_eq!(itemtype(&entry), "card");
+        assert_eq!(entry.protected, ["cvv", "number"]);
+        assert_eq!(
+            itemdata(&entry),
+            serde_json::json!({
+                "notes": "n",
+                "number": "4111",
+                "cvv": "123",
+                "holder": "Jane",
+                "expired": "false",
+            })
+        );
+    }
+
+    #[test]
+    fn test_applyitem_invalid_data() {
+        let mut entry = newentry(NOW);
+        applyitem(&mut entry, "login", "Keep", r#"{"password":"kept"}"#).unwrap();
+        for data in ["", "not json", "[]", r#""text""#] {
+            assert!(matches!(
+                applyitem(&mut entry, "login", "Lost", data),
+                Err(KeepassError::InvalidData)
+            ));
+        }
+        assert_eq!(entry.get("Password"), Some("kept"));
+    }
+
+    #[test]
+    fn test_itemtype_fallback() {
+        let mut entry = newentry(NOW);
+        assert_eq!(itemtype(&entry), "note");
+        entry.set("URL", "", false);
+        assert_eq!(itemtype(&entry), "note");
+        entry.set("URL", "https://example.com", false);
+        assert_eq!(itemtype(&entry), "login");
+        entry.set(TYPE_FIELD, "", false);
+        assert_eq!(itemtype(&entry), "login");
+        entry.set(TYPE_FIELD, "identity", false);
+        assert_eq!(itemtype(&entry), "identity");
+    }
+
+    #[test]
+    fn test_snapshot_history() {
+        let mut db = sample();
+        let id = listing(&db).0[0].id.clone();
+        let entry = db.root.entry_mut(&id).unwrap();
+        let history = entry.history.len();
+        snapshot(entry, NOW);
+        applyitem(entry, "login", "Mail", r#"{"password":"n&w"}"#).unwrap();
+
+        let reopened = reopen(&db);
+        let entry = reopened.root.groups[0].entries[0].clone();
+        assert_eq!(entry.uuid, id);
+        assert_eq!(entry.history.len(), history + 1);
+        assert_eq!(entry.history[0].get("Password"), Some("old"));
+        assert_eq!(entry.history[history].get("Password"), Some("s3cr&t"));
+        assert!(entry.history[history].history.is_empty());
+        assert_eq!(entry.time("LastModificationTime"), Some(NOW));
+    }
+
+    #[test]
+    fn test_snapshot_caps_history() {
+        let mut entry = newentry(NOW);
+        for i in 0..MAX_HISTORY + 5 {
+            entry.set("Password", &i.to_string(), true);
+            snapshot(&mut entry, NOW + i as u64);
+        }
+        assert_eq!(entry.history.len(), MAX_HISTORY);
+        assert_eq!(entry.history[0].get("Password"), Some("5"));
+        assert_eq!(
+            entry.time("LastModificationTime"),
+            Some(NOW + MAX_HISTORY as u64 + 4)
+        );
+    }
+
+    #[test]
+    fn test_delete_to_recyclebin() {
+        let mut db = fresh();
+        let id = item(&mut db, "login", "Mail", r#"{"password":"x"}"#);
+        assert_eq!(db.recyclebin(), None);
+
+        deleteentry(&mut db, &id, NOW + 1).unwrap();
+        let bin = db.recyclebin().unwrap();
+        assert_eq!(db.root.group(&bin).unwrap().entries[0].uuid, id);
+        assert!(db.deleted.is_none());
+        assert!(listing(&db).0.is_empty());
+
+        deleteentry(&mut db, &id, NOW + 2).unwrap();
+        assert!(db.root.group(&bin).unwrap().entries.is_empty());
+        assert!(db.deleted.as_deref().unwrap().contains(&id));
+        assert!(matches!(
+            deleteentry(&mut db, &id, NOW + 3),
+            Err(KeepassError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn test_delete_without_recyclebin() {
+        let mut db = fresh();
+        db.setmeta("RecycleBinEnabled", "False");
+        let first = item(&mut db, "note", "One", "{}");
+        let second = item(&mut db, "note", "Two", "{}");
+        deleteentry(&mut db, &first, NOW).unwrap();
+        deleteentry(&mut db, &second, NOW).unwrap();
+        assert_eq!(db.recyclebin(), None);
+        assert!(db.root.entries.is_empty());
+        let deleted = db.deleted.as_deref().unwrap();
+        assert_eq!(deleted.matches("<DeletedObject>").count(), 2);
+
+        let reopened = reopen(&db);
+        assert!(reopened.deleted.as_deref().unwrap().contains(&second));
+    }
+
+    #[test]
+    fn test_new_database_roundtrip() {
+        let mut db = fresh();
+        item(&mut db, "note", "Wifi", r#"{"content":"hall"}"#);
+        item(&mut db, "card", "Visa", r#"{"number":"4111","cvv":"123"}"#);
+        let reopened = reopen(&db);
+        assert_eq!(reopened.name(), "Team");
+        let (items, folders) = listing(&reopened);
+        assert!(folders.is_empty());
+        assert_eq!(items[0].data, r#"{"content":"hall"}"#);
+        assert_eq!(items[0].created, NOW);
+        assert_eq!(items[0].folder_id, None);
+        assert_eq!(items[1].item_type, "card");
+        assert!(items[1].data.contains(r#""cvv":"123""#));
+    }
+}
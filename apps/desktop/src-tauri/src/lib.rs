@@ -1,91 +1,45 @@
+mod agent;
+mod audit;
 mod auth;
+mod autosync;
+mod backup;
+mod batch;
 mod biometric;
+mod breach;
 mod commands;
 mod crypto;
+pub mod envrun;
+mod events;
+mod folders;
+mod generator;
+mod import;
+mod keepass;
+mod otp;
+mod otpimport;
 mod plugins;
+mod policy;
+mod search;
+mod sshkey;
+pub mod sshsig;
 mod storage;
+mod strength;
 mod sync;
 mod tray;
 mod twoskd;
-
-use rand::Rng;
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Deserialize)]
-pub struct GeneratorOptions {
-    length: usize,
-    uppercase: bool,
-    lowercase: bool,
-    numbers: bool,
-    symbols: bool,
-}
-
-#[derive(Debug, Serialize)]
-pub struct GeneratorResult {
-    password: String,
-    strength: String,
-}
-
-const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
-const NUMBERS: &[u8] = b"0123456789";
-const SYMBOLS: &[u8] = b"!@#$%^&*()_+-=[]{}|;:,.<>?";
-
-fn calculate_strength(length: usize, charset_size: usize) -> String {
-    let entropy = (length as f64) * (charset_size as f64).log2();
-    if entropy >= 128.0 {
-        "excellent".to_string()
-    } else if entropy >= 80.0 {
-        "strong".to_string()
-    } else if entropy >= 60.0 {
-        "good".to_string()
-    } else if entropy >= 40.0 {
-        "fair".to_string()
-    } else {
-        "weak".to_string()
-    }
-}
-
-#[tauri::command]
-fn generate_password(options: GeneratorOptions) -> Result<GeneratorResult, String> {
-    let mut charset = Vec::new();
-
-    if options.uppercase {
-        charset.extend_from_slice(UPPERCASE);
-    }
-    if options.lowercase {
-        charset.extend_from_slice(LOWERCASE);
-    }
-    if options.numbers {
-        charset.extend_from_slice(NUMBERS);
-    }
-    if options.symbols {
-        charset.extend_from_slice(SYMBOLS);
-    }
-
-    if charset.is_empty() {
-        return Err("at least one character type required".to_string());
-    }
-
-    let length = options.length.clamp(4, 128);
-    let mut rng = rand::thread_rng();
-
-    let password: String = (0..length)
-        .map(|_| {
-            let idx = rng.gen_range(0..charset.len());
-            charset[idx] as char
-        })
-        .collect();
-
-    let strength = calculate_strength(length, charset.len());
-
-    Ok(GeneratorResult { password, strength })
-}
+mod urlmatch;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_window_state::Builder::new().build())
+        .manage(agent::AgentState::default())
+        .manage(autosync::SyncWorker::default())
+        .manage(breach::BreachState::default())
+        .manage(envrun::EnvRunState::default())
+        .manage(folders::FolderStore::default())
+        .manage(generator::GeneratorState::default())
+        .manage(keepass::KeepassStore::default())
+        .manage(policy::PolicyStore::default())
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -98,7 +52,31 @@ pub fn run() {
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
-            generate_password,
+            generator::generate_password,
+            strength::strength_estimate,
+            audit::audit_vault,
+            breach::breach_source,
+            breach::breach_configure,
+            breach::breach_check,
+            agent::agent_start,
+            agent::agent_stop,
+            agent::agent_status,
+            agent::agent_approve,
+            envrun::envrun_start,
+            envrun::envrun_stop,
+            envrun::envrun_status,
+            envrun::envrun_approve,
+            sshkey::sshkey_generate,
+            sshkey::sshkey_import,
+            sshkey::sshkey_inspect,
+            sshkey::sshkey_fingerprint,
+            sshkey::sshkey_authorized_keys,
+            policy::policy_parse_rules,
+            policy::policy_list,
+            policy::policy_save,
+            policy::policy_delete,
+            policy::policy_attach,
+            policy::policy_for_url,
             auth::login,
             auth::register,
             auth::auth_get_session,
@@ -116,12 +94,69 @@ pub fn run() {
             commands::add_vault_entry,
             commands::update_vault_entry,
             commands::delete_vault_entry,
+            commands::list_vault_trash,
+            commands::restore_vault_entry,
+            commands::purge_vault_entry,
+            commands::empty_vault_trash,
+            commands::add_vault_folder,
+            commands::update_vault_folder,
+            commands::delete_vault_folder,
+            commands::move_vault_entry,
             commands::clear_all,
             sync::sync_fetch,
             sync::sync_create,
             sync::sync_update,
             sync::sync_delete,
+            sync::sync_trash,
+            sync::sync_restore,
+            sync::sync_purge,
+            sync::sync_empty_trash,
             sync::sync_login,
+            autosync::sync_start,
+            autosync::sync_stop,
+            autosync::sync_set_interval,
+            autosync::sync_now,
+            autosync::sync_push,
+            autosync::sync_resolve_conflict,
+            autosync::sync_pending,
+            batch::sync_batch,
+            folders::folder_fetch,
+            folders::folder_create,
+            folders::folder_update,
+            folders::folder_move,
+            folders::folder_delete,
+            folders::folder_move_items,
+            folders::folder_children,
+            folders::folder_path,
+            folders::folder_descendants,
+            search::search_query,
+            search::search_ready,
+            urlmatch::url_match,
+            urlmatch::url_registrable_domain,
+            otp::otp_parse,
+            otp::otp_uri,
+            otp::otp_generate,
+            otp::otp_from_item,
+            otpimport::otp_import_preview,
+            import::import_detect,
+            import::import_preview,
+            import::import_to_storage,
+            backup::backup_export,
+            backup::backup_manifest,
+            backup::backup_import,
+            backup::backup_export_plain,
+            keepass::keepass_open,
+            keepass::keepass_create,
+            keepass::keepass_reload,
+            keepass::keepass_close,
+            keepass::keepass_info,
+            keepass::keepass_list_items,
+            keepass::keepass_list_folders,
+            keepass::keepass_create_item,
+            keepass::keepass_update_item,
+            keepass::keepass_delete_item,
+            keepass::keepass_move_item,
+            keepass::keepass_create_folder,
             plugins::rounded::enable_rounded_corners,
             biometric::biometric_available,
             biometric::biometric_authenticate,
@@ -0,0 +1,22 @@
+use noro_common::otp::{fromdata, parse, OtpCode, OtpConfig, OtpError};
+use noro_common::time::now;
+
+#[tauri::command]
+pub fn otp_parse(input: String) -> Result<OtpConfig, OtpError> {
+    parse(&input)
+}
+
+#[tauri::command]
+pub fn otp_uri(config: OtpConfig) -> String {
+    config.touri()
+}
+
+#[tauri::command]
+pub fn otp_generate(config: OtpConfig, timestamp: Option<u64>) -> Result<OtpCode, OtpError> {
+    config.generate(timestamp.unwrap_or_else(now))
+}
+
+#[tauri::command]
+pub fn otp_from_item(data: String) -> Result<Option<OtpConfig>, OtpError> {
+    fromdata(data.as_bytes()).transpose()
+}
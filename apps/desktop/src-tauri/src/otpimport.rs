@@ -0,0 +1,18 @@
+use noro_common::otp::{self, OtpConfig};
+use noro_common::otpimport::{preview, OtpImportPreview};
+
+use crate::sync::{self, SyncError};
+
+#[tauri::command]
+pub async fn otp_import_preview(
+    base_url: String,
+    token: String,
+    text: String,
+) -> Result<OtpImportPreview, SyncError> {
+    let existing: Vec<(String, OtpConfig)> = sync::fetchitems(&base_url, &token)
+        .await?
+        .into_iter()
+        .filter_map(|i| Some((i.id, otp::fromdata(i.data.as_bytes())?.ok()?)))
+        .collect();
+    Ok(preview(&text, &existing))
+}
@@ -0,0 +1,545 @@
+use noro_common::urlmatch;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::State;
+
+use crate::import::newid;
+use crate::storage;
+
+const POLICIES_FILE: &str = "policies.json";
+const UPPER: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWER: &str = "abcdefghijklmnopqrstuvwxyz";
+const DIGIT: &str = "0123456789";
+const SPECIAL: &str = " -~!@#$%^&*_+=`|(){}[:;\"'<>,.?]/\\";
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PolicyError {
+    #[error("invalid password rule: {0}")]
+    InvalidRule(String),
+    #[error("invalid url")]
+    InvalidUrl,
+    #[error("policy not found")]
+    NotFound,
+    #[error("storage error: {0}")]
+    Storage(String),
+}
+
+impl Serialize for PolicyError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordRules {
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub max_consecutive: Option<usize>,
+    pub allowed: String,
+    pub required: Vec<String>,
+}
+
+impl Default for PasswordRules {
+    fn default() -> Self {
+        Self {
+            min_length: None,
+            max_length: None,
+            max_consecutive: None,
+            allowed: printable(),
+            required: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PasswordPolicy {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub rules: PasswordRules,
+    #[serde(default)]
+    pub domains: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct PolicyStore {
+    policies: Mutex<Option<Vec<PasswordPolicy>>>,
+}
+
+impl PolicyStore {
+    fn with<T>(
+        &self,
+        f: impl FnOnce(&mut Vec<PasswordPolicy>) -> Result<T, PolicyError>,
+    ) -> Result<T, PolicyError> {
+        let mut guard = self
+            .policies
+            .lock()
+            .map_err(|e| PolicyError::Storage(e.to_string()))?;
+        if guard.is_none() {
+            let policies = storage::get_settings(POLICIES_FILE)
+                .map_err(|e| PolicyError::Storage(e.to_string()))?;
+            *guard = Some(policies);
+        }
+        f(guard.get_or_insert_with(Vec::new))
+    }
+
+    fn save<T>(
+        &self,
+        f: impl FnOnce(&mut Vec<PasswordPolicy>) -> Result<T, PolicyError>,
+    ) -> Result<T, PolicyError> {
+        self.with(|policies| {
+            let value = f(policies)?;
+            storage::store_settings(POLICIES_FILE, policies)
+                .map_err(|e| PolicyError::Storage(e.to_string()))?;
+            Ok(value)
+        })
+    }
+
+    pub fn forurl(&self, url: &str) -> Option<PasswordPolicy> {
+        let domain = domain(url)?;
+        self.with(|policies| {
+            Ok(policies
+                .iter()
+                .find(|p| p.domains.contains(&domain))
+                .cloned())
+        })
+        .ok()
+        .flatten()
+    }
+}
+
+fn printable() -> String {
+    (' '..='~').collect()
+}
+
+fn domain(url: &str) -> Option<String> {
+    let url = urlmatch::parseurl(url)?;
+    let host = url.host_str()?;
+    Some(urlmatch::registrabledomain(host).unwrap_or_else(|| host.to_lowercase()))
+}
+
+fn merge(set: &mut String, chars: &str) {
+    for c in chars.chars() {
+        if !set.contains(c) {
+            set.push(c);
+        }
+    }
+}
+
+fn closing(value: &str) -> Option<usize> {
+    let mut chars = value.char_indices().skip(1).peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == ']' && chars.peek().map(|(_, next)| *next) != Some(']') {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn charclass(value: &str) -> Result<String, PolicyError> {
+    let value = value.trim();
+    if let Some(inner) = value.strip_prefix('[') {
+        let inner = inner
+            .strip_suffix(']')
+            .ok_or_else(|| PolicyError::InvalidRule(format!("unterminated class {}", value)))?;
+        let mut chars = String::new();
+        merge(&mut chars, inner);
+        return Ok(chars);
+    }
+    match value.to_lowercase().as_str() {
+        "upper" => Ok(UPPER.to_string()),
+        "lower" => Ok(LOWER.to_string()),
+        "digit" => Ok(DIGIT.to_string()),
+        "special" => Ok(SPECIAL.to_string()),
+        "ascii-printable" | "unicode" => Ok(printable()),
+        other => Err(PolicyError::InvalidRule(format!(
+            "unknown character class {}",
+            other
+        ))),
+    }
+}
+
+fn classes(value: &str) -> Result<String, PolicyError> {
+    let mut chars = String::new();
+    let mut rest = value.trim();
+    while !rest.is_empty() {
+        let end = if rest.starts_with('[') {
+            closing(rest)
+                .map(|i| i + 1)
+                .ok_or_else(|| PolicyError::InvalidRule(format!("unterminated class {}", rest)))?
+        } else {
+            rest.find(',').unwrap_or(rest.len())
+        };
+        merge(&mut chars, &charclass(&rest[..end])?);
+        rest = rest[end..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+    Ok(chars)
+}
+
+fn number(name: &str, value: &str) -> Result<usize, PolicyError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| PolicyError::InvalidRule(format!("{} must be a number", name)))
+}
+
+fn properties(rules: &str) -> Result<Vec<(String, &str)>, PolicyError> {
+    let mut found = Vec::new();
+    let mut rest = rules.trim();
+    while !rest.is_empty() {
+        let colon = rest
+            .find(':')
+            .ok_or_else(|| PolicyError::InvalidRule(format!("missing value in {}", rest)))?;
+        let name = rest[..colon].trim().to_lowercase();
+        let mut end = colon + 1;
+        while end < rest.len() {
+            match rest.as_bytes()[end] {
+                b'[' => end += closing(&rest[end..]).unwrap_or(rest.len() - end),
+                b';' => break,
+                _ => end += 1,
+            }
+        }
+        found.push((name, &rest[colon + 1..end]));
+        rest = rest[end.min(rest.len())..].trim_start();
+        rest = rest.strip_prefix(';').unwrap_or(rest).trim_start();
+    }
+    Ok(found)
+}
+
+pub fn parserules(rules: &str) -> Result<PasswordRules, PolicyError> {
+    let mut parsed = PasswordRules {
+        allowed: String::new(),
+        ..Default::default()
+    };
+    for (name, value) in properties(rules)? {
+        match name.as_str() {
+            "minlength" => {
+                let n = number(&name, value)?;
+                parsed.min_length = Some(parsed.min_length.map_or(n, |m| m.max(n)));
+            }
+            "maxlength" => {
+                let n = number(&name, value)?;
+                parsed.max_length = Some(parsed.max_length.map_or(n, |m| m.min(n)));
+            }
+            "max-consecutive" => {
+                let n = number(&name, value)?;
+                parsed.max_consecutive = Some(parsed.max_consecutive.map_or(n, |m| m.min(n)));
+            }
+            "allowed" => merge(&mut parsed.allowed, &classes(value)?),
+            "required" => {
+                let set = classes(value)?;
+                if !set.is_empty() && !parsed.required.contains(&set) {
+                    parsed.required.push(set);
+                }
+            }
+            _ => {}
+        }
+    }
+    if parsed.allowed.is_empty() && parsed.required.is_empty() {
+        parsed.allowed = printable();
+    }
+    for set in &parsed.required {
+        merge(&mut parsed.allowed, set);
+    }
+    if let (Some(min), Some(max)) = (parsed.min_length, parsed.max_length) {
+        if min > max {
+            return Err(PolicyError::InvalidRule(
+                "minlength is greater than maxlength".to_string(),
+            ));
+        }
+    }
+    Ok(parsed)
+}
+
+#[tauri::command]
+pub fn policy_parse_rules(rules: String) -> Result<PasswordRules, PolicyError> {
+    parserules(&rules)
+}
+
+#[tauri::command]
+pub fn policy_list(store: State<'_, PolicyStore>) -> Result<Vec<PasswordPolicy>, PolicyError> {
+    store.with(|policies| Ok(policies.clone()))
+}
+
+#[tauri::command]
+pub fn policy_save(
+    store: State<'_, PolicyStore>,
+    policy: PasswordPolicy,
+) -> Result<PasswordPolicy, PolicyError> {
+    let mut policy = policy;
+    if policy.name.trim().is_empty() {
+        return Err(PolicyError::InvalidRule("name is required".to_string()));
+    }
+    if policy.id.is_empty() {
+        policy.id = newid();
+    }
+    let mut domains = Vec::new();
+    for raw in &policy.domains {
+        let domain = domain(raw)
+            .or_else(|| domain(&format!("https://{}", raw.trim())))
+            .ok_or(PolicyError::InvalidUrl)?;
+        if !domains.contains(&domain) {
+            domains.push(domain);
+        }
+    }
+    policy.domains = domains;
+    store.save(|policies| {
+        for other in policies.iter_mut().filter(|p| p.id != policy.id) {
+            other.domains.retain(|d| !policy.domains.contains(d));
+        }
+        match policies.iter_mut().find(|p| p.id == policy.id) {
+            Some(existing) => *existing = policy.clone(),
+            None => policies.push(policy.clone()),
+        }
+        Ok(policy)
+    })
+}
+
+#[tauri::command]
+pub fn policy_delete(store: State<'_, PolicyStore>, id: String) -> Result<(), PolicyError> {
+    store.save(|policies| {
+        let before = policies.len();
+        policies.retain(|p| p.id != id);
+        if policies.len() == before {
+            return Err(PolicyError::NotFound);
+        }
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub fn policy_attach(
+    store: State<'_, PolicyStore>,
+    url: String,
+    id: Option<String>,
+) -> Result<(), PolicyError> {
+    let domain = domain(&url).ok_or(PolicyError::InvalidUrl)?;
+    store.save(|policies| {
+        if let Some(id) = &id {
+            if !policies.iter().any(|p| &p.id == id) {
+                return Err(PolicyError::NotFound);
+            }
+        }
+        for policy in policies.iter_mut() {
+            policy.domains.retain(|d| d != &domain);
+            if Some(&policy.id) == id.as_ref() {
+                policy.domains.push(domain.clone());
+            }
+        }
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub fn policy_for_url(store: State<'_, PolicyStore>, url: String) -> Option<PasswordPolicy> {
+    store.forurl(&url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator;
+    use noro_common::generator::{GeneratorError, GeneratorOptions};
+
+    fn generate(rules: &str, options: GeneratorOptions) -> Result<String, GeneratorError> {
+        generator::generaterules(&parserules(rules).unwrap(), &options).map(|r| r.password)
+    }
+
+    fn invalid(message: &str) -> Result<PasswordRules, PolicyError> {
+        Err(PolicyError::InvalidRule(message.to_string()))
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = parserules(
+            "minlength: 8; maxlength: 16; required: lower; required: upper; \
+             required: digit; required: [-_]; allowed: [!]; max-consecutive: 2;",
+        )
+        .unwrap();
+        assert_eq!(rules.min_length, Some(8));
+        assert_eq!(rules.max_length, Some(16));
+        assert_eq!(rules.max_consecutive, Some(2));
+        assert_eq!(rules.required, [LOWER, UPPER, DIGIT, "-_"]);
+        assert!(rules.allowed.contains('!') && rules.allowed.contains('a'));
+        assert!(!rules.allowed.contains('@'));
+    }
+
+    #[test]
+    fn test_parse_repeated_properties() {
+        let rules = parserules(
+            "MinLength: 8; minlength: 12; maxlength: 30; maxlength: 20; \
+             max-consecutive: 3; max-consecutive: 2; required: digit; required: digit",
+        )
+        .unwrap();
+        assert_eq!(rules.min_length, Some(12));
+        assert_eq!(rules.max_length, Some(20));
+        assert_eq!(rules.max_consecutive, Some(2));
+        assert_eq!(rules.required, [DIGIT]);
+        assert_eq!(rules.allowed, DIGIT);
+    }
+
+    #[test]
+    fn test_parse_bracket_classes() {
+        let brackets = parserules("required: upper, [;]]; allowed: digit").unwrap();
+        assert_eq!(brackets.required, [format!("{UPPER};]")]);
+        assert_eq!(brackets.allowed.len(), 26 + 2 + 10);
+        assert_eq!(parserules("allowed: [aab]").unwrap().allowed, "ab");
+        assert_eq!(
+            parserules("required: []").unwrap().required,
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_rules() {
+        for rules in ["", "  ", "passwordrules-version: 2", "allowed: []"] {
+            let parsed = parserules(rules).unwrap();
+            assert_eq!(parsed, PasswordRules::default(), "{rules}");
+            assert_eq!(parsed.allowed.len(), 95);
+        }
+        assert_eq!(parserules("allowed: unicode").unwrap().allowed, printable());
+    }
+
+    #[test]
+    fn test_parse_invalid_rules() {
+        assert_eq!(
+            parserules("minlength: 20; maxlength: 10"),
+            invalid("minlength is greater than maxlength")
+        );
+        assert_eq!(
+            parserules("required: emoji"),
+            invalid("unknown character class emoji")
+        );
+        assert_eq!(
+            parserules("minlength: lots"),
+            invalid("minlength must be a number")
+        );
+        assert_eq!(
+            parserules("maxlength: -1"),
+            invalid("maxlength must be a number")
+        );
+        assert_eq!(
+            parserules("minlength 8"),
+            invalid("missing value in minlength 8")
+        );
+        assert!(parserules("allowed: [abc").is_err());
+        assert!(parserules("required: upper, [abc").is_err());
+    }
+
+    #[test]
+    fn test_generate_satisfies_rules() {
+        let rules = parserules(
+            "minlength: 8; maxlength: 16; required: lower; required: upper; \
+             required: digit; required: [-_]; allowed: [!]; max-consecutive: 2;",
+        )
+        .unwrap();
+        for _ in 0..100 {
+            let password = generator::generaterules(
+                &rules,
+                &GeneratorOptions {
+                    length: 40,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .password;
+            assert_eq!(password.len(), 16);
+            assert!(password.chars().all(|c| rules.allowed.contains(c)));
+            assert!(rules
+                .required
+                .iter()
+                .all(|set| password.chars().any(|c| set.contains(c))));
+            let chars: Vec<char> = password.chars().collect();
+            assert!(chars.windows(3).all(|w| !(w[0] == w[1] && w[1] == w[2])));
+        }
+    }
+
+    #[test]
+    fn test_generate_clamps_length() {
+        let short = GeneratorOptions {
+            length: 1,
+            ..Default::default()
+        };
+        assert_eq!(generate("minlength: 12", short.clone()).unwrap().len(), 12);
+        assert_eq!(generate("", short).unwrap().len(), 4);
+        let long = GeneratorOptions {
+            length: 64,
+            ..Default::default()
+        };
+        assert_eq!(generate("maxlength: 10", long).unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_unsatisfiable_policy() {
+        assert_eq!(
+            generate("maxlength: 3", GeneratorOptions::default()),
+            Err(GeneratorError::TooShort)
+        );
+        assert_eq!(
+            generate("minlength: 200", GeneratorOptions::default()),
+            Err(GeneratorError::TooShort)
+        );
+        assert_eq!(
+            generate(
+                "required: digit; required: [x]",
+                GeneratorOptions {
+                    exclude: "x".into(),
+                    ..Default::default()
+                }
+            ),
+            Err(GeneratorError::NoCharacters)
+        );
+        assert_eq!(
+            generate(
+                "allowed: [0O]",
+                GeneratorOptions {
+                    exclude_ambiguous: true,
+                    ..Default::default()
+                }
+            ),
+            Err(GeneratorError::NoCharacters)
+        );
+        assert_eq!(
+            generate(
+                "required: [a]; max-consecutive: 1",
+                GeneratorOptions::default()
+            ),
+            Err(GeneratorError::NotEnoughCharacters)
+        );
+    }
+
+    #[test]
+    fn test_domain() {
+        assert_eq!(
+            domain("https://login.example.co.uk/path").as_deref(),
+            Some("example.co.uk")
+        );
+        assert_eq!(domain("Example.COM").as_deref(), Some("example.com"));
+        assert_eq!(
+            domain("http://localhost:8080").as_deref(),
+            Some("localhost")
+        );
+        assert_eq!(domain(""), None);
+    }
+
+    #[test]
+    fn test_forurl() {
+        let policy = PasswordPolicy {
+            id: "bank".into(),
+            name: "Bank".into(),
+            rules: parserules("maxlength: 12").unwrap(),
+            domains: vec!["example.com".into()],
+        };
+        let store = PolicyStore {
+            policies: Mutex::new(Some(vec![policy.clone()])),
+        };
+        assert_eq!(store.forurl("https://login.example.com/"), Some(policy));
+        assert_eq!(store.forurl("https://example.org"), None);
+        assert_eq!(store.forurl("not a url at all"), None);
+    }
+}
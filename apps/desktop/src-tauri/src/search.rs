@@ -0,0 +1,57 @@
+use noro_common::search::{SearchDoc, SearchHit, SearchIndex};
+use std::sync::RwLock;
+
+use crate::sync::RemoteItem;
+
+static INDEX: RwLock<Option<SearchIndex>> = RwLock::new(None);
+
+fn searchdoc(item: &RemoteItem) -> SearchDoc {
+    SearchDoc::new(
+        &item.id,
+        &item.item_type,
+        &item.title,
+        item.data.as_bytes(),
+        &item.tags.iter().map(|t| t.name.clone()).collect::<Vec<_>>(),
+        item.favorite,
+    )
+}
+
+pub fn rebuild(items: &[RemoteItem]) {
+    let index = SearchIndex::build(items.iter().filter(|i| !i.deleted).map(searchdoc));
+    *INDEX.write().unwrap() = Some(index);
+}
+
+pub fn upsert(item: &RemoteItem) {
+    let mut guard = INDEX.write().unwrap();
+    let index = guard.get_or_insert_with(SearchIndex::default);
+    if item.deleted {
+        index.remove(&item.id);
+    } else {
+        index.upsert(searchdoc(item));
+    }
+}
+
+pub fn remove(id: &str) {
+    if let Some(index) = INDEX.write().unwrap().as_mut() {
+        index.remove(id);
+    }
+}
+
+pub fn clear() {
+    *INDEX.write().unwrap() = None;
+}
+
+#[tauri::command]
+pub fn search_query(query: String, limit: Option<usize>) -> Vec<SearchHit> {
+    INDEX
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|index| index.search(&query, limit.unwrap_or(50)))
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn search_ready() -> bool {
+    INDEX.read().unwrap().is_some()
+}
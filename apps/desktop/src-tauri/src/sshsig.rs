@@ -0,0 +1,831 @@
+use chrono::{Local, NaiveDate};
+use ssh_key::{Algorithm, HashAlg, LineEnding, PrivateKey, PublicKey, Signature, SshSig};
+use std::io::{Read, Write};
+use std::path::Path;
+
+use crate::agent::{
+    self, message, readstring, readu32, writestring, SSH_AGENTC_SIGN_REQUEST,
+    SSH_AGENT_RSA_SHA2_512, SSH_AGENT_SIGN_RESPONSE,
+};
+
+const HASH: HashAlg = HashAlg::Sha512;
+const FAILED: i32 = 255;
+const TIME_FORMAT: &str = "%Y%m%d%H%M%S";
+const VALUE_FLAGS: &[&str] = &["-Y", "-n", "-f", "-I", "-s", "-O", "-r"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum SshSigError {
+    #[error("{0}")]
+    Usage(String),
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("invalid key: {0}")]
+    Key(String),
+    #[error("agent error: {0}")]
+    Agent(String),
+    #[error("invalid signature: {0}")]
+    Signature(String),
+    #[error("no allowed signer matches")]
+    NotAllowed,
+}
+
+fn ioerr(e: impl std::fmt::Display) -> SshSigError {
+    SshSigError::Io(e.to_string())
+}
+
+fn sigerr(e: ssh_key::Error) -> SshSigError {
+    SshSigError::Signature(e.to_string())
+}
+
+#[derive(Debug, Clone)]
+pub struct AllowedSigner {
+    pub principals: String,
+    pub namespaces: Option<Vec<String>>,
+    pub valid_after: Option<String>,
+    pub valid_before: Option<String>,
+    pub key: PublicKey,
+}
+
+impl AllowedSigner {
+    fn accepts(&self, key: &SshSig, namespace: Option<&str>, time: &str) -> bool {
+        self.key.key_data() == key.public_key()
+            && namespace.map_or(true, |ns| {
+                self.namespaces
+                    .as_ref()
+                    .map_or(true, |list| matchlist(list, ns))
+            })
+            && self.valid_after.as_deref().map_or(true, |t| time >= t)
+            && self.valid_before.as_deref().map_or(true, |t| time <= t)
+    }
+}
+
+fn wildcard(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            wildcard(&pattern[1..], text) || (!text.is_empty() && wildcard(pattern, &text[1..]))
+        }
+        (Some(b'?'), Some(_)) => wildcard(&pattern[1..], &text[1..]),
+        (Some(p), Some(t)) if p == t => wildcard(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
+
+fn matchlist(patterns: &[String], name: &str) -> bool {
+    let mut found = false;
+    for pattern in patterns {
+        match pattern.strip_prefix('!') {
+            Some(negated) if wildcard(negated.as_bytes(), name.as_bytes()) => return false,
+            Some(_) => {}
+            None => found |= wildcard(pattern.as_bytes(), name.as_bytes()),
+        }
+    }
+    found
+}
+
+fn split(text: &str, separator: impl Fn(char) -> bool) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in text.chars() {
+        if c == '"' {
+            quoted = !quoted;
+            current.push(c);
+        } else if !quoted && separator(c) {
+            if !current.is_empty() {
+                parts.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn timestamp(value: &str) -> Option<String> {
+    let digits = value.trim_end_matches(['Z', 'z']);
+    if !digits.bytes().all(|b| b.is_ascii_digit()) || ![8, 12, 14].contains(&digits.len()) {
+        return None;
+    }
+    let padded = format!("{:0<14}", digits);
+    if digits.len() == value.len() {
+        return Some(padded);
+    }
+    let part = |range: std::ops::Range<usize>| padded[range].parse::<u32>().ok();
+    let utc = NaiveDate::from_ymd_opt(part(0..4)? as i32, part(4..6)?, part(6..8)?)?
+        .and_hms_opt(part(8..10)?, part(10..12)?, part(12..14)?)?
+        .and_utc();
+    Some(utc.with_timezone(&Local).format(TIME_FORMAT).to_string())
+}
+
+fn now() -> String {
+    Local::now().format(TIME_FORMAT).to_string()
+}
+
+pub fn parseallowed(text: &str) -> Vec<AllowedSigner> {
+    let mut signers = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = split(line, char::is_whitespace);
+        if fields.len() < 3 {
+            continue;
+        }
+        let (options, key) = match PublicKey::from_openssh(&fields[1..].join(" ")) {
+            Ok(key) => (None, key),
+            Err(_) => match PublicKey::from_openssh(&fields[2..].join(" ")) {
+                Ok(key) => (Some(&fields[1]), key),
+                Err(_) => continue,
+            },
+        };
+        let mut signer = AllowedSigner {
+            principals: unquote(&fields[0]).to_string(),
+            namespaces: None,
+            valid_after: None,
+            valid_before: None,
+            key,
+        };
+        let mut supported = true;
+        for option in options.map(|o| split(o, |c| c == ',')).unwrap_or_default() {
+            let (name, value) = option.split_once('=').unwrap_or((&option, ""));
+            let value = unquote(value);
+            match name.to_ascii_lowercase().as_str() {
+                "namespaces" => {
+                    signer.namespaces = Some(value.split(',').map(str::to_string).collect())
+                }
+                "valid-after" => signer.valid_after = timestamp(value),
+                "valid-before" => signer.valid_before = timestamp(value),
+                _ => supported = false,
+            }
+        }
+        if supported {
+            signers.push(signer);
+        }
+    }
+    signers
+}
+
+pub fn verify<'a>(
+    signers: &'a [AllowedSigner],
+    principal: &str,
+    namespace: &str,
+    time: &str,
+    msg: &[u8],
+    signature: &SshSig,
+) -> Result<&'a AllowedSigner, SshSigError> {
+    let signer = signers
+        .iter()
+        .filter(|s| s.accepts(signature, Some(namespace), time))
+        .find(|s| {
+            let principals: Vec<String> = s.principals.split(',').map(str::to_string).collect();
+            matchlist(&principals, principal)
+        })
+        .ok_or(SshSigError::NotAllowed)?;
+    signer
+        .key
+        .verify(namespace, msg, signature)
+        .map_err(sigerr)?;
+    Ok(signer)
+}
+
+pub fn findprincipals(signers: &[AllowedSigner], signature: &SshSig, time: &str) -> Vec<String> {
+    signers
+        .iter()
+        .filter(|s| s.accepts(signature, None, time))
+        .map(|s| s.principals.clone())
+        .collect()
+}
+
+pub fn signingkey(text: &str) -> Result<PublicKey, SshSigError> {
+    let text = text.trim();
+    let text = text.strip_prefix("key::").unwrap_or(text);
+    if let Ok(key) = PublicKey::from_openssh(text) {
+        return Ok(key);
+    }
+    PrivateKey::from_openssh(text)
+        .map(|k| k.public_key().clone())
+        .map_err(|e| SshSigError::Key(e.to_string()))
+}
+
+fn agentsignature(response: &[u8]) -> Option<Signature> {
+    let (&kind, mut payload) = response.split_first()?;
+    if kind != SSH_AGENT_SIGN_RESPONSE {
+        return None;
+    }
+    let mut blob = readstring(&mut payload)?;
+    let algorithm = std::str::from_utf8(readstring(&mut blob)?).ok()?;
+    let data = readstring(&mut blob)?;
+    Signature::new(Algorithm::new(algorithm).ok()?, data).ok()
+}
+
+#[cfg(unix)]
+pub fn agentsign(socket: &Path, key: &PublicKey, data: &[u8]) -> Result<Signature, SshSigError> {
+    let blob = key
+        .to_bytes()
+        .map_err(|e| SshSigError::Key(e.to_string()))?;
+    let mut payload = Vec::new();
+    writestring(&mut payload, &blob);
+    writestring(&mut payload, data);
+    let flags = if key.key_data().is_rsa() {
+        SSH_AGENT_RSA_SHA2_512
+    } else {
+        0
+    };
+    payload.extend_from_slice(&flags.to_be_bytes());
+
+    let mut stream = std::os::unix::net::UnixStream::connect(socket).map_err(|e| {
+        SshSigError::Agent(format!(
+            "{}: {} (is the app running with the ssh agent started?)",
+            socket.display(),
+            e
+        ))
+    })?;
+    stream
+        .write_all(&message(SSH_AGENTC_SIGN_REQUEST, &payload))
+        .map_err(ioerr)?;
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len).map_err(ioerr)?;
+    let len = readu32(&mut &len[..]).unwrap_or(0) as usize;
+    let mut response = vec![0u8; len];
+    stream.read_exact(&mut response).map_err(ioerr)?;
+    agentsignature(&response)
+        .ok_or_else(|| SshSigError::Agent("signing was refused or the key is not loaded".into()))
+}
+
+#[cfg(not(unix))]
+pub fn agentsign(_socket: &Path, _key: &PublicKey, _data: &[u8]) -> Result<Signature, SshSigError> {
+    Err(SshSigError::Agent("not supported on this platform".into()))
+}
+
+pub fn sign(
+    socket: &Path,
+    key: &PublicKey,
+    namespace: &str,
+    msg: &[u8],
+) -> Result<SshSig, SshSigError> {
+    let data = SshSig::signed_data(namespace, HASH, msg).map_err(sigerr)?;
+    let signature = agentsign(socket, key, &data)?;
+    let sig = SshSig::new(key.key_data().clone(), namespace, HASH, signature).map_err(sigerr)?;
+    key.verify(namespace, msg, &sig).map_err(sigerr)?;
+    Ok(sig)
+}
+
+fn keytype(sig: &SshSig) -> String {
+    match sig.algorithm() {
+        Algorithm::Ed25519 => "ED25519".into(),
+        Algorithm::Ecdsa { .. } => "ECDSA".into(),
+        Algorithm::Rsa { .. } => "RSA".into(),
+        other => other.as_str().to_ascii_uppercase(),
+    }
+}
+
+#[derive(Default)]
+struct Args {
+    op: String,
+    namespace: Option<String>,
+    file: Option<String>,
+    identity: Option<String>,
+    signature: Option<String>,
+    revoked: Option<String>,
+    options: Vec<String>,
+    rest: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, SshSigError> {
+        let mut parsed = Args::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let (flag, mut attached) = match arg.get(..2) {
+                Some(flag) if arg.len() > 2 && VALUE_FLAGS.contains(&flag) => {
+                    (flag, Some(arg[2..].to_string()))
+                }
+                _ => (arg.as_str(), None),
+            };
+            let mut value = |flag: &str| {
+                attached
+                    .take()
+                    .or_else(|| iter.next().cloned())
+                    .ok_or_else(|| SshSigError::Usage(format!("{} needs a value", flag)))
+            };
+            match flag {
+                "-Y" => parsed.op = value(flag)?,
+                "-n" => parsed.namespace = Some(value(flag)?),
+                "-f" => parsed.file = Some(value(flag)?),
+                "-I" => parsed.identity = Some(value(flag)?),
+                "-s" => parsed.signature = Some(value(flag)?),
+                "-O" => parsed.options.push(value(flag)?),
+                "-r" => parsed.revoked = Some(value(flag)?),
+                "-U" | "-q" => {}
+                flag if flag.starts_with('-') && flag != "-" => {
+                    return Err(SshSigError::Usage(format!("unknown option {}", flag)))
+                }
+                _ => parsed.rest.push(arg.clone()),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn require<'a>(value: &'a Option<String>, flag: &str) -> Result<&'a str, SshSigError> {
+        value
+            .as_deref()
+            .ok_or_else(|| SshSigError::Usage(format!("missing {}", flag)))
+    }
+
+    fn time(&self) -> String {
+        self.options
+            .iter()
+            .filter_map(|o| o.strip_prefix("verify-time="))
+            .find_map(timestamp)
+            .unwrap_or_else(now)
+    }
+
+    fn signers(&self) -> Result<Vec<AllowedSigner>, SshSigError> {
+        let path = Self::require(&self.file, "-f allowed_signers")?;
+        let mut signers = parseallowed(&std::fs::read_to_string(path).map_err(ioerr)?);
+        if let Some(path) = &self.revoked {
+            let revoked: Vec<PublicKey> = std::fs::read_to_string(path)
+                .map_err(ioerr)?
+                .lines()
+                .filter_map(|line| PublicKey::from_openssh(line.trim()).ok())
+                .collect();
+            signers.retain(|s| !revoked.iter().any(|r| r.key_data() == s.key.key_data()));
+        }
+        Ok(signers)
+    }
+
+    fn sshsig(&self) -> Result<SshSig, SshSigError> {
+        let path = Self::require(&self.signature, "-s signature_file")?;
+        SshSig::from_pem(std::fs::read(path).map_err(ioerr)?).map_err(sigerr)
+    }
+}
+
+fn readinput(path: &str, stdin: &mut impl Read) -> Result<Vec<u8>, SshSigError> {
+    let mut msg = Vec::new();
+    if path == "-" {
+        stdin.read_to_end(&mut msg).map_err(ioerr)?;
+    } else {
+        msg = std::fs::read(path).map_err(ioerr)?;
+    }
+    Ok(msg)
+}
+
+fn run(
+    args: &Args,
+    stdin: &mut impl Read,
+    stdout: &mut impl Write,
+    socket: &Path,
+) -> Result<i32, SshSigError> {
+    match args.op.as_str() {
+        "sign" => {
+            let namespace = Args::require(&args.namespace, "-n namespace")?;
+            let keyfile = Args::require(&args.file, "-f key_file")?;
+            let key = signingkey(&std::fs::read_to_string(keyfile).map_err(ioerr)?)?;
+            let files = match args.rest.is_empty() {
+                true => vec!["-".to_string()],
+                false => args.rest.clone(),
+            };
+            for file in &files {
+                let msg = readinput(file, stdin)?;
+                let pem = sign(socket, &key, namespace, &msg)?
+                    .to_pem(LineEnding::LF)
+                    .map_err(sigerr)?;
+                if file == "-" {
+                    stdout.write_all(pem.as_bytes()).map_err(ioerr)?;
+                } else {
+                    let path = format!("{}.sig", file);
+                    std::fs::write(&path, pem).map_err(ioerr)?;
+                }
+            }
+            Ok(0)
+        }
+        "verify" => {
+            let namespace = Args::require(&args.namespace, "-n namespace")?;
+            let principal = Args::require(&args.identity, "-I signer_identity")?;
+            let signers = args.signers()?;
+            let sig = args.sshsig()?;
+            let msg = readinput("-", stdin)?;
+            match verify(&signers, principal, namespace, &args.time(), &msg, &sig) {
+                Ok(_) => {
+                    writeln!(
+                        stdout,
+                        "Good \"{}\" signature for {} with {} key {}",
+                        namespace,
+                        principal,
+                        keytype(&sig),
+                        sig.public_key().fingerprint(HashAlg::Sha256)
+                    )
+                    .map_err(ioerr)?;
+                    Ok(0)
+                }
+                Err(e) => {
+                    eprintln!("Could not verify signature: {}", e);
+                    Ok(FAILED)
+                }
+            }
+        }
+        "find-principals" => {
+            let signers = args.signers()?;
+            let principals = findprincipals(&signers, &args.sshsig()?, &args.time());
+            for principal in &principals {
+                writeln!(stdout, "{}", principal).map_err(ioerr)?;
+            }
+            Ok(if principals.is_empty() { 1 } else { 0 })
+        }
+        "check-novalidate" => {
+            let namespace = Args::require(&args.namespace, "-n namespace")?;
+            let sig = args.sshsig()?;
+            let msg = readinput("-", stdin)?;
+            let key = PublicKey::new(sig.public_key().clone(), "");
+            match key.verify(namespace, &msg, &sig) {
+                Ok(()) => {
+                    writeln!(
+                        stdout,
+                        "Good \"{}\" signature with {} key {}",
+                        namespace,
+                        keytype(&sig),
+                        key.fingerprint(HashAlg::Sha256)
+                    )
+                    .map_err(ioerr)?;
+                    Ok(0)
+                }
+                Err(e) => {
+                    eprintln!("Could not verify signature: {}", e);
+                    Ok(FAILED)
+                }
+            }
+        }
+        "" => Err(SshSigError::Usage("missing -Y operation".into())),
+        op => Err(SshSigError::Usage(format!("unsupported operation {}", op))),
+    }
+}
+
+pub fn main(args: Vec<String>) -> i32 {
+    let result = Args::parse(&args).and_then(|args| {
+        let socket = agent::socketpath().map_err(|e| SshSigError::Agent(e.to_string()))?;
+        run(
+            &args,
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout().lock(),
+            &socket,
+        )
+    });
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("noro-ssh-sign: {}", e);
+            FAILED
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::agent::AgentKey;
+    use chrono::{TimeZone, Utc};
+    use ssh_key::private::Ed25519Keypair;
+    use std::os::unix::net::UnixListener;
+    use std::path::PathBuf;
+
+    const COMMIT: &[u8] = b"tree 1234\n\nsigned commit\n";
+
+    struct Fixture {
+        dir: PathBuf,
+        private: PrivateKey,
+        public: String,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("noro-sshsig-{}-{}", name, std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let private = PrivateKey::new(
+                Ed25519Keypair::random(&mut rand::rngs::OsRng).into(),
+                "ann@laptop",
+            )
+            .unwrap();
+            let public = private.public_key().to_openssh().unwrap();
+            Fixture {
+                dir,
+                private,
+                public,
+            }
+        }
+
+        fn path(&self, name: &str) -> String {
+            self.dir.join(name).display().to_string()
+        }
+
+        fn write(&self, name: &str, contents: impl AsRef<[u8]>) -> String {
+            std::fs::write(self.dir.join(name), contents).unwrap();
+            self.path(name)
+        }
+
+        fn signature(&self) -> String {
+            let sig = self.private.sign("git", HASH, COMMIT).unwrap();
+            self.write("commit.sig", sig.to_pem(LineEnding::LF).unwrap())
+        }
+
+        fn allowed(&self) -> String {
+            let other = Ed25519Keypair::random(&mut rand::rngs::OsRng);
+            let other = PublicKey::new(other.public.into(), "bob")
+                .to_openssh()
+                .unwrap();
+            self.write(
+                "allowed_signers",
+                format!(
+                    "# team\n*@noro.sh namespaces=\"git,file\" {}\nbob@example.com {}\n",
+                    self.public, other
+                ),
+            )
+        }
+
+        fn run(&self, argv: &[&str], input: &[u8]) -> (i32, String) {
+            let mut out = Vec::new();
+            let socket = self.dir.join("agent.sock");
+            let code = run(&args(argv), &mut &input[..], &mut out, &socket).unwrap();
+            (code, String::from_utf8(out).unwrap())
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn args(list: &[&str]) -> Args {
+        Args::parse(&list.iter().map(|s| s.to_string()).collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn test_git_argv() {
+        let find = args(&[
+            "-Y",
+            "find-principals",
+            "-f",
+            "allowed",
+            "-s",
+            "sig",
+            "-Overify-time=20261019143000",
+        ]);
+        assert_eq!(find.op, "find-principals");
+        assert_eq!(find.options, ["verify-time=20261019143000"]);
+        assert_eq!(find.time(), "20261019143000");
+
+        let verify = args(&[
+            "-Y",
+            "verify",
+            "-n",
+            "git",
+            "-f",
+            "allowed",
+            "-I",
+            "ann@noro.sh",
+            "-s",
+            "sig",
+            "-r",
+            "revoked",
+            "-Overify-time=20261019143000",
+        ]);
+        assert_eq!(verify.namespace.as_deref(), Some("git"));
+        assert_eq!(verify.identity.as_deref(), Some("ann@noro.sh"));
+        assert_eq!(verify.revoked.as_deref(), Some("revoked"));
+        assert_eq!(verify.time(), "20261019143000");
+
+        let attached = args(&[
+            "-Ycheck-novalidate",
+            "-ngit",
+            "-ssig",
+            "-O",
+            "verify-time=20200101",
+        ]);
+        assert_eq!(attached.op, "check-novalidate");
+        assert_eq!(attached.signature.as_deref(), Some("sig"));
+        assert_eq!(attached.time(), "20200101000000");
+
+        let sign = args(&["-Y", "sign", "-n", "git", "-f", "key", "-U", "buffer"]);
+        assert_eq!(sign.rest, ["buffer"]);
+
+        let error = |list: &[&str]| {
+            let list: Vec<String> = list.iter().map(|s| s.to_string()).collect();
+            Args::parse(&list).err().unwrap().to_string()
+        };
+        assert_eq!(error(&["-Y", "verify", "-O"]), "-O needs a value");
+        assert_eq!(error(&["-x"]), "unknown option -x");
+    }
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(timestamp("20261019").as_deref(), Some("20261019000000"));
+        assert_eq!(timestamp("202610191430").as_deref(), Some("20261019143000"));
+        assert_eq!(timestamp("2026101914"), None);
+        assert_eq!(timestamp("2026-10-19"), None);
+        let local = Utc
+            .with_ymd_and_hms(2026, 10, 19, 14, 30, 0)
+            .unwrap()
+            .with_timezone(&Local)
+            .format(TIME_FORMAT)
+            .to_string();
+        assert_eq!(timestamp("20261019143000Z"), Some(local));
+        assert_eq!(timestamp("20261340Z"), None);
+        assert_eq!(now().len(), 14);
+    }
+
+    #[test]
+    fn test_sign_with_agent() {
+        let fixture = Fixture::new("sign");
+        let agentkey = AgentKey {
+            id: "k".into(),
+            title: "laptop".into(),
+            blob: fixture.private.public_key().to_bytes().unwrap(),
+            key: fixture.private.clone(),
+        };
+        let listener = UnixListener::bind(fixture.dir.join("agent.sock")).unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut len = [0u8; 4];
+            stream.read_exact(&mut len).unwrap();
+            let mut request = vec![0u8; u32::from_be_bytes(len) as usize];
+            stream.read_exact(&mut request).unwrap();
+            let mut payload = &request[1..];
+            readstring(&mut payload).unwrap();
+            let data = readstring(&mut payload).unwrap();
+            stream
+                .write_all(&agent::sign(&agentkey, data, 0).unwrap())
+                .unwrap();
+        });
+
+        let keyfile = fixture.write("key.pub", format!("key::{}", fixture.public));
+        let buffer = fixture.write("commit", COMMIT);
+        let argv = ["-Y", "sign", "-n", "git", "-f", &keyfile, "-U", &buffer];
+        let (code, out) = fixture.run(&argv, b"");
+        server.join().unwrap();
+        assert_eq!((code, out.as_str()), (0, ""));
+
+        let pem = std::fs::read_to_string(fixture.path("commit.sig")).unwrap();
+        assert!(pem.starts_with("-----BEGIN SSH SIGNATURE-----"));
+        let sig = SshSig::from_pem(&pem).unwrap();
+        let public = PublicKey::from_openssh(&fixture.public).unwrap();
+        assert!(public.verify("git", COMMIT, &sig).is_ok());
+    }
+
+    #[test]
+    fn test_sign_without_agent() {
+        let fixture = Fixture::new("noagent");
+        let keyfile = fixture.write("key.pub", &fixture.public);
+        let argv = ["-Y", "sign", "-n", "git", "-f", &keyfile, "-"];
+        let result = run(
+            &args(&argv),
+            &mut &COMMIT[..],
+            &mut Vec::new(),
+            &fixture.dir.join("agent.sock"),
+        );
+        assert!(matches!(result, Err(SshSigError::Agent(_))));
+    }
+
+    #[test]
+    fn test_verify() {
+        let fixture = Fixture::new("verify");
+        let allowed = fixture.allowed();
+        let sig = fixture.signature();
+        let verify = |principal: &str, namespace: &str, input: &[u8]| {
+            let argv = [
+                "-Y",
+                "verify",
+                "-n",
+                namespace,
+                "-f",
+                &allowed,
+                "-I",
+                principal,
+                "-s",
+                &sig,
+                "-Overify-time=20261019143000",
+            ];
+            fixture.run(&argv, input)
+        };
+
+        let (code, out) = verify("ann@noro.sh", "git", COMMIT);
+        assert_eq!(code, 0);
+        assert!(out.starts_with("Good \"git\" signature for ann@noro.sh with ED25519 key SHA256:"));
+
+        assert_eq!(verify("ann@noro.sh", "git", b"forged").0, FAILED);
+        assert_eq!(verify("bob@example.com", "git", COMMIT).0, FAILED);
+        assert_eq!(verify("ann@noro.sh", "email", COMMIT).0, FAILED);
+    }
+
+    #[test]
+    fn test_verify_revoked() {
+        let fixture = Fixture::new("revoked");
+        let allowed = fixture.allowed();
+        let sig = fixture.signature();
+        let revoked = fixture.write("revoked", &fixture.public);
+        let argv = [
+            "-Y",
+            "verify",
+            "-n",
+            "git",
+            "-f",
+            &allowed,
+            "-I",
+            "ann@noro.sh",
+            "-s",
+            &sig,
+            "-r",
+            &revoked,
+        ];
+        assert_eq!(fixture.run(&argv, COMMIT).0, FAILED);
+    }
+
+    #[test]
+    fn test_find_principals() {
+        let fixture = Fixture::new("find");
+        let allowed = fixture.allowed();
+        let sig = fixture.signature();
+        let argv = [
+            "-Y",
+            "find-principals",
+            "-f",
+            &allowed,
+            "-s",
+            &sig,
+            "-Overify-time=20261019143000",
+        ];
+        assert_eq!(fixture.run(&argv, b""), (0, "*@noro.sh\n".to_string()));
+
+        let other = fixture.write("other", "bob@example.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIIHs0QdTcwUOsuCFVk+v2Vu0ERtV8aJpRJhB8tE2MHq3\n");
+        let argv = ["-Y", "find-principals", "-f", &other, "-s", &sig];
+        assert_eq!(fixture.run(&argv, b""), (1, String::new()));
+    }
+
+    #[test]
+    fn test_check_novalidate() {
+        let fixture = Fixture::new("novalidate");
+        let sig = fixture.signature();
+        let argv = ["-Y", "check-novalidate", "-n", "git", "-s", &sig];
+        let (code, out) = fixture.run(&argv, COMMIT);
+        assert_eq!(code, 0);
+        assert!(out.starts_with("Good \"git\" signature with ED25519 key SHA256:"));
+        assert_eq!(fixture.run(&argv, b"forged").0, FAILED);
+    }
+
+    #[test]
+    fn test_allowed_signers() {
+        let fixture = Fixture::new("allowed");
+        let signers = parseallowed(&std::fs::read_to_string(fixture.allowed()).unwrap());
+        assert_eq!(signers.len(), 2);
+        assert_eq!(signers[0].principals, "*@noro.sh");
+        assert_eq!(
+            signers[0].namespaces,
+            Some(vec!["git".to_string(), "file".to_string()])
+        );
+        let unknown = format!("ann@noro.sh cert-authority {}", fixture.public);
+        assert!(parseallowed(&unknown).is_empty());
+
+        let sig = fixture.private.sign("git", HASH, COMMIT).unwrap();
+        let expired = parseallowed(&format!(
+            "ann@noro.sh valid-before=\"20200101\" {}",
+            fixture.public
+        ));
+        assert!(verify(
+            &expired,
+            "ann@noro.sh",
+            "git",
+            "20200101120000",
+            COMMIT,
+            &sig
+        )
+        .is_err());
+        assert!(verify(
+            &expired,
+            "ann@noro.sh",
+            "git",
+            "20191231000000",
+            COMMIT,
+            &sig
+        )
+        .is_ok());
+        assert!(matchlist(
+            &["*@noro.sh".into(), "!eve@noro.sh".into()],
+            "ann@noro.sh"
+        ));
+        assert!(!matchlist(
+            &["*@noro.sh".into(), "!eve@noro.sh".into()],
+            "eve@noro.sh"
+        ));
+    }
+}
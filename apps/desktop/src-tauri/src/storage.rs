@@ -6,7 +6,7 @@ use argon2::{password_hash::SaltString, Argon2, PasswordHasher};
 use base64::{engine::general_purpose::STANDARD, Engine};
 use keyring::Entry;
 use rand::{rngs::OsRng, RngCore};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{fs, path::PathBuf};
 use thiserror::Error;
 
@@ -39,6 +39,20 @@ impl From<StorageError> for String {
 #[derive(Serialize, Deserialize, Clone)]
 pub struct VaultData {
     pub entries: Vec<VaultEntry>,
+    #[serde(default)]
+    pub folders: Vec<VaultFolder>,
+    pub updated: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VaultFolder {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+    pub color: String,
+    pub icon: String,
+    pub order: i32,
+    pub created: u64,
     pub updated: u64,
 }
 
@@ -50,11 +64,15 @@ pub struct VaultEntry {
     pub password: Option<String>,
     pub url: Option<String>,
     pub notes: Option<String>,
+    #[serde(default)]
+    pub folder_id: Option<String>,
+    #[serde(default)]
+    pub deleted_at: Option<u64>,
     pub created: u64,
     pub updated: u64,
 }
 
-fn get_data_dir() -> Result<PathBuf, StorageError> {
+pub fn get_data_dir() -> Result<PathBuf, StorageError> {
     let dir = directories::ProjectDirs::from("sh", "noro", "app")
         .ok_or_else(|| StorageError::Io(std::io::Error::other("no data dir")))?;
     let path = dir.data_dir().to_path_buf();
@@ -62,6 +80,22 @@ fn get_data_dir() -> Result<PathBuf, StorageError> {
     Ok(path)
 }
 
+pub fn get_socket_dir() -> Result<PathBuf, StorageError> {
+    let path = get_data_dir()?.join("sockets");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+        fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(&path)?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o700))?;
+    }
+    #[cfg(not(unix))]
+    fs::create_dir_all(&path)?;
+    Ok(path)
+}
+
 fn derive_key(password: &str, salt: &[u8]) -> Result<[u8; KEY_SIZE], StorageError> {
     let salt_str =
         SaltString::encode_b64(salt).map_err(|e| StorageError::Encryption(e.to_string()))?;
@@ -210,6 +244,20 @@ pub fn delete_vault() -> Result<(), StorageError> {
     Ok(())
 }
 
+pub fn store_settings<T: Serialize>(name: &str, data: &T) -> Result<(), StorageError> {
+    let path = get_data_dir()?.join(name);
+    fs::write(path, serde_json::to_vec_pretty(data)?)?;
+    Ok(())
+}
+
+pub fn get_settings<T: DeserializeOwned + Default>(name: &str) -> Result<T, StorageError> {
+    let path = get_data_dir()?.join(name);
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
 pub fn clear_all() -> Result<(), StorageError> {
     let _ = delete_session();
     let _ = delete_master_key();
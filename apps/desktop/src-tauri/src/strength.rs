@@ -0,0 +1,6 @@
+use noro_common::strength::{estimate, Estimate};
+
+#[tauri::command]
+pub fn strength_estimate(password: String, inputs: Option<Vec<String>>) -> Estimate {
+    estimate(&password, &inputs.unwrap_or_default())
+}
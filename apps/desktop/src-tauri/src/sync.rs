@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::crypto;
+use crate::search;
 
 #[derive(Error, Debug)]
 pub enum SyncError {
@@ -11,6 +12,8 @@ pub enum SyncError {
     Auth(String),
     #[error("conflict: server revision {0}")]
     Conflict(i32),
+    #[error("rejected: {0}")]
+    Rejected(String),
     #[error("crypto error: {0}")]
     Crypto(String),
 }
@@ -27,14 +30,24 @@ impl Serialize for SyncError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoteItem {
     pub id: String,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default, deserialize_with = "nullable")]
     pub item_type: String,
     pub title: String,
     pub data: String,
+    #[serde(default, skip_serializing)]
+    pub meta: Option<String>,
     pub revision: i32,
     pub favorite: bool,
     pub deleted: bool,
+    #[serde(rename = "deletedAt", default)]
+    pub deleted_at: Option<String>,
     pub tags: Vec<RemoteTag>,
+    #[serde(rename = "folderId", default)]
+    pub folder_id: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,46 +56,99 @@ pub struct RemoteTag {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemMeta {
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub tags: Vec<String>,
+    pub favorite: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemsResponse {
     pub items: Vec<RemoteItem>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaResponse {
+    pub items: Vec<RemoteItem>,
+    #[serde(default)]
+    pub purged: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemResponse {
     pub item: RemoteItem,
 }
 
-fn auth_header(token: &str) -> String {
+pub fn auth_header(token: &str) -> String {
     format!("better-auth.session_token={}", token)
 }
 
-fn encryptitem(id: &str, title: &str, data: &str) -> Result<(String, String), SyncError> {
-    let enctitle = crypto::encryptfield(id, title).map_err(|e| SyncError::Crypto(e.to_string()))?;
-    let encdata = crypto::encryptfield(id, data).map_err(|e| SyncError::Crypto(e.to_string()))?;
-    Ok((enctitle, encdata))
+fn nullable<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
 }
 
-fn decryptitem(item: &mut RemoteItem) -> Result<(), SyncError> {
-    item.title = crypto::decryptfield(&item.id, &item.title)
-        .map_err(|e| SyncError::Crypto(e.to_string()))?;
-    item.data =
-        crypto::decryptfield(&item.id, &item.data).map_err(|e| SyncError::Crypto(e.to_string()))?;
+pub fn cryptoerr(e: impl std::fmt::Display) -> SyncError {
+    SyncError::Crypto(e.to_string())
+}
+
+pub fn typeindex(item_type: &str) -> Result<String, SyncError> {
+    crypto::blindindex("type", item_type).map_err(cryptoerr)
+}
+
+pub fn tagindexes(tags: &[String]) -> Result<Vec<String>, SyncError> {
+    tags.iter()
+        .map(|t| crypto::blindindex("tag", t).map_err(cryptoerr))
+        .collect()
+}
+
+pub fn sealmeta(key: &[u8; 32], meta: &ItemMeta) -> Result<String, SyncError> {
+    let json = serde_json::to_string(meta).map_err(cryptoerr)?;
+    crypto::sealfield(key, &json).map_err(cryptoerr)
+}
+
+pub fn openitem(item: &mut RemoteItem, key: &[u8; 32]) -> Result<(), SyncError> {
+    item.title = crypto::openfield(key, &item.title).map_err(cryptoerr)?;
+    item.data = crypto::openfield(key, &item.data).map_err(cryptoerr)?;
+
+    if let Some(sealed) = item.meta.take() {
+        let json = crypto::openfield(key, &sealed).map_err(cryptoerr)?;
+        let meta: ItemMeta = serde_json::from_str(&json).map_err(cryptoerr)?;
+        let indexed = std::mem::take(&mut item.tags);
+        item.tags = meta
+            .tags
+            .into_iter()
+            .map(|name| {
+                let index = crypto::blindindex("tag", &name).map_err(cryptoerr)?;
+                let id = indexed
+                    .iter()
+                    .find(|t| t.name == index)
+                    .map(|t| t.id.clone())
+                    .unwrap_or_default();
+                Ok(RemoteTag { id, name })
+            })
+            .collect::<Result<_, SyncError>>()?;
+        item.item_type = meta.item_type;
+        item.favorite = meta.favorite;
+    }
+
     Ok(())
 }
 
-#[tauri::command]
-pub async fn sync_fetch(base_url: String, token: String) -> Result<Vec<RemoteItem>, SyncError> {
-    let client = reqwest::Client::new();
-    let url = format!("{}/api/v1/vault/items", base_url);
+fn decryptitem(item: &mut RemoteItem) -> Result<(), SyncError> {
+    let key = crypto::itemkey(&item.id).map_err(cryptoerr)?;
+    openitem(item, &key)
+}
 
-    let res = client
-        .get(&url)
-        .header("cookie", auth_header(&token))
-        .send()
-        .await
-        .map_err(|e| SyncError::Http(e.to_string()))?;
+fn permanent(status: u16) -> bool {
+    (400..500).contains(&status) && status != 408 && status != 429
+}
 
+pub async fn checkstatus(res: reqwest::Response) -> Result<reqwest::Response, SyncError> {
     if res.status() == 401 {
         return Err(SyncError::Auth("session expired".into()));
     }
@@ -90,112 +156,254 @@ pub async fn sync_fetch(base_url: String, token: String) -> Result<Vec<RemoteIte
     if !res.status().is_success() {
         let status = res.status();
         let body = res.text().await.unwrap_or_default();
+        if permanent(status.as_u16()) {
+            return Err(SyncError::Rejected(format!("{}: {}", status, body)));
+        }
         return Err(SyncError::Http(format!("{}: {}", status, body)));
     }
 
-    let data: ItemsResponse = res
+    Ok(res)
+}
+
+pub async fn fetchitems(base_url: &str, token: &str) -> Result<Vec<RemoteItem>, SyncError> {
+    let items = fetchlist(base_url, token, "/api/v1/vault/items").await?;
+    search::rebuild(&items);
+    Ok(items)
+}
+
+pub async fn fetchall(base_url: &str, token: &str) -> Result<Vec<RemoteItem>, SyncError> {
+    let items = fetchlist(base_url, token, "/api/v1/vault/items?deleted=all").await?;
+    search::rebuild(&items);
+    Ok(items)
+}
+
+pub async fn fetchsince(
+    base_url: &str,
+    token: &str,
+    since: &str,
+) -> Result<DeltaResponse, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/items", base_url);
+
+    let res = client
+        .get(&url)
+        .query(&[("since", since)])
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let mut delta: DeltaResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    for item in &mut delta.items {
+        decryptitem(item)?;
+        search::upsert(item);
+    }
+    for id in &delta.purged {
+        search::remove(id);
+    }
+    Ok(delta)
+}
+
+pub async fn fetchtrash(base_url: &str, token: &str) -> Result<Vec<RemoteItem>, SyncError> {
+    fetchlist(base_url, token, "/api/v1/vault/trash").await
+}
+
+async fn fetchlist(base_url: &str, token: &str, path: &str) -> Result<Vec<RemoteItem>, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}{}", base_url, path);
+
+    let res = client
+        .get(&url)
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let data: ItemsResponse = checkstatus(res)
+        .await?
         .json()
         .await
         .map_err(|e| SyncError::Http(e.to_string()))?;
 
     let mut items = data.items;
+    let mut legacy = Vec::new();
     for item in &mut items {
+        if item.meta.is_none() {
+            legacy.push(item.id.clone());
+        }
         decryptitem(item)?;
     }
 
+    for item in items.iter().filter(|i| legacy.contains(&i.id)) {
+        let _ = migratemeta(base_url, token, item).await;
+    }
+
     Ok(items)
 }
 
-#[tauri::command]
-pub async fn sync_create(
-    base_url: String,
-    token: String,
+async fn migratemeta(base_url: &str, token: &str, item: &RemoteItem) -> Result<(), SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/items/{}", base_url, item.id);
+
+    let key = crypto::itemkey(&item.id).map_err(cryptoerr)?;
+    let tags: Vec<String> = item.tags.iter().map(|t| t.name.clone()).collect();
+
+    #[derive(Serialize)]
+    struct Body {
+        #[serde(rename = "typeIndex")]
+        type_index: String,
+        meta: String,
+        tags: Vec<String>,
+    }
+
+    let body = Body {
+        type_index: typeindex(&item.item_type)?,
+        tags: tagindexes(&tags)?,
+        meta: sealmeta(
+            &key,
+            &ItemMeta {
+                item_type: item.item_type.clone(),
+                tags,
+                favorite: item.favorite,
+            },
+        )?,
+    };
+
+    let res = client
+        .put(&url)
+        .header("cookie", auth_header(token))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    checkstatus(res).await?;
+    Ok(())
+}
+
+pub async fn fetchitem(base_url: &str, token: &str, id: &str) -> Result<RemoteItem, SyncError> {
+    let mut item = fetchsealed(base_url, token, id).await?;
+    decryptitem(&mut item)?;
+
+    search::upsert(&item);
+    Ok(item)
+}
+
+pub async fn fetchsealed(base_url: &str, token: &str, id: &str) -> Result<RemoteItem, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/items/{}", base_url, id);
+
+    let res = client
+        .get(&url)
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: ItemResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    Ok(resp.item)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn createitem(
+    base_url: &str,
+    token: &str,
     id: String,
     item_type: String,
-    title: String,
-    data: String,
+    title: &str,
+    data: &str,
     tags: Vec<String>,
     favorite: bool,
 ) -> Result<RemoteItem, SyncError> {
     let client = reqwest::Client::new();
     let url = format!("{}/api/v1/vault/items", base_url);
 
-    let (enctitle, encdata) = encryptitem(&id, &title, &data)?;
+    let key = crypto::itemkey(&id).map_err(cryptoerr)?;
 
     #[derive(Serialize)]
     struct Body {
         id: String,
-        #[serde(rename = "type")]
-        item_type: String,
+        #[serde(rename = "typeIndex")]
+        type_index: String,
         title: String,
         data: String,
+        meta: String,
         tags: Vec<String>,
-        favorite: bool,
     }
 
     let body = Body {
+        type_index: typeindex(&item_type)?,
+        title: crypto::sealfield(&key, title).map_err(cryptoerr)?,
+        data: crypto::sealfield(&key, data).map_err(cryptoerr)?,
+        tags: tagindexes(&tags)?,
+        meta: sealmeta(
+            &key,
+            &ItemMeta {
+                item_type,
+                tags,
+                favorite,
+            },
+        )?,
         id,
-        item_type,
-        title: enctitle,
-        data: encdata,
-        tags,
-        favorite,
     };
 
     let res = client
         .post(&url)
-        .header("cookie", auth_header(&token))
+        .header("cookie", auth_header(token))
         .json(&body)
         .send()
         .await
         .map_err(|e| SyncError::Http(e.to_string()))?;
 
-    if res.status() == 401 {
-        return Err(SyncError::Auth("session expired".into()));
-    }
-
-    if !res.status().is_success() {
-        let status = res.status();
-        let body = res.text().await.unwrap_or_default();
-        return Err(SyncError::Http(format!("{}: {}", status, body)));
-    }
-
-    let resp: ItemResponse = res
+    let resp: ItemResponse = checkstatus(res)
+        .await?
         .json()
         .await
         .map_err(|e| SyncError::Http(e.to_string()))?;
 
     let mut item = resp.item;
-    decryptitem(&mut item)?;
+    openitem(&mut item, &key)?;
 
+    search::upsert(&item);
     Ok(item)
 }
 
-#[tauri::command]
-pub async fn sync_update(
-    base_url: String,
-    token: String,
-    id: String,
-    title: Option<String>,
-    data: Option<String>,
+pub async fn updateitem(
+    base_url: &str,
+    token: &str,
+    id: &str,
+    title: Option<&str>,
+    data: Option<&str>,
     tags: Option<Vec<String>>,
     favorite: Option<bool>,
 ) -> Result<RemoteItem, SyncError> {
     let client = reqwest::Client::new();
     let url = format!("{}/api/v1/vault/items/{}", base_url, id);
 
-    let enctitle = match &title {
-        Some(t) => {
-            Some(crypto::encryptfield(&id, t).map_err(|e| SyncError::Crypto(e.to_string()))?)
-        }
-        None => None,
-    };
-
-    let encdata = match &data {
-        Some(d) => {
-            Some(crypto::encryptfield(&id, d).map_err(|e| SyncError::Crypto(e.to_string()))?)
-        }
-        None => None,
+    let key = crypto::itemkey(id).map_err(cryptoerr)?;
+
+    let meta = if tags.is_some() || favorite.is_some() {
+        let current = fetchitem(base_url, token, id).await?;
+        let meta = ItemMeta {
+            item_type: current.item_type,
+            tags: tags
+                .clone()
+                .unwrap_or_else(|| current.tags.into_iter().map(|t| t.name).collect()),
+            favorite: favorite.unwrap_or(current.favorite),
+        };
+        Some(sealmeta(&key, &meta)?)
+    } else {
+        None
     };
 
     #[derive(Serialize)]
@@ -205,37 +413,80 @@ pub async fn sync_update(
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        tags: Option<Vec<String>>,
+        meta: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        favorite: Option<bool>,
+        tags: Option<Vec<String>>,
     }
 
     let body = Body {
-        title: enctitle,
-        data: encdata,
-        tags,
-        favorite,
+        title: title
+            .map(|t| crypto::sealfield(&key, t))
+            .transpose()
+            .map_err(cryptoerr)?,
+        data: data
+            .map(|d| crypto::sealfield(&key, d))
+            .transpose()
+            .map_err(cryptoerr)?,
+        meta,
+        tags: tags.as_deref().map(tagindexes).transpose()?,
     };
 
     let res = client
         .put(&url)
-        .header("cookie", auth_header(&token))
+        .header("cookie", auth_header(token))
         .json(&body)
         .send()
         .await
         .map_err(|e| SyncError::Http(e.to_string()))?;
 
-    if res.status() == 401 {
-        return Err(SyncError::Auth("session expired".into()));
-    }
+    let resp: ItemResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
 
-    if !res.status().is_success() {
-        let status = res.status();
-        let body = res.text().await.unwrap_or_default();
-        return Err(SyncError::Http(format!("{}: {}", status, body)));
+    let mut item = resp.item;
+    openitem(&mut item, &key)?;
+
+    search::upsert(&item);
+    Ok(item)
+}
+
+pub async fn deleteitem(base_url: &str, token: &str, id: &str) -> Result<(), SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/items/{}", base_url, id);
+
+    let res = client
+        .delete(&url)
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    checkstatus(res).await?;
+    search::remove(id);
+    Ok(())
+}
+
+pub async fn restoreitem(base_url: &str, token: &str, id: &str) -> Result<RemoteItem, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/items/{}", base_url, id);
+
+    #[derive(Serialize)]
+    struct Body {
+        deleted: bool,
     }
 
-    let resp: ItemResponse = res
+    let res = client
+        .patch(&url)
+        .header("cookie", auth_header(token))
+        .json(&Body { deleted: false })
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: ItemResponse = checkstatus(res)
+        .await?
         .json()
         .await
         .map_err(|e| SyncError::Http(e.to_string()))?;
@@ -243,34 +494,127 @@ pub async fn sync_update(
     let mut item = resp.item;
     decryptitem(&mut item)?;
 
+    search::upsert(&item);
     Ok(item)
 }
 
-#[tauri::command]
-pub async fn sync_delete(base_url: String, token: String, id: String) -> Result<bool, SyncError> {
+pub async fn purgeitem(base_url: &str, token: &str, id: &str) -> Result<(), SyncError> {
     let client = reqwest::Client::new();
-    let url = format!("{}/api/v1/vault/items/{}", base_url, id);
+    let url = format!("{}/api/v1/vault/items/{}?permanent=true", base_url, id);
 
     let res = client
         .delete(&url)
-        .header("cookie", auth_header(&token))
+        .header("cookie", auth_header(token))
         .send()
         .await
         .map_err(|e| SyncError::Http(e.to_string()))?;
 
-    if res.status() == 401 {
-        return Err(SyncError::Auth("session expired".into()));
-    }
+    checkstatus(res).await?;
+    search::remove(id);
+    Ok(())
+}
 
-    if !res.status().is_success() {
-        let status = res.status();
-        let body = res.text().await.unwrap_or_default();
-        return Err(SyncError::Http(format!("{}: {}", status, body)));
+pub async fn emptytrash(base_url: &str, token: &str) -> Result<Vec<String>, SyncError> {
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/vault/trash", base_url);
+
+    #[derive(Deserialize)]
+    struct PurgeResponse {
+        purged: Vec<String>,
     }
 
+    let res = client
+        .delete(&url)
+        .header("cookie", auth_header(token))
+        .send()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    let resp: PurgeResponse = checkstatus(res)
+        .await?
+        .json()
+        .await
+        .map_err(|e| SyncError::Http(e.to_string()))?;
+
+    Ok(resp.purged)
+}
+
+#[tauri::command]
+pub async fn sync_fetch(base_url: String, token: String) -> Result<Vec<RemoteItem>, SyncError> {
+    fetchitems(&base_url, &token).await
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn sync_create(
+    base_url: String,
+    token: String,
+    id: String,
+    item_type: String,
+    title: String,
+    data: String,
+    tags: Vec<String>,
+    favorite: bool,
+) -> Result<RemoteItem, SyncError> {
+    createitem(
+        &base_url, &token, id, item_type, &title, &data, tags, favorite,
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn sync_update(
+    base_url: String,
+    token: String,
+    id: String,
+    title: Option<String>,
+    data: Option<String>,
+    tags: Option<Vec<String>>,
+    favorite: Option<bool>,
+) -> Result<RemoteItem, SyncError> {
+    updateitem(
+        &base_url,
+        &token,
+        &id,
+        title.as_deref(),
+        data.as_deref(),
+        tags,
+        favorite,
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn sync_delete(base_url: String, token: String, id: String) -> Result<bool, SyncError> {
+    deleteitem(&base_url, &token, &id).await?;
     Ok(true)
 }
 
+#[tauri::command]
+pub async fn sync_trash(base_url: String, token: String) -> Result<Vec<RemoteItem>, SyncError> {
+    fetchtrash(&base_url, &token).await
+}
+
+#[tauri::command]
+pub async fn sync_restore(
+    base_url: String,
+    token: String,
+    id: String,
+) -> Result<RemoteItem, SyncError> {
+    restoreitem(&base_url, &token, &id).await
+}
+
+#[tauri::command]
+pub async fn sync_purge(base_url: String, token: String, id: String) -> Result<bool, SyncError> {
+    purgeitem(&base_url, &token, &id).await?;
+    Ok(true)
+}
+
+#[tauri::command]
+pub async fn sync_empty_trash(base_url: String, token: String) -> Result<Vec<String>, SyncError> {
+    emptytrash(&base_url, &token).await
+}
+
 #[tauri::command]
 pub async fn sync_login(
     base_url: String,
@@ -307,3 +651,54 @@ pub async fn sync_login(
 
     Ok(token)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITEMS_RESPONSE: &str = r#"{"items":[
+        {"id":"cm8a1login","vaultId":"cm8vault","folderId":"cm8folder","type":null,"typeIndex":"5d1e","title":"BxhMQL+dY/clJ3oJ+StKzoUw6bp0VZuq2+usCFvnTL5swQ==","data":"lBiIKqB5sKCubdENC6rMvFRYAXtWiWei36b8e7/C1fCRD8Uzf1hxt2tWvz67bU6WFSvvkeGZVfuY4LNJoNBzz+RjR7SVZms=","meta":"dJYZfLVvntmy/awMyuQFBOQB0W2r9P/WvclIBTG1CX+zkc1SAOHJKmJwhAVnozcATa61S3BhOWZXfS8wE2nBnydXjhVkpYJOJr7vlA==","revision":3,"favorite":false,"deleted":false,"createdAt":"2024-02-29T23:59:59.000Z","updatedAt":"2025-03-14T09:26:53.589Z","tags":[{"id":"cm8tag","name":"8c41","itemId":"cm8a1login"}]},
+        {"id":"cm8a2note","vaultId":"cm8vault","folderId":null,"type":"note","typeIndex":null,"title":"vBtc9P2iUtNZtp9thzdptamwTniJdCWcCbDxIPHdDDM=","data":"jJzYzm6aVJTwMGywm9oDWYpNtxubgRSR5fS9c3uGxBUKrlGzYk/DHU+xhybRn9noudvL0xKOIA==","meta":null,"revision":1,"favorite":true,"deleted":false,"createdAt":"2025-06-01T18:00:00.000Z","updatedAt":"2025-06-01T18:00:00.000Z","tags":[{"id":"cm8tag2","name":"home","itemId":"cm8a2note"}]}
+    ]}"#;
+
+    #[test]
+    fn test_recorded_items_response() {
+        let response: ItemsResponse = serde_json::from_str(ITEMS_RESPONSE).unwrap();
+        let mut items = response.items;
+        assert_eq!(items[0].item_type, "");
+        assert_eq!(items[0].folder_id.as_deref(), Some("cm8folder"));
+        assert_eq!(items[0].created_at, "2024-02-29T23:59:59.000Z");
+        assert_eq!(items[0].updated_at, "2025-03-14T09:26:53.589Z");
+        assert_eq!(items[1].folder_id, None);
+
+        let key = [9u8; 32];
+        let meta = crypto::openfield(&key, items[0].meta.as_deref().unwrap()).unwrap();
+        let meta: ItemMeta = serde_json::from_str(&meta).unwrap();
+        assert_eq!(meta.item_type, "login");
+        assert!(meta.favorite);
+        for item in &mut items {
+            item.title = crypto::openfield(&key, &item.title).unwrap();
+            item.data = crypto::openfield(&key, &item.data).unwrap();
+        }
+        assert_eq!(items[0].title, "github");
+        assert_eq!(items[1].data, r#"{"content":"guest / guest"}"#);
+
+        let encoded = serde_json::to_value(&items[0]).unwrap();
+        assert_eq!(encoded["folderId"], "cm8folder");
+        assert_eq!(encoded["createdAt"], "2024-02-29T23:59:59.000Z");
+        assert_eq!(encoded["updatedAt"], "2025-03-14T09:26:53.589Z");
+        assert!(encoded.get("meta").is_none());
+        let again: RemoteItem = serde_json::from_value(encoded).unwrap();
+        assert_eq!(again.updated_at, items[0].updated_at);
+    }
+
+    #[test]
+    fn test_permanent_status() {
+        assert!(permanent(400));
+        assert!(permanent(404));
+        assert!(!permanent(408));
+        assert!(!permanent(429));
+        assert!(!permanent(500));
+        assert!(!permanent(503));
+    }
+}
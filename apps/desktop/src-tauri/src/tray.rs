@@ -1,18 +1,20 @@
-use rand::Rng;
+use crate::generator::GeneratorState;
+use noro_common::generator::{generate, GeneratorOptions};
 use tauri::{
     menu::{Menu, MenuItem, PredefinedMenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
     AppHandle, Emitter, Manager, Runtime,
 };
 
-fn quickpassword() -> String {
-    let charset: Vec<u8> =
-        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!@#$%^&*()_+-=[]{}|;:,.<>?"
-            .to_vec();
-    let mut rng = rand::thread_rng();
-    (0..20)
-        .map(|_| charset[rng.gen_range(0..charset.len())] as char)
-        .collect()
+fn quickpassword<R: Runtime>(app: &AppHandle<R>) -> Option<String> {
+    let options = app
+        .try_state::<GeneratorState>()
+        .map(|state| state.options())
+        .unwrap_or_default();
+    generate(&options)
+        .or_else(|_| generate(&GeneratorOptions::default()))
+        .ok()
+        .map(|result| result.password)
 }
 
 fn copytoclipboard(text: &str) {
@@ -78,10 +80,11 @@ pub fn create<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::error::
                 }
             }
             "generate" => {
-                let password = quickpassword();
-                copytoclipboard(&password);
-                if let Some(w) = app.get_webview_window("main") {
-                    let _ = w.emit("password_copied", password);
+                if let Some(password) = quickpassword(app) {
+                    copytoclipboard(&password);
+                    if let Some(w) = app.get_webview_window("main") {
+                        let _ = w.emit("password_copied", password);
+                    }
                 }
             }
             "quit" => app.exit(0),
@@ -3,7 +3,9 @@ use aes_gcm::{
     Aes256Gcm, Nonce,
 };
 use argon2::{Algorithm, Argon2, Params, Version};
+use hmac::{Hmac, Mac};
 use rand::Rng;
+use sha2::Sha256;
 use thiserror::Error;
 
 const ARGON_MEMORY: u32 = 65536;
@@ -12,6 +14,7 @@ const ARGON_PARALLELISM: u32 = 4;
 const ARGON_OUTPUT_LEN: usize = 32;
 const SECRET_KEY_BYTES: usize = 20;
 const NONCE_LEN: usize = 12;
+const INDEX_CONTEXT: &[u8] = b"noro-blind-index-v1";
 const BASE32_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
 
 #[derive(Error, Debug)]
@@ -153,6 +156,24 @@ pub fn deriveitemkey(vaultkey: &[u8; 32], itemid: &str) -> Result<[u8; 32]> {
     Ok(itemkey)
 }
 
+fn hmacsha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("hmac accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+pub fn deriveindexkey(vaultkey: &[u8; 32]) -> [u8; 32] {
+    hmacsha256(vaultkey, &[INDEX_CONTEXT])
+}
+
+pub fn blindindex(indexkey: &[u8; 32], kind: &str, value: &str) -> String {
+    let normalized = value.trim().to_lowercase();
+    let digest = hmacsha256(indexkey, &[kind.as_bytes(), &[0], normalized.as_bytes()]);
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 pub fn encryptitem(data: &[u8], itemkey: &[u8; 32]) -> Result<Vec<u8>> {
     let cipher = Aes256Gcm::new_from_slice(itemkey).map_err(|_| TwoskdError::Encryption)?;
     let mut rng = rand::thread_rng();
@@ -212,4 +233,17 @@ mod tests {
         let unwrapped = unwrapvaultkey(&wrapped, &auk).unwrap();
         assert_eq!(vaultkey, unwrapped);
     }
+
+    #[test]
+    fn test_blindindex() {
+        let indexkey = deriveindexkey(&[7u8; 32]);
+        let index = blindindex(&indexkey, "tag", "Work");
+        assert_eq!(index.len(), 64);
+        assert_eq!(index, blindindex(&indexkey, "tag", " work "));
+        assert_ne!(index, blindindex(&indexkey, "type", "work"));
+        assert_ne!(
+            index,
+            blindindex(&deriveindexkey(&[8u8; 32]), "tag", "work")
+        );
+    }
 }
@@ -0,0 +1,23 @@
+use noro_common::urlmatch::{loginurls, matchlogins, parseurl, registrabledomain, Login, UrlMatch};
+
+use crate::sync::RemoteItem;
+
+#[tauri::command]
+pub fn url_match(page_url: String, items: Vec<RemoteItem>) -> Vec<UrlMatch> {
+    let logins: Vec<Login> = items
+        .iter()
+        .filter(|i| i.item_type == "login" && !i.deleted)
+        .map(|i| Login {
+            id: i.id.clone(),
+            urls: loginurls(i.data.as_bytes()),
+        })
+        .filter(|l| !l.urls.is_empty())
+        .collect();
+    matchlogins(&page_url, &logins)
+}
+
+#[tauri::command]
+pub fn url_registrable_domain(url: String) -> Option<String> {
+    let url = parseurl(&url)?;
+    registrabledomain(url.host_str()?)
+}
@@ -1,6 +1,7 @@
 import { useEffect, useState } from "react";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { getSession, logout, type Session } from "./auth";
+import { stopsync } from "./lib/autosync";
 import { Login } from "./pages/login";
 import { Vault } from "./pages/vault";
 import { Generator } from "./pages/generator";
@@ -69,6 +70,7 @@ export function App() {
 	}
 
 	async function handleLogout() {
+		await stopsync();
 		await logout();
 		setSession(null);
 		setView("login");
@@ -0,0 +1,239 @@
+import { useState, useEffect } from "react";
+import {
+	agentstatus,
+	approvesign,
+	onapprovalrequest,
+	startagent,
+	stopagent,
+	type AgentStatus,
+	type ApprovalRequest,
+} from "../lib/agent";
+
+interface Props {
+	token: string;
+}
+
+export function Agent({ token }: Props) {
+	const [status, setStatus] = useState<AgentStatus | null>(null);
+	const [loading, setLoading] = useState(false);
+	const [error, setError] = useState("");
+
+	useEffect(() => {
+		agentstatus()
+			.then(setStatus)
+			.catch(() => {});
+	}, []);
+
+	async function start() {
+		setLoading(true);
+		try {
+			setStatus(await startagent(token));
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setLoading(false);
+		}
+	}
+
+	async function stop() {
+		try {
+			await stopagent();
+			setStatus(await agentstatus());
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	return (
+		<div className="agent">
+			<div className="agent-header">
+				<h2>ssh agent</h2>
+				<div className="agent-actions">
+					<button type="button" onClick={start} disabled={loading}>
+						{loading ? "loading..." : status?.running ? "reload keys" : "start"}
+					</button>
+					{status?.running && (
+						<button type="button" onClick={stop}>
+							stop
+						</button>
+					)}
+				</div>
+			</div>
+			{error && <p className="agent-error">{error}</p>}
+			{status?.socket && (
+				<code className="agent-socket">export SSH_AUTH_SOCK="{status.socket}"</code>
+			)}
+			{status?.running && (
+				<>
+					<p className="agent-total">
+						{status.identities.length} {status.identities.length === 1 ? "key" : "keys"} served,
+						each signature asks for approval
+					</p>
+					<ul className="agent-list">
+						{status.identities.map((key) => (
+							<li key={key.id}>
+								<span className="agent-title">{key.title}</span>
+								<span className="agent-detail">
+									{key.algorithm} {key.fingerprint}
+								</span>
+							</li>
+						))}
+						{status.skipped.map((key) => (
+							<li key={key.id} className="agent-skipped">
+								<span className="agent-title">{key.title}</span>
+								<span className="agent-detail">{key.reason}</span>
+							</li>
+						))}
+					</ul>
+				</>
+			)}
+
+			<style>{`
+				.agent {
+					padding: 1.5rem;
+					overflow-y: auto;
+				}
+				.agent-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+					margin-bottom: 0.75rem;
+				}
+				.agent-header h2 {
+					font-size: 1.125rem;
+					font-weight: 600;
+				}
+				.agent-actions {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.agent-actions button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.agent-actions button:hover:not(:disabled) {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.agent-socket {
+					display: block;
+					padding: 0.625rem 0.75rem;
+					margin-bottom: 1rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.75rem;
+					color: var(--fg);
+					user-select: all;
+					overflow-x: auto;
+				}
+				.agent-total {
+					font-size: 0.8125rem;
+					color: var(--fg-subtle);
+					margin-bottom: 1rem;
+				}
+				.agent-list {
+					list-style: none;
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+				}
+				.agent-list li {
+					display: flex;
+					justify-content: space-between;
+					gap: 1rem;
+					padding: 0.625rem 0.75rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+				}
+				.agent-skipped {
+					opacity: 0.6;
+				}
+				.agent-title {
+					font-size: 0.875rem;
+					color: var(--fg);
+				}
+				.agent-detail {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+					font-family: ui-monospace, "SF Mono", monospace;
+				}
+				.agent-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+					margin-bottom: 1rem;
+				}
+			`}</style>
+		</div>
+	);
+}
+
+export function AgentPrompt() {
+	const [requests, setRequests] = useState<ApprovalRequest[]>([]);
+
+	useEffect(() => {
+		const unlisten = onapprovalrequest((request) => {
+			setRequests((current) => [...current, request]);
+		});
+		return () => {
+			unlisten.then((fn) => fn());
+		};
+	}, []);
+
+	const request = requests[0];
+	if (!request) return null;
+
+	async function answer(approved: boolean) {
+		setRequests((current) => current.slice(1));
+		try {
+			await approvesign(request.request, approved);
+		} catch {}
+	}
+
+	return (
+		<div className="add-modal-overlay">
+			<div className="add-modal agent-prompt">
+				<h3>allow ssh signature?</h3>
+				<p>
+					a program wants to sign with <strong>{request.title}</strong>
+				</p>
+				<code>{request.fingerprint}</code>
+				<div className="add-actions">
+					<button type="button" className="btn-secondary" onClick={() => answer(false)}>
+						deny
+					</button>
+					<button type="button" className="btn-primary" onClick={() => answer(true)} autoFocus>
+						allow once
+					</button>
+				</div>
+			</div>
+			<style>{`
+				.agent-prompt p {
+					font-size: 0.875rem;
+					color: var(--fg-muted);
+					margin-bottom: 0.75rem;
+				}
+				.agent-prompt strong {
+					color: var(--fg);
+				}
+				.agent-prompt code {
+					display: block;
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+					margin-bottom: 1.25rem;
+					word-break: break-all;
+				}
+			`}</style>
+		</div>
+	);
+}
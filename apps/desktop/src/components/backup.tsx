@@ -0,0 +1,168 @@
+import { useState } from "react";
+import {
+	exportbackup,
+	exportplaintext,
+	importbackup,
+	readbackupmanifest,
+	type PlainFormat,
+} from "../lib/backup";
+
+type Mode = "export" | "restore" | "plain";
+
+export function Backup() {
+	const [mode, setMode] = useState<Mode>("export");
+	const [path, setPath] = useState("");
+	const [password, setPassword] = useState("");
+	const [format, setFormat] = useState<PlainFormat>("json");
+	const [busy, setBusy] = useState(false);
+	const [error, setError] = useState("");
+	const [result, setResult] = useState("");
+
+	async function inspect() {
+		if (mode !== "restore" || !path.trim()) return;
+		try {
+			const manifest = await readbackupmanifest(path.trim());
+			const created = new Date(manifest.created * 1000).toLocaleDateString();
+			setResult(`backup from ${manifest.source} on ${created}, ${manifest.items} items`);
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function submit(e: React.FormEvent) {
+		e.preventDefault();
+		setBusy(true);
+		try {
+			if (mode === "export") {
+				const manifest = await exportbackup(path.trim(), password);
+				setResult(`saved ${manifest.items} items and ${manifest.folders} folders`);
+			} else if (mode === "restore") {
+				const report = await importbackup(path.trim(), password);
+				setResult(
+					`${report.created} added, ${report.updated} updated, ${report.unchanged} unchanged`,
+				);
+			} else {
+				await exportplaintext(path.trim(), password, format);
+				setResult(`saved unencrypted ${format} export`);
+			}
+			setPassword("");
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setBusy(false);
+		}
+	}
+
+	return (
+		<form className="backup" onSubmit={submit}>
+			<div className="backup-header">
+				<h3>backup</h3>
+				<div className="backup-tabs">
+					{(["export", "restore", "plain"] as Mode[]).map((m) => (
+						<button
+							key={m}
+							type="button"
+							className={mode === m ? "active" : ""}
+							onClick={() => {
+								setMode(m);
+								setResult("");
+							}}
+						>
+							{m === "plain" ? "unencrypted export" : m}
+						</button>
+					))}
+				</div>
+			</div>
+			{error && <p className="backup-error">{error}</p>}
+			{result && <p className="backup-result">{result}</p>}
+			<input
+				type="text"
+				value={path}
+				onChange={(e) => setPath(e.target.value)}
+				onBlur={inspect}
+				placeholder={mode === "restore" ? "backup file to restore" : "where to save the file"}
+			/>
+			<div className="backup-row">
+				<input
+					type="password"
+					value={password}
+					onChange={(e) => setPassword(e.target.value)}
+					placeholder={mode === "plain" ? "master password" : "backup password"}
+				/>
+				{mode === "plain" && (
+					<select value={format} onChange={(e) => setFormat(e.target.value as PlainFormat)}>
+						<option value="json">json</option>
+						<option value="csv">csv</option>
+					</select>
+				)}
+				<button type="submit" className="backup-submit" disabled={busy || !path.trim() || !password}>
+					{busy ? "working..." : mode === "restore" ? "restore" : "export"}
+				</button>
+			</div>
+
+			<style>{`
+				.backup {
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+					padding: 1.5rem 1.5rem 0;
+				}
+				.backup-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+				}
+				.backup-header h3 {
+					font-size: 0.875rem;
+					font-weight: 600;
+				}
+				.backup-tabs {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.backup-tabs button,
+				.backup-submit {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.backup-tabs button.active,
+				.backup-submit:hover:not(:disabled) {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.backup-row {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.backup-row input {
+					flex: 1;
+				}
+				.backup input,
+				.backup select {
+					padding: 0.5rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-size: 0.8125rem;
+				}
+				.backup-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+				}
+				.backup-result {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+			`}</style>
+		</form>
+	);
+}
@@ -0,0 +1,167 @@
+import { useState, useEffect } from "react";
+import {
+	approveenvrun,
+	envrunstatus,
+	onenvrunrequest,
+	startenvrun,
+	stopenvrun,
+	type EnvRunApproval,
+	type EnvRunStatus,
+} from "../lib/envrun";
+
+interface Props {
+	token: string;
+}
+
+export function EnvRun({ token }: Props) {
+	const [status, setStatus] = useState<EnvRunStatus | null>(null);
+	const [error, setError] = useState("");
+
+	useEffect(() => {
+		envrunstatus()
+			.then(setStatus)
+			.catch(() => {});
+	}, []);
+
+	async function start() {
+		try {
+			setStatus(await startenvrun(token));
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function stop() {
+		try {
+			await stopenvrun();
+			setStatus(await envrunstatus());
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	return (
+		<div className="envrun">
+			<div className="envrun-header">
+				<h3>env runner</h3>
+				<button type="button" onClick={status?.running ? stop : start}>
+					{status?.running ? "stop" : "start"}
+				</button>
+			</div>
+			{error && <p className="envrun-error">{error}</p>}
+			<p className="envrun-hint">
+				reference fields as <code>noro://item/field</code> in a .env file, then run{" "}
+				<code>noro-run -e .env -- npm start</code>. each run asks for approval and secret values
+				are masked in the output.
+			</p>
+
+			<style>{`
+				.envrun {
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+					padding: 0 1.5rem 1.5rem;
+				}
+				.envrun-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+				}
+				.envrun-header h3 {
+					font-size: 0.875rem;
+					font-weight: 600;
+				}
+				.envrun-header button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.envrun-header button:hover {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.envrun-hint {
+					font-size: 0.8125rem;
+					color: var(--fg-subtle);
+				}
+				.envrun-hint code {
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.75rem;
+					color: var(--fg);
+				}
+				.envrun-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+				}
+			`}</style>
+		</div>
+	);
+}
+
+export function EnvRunPrompt() {
+	const [requests, setRequests] = useState<EnvRunApproval[]>([]);
+
+	useEffect(() => {
+		const unlisten = onenvrunrequest((request) => {
+			setRequests((current) => [...current, request]);
+		});
+		return () => {
+			unlisten.then((fn) => fn());
+		};
+	}, []);
+
+	const request = requests[0];
+	if (!request) return null;
+
+	async function answer(approved: boolean) {
+		setRequests((current) => current.slice(1));
+		try {
+			await approveenvrun(request.request, approved);
+		} catch {}
+	}
+
+	return (
+		<div className="add-modal-overlay">
+			<div className="add-modal envrun-prompt">
+				<h3>share secrets with a command?</h3>
+				<code>{request.command}</code>
+				<p>
+					wants {request.items.length === 1 ? "a field" : "fields"} from{" "}
+					<strong>{request.items.join(", ")}</strong>
+				</p>
+				<div className="add-actions">
+					<button type="button" className="btn-secondary" onClick={() => answer(false)}>
+						deny
+					</button>
+					<button type="button" className="btn-primary" onClick={() => answer(true)} autoFocus>
+						allow once
+					</button>
+				</div>
+			</div>
+			<style>{`
+				.envrun-prompt p {
+					font-size: 0.875rem;
+					color: var(--fg-muted);
+					margin-bottom: 1.25rem;
+				}
+				.envrun-prompt strong {
+					color: var(--fg);
+				}
+				.envrun-prompt code {
+					display: block;
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+					margin-bottom: 0.75rem;
+					word-break: break-all;
+				}
+			`}</style>
+		</div>
+	);
+}
@@ -0,0 +1,206 @@
+import { useState, useEffect } from "react";
+import { BASE_URL } from "../config";
+import {
+	createfolder,
+	deletefolder,
+	fetchfolders,
+	type FolderList,
+	type RemoteFolder,
+} from "../lib/folders";
+
+interface Props {
+	token: string;
+	selected: string | null;
+	refresh: number;
+	onSelect: (ids: string[] | null, folder: string | null) => void;
+	onFolders: (folders: RemoteFolder[]) => void;
+}
+
+function subtree(folders: RemoteFolder[], id: string): string[] {
+	const children = folders.filter((f) => f.parentId === id);
+	return [id, ...children.flatMap((f) => subtree(folders, f.id))];
+}
+
+export function Folders({ token, selected, refresh, onSelect, onFolders }: Props) {
+	const [list, setList] = useState<FolderList>({ folders: [], counts: {} });
+	const [naming, setNaming] = useState(false);
+	const [name, setName] = useState("");
+	const [error, setError] = useState("");
+
+	async function load() {
+		try {
+			const result = await fetchfolders(BASE_URL, token);
+			setList(result);
+			onFolders(result.folders);
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	useEffect(() => {
+		load();
+	}, [token, refresh]);
+
+	function select(id: string | null) {
+		onSelect(id ? subtree(list.folders, id) : null, id);
+	}
+
+	async function create(e: React.FormEvent) {
+		e.preventDefault();
+		if (!name.trim()) return;
+		try {
+			await createfolder(BASE_URL, token, name.trim(), selected);
+			setName("");
+			setNaming(false);
+			await load();
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function remove(id: string) {
+		try {
+			const deleted = await deletefolder(BASE_URL, token, id);
+			if (selected && deleted.includes(selected)) select(null);
+			await load();
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	function branch(parentId: string | null, depth: number): React.ReactNode {
+		return list.folders
+			.filter((f) => f.parentId === parentId)
+			.sort((a, b) => a.order - b.order || a.name.localeCompare(b.name))
+			.map((folder) => (
+				<div key={folder.id}>
+					<div
+						className={`folder-row ${selected === folder.id ? "active" : ""}`}
+						style={{ paddingLeft: `${depth * 0.75}rem` }}
+					>
+						<button type="button" className="folder-name" onClick={() => select(folder.id)}>
+							{folder.name}
+						</button>
+						<span className="folder-count">{list.counts[folder.id] ?? 0}</span>
+						<button type="button" className="folder-delete" onClick={() => remove(folder.id)}>
+							×
+						</button>
+					</div>
+					{branch(folder.id, depth + 1)}
+				</div>
+			));
+	}
+
+	return (
+		<div className="folders">
+			<div className="folders-header">
+				<span>folders</span>
+				<button type="button" onClick={() => setNaming(!naming)}>
+					+
+				</button>
+			</div>
+			{naming && (
+				<form onSubmit={create}>
+					<input
+						type="text"
+						value={name}
+						onChange={(e) => setName(e.target.value)}
+						placeholder={selected ? "subfolder name" : "folder name"}
+						autoFocus
+					/>
+				</form>
+			)}
+			<div className={`folder-row ${selected === null ? "active" : ""}`}>
+				<button type="button" className="folder-name" onClick={() => select(null)}>
+					all items
+				</button>
+			</div>
+			{branch(null, 0)}
+			{error && <p className="folders-error">{error}</p>}
+
+			<style>{`
+				.folders {
+					margin-top: 1.25rem;
+					display: flex;
+					flex-direction: column;
+					gap: 0.125rem;
+				}
+				.folders-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+					padding: 0 0.75rem 0.375rem;
+					font-size: 0.6875rem;
+					text-transform: uppercase;
+					letter-spacing: 0.05em;
+					color: var(--fg-subtle);
+				}
+				.folders-header button,
+				.folder-delete {
+					background: none;
+					border: none;
+					color: var(--fg-subtle);
+					cursor: pointer;
+					font-size: 0.875rem;
+				}
+				.folders input {
+					width: 100%;
+					padding: 0.375rem 0.75rem;
+					margin-bottom: 0.25rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-size: 0.8125rem;
+				}
+				.folder-row {
+					display: flex;
+					align-items: center;
+					gap: 0.25rem;
+					padding-right: 0.5rem;
+					border-radius: var(--radius-sm);
+				}
+				.folder-row.active {
+					background: var(--bg-elevated);
+				}
+				.folder-name {
+					flex: 1;
+					min-width: 0;
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: none;
+					text-align: left;
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					overflow: hidden;
+					text-overflow: ellipsis;
+					white-space: nowrap;
+				}
+				.folder-row.active .folder-name {
+					color: var(--fg);
+				}
+				.folder-count {
+					font-size: 0.6875rem;
+					color: var(--fg-subtle);
+					font-variant-numeric: tabular-nums;
+				}
+				.folder-delete {
+					opacity: 0;
+				}
+				.folder-row:hover .folder-delete {
+					opacity: 1;
+				}
+				.folder-delete:hover {
+					color: #ef4444;
+				}
+				.folders-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+					padding: 0 0.75rem;
+				}
+			`}</style>
+		</div>
+	);
+}
@@ -0,0 +1,291 @@
+import { useState, useEffect } from "react";
+import { auditvault, type AuditReport, type ItemAudit } from "../lib/audit";
+import { breachsource, configurebreach, type BreachSource } from "../lib/breach";
+
+interface Props {
+	token: string;
+}
+
+type Filter = "weak" | "reused" | "old" | "totp";
+
+const filters: { id: Filter; label: string; count: (r: AuditReport) => number }[] = [
+	{ id: "weak", label: "weak", count: (r) => r.weak },
+	{ id: "reused", label: "reused", count: (r) => r.reused },
+	{ id: "old", label: "over a year old", count: (r) => r.old },
+	{ id: "totp", label: "no 2fa", count: (r) => r.missing_totp },
+];
+
+function matches(item: ItemAudit, filter: Filter): boolean {
+	switch (filter) {
+		case "weak":
+			return item.weak;
+		case "reused":
+			return item.reused > 0;
+		case "old":
+			return item.old;
+		case "totp":
+			return !item.has_totp;
+	}
+}
+
+function detail(item: ItemAudit, filter: Filter): string {
+	switch (filter) {
+		case "weak":
+			return (item.warning ?? item.strength).toLowerCase();
+		case "reused":
+			return `used by ${item.reused} other ${item.reused === 1 ? "login" : "logins"}`;
+		case "old":
+			return `changed ${item.age_days} days ago`;
+		case "totp":
+			return "no one-time password saved";
+	}
+}
+
+export function Health({ token }: Props) {
+	const [report, setReport] = useState<AuditReport | null>(null);
+	const [filter, setFilter] = useState<Filter>("weak");
+	const [loading, setLoading] = useState(false);
+	const [error, setError] = useState("");
+	const [sourcekind, setSourcekind] = useState<BreachSource["kind"] | "">("");
+	const [sourcepath, setSourcepath] = useState("");
+	const [sourcesaved, setSourcesaved] = useState(false);
+
+	useEffect(() => {
+		breachsource()
+			.then((source) => {
+				if (!source) return;
+				setSourcekind(source.kind);
+				setSourcepath(source.kind === "http" ? source.base_url : source.path);
+			})
+			.catch(() => {});
+	}, []);
+
+	async function savesource(e: React.FormEvent) {
+		e.preventDefault();
+		const value = sourcepath.trim();
+		let source: BreachSource | null = null;
+		if (sourcekind === "http") {
+			source = { kind: "http", base_url: value };
+		} else if (sourcekind) {
+			source = { kind: sourcekind, path: value };
+		}
+		try {
+			await configurebreach(source);
+			setSourcesaved(true);
+			setTimeout(() => setSourcesaved(false), 2000);
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function run() {
+		setLoading(true);
+		try {
+			setReport(await auditvault(token));
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setLoading(false);
+		}
+	}
+
+	useEffect(() => {
+		run();
+	}, [token]);
+
+	const items = report ? report.items.filter((item) => matches(item, filter)) : [];
+
+	return (
+		<div className="health">
+			<div className="health-header">
+				<h2>password health</h2>
+				<button type="button" onClick={run} disabled={loading}>
+					{loading ? "checking..." : "check again"}
+				</button>
+			</div>
+			{error && <p className="health-error">{error}</p>}
+			{report && (
+				<>
+					<p className="health-total">{report.total} logins checked</p>
+					<div className="health-filters">
+						{filters.map((f) => (
+							<button
+								key={f.id}
+								type="button"
+								className={`health-filter ${filter === f.id ? "active" : ""}`}
+								onClick={() => setFilter(f.id)}
+							>
+								<span className="health-count">{f.count(report)}</span>
+								<span>{f.label}</span>
+							</button>
+						))}
+					</div>
+					{items.length === 0 ? (
+						<p className="health-empty">nothing to fix here</p>
+					) : (
+						<ul className="health-list">
+							{items.map((item) => (
+								<li key={item.id}>
+									<span className="health-title">{item.title}</span>
+									<span className="health-detail">{detail(item, filter)}</span>
+								</li>
+							))}
+						</ul>
+					)}
+				</>
+			)}
+
+			<form className="health-breach" onSubmit={savesource}>
+				<h3>breach dataset</h3>
+				<div className="health-breach-row">
+					<select
+						value={sourcekind}
+						onChange={(e) => setSourcekind(e.target.value as BreachSource["kind"] | "")}
+					>
+						<option value="">off</option>
+						<option value="file">sorted hash file</option>
+						<option value="directory">range directory</option>
+						<option value="http">range api</option>
+					</select>
+					<input
+						type="text"
+						value={sourcepath}
+						onChange={(e) => setSourcepath(e.target.value)}
+						placeholder={sourcekind === "http" ? "https://api.pwnedpasswords.com" : "path on disk"}
+						disabled={!sourcekind}
+					/>
+					<button type="submit">{sourcesaved ? "saved" : "save"}</button>
+				</div>
+			</form>
+
+			<style>{`
+				.health {
+					padding: 1.5rem;
+					overflow-y: auto;
+				}
+				.health-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+					margin-bottom: 0.5rem;
+				}
+				.health-header h2 {
+					font-size: 1.125rem;
+					font-weight: 600;
+				}
+				.health-header button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.health-header button:hover:not(:disabled) {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.health-total,
+				.health-empty {
+					font-size: 0.8125rem;
+					color: var(--fg-subtle);
+					margin-bottom: 1rem;
+				}
+				.health-filters {
+					display: grid;
+					grid-template-columns: repeat(4, 1fr);
+					gap: 0.5rem;
+					margin-bottom: 1.25rem;
+				}
+				.health-filter {
+					display: flex;
+					flex-direction: column;
+					align-items: flex-start;
+					gap: 0.25rem;
+					padding: 0.75rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.75rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.health-filter.active {
+					border-color: var(--accent);
+					color: var(--fg);
+				}
+				.health-count {
+					font-size: 1.25rem;
+					font-weight: 600;
+					font-variant-numeric: tabular-nums;
+				}
+				.health-list {
+					list-style: none;
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+				}
+				.health-list li {
+					display: flex;
+					justify-content: space-between;
+					gap: 1rem;
+					padding: 0.625rem 0.75rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+				}
+				.health-title {
+					font-size: 0.875rem;
+					color: var(--fg);
+				}
+				.health-detail {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+				.health-breach {
+					margin-top: 1.5rem;
+				}
+				.health-breach h3 {
+					font-size: 0.875rem;
+					font-weight: 600;
+					margin-bottom: 0.5rem;
+				}
+				.health-breach-row {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.health-breach-row select,
+				.health-breach-row input {
+					padding: 0.5rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-size: 0.8125rem;
+				}
+				.health-breach-row input {
+					flex: 1;
+					font-family: ui-monospace, "SF Mono", monospace;
+				}
+				.health-breach-row button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+				}
+				.health-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+					margin-bottom: 1rem;
+				}
+			`}</style>
+		</div>
+	);
+}
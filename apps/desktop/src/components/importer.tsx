@@ -0,0 +1,258 @@
+import { useState } from "react";
+import { BASE_URL } from "../config";
+import {
+	detectimport,
+	importtosync,
+	previewimport,
+	type ImportFormat,
+	type ImportReport,
+} from "../lib/import";
+import { importotp, previewotpimport, type OtpImportPreview } from "../lib/otp";
+import type { BatchResult } from "../lib/batch";
+
+interface Props {
+	token: string;
+	onImported: () => void;
+}
+
+const formats: { id: ImportFormat; label: string }[] = [
+	{ id: "onepux", label: "1password (.1pux)" },
+	{ id: "bitwarden", label: "bitwarden (.json)" },
+	{ id: "lastpass", label: "lastpass (.csv)" },
+	{ id: "kdbx", label: "keepass (.kdbx)" },
+	{ id: "csv", label: "other csv" },
+];
+
+function summary(result: BatchResult | null): string {
+	if (!result) return "nothing new to import";
+	return result.failed > 0
+		? `imported ${result.succeeded}, ${result.failed} failed`
+		: `imported ${result.succeeded} items`;
+}
+
+export function Importer({ token, onImported }: Props) {
+	const [mode, setMode] = useState<"file" | "otp">("file");
+	const [path, setPath] = useState("");
+	const [format, setFormat] = useState<ImportFormat | "">("");
+	const [password, setPassword] = useState("");
+	const [text, setText] = useState("");
+	const [report, setReport] = useState<ImportReport | null>(null);
+	const [otppreview, setOtppreview] = useState<OtpImportPreview | null>(null);
+	const [busy, setBusy] = useState(false);
+	const [error, setError] = useState("");
+	const [result, setResult] = useState("");
+
+	async function preview() {
+		setBusy(true);
+		setResult("");
+		try {
+			if (mode === "file") {
+				const detected = format || (await detectimport(path.trim()));
+				if (!detected) throw new Error("unrecognised export, pick the format");
+				setFormat(detected);
+				setReport(
+					await previewimport(path.trim(), detected, { password: password || undefined }),
+				);
+			} else {
+				setOtppreview(await previewotpimport(BASE_URL, token, text));
+			}
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setBusy(false);
+		}
+	}
+
+	async function submit(e: React.FormEvent) {
+		e.preventDefault();
+		setBusy(true);
+		try {
+			if (mode === "file" && report) {
+				setResult(summary(await importtosync(BASE_URL, token, report)));
+				setReport(null);
+			} else if (mode === "otp") {
+				setResult(summary(await importotp(BASE_URL, token, text)));
+				setOtppreview(null);
+				setText("");
+			}
+			setError("");
+			onImported();
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setBusy(false);
+		}
+	}
+
+	const ready = mode === "file" ? report !== null : otppreview !== null;
+
+	return (
+		<form className="importer" onSubmit={submit}>
+			<div className="importer-header">
+				<h3>import</h3>
+				<div className="importer-tabs">
+					<button
+						type="button"
+						className={mode === "file" ? "active" : ""}
+						onClick={() => setMode("file")}
+					>
+						password manager
+					</button>
+					<button
+						type="button"
+						className={mode === "otp" ? "active" : ""}
+						onClick={() => setMode("otp")}
+					>
+						one-time passwords
+					</button>
+				</div>
+			</div>
+			{error && <p className="importer-error">{error}</p>}
+			{result && <p className="importer-result">{result}</p>}
+			{mode === "file" ? (
+				<>
+					<div className="importer-row">
+						<input
+							type="text"
+							value={path}
+							onChange={(e) => {
+								setPath(e.target.value);
+								setReport(null);
+							}}
+							placeholder="exported file"
+						/>
+						<select
+							value={format}
+							onChange={(e) => {
+								setFormat(e.target.value as ImportFormat | "");
+								setReport(null);
+							}}
+						>
+							<option value="">detect format</option>
+							{formats.map((f) => (
+								<option key={f.id} value={f.id}>
+									{f.label}
+								</option>
+							))}
+						</select>
+					</div>
+					<input
+						type="password"
+						value={password}
+						onChange={(e) => setPassword(e.target.value)}
+						placeholder="export password, if it has one"
+					/>
+					{report && (
+						<p className="importer-result">
+							{Object.entries(report.counts)
+								.map(([kind, count]) => `${count} ${kind}`)
+								.join(", ")}
+							{report.folders.length > 0 && ` in ${report.folders.length} folders`}
+							{report.skipped.length > 0 && `, ${report.skipped.length} skipped`}
+						</p>
+					)}
+				</>
+			) : (
+				<>
+					<textarea
+						value={text}
+						onChange={(e) => {
+							setText(e.target.value);
+							setOtppreview(null);
+						}}
+						placeholder="otpauth:// or otpauth-migration:// links, one per line"
+						rows={4}
+					/>
+					{otppreview && (
+						<p className="importer-result">
+							{otppreview.candidates.length} codes found
+							{otppreview.candidates.some((c) => c.duplicate_of) &&
+								`, ${otppreview.candidates.filter((c) => c.duplicate_of).length} already saved`}
+							{otppreview.invalid.length > 0 && `, ${otppreview.invalid.length} invalid`}
+						</p>
+					)}
+				</>
+			)}
+			<div className="importer-row importer-actions">
+				<button
+					type="button"
+					onClick={preview}
+					disabled={busy || (mode === "file" ? !path.trim() : !text.trim())}
+				>
+					preview
+				</button>
+				<button type="submit" disabled={busy || !ready}>
+					{busy ? "working..." : "import"}
+				</button>
+			</div>
+
+			<style>{`
+				.importer {
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+					padding: 1.5rem 1.5rem 0;
+				}
+				.importer-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+				}
+				.importer-header h3 {
+					font-size: 0.875rem;
+					font-weight: 600;
+				}
+				.importer-tabs,
+				.importer-row {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.importer-row input {
+					flex: 1;
+				}
+				.importer-actions {
+					justify-content: flex-end;
+				}
+				.importer-tabs button,
+				.importer-actions button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.importer-tabs button.active,
+				.importer-actions button:hover:not(:disabled) {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.importer input,
+				.importer select,
+				.importer textarea {
+					padding: 0.5rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-size: 0.8125rem;
+				}
+				.importer textarea {
+					font-family: ui-monospace, "SF Mono", monospace;
+					resize: vertical;
+				}
+				.importer-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+				}
+				.importer-result {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+			`}</style>
+		</form>
+	);
+}
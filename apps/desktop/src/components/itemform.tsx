@@ -1,5 +1,7 @@
 import { useState, useEffect } from "react";
 import type { RemoteItem } from "../hooks/sync";
+import { Strengthmeter } from "./strengthmeter";
+import { attachpolicy, listpolicies, policyforurl, type PasswordPolicy } from "../lib/policy";
 
 type ItemType = "login" | "note" | "card" | "identity" | "ssh" | "api" | "otp" | "passkey";
 
@@ -45,6 +47,8 @@ const schemas: Record<ItemType, { key: string; label: string; type: string; sens
 		{ key: "username", label: "username", type: "text" },
 		{ key: "privatekey", label: "private key", type: "textarea", sensitive: true },
 		{ key: "passphrase", label: "passphrase", type: "password", sensitive: true },
+		{ key: "publickey", label: "public key", type: "textarea" },
+		{ key: "fingerprint", label: "fingerprint", type: "text" },
 	],
 	api: [
 		{ key: "service", label: "service", type: "text" },
@@ -95,6 +99,8 @@ export function Itemform({ item, onSave, onCancel }: Props) {
 	const [tags, setTags] = useState(isEdit ? item.tags.map((t) => t.name).join(", ") : "");
 	const [favorite, setFavorite] = useState(isEdit ? item.favorite : false);
 	const [revealed, setRevealed] = useState<Record<string, boolean>>({});
+	const [policies, setPolicies] = useState<PasswordPolicy[]>([]);
+	const [policy, setPolicy] = useState("");
 
 	useEffect(() => {
 		if (isEdit) {
@@ -104,6 +110,34 @@ export function Itemform({ item, onSave, onCancel }: Props) {
 		}
 	}, [isEdit, item]);
 
+	useEffect(() => {
+		listpolicies()
+			.then(setPolicies)
+			.catch(() => setPolicies([]));
+	}, []);
+
+	useEffect(() => {
+		const url = fields.url?.trim();
+		if (itemtype !== "login" || !url) {
+			setPolicy("");
+			return;
+		}
+		policyforurl(url)
+			.then((found) => setPolicy(found?.id ?? ""))
+			.catch(() => setPolicy(""));
+	}, [itemtype, fields.url]);
+
+	async function handlepolicychange(id: string) {
+		const url = fields.url?.trim();
+		if (!url) return;
+		try {
+			await attachpolicy(url, id || null);
+			setPolicy(id);
+		} catch {
+			setPolicy("");
+		}
+	}
+
 	function handlefieldchange(key: string, value: string) {
 		setFields((prev) => ({ ...prev, [key]: value }));
 	}
@@ -205,6 +239,24 @@ export function Itemform({ item, onSave, onCancel }: Props) {
 								)}
 							</div>
 						)}
+						{itemtype === "login" && field.key === "url" && fields.url && policies.length > 0 && (
+							<select
+								className="policy-select"
+								value={policy}
+								onChange={(e) => handlepolicychange(e.target.value)}
+							>
+								<option value="">no password policy</option>
+								{policies.map((p) => (
+									<option key={p.id} value={p.id}>{p.name}</option>
+								))}
+							</select>
+						)}
+						{itemtype === "login" && field.key === "password" && (
+							<Strengthmeter
+								password={fields.password || ""}
+								inputs={[title, fields.username || ""].filter(Boolean)}
+							/>
+						)}
 					</div>
 				))}
 
@@ -317,6 +369,9 @@ export function Itemform({ item, onSave, onCancel }: Props) {
 				.form-row select {
 					cursor: pointer;
 				}
+				.form-row .policy-select {
+					margin-top: 0.5rem;
+				}
 				.form-row textarea {
 					resize: vertical;
 					min-height: 80px;
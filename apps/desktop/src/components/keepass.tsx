@@ -0,0 +1,199 @@
+import { useState, useEffect } from "react";
+import {
+	closekeepass,
+	keepassinfo,
+	listkeepassitems,
+	openkeepass,
+	reloadkeepass,
+	type KeepassInfo,
+	type KeepassItem,
+} from "../lib/keepass";
+
+export function Keepass() {
+	const [info, setInfo] = useState<KeepassInfo | null>(null);
+	const [items, setItems] = useState<KeepassItem[]>([]);
+	const [path, setPath] = useState("");
+	const [password, setPassword] = useState("");
+	const [keyfile, setKeyfile] = useState("");
+	const [busy, setBusy] = useState(false);
+	const [error, setError] = useState("");
+
+	async function show(opened: KeepassInfo | null) {
+		setInfo(opened);
+		setItems(opened ? await listkeepassitems() : []);
+	}
+
+	useEffect(() => {
+		keepassinfo()
+			.then(show)
+			.catch(() => {});
+	}, []);
+
+	async function open(e: React.FormEvent) {
+		e.preventDefault();
+		setBusy(true);
+		try {
+			await show(await openkeepass(path.trim(), password, keyfile.trim() || undefined));
+			setPassword("");
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setBusy(false);
+		}
+	}
+
+	async function reload() {
+		try {
+			await show(await reloadkeepass());
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function close() {
+		try {
+			await closekeepass();
+			await show(null);
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	return (
+		<form className="keepass" onSubmit={open}>
+			<div className="keepass-header">
+				<h3>keepass database</h3>
+				{info && (
+					<div className="keepass-actions">
+						<button type="button" onClick={reload}>
+							reload
+						</button>
+						<button type="button" onClick={close}>
+							close
+						</button>
+					</div>
+				)}
+			</div>
+			{error && <p className="keepass-error">{error}</p>}
+			{info ? (
+				<>
+					<p className="keepass-detail">
+						{info.name}: {info.items} entries in {info.folders} groups, saved back to {info.path}
+					</p>
+					<ul className="keepass-list">
+						{items.map((item) => (
+							<li key={item.id}>
+								<span className="keepass-title">{item.title}</span>
+								<span className="keepass-detail">{item.item_type}</span>
+							</li>
+						))}
+					</ul>
+				</>
+			) : (
+				<>
+					<input
+						type="text"
+						value={path}
+						onChange={(e) => setPath(e.target.value)}
+						placeholder="path to .kdbx file"
+					/>
+					<div className="keepass-row">
+						<input
+							type="password"
+							value={password}
+							onChange={(e) => setPassword(e.target.value)}
+							placeholder="database password"
+						/>
+						<input
+							type="text"
+							value={keyfile}
+							onChange={(e) => setKeyfile(e.target.value)}
+							placeholder="key file, optional"
+						/>
+						<button type="submit" className="keepass-submit" disabled={busy || !path.trim()}>
+							{busy ? "opening..." : "open"}
+						</button>
+					</div>
+				</>
+			)}
+
+			<style>{`
+				.keepass {
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+					padding: 1.5rem;
+				}
+				.keepass-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+				}
+				.keepass-header h3 {
+					font-size: 0.875rem;
+					font-weight: 600;
+				}
+				.keepass-actions,
+				.keepass-row {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.keepass-row input {
+					flex: 1;
+				}
+				.keepass-actions button,
+				.keepass-submit {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.keepass-actions button:hover,
+				.keepass-submit:hover:not(:disabled) {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.keepass input {
+					padding: 0.5rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-size: 0.8125rem;
+				}
+				.keepass-list {
+					list-style: none;
+					display: flex;
+					flex-direction: column;
+					gap: 0.375rem;
+				}
+				.keepass-list li {
+					display: flex;
+					justify-content: space-between;
+					gap: 1rem;
+					padding: 0.5rem 0.75rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+				}
+				.keepass-title {
+					font-size: 0.875rem;
+					color: var(--fg);
+				}
+				.keepass-detail {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+				.keepass-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+				}
+			`}</style>
+		</form>
+	);
+}
@@ -0,0 +1,69 @@
+import { useState, useEffect } from "react";
+import { generateotp, otpfromitem, type OtpCode, type OtpConfig } from "../lib/otp";
+
+interface Props {
+	data: string;
+}
+
+export function Otp({ data }: Props) {
+	const [config, setConfig] = useState<OtpConfig | null>(null);
+	const [code, setCode] = useState<OtpCode | null>(null);
+	const [copied, setCopied] = useState(false);
+
+	useEffect(() => {
+		otpfromitem(data)
+			.then(setConfig)
+			.catch(() => setConfig(null));
+	}, [data]);
+
+	useEffect(() => {
+		if (!config) return;
+		const refresh = () =>
+			generateotp(config)
+				.then(setCode)
+				.catch(() => setCode(null));
+		refresh();
+		if (config.kind === "hotp") return;
+		const timer = setInterval(refresh, 1000);
+		return () => clearInterval(timer);
+	}, [config]);
+
+	if (!code) return null;
+
+	async function copy(e: React.MouseEvent) {
+		e.stopPropagation();
+		if (!code) return;
+		await navigator.clipboard.writeText(code.code);
+		setCopied(true);
+		setTimeout(() => setCopied(false), 2000);
+	}
+
+	return (
+		<button type="button" className="otp-code" onClick={copy}>
+			<span>{copied ? "copied" : code.code}</span>
+			{config?.kind !== "hotp" && <span className="otp-remaining">{code.remaining}s</span>}
+
+			<style>{`
+				.otp-code {
+					display: inline-flex;
+					align-items: center;
+					gap: 0.375rem;
+					margin-top: 0.25rem;
+					padding: 0.125rem 0.5rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--accent);
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.8125rem;
+					letter-spacing: 0.05em;
+					cursor: pointer;
+				}
+				.otp-remaining {
+					font-size: 0.6875rem;
+					color: var(--fg-subtle);
+				}
+			`}</style>
+		</button>
+	);
+}
@@ -0,0 +1,205 @@
+import { useState, useEffect } from "react";
+import {
+	deletepolicy,
+	listpolicies,
+	parsepasswordrules,
+	savepolicy,
+	type PasswordPolicy,
+} from "../lib/policy";
+
+function summary(policy: PasswordPolicy): string {
+	const { rules } = policy;
+	const parts = [];
+	if (rules.min_length !== null || rules.max_length !== null) {
+		parts.push(`${rules.min_length ?? "any"}-${rules.max_length ?? "any"} chars`);
+	}
+	if (rules.required.length > 0) {
+		parts.push(`${rules.required.length} required classes`);
+	}
+	if (rules.max_consecutive !== null) {
+		parts.push(`max ${rules.max_consecutive} repeated`);
+	}
+	return parts.join(", ") || "any printable characters";
+}
+
+export function Policies() {
+	const [policies, setPolicies] = useState<PasswordPolicy[]>([]);
+	const [name, setName] = useState("");
+	const [rules, setRules] = useState("");
+	const [domains, setDomains] = useState("");
+	const [error, setError] = useState("");
+
+	useEffect(() => {
+		listpolicies()
+			.then(setPolicies)
+			.catch((e) => setError(String(e)));
+	}, []);
+
+	async function add(e: React.FormEvent) {
+		e.preventDefault();
+		try {
+			const parsed = await parsepasswordrules(rules);
+			await savepolicy({
+				id: "",
+				name: name.trim(),
+				rules: parsed,
+				domains: domains
+					.split(",")
+					.map((d) => d.trim())
+					.filter(Boolean),
+			});
+			setPolicies(await listpolicies());
+			setName("");
+			setRules("");
+			setDomains("");
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function remove(id: string) {
+		try {
+			await deletepolicy(id);
+			setPolicies((prev) => prev.filter((p) => p.id !== id));
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	return (
+		<div className="policies-card">
+			<h2>site policies</h2>
+			{policies.length === 0 && <p className="policies-empty">no policies yet</p>}
+			<ul className="policies-list">
+				{policies.map((policy) => (
+					<li key={policy.id}>
+						<div>
+							<span className="policy-name">{policy.name}</span>
+							<span className="policy-summary">{summary(policy)}</span>
+							{policy.domains.length > 0 && (
+								<span className="policy-domains">{policy.domains.join(", ")}</span>
+							)}
+						</div>
+						<button type="button" onClick={() => remove(policy.id)}>
+							remove
+						</button>
+					</li>
+				))}
+			</ul>
+
+			<form onSubmit={add} className="policies-form">
+				<input
+					type="text"
+					value={name}
+					onChange={(e) => setName(e.target.value)}
+					placeholder="name"
+					required
+				/>
+				<input
+					type="text"
+					value={rules}
+					onChange={(e) => setRules(e.target.value)}
+					placeholder="minlength: 8; maxlength: 16; required: upper; allowed: lower, digit"
+				/>
+				<input
+					type="text"
+					value={domains}
+					onChange={(e) => setDomains(e.target.value)}
+					placeholder="sites, comma separated"
+				/>
+				<button type="submit" disabled={!name.trim()}>
+					add policy
+				</button>
+			</form>
+			{error && <p className="policies-error">{error}</p>}
+
+			<style>{`
+				.policies-card {
+					width: 100%;
+					max-width: 480px;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-lg);
+					padding: 1.5rem 2rem;
+				}
+				.policies-card h2 {
+					font-size: 1rem;
+					font-weight: 600;
+					margin-bottom: 1rem;
+				}
+				.policies-empty {
+					font-size: 0.8125rem;
+					color: var(--fg-subtle);
+				}
+				.policies-list {
+					list-style: none;
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+					margin-bottom: 1rem;
+				}
+				.policies-list li {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+					gap: 0.75rem;
+				}
+				.policies-list li div {
+					display: flex;
+					flex-direction: column;
+					gap: 0.125rem;
+					min-width: 0;
+				}
+				.policy-name {
+					font-size: 0.875rem;
+					color: var(--fg);
+				}
+				.policy-summary,
+				.policy-domains {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+				.policies-list button,
+				.policies-form button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.75rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.policies-list button:hover,
+				.policies-form button:hover:not(:disabled) {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.policies-form {
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+				}
+				.policies-form input {
+					padding: 0.5rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.8125rem;
+				}
+				.policies-form input:focus {
+					outline: none;
+					border-color: var(--accent);
+				}
+				.policies-error {
+					margin-top: 0.5rem;
+					font-size: 0.75rem;
+					color: #ef4444;
+				}
+			`}</style>
+		</div>
+	);
+}
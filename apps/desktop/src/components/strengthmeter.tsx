@@ -0,0 +1,134 @@
+import { useState, useEffect } from "react";
+import { estimatestrength, type StrengthEstimate } from "../lib/strength";
+import { breachsource, checkbreach, type BreachResult } from "../lib/breach";
+
+interface Props {
+	password: string;
+	inputs?: string[];
+}
+
+const strengthColors: Record<string, string> = {
+	weak: "#ef4444",
+	fair: "#f59e0b",
+	good: "#eab308",
+	strong: "#22c55e",
+	excellent: "#10b981",
+};
+
+export function Strengthmeter({ password, inputs = [] }: Props) {
+	const [estimate, setEstimate] = useState<StrengthEstimate | null>(null);
+	const [breach, setBreach] = useState<BreachResult | null>(null);
+	const [offlinebreach, setOfflinebreach] = useState(false);
+	const key = inputs.join("\n");
+
+	useEffect(() => {
+		breachsource()
+			.then((source) => setOfflinebreach(source !== null && source.kind !== "http"))
+			.catch(() => setOfflinebreach(false));
+	}, []);
+
+	useEffect(() => {
+		if (!password) {
+			setEstimate(null);
+			setBreach(null);
+			return;
+		}
+		let cancelled = false;
+		const timer = setTimeout(() => {
+			estimatestrength(password, key ? key.split("\n") : [])
+				.then((result) => {
+					if (!cancelled) setEstimate(result);
+				})
+				.catch(() => {
+					if (!cancelled) setEstimate(null);
+				});
+			if (offlinebreach) {
+				checkbreach(password)
+					.then((result) => {
+						if (!cancelled) setBreach(result);
+					})
+					.catch(() => {
+						if (!cancelled) setBreach(null);
+					});
+			}
+		}, 150);
+		return () => {
+			cancelled = true;
+			clearTimeout(timer);
+		};
+	}, [password, key, offlinebreach]);
+
+	if (!estimate) return null;
+
+	const color = strengthColors[estimate.strength];
+	const offline = estimate.crack_times.find((t) => t.scenario === "offline_slow_hashing");
+
+	return (
+		<div className="strengthmeter">
+			<div className="strengthmeter-row">
+				<div className="strengthmeter-bar">
+					<div
+						className="strengthmeter-fill"
+						style={{ width: `${((estimate.score + 1) / 5) * 100}%`, background: color }}
+					/>
+				</div>
+				<span className="strengthmeter-label" style={{ color }}>
+					{estimate.strength}
+				</span>
+			</div>
+			{offline && <p className="strengthmeter-time">cracked offline in {offline.display}</p>}
+			{breach?.breached && (
+				<p className="strengthmeter-warning">
+					found {breach.count.toLocaleString()} times in known breaches
+				</p>
+			)}
+			{estimate.warning && <p className="strengthmeter-warning">{estimate.warning.toLowerCase()}</p>}
+			{estimate.suggestions.map((s) => (
+				<p key={s} className="strengthmeter-suggestion">{s.toLowerCase()}</p>
+			))}
+
+			<style>{`
+				.strengthmeter {
+					display: flex;
+					flex-direction: column;
+					gap: 0.25rem;
+					margin-top: 0.5rem;
+				}
+				.strengthmeter-row {
+					display: flex;
+					align-items: center;
+					gap: 0.75rem;
+				}
+				.strengthmeter-bar {
+					flex: 1;
+					height: 4px;
+					background: var(--bg);
+					border-radius: 2px;
+					overflow: hidden;
+				}
+				.strengthmeter-fill {
+					height: 100%;
+					border-radius: 2px;
+					transition: width 0.3s ease, background 0.3s ease;
+				}
+				.strengthmeter-label {
+					font-size: 0.75rem;
+					font-weight: 600;
+					text-transform: uppercase;
+					letter-spacing: 0.05em;
+				}
+				.strengthmeter-time,
+				.strengthmeter-suggestion {
+					margin: 0;
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+				.strengthmeter-warning {
+					margin: 0;
+					font-size: 0.75rem;
+					color: #f59e0b;
+				}
+			`}</style>
+		</div>
+	);
+}
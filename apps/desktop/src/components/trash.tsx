@@ -0,0 +1,180 @@
+import { useState, useEffect } from "react";
+import { BASE_URL } from "../config";
+import { emptytrash, fetchtrash, purgeitem, restoreitem } from "../lib/trash";
+import type { RemoteItem } from "../hooks/sync";
+
+interface Props {
+	token: string;
+	onRestored: () => void;
+}
+
+function deletedago(item: RemoteItem): string {
+	if (!item.deletedAt) return "";
+	const days = Math.floor((Date.now() - Date.parse(item.deletedAt)) / 86400000);
+	return days === 0 ? "deleted today" : `deleted ${days} ${days === 1 ? "day" : "days"} ago`;
+}
+
+export function Trash({ token, onRestored }: Props) {
+	const [items, setItems] = useState<RemoteItem[]>([]);
+	const [loading, setLoading] = useState(false);
+	const [error, setError] = useState("");
+
+	async function load() {
+		setLoading(true);
+		try {
+			setItems(await fetchtrash(BASE_URL, token));
+			setError("");
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setLoading(false);
+		}
+	}
+
+	useEffect(() => {
+		load();
+	}, [token]);
+
+	async function restore(id: string) {
+		try {
+			await restoreitem(BASE_URL, token, id);
+			setItems((prev) => prev.filter((i) => i.id !== id));
+			onRestored();
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function purge(id: string) {
+		try {
+			await purgeitem(BASE_URL, token, id);
+			setItems((prev) => prev.filter((i) => i.id !== id));
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	async function empty() {
+		try {
+			const purged = await emptytrash(BASE_URL, token);
+			setItems((prev) => prev.filter((i) => !purged.includes(i.id)));
+		} catch (e) {
+			setError(String(e));
+		}
+	}
+
+	return (
+		<div className="trash">
+			<div className="trash-header">
+				<h2>trash</h2>
+				<button type="button" onClick={empty} disabled={loading || items.length === 0}>
+					empty trash
+				</button>
+			</div>
+			<p className="trash-note">items are deleted for good after 30 days</p>
+			{error && <p className="trash-error">{error}</p>}
+			{items.length === 0 ? (
+				<p className="trash-note">{loading ? "loading..." : "trash is empty"}</p>
+			) : (
+				<ul className="trash-list">
+					{items.map((item) => (
+						<li key={item.id}>
+							<div>
+								<span className="trash-title">{item.title}</span>
+								<span className="trash-detail">{deletedago(item)}</span>
+							</div>
+							<div className="trash-actions">
+								<button type="button" onClick={() => restore(item.id)}>
+									restore
+								</button>
+								<button type="button" className="trash-purge" onClick={() => purge(item.id)}>
+									delete forever
+								</button>
+							</div>
+						</li>
+					))}
+				</ul>
+			)}
+
+			<style>{`
+				.trash {
+					padding: 1.5rem;
+					overflow-y: auto;
+				}
+				.trash-header {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+					margin-bottom: 0.5rem;
+				}
+				.trash-header h2 {
+					font-size: 1.125rem;
+					font-weight: 600;
+				}
+				.trash-header button,
+				.trash-actions button {
+					padding: 0.375rem 0.75rem;
+					background: none;
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.8125rem;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.trash-header button:hover:not(:disabled),
+				.trash-actions button:hover {
+					color: var(--accent);
+					border-color: var(--accent);
+				}
+				.trash-actions .trash-purge:hover {
+					color: #ef4444;
+					border-color: #ef4444;
+				}
+				.trash-note {
+					font-size: 0.8125rem;
+					color: var(--fg-subtle);
+					margin-bottom: 1rem;
+				}
+				.trash-list {
+					list-style: none;
+					display: flex;
+					flex-direction: column;
+					gap: 0.5rem;
+				}
+				.trash-list li {
+					display: flex;
+					align-items: center;
+					justify-content: space-between;
+					gap: 1rem;
+					padding: 0.625rem 0.75rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+				}
+				.trash-list li > div:first-child {
+					display: flex;
+					flex-direction: column;
+					gap: 0.125rem;
+				}
+				.trash-title {
+					font-size: 0.875rem;
+					color: var(--fg);
+				}
+				.trash-detail {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+				.trash-actions {
+					display: flex;
+					gap: 0.5rem;
+				}
+				.trash-error {
+					font-size: 0.75rem;
+					color: #ef4444;
+					margin-bottom: 1rem;
+				}
+			`}</style>
+		</div>
+	);
+}
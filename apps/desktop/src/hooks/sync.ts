@@ -1,6 +1,15 @@
 import { invoke } from "@tauri-apps/api/core";
-import { useState, useCallback } from "react";
+import { useState, useCallback, useEffect } from "react";
 import { BASE_URL } from "../config";
+import {
+	onsynccompleted,
+	onsyncconflict,
+	onsyncrejected,
+	pushchange,
+	resolveconflict,
+	startsync,
+	type SyncConflict,
+} from "../lib/autosync";
 
 export interface RemoteItem {
 	id: string;
@@ -10,13 +19,27 @@ export interface RemoteItem {
 	revision: number;
 	favorite: boolean;
 	deleted: boolean;
+	deletedAt: string | null;
 	tags: { id: string; name: string }[];
+	folderId: string | null;
+	createdAt: string;
+	updatedAt: string;
+}
+
+function merge(items: RemoteItem[], changed: RemoteItem[], removed: string[]): RemoteItem[] {
+	const updates = new Map(changed.map((item) => [item.id, item]));
+	const added = changed.filter((item) => !items.some((i) => i.id === item.id));
+	const kept = items
+		.filter((item) => !removed.includes(item.id))
+		.map((item) => updates.get(item.id) ?? item);
+	return [...added, ...kept];
 }
 
 export function useSync(token: string | null) {
 	const [items, setItems] = useState<RemoteItem[]>([]);
 	const [loading, setLoading] = useState(false);
 	const [error, setError] = useState<string | null>(null);
+	const [conflicts, setConflicts] = useState<SyncConflict[]>([]);
 
 	const fetch = useCallback(async () => {
 		if (!token) return;
@@ -35,6 +58,26 @@ export function useSync(token: string | null) {
 		}
 	}, [token]);
 
+	useEffect(() => {
+		if (!token) return;
+		startsync(BASE_URL, token).catch((e) => setError(String(e)));
+		const unlisten = [
+			onsynccompleted((result) => {
+				setError(result.error);
+				setItems((prev) => merge(prev, result.changed, result.removed));
+			}),
+			onsyncconflict((conflict) => {
+				setConflicts((prev) => [...prev.filter((c) => c.id !== conflict.id), conflict]);
+			}),
+			onsyncrejected(({ change, error }) => {
+				setError(`server rejected ${change.op} of ${change.id}: ${error}`);
+			}),
+		];
+		return () => {
+			for (const fn of unlisten) fn.then((f) => f());
+		};
+	}, [token]);
+
 	const create = useCallback(
 		async (
 			itemType: string,
@@ -44,26 +87,25 @@ export function useSync(token: string | null) {
 			favorite = false,
 		) => {
 			if (!token) throw new Error("not authenticated");
-			setLoading(true);
-			setError(null);
-			try {
-				const item = await invoke<RemoteItem>("sync_create", {
-					baseUrl: BASE_URL,
-					token,
-					itemType,
-					title,
-					data,
-					tags,
-					favorite,
-				});
-				setItems((prev) => [item, ...prev]);
-				return item;
-			} catch (e) {
-				setError(String(e));
-				throw e;
-			} finally {
-				setLoading(false);
-			}
+			const id = crypto.randomUUID();
+			const now = new Date().toISOString();
+			await pushchange({ op: "create", id, item_type: itemType, title, data, tags, favorite });
+			const item: RemoteItem = {
+				id,
+				type: itemType,
+				title,
+				data,
+				revision: 0,
+				favorite,
+				deleted: false,
+				deletedAt: null,
+				tags: tags.map((name) => ({ id: name, name })),
+				folderId: null,
+				createdAt: now,
+				updatedAt: now,
+			};
+			setItems((prev) => [item, ...prev]);
+			return item;
 		},
 		[token],
 	);
@@ -79,49 +121,39 @@ export function useSync(token: string | null) {
 			},
 		) => {
 			if (!token) throw new Error("not authenticated");
-			setLoading(true);
-			setError(null);
-			try {
-				const item = await invoke<RemoteItem>("sync_update", {
-					baseUrl: BASE_URL,
-					token,
-					id,
-					...updates,
-				});
-				setItems((prev) => prev.map((i) => (i.id === id ? item : i)));
-				return item;
-			} catch (e) {
-				setError(String(e));
-				throw e;
-			} finally {
-				setLoading(false);
-			}
+			const current = items.find((i) => i.id === id);
+			if (!current) throw new Error("item not found");
+			await pushchange({ op: "update", id, revision: current.revision, ...updates });
+			const { tags, ...fields } = updates;
+			const item: RemoteItem = {
+				...current,
+				...fields,
+				tags: tags ? tags.map((name) => ({ id: name, name })) : current.tags,
+				updatedAt: new Date().toISOString(),
+			};
+			setItems((prev) => prev.map((i) => (i.id === id ? item : i)));
+			return item;
 		},
-		[token],
+		[token, items],
 	);
 
 	const remove = useCallback(
 		async (id: string) => {
 			if (!token) throw new Error("not authenticated");
-			setLoading(true);
-			setError(null);
-			try {
-				await invoke<boolean>("sync_delete", {
-					baseUrl: BASE_URL,
-					token,
-					id,
-				});
-				setItems((prev) => prev.filter((i) => i.id !== id));
-			} catch (e) {
-				setError(String(e));
-				throw e;
-			} finally {
-				setLoading(false);
-			}
+			await pushchange({ op: "delete", id });
+			setItems((prev) => prev.filter((i) => i.id !== id));
 		},
 		[token],
 	);
 
+	const resolve = useCallback(async (conflict: SyncConflict, keepLocal: boolean) => {
+		await resolveconflict(conflict.id, keepLocal);
+		setConflicts((prev) => prev.filter((c) => c.id !== conflict.id));
+		if (!keepLocal) {
+			setItems((prev) => prev.map((i) => (i.id === conflict.id ? conflict.server : i)));
+		}
+	}, []);
+
 	return {
 		items,
 		loading,
@@ -130,5 +162,7 @@ export function useSync(token: string | null) {
 		create,
 		update,
 		remove,
+		conflicts,
+		resolve,
 	};
 }
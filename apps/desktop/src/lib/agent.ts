@@ -0,0 +1,55 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { BASE_URL } from "../config";
+
+export interface AgentIdentity {
+	id: string;
+	title: string;
+	algorithm: string;
+	fingerprint: string;
+	comment: string;
+}
+
+export interface SkippedKey {
+	id: string;
+	title: string;
+	reason: string;
+}
+
+export interface AgentStatus {
+	running: boolean;
+	socket: string | null;
+	identities: AgentIdentity[];
+	skipped: SkippedKey[];
+}
+
+export interface ApprovalRequest {
+	request: string;
+	id: string;
+	title: string;
+	fingerprint: string;
+}
+
+export async function startagent(token: string): Promise<AgentStatus> {
+	return invoke("agent_start", { baseUrl: BASE_URL, token });
+}
+
+export async function stopagent(): Promise<void> {
+	return invoke("agent_stop");
+}
+
+export async function agentstatus(): Promise<AgentStatus> {
+	return invoke("agent_status");
+}
+
+export async function approvesign(request: string, approved: boolean): Promise<void> {
+	return invoke("agent_approve", { request, approved });
+}
+
+export async function onapprovalrequest(
+	callback: (request: ApprovalRequest) => void,
+): Promise<() => void> {
+	return listen<ApprovalRequest>("agent_approval", (event) => {
+		callback(event.payload);
+	});
+}
@@ -0,0 +1,33 @@
+import { invoke } from "@tauri-apps/api/core";
+import { BASE_URL } from "../config";
+
+export interface ItemAudit {
+	id: string;
+	title: string;
+	score: number;
+	strength: string;
+	warning: string | null;
+	age_days: number;
+	reused: number;
+	weak: boolean;
+	old: boolean;
+	has_totp: boolean;
+}
+
+export interface ReusedGroup {
+	ids: string[];
+}
+
+export interface AuditReport {
+	total: number;
+	weak: number;
+	reused: number;
+	old: number;
+	missing_totp: number;
+	groups: ReusedGroup[];
+	items: ItemAudit[];
+}
+
+export async function auditvault(token: string): Promise<AuditReport> {
+	return invoke("audit_vault", { baseUrl: BASE_URL, token });
+}
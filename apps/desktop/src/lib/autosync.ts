@@ -0,0 +1,133 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import type { RemoteItem } from "../hooks/sync";
+
+export type PendingChange =
+	| {
+			op: "create";
+			id: string;
+			item_type: string;
+			title: string;
+			data: string;
+			tags: string[];
+			favorite: boolean;
+	  }
+	| {
+			op: "update";
+			id: string;
+			revision: number;
+			title?: string;
+			data?: string;
+			tags?: string[];
+			favorite?: boolean;
+	  }
+	| { op: "delete"; id: string };
+
+export interface SyncProgress {
+	phase: "push" | "pull";
+	done: number;
+	total: number;
+}
+
+export interface SyncCompleted {
+	changed: RemoteItem[];
+	removed: string[];
+	pushed: number;
+	error: string | null;
+	timestamp: number;
+}
+
+export interface SyncConflict {
+	id: string;
+	local_revision: number;
+	server_revision: number;
+	server: RemoteItem;
+}
+
+export interface SyncRejected {
+	change: PendingChange;
+	error: string;
+}
+
+export async function startsync(
+	baseUrl: string,
+	token: string,
+	interval?: number,
+): Promise<void> {
+	return invoke("sync_start", { baseUrl, token, interval });
+}
+
+export async function stopsync(): Promise<void> {
+	return invoke("sync_stop");
+}
+
+export async function setinterval(interval: number): Promise<void> {
+	return invoke("sync_set_interval", { interval });
+}
+
+export async function syncnow(): Promise<void> {
+	return invoke("sync_now");
+}
+
+export async function pushchange(change: PendingChange): Promise<void> {
+	return invoke("sync_push", { change });
+}
+
+export async function resolveconflict(
+	id: string,
+	keepLocal: boolean,
+): Promise<void> {
+	return invoke("sync_resolve_conflict", { id, keepLocal });
+}
+
+export async function pendingchanges(): Promise<PendingChange[]> {
+	return invoke("sync_pending");
+}
+
+export async function onsyncstarted(
+	callback: (reason: string) => void,
+): Promise<() => void> {
+	return listen<{ reason: string }>("sync_started", (event) => {
+		callback(event.payload.reason);
+	});
+}
+
+export async function onsyncprogress(
+	callback: (progress: SyncProgress) => void,
+): Promise<() => void> {
+	return listen<SyncProgress>("sync_progress", (event) => {
+		callback(event.payload);
+	});
+}
+
+export async function onsynccompleted(
+	callback: (result: SyncCompleted) => void,
+): Promise<() => void> {
+	return listen<SyncCompleted>("sync_completed", (event) => {
+		callback(event.payload);
+	});
+}
+
+export async function onsyncconflict(
+	callback: (conflict: SyncConflict) => void,
+): Promise<() => void> {
+	return listen<SyncConflict>("sync_conflict", (event) => {
+		callback(event.payload);
+	});
+}
+
+export async function onsyncrejected(
+	callback: (rejected: SyncRejected) => void,
+): Promise<() => void> {
+	return listen<SyncRejected>("sync_rejected", (event) => {
+		callback(event.payload);
+	});
+}
+
+export async function onsyncconnection(
+	callback: (connected: boolean) => void,
+): Promise<() => void> {
+	return listen<{ connected: boolean }>("sync_connection", (event) => {
+		callback(event.payload.connected);
+	});
+}
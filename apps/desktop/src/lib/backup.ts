@@ -0,0 +1,66 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export type PlainFormat = "json" | "csv";
+
+export interface BackupKdf {
+	algorithm: string;
+	memory: number;
+	iterations: number;
+	parallelism: number;
+	salt: string;
+}
+
+export interface BackupManifest {
+	format: string;
+	version: number;
+	created: number;
+	source: string;
+	kdf: BackupKdf;
+	cipher: string;
+	nonce: string;
+	items: number;
+	folders: number;
+	attachments: number;
+}
+
+export interface BackupAttachment {
+	id: string;
+	item_id: string;
+	name: string;
+	mime: string | null;
+	data: string;
+}
+
+export interface BackupImportReport {
+	manifest: BackupManifest;
+	created: number;
+	updated: number;
+	unchanged: number;
+	folders: number;
+	skipped: string[];
+	attachments: BackupAttachment[];
+}
+
+export async function exportbackup(
+	path: string,
+	password: string,
+	attachments?: BackupAttachment[],
+): Promise<BackupManifest> {
+	return invoke("backup_export", { path, password, attachments });
+}
+
+export async function readbackupmanifest(path: string): Promise<BackupManifest> {
+	return invoke("backup_manifest", { path });
+}
+
+export async function importbackup(path: string, password: string): Promise<BackupImportReport> {
+	return invoke("backup_import", { path, password });
+}
+
+export async function exportplaintext(
+	path: string,
+	password: string,
+	format: PlainFormat,
+): Promise<void> {
+	return invoke("backup_export_plain", { path, password, format });
+}
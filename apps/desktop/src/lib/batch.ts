@@ -0,0 +1,44 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { RemoteItem } from "../hooks/sync";
+
+export type BatchOp =
+	| {
+			op: "create";
+			id: string;
+			item_type: string;
+			title: string;
+			data: string;
+			tags: string[];
+			favorite: boolean;
+	  }
+	| {
+			op: "update";
+			id: string;
+			title?: string;
+			data?: string;
+			tags?: string[];
+			favorite?: boolean;
+	  }
+	| { op: "delete"; id: string };
+
+export interface BatchItemResult {
+	id: string | null;
+	op: "create" | "update" | "delete";
+	ok: boolean;
+	error: string | null;
+	item: RemoteItem | null;
+}
+
+export interface BatchResult {
+	results: BatchItemResult[];
+	succeeded: number;
+	failed: number;
+}
+
+export async function syncbatch(
+	baseUrl: string,
+	token: string,
+	ops: BatchOp[],
+): Promise<BatchResult> {
+	return invoke("sync_batch", { baseUrl, token, ops });
+}
@@ -0,0 +1,23 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export type BreachSource =
+	| { kind: "file"; path: string }
+	| { kind: "directory"; path: string }
+	| { kind: "http"; base_url: string };
+
+export interface BreachResult {
+	breached: boolean;
+	count: number;
+}
+
+export async function breachsource(): Promise<BreachSource | null> {
+	return invoke("breach_source");
+}
+
+export async function configurebreach(source: BreachSource | null): Promise<void> {
+	return invoke("breach_configure", { source });
+}
+
+export async function checkbreach(password: string): Promise<BreachResult> {
+	return invoke("breach_check", { password });
+}
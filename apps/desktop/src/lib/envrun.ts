@@ -0,0 +1,38 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { BASE_URL } from "../config";
+
+export interface EnvRunStatus {
+	running: boolean;
+	socket: string | null;
+}
+
+export interface EnvRunApproval {
+	request: string;
+	command: string;
+	items: string[];
+}
+
+export async function startenvrun(token: string): Promise<EnvRunStatus> {
+	return invoke("envrun_start", { baseUrl: BASE_URL, token });
+}
+
+export async function stopenvrun(): Promise<void> {
+	return invoke("envrun_stop");
+}
+
+export async function envrunstatus(): Promise<EnvRunStatus> {
+	return invoke("envrun_status");
+}
+
+export async function approveenvrun(request: string, approved: boolean): Promise<void> {
+	return invoke("envrun_approve", { request, approved });
+}
+
+export async function onenvrunrequest(
+	callback: (request: EnvRunApproval) => void,
+): Promise<() => void> {
+	return listen<EnvRunApproval>("envrun_approval", (event) => {
+		callback(event.payload);
+	});
+}
@@ -0,0 +1,76 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export interface RemoteFolder {
+	id: string;
+	name: string;
+	parentId: string | null;
+	color: string;
+	icon: string;
+	order: number;
+	createdAt: string;
+	updatedAt: string;
+}
+
+export interface FolderList {
+	folders: RemoteFolder[];
+	counts: Record<string, number>;
+}
+
+export async function fetchfolders(baseUrl: string, token: string): Promise<FolderList> {
+	return invoke("folder_fetch", { baseUrl, token });
+}
+
+export async function createfolder(
+	baseUrl: string,
+	token: string,
+	name: string,
+	parentId: string | null = null,
+	color?: string,
+	icon?: string,
+): Promise<RemoteFolder> {
+	const id = crypto.randomUUID();
+	return invoke("folder_create", { baseUrl, token, id, name, parentId, color, icon });
+}
+
+export async function updatefolder(
+	baseUrl: string,
+	token: string,
+	id: string,
+	updates: { name?: string; color?: string; icon?: string; order?: number },
+): Promise<RemoteFolder> {
+	return invoke("folder_update", { baseUrl, token, id, ...updates });
+}
+
+export async function movefolder(
+	baseUrl: string,
+	token: string,
+	id: string,
+	parentId: string | null,
+): Promise<RemoteFolder> {
+	return invoke("folder_move", { baseUrl, token, id, parentId });
+}
+
+export async function deletefolder(baseUrl: string, token: string, id: string): Promise<string[]> {
+	return invoke("folder_delete", { baseUrl, token, id });
+}
+
+export async function moveitems(
+	baseUrl: string,
+	token: string,
+	folderId: string | null,
+	itemIds: string[],
+): Promise<number> {
+	return invoke("folder_move_items", { baseUrl, token, folderId, itemIds });
+}
+
+export async function folderchildren(parentId: string | null): Promise<RemoteFolder[]> {
+	return invoke("folder_children", { parentId });
+}
+
+export async function folderpath(id: string): Promise<RemoteFolder[]> {
+	return invoke("folder_path", { id });
+}
+
+export async function folderdescendants(id: string): Promise<RemoteFolder[]> {
+	return invoke("folder_descendants", { id });
+}
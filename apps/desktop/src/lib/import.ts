@@ -0,0 +1,115 @@
+import { invoke } from "@tauri-apps/api/core";
+import { syncbatch, type BatchOp, type BatchResult } from "./batch";
+import { createfolder, fetchfolders, moveitems, type RemoteFolder } from "./folders";
+
+export type ImportFormat = "onepux" | "bitwarden" | "lastpass" | "kdbx" | "csv";
+
+export interface CsvMapping {
+	delimiter?: string;
+	title?: string;
+	username?: string;
+	password?: string;
+	url?: string;
+	notes?: string;
+	totp?: string;
+	folder?: string;
+	tags?: string;
+	favorite?: string;
+	item_type?: string;
+}
+
+export interface ImportOptions {
+	password?: string;
+	keyfile?: string;
+	mapping?: CsvMapping;
+}
+
+export interface ImportedItem {
+	item_type: string;
+	title: string;
+	data: string;
+	tags: string[];
+	favorite: boolean;
+	folder: string | null;
+}
+
+export interface ImportReport {
+	format: ImportFormat;
+	items: ImportedItem[];
+	counts: Record<string, number>;
+	folders: string[];
+	skipped: string[];
+}
+
+export async function detectimport(path: string): Promise<ImportFormat | null> {
+	return invoke("import_detect", { path });
+}
+
+export async function previewimport(
+	path: string,
+	format?: ImportFormat,
+	options?: ImportOptions,
+): Promise<ImportReport> {
+	return invoke("import_preview", { path, format, options });
+}
+
+export async function importtostorage(
+	path: string,
+	format?: ImportFormat,
+	options?: ImportOptions,
+): Promise<ImportReport> {
+	return invoke("import_to_storage", { path, format, options });
+}
+
+async function folderids(
+	baseUrl: string,
+	token: string,
+	paths: string[],
+): Promise<Map<string, string>> {
+	const { folders } = await fetchfolders(baseUrl, token);
+	const known: RemoteFolder[] = [...folders];
+	const ids = new Map<string, string>();
+	for (const path of paths) {
+		let parentId: string | null = null;
+		for (const name of path.split("/").filter((n) => n.length > 0)) {
+			let folder = known.find((f) => f.name === name && f.parentId === parentId);
+			if (!folder) {
+				folder = await createfolder(baseUrl, token, name, parentId);
+				known.push(folder);
+			}
+			parentId = folder.id;
+		}
+		if (parentId) ids.set(path, parentId);
+	}
+	return ids;
+}
+
+export async function importtosync(
+	baseUrl: string,
+	token: string,
+	report: ImportReport,
+): Promise<BatchResult | null> {
+	const items = report.items.map((item) => ({ id: crypto.randomUUID(), item }));
+	const ops: BatchOp[] = items.map(({ id, item }) => ({
+		op: "create",
+		id,
+		item_type: item.item_type,
+		title: item.title,
+		data: item.data,
+		tags: item.tags,
+		favorite: item.favorite,
+	}));
+	if (ops.length === 0) return null;
+	const result = await syncbatch(baseUrl, token, ops);
+
+	const ids = await folderids(baseUrl, token, report.folders);
+	const moves = new Map<string, string[]>();
+	for (const { id, item } of items) {
+		const folderId = item.folder ? ids.get(item.folder) : undefined;
+		if (folderId) moves.set(folderId, [...(moves.get(folderId) ?? []), id]);
+	}
+	for (const [folderId, itemIds] of moves) {
+		await moveitems(baseUrl, token, folderId, itemIds);
+	}
+	return result;
+}
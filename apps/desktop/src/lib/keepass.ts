@@ -0,0 +1,95 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export interface KeepassInfo {
+	path: string;
+	name: string;
+	items: number;
+	folders: number;
+}
+
+export interface KeepassItem {
+	id: string;
+	item_type: string;
+	title: string;
+	data: string;
+	tags: string[];
+	favorite: boolean;
+	folder_id: string | null;
+	created: number;
+	updated: number;
+}
+
+export interface KeepassFolder {
+	id: string;
+	name: string;
+	parent_id: string | null;
+}
+
+export async function openkeepass(
+	path: string,
+	password: string,
+	keyfile?: string,
+): Promise<KeepassInfo> {
+	return invoke("keepass_open", { path, password, keyfile });
+}
+
+export async function createkeepass(
+	path: string,
+	password: string,
+	name: string,
+	keyfile?: string,
+): Promise<KeepassInfo> {
+	return invoke("keepass_create", { path, password, keyfile, name });
+}
+
+export async function reloadkeepass(): Promise<KeepassInfo> {
+	return invoke("keepass_reload");
+}
+
+export async function closekeepass(): Promise<void> {
+	return invoke("keepass_close");
+}
+
+export async function keepassinfo(): Promise<KeepassInfo | null> {
+	return invoke("keepass_info");
+}
+
+export async function listkeepassitems(): Promise<KeepassItem[]> {
+	return invoke("keepass_list_items");
+}
+
+export async function listkeepassfolders(): Promise<KeepassFolder[]> {
+	return invoke("keepass_list_folders");
+}
+
+export async function createkeepassitem(
+	itemType: string,
+	title: string,
+	data: string,
+	tags: string[] = [],
+	folderId: string | null = null,
+): Promise<KeepassItem> {
+	return invoke("keepass_create_item", { itemType, title, data, tags, folderId });
+}
+
+export async function updatekeepassitem(
+	id: string,
+	updates: { title?: string; data?: string; tags?: string[] },
+): Promise<KeepassItem> {
+	return invoke("keepass_update_item", { id, ...updates });
+}
+
+export async function deletekeepassitem(id: string): Promise<void> {
+	return invoke("keepass_delete_item", { id });
+}
+
+export async function movekeepassitem(id: string, folderId: string | null): Promise<void> {
+	return invoke("keepass_move_item", { id, folderId });
+}
+
+export async function createkeepassfolder(
+	name: string,
+	parentId: string | null = null,
+): Promise<KeepassFolder> {
+	return invoke("keepass_create_folder", { name, parentId });
+}
@@ -0,0 +1,82 @@
+import { invoke } from "@tauri-apps/api/core";
+import { syncbatch, type BatchOp, type BatchResult } from "./batch";
+
+export type OtpKind = "totp" | "hotp" | "steam";
+export type OtpAlgorithm = "sha1" | "sha256" | "sha512";
+
+export interface OtpConfig {
+	kind: OtpKind;
+	secret: string;
+	issuer: string | null;
+	account: string | null;
+	algorithm: OtpAlgorithm;
+	digits: number;
+	period: number;
+	counter: number;
+}
+
+export interface OtpCode {
+	code: string;
+	counter: number;
+	period: number;
+	remaining: number;
+	expires: number;
+}
+
+export async function parseotp(input: string): Promise<OtpConfig> {
+	return invoke("otp_parse", { input });
+}
+
+export async function otpuri(config: OtpConfig): Promise<string> {
+	return invoke("otp_uri", { config });
+}
+
+export async function generateotp(config: OtpConfig, timestamp?: number): Promise<OtpCode> {
+	return invoke("otp_generate", { config, timestamp });
+}
+
+export async function otpfromitem(data: string): Promise<OtpConfig | null> {
+	return invoke("otp_from_item", { data });
+}
+
+export interface OtpImportCandidate {
+	config: OtpConfig;
+	title: string;
+	data: string;
+	duplicate_of: string | null;
+}
+
+export interface OtpImportPreview {
+	candidates: OtpImportCandidate[];
+	invalid: string[];
+}
+
+export async function previewotpimport(
+	baseUrl: string,
+	token: string,
+	text: string,
+): Promise<OtpImportPreview> {
+	return invoke("otp_import_preview", { baseUrl, token, text });
+}
+
+export async function importotp(
+	baseUrl: string,
+	token: string,
+	text: string,
+	skipDuplicates = true,
+): Promise<BatchResult | null> {
+	const preview = await previewotpimport(baseUrl, token, text);
+	const ops: BatchOp[] = preview.candidates
+		.filter((c) => !skipDuplicates || c.duplicate_of === null)
+		.map((c) => ({
+			op: "create",
+			id: crypto.randomUUID(),
+			item_type: "otp",
+			title: c.title,
+			data: c.data,
+			tags: [],
+			favorite: false,
+		}));
+	if (ops.length === 0) return null;
+	return syncbatch(baseUrl, token, ops);
+}
@@ -0,0 +1,40 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export interface PasswordRules {
+	min_length: number | null;
+	max_length: number | null;
+	max_consecutive: number | null;
+	allowed: string;
+	required: string[];
+}
+
+export interface PasswordPolicy {
+	id: string;
+	name: string;
+	rules: PasswordRules;
+	domains: string[];
+}
+
+export async function parsepasswordrules(rules: string): Promise<PasswordRules> {
+	return invoke("policy_parse_rules", { rules });
+}
+
+export async function listpolicies(): Promise<PasswordPolicy[]> {
+	return invoke("policy_list");
+}
+
+export async function savepolicy(policy: PasswordPolicy): Promise<PasswordPolicy> {
+	return invoke("policy_save", { policy });
+}
+
+export async function deletepolicy(id: string): Promise<void> {
+	return invoke("policy_delete", { id });
+}
+
+export async function attachpolicy(url: string, id: string | null): Promise<void> {
+	return invoke("policy_attach", { url, id });
+}
+
+export async function policyforurl(url: string): Promise<PasswordPolicy | null> {
+	return invoke("policy_for_url", { url });
+}
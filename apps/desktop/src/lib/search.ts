@@ -0,0 +1,14 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export interface SearchHit {
+	id: string;
+	score: number;
+}
+
+export async function search(query: string, limit?: number): Promise<SearchHit[]> {
+	return invoke("search_query", { query, limit });
+}
+
+export async function searchready(): Promise<boolean> {
+	return invoke("search_ready");
+}
@@ -0,0 +1,48 @@
+import { invoke } from "@tauri-apps/api/core";
+import { BASE_URL } from "../config";
+import type { RemoteItem } from "../hooks/sync";
+
+export type KeyKind = "ed25519" | "ecdsa" | "rsa";
+
+export interface KeyOptions {
+	kind: KeyKind;
+	bits?: number;
+	comment?: string;
+}
+
+export interface SshKey {
+	privatekey: string;
+	publickey: string;
+	fingerprint: string;
+	algorithm: string;
+	comment: string;
+}
+
+export async function generatesshkey(
+	token: string,
+	title: string,
+	options: KeyOptions,
+): Promise<RemoteItem> {
+	return invoke("sshkey_generate", { baseUrl: BASE_URL, token, title, options });
+}
+
+export async function importsshkey(
+	token: string,
+	title: string,
+	privatekey: string,
+	passphrase?: string,
+): Promise<RemoteItem> {
+	return invoke("sshkey_import", { baseUrl: BASE_URL, token, title, privatekey, passphrase });
+}
+
+export async function inspectsshkey(privatekey: string, passphrase?: string): Promise<SshKey> {
+	return invoke("sshkey_inspect", { privatekey, passphrase });
+}
+
+export async function sshfingerprint(publickey: string): Promise<string> {
+	return invoke("sshkey_fingerprint", { publickey });
+}
+
+export async function authorizedkeys(token: string): Promise<string> {
+	return invoke("sshkey_authorized_keys", { baseUrl: BASE_URL, token });
+}
@@ -0,0 +1,31 @@
+import { invoke } from "@tauri-apps/api/core";
+
+export interface CrackTime {
+	scenario: string;
+	seconds: number;
+	display: string;
+}
+
+export interface PatternMatch {
+	pattern: string;
+	token: string;
+	guesses: number;
+}
+
+export interface StrengthEstimate {
+	guesses: number;
+	guesses_log10: number;
+	score: number;
+	strength: string;
+	crack_times: CrackTime[];
+	warning: string | null;
+	suggestions: string[];
+	sequence: PatternMatch[];
+}
+
+export async function estimatestrength(
+	password: string,
+	inputs: string[] = [],
+): Promise<StrengthEstimate> {
+	return invoke("strength_estimate", { password, inputs });
+}
@@ -0,0 +1,18 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { RemoteItem } from "../hooks/sync";
+
+export async function fetchtrash(baseUrl: string, token: string): Promise<RemoteItem[]> {
+	return invoke("sync_trash", { baseUrl, token });
+}
+
+export async function restoreitem(baseUrl: string, token: string, id: string): Promise<RemoteItem> {
+	return invoke("sync_restore", { baseUrl, token, id });
+}
+
+export async function purgeitem(baseUrl: string, token: string, id: string): Promise<boolean> {
+	return invoke("sync_purge", { baseUrl, token, id });
+}
+
+export async function emptytrash(baseUrl: string, token: string): Promise<string[]> {
+	return invoke("sync_empty_trash", { baseUrl, token });
+}
@@ -0,0 +1,19 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { RemoteItem } from "../hooks/sync";
+
+export type UrlMatchRule = "domain" | "host" | "starts_with" | "regex" | "never";
+
+export interface UrlMatch {
+	id: string;
+	url: string;
+	rule: UrlMatchRule;
+	score: number;
+}
+
+export async function matchurl(pageUrl: string, items: RemoteItem[]): Promise<UrlMatch[]> {
+	return invoke("url_match", { pageUrl, items });
+}
+
+export async function registrabledomain(url: string): Promise<string | null> {
+	return invoke("url_registrable_domain", { url });
+}
@@ -1,6 +1,7 @@
 import { useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { Titlebar } from "../app";
+import { Policies } from "../components/policies";
 
 interface Props {
 	user: { email: string };
@@ -8,17 +9,56 @@ interface Props {
 	onBack: () => void;
 }
 
+type Mode = "random" | "passphrase" | "pronounceable" | "pin";
+
 interface Options {
+	mode: Mode;
 	length: number;
 	uppercase: boolean;
 	lowercase: boolean;
 	numbers: boolean;
 	symbols: boolean;
+	min_numbers: number;
+	min_symbols: number;
+	exclude_ambiguous: boolean;
+	no_repeat: boolean;
+	include: string;
+	exclude: string;
+	words: number;
+	separator: string;
+	capitalize: boolean;
+	append_digit: boolean;
 }
 
+type ToggleKey =
+	| "uppercase"
+	| "lowercase"
+	| "numbers"
+	| "symbols"
+	| "exclude_ambiguous"
+	| "no_repeat"
+	| "capitalize"
+	| "append_digit";
+
+const modes: { id: Mode; label: string }[] = [
+	{ id: "random", label: "random" },
+	{ id: "passphrase", label: "passphrase" },
+	{ id: "pronounceable", label: "pronounceable" },
+	{ id: "pin", label: "pin" },
+];
+
+const lengthRange: Record<Mode, [number, number]> = {
+	random: [8, 128],
+	passphrase: [3, 20],
+	pronounceable: [8, 64],
+	pin: [4, 16],
+};
+
 interface Result {
 	password: string;
 	strength: string;
+	entropy: number;
+	policy: string | null;
 }
 
 const strengthColors: Record<string, string> = {
@@ -71,23 +111,46 @@ const navitems = [
 export function Generator({ user, onLogout, onBack }: Props) {
 	const [password, setPassword] = useState("");
 	const [strength, setStrength] = useState("");
+	const [entropy, setEntropy] = useState(0);
+	const [error, setError] = useState("");
+	const [site, setSite] = useState("");
+	const [policy, setPolicy] = useState<string | null>(null);
 	const [copied, setCopied] = useState(false);
 	const [activeNav, setActiveNav] = useState("generator");
 	const [options, setOptions] = useState<Options>({
+		mode: "random",
 		length: 20,
 		uppercase: true,
 		lowercase: true,
 		numbers: true,
 		symbols: true,
+		min_numbers: 1,
+		min_symbols: 1,
+		exclude_ambiguous: false,
+		no_repeat: false,
+		include: "",
+		exclude: "",
+		words: 5,
+		separator: "-",
+		capitalize: false,
+		append_digit: false,
 	});
 
 	async function generate() {
 		try {
-			const result = await invoke<Result>("generate_password", { options });
+			const result = await invoke<Result>("generate_password", {
+				options,
+				url: site.trim() || null,
+			});
 			setPassword(result.password);
 			setStrength(result.strength);
+			setEntropy(result.entropy);
+			setPolicy(result.policy);
+			setError("");
 			setCopied(false);
-		} catch {}
+		} catch (e) {
+			setError(String(e));
+		}
 	}
 
 	async function copy() {
@@ -96,10 +159,18 @@ export function Generator({ user, onLogout, onBack }: Props) {
 		setTimeout(() => setCopied(false), 2000);
 	}
 
-	function toggle(key: keyof Omit<Options, "length">) {
+	function toggle(key: ToggleKey) {
 		setOptions((prev) => ({ ...prev, [key]: !prev[key] }));
 	}
 
+	function selectMode(mode: Mode) {
+		setOptions((prev) => ({
+			...prev,
+			mode,
+			length: mode === "pin" ? 6 : mode === "pronounceable" ? 14 : 20,
+		}));
+	}
+
 	function handleNavClick(id: string) {
 		setActiveNav(id);
 		if (id === "vault") {
@@ -238,72 +309,207 @@ export function Generator({ user, onLogout, onBack }: Props) {
 									>
 										{strengthLabels[strength]}
 									</span>
+									<span className="entropy-value">{Math.round(entropy)} bits</span>
 								</div>
 							)}
+							{error && <p className="generator-error">{error}</p>}
+							{policy && <p className="generator-policy">using the {policy} policy</p>}
 						</div>
 
 						<div className="options-section">
+							<label className="field-option site-option">
+								<span>site</span>
+								<input
+									type="url"
+									value={site}
+									onChange={(e) => setSite(e.target.value)}
+									placeholder="https://example.com"
+								/>
+							</label>
+							<div className="mode-tabs">
+								{modes.map((mode) => (
+									<button
+										key={mode.id}
+										type="button"
+										className={`mode-tab ${options.mode === mode.id ? "active" : ""}`}
+										onClick={() => selectMode(mode.id)}
+									>
+										{mode.label}
+									</button>
+								))}
+							</div>
+
 							<div className="option-row">
 								<div className="option-label">
-									<span>length</span>
-									<span className="length-value">{options.length}</span>
+									<span>{options.mode === "passphrase" ? "words" : "length"}</span>
+									<span className="length-value">
+										{options.mode === "passphrase" ? options.words : options.length}
+									</span>
 								</div>
 								<input
 									type="range"
-									min={8}
-									max={128}
-									value={options.length}
+									min={lengthRange[options.mode][0]}
+									max={lengthRange[options.mode][1]}
+									value={options.mode === "passphrase" ? options.words : options.length}
 									onChange={(e) =>
-										setOptions((prev) => ({
-											...prev,
-											length: Number(e.target.value),
-										}))
+										setOptions((prev) =>
+											prev.mode === "passphrase"
+												? { ...prev, words: Number(e.target.value) }
+												: { ...prev, length: Number(e.target.value) },
+										)
 									}
 									className="range-slider"
 								/>
 							</div>
 
-							<div className="toggles-grid">
-								<label className="toggle-option">
-									<input
-										type="checkbox"
-										checked={options.uppercase}
-										onChange={() => toggle("uppercase")}
-									/>
-									<span className="toggle-switch" />
-									<span className="toggle-label">uppercase (A-Z)</span>
-								</label>
-
-								<label className="toggle-option">
-									<input
-										type="checkbox"
-										checked={options.lowercase}
-										onChange={() => toggle("lowercase")}
-									/>
-									<span className="toggle-switch" />
-									<span className="toggle-label">lowercase (a-z)</span>
-								</label>
-
-								<label className="toggle-option">
-									<input
-										type="checkbox"
-										checked={options.numbers}
-										onChange={() => toggle("numbers")}
-									/>
-									<span className="toggle-switch" />
-									<span className="toggle-label">numbers (0-9)</span>
-								</label>
-
-								<label className="toggle-option">
-									<input
-										type="checkbox"
-										checked={options.symbols}
-										onChange={() => toggle("symbols")}
-									/>
-									<span className="toggle-switch" />
-									<span className="toggle-label">symbols (!@#$)</span>
-								</label>
-							</div>
+							{(options.mode === "passphrase" || options.mode === "pronounceable") && (
+								<div className="toggles-grid">
+									<label className="toggle-option">
+										<input
+											type="checkbox"
+											checked={options.capitalize}
+											onChange={() => toggle("capitalize")}
+										/>
+										<span className="toggle-switch" />
+										<span className="toggle-label">capitalize</span>
+									</label>
+
+									<label className="toggle-option">
+										<input
+											type="checkbox"
+											checked={options.append_digit}
+											onChange={() => toggle("append_digit")}
+										/>
+										<span className="toggle-switch" />
+										<span className="toggle-label">include a digit</span>
+									</label>
+								</div>
+							)}
+
+							{options.mode === "passphrase" && (
+								<div className="fields-grid">
+									<label className="field-option">
+										<span>separator</span>
+										<input
+											type="text"
+											maxLength={3}
+											value={options.separator}
+											onChange={(e) =>
+												setOptions((prev) => ({ ...prev, separator: e.target.value }))
+											}
+										/>
+									</label>
+								</div>
+							)}
+
+							{options.mode === "random" && (
+								<>
+									<div className="toggles-grid">
+										<label className="toggle-option">
+											<input
+												type="checkbox"
+												checked={options.uppercase}
+												onChange={() => toggle("uppercase")}
+											/>
+											<span className="toggle-switch" />
+											<span className="toggle-label">uppercase (A-Z)</span>
+										</label>
+
+										<label className="toggle-option">
+											<input
+												type="checkbox"
+												checked={options.lowercase}
+												onChange={() => toggle("lowercase")}
+											/>
+											<span className="toggle-switch" />
+											<span className="toggle-label">lowercase (a-z)</span>
+										</label>
+
+										<label className="toggle-option">
+											<input
+												type="checkbox"
+												checked={options.numbers}
+												onChange={() => toggle("numbers")}
+											/>
+											<span className="toggle-switch" />
+											<span className="toggle-label">numbers (0-9)</span>
+										</label>
+
+										<label className="toggle-option">
+											<input
+												type="checkbox"
+												checked={options.symbols}
+												onChange={() => toggle("symbols")}
+											/>
+											<span className="toggle-switch" />
+											<span className="toggle-label">symbols (!@#$)</span>
+										</label>
+
+										<label className="toggle-option">
+											<input
+												type="checkbox"
+												checked={options.exclude_ambiguous}
+												onChange={() => toggle("exclude_ambiguous")}
+											/>
+											<span className="toggle-switch" />
+											<span className="toggle-label">avoid ambiguous (l1O0)</span>
+										</label>
+
+										<label className="toggle-option">
+											<input
+												type="checkbox"
+												checked={options.no_repeat}
+												onChange={() => toggle("no_repeat")}
+											/>
+											<span className="toggle-switch" />
+											<span className="toggle-label">no repeated characters</span>
+										</label>
+									</div>
+
+									<div className="fields-grid">
+										<label className="field-option">
+											<span>min numbers</span>
+											<input
+												type="number"
+												min={0}
+												max={options.length}
+												value={options.min_numbers}
+												onChange={(e) =>
+													setOptions((prev) => ({ ...prev, min_numbers: Number(e.target.value) }))
+												}
+											/>
+										</label>
+										<label className="field-option">
+											<span>min symbols</span>
+											<input
+												type="number"
+												min={0}
+												max={options.length}
+												value={options.min_symbols}
+												onChange={(e) =>
+													setOptions((prev) => ({ ...prev, min_symbols: Number(e.target.value) }))
+												}
+											/>
+										</label>
+										<label className="field-option">
+											<span>also include</span>
+											<input
+												type="text"
+												value={options.include}
+												onChange={(e) => setOptions((prev) => ({ ...prev, include: e.target.value }))}
+											/>
+										</label>
+										<label className="field-option">
+											<span>exclude</span>
+											<input
+												type="text"
+												value={options.exclude}
+												onChange={(e) => setOptions((prev) => ({ ...prev, exclude: e.target.value }))}
+											/>
+										</label>
+									</div>
+								</>
+							)}
 						</div>
 
 						<button onClick={generate} className="generate-btn" type="button">
@@ -319,6 +525,8 @@ export function Generator({ user, onLogout, onBack }: Props) {
 							generate password
 						</button>
 					</div>
+
+					<Policies />
 				</main>
 			</div>
 
@@ -448,8 +656,11 @@ export function Generator({ user, onLogout, onBack }: Props) {
 					display: flex;
 					align-items: center;
 					justify-content: center;
+					flex-wrap: wrap;
+					gap: 1.5rem;
 					padding: 2rem;
 					background: var(--bg);
+					overflow-y: auto;
 				}
 				.generator-card {
 					width: 100%;
@@ -547,6 +758,38 @@ export function Generator({ user, onLogout, onBack }: Props) {
 					text-transform: uppercase;
 					letter-spacing: 0.05em;
 				}
+				.entropy-value {
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+					font-variant-numeric: tabular-nums;
+				}
+				.mode-tabs {
+					display: grid;
+					grid-template-columns: repeat(4, 1fr);
+					gap: 0.25rem;
+					padding: 0.25rem;
+					margin-bottom: 1.25rem;
+					background: var(--bg);
+					border-radius: var(--radius-sm);
+				}
+				.mode-tab {
+					padding: 0.5rem;
+					background: none;
+					border: none;
+					border-radius: var(--radius-sm);
+					color: var(--fg-muted);
+					font-size: 0.75rem;
+					font-weight: 500;
+					cursor: pointer;
+					transition: var(--transition);
+				}
+				.mode-tab:hover {
+					color: var(--fg);
+				}
+				.mode-tab.active {
+					color: var(--fg);
+					background: var(--bg-elevated);
+				}
 				.options-section {
 					margin-bottom: 1.5rem;
 				}
@@ -633,6 +876,45 @@ export function Generator({ user, onLogout, onBack }: Props) {
 					font-size: 0.8125rem;
 					color: var(--fg-muted);
 				}
+				.fields-grid {
+					display: grid;
+					grid-template-columns: 1fr 1fr;
+					gap: 0.75rem;
+					margin-top: 1rem;
+				}
+				.field-option {
+					display: flex;
+					flex-direction: column;
+					gap: 0.375rem;
+					font-size: 0.8125rem;
+					color: var(--fg-muted);
+				}
+				.field-option input {
+					padding: 0.5rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-family: ui-monospace, "SF Mono", monospace;
+					font-size: 0.8125rem;
+				}
+				.field-option input:focus {
+					outline: none;
+					border-color: var(--accent);
+				}
+				.generator-policy {
+					margin-top: 0.5rem;
+					font-size: 0.75rem;
+					color: var(--fg-subtle);
+				}
+				.site-option {
+					margin-bottom: 1rem;
+				}
+				.generator-error {
+					margin-top: 0.5rem;
+					font-size: 0.75rem;
+					color: #ef4444;
+				}
 				.generate-btn {
 					width: 100%;
 					display: flex;
@@ -1,6 +1,7 @@
 import { useState } from "react";
 import { login, register, type Session } from "../auth";
 import { Titlebar } from "../app";
+import { Strengthmeter } from "../components/strengthmeter";
 
 interface Props {
 	onLogin: (session: Session) => void;
@@ -94,6 +95,9 @@ export function Login({ onLogin }: Props) {
 								minLength={isLogin ? undefined : 12}
 							/>
 							{!isLogin && <p className="hint">minimum 12 characters</p>}
+							{!isLogin && (
+								<Strengthmeter password={password} inputs={[email, name].filter(Boolean)} />
+							)}
 						</div>
 
 						{error && <p className="error">{error}</p>}
@@ -1,6 +1,21 @@
 import { useState, useEffect } from "react";
 import { useSync, type RemoteItem } from "../hooks/sync";
+import { Health } from "../components/health";
+import { Agent, AgentPrompt } from "../components/agent";
+import { SshKeys } from "../components/sshkeys";
+import { EnvRun, EnvRunPrompt } from "../components/envrun";
+import { Trash } from "../components/trash";
+import { Folders } from "../components/folders";
+import { Otp } from "../components/otp";
+import { Backup } from "../components/backup";
+import { Importer } from "../components/importer";
+import { Keepass } from "../components/keepass";
 import { Titlebar } from "../app";
+import { BASE_URL } from "../config";
+import { search as searchindex, searchready } from "../lib/search";
+import { matchurl } from "../lib/urlmatch";
+import { syncbatch, type BatchOp } from "../lib/batch";
+import { moveitems, type RemoteFolder } from "../lib/folders";
 
 interface Props {
 	user: { email: string };
@@ -71,6 +86,46 @@ const navitems = [
 			</svg>
 		),
 	},
+	{
+		id: "health",
+		label: "health",
+		icon: (
+			<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2" aria-hidden="true">
+				<path d="M22 12h-4l-3 9L9 3l-3 9H2" />
+			</svg>
+		),
+	},
+	{
+		id: "agent",
+		label: "ssh agent",
+		icon: (
+			<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2" aria-hidden="true">
+				<circle cx="7.5" cy="15.5" r="5.5" />
+				<path d="m21 2-9.6 9.6M15.5 7.5l3 3L22 7l-3-3" />
+			</svg>
+		),
+	},
+	{
+		id: "trash",
+		label: "trash",
+		icon: (
+			<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2" aria-hidden="true">
+				<polyline points="3 6 5 6 21 6" />
+				<path d="M19 6v14a2 2 0 0 1-2 2H7a2 2 0 0 1-2-2V6m3 0V4a2 2 0 0 1 2-2h4a2 2 0 0 1 2 2v2" />
+			</svg>
+		),
+	},
+	{
+		id: "data",
+		label: "import & export",
+		icon: (
+			<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2" aria-hidden="true">
+				<path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4" />
+				<polyline points="7 10 12 15 17 10" />
+				<line x1="12" y1="15" x2="12" y2="3" />
+			</svg>
+		),
+	},
 	{
 		id: "settings",
 		label: "settings",
@@ -84,22 +139,59 @@ const navitems = [
 ];
 
 export function Vault({ user, token, onLogout, onNavigate }: Props) {
-	const { items, loading, error, fetch, create, remove } = useSync(token);
+	const { items, loading, error, fetch, create, update, remove, conflicts, resolve } =
+		useSync(token);
 	const [search, setSearch] = useState("");
+	const [hits, setHits] = useState<string[] | null>(null);
 	const [adding, setAdding] = useState(false);
 	const [newTitle, setNewTitle] = useState("");
 	const [newType, setNewType] = useState("login");
 	const [activeNav, setActiveNav] = useState("vault");
+	const [folder, setFolder] = useState<string | null>(null);
+	const [folderIds, setFolderIds] = useState<string[] | null>(null);
+	const [folders, setFolders] = useState<RemoteFolder[]>([]);
+	const [foldersVersion, setFoldersVersion] = useState(0);
+	const [selected, setSelected] = useState<string[]>([]);
+	const [batchError, setBatchError] = useState("");
 
 	useEffect(() => {
 		fetch();
 	}, [fetch]);
 
-	const filtered = items.filter(
+	useEffect(() => {
+		const query = search.trim();
+		if (!query) {
+			setHits(null);
+			return;
+		}
+		let cancelled = false;
+		const lookup: Promise<string[] | null> = /^https?:\/\//.test(query)
+			? matchurl(query, items).then((matches) => matches.map((m) => m.id))
+			: searchready().then((ready) =>
+					ready ? searchindex(query).then((found) => found.map((h) => h.id)) : null,
+				);
+		lookup
+			.then((ids) => {
+				if (!cancelled) setHits(ids);
+			})
+			.catch(() => {
+				if (!cancelled) setHits(null);
+			});
+		return () => {
+			cancelled = true;
+		};
+	}, [search, items]);
+
+	const visible = items.filter(
 		(item) =>
 			!item.deleted &&
-			item.title.toLowerCase().includes(search.toLowerCase()),
+			(!folderIds || (item.folderId !== null && folderIds.includes(item.folderId))),
 	);
+	const filtered = hits
+		? hits
+				.map((id) => visible.find((item) => item.id === id))
+				.filter((item): item is RemoteItem => item !== undefined)
+		: visible.filter((item) => item.title.toLowerCase().includes(search.toLowerCase()));
 
 	async function handleAdd() {
 		if (!newTitle.trim()) return;
@@ -116,6 +208,40 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 		} catch {}
 	}
 
+	async function handleFavorite(item: RemoteItem) {
+		try {
+			await update(item.id, { favorite: !item.favorite });
+		} catch {}
+	}
+
+	function toggleSelected(id: string) {
+		setSelected((prev) => (prev.includes(id) ? prev.filter((s) => s !== id) : [...prev, id]));
+	}
+
+	async function runBatch(ops: BatchOp[]) {
+		try {
+			const result = await syncbatch(BASE_URL, token, ops);
+			setBatchError(
+				result.failed > 0 ? `${result.failed} of ${ops.length} changes failed` : "",
+			);
+			setSelected([]);
+			fetch();
+		} catch (e) {
+			setBatchError(String(e));
+		}
+	}
+
+	async function moveSelected(folderId: string | null) {
+		try {
+			await moveitems(BASE_URL, token, folderId, selected);
+			setSelected([]);
+			setFoldersVersion((v) => v + 1);
+			fetch();
+		} catch (e) {
+			setBatchError(String(e));
+		}
+	}
+
 	function handleNavClick(id: string) {
 		setActiveNav(id);
 		if (id === "generator") {
@@ -150,6 +276,18 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 								<span>{item.label}</span>
 							</button>
 						))}
+						{activeNav === "vault" && (
+							<Folders
+								token={token}
+								selected={folder}
+								refresh={foldersVersion}
+								onSelect={(ids, id) => {
+									setFolderIds(ids);
+									setFolder(id);
+								}}
+								onFolders={setFolders}
+							/>
+						)}
 					</nav>
 
 					<div className="sidebar-footer">
@@ -176,30 +314,26 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 				</aside>
 
 				<main className="content">
-					<header className="header">
-						<div className="search-wrapper">
-							<svg
-								viewBox="0 0 24 24"
-								fill="none"
-								stroke="currentColor"
-								strokeWidth="2"
-								aria-hidden="true"
-							>
-								<circle cx="11" cy="11" r="8" />
-								<path d="m21 21-4.3-4.3" />
-							</svg>
-							<input
-								type="text"
-								placeholder="search vault..."
-								value={search}
-								onChange={(e) => setSearch(e.target.value)}
-							/>
-							{search && (
-								<button
-									type="button"
-									className="clear-search"
-									onClick={() => setSearch("")}
-								>
+					{activeNav === "health" ? (
+						<Health token={token} />
+					) : activeNav === "agent" ? (
+						<>
+							<Agent token={token} />
+							<SshKeys token={token} onCreated={() => fetch()} />
+							<EnvRun token={token} />
+						</>
+					) : activeNav === "trash" ? (
+						<Trash token={token} onRestored={() => fetch()} />
+					) : activeNav === "data" ? (
+						<>
+							<Importer token={token} onImported={() => fetch()} />
+							<Backup />
+							<Keepass />
+						</>
+					) : (
+						<>
+							<header className="header">
+								<div className="search-wrapper">
 									<svg
 										viewBox="0 0 24 24"
 										fill="none"
@@ -207,142 +341,237 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 										strokeWidth="2"
 										aria-hidden="true"
 									>
-										<line x1="18" y1="6" x2="6" y2="18" />
-										<line x1="6" y1="6" x2="18" y2="18" />
+										<circle cx="11" cy="11" r="8" />
+										<path d="m21 21-4.3-4.3" />
 									</svg>
-								</button>
-							)}
-						</div>
-						<div className="header-actions">
-							<button
-								className="btn-secondary"
-								onClick={() => fetch()}
-								type="button"
-								disabled={loading}
-							>
-								<svg
-									viewBox="0 0 24 24"
-									fill="none"
-									stroke="currentColor"
-									strokeWidth="2"
-									aria-hidden="true"
-								>
-									<path d="M23 4v6h-6" />
-									<path d="M1 20v-6h6" />
-									<path d="M3.51 9a9 9 0 0 1 14.85-3.36L23 10M1 14l4.64 4.36A9 9 0 0 0 20.49 15" />
-								</svg>
-								{loading ? "syncing..." : "sync"}
-							</button>
-							<button
-								className="btn-primary"
-								onClick={() => setAdding(true)}
-								type="button"
-							>
-								<svg
-									viewBox="0 0 24 24"
-									fill="none"
-									stroke="currentColor"
-									strokeWidth="2"
-									aria-hidden="true"
-								>
-									<line x1="12" y1="5" x2="12" y2="19" />
-									<line x1="5" y1="12" x2="19" y2="12" />
-								</svg>
-								add item
-							</button>
-						</div>
-					</header>
-
-					{error && <div className="error-banner">{error}</div>}
-
-					{adding && (
-						<div className="add-modal-overlay" onClick={() => setAdding(false)}>
-							<div className="add-modal" onClick={(e) => e.stopPropagation()}>
-								<h3>new item</h3>
-								<div className="add-form">
-									<div className="field">
-										<label>type</label>
-										<select
-											value={newType}
-											onChange={(e) => setNewType(e.target.value)}
-										>
-											<option value="login">login</option>
-											<option value="note">secure note</option>
-											<option value="card">payment card</option>
-											<option value="identity">identity</option>
-											<option value="ssh">ssh key</option>
-											<option value="api">api credential</option>
-										</select>
-									</div>
-									<div className="field">
-										<label>title</label>
-										<input
-											type="text"
-											placeholder="e.g. GitHub, Gmail..."
-											value={newTitle}
-											onChange={(e) => setNewTitle(e.target.value)}
-											onKeyDown={(e) => e.key === "Enter" && handleAdd()}
-											autoFocus
-										/>
-									</div>
-									<div className="add-actions">
+									<input
+										type="text"
+										placeholder="search vault..."
+										value={search}
+										onChange={(e) => setSearch(e.target.value)}
+									/>
+									{search && (
 										<button
 											type="button"
-											className="btn-secondary"
-											onClick={() => setAdding(false)}
+											className="clear-search"
+											onClick={() => setSearch("")}
 										>
-											cancel
+											<svg
+												viewBox="0 0 24 24"
+												fill="none"
+												stroke="currentColor"
+												strokeWidth="2"
+												aria-hidden="true"
+											>
+												<line x1="18" y1="6" x2="6" y2="18" />
+												<line x1="6" y1="6" x2="18" y2="18" />
+											</svg>
 										</button>
-										<button
-											type="button"
-											className="btn-primary"
-											onClick={handleAdd}
-											disabled={!newTitle.trim()}
+									)}
+								</div>
+								<div className="header-actions">
+									<button
+										className="btn-secondary"
+										onClick={() => fetch()}
+										type="button"
+										disabled={loading}
+									>
+										<svg
+											viewBox="0 0 24 24"
+											fill="none"
+											stroke="currentColor"
+											strokeWidth="2"
+											aria-hidden="true"
 										>
-											create
-										</button>
+											<path d="M23 4v6h-6" />
+											<path d="M1 20v-6h6" />
+											<path d="M3.51 9a9 9 0 0 1 14.85-3.36L23 10M1 14l4.64 4.36A9 9 0 0 0 20.49 15" />
+										</svg>
+										{loading ? "syncing..." : "sync"}
+									</button>
+									<button
+										className="btn-primary"
+										onClick={() => setAdding(true)}
+										type="button"
+									>
+										<svg
+											viewBox="0 0 24 24"
+											fill="none"
+											stroke="currentColor"
+											strokeWidth="2"
+											aria-hidden="true"
+										>
+											<line x1="12" y1="5" x2="12" y2="19" />
+											<line x1="5" y1="12" x2="19" y2="12" />
+										</svg>
+										add item
+									</button>
+								</div>
+							</header>
+
+							{error && <div className="error-banner">{error}</div>}
+							{batchError && <div className="error-banner">{batchError}</div>}
+
+							{conflicts[0] && (
+								<div className="conflict-banner">
+									<span>
+										<strong>{conflicts[0].server.title}</strong> was changed on another
+										device before your edit was saved
+									</span>
+									<button
+										type="button"
+										className="btn-secondary"
+										onClick={() => resolve(conflicts[0], false)}
+									>
+										use theirs
+									</button>
+									<button
+										type="button"
+										className="btn-primary"
+										onClick={() => resolve(conflicts[0], true)}
+									>
+										keep mine
+									</button>
+								</div>
+							)}
+
+							{selected.length > 0 && (
+								<div className="batch-bar">
+									<span>{selected.length} selected</span>
+									<button
+										type="button"
+										className="btn-secondary"
+										onClick={() =>
+											runBatch(selected.map((id) => ({ op: "update", id, favorite: true })))
+										}
+									>
+										favorite
+									</button>
+									<select
+										value=""
+										onChange={(e) =>
+											moveSelected(e.target.value === "none" ? null : e.target.value)
+										}
+									>
+										<option value="" disabled>
+											move to...
+										</option>
+										<option value="none">no folder</option>
+										{folders.map((f) => (
+											<option key={f.id} value={f.id}>
+												{f.name}
+											</option>
+										))}
+									</select>
+									<button
+										type="button"
+										className="btn-secondary"
+										onClick={() => runBatch(selected.map((id) => ({ op: "delete", id })))}
+									>
+										delete
+									</button>
+									<button type="button" className="btn-secondary" onClick={() => setSelected([])}>
+										clear
+									</button>
+								</div>
+							)}
+
+							{adding && (
+								<div className="add-modal-overlay" onClick={() => setAdding(false)}>
+									<div className="add-modal" onClick={(e) => e.stopPropagation()}>
+										<h3>new item</h3>
+										<div className="add-form">
+											<div className="field">
+												<label>type</label>
+												<select
+													value={newType}
+													onChange={(e) => setNewType(e.target.value)}
+												>
+													<option value="login">login</option>
+													<option value="note">secure note</option>
+													<option value="card">payment card</option>
+													<option value="identity">identity</option>
+													<option value="ssh">ssh key</option>
+													<option value="api">api credential</option>
+												</select>
+											</div>
+											<div className="field">
+												<label>title</label>
+												<input
+													type="text"
+													placeholder="e.g. GitHub, Gmail..."
+													value={newTitle}
+													onChange={(e) => setNewTitle(e.target.value)}
+													onKeyDown={(e) => e.key === "Enter" && handleAdd()}
+													autoFocus
+												/>
+											</div>
+											<div className="add-actions">
+												<button
+													type="button"
+													className="btn-secondary"
+													onClick={() => setAdding(false)}
+												>
+													cancel
+												</button>
+												<button
+													type="button"
+													className="btn-primary"
+													onClick={handleAdd}
+													disabled={!newTitle.trim()}
+												>
+													create
+												</button>
+											</div>
+										</div>
 									</div>
 								</div>
-							</div>
-						</div>
-					)}
+							)}
 
-					<div className="items-container">
-						{filtered.length === 0 ? (
-							<div className="empty-state">
-								<svg
-									viewBox="0 0 24 24"
-									fill="none"
-									stroke="currentColor"
-									strokeWidth="1.5"
-									aria-hidden="true"
-								>
-									<path d="M12 22s8-4 8-10V5l-8-3-8 3v7c0 6 8 10 8 10z" />
-								</svg>
-								<h3>{loading ? "loading..." : "no items found"}</h3>
-								<p>
-									{loading
-										? "fetching your vault..."
-										: search
-											? "try a different search"
-											: "add your first item to get started"}
-								</p>
-							</div>
-						) : (
-							<div className="items-grid">
-								{filtered.map((item) => (
-									<ItemCard
-										key={item.id}
-										item={item}
-										onDelete={() => handleDelete(item.id)}
-									/>
-								))}
+							<div className="items-container">
+								{filtered.length === 0 ? (
+									<div className="empty-state">
+										<svg
+											viewBox="0 0 24 24"
+											fill="none"
+											stroke="currentColor"
+											strokeWidth="1.5"
+											aria-hidden="true"
+										>
+											<path d="M12 22s8-4 8-10V5l-8-3-8 3v7c0 6 8 10 8 10z" />
+										</svg>
+										<h3>{loading ? "loading..." : "no items found"}</h3>
+										<p>
+											{loading
+												? "fetching your vault..."
+												: search
+													? "try a different search"
+													: "add your first item to get started"}
+										</p>
+									</div>
+								) : (
+									<div className="items-grid">
+										{filtered.map((item) => (
+											<ItemCard
+												key={item.id}
+												item={item}
+												selected={selected.includes(item.id)}
+												onSelect={() => toggleSelected(item.id)}
+												onFavorite={() => handleFavorite(item)}
+												onDelete={() => handleDelete(item.id)}
+											/>
+										))}
+									</div>
+								)}
 							</div>
-						)}
-					</div>
+						</>
+					)}
 				</main>
 			</div>
 
+			<AgentPrompt />
+			<EnvRunPrompt />
+
 			<style>{`
 				.vault-layout {
 					flex: 1;
@@ -677,6 +906,34 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 					border-color: var(--accent);
 					background: var(--bg-subtle);
 				}
+				.item-card.selected {
+					border-color: var(--accent);
+					box-shadow: 0 0 0 1px var(--accent);
+				}
+				.conflict-banner,
+				.batch-bar {
+					display: flex;
+					align-items: center;
+					gap: 0.5rem;
+					margin: 1rem 1.5rem 0;
+					padding: 0.625rem 1rem;
+					background: var(--bg-elevated);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					font-size: 0.8125rem;
+				}
+				.conflict-banner span,
+				.batch-bar span {
+					flex: 1;
+				}
+				.batch-bar select {
+					padding: 0.375rem 0.75rem;
+					background: var(--bg);
+					border: 1px solid var(--border);
+					border-radius: var(--radius-sm);
+					color: var(--fg);
+					font-size: 0.8125rem;
+				}
 				.item-icon {
 					width: 40px;
 					height: 40px;
@@ -722,6 +979,7 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 					font-size: 0.875rem;
 					margin-right: 0.25rem;
 				}
+				.item-favorite,
 				.item-delete {
 					width: 28px;
 					height: 28px;
@@ -735,6 +993,10 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 					border-radius: 4px;
 					transition: var(--transition);
 				}
+				.item-favorite:hover {
+					color: var(--accent);
+					background: rgba(212, 176, 140, 0.1);
+				}
 				.item-delete:hover {
 					color: #ef4444;
 					background: rgba(239, 68, 68, 0.1);
@@ -773,19 +1035,39 @@ export function Vault({ user, token, onLogout, onNavigate }: Props) {
 
 function ItemCard({
 	item,
+	selected,
+	onSelect,
+	onFavorite,
 	onDelete,
-}: { item: RemoteItem; onDelete: () => void }) {
+}: {
+	item: RemoteItem;
+	selected: boolean;
+	onSelect: () => void;
+	onFavorite: () => void;
+	onDelete: () => void;
+}) {
 	return (
-		<div className="item-card">
+		<div className={`item-card ${selected ? "selected" : ""}`} onClick={onSelect}>
 			<span className="item-icon">
 				{typeicons[item.type] || typeicons.note}
 			</span>
 			<div className="item-info">
 				<p className="item-title">{item.title}</p>
 				<p className="item-type">{item.type}</p>
+				<Otp data={item.data} />
 			</div>
 			{item.favorite && <span className="item-star">★</span>}
 			<div className="item-actions">
+				<button
+					className="item-favorite"
+					onClick={(e) => {
+						e.stopPropagation();
+						onFavorite();
+					}}
+					type="button"
+				>
+					{item.favorite ? "★" : "☆"}
+				</button>
 				<button
 					className="item-delete"
 					onClick={(e) => {
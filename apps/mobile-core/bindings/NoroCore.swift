@@ -396,6 +396,38 @@ fileprivate class UniffiHandleMap<T> {
 // Public interface members begin here.
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt8: FfiConverterPrimitive {
+    typealias FfiType = UInt8
+    typealias SwiftType = UInt8
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt8 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: UInt8, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -428,6 +460,22 @@ fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -516,16 +564,58 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
 
 public protocol SyncClientProtocol : AnyObject {
     
+    func batch(ops: [BatchOp], listener: SyncListener?) throws  -> BatchResult
+    
+    func createFolder(name: String, parentId: String?, color: String?, icon: String?) throws  -> Folder
+    
     func createItem(itemType: String, title: String, data: Data, tags: [String], favorite: Bool) throws  -> VaultItem
     
+    func deleteFolder(id: String) throws  -> [String]
+    
     func deleteItem(id: String) throws 
     
+    func emptyTrash() throws  -> [String]
+    
+    func fetchFolders() throws  -> [Folder]
+    
     func fetchItems() throws  -> [VaultItem]
     
+    func fetchTrash() throws  -> [VaultItem]
+    
+    func isLocked()  -> Bool
+    
+    func lock() 
+    
     func login(email: String, password: String) throws  -> String
     
+    func moveFolder(id: String, parentId: String?) throws  -> Folder
+    
+    func moveItems(folderId: String?, itemIds: [String]) throws  -> UInt32
+    
+    func pendingChanges()  -> [PendingChange]
+    
+    func purgeItem(id: String) throws 
+    
+    func queueChange(change: PendingChange) 
+    
+    func requestSync() 
+    
+    func resolveSyncConflict(id: String, keepLocal: Bool) 
+    
+    func restoreItem(id: String) throws  -> VaultItem
+    
     func setToken(token: String) 
     
+    func startBackgroundSync(listener: SyncListener, intervalSecs: UInt64) 
+    
+    func stopBackgroundSync() 
+    
+    func unlock(password: String, secretKey: String, salt: Data, wrappedVaultKey: Data) throws 
+    
+    func unlockWithKey(vaultKey: Data) throws 
+    
+    func updateFolder(id: String, name: String?, color: String?, icon: String?, order: Int32?) throws  -> Folder
+    
     func updateItem(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?) throws  -> VaultItem
     
 }
@@ -588,6 +678,26 @@ public convenience init(baseUrl: String) {
     
 
     
+open func batch(ops: [BatchOp], listener: SyncListener?)throws  -> BatchResult {
+    return try  FfiConverterTypeBatchResult.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_batch(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeBatchOp.lower(ops),
+        FfiConverterOptionTypeSyncListener.lower(listener),$0
+    )
+})
+}
+    
+open func createFolder(name: String, parentId: String?, color: String?, icon: String?)throws  -> Folder {
+    return try  FfiConverterTypeFolder.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_create_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),
+        FfiConverterOptionString.lower(parentId),
+        FfiConverterOptionString.lower(color),
+        FfiConverterOptionString.lower(icon),$0
+    )
+})
+}
+    
 open func createItem(itemType: String, title: String, data: Data, tags: [String], favorite: Bool)throws  -> VaultItem {
     return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
     uniffi_noro_mobile_core_fn_method_syncclient_create_item(self.uniffiClonePointer(),
@@ -600,6 +710,14 @@ open func createItem(itemType: String, title: String, data: Data, tags: [String]
 })
 }
     
+open func deleteFolder(id: String)throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_delete_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
 open func deleteItem(id: String)throws  {try rustCallWithError(FfiConverterTypeSyncError.lift) {
     uniffi_noro_mobile_core_fn_method_syncclient_delete_item(self.uniffiClonePointer(),
         FfiConverterString.lower(id),$0
@@ -607,6 +725,20 @@ open func deleteItem(id: String)throws  {try rustCallWithError(FfiConverterTypeS
 }
 }
     
+open func emptyTrash()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_empty_trash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func fetchFolders()throws  -> [Folder] {
+    return try  FfiConverterSequenceTypeFolder.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_fetch_folders(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
 open func fetchItems()throws  -> [VaultItem] {
     return try  FfiConverterSequenceTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
     uniffi_noro_mobile_core_fn_method_syncclient_fetch_items(self.uniffiClonePointer(),$0
@@ -614,6 +746,26 @@ open func fetchItems()throws  -> [VaultItem] {
 })
 }
     
+open func fetchTrash()throws  -> [VaultItem] {
+    return try  FfiConverterSequenceTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_fetch_trash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func isLocked() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_is_locked(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func lock() {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_lock(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
 open func login(email: String, password: String)throws  -> String {
     return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
     uniffi_noro_mobile_core_fn_method_syncclient_login(self.uniffiClonePointer(),
@@ -623,6 +775,67 @@ open func login(email: String, password: String)throws  -> String {
 })
 }
     
+open func moveFolder(id: String, parentId: String?)throws  -> Folder {
+    return try  FfiConverterTypeFolder.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_move_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(parentId),$0
+    )
+})
+}
+    
+open func moveItems(folderId: String?, itemIds: [String])throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_move_items(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(folderId),
+        FfiConverterSequenceString.lower(itemIds),$0
+    )
+})
+}
+    
+open func pendingChanges() -> [PendingChange] {
+    return try!  FfiConverterSequenceTypePendingChange.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_pending_changes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func purgeItem(id: String)throws  {try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_purge_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+}
+}
+    
+open func queueChange(change: PendingChange) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_queue_change(self.uniffiClonePointer(),
+        FfiConverterTypePendingChange.lower(change),$0
+    )
+}
+}
+    
+open func requestSync() {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_request_sync(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func resolveSyncConflict(id: String, keepLocal: Bool) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_resolve_sync_conflict(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterBool.lower(keepLocal),$0
+    )
+}
+}
+    
+open func restoreItem(id: String)throws  -> VaultItem {
+    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_restore_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
 open func setToken(token: String) {try! rustCall() {
     uniffi_noro_mobile_core_fn_method_syncclient_set_token(self.uniffiClonePointer(),
         FfiConverterString.lower(token),$0
@@ -630,6 +843,49 @@ open func setToken(token: String) {try! rustCall() {
 }
 }
     
+open func startBackgroundSync(listener: SyncListener, intervalSecs: UInt64) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_start_background_sync(self.uniffiClonePointer(),
+        FfiConverterTypeSyncListener.lower(listener),
+        FfiConverterUInt64.lower(intervalSecs),$0
+    )
+}
+}
+    
+open func stopBackgroundSync() {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_syncclient_stop_background_sync(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func unlock(password: String, secretKey: String, salt: Data, wrappedVaultKey: Data)throws  {try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_unlock(self.uniffiClonePointer(),
+        FfiConverterString.lower(password),
+        FfiConverterString.lower(secretKey),
+        FfiConverterData.lower(salt),
+        FfiConverterData.lower(wrappedVaultKey),$0
+    )
+}
+}
+    
+open func unlockWithKey(vaultKey: Data)throws  {try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_unlock_with_key(self.uniffiClonePointer(),
+        FfiConverterData.lower(vaultKey),$0
+    )
+}
+}
+    
+open func updateFolder(id: String, name: String?, color: String?, icon: String?, order: Int32?)throws  -> Folder {
+    return try  FfiConverterTypeFolder.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
+    uniffi_noro_mobile_core_fn_method_syncclient_update_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(name),
+        FfiConverterOptionString.lower(color),
+        FfiConverterOptionString.lower(icon),
+        FfiConverterOptionInt32.lower(order),$0
+    )
+})
+}
+    
 open func updateItem(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?)throws  -> VaultItem {
     return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeSyncError.lift) {
     uniffi_noro_mobile_core_fn_method_syncclient_update_item(self.uniffiClonePointer(),
@@ -699,28 +955,24 @@ public func FfiConverterTypeSyncClient_lower(_ value: SyncClient) -> UnsafeMutab
 
 
 
-public protocol VaultProtocol : AnyObject {
-    
-    func createItem(itemType: String, title: String, data: Data, tags: [String], favorite: Bool) throws  -> VaultItem
-    
-    func deleteItem(id: String) throws 
+public protocol SyncListener : AnyObject {
     
-    func getItem(id: String) throws  -> VaultItem?
+    func onSyncStarted(reason: String) 
     
-    func listItems()  -> [VaultItem]
+    func onSyncProgress(phase: String, done: UInt32, total: UInt32) 
     
-    func load(encrypted: Data, key: Data) throws 
+    func onSyncCompleted(result: SyncResult) 
     
-    func save(key: Data) throws  -> Data
+    func onSyncConflict(conflict: SyncConflict) 
     
-    func searchItems(query: String)  -> [VaultItem]
+    func onSyncRejected(change: PendingChange) 
     
-    func updateItem(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?) throws  -> VaultItem
+    func onSyncConnection(connected: Bool) 
     
 }
 
-open class Vault:
-    VaultProtocol {
+open class SyncListenerImpl:
+    SyncListener {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -754,118 +1006,263 @@ open class Vault:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_noro_mobile_core_fn_clone_vault(self.pointer, $0) }
+        return try! rustCall { uniffi_noro_mobile_core_fn_clone_synclistener(self.pointer, $0) }
     }
-public convenience init() {
-    let pointer =
-        try! rustCall() {
-    uniffi_noro_mobile_core_fn_constructor_vault_new($0
-    )
-}
-    self.init(unsafeFromRawPointer: pointer)
-}
+    // No primary constructor declared for this class.
 
     deinit {
         guard let pointer = pointer else {
             return
         }
 
-        try! rustCall { uniffi_noro_mobile_core_fn_free_vault(pointer, $0) }
+        try! rustCall { uniffi_noro_mobile_core_fn_free_synclistener(pointer, $0) }
     }
 
     
 
     
-open func createItem(itemType: String, title: String, data: Data, tags: [String], favorite: Bool)throws  -> VaultItem {
-    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
-    uniffi_noro_mobile_core_fn_method_vault_create_item(self.uniffiClonePointer(),
-        FfiConverterString.lower(itemType),
-        FfiConverterString.lower(title),
-        FfiConverterData.lower(data),
-        FfiConverterSequenceString.lower(tags),
-        FfiConverterBool.lower(favorite),$0
+open func onSyncStarted(reason: String) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_synclistener_on_sync_started(self.uniffiClonePointer(),
+        FfiConverterString.lower(reason),$0
     )
-})
+}
 }
     
-open func deleteItem(id: String)throws  {try rustCallWithError(FfiConverterTypeVaultError.lift) {
-    uniffi_noro_mobile_core_fn_method_vault_delete_item(self.uniffiClonePointer(),
-        FfiConverterString.lower(id),$0
+open func onSyncProgress(phase: String, done: UInt32, total: UInt32) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_synclistener_on_sync_progress(self.uniffiClonePointer(),
+        FfiConverterString.lower(phase),
+        FfiConverterUInt32.lower(done),
+        FfiConverterUInt32.lower(total),$0
     )
 }
 }
     
-open func getItem(id: String)throws  -> VaultItem? {
-    return try  FfiConverterOptionTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
-    uniffi_noro_mobile_core_fn_method_vault_get_item(self.uniffiClonePointer(),
-        FfiConverterString.lower(id),$0
+open func onSyncCompleted(result: SyncResult) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_synclistener_on_sync_completed(self.uniffiClonePointer(),
+        FfiConverterTypeSyncResult.lower(result),$0
     )
-})
 }
-    
-open func listItems() -> [VaultItem] {
-    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
-    uniffi_noro_mobile_core_fn_method_vault_list_items(self.uniffiClonePointer(),$0
-    )
-})
 }
     
-open func load(encrypted: Data, key: Data)throws  {try rustCallWithError(FfiConverterTypeVaultError.lift) {
-    uniffi_noro_mobile_core_fn_method_vault_load(self.uniffiClonePointer(),
-        FfiConverterData.lower(encrypted),
-        FfiConverterData.lower(key),$0
+open func onSyncConflict(conflict: SyncConflict) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_synclistener_on_sync_conflict(self.uniffiClonePointer(),
+        FfiConverterTypeSyncConflict.lower(conflict),$0
     )
 }
 }
     
-open func save(key: Data)throws  -> Data {
-    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
-    uniffi_noro_mobile_core_fn_method_vault_save(self.uniffiClonePointer(),
-        FfiConverterData.lower(key),$0
+open func onSyncRejected(change: PendingChange) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_synclistener_on_sync_rejected(self.uniffiClonePointer(),
+        FfiConverterTypePendingChange.lower(change),$0
     )
-})
+}
 }
     
-open func searchItems(query: String) -> [VaultItem] {
-    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
-    uniffi_noro_mobile_core_fn_method_vault_search_items(self.uniffiClonePointer(),
-        FfiConverterString.lower(query),$0
+open func onSyncConnection(connected: Bool) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_synclistener_on_sync_connection(self.uniffiClonePointer(),
+        FfiConverterBool.lower(connected),$0
     )
-})
+}
 }
     
-open func updateItem(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?)throws  -> VaultItem {
-    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
-    uniffi_noro_mobile_core_fn_method_vault_update_item(self.uniffiClonePointer(),
-        FfiConverterString.lower(id),
-        FfiConverterOptionString.lower(title),
-        FfiConverterOptionData.lower(data),
-        FfiConverterOptionSequenceString.lower(tags),
-        FfiConverterOptionBool.lower(favorite),$0
+
+}
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceSyncListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceSyncListener = UniffiVTableCallbackInterfaceSyncListener(
+        onSyncStarted: { (
+            uniffiHandle: UInt64,
+            reason: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeSyncListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSyncStarted(
+                     reason: try FfiConverterString.lift(reason)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSyncProgress: { (
+            uniffiHandle: UInt64,
+            phase: RustBuffer,
+            done: UInt32,
+            total: UInt32,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeSyncListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSyncProgress(
+                     phase: try FfiConverterString.lift(phase),
+                     done: try FfiConverterUInt32.lift(done),
+                     total: try FfiConverterUInt32.lift(total)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSyncCompleted: { (
+            uniffiHandle: UInt64,
+            result: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeSyncListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSyncCompleted(
+                     result: try FfiConverterTypeSyncResult.lift(result)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSyncConflict: { (
+            uniffiHandle: UInt64,
+            conflict: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeSyncListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSyncConflict(
+                     conflict: try FfiConverterTypeSyncConflict.lift(conflict)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSyncRejected: { (
+            uniffiHandle: UInt64,
+            change: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeSyncListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSyncRejected(
+                     change: try FfiConverterTypePendingChange.lift(change)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSyncConnection: { (
+            uniffiHandle: UInt64,
+            connected: Int8,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeSyncListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSyncConnection(
+                     connected: try FfiConverterBool.lift(connected)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterTypeSyncListener.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface SyncListener: handle missing in uniffiFree")
+            }
+        }
     )
-})
 }
-    
 
+private func uniffiCallbackInitSyncListener() {
+    uniffi_noro_mobile_core_fn_init_callback_vtable_synclistener(&UniffiCallbackInterfaceSyncListener.vtable)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVault: FfiConverter {
+public struct FfiConverterTypeSyncListener: FfiConverter {
+    fileprivate static var handleMap = UniffiHandleMap<SyncListener>()
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = Vault
+    typealias SwiftType = SyncListener
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Vault {
-        return Vault(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncListener {
+        return SyncListenerImpl(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: Vault) -> UnsafeMutableRawPointer {
-        return value.uniffiClonePointer()
+    public static func lower(_ value: SyncListener) -> UnsafeMutableRawPointer {
+        guard let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: handleMap.insert(obj: value))) else {
+            fatalError("Cast to UnsafeMutableRawPointer failed")
+        }
+        return ptr
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Vault {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncListener {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -876,7 +1273,7 @@ public struct FfiConverterTypeVault: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: Vault, into buf: inout [UInt8]) {
+    public static func write(_ value: SyncListener, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -889,560 +1286,4722 @@ public struct FfiConverterTypeVault: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVault_lift(_ pointer: UnsafeMutableRawPointer) throws -> Vault {
-    return try FfiConverterTypeVault.lift(pointer)
+public func FfiConverterTypeSyncListener_lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncListener {
+    return try FfiConverterTypeSyncListener.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVault_lower(_ value: Vault) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeVault.lower(value)
+public func FfiConverterTypeSyncListener_lower(_ value: SyncListener) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeSyncListener.lower(value)
 }
 
 
-public struct VaultData {
+
+
+public protocol VaultProtocol : AnyObject {
+    
+    func applyRemote(changed: [VaultItem], removed: [String]) 
+    
+    func audit()  -> AuditReport
+    
+    func createFolder(name: String, parentId: String?, color: String, icon: String) throws  -> Folder
+    
+    func createItem(itemType: String, title: String, data: Data, tags: [String], favorite: Bool) throws  -> VaultItem
+    
+    func deleteFolder(id: String) throws  -> [String]
+    
+    func deleteItem(id: String) throws 
+    
+    func emptyTrash()  -> [String]
+    
+    func exportBackup(password: String, attachments: [BackupAttachment]) throws  -> Data
+    
+    func exportPlaintext(format: PlainExportFormat, password: String, secretKey: String, salt: Data, wrappedVaultKey: Data) throws  -> String
+    
+    func folderChildren(parentId: String?)  -> [Folder]
+    
+    func folderDescendants(id: String)  -> [Folder]
+    
+    func folderItems(folderId: String?)  -> [VaultItem]
+    
+    func folderPath(id: String)  -> [Folder]
+    
+    func getItem(id: String) throws  -> VaultItem?
+    
+    func importBackup(archive: Data, password: String) throws  -> BackupImportResult
+    
+    func importOtp(text: String, skipDuplicates: Bool) throws  -> OtpImportResult
+    
+    func listFolders()  -> [Folder]
+    
+    func listItems()  -> [VaultItem]
+    
+    func listTrash()  -> [VaultItem]
+    
+    func load(encrypted: Data, key: Data) throws 
+    
+    func lock() 
+    
+    func matchUrl(pageUrl: String)  -> [VaultItem]
+    
+    func moveFolder(id: String, parentId: String?) throws  -> Folder
+    
+    func moveItem(id: String, folderId: String?) throws  -> VaultItem
+    
+    func otpCode(id: String, timestamp: UInt64?) throws  -> OtpCode?
+    
+    func previewOtpImport(text: String)  -> OtpImportPreview
+    
+    func purgeExpired()  -> [String]
+    
+    func purgeItem(id: String) throws 
+    
+    func restoreItem(id: String) throws  -> VaultItem
+    
+    func save(key: Data) throws  -> Data
+    
+    func search(query: String, limit: UInt32)  -> [VaultItem]
+    
+    func searchItems(query: String)  -> [VaultItem]
+    
+    func setFolders(folders: [Folder]) 
+    
+    func updateFolder(id: String, name: String?, color: String?, icon: String?, order: Int32?) throws  -> Folder
+    
+    func updateItem(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?) throws  -> VaultItem
+    
+}
+
+open class Vault:
+    VaultProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_noro_mobile_core_fn_clone_vault(self.pointer, $0) }
+    }
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_noro_mobile_core_fn_constructor_vault_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_noro_mobile_core_fn_free_vault(pointer, $0) }
+    }
+
+    
+
+    
+open func applyRemote(changed: [VaultItem], removed: [String]) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_apply_remote(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeVaultItem.lower(changed),
+        FfiConverterSequenceString.lower(removed),$0
+    )
+}
+}
+    
+open func audit() -> AuditReport {
+    return try!  FfiConverterTypeAuditReport.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_audit(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func createFolder(name: String, parentId: String?, color: String, icon: String)throws  -> Folder {
+    return try  FfiConverterTypeFolder.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_create_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),
+        FfiConverterOptionString.lower(parentId),
+        FfiConverterString.lower(color),
+        FfiConverterString.lower(icon),$0
+    )
+})
+}
+    
+open func createItem(itemType: String, title: String, data: Data, tags: [String], favorite: Bool)throws  -> VaultItem {
+    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_create_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(itemType),
+        FfiConverterString.lower(title),
+        FfiConverterData.lower(data),
+        FfiConverterSequenceString.lower(tags),
+        FfiConverterBool.lower(favorite),$0
+    )
+})
+}
+    
+open func deleteFolder(id: String)throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_delete_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
+open func deleteItem(id: String)throws  {try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_delete_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+}
+}
+    
+open func emptyTrash() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_empty_trash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func exportBackup(password: String, attachments: [BackupAttachment])throws  -> Data {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeBackupError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_export_backup(self.uniffiClonePointer(),
+        FfiConverterString.lower(password),
+        FfiConverterSequenceTypeBackupAttachment.lower(attachments),$0
+    )
+})
+}
+    
+open func exportPlaintext(format: PlainExportFormat, password: String, secretKey: String, salt: Data, wrappedVaultKey: Data)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackupError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_export_plaintext(self.uniffiClonePointer(),
+        FfiConverterTypePlainExportFormat.lower(format),
+        FfiConverterString.lower(password),
+        FfiConverterString.lower(secretKey),
+        FfiConverterData.lower(salt),
+        FfiConverterData.lower(wrappedVaultKey),$0
+    )
+})
+}
+    
+open func folderChildren(parentId: String?) -> [Folder] {
+    return try!  FfiConverterSequenceTypeFolder.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_folder_children(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(parentId),$0
+    )
+})
+}
+    
+open func folderDescendants(id: String) -> [Folder] {
+    return try!  FfiConverterSequenceTypeFolder.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_folder_descendants(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
+open func folderItems(folderId: String?) -> [VaultItem] {
+    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_folder_items(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(folderId),$0
+    )
+})
+}
+    
+open func folderPath(id: String) -> [Folder] {
+    return try!  FfiConverterSequenceTypeFolder.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_folder_path(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
+open func getItem(id: String)throws  -> VaultItem? {
+    return try  FfiConverterOptionTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_get_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
+open func importBackup(archive: Data, password: String)throws  -> BackupImportResult {
+    return try  FfiConverterTypeBackupImportResult.lift(try rustCallWithError(FfiConverterTypeBackupError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_import_backup(self.uniffiClonePointer(),
+        FfiConverterData.lower(archive),
+        FfiConverterString.lower(password),$0
+    )
+})
+}
+    
+open func importOtp(text: String, skipDuplicates: Bool)throws  -> OtpImportResult {
+    return try  FfiConverterTypeOtpImportResult.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_import_otp(self.uniffiClonePointer(),
+        FfiConverterString.lower(text),
+        FfiConverterBool.lower(skipDuplicates),$0
+    )
+})
+}
+    
+open func listFolders() -> [Folder] {
+    return try!  FfiConverterSequenceTypeFolder.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_list_folders(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func listItems() -> [VaultItem] {
+    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_list_items(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func listTrash() -> [VaultItem] {
+    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_list_trash(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func load(encrypted: Data, key: Data)throws  {try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_load(self.uniffiClonePointer(),
+        FfiConverterData.lower(encrypted),
+        FfiConverterData.lower(key),$0
+    )
+}
+}
+    
+open func lock() {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_lock(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func matchUrl(pageUrl: String) -> [VaultItem] {
+    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_match_url(self.uniffiClonePointer(),
+        FfiConverterString.lower(pageUrl),$0
+    )
+})
+}
+    
+open func moveFolder(id: String, parentId: String?)throws  -> Folder {
+    return try  FfiConverterTypeFolder.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_move_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(parentId),$0
+    )
+})
+}
+    
+open func moveItem(id: String, folderId: String?)throws  -> VaultItem {
+    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_move_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(folderId),$0
+    )
+})
+}
+    
+open func otpCode(id: String, timestamp: UInt64?)throws  -> OtpCode? {
+    return try  FfiConverterOptionTypeOtpCode.lift(try rustCallWithError(FfiConverterTypeOtpError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_otp_code(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionUInt64.lower(timestamp),$0
+    )
+})
+}
+    
+open func previewOtpImport(text: String) -> OtpImportPreview {
+    return try!  FfiConverterTypeOtpImportPreview.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_preview_otp_import(self.uniffiClonePointer(),
+        FfiConverterString.lower(text),$0
+    )
+})
+}
+    
+open func purgeExpired() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_purge_expired(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func purgeItem(id: String)throws  {try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_purge_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+}
+}
+    
+open func restoreItem(id: String)throws  -> VaultItem {
+    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_restore_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),$0
+    )
+})
+}
+    
+open func save(key: Data)throws  -> Data {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_save(self.uniffiClonePointer(),
+        FfiConverterData.lower(key),$0
+    )
+})
+}
+    
+open func search(query: String, limit: UInt32) -> [VaultItem] {
+    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_search(self.uniffiClonePointer(),
+        FfiConverterString.lower(query),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+open func searchItems(query: String) -> [VaultItem] {
+    return try!  FfiConverterSequenceTypeVaultItem.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_search_items(self.uniffiClonePointer(),
+        FfiConverterString.lower(query),$0
+    )
+})
+}
+    
+open func setFolders(folders: [Folder]) {try! rustCall() {
+    uniffi_noro_mobile_core_fn_method_vault_set_folders(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeFolder.lower(folders),$0
+    )
+}
+}
+    
+open func updateFolder(id: String, name: String?, color: String?, icon: String?, order: Int32?)throws  -> Folder {
+    return try  FfiConverterTypeFolder.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_update_folder(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(name),
+        FfiConverterOptionString.lower(color),
+        FfiConverterOptionString.lower(icon),
+        FfiConverterOptionInt32.lower(order),$0
+    )
+})
+}
+    
+open func updateItem(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?)throws  -> VaultItem {
+    return try  FfiConverterTypeVaultItem.lift(try rustCallWithError(FfiConverterTypeVaultError.lift) {
+    uniffi_noro_mobile_core_fn_method_vault_update_item(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(title),
+        FfiConverterOptionData.lower(data),
+        FfiConverterOptionSequenceString.lower(tags),
+        FfiConverterOptionBool.lower(favorite),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVault: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = Vault
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Vault {
+        return Vault(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: Vault) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Vault {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: Vault, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVault_lift(_ pointer: UnsafeMutableRawPointer) throws -> Vault {
+    return try FfiConverterTypeVault.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVault_lower(_ value: Vault) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeVault.lower(value)
+}
+
+
+public struct AuditReport {
+    public var total: UInt32
+    public var weak: UInt32
+    public var reused: UInt32
+    public var old: UInt32
+    public var missingTotp: UInt32
+    public var groups: [ReusedGroup]
+    public var items: [ItemAudit]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(total: UInt32, weak: UInt32, reused: UInt32, old: UInt32, missingTotp: UInt32, groups: [ReusedGroup], items: [ItemAudit]) {
+        self.total = total
+        self.weak = weak
+        self.reused = reused
+        self.old = old
+        self.missingTotp = missingTotp
+        self.groups = groups
+        self.items = items
+    }
+}
+
+
+
+extension AuditReport: Equatable, Hashable {
+    public static func ==(lhs: AuditReport, rhs: AuditReport) -> Bool {
+        if lhs.total != rhs.total {
+            return false
+        }
+        if lhs.weak != rhs.weak {
+            return false
+        }
+        if lhs.reused != rhs.reused {
+            return false
+        }
+        if lhs.old != rhs.old {
+            return false
+        }
+        if lhs.missingTotp != rhs.missingTotp {
+            return false
+        }
+        if lhs.groups != rhs.groups {
+            return false
+        }
+        if lhs.items != rhs.items {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(total)
+        hasher.combine(weak)
+        hasher.combine(reused)
+        hasher.combine(old)
+        hasher.combine(missingTotp)
+        hasher.combine(groups)
+        hasher.combine(items)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAuditReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AuditReport {
+        return
+            try AuditReport(
+                total: FfiConverterUInt32.read(from: &buf), 
+                weak: FfiConverterUInt32.read(from: &buf), 
+                reused: FfiConverterUInt32.read(from: &buf), 
+                old: FfiConverterUInt32.read(from: &buf), 
+                missingTotp: FfiConverterUInt32.read(from: &buf), 
+                groups: FfiConverterSequenceTypeReusedGroup.read(from: &buf), 
+                items: FfiConverterSequenceTypeItemAudit.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AuditReport, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.total, into: &buf)
+        FfiConverterUInt32.write(value.weak, into: &buf)
+        FfiConverterUInt32.write(value.reused, into: &buf)
+        FfiConverterUInt32.write(value.old, into: &buf)
+        FfiConverterUInt32.write(value.missingTotp, into: &buf)
+        FfiConverterSequenceTypeReusedGroup.write(value.groups, into: &buf)
+        FfiConverterSequenceTypeItemAudit.write(value.items, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAuditReport_lift(_ buf: RustBuffer) throws -> AuditReport {
+    return try FfiConverterTypeAuditReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAuditReport_lower(_ value: AuditReport) -> RustBuffer {
+    return FfiConverterTypeAuditReport.lower(value)
+}
+
+
+public struct BackupAttachment {
+    public var id: String
+    public var itemId: String
+    public var name: String
+    public var mime: String?
+    public var data: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, itemId: String, name: String, mime: String?, data: Data) {
+        self.id = id
+        self.itemId = itemId
+        self.name = name
+        self.mime = mime
+        self.data = data
+    }
+}
+
+
+
+extension BackupAttachment: Equatable, Hashable {
+    public static func ==(lhs: BackupAttachment, rhs: BackupAttachment) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.mime != rhs.mime {
+            return false
+        }
+        if lhs.data != rhs.data {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(itemId)
+        hasher.combine(name)
+        hasher.combine(mime)
+        hasher.combine(data)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBackupAttachment: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BackupAttachment {
+        return
+            try BackupAttachment(
+                id: FfiConverterString.read(from: &buf), 
+                itemId: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                mime: FfiConverterOptionString.read(from: &buf), 
+                data: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BackupAttachment, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.mime, into: &buf)
+        FfiConverterData.write(value.data, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackupAttachment_lift(_ buf: RustBuffer) throws -> BackupAttachment {
+    return try FfiConverterTypeBackupAttachment.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackupAttachment_lower(_ value: BackupAttachment) -> RustBuffer {
+    return FfiConverterTypeBackupAttachment.lower(value)
+}
+
+
+public struct BackupImportResult {
+    public var created: UInt32
+    public var updated: UInt32
+    public var unchanged: UInt32
+    public var folders: UInt32
+    public var attachments: [BackupAttachment]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(created: UInt32, updated: UInt32, unchanged: UInt32, folders: UInt32, attachments: [BackupAttachment]) {
+        self.created = created
+        self.updated = updated
+        self.unchanged = unchanged
+        self.folders = folders
+        self.attachments = attachments
+    }
+}
+
+
+
+extension BackupImportResult: Equatable, Hashable {
+    public static func ==(lhs: BackupImportResult, rhs: BackupImportResult) -> Bool {
+        if lhs.created != rhs.created {
+            return false
+        }
+        if lhs.updated != rhs.updated {
+            return false
+        }
+        if lhs.unchanged != rhs.unchanged {
+            return false
+        }
+        if lhs.folders != rhs.folders {
+            return false
+        }
+        if lhs.attachments != rhs.attachments {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(created)
+        hasher.combine(updated)
+        hasher.combine(unchanged)
+        hasher.combine(folders)
+        hasher.combine(attachments)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBackupImportResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BackupImportResult {
+        return
+            try BackupImportResult(
+                created: FfiConverterUInt32.read(from: &buf), 
+                updated: FfiConverterUInt32.read(from: &buf), 
+                unchanged: FfiConverterUInt32.read(from: &buf), 
+                folders: FfiConverterUInt32.read(from: &buf), 
+                attachments: FfiConverterSequenceTypeBackupAttachment.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BackupImportResult, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.created, into: &buf)
+        FfiConverterUInt32.write(value.updated, into: &buf)
+        FfiConverterUInt32.write(value.unchanged, into: &buf)
+        FfiConverterUInt32.write(value.folders, into: &buf)
+        FfiConverterSequenceTypeBackupAttachment.write(value.attachments, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackupImportResult_lift(_ buf: RustBuffer) throws -> BackupImportResult {
+    return try FfiConverterTypeBackupImportResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackupImportResult_lower(_ value: BackupImportResult) -> RustBuffer {
+    return FfiConverterTypeBackupImportResult.lower(value)
+}
+
+
+public struct BackupManifest {
+    public var version: UInt32
+    public var created: UInt64
+    public var source: String
+    public var items: UInt32
+    public var folders: UInt32
+    public var attachments: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(version: UInt32, created: UInt64, source: String, items: UInt32, folders: UInt32, attachments: UInt32) {
+        self.version = version
+        self.created = created
+        self.source = source
+        self.items = items
+        self.folders = folders
+        self.attachments = attachments
+    }
+}
+
+
+
+extension BackupManifest: Equatable, Hashable {
+    public static func ==(lhs: BackupManifest, rhs: BackupManifest) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.created != rhs.created {
+            return false
+        }
+        if lhs.source != rhs.source {
+            return false
+        }
+        if lhs.items != rhs.items {
+            return false
+        }
+        if lhs.folders != rhs.folders {
+            return false
+        }
+        if lhs.attachments != rhs.attachments {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(created)
+        hasher.combine(source)
+        hasher.combine(items)
+        hasher.combine(folders)
+        hasher.combine(attachments)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBackupManifest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BackupManifest {
+        return
+            try BackupManifest(
+                version: FfiConverterUInt32.read(from: &buf), 
+                created: FfiConverterUInt64.read(from: &buf), 
+                source: FfiConverterString.read(from: &buf), 
+                items: FfiConverterUInt32.read(from: &buf), 
+                folders: FfiConverterUInt32.read(from: &buf), 
+                attachments: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BackupManifest, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.version, into: &buf)
+        FfiConverterUInt64.write(value.created, into: &buf)
+        FfiConverterString.write(value.source, into: &buf)
+        FfiConverterUInt32.write(value.items, into: &buf)
+        FfiConverterUInt32.write(value.folders, into: &buf)
+        FfiConverterUInt32.write(value.attachments, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackupManifest_lift(_ buf: RustBuffer) throws -> BackupManifest {
+    return try FfiConverterTypeBackupManifest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackupManifest_lower(_ value: BackupManifest) -> RustBuffer {
+    return FfiConverterTypeBackupManifest.lower(value)
+}
+
+
+public struct BatchItemResult {
+    public var id: String?
+    public var op: String
+    public var ok: Bool
+    public var error: String?
+    public var item: VaultItem?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String?, op: String, ok: Bool, error: String?, item: VaultItem?) {
+        self.id = id
+        self.op = op
+        self.ok = ok
+        self.error = error
+        self.item = item
+    }
+}
+
+
+
+extension BatchItemResult: Equatable, Hashable {
+    public static func ==(lhs: BatchItemResult, rhs: BatchItemResult) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.op != rhs.op {
+            return false
+        }
+        if lhs.ok != rhs.ok {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        if lhs.item != rhs.item {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(op)
+        hasher.combine(ok)
+        hasher.combine(error)
+        hasher.combine(item)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBatchItemResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BatchItemResult {
+        return
+            try BatchItemResult(
+                id: FfiConverterOptionString.read(from: &buf), 
+                op: FfiConverterString.read(from: &buf), 
+                ok: FfiConverterBool.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf), 
+                item: FfiConverterOptionTypeVaultItem.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BatchItemResult, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.id, into: &buf)
+        FfiConverterString.write(value.op, into: &buf)
+        FfiConverterBool.write(value.ok, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+        FfiConverterOptionTypeVaultItem.write(value.item, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBatchItemResult_lift(_ buf: RustBuffer) throws -> BatchItemResult {
+    return try FfiConverterTypeBatchItemResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBatchItemResult_lower(_ value: BatchItemResult) -> RustBuffer {
+    return FfiConverterTypeBatchItemResult.lower(value)
+}
+
+
+public struct BatchResult {
+    public var results: [BatchItemResult]
+    public var succeeded: UInt32
+    public var failed: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(results: [BatchItemResult], succeeded: UInt32, failed: UInt32) {
+        self.results = results
+        self.succeeded = succeeded
+        self.failed = failed
+    }
+}
+
+
+
+extension BatchResult: Equatable, Hashable {
+    public static func ==(lhs: BatchResult, rhs: BatchResult) -> Bool {
+        if lhs.results != rhs.results {
+            return false
+        }
+        if lhs.succeeded != rhs.succeeded {
+            return false
+        }
+        if lhs.failed != rhs.failed {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(results)
+        hasher.combine(succeeded)
+        hasher.combine(failed)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBatchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BatchResult {
+        return
+            try BatchResult(
+                results: FfiConverterSequenceTypeBatchItemResult.read(from: &buf), 
+                succeeded: FfiConverterUInt32.read(from: &buf), 
+                failed: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BatchResult, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeBatchItemResult.write(value.results, into: &buf)
+        FfiConverterUInt32.write(value.succeeded, into: &buf)
+        FfiConverterUInt32.write(value.failed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBatchResult_lift(_ buf: RustBuffer) throws -> BatchResult {
+    return try FfiConverterTypeBatchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBatchResult_lower(_ value: BatchResult) -> RustBuffer {
+    return FfiConverterTypeBatchResult.lower(value)
+}
+
+
+public struct BreachResult {
+    public var breached: Bool
+    public var count: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(breached: Bool, count: UInt64) {
+        self.breached = breached
+        self.count = count
+    }
+}
+
+
+
+extension BreachResult: Equatable, Hashable {
+    public static func ==(lhs: BreachResult, rhs: BreachResult) -> Bool {
+        if lhs.breached != rhs.breached {
+            return false
+        }
+        if lhs.count != rhs.count {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(breached)
+        hasher.combine(count)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBreachResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BreachResult {
+        return
+            try BreachResult(
+                breached: FfiConverterBool.read(from: &buf), 
+                count: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BreachResult, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.breached, into: &buf)
+        FfiConverterUInt64.write(value.count, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBreachResult_lift(_ buf: RustBuffer) throws -> BreachResult {
+    return try FfiConverterTypeBreachResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBreachResult_lower(_ value: BreachResult) -> RustBuffer {
+    return FfiConverterTypeBreachResult.lower(value)
+}
+
+
+public struct CrackTime {
+    public var scenario: String
+    public var seconds: Double
+    public var display: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(scenario: String, seconds: Double, display: String) {
+        self.scenario = scenario
+        self.seconds = seconds
+        self.display = display
+    }
+}
+
+
+
+extension CrackTime: Equatable, Hashable {
+    public static func ==(lhs: CrackTime, rhs: CrackTime) -> Bool {
+        if lhs.scenario != rhs.scenario {
+            return false
+        }
+        if lhs.seconds != rhs.seconds {
+            return false
+        }
+        if lhs.display != rhs.display {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(scenario)
+        hasher.combine(seconds)
+        hasher.combine(display)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCrackTime: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CrackTime {
+        return
+            try CrackTime(
+                scenario: FfiConverterString.read(from: &buf), 
+                seconds: FfiConverterDouble.read(from: &buf), 
+                display: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CrackTime, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.scenario, into: &buf)
+        FfiConverterDouble.write(value.seconds, into: &buf)
+        FfiConverterString.write(value.display, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCrackTime_lift(_ buf: RustBuffer) throws -> CrackTime {
+    return try FfiConverterTypeCrackTime.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCrackTime_lower(_ value: CrackTime) -> RustBuffer {
+    return FfiConverterTypeCrackTime.lower(value)
+}
+
+
+public struct Folder {
+    public var id: String
+    public var name: String
+    public var parentId: String?
+    public var color: String
+    public var icon: String
+    public var order: Int32
+    public var created: UInt64
+    public var updated: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, name: String, parentId: String?, color: String, icon: String, order: Int32, created: UInt64, updated: UInt64) {
+        self.id = id
+        self.name = name
+        self.parentId = parentId
+        self.color = color
+        self.icon = icon
+        self.order = order
+        self.created = created
+        self.updated = updated
+    }
+}
+
+
+
+extension Folder: Equatable, Hashable {
+    public static func ==(lhs: Folder, rhs: Folder) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.parentId != rhs.parentId {
+            return false
+        }
+        if lhs.color != rhs.color {
+            return false
+        }
+        if lhs.icon != rhs.icon {
+            return false
+        }
+        if lhs.order != rhs.order {
+            return false
+        }
+        if lhs.created != rhs.created {
+            return false
+        }
+        if lhs.updated != rhs.updated {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(name)
+        hasher.combine(parentId)
+        hasher.combine(color)
+        hasher.combine(icon)
+        hasher.combine(order)
+        hasher.combine(created)
+        hasher.combine(updated)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFolder: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Folder {
+        return
+            try Folder(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                parentId: FfiConverterOptionString.read(from: &buf), 
+                color: FfiConverterString.read(from: &buf), 
+                icon: FfiConverterString.read(from: &buf), 
+                order: FfiConverterInt32.read(from: &buf), 
+                created: FfiConverterUInt64.read(from: &buf), 
+                updated: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Folder, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.parentId, into: &buf)
+        FfiConverterString.write(value.color, into: &buf)
+        FfiConverterString.write(value.icon, into: &buf)
+        FfiConverterInt32.write(value.order, into: &buf)
+        FfiConverterUInt64.write(value.created, into: &buf)
+        FfiConverterUInt64.write(value.updated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFolder_lift(_ buf: RustBuffer) throws -> Folder {
+    return try FfiConverterTypeFolder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFolder_lower(_ value: Folder) -> RustBuffer {
+    return FfiConverterTypeFolder.lower(value)
+}
+
+
+public struct GeneratorOptions {
+    public var mode: GeneratorMode
+    public var length: UInt32
+    public var uppercase: Bool
+    public var lowercase: Bool
+    public var numbers: Bool
+    public var symbols: Bool
+    public var minUppercase: UInt32
+    public var minLowercase: UInt32
+    public var minNumbers: UInt32
+    public var minSymbols: UInt32
+    public var excludeAmbiguous: Bool
+    public var include: String
+    public var exclude: String
+    public var noRepeat: Bool
+    public var noConsecutive: Bool
+    public var words: UInt32
+    public var separator: String
+    public var capitalize: Bool
+    public var appendDigit: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(mode: GeneratorMode, length: UInt32, uppercase: Bool, lowercase: Bool, numbers: Bool, symbols: Bool, minUppercase: UInt32, minLowercase: UInt32, minNumbers: UInt32, minSymbols: UInt32, excludeAmbiguous: Bool, include: String, exclude: String, noRepeat: Bool, noConsecutive: Bool, words: UInt32, separator: String, capitalize: Bool, appendDigit: Bool) {
+        self.mode = mode
+        self.length = length
+        self.uppercase = uppercase
+        self.lowercase = lowercase
+        self.numbers = numbers
+        self.symbols = symbols
+        self.minUppercase = minUppercase
+        self.minLowercase = minLowercase
+        self.minNumbers = minNumbers
+        self.minSymbols = minSymbols
+        self.excludeAmbiguous = excludeAmbiguous
+        self.include = include
+        self.exclude = exclude
+        self.noRepeat = noRepeat
+        self.noConsecutive = noConsecutive
+        self.words = words
+        self.separator = separator
+        self.capitalize = capitalize
+        self.appendDigit = appendDigit
+    }
+}
+
+
+
+extension GeneratorOptions: Equatable, Hashable {
+    public static func ==(lhs: GeneratorOptions, rhs: GeneratorOptions) -> Bool {
+        if lhs.mode != rhs.mode {
+            return false
+        }
+        if lhs.length != rhs.length {
+            return false
+        }
+        if lhs.uppercase != rhs.uppercase {
+            return false
+        }
+        if lhs.lowercase != rhs.lowercase {
+            return false
+        }
+        if lhs.numbers != rhs.numbers {
+            return false
+        }
+        if lhs.symbols != rhs.symbols {
+            return false
+        }
+        if lhs.minUppercase != rhs.minUppercase {
+            return false
+        }
+        if lhs.minLowercase != rhs.minLowercase {
+            return false
+        }
+        if lhs.minNumbers != rhs.minNumbers {
+            return false
+        }
+        if lhs.minSymbols != rhs.minSymbols {
+            return false
+        }
+        if lhs.excludeAmbiguous != rhs.excludeAmbiguous {
+            return false
+        }
+        if lhs.include != rhs.include {
+            return false
+        }
+        if lhs.exclude != rhs.exclude {
+            return false
+        }
+        if lhs.noRepeat != rhs.noRepeat {
+            return false
+        }
+        if lhs.noConsecutive != rhs.noConsecutive {
+            return false
+        }
+        if lhs.words != rhs.words {
+            return false
+        }
+        if lhs.separator != rhs.separator {
+            return false
+        }
+        if lhs.capitalize != rhs.capitalize {
+            return false
+        }
+        if lhs.appendDigit != rhs.appendDigit {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(mode)
+        hasher.combine(length)
+        hasher.combine(uppercase)
+        hasher.combine(lowercase)
+        hasher.combine(numbers)
+        hasher.combine(symbols)
+        hasher.combine(minUppercase)
+        hasher.combine(minLowercase)
+        hasher.combine(minNumbers)
+        hasher.combine(minSymbols)
+        hasher.combine(excludeAmbiguous)
+        hasher.combine(include)
+        hasher.combine(exclude)
+        hasher.combine(noRepeat)
+        hasher.combine(noConsecutive)
+        hasher.combine(words)
+        hasher.combine(separator)
+        hasher.combine(capitalize)
+        hasher.combine(appendDigit)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGeneratorOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GeneratorOptions {
+        return
+            try GeneratorOptions(
+                mode: FfiConverterTypeGeneratorMode.read(from: &buf), 
+                length: FfiConverterUInt32.read(from: &buf), 
+                uppercase: FfiConverterBool.read(from: &buf), 
+                lowercase: FfiConverterBool.read(from: &buf), 
+                numbers: FfiConverterBool.read(from: &buf), 
+                symbols: FfiConverterBool.read(from: &buf), 
+                minUppercase: FfiConverterUInt32.read(from: &buf), 
+                minLowercase: FfiConverterUInt32.read(from: &buf), 
+                minNumbers: FfiConverterUInt32.read(from: &buf), 
+                minSymbols: FfiConverterUInt32.read(from: &buf), 
+                excludeAmbiguous: FfiConverterBool.read(from: &buf), 
+                include: FfiConverterString.read(from: &buf), 
+                exclude: FfiConverterString.read(from: &buf), 
+                noRepeat: FfiConverterBool.read(from: &buf), 
+                noConsecutive: FfiConverterBool.read(from: &buf), 
+                words: FfiConverterUInt32.read(from: &buf), 
+                separator: FfiConverterString.read(from: &buf), 
+                capitalize: FfiConverterBool.read(from: &buf), 
+                appendDigit: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GeneratorOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeGeneratorMode.write(value.mode, into: &buf)
+        FfiConverterUInt32.write(value.length, into: &buf)
+        FfiConverterBool.write(value.uppercase, into: &buf)
+        FfiConverterBool.write(value.lowercase, into: &buf)
+        FfiConverterBool.write(value.numbers, into: &buf)
+        FfiConverterBool.write(value.symbols, into: &buf)
+        FfiConverterUInt32.write(value.minUppercase, into: &buf)
+        FfiConverterUInt32.write(value.minLowercase, into: &buf)
+        FfiConverterUInt32.write(value.minNumbers, into: &buf)
+        FfiConverterUInt32.write(value.minSymbols, into: &buf)
+        FfiConverterBool.write(value.excludeAmbiguous, into: &buf)
+        FfiConverterString.write(value.include, into: &buf)
+        FfiConverterString.write(value.exclude, into: &buf)
+        FfiConverterBool.write(value.noRepeat, into: &buf)
+        FfiConverterBool.write(value.noConsecutive, into: &buf)
+        FfiConverterUInt32.write(value.words, into: &buf)
+        FfiConverterString.write(value.separator, into: &buf)
+        FfiConverterBool.write(value.capitalize, into: &buf)
+        FfiConverterBool.write(value.appendDigit, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGeneratorOptions_lift(_ buf: RustBuffer) throws -> GeneratorOptions {
+    return try FfiConverterTypeGeneratorOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGeneratorOptions_lower(_ value: GeneratorOptions) -> RustBuffer {
+    return FfiConverterTypeGeneratorOptions.lower(value)
+}
+
+
+public struct GeneratorResult {
+    public var password: String
+    public var strength: String
+    public var entropy: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(password: String, strength: String, entropy: Double) {
+        self.password = password
+        self.strength = strength
+        self.entropy = entropy
+    }
+}
+
+
+
+extension GeneratorResult: Equatable, Hashable {
+    public static func ==(lhs: GeneratorResult, rhs: GeneratorResult) -> Bool {
+        if lhs.password != rhs.password {
+            return false
+        }
+        if lhs.strength != rhs.strength {
+            return false
+        }
+        if lhs.entropy != rhs.entropy {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(password)
+        hasher.combine(strength)
+        hasher.combine(entropy)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGeneratorResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GeneratorResult {
+        return
+            try GeneratorResult(
+                password: FfiConverterString.read(from: &buf), 
+                strength: FfiConverterString.read(from: &buf), 
+                entropy: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GeneratorResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.password, into: &buf)
+        FfiConverterString.write(value.strength, into: &buf)
+        FfiConverterDouble.write(value.entropy, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGeneratorResult_lift(_ buf: RustBuffer) throws -> GeneratorResult {
+    return try FfiConverterTypeGeneratorResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGeneratorResult_lower(_ value: GeneratorResult) -> RustBuffer {
+    return FfiConverterTypeGeneratorResult.lower(value)
+}
+
+
+public struct ItemAudit {
+    public var id: String
+    public var title: String
+    public var score: UInt8
+    public var strength: String
+    public var warning: String?
+    public var ageDays: UInt64
+    public var reused: UInt32
+    public var weak: Bool
+    public var old: Bool
+    public var hasTotp: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, title: String, score: UInt8, strength: String, warning: String?, ageDays: UInt64, reused: UInt32, weak: Bool, old: Bool, hasTotp: Bool) {
+        self.id = id
+        self.title = title
+        self.score = score
+        self.strength = strength
+        self.warning = warning
+        self.ageDays = ageDays
+        self.reused = reused
+        self.weak = weak
+        self.old = old
+        self.hasTotp = hasTotp
+    }
+}
+
+
+
+extension ItemAudit: Equatable, Hashable {
+    public static func ==(lhs: ItemAudit, rhs: ItemAudit) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.score != rhs.score {
+            return false
+        }
+        if lhs.strength != rhs.strength {
+            return false
+        }
+        if lhs.warning != rhs.warning {
+            return false
+        }
+        if lhs.ageDays != rhs.ageDays {
+            return false
+        }
+        if lhs.reused != rhs.reused {
+            return false
+        }
+        if lhs.weak != rhs.weak {
+            return false
+        }
+        if lhs.old != rhs.old {
+            return false
+        }
+        if lhs.hasTotp != rhs.hasTotp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(score)
+        hasher.combine(strength)
+        hasher.combine(warning)
+        hasher.combine(ageDays)
+        hasher.combine(reused)
+        hasher.combine(weak)
+        hasher.combine(old)
+        hasher.combine(hasTotp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeItemAudit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ItemAudit {
+        return
+            try ItemAudit(
+                id: FfiConverterString.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                score: FfiConverterUInt8.read(from: &buf), 
+                strength: FfiConverterString.read(from: &buf), 
+                warning: FfiConverterOptionString.read(from: &buf), 
+                ageDays: FfiConverterUInt64.read(from: &buf), 
+                reused: FfiConverterUInt32.read(from: &buf), 
+                weak: FfiConverterBool.read(from: &buf), 
+                old: FfiConverterBool.read(from: &buf), 
+                hasTotp: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ItemAudit, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterUInt8.write(value.score, into: &buf)
+        FfiConverterString.write(value.strength, into: &buf)
+        FfiConverterOptionString.write(value.warning, into: &buf)
+        FfiConverterUInt64.write(value.ageDays, into: &buf)
+        FfiConverterUInt32.write(value.reused, into: &buf)
+        FfiConverterBool.write(value.weak, into: &buf)
+        FfiConverterBool.write(value.old, into: &buf)
+        FfiConverterBool.write(value.hasTotp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemAudit_lift(_ buf: RustBuffer) throws -> ItemAudit {
+    return try FfiConverterTypeItemAudit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemAudit_lower(_ value: ItemAudit) -> RustBuffer {
+    return FfiConverterTypeItemAudit.lower(value)
+}
+
+
+public struct LoginUrl {
+    public var url: String
+    public var rule: UrlMatchRule?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(url: String, rule: UrlMatchRule?) {
+        self.url = url
+        self.rule = rule
+    }
+}
+
+
+
+extension LoginUrl: Equatable, Hashable {
+    public static func ==(lhs: LoginUrl, rhs: LoginUrl) -> Bool {
+        if lhs.url != rhs.url {
+            return false
+        }
+        if lhs.rule != rhs.rule {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(url)
+        hasher.combine(rule)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLoginUrl: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LoginUrl {
+        return
+            try LoginUrl(
+                url: FfiConverterString.read(from: &buf), 
+                rule: FfiConverterOptionTypeUrlMatchRule.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LoginUrl, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.url, into: &buf)
+        FfiConverterOptionTypeUrlMatchRule.write(value.rule, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLoginUrl_lift(_ buf: RustBuffer) throws -> LoginUrl {
+    return try FfiConverterTypeLoginUrl.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLoginUrl_lower(_ value: LoginUrl) -> RustBuffer {
+    return FfiConverterTypeLoginUrl.lower(value)
+}
+
+
+public struct LoginUrls {
+    public var id: String
+    public var urls: [LoginUrl]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, urls: [LoginUrl]) {
+        self.id = id
+        self.urls = urls
+    }
+}
+
+
+
+extension LoginUrls: Equatable, Hashable {
+    public static func ==(lhs: LoginUrls, rhs: LoginUrls) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.urls != rhs.urls {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(urls)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLoginUrls: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LoginUrls {
+        return
+            try LoginUrls(
+                id: FfiConverterString.read(from: &buf), 
+                urls: FfiConverterSequenceTypeLoginUrl.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LoginUrls, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterSequenceTypeLoginUrl.write(value.urls, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLoginUrls_lift(_ buf: RustBuffer) throws -> LoginUrls {
+    return try FfiConverterTypeLoginUrls.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLoginUrls_lower(_ value: LoginUrls) -> RustBuffer {
+    return FfiConverterTypeLoginUrls.lower(value)
+}
+
+
+public struct OtpCode {
+    public var code: String
+    public var counter: UInt64
+    public var period: UInt64
+    public var remaining: UInt64
+    public var expires: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(code: String, counter: UInt64, period: UInt64, remaining: UInt64, expires: UInt64) {
+        self.code = code
+        self.counter = counter
+        self.period = period
+        self.remaining = remaining
+        self.expires = expires
+    }
+}
+
+
+
+extension OtpCode: Equatable, Hashable {
+    public static func ==(lhs: OtpCode, rhs: OtpCode) -> Bool {
+        if lhs.code != rhs.code {
+            return false
+        }
+        if lhs.counter != rhs.counter {
+            return false
+        }
+        if lhs.period != rhs.period {
+            return false
+        }
+        if lhs.remaining != rhs.remaining {
+            return false
+        }
+        if lhs.expires != rhs.expires {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(code)
+        hasher.combine(counter)
+        hasher.combine(period)
+        hasher.combine(remaining)
+        hasher.combine(expires)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpCode: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpCode {
+        return
+            try OtpCode(
+                code: FfiConverterString.read(from: &buf), 
+                counter: FfiConverterUInt64.read(from: &buf), 
+                period: FfiConverterUInt64.read(from: &buf), 
+                remaining: FfiConverterUInt64.read(from: &buf), 
+                expires: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OtpCode, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.code, into: &buf)
+        FfiConverterUInt64.write(value.counter, into: &buf)
+        FfiConverterUInt64.write(value.period, into: &buf)
+        FfiConverterUInt64.write(value.remaining, into: &buf)
+        FfiConverterUInt64.write(value.expires, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpCode_lift(_ buf: RustBuffer) throws -> OtpCode {
+    return try FfiConverterTypeOtpCode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpCode_lower(_ value: OtpCode) -> RustBuffer {
+    return FfiConverterTypeOtpCode.lower(value)
+}
+
+
+public struct OtpConfig {
+    public var kind: OtpKind
+    public var secret: String
+    public var issuer: String?
+    public var account: String?
+    public var algorithm: OtpAlgorithm
+    public var digits: UInt32
+    public var period: UInt64
+    public var counter: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: OtpKind, secret: String, issuer: String?, account: String?, algorithm: OtpAlgorithm, digits: UInt32, period: UInt64, counter: UInt64) {
+        self.kind = kind
+        self.secret = secret
+        self.issuer = issuer
+        self.account = account
+        self.algorithm = algorithm
+        self.digits = digits
+        self.period = period
+        self.counter = counter
+    }
+}
+
+
+
+extension OtpConfig: Equatable, Hashable {
+    public static func ==(lhs: OtpConfig, rhs: OtpConfig) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.secret != rhs.secret {
+            return false
+        }
+        if lhs.issuer != rhs.issuer {
+            return false
+        }
+        if lhs.account != rhs.account {
+            return false
+        }
+        if lhs.algorithm != rhs.algorithm {
+            return false
+        }
+        if lhs.digits != rhs.digits {
+            return false
+        }
+        if lhs.period != rhs.period {
+            return false
+        }
+        if lhs.counter != rhs.counter {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(secret)
+        hasher.combine(issuer)
+        hasher.combine(account)
+        hasher.combine(algorithm)
+        hasher.combine(digits)
+        hasher.combine(period)
+        hasher.combine(counter)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpConfig {
+        return
+            try OtpConfig(
+                kind: FfiConverterTypeOtpKind.read(from: &buf), 
+                secret: FfiConverterString.read(from: &buf), 
+                issuer: FfiConverterOptionString.read(from: &buf), 
+                account: FfiConverterOptionString.read(from: &buf), 
+                algorithm: FfiConverterTypeOtpAlgorithm.read(from: &buf), 
+                digits: FfiConverterUInt32.read(from: &buf), 
+                period: FfiConverterUInt64.read(from: &buf), 
+                counter: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OtpConfig, into buf: inout [UInt8]) {
+        FfiConverterTypeOtpKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.secret, into: &buf)
+        FfiConverterOptionString.write(value.issuer, into: &buf)
+        FfiConverterOptionString.write(value.account, into: &buf)
+        FfiConverterTypeOtpAlgorithm.write(value.algorithm, into: &buf)
+        FfiConverterUInt32.write(value.digits, into: &buf)
+        FfiConverterUInt64.write(value.period, into: &buf)
+        FfiConverterUInt64.write(value.counter, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpConfig_lift(_ buf: RustBuffer) throws -> OtpConfig {
+    return try FfiConverterTypeOtpConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpConfig_lower(_ value: OtpConfig) -> RustBuffer {
+    return FfiConverterTypeOtpConfig.lower(value)
+}
+
+
+public struct OtpImportCandidate {
+    public var config: OtpConfig
+    public var title: String
+    public var duplicateOf: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(config: OtpConfig, title: String, duplicateOf: String?) {
+        self.config = config
+        self.title = title
+        self.duplicateOf = duplicateOf
+    }
+}
+
+
+
+extension OtpImportCandidate: Equatable, Hashable {
+    public static func ==(lhs: OtpImportCandidate, rhs: OtpImportCandidate) -> Bool {
+        if lhs.config != rhs.config {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.duplicateOf != rhs.duplicateOf {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(config)
+        hasher.combine(title)
+        hasher.combine(duplicateOf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpImportCandidate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpImportCandidate {
+        return
+            try OtpImportCandidate(
+                config: FfiConverterTypeOtpConfig.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                duplicateOf: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OtpImportCandidate, into buf: inout [UInt8]) {
+        FfiConverterTypeOtpConfig.write(value.config, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterOptionString.write(value.duplicateOf, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpImportCandidate_lift(_ buf: RustBuffer) throws -> OtpImportCandidate {
+    return try FfiConverterTypeOtpImportCandidate.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpImportCandidate_lower(_ value: OtpImportCandidate) -> RustBuffer {
+    return FfiConverterTypeOtpImportCandidate.lower(value)
+}
+
+
+public struct OtpImportPreview {
+    public var candidates: [OtpImportCandidate]
+    public var invalid: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(candidates: [OtpImportCandidate], invalid: [String]) {
+        self.candidates = candidates
+        self.invalid = invalid
+    }
+}
+
+
+
+extension OtpImportPreview: Equatable, Hashable {
+    public static func ==(lhs: OtpImportPreview, rhs: OtpImportPreview) -> Bool {
+        if lhs.candidates != rhs.candidates {
+            return false
+        }
+        if lhs.invalid != rhs.invalid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(candidates)
+        hasher.combine(invalid)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpImportPreview: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpImportPreview {
+        return
+            try OtpImportPreview(
+                candidates: FfiConverterSequenceTypeOtpImportCandidate.read(from: &buf), 
+                invalid: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OtpImportPreview, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeOtpImportCandidate.write(value.candidates, into: &buf)
+        FfiConverterSequenceString.write(value.invalid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpImportPreview_lift(_ buf: RustBuffer) throws -> OtpImportPreview {
+    return try FfiConverterTypeOtpImportPreview.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpImportPreview_lower(_ value: OtpImportPreview) -> RustBuffer {
+    return FfiConverterTypeOtpImportPreview.lower(value)
+}
+
+
+public struct OtpImportResult {
+    public var created: [VaultItem]
+    public var duplicates: UInt32
+    public var invalid: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(created: [VaultItem], duplicates: UInt32, invalid: [String]) {
+        self.created = created
+        self.duplicates = duplicates
+        self.invalid = invalid
+    }
+}
+
+
+
+extension OtpImportResult: Equatable, Hashable {
+    public static func ==(lhs: OtpImportResult, rhs: OtpImportResult) -> Bool {
+        if lhs.created != rhs.created {
+            return false
+        }
+        if lhs.duplicates != rhs.duplicates {
+            return false
+        }
+        if lhs.invalid != rhs.invalid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(created)
+        hasher.combine(duplicates)
+        hasher.combine(invalid)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpImportResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpImportResult {
+        return
+            try OtpImportResult(
+                created: FfiConverterSequenceTypeVaultItem.read(from: &buf), 
+                duplicates: FfiConverterUInt32.read(from: &buf), 
+                invalid: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OtpImportResult, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeVaultItem.write(value.created, into: &buf)
+        FfiConverterUInt32.write(value.duplicates, into: &buf)
+        FfiConverterSequenceString.write(value.invalid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpImportResult_lift(_ buf: RustBuffer) throws -> OtpImportResult {
+    return try FfiConverterTypeOtpImportResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpImportResult_lower(_ value: OtpImportResult) -> RustBuffer {
+    return FfiConverterTypeOtpImportResult.lower(value)
+}
+
+
+public struct PasswordStrength {
+    public var guesses: Double
+    public var guessesLog10: Double
+    public var score: UInt8
+    public var strength: String
+    public var crackTimes: [CrackTime]
+    public var warning: String?
+    public var suggestions: [String]
+    public var sequence: [StrengthMatch]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(guesses: Double, guessesLog10: Double, score: UInt8, strength: String, crackTimes: [CrackTime], warning: String?, suggestions: [String], sequence: [StrengthMatch]) {
+        self.guesses = guesses
+        self.guessesLog10 = guessesLog10
+        self.score = score
+        self.strength = strength
+        self.crackTimes = crackTimes
+        self.warning = warning
+        self.suggestions = suggestions
+        self.sequence = sequence
+    }
+}
+
+
+
+extension PasswordStrength: Equatable, Hashable {
+    public static func ==(lhs: PasswordStrength, rhs: PasswordStrength) -> Bool {
+        if lhs.guesses != rhs.guesses {
+            return false
+        }
+        if lhs.guessesLog10 != rhs.guessesLog10 {
+            return false
+        }
+        if lhs.score != rhs.score {
+            return false
+        }
+        if lhs.strength != rhs.strength {
+            return false
+        }
+        if lhs.crackTimes != rhs.crackTimes {
+            return false
+        }
+        if lhs.warning != rhs.warning {
+            return false
+        }
+        if lhs.suggestions != rhs.suggestions {
+            return false
+        }
+        if lhs.sequence != rhs.sequence {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(guesses)
+        hasher.combine(guessesLog10)
+        hasher.combine(score)
+        hasher.combine(strength)
+        hasher.combine(crackTimes)
+        hasher.combine(warning)
+        hasher.combine(suggestions)
+        hasher.combine(sequence)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePasswordStrength: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PasswordStrength {
+        return
+            try PasswordStrength(
+                guesses: FfiConverterDouble.read(from: &buf), 
+                guessesLog10: FfiConverterDouble.read(from: &buf), 
+                score: FfiConverterUInt8.read(from: &buf), 
+                strength: FfiConverterString.read(from: &buf), 
+                crackTimes: FfiConverterSequenceTypeCrackTime.read(from: &buf), 
+                warning: FfiConverterOptionString.read(from: &buf), 
+                suggestions: FfiConverterSequenceString.read(from: &buf), 
+                sequence: FfiConverterSequenceTypeStrengthMatch.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PasswordStrength, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.guesses, into: &buf)
+        FfiConverterDouble.write(value.guessesLog10, into: &buf)
+        FfiConverterUInt8.write(value.score, into: &buf)
+        FfiConverterString.write(value.strength, into: &buf)
+        FfiConverterSequenceTypeCrackTime.write(value.crackTimes, into: &buf)
+        FfiConverterOptionString.write(value.warning, into: &buf)
+        FfiConverterSequenceString.write(value.suggestions, into: &buf)
+        FfiConverterSequenceTypeStrengthMatch.write(value.sequence, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePasswordStrength_lift(_ buf: RustBuffer) throws -> PasswordStrength {
+    return try FfiConverterTypePasswordStrength.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePasswordStrength_lower(_ value: PasswordStrength) -> RustBuffer {
+    return FfiConverterTypePasswordStrength.lower(value)
+}
+
+
+public struct ReusedGroup {
+    public var ids: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(ids: [String]) {
+        self.ids = ids
+    }
+}
+
+
+
+extension ReusedGroup: Equatable, Hashable {
+    public static func ==(lhs: ReusedGroup, rhs: ReusedGroup) -> Bool {
+        if lhs.ids != rhs.ids {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(ids)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReusedGroup: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReusedGroup {
+        return
+            try ReusedGroup(
+                ids: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ReusedGroup, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.ids, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReusedGroup_lift(_ buf: RustBuffer) throws -> ReusedGroup {
+    return try FfiConverterTypeReusedGroup.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReusedGroup_lower(_ value: ReusedGroup) -> RustBuffer {
+    return FfiConverterTypeReusedGroup.lower(value)
+}
+
+
+public struct StrengthMatch {
+    public var pattern: String
+    public var token: String
+    public var guesses: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(pattern: String, token: String, guesses: Double) {
+        self.pattern = pattern
+        self.token = token
+        self.guesses = guesses
+    }
+}
+
+
+
+extension StrengthMatch: Equatable, Hashable {
+    public static func ==(lhs: StrengthMatch, rhs: StrengthMatch) -> Bool {
+        if lhs.pattern != rhs.pattern {
+            return false
+        }
+        if lhs.token != rhs.token {
+            return false
+        }
+        if lhs.guesses != rhs.guesses {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pattern)
+        hasher.combine(token)
+        hasher.combine(guesses)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStrengthMatch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StrengthMatch {
+        return
+            try StrengthMatch(
+                pattern: FfiConverterString.read(from: &buf), 
+                token: FfiConverterString.read(from: &buf), 
+                guesses: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StrengthMatch, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pattern, into: &buf)
+        FfiConverterString.write(value.token, into: &buf)
+        FfiConverterDouble.write(value.guesses, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStrengthMatch_lift(_ buf: RustBuffer) throws -> StrengthMatch {
+    return try FfiConverterTypeStrengthMatch.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStrengthMatch_lower(_ value: StrengthMatch) -> RustBuffer {
+    return FfiConverterTypeStrengthMatch.lower(value)
+}
+
+
+public struct SyncConflict {
+    public var id: String
+    public var localRevision: Int32
+    public var serverRevision: Int32
+    public var server: VaultItem
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, localRevision: Int32, serverRevision: Int32, server: VaultItem) {
+        self.id = id
+        self.localRevision = localRevision
+        self.serverRevision = serverRevision
+        self.server = server
+    }
+}
+
+
+
+extension SyncConflict: Equatable, Hashable {
+    public static func ==(lhs: SyncConflict, rhs: SyncConflict) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.localRevision != rhs.localRevision {
+            return false
+        }
+        if lhs.serverRevision != rhs.serverRevision {
+            return false
+        }
+        if lhs.server != rhs.server {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(localRevision)
+        hasher.combine(serverRevision)
+        hasher.combine(server)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyncConflict: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncConflict {
+        return
+            try SyncConflict(
+                id: FfiConverterString.read(from: &buf), 
+                localRevision: FfiConverterInt32.read(from: &buf), 
+                serverRevision: FfiConverterInt32.read(from: &buf), 
+                server: FfiConverterTypeVaultItem.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SyncConflict, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterInt32.write(value.localRevision, into: &buf)
+        FfiConverterInt32.write(value.serverRevision, into: &buf)
+        FfiConverterTypeVaultItem.write(value.server, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncConflict_lift(_ buf: RustBuffer) throws -> SyncConflict {
+    return try FfiConverterTypeSyncConflict.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncConflict_lower(_ value: SyncConflict) -> RustBuffer {
+    return FfiConverterTypeSyncConflict.lower(value)
+}
+
+
+public struct SyncResult {
+    public var changed: [VaultItem]
+    public var removed: [String]
+    public var pushed: UInt32
+    public var error: String?
+    public var timestamp: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(changed: [VaultItem], removed: [String], pushed: UInt32, error: String?, timestamp: UInt64) {
+        self.changed = changed
+        self.removed = removed
+        self.pushed = pushed
+        self.error = error
+        self.timestamp = timestamp
+    }
+}
+
+
+
+extension SyncResult: Equatable, Hashable {
+    public static func ==(lhs: SyncResult, rhs: SyncResult) -> Bool {
+        if lhs.changed != rhs.changed {
+            return false
+        }
+        if lhs.removed != rhs.removed {
+            return false
+        }
+        if lhs.pushed != rhs.pushed {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(changed)
+        hasher.combine(removed)
+        hasher.combine(pushed)
+        hasher.combine(error)
+        hasher.combine(timestamp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyncResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncResult {
+        return
+            try SyncResult(
+                changed: FfiConverterSequenceTypeVaultItem.read(from: &buf), 
+                removed: FfiConverterSequenceString.read(from: &buf), 
+                pushed: FfiConverterUInt32.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SyncResult, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeVaultItem.write(value.changed, into: &buf)
+        FfiConverterSequenceString.write(value.removed, into: &buf)
+        FfiConverterUInt32.write(value.pushed, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncResult_lift(_ buf: RustBuffer) throws -> SyncResult {
+    return try FfiConverterTypeSyncResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncResult_lower(_ value: SyncResult) -> RustBuffer {
+    return FfiConverterTypeSyncResult.lower(value)
+}
+
+
+public struct UrlMatch {
+    public var id: String
+    public var url: String
+    public var rule: UrlMatchRule
+    public var score: UInt8
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, url: String, rule: UrlMatchRule, score: UInt8) {
+        self.id = id
+        self.url = url
+        self.rule = rule
+        self.score = score
+    }
+}
+
+
+
+extension UrlMatch: Equatable, Hashable {
+    public static func ==(lhs: UrlMatch, rhs: UrlMatch) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.url != rhs.url {
+            return false
+        }
+        if lhs.rule != rhs.rule {
+            return false
+        }
+        if lhs.score != rhs.score {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(url)
+        hasher.combine(rule)
+        hasher.combine(score)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUrlMatch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UrlMatch {
+        return
+            try UrlMatch(
+                id: FfiConverterString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf), 
+                rule: FfiConverterTypeUrlMatchRule.read(from: &buf), 
+                score: FfiConverterUInt8.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UrlMatch, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+        FfiConverterTypeUrlMatchRule.write(value.rule, into: &buf)
+        FfiConverterUInt8.write(value.score, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUrlMatch_lift(_ buf: RustBuffer) throws -> UrlMatch {
+    return try FfiConverterTypeUrlMatch.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUrlMatch_lower(_ value: UrlMatch) -> RustBuffer {
+    return FfiConverterTypeUrlMatch.lower(value)
+}
+
+
+public struct VaultData {
     public var items: [VaultItem]
+    public var folders: [Folder]
+    public var updated: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(items: [VaultItem], folders: [Folder], updated: UInt64) {
+        self.items = items
+        self.folders = folders
+        self.updated = updated
+    }
+}
+
+
+
+extension VaultData: Equatable, Hashable {
+    public static func ==(lhs: VaultData, rhs: VaultData) -> Bool {
+        if lhs.items != rhs.items {
+            return false
+        }
+        if lhs.folders != rhs.folders {
+            return false
+        }
+        if lhs.updated != rhs.updated {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(items)
+        hasher.combine(folders)
+        hasher.combine(updated)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVaultData: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VaultData {
+        return
+            try VaultData(
+                items: FfiConverterSequenceTypeVaultItem.read(from: &buf), 
+                folders: FfiConverterSequenceTypeFolder.read(from: &buf), 
+                updated: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VaultData, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeVaultItem.write(value.items, into: &buf)
+        FfiConverterSequenceTypeFolder.write(value.folders, into: &buf)
+        FfiConverterUInt64.write(value.updated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVaultData_lift(_ buf: RustBuffer) throws -> VaultData {
+    return try FfiConverterTypeVaultData.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVaultData_lower(_ value: VaultData) -> RustBuffer {
+    return FfiConverterTypeVaultData.lower(value)
+}
+
+
+public struct VaultItem {
+    public var id: String
+    public var itemType: String
+    public var title: String
+    public var data: Data
+    public var revision: Int32
+    public var favorite: Bool
+    public var deleted: Bool
+    public var deletedAt: UInt64?
+    public var tags: [String]
+    public var folderId: String?
+    public var created: UInt64
     public var updated: UInt64
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(items: [VaultItem], updated: UInt64) {
-        self.items = items
-        self.updated = updated
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, itemType: String, title: String, data: Data, revision: Int32, favorite: Bool, deleted: Bool, deletedAt: UInt64?, tags: [String], folderId: String?, created: UInt64, updated: UInt64) {
+        self.id = id
+        self.itemType = itemType
+        self.title = title
+        self.data = data
+        self.revision = revision
+        self.favorite = favorite
+        self.deleted = deleted
+        self.deletedAt = deletedAt
+        self.tags = tags
+        self.folderId = folderId
+        self.created = created
+        self.updated = updated
+    }
+}
+
+
+
+extension VaultItem: Equatable, Hashable {
+    public static func ==(lhs: VaultItem, rhs: VaultItem) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.itemType != rhs.itemType {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.data != rhs.data {
+            return false
+        }
+        if lhs.revision != rhs.revision {
+            return false
+        }
+        if lhs.favorite != rhs.favorite {
+            return false
+        }
+        if lhs.deleted != rhs.deleted {
+            return false
+        }
+        if lhs.deletedAt != rhs.deletedAt {
+            return false
+        }
+        if lhs.tags != rhs.tags {
+            return false
+        }
+        if lhs.folderId != rhs.folderId {
+            return false
+        }
+        if lhs.created != rhs.created {
+            return false
+        }
+        if lhs.updated != rhs.updated {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(itemType)
+        hasher.combine(title)
+        hasher.combine(data)
+        hasher.combine(revision)
+        hasher.combine(favorite)
+        hasher.combine(deleted)
+        hasher.combine(deletedAt)
+        hasher.combine(tags)
+        hasher.combine(folderId)
+        hasher.combine(created)
+        hasher.combine(updated)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVaultItem: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VaultItem {
+        return
+            try VaultItem(
+                id: FfiConverterString.read(from: &buf), 
+                itemType: FfiConverterString.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                data: FfiConverterData.read(from: &buf), 
+                revision: FfiConverterInt32.read(from: &buf), 
+                favorite: FfiConverterBool.read(from: &buf), 
+                deleted: FfiConverterBool.read(from: &buf), 
+                deletedAt: FfiConverterOptionUInt64.read(from: &buf), 
+                tags: FfiConverterSequenceString.read(from: &buf), 
+                folderId: FfiConverterOptionString.read(from: &buf), 
+                created: FfiConverterUInt64.read(from: &buf), 
+                updated: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VaultItem, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.itemType, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterData.write(value.data, into: &buf)
+        FfiConverterInt32.write(value.revision, into: &buf)
+        FfiConverterBool.write(value.favorite, into: &buf)
+        FfiConverterBool.write(value.deleted, into: &buf)
+        FfiConverterOptionUInt64.write(value.deletedAt, into: &buf)
+        FfiConverterSequenceString.write(value.tags, into: &buf)
+        FfiConverterOptionString.write(value.folderId, into: &buf)
+        FfiConverterUInt64.write(value.created, into: &buf)
+        FfiConverterUInt64.write(value.updated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVaultItem_lift(_ buf: RustBuffer) throws -> VaultItem {
+    return try FfiConverterTypeVaultItem.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVaultItem_lower(_ value: VaultItem) -> RustBuffer {
+    return FfiConverterTypeVaultItem.lower(value)
+}
+
+
+public enum BackupError {
+
+    
+    
+    case InvalidPassword
+    case Format
+    case Unsupported
+    case Crypto
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBackupError: FfiConverterRustBuffer {
+    typealias SwiftType = BackupError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BackupError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidPassword
+        case 2: return .Format
+        case 3: return .Unsupported
+        case 4: return .Crypto
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BackupError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .InvalidPassword:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .Format:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .Unsupported:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .Crypto:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+extension BackupError: Equatable, Hashable {}
+
+extension BackupError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum BatchOp {
+    
+    case create(id: String, itemType: String, title: String, data: Data, tags: [String], favorite: Bool
+    )
+    case update(id: String, title: String?, data: Data?, tags: [String]?, favorite: Bool?
+    )
+    case delete(id: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBatchOp: FfiConverterRustBuffer {
+    typealias SwiftType = BatchOp
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BatchOp {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .create(id: try FfiConverterString.read(from: &buf), itemType: try FfiConverterString.read(from: &buf), title: try FfiConverterString.read(from: &buf), data: try FfiConverterData.read(from: &buf), tags: try FfiConverterSequenceString.read(from: &buf), favorite: try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 2: return .update(id: try FfiConverterString.read(from: &buf), title: try FfiConverterOptionString.read(from: &buf), data: try FfiConverterOptionData.read(from: &buf), tags: try FfiConverterOptionSequenceString.read(from: &buf), favorite: try FfiConverterOptionBool.read(from: &buf)
+        )
+        
+        case 3: return .delete(id: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BatchOp, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .create(id,itemType,title,data,tags,favorite):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(id, into: &buf)
+            FfiConverterString.write(itemType, into: &buf)
+            FfiConverterString.write(title, into: &buf)
+            FfiConverterData.write(data, into: &buf)
+            FfiConverterSequenceString.write(tags, into: &buf)
+            FfiConverterBool.write(favorite, into: &buf)
+            
+        
+        case let .update(id,title,data,tags,favorite):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(id, into: &buf)
+            FfiConverterOptionString.write(title, into: &buf)
+            FfiConverterOptionData.write(data, into: &buf)
+            FfiConverterOptionSequenceString.write(tags, into: &buf)
+            FfiConverterOptionBool.write(favorite, into: &buf)
+            
+        
+        case let .delete(id):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(id, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBatchOp_lift(_ buf: RustBuffer) throws -> BatchOp {
+    return try FfiConverterTypeBatchOp.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBatchOp_lower(_ value: BatchOp) -> RustBuffer {
+    return FfiConverterTypeBatchOp.lower(value)
+}
+
+
+
+extension BatchOp: Equatable, Hashable {}
+
+
+
+
+public enum BreachError {
+
+    
+    
+    case Io(String
+    )
+    case InvalidDataset(String
+    )
+    case Http(String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBreachError: FfiConverterRustBuffer {
+    typealias SwiftType = BreachError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BreachError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Io(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .InvalidDataset(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .Http(
+            try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BreachError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .Io(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .InvalidDataset(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Http(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+extension BreachError: Equatable, Hashable {}
+
+extension BreachError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum BreachSource {
+    
+    case file(path: String
+    )
+    case directory(path: String
+    )
+    case http(baseUrl: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBreachSource: FfiConverterRustBuffer {
+    typealias SwiftType = BreachSource
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BreachSource {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .file(path: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .directory(path: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .http(baseUrl: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BreachSource, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .file(path):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(path, into: &buf)
+            
+        
+        case let .directory(path):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(path, into: &buf)
+            
+        
+        case let .http(baseUrl):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(baseUrl, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBreachSource_lift(_ buf: RustBuffer) throws -> BreachSource {
+    return try FfiConverterTypeBreachSource.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBreachSource_lower(_ value: BreachSource) -> RustBuffer {
+    return FfiConverterTypeBreachSource.lower(value)
+}
+
+
+
+extension BreachSource: Equatable, Hashable {}
+
+
+
+
+public enum CryptoError {
+
+    
+    
+    case Argon2
+    case Encryption
+    case Decryption
+    case InvalidSecretKey
+    case InvalidKeyLength
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCryptoError: FfiConverterRustBuffer {
+    typealias SwiftType = CryptoError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CryptoError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Argon2
+        case 2: return .Encryption
+        case 3: return .Decryption
+        case 4: return .InvalidSecretKey
+        case 5: return .InvalidKeyLength
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CryptoError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .Argon2:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .Encryption:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .Decryption:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .InvalidSecretKey:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .InvalidKeyLength:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+extension CryptoError: Equatable, Hashable {}
+
+extension CryptoError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+public enum GeneratorError {
+
+    
+    
+    case NoCharacters
+    case TooShort
+    case NotEnoughCharacters
+    case Exhausted
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGeneratorError: FfiConverterRustBuffer {
+    typealias SwiftType = GeneratorError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GeneratorError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .NoCharacters
+        case 2: return .TooShort
+        case 3: return .NotEnoughCharacters
+        case 4: return .Exhausted
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: GeneratorError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .NoCharacters:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .TooShort:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .NotEnoughCharacters:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .Exhausted:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+extension GeneratorError: Equatable, Hashable {}
+
+extension GeneratorError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum GeneratorMode {
+    
+    case random
+    case passphrase
+    case pronounceable
+    case pin
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGeneratorMode: FfiConverterRustBuffer {
+    typealias SwiftType = GeneratorMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GeneratorMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .random
+        
+        case 2: return .passphrase
+        
+        case 3: return .pronounceable
+        
+        case 4: return .pin
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: GeneratorMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .random:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .passphrase:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .pronounceable:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .pin:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGeneratorMode_lift(_ buf: RustBuffer) throws -> GeneratorMode {
+    return try FfiConverterTypeGeneratorMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGeneratorMode_lower(_ value: GeneratorMode) -> RustBuffer {
+    return FfiConverterTypeGeneratorMode.lower(value)
+}
+
+
+
+extension GeneratorMode: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OtpAlgorithm {
+    
+    case sha1
+    case sha256
+    case sha512
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpAlgorithm: FfiConverterRustBuffer {
+    typealias SwiftType = OtpAlgorithm
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpAlgorithm {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .sha1
+        
+        case 2: return .sha256
+        
+        case 3: return .sha512
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OtpAlgorithm, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .sha1:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .sha256:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .sha512:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpAlgorithm_lift(_ buf: RustBuffer) throws -> OtpAlgorithm {
+    return try FfiConverterTypeOtpAlgorithm.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpAlgorithm_lower(_ value: OtpAlgorithm) -> RustBuffer {
+    return FfiConverterTypeOtpAlgorithm.lower(value)
+}
+
+
+
+extension OtpAlgorithm: Equatable, Hashable {}
+
+
+
+
+public enum OtpError {
+
+    
+    
+    case InvalidSecret
+    case InvalidUri
+    case Unsupported
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpError: FfiConverterRustBuffer {
+    typealias SwiftType = OtpError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidSecret
+        case 2: return .InvalidUri
+        case 3: return .Unsupported
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OtpError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .InvalidSecret:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .InvalidUri:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .Unsupported:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+extension OtpError: Equatable, Hashable {}
+
+extension OtpError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OtpKind {
+    
+    case totp
+    case hotp
+    case steam
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOtpKind: FfiConverterRustBuffer {
+    typealias SwiftType = OtpKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OtpKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .totp
+        
+        case 2: return .hotp
+        
+        case 3: return .steam
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OtpKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .totp:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .hotp:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .steam:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpKind_lift(_ buf: RustBuffer) throws -> OtpKind {
+    return try FfiConverterTypeOtpKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOtpKind_lower(_ value: OtpKind) -> RustBuffer {
+    return FfiConverterTypeOtpKind.lower(value)
+}
+
+
+
+extension OtpKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PendingChange {
+    
+    case create(id: String, itemType: String, title: String, data: Data, tags: [String], favorite: Bool
+    )
+    case update(id: String, revision: Int32, title: String?, data: Data?, tags: [String]?, favorite: Bool?
+    )
+    case delete(id: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePendingChange: FfiConverterRustBuffer {
+    typealias SwiftType = PendingChange
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PendingChange {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .create(id: try FfiConverterString.read(from: &buf), itemType: try FfiConverterString.read(from: &buf), title: try FfiConverterString.read(from: &buf), data: try FfiConverterData.read(from: &buf), tags: try FfiConverterSequenceString.read(from: &buf), favorite: try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 2: return .update(id: try FfiConverterString.read(from: &buf), revision: try FfiConverterInt32.read(from: &buf), title: try FfiConverterOptionString.read(from: &buf), data: try FfiConverterOptionData.read(from: &buf), tags: try FfiConverterOptionSequenceString.read(from: &buf), favorite: try FfiConverterOptionBool.read(from: &buf)
+        )
+        
+        case 3: return .delete(id: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PendingChange, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .create(id,itemType,title,data,tags,favorite):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(id, into: &buf)
+            FfiConverterString.write(itemType, into: &buf)
+            FfiConverterString.write(title, into: &buf)
+            FfiConverterData.write(data, into: &buf)
+            FfiConverterSequenceString.write(tags, into: &buf)
+            FfiConverterBool.write(favorite, into: &buf)
+            
+        
+        case let .update(id,revision,title,data,tags,favorite):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(id, into: &buf)
+            FfiConverterInt32.write(revision, into: &buf)
+            FfiConverterOptionString.write(title, into: &buf)
+            FfiConverterOptionData.write(data, into: &buf)
+            FfiConverterOptionSequenceString.write(tags, into: &buf)
+            FfiConverterOptionBool.write(favorite, into: &buf)
+            
+        
+        case let .delete(id):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(id, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePendingChange_lift(_ buf: RustBuffer) throws -> PendingChange {
+    return try FfiConverterTypePendingChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePendingChange_lower(_ value: PendingChange) -> RustBuffer {
+    return FfiConverterTypePendingChange.lower(value)
+}
+
+
+
+extension PendingChange: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PlainExportFormat {
+    
+    case json
+    case csv
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePlainExportFormat: FfiConverterRustBuffer {
+    typealias SwiftType = PlainExportFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PlainExportFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .json
+        
+        case 2: return .csv
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PlainExportFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .json:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .csv:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlainExportFormat_lift(_ buf: RustBuffer) throws -> PlainExportFormat {
+    return try FfiConverterTypePlainExportFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlainExportFormat_lower(_ value: PlainExportFormat) -> RustBuffer {
+    return FfiConverterTypePlainExportFormat.lower(value)
+}
+
+
+
+extension PlainExportFormat: Equatable, Hashable {}
+
+
+
+
+public enum SyncError {
+
+    
+    
+    case Http
+    case Auth
+    case Conflict
+    case Rejected
+    case Parse
+    case Crypto
+    case Locked
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyncError: FfiConverterRustBuffer {
+    typealias SwiftType = SyncError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Http
+        case 2: return .Auth
+        case 3: return .Conflict
+        case 4: return .Rejected
+        case 5: return .Parse
+        case 6: return .Crypto
+        case 7: return .Locked
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SyncError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .Http:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .Auth:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .Conflict:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .Rejected:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .Parse:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .Crypto:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .Locked:
+            writeInt(&buf, Int32(7))
+        
+        }
+    }
+}
+
+
+extension SyncError: Equatable, Hashable {}
+
+extension SyncError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
     }
 }
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
+public enum UrlMatchRule {
+    
+    case domain
+    case host
+    case startsWith
+    case regex
+    case never
+}
 
-extension VaultData: Equatable, Hashable {
-    public static func ==(lhs: VaultData, rhs: VaultData) -> Bool {
-        if lhs.items != rhs.items {
-            return false
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUrlMatchRule: FfiConverterRustBuffer {
+    typealias SwiftType = UrlMatchRule
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UrlMatchRule {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .domain
+        
+        case 2: return .host
+        
+        case 3: return .startsWith
+        
+        case 4: return .regex
+        
+        case 5: return .never
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
-        if lhs.updated != rhs.updated {
-            return false
+    }
+
+    public static func write(_ value: UrlMatchRule, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .domain:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .host:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .startsWith:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .regex:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .never:
+            writeInt(&buf, Int32(5))
+        
         }
-        return true
     }
+}
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(items)
-        hasher.combine(updated)
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUrlMatchRule_lift(_ buf: RustBuffer) throws -> UrlMatchRule {
+    return try FfiConverterTypeUrlMatchRule.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUrlMatchRule_lower(_ value: UrlMatchRule) -> RustBuffer {
+    return FfiConverterTypeUrlMatchRule.lower(value)
+}
+
+
+
+extension UrlMatchRule: Equatable, Hashable {}
+
+
+
+
+public enum VaultError {
+
+    
+    
+    case NotFound
+    case Serialization
+    case Crypto
+    case Cycle
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVaultError: FfiConverterRustBuffer {
+    typealias SwiftType = VaultError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VaultError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .NotFound
+        case 2: return .Serialization
+        case 3: return .Crypto
+        case 4: return .Cycle
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VaultError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .NotFound:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .Serialization:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .Crypto:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .Cycle:
+            writeInt(&buf, Int32(4))
+        
+        }
     }
 }
 
 
+extension VaultError: Equatable, Hashable {}
+
+extension VaultError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVaultData: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VaultData {
-        return
-            try VaultData(
-                items: FfiConverterSequenceTypeVaultItem.read(from: &buf), 
-                updated: FfiConverterUInt64.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionInt32: FfiConverterRustBuffer {
+    typealias SwiftType = Int32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt32.write(value, into: &buf)
     }
 
-    public static func write(_ value: VaultData, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeVaultItem.write(value.items, into: &buf)
-        FfiConverterUInt64.write(value.updated, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVaultData_lift(_ buf: RustBuffer) throws -> VaultData {
-    return try FfiConverterTypeVaultData.lift(buf)
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVaultData_lower(_ value: VaultData) -> RustBuffer {
-    return FfiConverterTypeVaultData.lower(value)
-}
-
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
 
-public struct VaultItem {
-    public var id: String
-    public var itemType: String
-    public var title: String
-    public var data: Data
-    public var revision: Int32
-    public var favorite: Bool
-    public var deleted: Bool
-    public var tags: [String]
-    public var created: UInt64
-    public var updated: UInt64
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(id: String, itemType: String, title: String, data: Data, revision: Int32, favorite: Bool, deleted: Bool, tags: [String], created: UInt64, updated: UInt64) {
-        self.id = id
-        self.itemType = itemType
-        self.title = title
-        self.data = data
-        self.revision = revision
-        self.favorite = favorite
-        self.deleted = deleted
-        self.tags = tags
-        self.created = created
-        self.updated = updated
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
 
-
-extension VaultItem: Equatable, Hashable {
-    public static func ==(lhs: VaultItem, rhs: VaultItem) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.itemType != rhs.itemType {
-            return false
-        }
-        if lhs.title != rhs.title {
-            return false
-        }
-        if lhs.data != rhs.data {
-            return false
-        }
-        if lhs.revision != rhs.revision {
-            return false
-        }
-        if lhs.favorite != rhs.favorite {
-            return false
-        }
-        if lhs.deleted != rhs.deleted {
-            return false
-        }
-        if lhs.tags != rhs.tags {
-            return false
-        }
-        if lhs.created != rhs.created {
-            return false
-        }
-        if lhs.updated != rhs.updated {
-            return false
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
-        return true
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(itemType)
-        hasher.combine(title)
-        hasher.combine(data)
-        hasher.combine(revision)
-        hasher.combine(favorite)
-        hasher.combine(deleted)
-        hasher.combine(tags)
-        hasher.combine(created)
-        hasher.combine(updated)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVaultItem: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VaultItem {
-        return
-            try VaultItem(
-                id: FfiConverterString.read(from: &buf), 
-                itemType: FfiConverterString.read(from: &buf), 
-                title: FfiConverterString.read(from: &buf), 
-                data: FfiConverterData.read(from: &buf), 
-                revision: FfiConverterInt32.read(from: &buf), 
-                favorite: FfiConverterBool.read(from: &buf), 
-                deleted: FfiConverterBool.read(from: &buf), 
-                tags: FfiConverterSequenceString.read(from: &buf), 
-                created: FfiConverterUInt64.read(from: &buf), 
-                updated: FfiConverterUInt64.read(from: &buf)
-        )
+fileprivate struct FfiConverterOptionTypeSyncListener: FfiConverterRustBuffer {
+    typealias SwiftType = SyncListener?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSyncListener.write(value, into: &buf)
     }
 
-    public static func write(_ value: VaultItem, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterString.write(value.itemType, into: &buf)
-        FfiConverterString.write(value.title, into: &buf)
-        FfiConverterData.write(value.data, into: &buf)
-        FfiConverterInt32.write(value.revision, into: &buf)
-        FfiConverterBool.write(value.favorite, into: &buf)
-        FfiConverterBool.write(value.deleted, into: &buf)
-        FfiConverterSequenceString.write(value.tags, into: &buf)
-        FfiConverterUInt64.write(value.created, into: &buf)
-        FfiConverterUInt64.write(value.updated, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSyncListener.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVaultItem_lift(_ buf: RustBuffer) throws -> VaultItem {
-    return try FfiConverterTypeVaultItem.lift(buf)
+fileprivate struct FfiConverterOptionTypeOtpCode: FfiConverterRustBuffer {
+    typealias SwiftType = OtpCode?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeOtpCode.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeOtpCode.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVaultItem_lower(_ value: VaultItem) -> RustBuffer {
-    return FfiConverterTypeVaultItem.lower(value)
-}
-
+fileprivate struct FfiConverterOptionTypeOtpConfig: FfiConverterRustBuffer {
+    typealias SwiftType = OtpConfig?
 
-public enum CryptoError {
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeOtpConfig.write(value, into: &buf)
+    }
 
-    
-    
-    case Argon2
-    case Encryption
-    case Decryption
-    case InvalidSecretKey
-    case InvalidKeyLength
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeOtpConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCryptoError: FfiConverterRustBuffer {
-    typealias SwiftType = CryptoError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CryptoError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
+fileprivate struct FfiConverterOptionTypeVaultItem: FfiConverterRustBuffer {
+    typealias SwiftType = VaultItem?
 
-        
-        case 1: return .Argon2
-        case 2: return .Encryption
-        case 3: return .Decryption
-        case 4: return .InvalidSecretKey
-        case 5: return .InvalidKeyLength
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeVaultItem.write(value, into: &buf)
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeVaultItem.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
+}
 
-    public static func write(_ value: CryptoError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeUrlMatchRule: FfiConverterRustBuffer {
+    typealias SwiftType = UrlMatchRule?
 
-        
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeUrlMatchRule.write(value, into: &buf)
+    }
 
-        
-        
-        case .Argon2:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .Encryption:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .Decryption:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .InvalidSecretKey:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .InvalidKeyLength:
-            writeInt(&buf, Int32(5))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeUrlMatchRule.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]?
 
-extension CryptoError: Equatable, Hashable {}
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceString.write(value, into: &buf)
+    }
 
-extension CryptoError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
 
-public enum SyncError {
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
 
-    
-    
-    case Http
-    case Auth
-    case Conflict
-    case Parse
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSyncError: FfiConverterRustBuffer {
-    typealias SwiftType = SyncError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
+fileprivate struct FfiConverterSequenceTypeBackupAttachment: FfiConverterRustBuffer {
+    typealias SwiftType = [BackupAttachment]
 
-        
-        case 1: return .Http
-        case 2: return .Auth
-        case 3: return .Conflict
-        case 4: return .Parse
+    public static func write(_ value: [BackupAttachment], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBackupAttachment.write(item, into: &buf)
+        }
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BackupAttachment] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [BackupAttachment]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBackupAttachment.read(from: &buf))
         }
+        return seq
     }
+}
 
-    public static func write(_ value: SyncError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeBatchItemResult: FfiConverterRustBuffer {
+    typealias SwiftType = [BatchItemResult]
 
-        
+    public static func write(_ value: [BatchItemResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBatchItemResult.write(item, into: &buf)
+        }
+    }
 
-        
-        
-        case .Http:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .Auth:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .Conflict:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .Parse:
-            writeInt(&buf, Int32(4))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BatchItemResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [BatchItemResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBatchItemResult.read(from: &buf))
         }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCrackTime: FfiConverterRustBuffer {
+    typealias SwiftType = [CrackTime]
 
-extension SyncError: Equatable, Hashable {}
+    public static func write(_ value: [CrackTime], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCrackTime.write(item, into: &buf)
+        }
+    }
 
-extension SyncError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CrackTime] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CrackTime]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCrackTime.read(from: &buf))
+        }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFolder: FfiConverterRustBuffer {
+    typealias SwiftType = [Folder]
 
-public enum VaultError {
+    public static func write(_ value: [Folder], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFolder.write(item, into: &buf)
+        }
+    }
 
-    
-    
-    case NotFound
-    case Serialization
-    case Crypto
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Folder] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Folder]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFolder.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVaultError: FfiConverterRustBuffer {
-    typealias SwiftType = VaultError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VaultError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+fileprivate struct FfiConverterSequenceTypeItemAudit: FfiConverterRustBuffer {
+    typealias SwiftType = [ItemAudit]
 
-        
-
-        
-        case 1: return .NotFound
-        case 2: return .Serialization
-        case 3: return .Crypto
+    public static func write(_ value: [ItemAudit], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeItemAudit.write(item, into: &buf)
+        }
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ItemAudit] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ItemAudit]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeItemAudit.read(from: &buf))
         }
+        return seq
     }
+}
 
-    public static func write(_ value: VaultError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeLoginUrl: FfiConverterRustBuffer {
+    typealias SwiftType = [LoginUrl]
 
-        
+    public static func write(_ value: [LoginUrl], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLoginUrl.write(item, into: &buf)
+        }
+    }
 
-        
-        
-        case .NotFound:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .Serialization:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .Crypto:
-            writeInt(&buf, Int32(3))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LoginUrl] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LoginUrl]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLoginUrl.read(from: &buf))
         }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeLoginUrls: FfiConverterRustBuffer {
+    typealias SwiftType = [LoginUrls]
 
-extension VaultError: Equatable, Hashable {}
+    public static func write(_ value: [LoginUrls], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLoginUrls.write(item, into: &buf)
+        }
+    }
 
-extension VaultError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LoginUrls] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LoginUrls]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLoginUrls.read(from: &buf))
+        }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
-    typealias SwiftType = Bool?
+fileprivate struct FfiConverterSequenceTypeOtpImportCandidate: FfiConverterRustBuffer {
+    typealias SwiftType = [OtpImportCandidate]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [OtpImportCandidate], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOtpImportCandidate.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterBool.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterBool.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OtpImportCandidate] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OtpImportCandidate]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOtpImportCandidate.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypeReusedGroup: FfiConverterRustBuffer {
+    typealias SwiftType = [ReusedGroup]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [ReusedGroup], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeReusedGroup.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ReusedGroup] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ReusedGroup]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeReusedGroup.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
-    typealias SwiftType = Data?
+fileprivate struct FfiConverterSequenceTypeStrengthMatch: FfiConverterRustBuffer {
+    typealias SwiftType = [StrengthMatch]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [StrengthMatch], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeStrengthMatch.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterData.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterData.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [StrengthMatch] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [StrengthMatch]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeStrengthMatch.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeVaultItem: FfiConverterRustBuffer {
-    typealias SwiftType = VaultItem?
+fileprivate struct FfiConverterSequenceTypeUrlMatch: FfiConverterRustBuffer {
+    typealias SwiftType = [UrlMatch]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [UrlMatch], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUrlMatch.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeVaultItem.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeVaultItem.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UrlMatch] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UrlMatch]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUrlMatch.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]?
+fileprivate struct FfiConverterSequenceTypeVaultItem: FfiConverterRustBuffer {
+    typealias SwiftType = [VaultItem]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [VaultItem], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeVaultItem.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterSequenceString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterSequenceString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [VaultItem] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [VaultItem]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeVaultItem.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeBatchOp: FfiConverterRustBuffer {
+    typealias SwiftType = [BatchOp]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [BatchOp], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeBatchOp.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BatchOp] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [BatchOp]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeBatchOp.read(from: &buf))
         }
         return seq
     }
@@ -1451,27 +6010,35 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeVaultItem: FfiConverterRustBuffer {
-    typealias SwiftType = [VaultItem]
+fileprivate struct FfiConverterSequenceTypePendingChange: FfiConverterRustBuffer {
+    typealias SwiftType = [PendingChange]
 
-    public static func write(_ value: [VaultItem], into buf: inout [UInt8]) {
+    public static func write(_ value: [PendingChange], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeVaultItem.write(item, into: &buf)
+            FfiConverterTypePendingChange.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [VaultItem] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PendingChange] {
         let len: Int32 = try readInt(&buf)
-        var seq = [VaultItem]()
+        var seq = [PendingChange]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeVaultItem.read(from: &buf))
+            seq.append(try FfiConverterTypePendingChange.read(from: &buf))
         }
         return seq
     }
 }
+public func checkBreachedPassword(source: BreachSource, password: String)throws  -> BreachResult {
+    return try  FfiConverterTypeBreachResult.lift(try rustCallWithError(FfiConverterTypeBreachError.lift) {
+    uniffi_noro_mobile_core_fn_func_check_breached_password(
+        FfiConverterTypeBreachSource.lower(source),
+        FfiConverterString.lower(password),$0
+    )
+})
+}
 public func decrypt(ciphertext: Data, key: Data)throws  -> Data {
     return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeCryptoError.lift) {
     uniffi_noro_mobile_core_fn_func_decrypt(
@@ -1505,12 +6072,27 @@ public func encrypt(plaintext: Data, key: Data)throws  -> Data {
     )
 })
 }
+public func estimatePasswordStrength(password: String, userInputs: [String]) -> PasswordStrength {
+    return try!  FfiConverterTypePasswordStrength.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_estimate_password_strength(
+        FfiConverterString.lower(password),
+        FfiConverterSequenceString.lower(userInputs),$0
+    )
+})
+}
 public func generateItemId() -> String {
     return try!  FfiConverterString.lift(try! rustCall() {
     uniffi_noro_mobile_core_fn_func_generate_item_id($0
     )
 })
 }
+public func generatePassword(options: GeneratorOptions)throws  -> GeneratorResult {
+    return try  FfiConverterTypeGeneratorResult.lift(try rustCallWithError(FfiConverterTypeGeneratorError.lift) {
+    uniffi_noro_mobile_core_fn_func_generate_password(
+        FfiConverterTypeGeneratorOptions.lower(options),$0
+    )
+})
+}
 public func generateSalt() -> Data {
     return try!  FfiConverterData.lift(try! rustCall() {
     uniffi_noro_mobile_core_fn_func_generate_salt($0
@@ -1529,6 +6111,77 @@ public func generateVaultKey() -> Data {
     )
 })
 }
+public func matchUrl(pageUrl: String, logins: [LoginUrls]) -> [UrlMatch] {
+    return try!  FfiConverterSequenceTypeUrlMatch.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_match_url(
+        FfiConverterString.lower(pageUrl),
+        FfiConverterSequenceTypeLoginUrls.lower(logins),$0
+    )
+})
+}
+public func otpCode(config: OtpConfig, timestamp: UInt64?)throws  -> OtpCode {
+    return try  FfiConverterTypeOtpCode.lift(try rustCallWithError(FfiConverterTypeOtpError.lift) {
+    uniffi_noro_mobile_core_fn_func_otp_code(
+        FfiConverterTypeOtpConfig.lower(config),
+        FfiConverterOptionUInt64.lower(timestamp),$0
+    )
+})
+}
+public func otpFromItem(data: Data)throws  -> OtpConfig? {
+    return try  FfiConverterOptionTypeOtpConfig.lift(try rustCallWithError(FfiConverterTypeOtpError.lift) {
+    uniffi_noro_mobile_core_fn_func_otp_from_item(
+        FfiConverterData.lower(data),$0
+    )
+})
+}
+public func otpItemData(config: OtpConfig) -> Data {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_otp_item_data(
+        FfiConverterTypeOtpConfig.lower(config),$0
+    )
+})
+}
+public func otpUri(config: OtpConfig) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_otp_uri(
+        FfiConverterTypeOtpConfig.lower(config),$0
+    )
+})
+}
+public func parseOtp(input: String)throws  -> OtpConfig {
+    return try  FfiConverterTypeOtpConfig.lift(try rustCallWithError(FfiConverterTypeOtpError.lift) {
+    uniffi_noro_mobile_core_fn_func_parse_otp(
+        FfiConverterString.lower(input),$0
+    )
+})
+}
+public func parseOtpImport(text: String) -> OtpImportPreview {
+    return try!  FfiConverterTypeOtpImportPreview.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_parse_otp_import(
+        FfiConverterString.lower(text),$0
+    )
+})
+}
+public func readBackupManifest(archive: Data)throws  -> BackupManifest {
+    return try  FfiConverterTypeBackupManifest.lift(try rustCallWithError(FfiConverterTypeBackupError.lift) {
+    uniffi_noro_mobile_core_fn_func_read_backup_manifest(
+        FfiConverterData.lower(archive),$0
+    )
+})
+}
+public func registrableDomain(url: String) -> String? {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_registrable_domain(
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+public func trashRetentionDays() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_noro_mobile_core_fn_func_trash_retention_days($0
+    )
+})
+}
 public func unwrapVaultKey(wrapped: Data, auk: Data)throws  -> Data {
     return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeCryptoError.lift) {
     uniffi_noro_mobile_core_fn_func_unwrap_vault_key(
@@ -1561,6 +6214,9 @@ private var initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_func_check_breached_password() != 3378) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_func_decrypt() != 32839) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -1573,9 +6229,15 @@ private var initializationResult: InitializationResult = {
     if (uniffi_noro_mobile_core_checksum_func_encrypt() != 46133) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_func_estimate_password_strength() != 20836) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_func_generate_item_id() != 22417) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_func_generate_password() != 20375) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_func_generate_salt() != 31865) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -1585,51 +6247,243 @@ private var initializationResult: InitializationResult = {
     if (uniffi_noro_mobile_core_checksum_func_generate_vault_key() != 50386) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_func_match_url() != 36827) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_otp_code() != 61123) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_otp_from_item() != 19567) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_otp_item_data() != 24438) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_otp_uri() != 41535) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_parse_otp() != 59386) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_parse_otp_import() != 2293) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_read_backup_manifest() != 45584) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_registrable_domain() != 49842) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_func_trash_retention_days() != 31188) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_func_unwrap_vault_key() != 38585) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_noro_mobile_core_checksum_func_wrap_vault_key() != 17122) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_batch() != 10730) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_create_folder() != 44035) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_syncclient_create_item() != 51847) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_delete_folder() != 16292) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_syncclient_delete_item() != 22816) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_empty_trash() != 51922) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_fetch_folders() != 25976) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_syncclient_fetch_items() != 53777) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_fetch_trash() != 28258) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_is_locked() != 58953) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_lock() != 45168) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_syncclient_login() != 19049) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_move_folder() != 45594) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_move_items() != 57810) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_pending_changes() != 48051) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_purge_item() != 23732) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_queue_change() != 14986) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_request_sync() != 6274) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_resolve_sync_conflict() != 54286) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_restore_item() != 43560) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_syncclient_set_token() != 22416) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_start_background_sync() != 15057) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_stop_background_sync() != 48056) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_unlock() != 35563) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_unlock_with_key() != 45254) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_syncclient_update_folder() != 56843) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_syncclient_update_item() != 45106) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_started() != 53438) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_progress() != 9787) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_completed() != 25949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_conflict() != 59857) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_rejected() != 28494) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_connection() != 54082) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_apply_remote() != 35835) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_audit() != 50508) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_create_folder() != 59998) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_create_item() != 60480) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_delete_folder() != 57648) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_delete_item() != 63195) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_empty_trash() != 65212) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_export_backup() != 59750) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_export_plaintext() != 40395) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_folder_children() != 12955) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_folder_descendants() != 1244) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_folder_items() != 20450) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_folder_path() != 54054) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_get_item() != 55932) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_import_backup() != 2687) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_import_otp() != 18008) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_list_folders() != 11150) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_list_items() != 61447) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_list_trash() != 61971) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_load() != 6499) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_lock() != 17287) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_match_url() != 57037) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_move_folder() != 53951) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_move_item() != 15719) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_otp_code() != 45180) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_preview_otp_import() != 44635) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_purge_expired() != 10347) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_purge_item() != 34774) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_restore_item() != 58558) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_save() != 50962) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_search() != 205) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_search_items() != 42826) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_noro_mobile_core_checksum_method_vault_set_folders() != 54506) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_noro_mobile_core_checksum_method_vault_update_folder() != 51941) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_noro_mobile_core_checksum_method_vault_update_item() != 55533) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -1640,6 +6494,7 @@ private var initializationResult: InitializationResult = {
         return InitializationResult.apiChecksumMismatch
     }
 
+    uniffiCallbackInitSyncListener()
     return InitializationResult.ok
 }()
 
@@ -250,6 +250,61 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD0
+typedef void (*UniffiCallbackInterfaceSyncListenerMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD1
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD1
+typedef void (*UniffiCallbackInterfaceSyncListenerMethod1)(uint64_t, RustBuffer, uint32_t, uint32_t, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD2
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD2
+typedef void (*UniffiCallbackInterfaceSyncListenerMethod2)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD3
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD3
+typedef void (*UniffiCallbackInterfaceSyncListenerMethod3)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD4
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD4
+typedef void (*UniffiCallbackInterfaceSyncListenerMethod4)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD5
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_SYNC_LISTENER_METHOD5
+typedef void (*UniffiCallbackInterfaceSyncListenerMethod5)(uint64_t, int8_t, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_SYNC_LISTENER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_SYNC_LISTENER
+typedef struct UniffiVTableCallbackInterfaceSyncListener {
+    UniffiCallbackInterfaceSyncListenerMethod0 _Nonnull onSyncStarted;
+    UniffiCallbackInterfaceSyncListenerMethod1 _Nonnull onSyncProgress;
+    UniffiCallbackInterfaceSyncListenerMethod2 _Nonnull onSyncCompleted;
+    UniffiCallbackInterfaceSyncListenerMethod3 _Nonnull onSyncConflict;
+    UniffiCallbackInterfaceSyncListenerMethod4 _Nonnull onSyncRejected;
+    UniffiCallbackInterfaceSyncListenerMethod5 _Nonnull onSyncConnection;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceSyncListener;
+
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CLONE_SYNCCLIENT
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CLONE_SYNCCLIENT
@@ -266,36 +321,186 @@ void uniffi_noro_mobile_core_fn_free_syncclient(void*_Nonnull ptr, RustCallStatu
 void*_Nonnull uniffi_noro_mobile_core_fn_constructor_syncclient_new(RustBuffer base_url, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_BATCH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_BATCH
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_batch(void*_Nonnull ptr, RustBuffer ops, RustBuffer listener, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_CREATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_CREATE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_create_folder(void*_Nonnull ptr, RustBuffer name, RustBuffer parent_id, RustBuffer color, RustBuffer icon, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_CREATE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_CREATE_ITEM
 RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_create_item(void*_Nonnull ptr, RustBuffer item_type, RustBuffer title, RustBuffer data, RustBuffer tags, int8_t favorite, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_DELETE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_DELETE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_delete_folder(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_DELETE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_DELETE_ITEM
 void uniffi_noro_mobile_core_fn_method_syncclient_delete_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_EMPTY_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_EMPTY_TRASH
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_empty_trash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_FETCH_FOLDERS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_FETCH_FOLDERS
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_fetch_folders(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_FETCH_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_FETCH_ITEMS
 RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_fetch_items(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_FETCH_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_FETCH_TRASH
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_fetch_trash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_IS_LOCKED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_IS_LOCKED
+int8_t uniffi_noro_mobile_core_fn_method_syncclient_is_locked(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_LOCK
+void uniffi_noro_mobile_core_fn_method_syncclient_lock(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_LOGIN
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_LOGIN
 RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_login(void*_Nonnull ptr, RustBuffer email, RustBuffer password, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_MOVE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_MOVE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_move_folder(void*_Nonnull ptr, RustBuffer id, RustBuffer parent_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_MOVE_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_MOVE_ITEMS
+uint32_t uniffi_noro_mobile_core_fn_method_syncclient_move_items(void*_Nonnull ptr, RustBuffer folder_id, RustBuffer item_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_PENDING_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_PENDING_CHANGES
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_pending_changes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_PURGE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_PURGE_ITEM
+void uniffi_noro_mobile_core_fn_method_syncclient_purge_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_QUEUE_CHANGE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_QUEUE_CHANGE
+void uniffi_noro_mobile_core_fn_method_syncclient_queue_change(void*_Nonnull ptr, RustBuffer change, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_REQUEST_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_REQUEST_SYNC
+void uniffi_noro_mobile_core_fn_method_syncclient_request_sync(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_RESOLVE_SYNC_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_RESOLVE_SYNC_CONFLICT
+void uniffi_noro_mobile_core_fn_method_syncclient_resolve_sync_conflict(void*_Nonnull ptr, RustBuffer id, int8_t keep_local, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_RESTORE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_RESTORE_ITEM
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_restore_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_SET_TOKEN
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_SET_TOKEN
 void uniffi_noro_mobile_core_fn_method_syncclient_set_token(void*_Nonnull ptr, RustBuffer token, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_START_BACKGROUND_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_START_BACKGROUND_SYNC
+void uniffi_noro_mobile_core_fn_method_syncclient_start_background_sync(void*_Nonnull ptr, void*_Nonnull listener, uint64_t interval_secs, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_STOP_BACKGROUND_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_STOP_BACKGROUND_SYNC
+void uniffi_noro_mobile_core_fn_method_syncclient_stop_background_sync(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UNLOCK
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UNLOCK
+void uniffi_noro_mobile_core_fn_method_syncclient_unlock(void*_Nonnull ptr, RustBuffer password, RustBuffer secret_key, RustBuffer salt, RustBuffer wrapped_vault_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UNLOCK_WITH_KEY
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UNLOCK_WITH_KEY
+void uniffi_noro_mobile_core_fn_method_syncclient_unlock_with_key(void*_Nonnull ptr, RustBuffer vault_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UPDATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UPDATE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_update_folder(void*_Nonnull ptr, RustBuffer id, RustBuffer name, RustBuffer color, RustBuffer icon, RustBuffer order, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UPDATE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCCLIENT_UPDATE_ITEM
 RustBuffer uniffi_noro_mobile_core_fn_method_syncclient_update_item(void*_Nonnull ptr, RustBuffer id, RustBuffer title, RustBuffer data, RustBuffer tags, RustBuffer favorite, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CLONE_SYNCLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CLONE_SYNCLISTENER
+void*_Nonnull uniffi_noro_mobile_core_fn_clone_synclistener(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FREE_SYNCLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FREE_SYNCLISTENER
+void uniffi_noro_mobile_core_fn_free_synclistener(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_INIT_CALLBACK_VTABLE_SYNCLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_INIT_CALLBACK_VTABLE_SYNCLISTENER
+void uniffi_noro_mobile_core_fn_init_callback_vtable_synclistener(UniffiVTableCallbackInterfaceSyncListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_STARTED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_STARTED
+void uniffi_noro_mobile_core_fn_method_synclistener_on_sync_started(void*_Nonnull ptr, RustBuffer reason, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_PROGRESS
+void uniffi_noro_mobile_core_fn_method_synclistener_on_sync_progress(void*_Nonnull ptr, RustBuffer phase, uint32_t done, uint32_t total, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_COMPLETED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_COMPLETED
+void uniffi_noro_mobile_core_fn_method_synclistener_on_sync_completed(void*_Nonnull ptr, RustBuffer result, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_CONFLICT
+void uniffi_noro_mobile_core_fn_method_synclistener_on_sync_conflict(void*_Nonnull ptr, RustBuffer conflict, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_REJECTED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_REJECTED
+void uniffi_noro_mobile_core_fn_method_synclistener_on_sync_rejected(void*_Nonnull ptr, RustBuffer change, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_CONNECTION
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_SYNCLISTENER_ON_SYNC_CONNECTION
+void uniffi_noro_mobile_core_fn_method_synclistener_on_sync_connection(void*_Nonnull ptr, int8_t connected, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CLONE_VAULT
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CLONE_VAULT
 void*_Nonnull uniffi_noro_mobile_core_fn_clone_vault(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -310,6 +515,21 @@ void uniffi_noro_mobile_core_fn_free_vault(void*_Nonnull ptr, RustCallStatus *_N
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_CONSTRUCTOR_VAULT_NEW
 void*_Nonnull uniffi_noro_mobile_core_fn_constructor_vault_new(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_APPLY_REMOTE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_APPLY_REMOTE
+void uniffi_noro_mobile_core_fn_method_vault_apply_remote(void*_Nonnull ptr, RustBuffer changed, RustBuffer removed, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_AUDIT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_AUDIT
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_audit(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_CREATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_CREATE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_create_folder(void*_Nonnull ptr, RustBuffer name, RustBuffer parent_id, RustBuffer color, RustBuffer icon, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_CREATE_ITEM
@@ -317,41 +537,166 @@ void*_Nonnull uniffi_noro_mobile_core_fn_constructor_vault_new(RustCallStatus *_
 RustBuffer uniffi_noro_mobile_core_fn_method_vault_create_item(void*_Nonnull ptr, RustBuffer item_type, RustBuffer title, RustBuffer data, RustBuffer tags, int8_t favorite, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_DELETE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_DELETE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_delete_folder(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_DELETE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_DELETE_ITEM
 void uniffi_noro_mobile_core_fn_method_vault_delete_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_EMPTY_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_EMPTY_TRASH
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_empty_trash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_EXPORT_BACKUP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_EXPORT_BACKUP
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_export_backup(void*_Nonnull ptr, RustBuffer password, RustBuffer attachments, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_EXPORT_PLAINTEXT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_EXPORT_PLAINTEXT
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_export_plaintext(void*_Nonnull ptr, RustBuffer format, RustBuffer password, RustBuffer secret_key, RustBuffer salt, RustBuffer wrapped_vault_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_CHILDREN
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_CHILDREN
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_folder_children(void*_Nonnull ptr, RustBuffer parent_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_DESCENDANTS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_DESCENDANTS
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_folder_descendants(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_ITEMS
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_folder_items(void*_Nonnull ptr, RustBuffer folder_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_PATH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_FOLDER_PATH
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_folder_path(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_GET_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_GET_ITEM
 RustBuffer uniffi_noro_mobile_core_fn_method_vault_get_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_IMPORT_BACKUP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_IMPORT_BACKUP
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_import_backup(void*_Nonnull ptr, RustBuffer archive, RustBuffer password, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_IMPORT_OTP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_IMPORT_OTP
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_import_otp(void*_Nonnull ptr, RustBuffer text, int8_t skip_duplicates, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LIST_FOLDERS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LIST_FOLDERS
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_list_folders(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LIST_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LIST_ITEMS
 RustBuffer uniffi_noro_mobile_core_fn_method_vault_list_items(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LIST_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LIST_TRASH
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_list_trash(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LOAD
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LOAD
 void uniffi_noro_mobile_core_fn_method_vault_load(void*_Nonnull ptr, RustBuffer encrypted, RustBuffer key, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_LOCK
+void uniffi_noro_mobile_core_fn_method_vault_lock(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_MATCH_URL
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_MATCH_URL
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_match_url(void*_Nonnull ptr, RustBuffer page_url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_MOVE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_MOVE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_move_folder(void*_Nonnull ptr, RustBuffer id, RustBuffer parent_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_MOVE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_MOVE_ITEM
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_move_item(void*_Nonnull ptr, RustBuffer id, RustBuffer folder_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_OTP_CODE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_OTP_CODE
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_otp_code(void*_Nonnull ptr, RustBuffer id, RustBuffer timestamp, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_PREVIEW_OTP_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_PREVIEW_OTP_IMPORT
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_preview_otp_import(void*_Nonnull ptr, RustBuffer text, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_PURGE_EXPIRED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_PURGE_EXPIRED
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_purge_expired(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_PURGE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_PURGE_ITEM
+void uniffi_noro_mobile_core_fn_method_vault_purge_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_RESTORE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_RESTORE_ITEM
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_restore_item(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SAVE
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SAVE
 RustBuffer uniffi_noro_mobile_core_fn_method_vault_save(void*_Nonnull ptr, RustBuffer key, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SEARCH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SEARCH
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_search(void*_Nonnull ptr, RustBuffer query, uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SEARCH_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SEARCH_ITEMS
 RustBuffer uniffi_noro_mobile_core_fn_method_vault_search_items(void*_Nonnull ptr, RustBuffer query, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SET_FOLDERS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_SET_FOLDERS
+void uniffi_noro_mobile_core_fn_method_vault_set_folders(void*_Nonnull ptr, RustBuffer folders, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_UPDATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_UPDATE_FOLDER
+RustBuffer uniffi_noro_mobile_core_fn_method_vault_update_folder(void*_Nonnull ptr, RustBuffer id, RustBuffer name, RustBuffer color, RustBuffer icon, RustBuffer order, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_UPDATE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_METHOD_VAULT_UPDATE_ITEM
 RustBuffer uniffi_noro_mobile_core_fn_method_vault_update_item(void*_Nonnull ptr, RustBuffer id, RustBuffer title, RustBuffer data, RustBuffer tags, RustBuffer favorite, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_CHECK_BREACHED_PASSWORD
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_CHECK_BREACHED_PASSWORD
+RustBuffer uniffi_noro_mobile_core_fn_func_check_breached_password(RustBuffer source, RustBuffer password, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_DECRYPT
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_DECRYPT
 RustBuffer uniffi_noro_mobile_core_fn_func_decrypt(RustBuffer ciphertext, RustBuffer key, RustCallStatus *_Nonnull out_status
@@ -372,10 +717,20 @@ RustBuffer uniffi_noro_mobile_core_fn_func_derive_item_key(RustBuffer vault_key,
 RustBuffer uniffi_noro_mobile_core_fn_func_encrypt(RustBuffer plaintext, RustBuffer key, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_ESTIMATE_PASSWORD_STRENGTH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_ESTIMATE_PASSWORD_STRENGTH
+RustBuffer uniffi_noro_mobile_core_fn_func_estimate_password_strength(RustBuffer password, RustBuffer user_inputs, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_GENERATE_ITEM_ID
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_GENERATE_ITEM_ID
 RustBuffer uniffi_noro_mobile_core_fn_func_generate_item_id(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_GENERATE_PASSWORD
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_GENERATE_PASSWORD
+RustBuffer uniffi_noro_mobile_core_fn_func_generate_password(RustBuffer options, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_GENERATE_SALT
@@ -394,6 +749,57 @@ RustBuffer uniffi_noro_mobile_core_fn_func_generate_secret_key(RustCallStatus *_
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_GENERATE_VAULT_KEY
 RustBuffer uniffi_noro_mobile_core_fn_func_generate_vault_key(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_MATCH_URL
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_MATCH_URL
+RustBuffer uniffi_noro_mobile_core_fn_func_match_url(RustBuffer page_url, RustBuffer logins, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_CODE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_CODE
+RustBuffer uniffi_noro_mobile_core_fn_func_otp_code(RustBuffer config, RustBuffer timestamp, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_FROM_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_FROM_ITEM
+RustBuffer uniffi_noro_mobile_core_fn_func_otp_from_item(RustBuffer data, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_ITEM_DATA
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_ITEM_DATA
+RustBuffer uniffi_noro_mobile_core_fn_func_otp_item_data(RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_URI
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_OTP_URI
+RustBuffer uniffi_noro_mobile_core_fn_func_otp_uri(RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_PARSE_OTP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_PARSE_OTP
+RustBuffer uniffi_noro_mobile_core_fn_func_parse_otp(RustBuffer input, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_PARSE_OTP_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_PARSE_OTP_IMPORT
+RustBuffer uniffi_noro_mobile_core_fn_func_parse_otp_import(RustBuffer text, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_READ_BACKUP_MANIFEST
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_READ_BACKUP_MANIFEST
+RustBuffer uniffi_noro_mobile_core_fn_func_read_backup_manifest(RustBuffer archive, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_REGISTRABLE_DOMAIN
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_REGISTRABLE_DOMAIN
+RustBuffer uniffi_noro_mobile_core_fn_func_registrable_domain(RustBuffer url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_TRASH_RETENTION_DAYS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_TRASH_RETENTION_DAYS
+uint64_t uniffi_noro_mobile_core_fn_func_trash_retention_days(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_FN_FUNC_UNWRAP_VAULT_KEY
@@ -684,6 +1090,12 @@ void ffi_noro_mobile_core_rust_future_free_void(uint64_t handle
 #ifndef UNIFFI_FFIDEF_FFI_NORO_MOBILE_CORE_RUST_FUTURE_COMPLETE_VOID
 #define UNIFFI_FFIDEF_FFI_NORO_MOBILE_CORE_RUST_FUTURE_COMPLETE_VOID
 void ffi_noro_mobile_core_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_CHECK_BREACHED_PASSWORD
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_CHECK_BREACHED_PASSWORD
+uint16_t uniffi_noro_mobile_core_checksum_func_check_breached_password(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_DECRYPT
@@ -708,12 +1120,24 @@ uint16_t uniffi_noro_mobile_core_checksum_func_derive_item_key(void
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_ENCRYPT
 uint16_t uniffi_noro_mobile_core_checksum_func_encrypt(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_ESTIMATE_PASSWORD_STRENGTH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_ESTIMATE_PASSWORD_STRENGTH
+uint16_t uniffi_noro_mobile_core_checksum_func_estimate_password_strength(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_GENERATE_ITEM_ID
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_GENERATE_ITEM_ID
 uint16_t uniffi_noro_mobile_core_checksum_func_generate_item_id(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_GENERATE_PASSWORD
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_GENERATE_PASSWORD
+uint16_t uniffi_noro_mobile_core_checksum_func_generate_password(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_GENERATE_SALT
@@ -732,6 +1156,66 @@ uint16_t uniffi_noro_mobile_core_checksum_func_generate_secret_key(void
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_GENERATE_VAULT_KEY
 uint16_t uniffi_noro_mobile_core_checksum_func_generate_vault_key(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_MATCH_URL
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_MATCH_URL
+uint16_t uniffi_noro_mobile_core_checksum_func_match_url(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_CODE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_CODE
+uint16_t uniffi_noro_mobile_core_checksum_func_otp_code(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_FROM_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_FROM_ITEM
+uint16_t uniffi_noro_mobile_core_checksum_func_otp_from_item(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_ITEM_DATA
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_ITEM_DATA
+uint16_t uniffi_noro_mobile_core_checksum_func_otp_item_data(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_URI
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_OTP_URI
+uint16_t uniffi_noro_mobile_core_checksum_func_otp_uri(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_PARSE_OTP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_PARSE_OTP
+uint16_t uniffi_noro_mobile_core_checksum_func_parse_otp(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_PARSE_OTP_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_PARSE_OTP_IMPORT
+uint16_t uniffi_noro_mobile_core_checksum_func_parse_otp_import(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_READ_BACKUP_MANIFEST
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_READ_BACKUP_MANIFEST
+uint16_t uniffi_noro_mobile_core_checksum_func_read_backup_manifest(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_REGISTRABLE_DOMAIN
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_REGISTRABLE_DOMAIN
+uint16_t uniffi_noro_mobile_core_checksum_func_registrable_domain(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_TRASH_RETENTION_DAYS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_TRASH_RETENTION_DAYS
+uint16_t uniffi_noro_mobile_core_checksum_func_trash_retention_days(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_UNWRAP_VAULT_KEY
@@ -744,84 +1228,408 @@ uint16_t uniffi_noro_mobile_core_checksum_func_unwrap_vault_key(void
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_FUNC_WRAP_VAULT_KEY
 uint16_t uniffi_noro_mobile_core_checksum_func_wrap_vault_key(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_BATCH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_BATCH
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_batch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_CREATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_CREATE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_create_folder(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_CREATE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_CREATE_ITEM
 uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_create_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_DELETE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_DELETE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_delete_folder(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_DELETE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_DELETE_ITEM
 uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_delete_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_EMPTY_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_EMPTY_TRASH
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_empty_trash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_FETCH_FOLDERS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_FETCH_FOLDERS
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_fetch_folders(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_FETCH_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_FETCH_ITEMS
 uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_fetch_items(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_FETCH_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_FETCH_TRASH
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_fetch_trash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_IS_LOCKED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_IS_LOCKED
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_is_locked(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_LOCK
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_lock(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_LOGIN
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_LOGIN
 uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_login(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_MOVE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_MOVE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_move_folder(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_MOVE_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_MOVE_ITEMS
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_move_items(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_PENDING_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_PENDING_CHANGES
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_pending_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_PURGE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_PURGE_ITEM
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_purge_item(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_QUEUE_CHANGE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_QUEUE_CHANGE
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_queue_change(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_REQUEST_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_REQUEST_SYNC
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_request_sync(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_RESOLVE_SYNC_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_RESOLVE_SYNC_CONFLICT
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_resolve_sync_conflict(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_RESTORE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_RESTORE_ITEM
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_restore_item(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_SET_TOKEN
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_SET_TOKEN
 uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_set_token(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_START_BACKGROUND_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_START_BACKGROUND_SYNC
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_start_background_sync(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_STOP_BACKGROUND_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_STOP_BACKGROUND_SYNC
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_stop_background_sync(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UNLOCK
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UNLOCK
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_unlock(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UNLOCK_WITH_KEY
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UNLOCK_WITH_KEY
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_unlock_with_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UPDATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UPDATE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_update_folder(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UPDATE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCCLIENT_UPDATE_ITEM
 uint16_t uniffi_noro_mobile_core_checksum_method_syncclient_update_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_STARTED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_STARTED
+uint16_t uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_started(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_PROGRESS
+uint16_t uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_COMPLETED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_COMPLETED
+uint16_t uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_completed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_CONFLICT
+uint16_t uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_conflict(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_REJECTED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_REJECTED
+uint16_t uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_rejected(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_CONNECTION
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_SYNCLISTENER_ON_SYNC_CONNECTION
+uint16_t uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_connection(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_APPLY_REMOTE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_APPLY_REMOTE
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_apply_remote(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_AUDIT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_AUDIT
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_audit(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_CREATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_CREATE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_create_folder(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_CREATE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_CREATE_ITEM
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_create_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_DELETE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_DELETE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_delete_folder(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_DELETE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_DELETE_ITEM
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_delete_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_EMPTY_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_EMPTY_TRASH
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_empty_trash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_EXPORT_BACKUP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_EXPORT_BACKUP
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_export_backup(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_EXPORT_PLAINTEXT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_EXPORT_PLAINTEXT
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_export_plaintext(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_CHILDREN
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_CHILDREN
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_folder_children(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_DESCENDANTS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_DESCENDANTS
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_folder_descendants(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_ITEMS
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_folder_items(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_PATH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_FOLDER_PATH
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_folder_path(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_GET_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_GET_ITEM
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_get_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_IMPORT_BACKUP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_IMPORT_BACKUP
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_import_backup(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_IMPORT_OTP
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_IMPORT_OTP
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_import_otp(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LIST_FOLDERS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LIST_FOLDERS
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_list_folders(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LIST_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LIST_ITEMS
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_list_items(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LIST_TRASH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LIST_TRASH
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_list_trash(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LOAD
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LOAD
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_load(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_LOCK
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_lock(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_MATCH_URL
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_MATCH_URL
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_match_url(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_MOVE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_MOVE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_move_folder(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_MOVE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_MOVE_ITEM
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_move_item(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_OTP_CODE
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_OTP_CODE
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_otp_code(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_PREVIEW_OTP_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_PREVIEW_OTP_IMPORT
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_preview_otp_import(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_PURGE_EXPIRED
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_PURGE_EXPIRED
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_purge_expired(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_PURGE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_PURGE_ITEM
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_purge_item(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_RESTORE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_RESTORE_ITEM
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_restore_item(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SAVE
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SAVE
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_save(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SEARCH
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SEARCH
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_search(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SEARCH_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SEARCH_ITEMS
 uint16_t uniffi_noro_mobile_core_checksum_method_vault_search_items(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SET_FOLDERS
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_SET_FOLDERS
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_set_folders(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_UPDATE_FOLDER
+#define UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_UPDATE_FOLDER
+uint16_t uniffi_noro_mobile_core_checksum_method_vault_update_folder(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_NORO_MOBILE_CORE_CHECKSUM_METHOD_VAULT_UPDATE_ITEM
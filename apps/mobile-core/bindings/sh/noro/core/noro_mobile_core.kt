@@ -654,6 +654,192 @@ internal open class UniffiForeignFutureStructVoid(
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
     fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructVoid.UniffiByValue,)
 }
+internal interface UniffiCallbackInterfaceSyncListenerMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`reason`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceSyncListenerMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`phase`: RustBuffer.ByValue,`done`: Int,`total`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceSyncListenerMethod2 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`result`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceSyncListenerMethod3 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`conflict`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceSyncListenerMethod4 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`change`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceSyncListenerMethod5 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`connected`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+@Structure.FieldOrder("onSyncStarted", "onSyncProgress", "onSyncCompleted", "onSyncConflict", "onSyncRejected", "onSyncConnection", "uniffiFree")
+internal open class UniffiVTableCallbackInterfaceSyncListener(
+    @JvmField internal var `onSyncStarted`: UniffiCallbackInterfaceSyncListenerMethod0? = null,
+    @JvmField internal var `onSyncProgress`: UniffiCallbackInterfaceSyncListenerMethod1? = null,
+    @JvmField internal var `onSyncCompleted`: UniffiCallbackInterfaceSyncListenerMethod2? = null,
+    @JvmField internal var `onSyncConflict`: UniffiCallbackInterfaceSyncListenerMethod3? = null,
+    @JvmField internal var `onSyncRejected`: UniffiCallbackInterfaceSyncListenerMethod4? = null,
+    @JvmField internal var `onSyncConnection`: UniffiCallbackInterfaceSyncListenerMethod5? = null,
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+) : Structure() {
+    class UniffiByValue(
+        `onSyncStarted`: UniffiCallbackInterfaceSyncListenerMethod0? = null,
+        `onSyncProgress`: UniffiCallbackInterfaceSyncListenerMethod1? = null,
+        `onSyncCompleted`: UniffiCallbackInterfaceSyncListenerMethod2? = null,
+        `onSyncConflict`: UniffiCallbackInterfaceSyncListenerMethod3? = null,
+        `onSyncRejected`: UniffiCallbackInterfaceSyncListenerMethod4? = null,
+        `onSyncConnection`: UniffiCallbackInterfaceSyncListenerMethod5? = null,
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    ): UniffiVTableCallbackInterfaceSyncListener(`onSyncStarted`,`onSyncProgress`,`onSyncCompleted`,`onSyncConflict`,`onSyncRejected`,`onSyncConnection`,`uniffiFree`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceSyncListener) {
+        `onSyncStarted` = other.`onSyncStarted`
+        `onSyncProgress` = other.`onSyncProgress`
+        `onSyncCompleted` = other.`onSyncCompleted`
+        `onSyncConflict` = other.`onSyncConflict`
+        `onSyncRejected` = other.`onSyncRejected`
+        `onSyncConnection` = other.`onSyncConnection`
+        `uniffiFree` = other.`uniffiFree`
+    }
+
+}
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
 
 
 
@@ -778,6 +964,7 @@ internal interface UniffiLib : Library {
             .also { lib: UniffiLib ->
                 uniffiCheckContractApiVersion(lib)
                 uniffiCheckApiChecksums(lib)
+                uniffiCallbackInterfaceSyncListener.register(lib)
                 }
         }
         
@@ -793,40 +980,156 @@ internal interface UniffiLib : Library {
     ): Unit
     fun uniffi_noro_mobile_core_fn_constructor_syncclient_new(`baseUrl`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Pointer
+    fun uniffi_noro_mobile_core_fn_method_syncclient_batch(`ptr`: Pointer,`ops`: RustBuffer.ByValue,`listener`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_create_folder(`ptr`: Pointer,`name`: RustBuffer.ByValue,`parentId`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,`icon`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_syncclient_create_item(`ptr`: Pointer,`itemType`: RustBuffer.ByValue,`title`: RustBuffer.ByValue,`data`: RustBuffer.ByValue,`tags`: RustBuffer.ByValue,`favorite`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_delete_folder(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_syncclient_delete_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_empty_trash(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_fetch_folders(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_syncclient_fetch_items(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_fetch_trash(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_is_locked(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_noro_mobile_core_fn_method_syncclient_lock(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
     fun uniffi_noro_mobile_core_fn_method_syncclient_login(`ptr`: Pointer,`email`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_move_folder(`ptr`: Pointer,`id`: RustBuffer.ByValue,`parentId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_move_items(`ptr`: Pointer,`folderId`: RustBuffer.ByValue,`itemIds`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun uniffi_noro_mobile_core_fn_method_syncclient_pending_changes(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_syncclient_purge_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_queue_change(`ptr`: Pointer,`change`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_request_sync(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_resolve_sync_conflict(`ptr`: Pointer,`id`: RustBuffer.ByValue,`keepLocal`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_restore_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_syncclient_set_token(`ptr`: Pointer,`token`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_start_background_sync(`ptr`: Pointer,`listener`: Pointer,`intervalSecs`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_stop_background_sync(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_unlock(`ptr`: Pointer,`password`: RustBuffer.ByValue,`secretKey`: RustBuffer.ByValue,`salt`: RustBuffer.ByValue,`wrappedVaultKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_unlock_with_key(`ptr`: Pointer,`vaultKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_syncclient_update_folder(`ptr`: Pointer,`id`: RustBuffer.ByValue,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,`icon`: RustBuffer.ByValue,`order`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_syncclient_update_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,`title`: RustBuffer.ByValue,`data`: RustBuffer.ByValue,`tags`: RustBuffer.ByValue,`favorite`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_clone_synclistener(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Pointer
+    fun uniffi_noro_mobile_core_fn_free_synclistener(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_init_callback_vtable_synclistener(`vtable`: UniffiVTableCallbackInterfaceSyncListener,
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_synclistener_on_sync_started(`ptr`: Pointer,`reason`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_synclistener_on_sync_progress(`ptr`: Pointer,`phase`: RustBuffer.ByValue,`done`: Int,`total`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_synclistener_on_sync_completed(`ptr`: Pointer,`result`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_synclistener_on_sync_conflict(`ptr`: Pointer,`conflict`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_synclistener_on_sync_rejected(`ptr`: Pointer,`change`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_synclistener_on_sync_connection(`ptr`: Pointer,`connected`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
     fun uniffi_noro_mobile_core_fn_clone_vault(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
     ): Pointer
     fun uniffi_noro_mobile_core_fn_free_vault(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
     fun uniffi_noro_mobile_core_fn_constructor_vault_new(uniffi_out_err: UniffiRustCallStatus, 
     ): Pointer
+    fun uniffi_noro_mobile_core_fn_method_vault_apply_remote(`ptr`: Pointer,`changed`: RustBuffer.ByValue,`removed`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_vault_audit(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_create_folder(`ptr`: Pointer,`name`: RustBuffer.ByValue,`parentId`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,`icon`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_create_item(`ptr`: Pointer,`itemType`: RustBuffer.ByValue,`title`: RustBuffer.ByValue,`data`: RustBuffer.ByValue,`tags`: RustBuffer.ByValue,`favorite`: Byte,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_delete_folder(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_delete_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    fun uniffi_noro_mobile_core_fn_method_vault_empty_trash(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_export_backup(`ptr`: Pointer,`password`: RustBuffer.ByValue,`attachments`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_export_plaintext(`ptr`: Pointer,`format`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,`secretKey`: RustBuffer.ByValue,`salt`: RustBuffer.ByValue,`wrappedVaultKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_folder_children(`ptr`: Pointer,`parentId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_folder_descendants(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_folder_items(`ptr`: Pointer,`folderId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_folder_path(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_get_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_import_backup(`ptr`: Pointer,`archive`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_import_otp(`ptr`: Pointer,`text`: RustBuffer.ByValue,`skipDuplicates`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_list_folders(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_list_items(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_list_trash(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_load(`ptr`: Pointer,`encrypted`: RustBuffer.ByValue,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    fun uniffi_noro_mobile_core_fn_method_vault_lock(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_vault_match_url(`ptr`: Pointer,`pageUrl`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_move_folder(`ptr`: Pointer,`id`: RustBuffer.ByValue,`parentId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_move_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,`folderId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_otp_code(`ptr`: Pointer,`id`: RustBuffer.ByValue,`timestamp`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_preview_otp_import(`ptr`: Pointer,`text`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_purge_expired(`ptr`: Pointer,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_purge_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_vault_restore_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_save(`ptr`: Pointer,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_search(`ptr`: Pointer,`query`: RustBuffer.ByValue,`limit`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_search_items(`ptr`: Pointer,`query`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_method_vault_set_folders(`ptr`: Pointer,`folders`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_noro_mobile_core_fn_method_vault_update_folder(`ptr`: Pointer,`id`: RustBuffer.ByValue,`name`: RustBuffer.ByValue,`color`: RustBuffer.ByValue,`icon`: RustBuffer.ByValue,`order`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_method_vault_update_item(`ptr`: Pointer,`id`: RustBuffer.ByValue,`title`: RustBuffer.ByValue,`data`: RustBuffer.ByValue,`tags`: RustBuffer.ByValue,`favorite`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_check_breached_password(`source`: RustBuffer.ByValue,`password`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_decrypt(`ciphertext`: RustBuffer.ByValue,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_derive_auk(`password`: RustBuffer.ByValue,`secretKey`: RustBuffer.ByValue,`salt`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
@@ -835,14 +1138,38 @@ internal interface UniffiLib : Library {
     ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_encrypt(`plaintext`: RustBuffer.ByValue,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_estimate_password_strength(`password`: RustBuffer.ByValue,`userInputs`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_generate_item_id(uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_generate_password(`options`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_generate_salt(uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_generate_secret_key(uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_generate_vault_key(uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_match_url(`pageUrl`: RustBuffer.ByValue,`logins`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_otp_code(`config`: RustBuffer.ByValue,`timestamp`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_otp_from_item(`data`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_otp_item_data(`config`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_otp_uri(`config`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_parse_otp(`input`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_parse_otp_import(`text`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_read_backup_manifest(`archive`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_registrable_domain(`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_noro_mobile_core_fn_func_trash_retention_days(uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
     fun uniffi_noro_mobile_core_fn_func_unwrap_vault_key(`wrapped`: RustBuffer.ByValue,`auk`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
     fun uniffi_noro_mobile_core_fn_func_wrap_vault_key(`vaultKey`: RustBuffer.ByValue,`auk`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
@@ -959,6 +1286,8 @@ internal interface UniffiLib : Library {
     ): Unit
     fun ffi_noro_mobile_core_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    fun uniffi_noro_mobile_core_checksum_func_check_breached_password(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_func_decrypt(
     ): Short
     fun uniffi_noro_mobile_core_checksum_func_derive_auk(
@@ -967,54 +1296,186 @@ internal interface UniffiLib : Library {
     ): Short
     fun uniffi_noro_mobile_core_checksum_func_encrypt(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_func_estimate_password_strength(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_func_generate_item_id(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_func_generate_password(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_func_generate_salt(
     ): Short
     fun uniffi_noro_mobile_core_checksum_func_generate_secret_key(
     ): Short
     fun uniffi_noro_mobile_core_checksum_func_generate_vault_key(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_func_match_url(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_otp_code(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_otp_from_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_otp_item_data(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_otp_uri(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_parse_otp(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_parse_otp_import(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_read_backup_manifest(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_registrable_domain(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_func_trash_retention_days(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_func_unwrap_vault_key(
     ): Short
     fun uniffi_noro_mobile_core_checksum_func_wrap_vault_key(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_batch(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_create_folder(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_method_syncclient_create_item(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_delete_folder(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_method_syncclient_delete_item(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_empty_trash(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_fetch_folders(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_method_syncclient_fetch_items(
     ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_fetch_trash(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_is_locked(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_lock(
+    ): Short
     fun uniffi_noro_mobile_core_checksum_method_syncclient_login(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_syncclient_set_token(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_move_folder(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_syncclient_update_item(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_move_items(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_create_item(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_pending_changes(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_delete_item(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_purge_item(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_get_item(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_queue_change(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_list_items(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_request_sync(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_load(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_resolve_sync_conflict(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_save(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_restore_item(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_search_items(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_set_token(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_method_vault_update_item(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_start_background_sync(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_constructor_syncclient_new(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_stop_background_sync(
     ): Short
-    fun uniffi_noro_mobile_core_checksum_constructor_vault_new(
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_unlock(
     ): Short
-    fun ffi_noro_mobile_core_uniffi_contract_version(
-    ): Int
-    
-}
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_unlock_with_key(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_update_folder(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_syncclient_update_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_started(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_progress(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_completed(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_conflict(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_rejected(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_connection(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_apply_remote(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_audit(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_create_folder(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_create_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_delete_folder(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_delete_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_empty_trash(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_export_backup(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_export_plaintext(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_folder_children(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_folder_descendants(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_folder_items(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_folder_path(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_get_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_import_backup(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_import_otp(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_list_folders(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_list_items(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_list_trash(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_load(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_lock(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_match_url(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_move_folder(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_move_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_otp_code(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_preview_otp_import(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_purge_expired(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_purge_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_restore_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_save(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_search(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_search_items(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_set_folders(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_update_folder(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_method_vault_update_item(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_constructor_syncclient_new(
+    ): Short
+    fun uniffi_noro_mobile_core_checksum_constructor_vault_new(
+    ): Short
+    fun ffi_noro_mobile_core_uniffi_contract_version(
+    ): Int
+    
+}
 
 private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
     // Get the bindings contract version from our ComponentInterface
@@ -1028,6 +1489,9 @@ private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
 
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: UniffiLib) {
+    if (lib.uniffi_noro_mobile_core_checksum_func_check_breached_password() != 3378.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_func_decrypt() != 32839.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1040,9 +1504,15 @@ private fun uniffiCheckApiChecksums(lib: UniffiLib) {
     if (lib.uniffi_noro_mobile_core_checksum_func_encrypt() != 46133.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_func_estimate_password_strength() != 20836.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_func_generate_item_id() != 22417.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_func_generate_password() != 20375.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_func_generate_salt() != 31865.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1052,51 +1522,243 @@ private fun uniffiCheckApiChecksums(lib: UniffiLib) {
     if (lib.uniffi_noro_mobile_core_checksum_func_generate_vault_key() != 50386.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_func_match_url() != 36827.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_otp_code() != 61123.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_otp_from_item() != 19567.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_otp_item_data() != 24438.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_otp_uri() != 41535.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_parse_otp() != 59386.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_parse_otp_import() != 2293.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_read_backup_manifest() != 45584.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_registrable_domain() != 49842.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_func_trash_retention_days() != 31188.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_func_unwrap_vault_key() != 38585.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_noro_mobile_core_checksum_func_wrap_vault_key() != 17122.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_batch() != 10730.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_create_folder() != 44035.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_create_item() != 51847.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_delete_folder() != 16292.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_delete_item() != 22816.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_empty_trash() != 51922.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_fetch_folders() != 25976.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_fetch_items() != 53777.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_fetch_trash() != 28258.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_is_locked() != 58953.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_lock() != 45168.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_login() != 19049.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_move_folder() != 45594.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_move_items() != 57810.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_pending_changes() != 48051.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_purge_item() != 23732.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_queue_change() != 14986.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_request_sync() != 6274.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_resolve_sync_conflict() != 54286.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_restore_item() != 43560.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_set_token() != 22416.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_start_background_sync() != 15057.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_stop_background_sync() != 48056.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_unlock() != 35563.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_unlock_with_key() != 45254.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_update_folder() != 56843.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_syncclient_update_item() != 45106.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_started() != 53438.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_progress() != 9787.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_completed() != 25949.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_conflict() != 59857.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_rejected() != 28494.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_synclistener_on_sync_connection() != 54082.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_apply_remote() != 35835.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_audit() != 50508.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_create_folder() != 59998.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_create_item() != 60480.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_delete_folder() != 57648.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_delete_item() != 63195.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_empty_trash() != 65212.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_export_backup() != 59750.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_export_plaintext() != 40395.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_folder_children() != 12955.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_folder_descendants() != 1244.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_folder_items() != 20450.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_folder_path() != 54054.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_get_item() != 55932.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_import_backup() != 2687.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_import_otp() != 18008.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_list_folders() != 11150.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_list_items() != 61447.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_list_trash() != 61971.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_load() != 6499.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_lock() != 17287.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_match_url() != 57037.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_move_folder() != 53951.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_move_item() != 15719.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_otp_code() != 45180.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_preview_otp_import() != 44635.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_purge_expired() != 10347.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_purge_item() != 34774.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_restore_item() != 58558.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_save() != 50962.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_search() != 205.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_search_items() != 42826.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_set_folders() != 54506.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_noro_mobile_core_checksum_method_vault_update_folder() != 51941.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_noro_mobile_core_checksum_method_vault_update_item() != 55533.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1153,6 +1815,52 @@ inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
  * */
 object NoPointer
 
+/**
+ * @suppress
+ */
+public object FfiConverterUByte: FfiConverter<UByte, Byte> {
+    override fun lift(value: Byte): UByte {
+        return value.toUByte()
+    }
+
+    override fun read(buf: ByteBuffer): UByte {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: UByte): Byte {
+        return value.toByte()
+    }
+
+    override fun allocationSize(value: UByte) = 1UL
+
+    override fun write(value: UByte, buf: ByteBuffer) {
+        buf.put(value.toByte())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
 /**
  * @suppress
  */
@@ -1199,6 +1907,29 @@ public object FfiConverterULong: FfiConverter<ULong, Long> {
     }
 }
 
+/**
+ * @suppress
+ */
+public object FfiConverterDouble: FfiConverter<Double, Double> {
+    override fun lift(value: Double): Double {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Double {
+        return buf.getDouble()
+    }
+
+    override fun lower(value: Double): Double {
+        return value
+    }
+
+    override fun allocationSize(value: Double) = 8UL
+
+    override fun write(value: Double, buf: ByteBuffer) {
+        buf.putDouble(value)
+    }
+}
+
 /**
  * @suppress
  */
@@ -1462,20 +2193,62 @@ private class JavaLangRefCleanable(
 }
 public interface SyncClientInterface {
     
+    fun `batch`(`ops`: List<BatchOp>, `listener`: SyncListener?): BatchResult
+    
+    fun `createFolder`(`name`: kotlin.String, `parentId`: kotlin.String?, `color`: kotlin.String?, `icon`: kotlin.String?): Folder
+    
     fun `createItem`(`itemType`: kotlin.String, `title`: kotlin.String, `data`: kotlin.ByteArray, `tags`: List<kotlin.String>, `favorite`: kotlin.Boolean): VaultItem
     
+    fun `deleteFolder`(`id`: kotlin.String): List<kotlin.String>
+    
     fun `deleteItem`(`id`: kotlin.String)
     
+    fun `emptyTrash`(): List<kotlin.String>
+    
+    fun `fetchFolders`(): List<Folder>
+    
     fun `fetchItems`(): List<VaultItem>
     
+    fun `fetchTrash`(): List<VaultItem>
+    
+    fun `isLocked`(): kotlin.Boolean
+    
+    fun `lock`()
+    
     fun `login`(`email`: kotlin.String, `password`: kotlin.String): kotlin.String
     
+    fun `moveFolder`(`id`: kotlin.String, `parentId`: kotlin.String?): Folder
+    
+    fun `moveItems`(`folderId`: kotlin.String?, `itemIds`: List<kotlin.String>): kotlin.UInt
+    
+    fun `pendingChanges`(): List<PendingChange>
+    
+    fun `purgeItem`(`id`: kotlin.String)
+    
+    fun `queueChange`(`change`: PendingChange)
+    
+    fun `requestSync`()
+    
+    fun `resolveSyncConflict`(`id`: kotlin.String, `keepLocal`: kotlin.Boolean)
+    
+    fun `restoreItem`(`id`: kotlin.String): VaultItem
+    
     fun `setToken`(`token`: kotlin.String)
     
-    fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem
+    fun `startBackgroundSync`(`listener`: SyncListener, `intervalSecs`: kotlin.ULong)
     
-    companion object
-}
+    fun `stopBackgroundSync`()
+    
+    fun `unlock`(`password`: kotlin.String, `secretKey`: kotlin.String, `salt`: kotlin.ByteArray, `wrappedVaultKey`: kotlin.ByteArray)
+    
+    fun `unlockWithKey`(`vaultKey`: kotlin.ByteArray)
+    
+    fun `updateFolder`(`id`: kotlin.String, `name`: kotlin.String?, `color`: kotlin.String?, `icon`: kotlin.String?, `order`: kotlin.Int?): Folder
+    
+    fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem
+    
+    companion object
+}
 
 open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
 
@@ -1566,6 +2339,32 @@ open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
     }
 
     
+    @Throws(SyncException::class)override fun `batch`(`ops`: List<BatchOp>, `listener`: SyncListener?): BatchResult {
+            return FfiConverterTypeBatchResult.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_batch(
+        it, FfiConverterSequenceTypeBatchOp.lower(`ops`),FfiConverterOptionalTypeSyncListener.lower(`listener`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(SyncException::class)override fun `createFolder`(`name`: kotlin.String, `parentId`: kotlin.String?, `color`: kotlin.String?, `icon`: kotlin.String?): Folder {
+            return FfiConverterTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_create_folder(
+        it, FfiConverterString.lower(`name`),FfiConverterOptionalString.lower(`parentId`),FfiConverterOptionalString.lower(`color`),FfiConverterOptionalString.lower(`icon`),_status)
+}
+    }
+    )
+    }
+    
+
+    
     @Throws(SyncException::class)override fun `createItem`(`itemType`: kotlin.String, `title`: kotlin.String, `data`: kotlin.ByteArray, `tags`: List<kotlin.String>, `favorite`: kotlin.Boolean): VaultItem {
             return FfiConverterTypeVaultItem.lift(
     callWithPointer {
@@ -1579,6 +2378,19 @@ open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
     
 
     
+    @Throws(SyncException::class)override fun `deleteFolder`(`id`: kotlin.String): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_delete_folder(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    
     @Throws(SyncException::class)override fun `deleteItem`(`id`: kotlin.String)
         = 
     callWithPointer {
@@ -1591,6 +2403,32 @@ open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
     
 
     
+    @Throws(SyncException::class)override fun `emptyTrash`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_empty_trash(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(SyncException::class)override fun `fetchFolders`(): List<Folder> {
+            return FfiConverterSequenceTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_fetch_folders(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
     @Throws(SyncException::class)override fun `fetchItems`(): List<VaultItem> {
             return FfiConverterSequenceTypeVaultItem.lift(
     callWithPointer {
@@ -1604,6 +2442,42 @@ open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
     
 
     
+    @Throws(SyncException::class)override fun `fetchTrash`(): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_fetch_trash(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `isLocked`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_is_locked(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `lock`()
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_lock(
+        it, _status)
+}
+    }
+    
+    
+
+    
     @Throws(SyncException::class)override fun `login`(`email`: kotlin.String, `password`: kotlin.String): kotlin.String {
             return FfiConverterString.lift(
     callWithPointer {
@@ -1616,6 +2490,102 @@ open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
     }
     
 
+    
+    @Throws(SyncException::class)override fun `moveFolder`(`id`: kotlin.String, `parentId`: kotlin.String?): Folder {
+            return FfiConverterTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_move_folder(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`parentId`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(SyncException::class)override fun `moveItems`(`folderId`: kotlin.String?, `itemIds`: List<kotlin.String>): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_move_items(
+        it, FfiConverterOptionalString.lower(`folderId`),FfiConverterSequenceString.lower(`itemIds`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `pendingChanges`(): List<PendingChange> {
+            return FfiConverterSequenceTypePendingChange.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_pending_changes(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(SyncException::class)override fun `purgeItem`(`id`: kotlin.String)
+        = 
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_purge_item(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    
+    
+
+    override fun `queueChange`(`change`: PendingChange)
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_queue_change(
+        it, FfiConverterTypePendingChange.lower(`change`),_status)
+}
+    }
+    
+    
+
+    override fun `requestSync`()
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_request_sync(
+        it, _status)
+}
+    }
+    
+    
+
+    override fun `resolveSyncConflict`(`id`: kotlin.String, `keepLocal`: kotlin.Boolean)
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_resolve_sync_conflict(
+        it, FfiConverterString.lower(`id`),FfiConverterBoolean.lower(`keepLocal`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(SyncException::class)override fun `restoreItem`(`id`: kotlin.String): VaultItem {
+            return FfiConverterTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_restore_item(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
     override fun `setToken`(`token`: kotlin.String)
         = 
     callWithPointer {
@@ -1627,6 +2597,65 @@ open class SyncClient: Disposable, AutoCloseable, SyncClientInterface {
     
     
 
+    override fun `startBackgroundSync`(`listener`: SyncListener, `intervalSecs`: kotlin.ULong)
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_start_background_sync(
+        it, FfiConverterTypeSyncListener.lower(`listener`),FfiConverterULong.lower(`intervalSecs`),_status)
+}
+    }
+    
+    
+
+    override fun `stopBackgroundSync`()
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_stop_background_sync(
+        it, _status)
+}
+    }
+    
+    
+
+    
+    @Throws(SyncException::class)override fun `unlock`(`password`: kotlin.String, `secretKey`: kotlin.String, `salt`: kotlin.ByteArray, `wrappedVaultKey`: kotlin.ByteArray)
+        = 
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_unlock(
+        it, FfiConverterString.lower(`password`),FfiConverterString.lower(`secretKey`),FfiConverterByteArray.lower(`salt`),FfiConverterByteArray.lower(`wrappedVaultKey`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(SyncException::class)override fun `unlockWithKey`(`vaultKey`: kotlin.ByteArray)
+        = 
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_unlock_with_key(
+        it, FfiConverterByteArray.lower(`vaultKey`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(SyncException::class)override fun `updateFolder`(`id`: kotlin.String, `name`: kotlin.String?, `color`: kotlin.String?, `icon`: kotlin.String?, `order`: kotlin.Int?): Folder {
+            return FfiConverterTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(SyncException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_syncclient_update_folder(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`name`),FfiConverterOptionalString.lower(`color`),FfiConverterOptionalString.lower(`icon`),FfiConverterOptionalInt.lower(`order`),_status)
+}
+    }
+    )
+    }
+    
+
     
     @Throws(SyncException::class)override fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem {
             return FfiConverterTypeVaultItem.lift(
@@ -1775,28 +2804,24 @@ public object FfiConverterTypeSyncClient: FfiConverter<SyncClient, Pointer> {
 //
 
 
-public interface VaultInterface {
-    
-    fun `createItem`(`itemType`: kotlin.String, `title`: kotlin.String, `data`: kotlin.ByteArray, `tags`: List<kotlin.String>, `favorite`: kotlin.Boolean): VaultItem
-    
-    fun `deleteItem`(`id`: kotlin.String)
+public interface SyncListener {
     
-    fun `getItem`(`id`: kotlin.String): VaultItem?
+    fun `onSyncStarted`(`reason`: kotlin.String)
     
-    fun `listItems`(): List<VaultItem>
+    fun `onSyncProgress`(`phase`: kotlin.String, `done`: kotlin.UInt, `total`: kotlin.UInt)
     
-    fun `load`(`encrypted`: kotlin.ByteArray, `key`: kotlin.ByteArray)
+    fun `onSyncCompleted`(`result`: SyncResult)
     
-    fun `save`(`key`: kotlin.ByteArray): kotlin.ByteArray
+    fun `onSyncConflict`(`conflict`: SyncConflict)
     
-    fun `searchItems`(`query`: kotlin.String): List<VaultItem>
+    fun `onSyncRejected`(`change`: PendingChange)
     
-    fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem
+    fun `onSyncConnection`(`connected`: kotlin.Boolean)
     
     companion object
 }
 
-open class Vault: Disposable, AutoCloseable, VaultInterface {
+open class SyncListenerImpl: Disposable, AutoCloseable, SyncListener {
 
     constructor(pointer: Pointer) {
         this.pointer = pointer
@@ -1813,13 +2838,6 @@ open class Vault: Disposable, AutoCloseable, VaultInterface {
         this.pointer = null
         this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(pointer))
     }
-    constructor() :
-        this(
-    uniffiRustCall() { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_constructor_vault_new(
-        _status)
-}
-    )
 
     protected val pointer: Pointer?
     protected val cleanable: UniffiCleaner.Cleanable
@@ -1872,7 +2890,7 @@ open class Vault: Disposable, AutoCloseable, VaultInterface {
         override fun run() {
             pointer?.let { ptr ->
                 uniffiRustCall { status ->
-                    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_free_vault(ptr, status)
+                    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_free_synclistener(ptr, status)
                 }
             }
         }
@@ -1880,108 +2898,74 @@ open class Vault: Disposable, AutoCloseable, VaultInterface {
 
     fun uniffiClonePointer(): Pointer {
         return uniffiRustCall() { status ->
-            UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_clone_vault(pointer!!, status)
+            UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_clone_synclistener(pointer!!, status)
         }
     }
 
-    
-    @Throws(VaultException::class)override fun `createItem`(`itemType`: kotlin.String, `title`: kotlin.String, `data`: kotlin.ByteArray, `tags`: List<kotlin.String>, `favorite`: kotlin.Boolean): VaultItem {
-            return FfiConverterTypeVaultItem.lift(
+    override fun `onSyncStarted`(`reason`: kotlin.String)
+        = 
     callWithPointer {
-    uniffiRustCallWithError(VaultException) { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_create_item(
-        it, FfiConverterString.lower(`itemType`),FfiConverterString.lower(`title`),FfiConverterByteArray.lower(`data`),FfiConverterSequenceString.lower(`tags`),FfiConverterBoolean.lower(`favorite`),_status)
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_synclistener_on_sync_started(
+        it, FfiConverterString.lower(`reason`),_status)
 }
     }
-    )
-    }
     
-
     
-    @Throws(VaultException::class)override fun `deleteItem`(`id`: kotlin.String)
+
+    override fun `onSyncProgress`(`phase`: kotlin.String, `done`: kotlin.UInt, `total`: kotlin.UInt)
         = 
     callWithPointer {
-    uniffiRustCallWithError(VaultException) { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_delete_item(
-        it, FfiConverterString.lower(`id`),_status)
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_synclistener_on_sync_progress(
+        it, FfiConverterString.lower(`phase`),FfiConverterUInt.lower(`done`),FfiConverterUInt.lower(`total`),_status)
 }
     }
     
     
 
-    
-    @Throws(VaultException::class)override fun `getItem`(`id`: kotlin.String): VaultItem? {
-            return FfiConverterOptionalTypeVaultItem.lift(
+    override fun `onSyncCompleted`(`result`: SyncResult)
+        = 
     callWithPointer {
-    uniffiRustCallWithError(VaultException) { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_get_item(
-        it, FfiConverterString.lower(`id`),_status)
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_synclistener_on_sync_completed(
+        it, FfiConverterTypeSyncResult.lower(`result`),_status)
 }
     }
-    )
-    }
+    
     
 
-    override fun `listItems`(): List<VaultItem> {
-            return FfiConverterSequenceTypeVaultItem.lift(
+    override fun `onSyncConflict`(`conflict`: SyncConflict)
+        = 
     callWithPointer {
     uniffiRustCall() { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_list_items(
-        it, _status)
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_synclistener_on_sync_conflict(
+        it, FfiConverterTypeSyncConflict.lower(`conflict`),_status)
 }
     }
-    )
-    }
     
-
     
-    @Throws(VaultException::class)override fun `load`(`encrypted`: kotlin.ByteArray, `key`: kotlin.ByteArray)
+
+    override fun `onSyncRejected`(`change`: PendingChange)
         = 
     callWithPointer {
-    uniffiRustCallWithError(VaultException) { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_load(
-        it, FfiConverterByteArray.lower(`encrypted`),FfiConverterByteArray.lower(`key`),_status)
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_synclistener_on_sync_rejected(
+        it, FfiConverterTypePendingChange.lower(`change`),_status)
 }
     }
     
     
 
-    
-    @Throws(VaultException::class)override fun `save`(`key`: kotlin.ByteArray): kotlin.ByteArray {
-            return FfiConverterByteArray.lift(
-    callWithPointer {
-    uniffiRustCallWithError(VaultException) { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_save(
-        it, FfiConverterByteArray.lower(`key`),_status)
-}
-    }
-    )
-    }
-    
-
-    override fun `searchItems`(`query`: kotlin.String): List<VaultItem> {
-            return FfiConverterSequenceTypeVaultItem.lift(
+    override fun `onSyncConnection`(`connected`: kotlin.Boolean)
+        = 
     callWithPointer {
     uniffiRustCall() { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_search_items(
-        it, FfiConverterString.lower(`query`),_status)
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_synclistener_on_sync_connection(
+        it, FfiConverterBoolean.lower(`connected`),_status)
 }
     }
-    )
-    }
-    
-
     
-    @Throws(VaultException::class)override fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem {
-            return FfiConverterTypeVaultItem.lift(
-    callWithPointer {
-    uniffiRustCallWithError(VaultException) { _status ->
-    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_update_item(
-        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`title`),FfiConverterOptionalByteArray.lower(`data`),FfiConverterOptionalSequenceString.lower(`tags`),FfiConverterOptionalBoolean.lower(`favorite`),_status)
-}
-    }
-    )
-    }
     
 
     
@@ -1991,423 +2975,3865 @@ open class Vault: Disposable, AutoCloseable, VaultInterface {
     companion object
     
 }
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+internal const val IDX_CALLBACK_FREE = 0
+// Callback return codes
+internal const val UNIFFI_CALLBACK_SUCCESS = 0
+internal const val UNIFFI_CALLBACK_ERROR = 1
+internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
 
 /**
  * @suppress
  */
-public object FfiConverterTypeVault: FfiConverter<Vault, Pointer> {
+public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
+    internal val handleMap = UniffiHandleMap<CallbackInterface>()
 
-    override fun lower(value: Vault): Pointer {
-        return value.uniffiClonePointer()
+    internal fun drop(handle: Long) {
+        handleMap.remove(handle)
     }
 
-    override fun lift(value: Pointer): Vault {
-        return Vault(value)
+    override fun lift(value: Long): CallbackInterface {
+        return handleMap.get(value)
     }
 
-    override fun read(buf: ByteBuffer): Vault {
-        // The Rust code always writes pointers as 8 bytes, and will
-        // fail to compile if they don't fit.
-        return lift(Pointer(buf.getLong()))
-    }
+    override fun read(buf: ByteBuffer) = lift(buf.getLong())
 
-    override fun allocationSize(value: Vault) = 8UL
+    override fun lower(value: CallbackInterface) = handleMap.insert(value)
 
-    override fun write(value: Vault, buf: ByteBuffer) {
-        // The Rust code always expects pointers written as 8 bytes,
-        // and will fail to compile if they don't fit.
-        buf.putLong(Pointer.nativeValue(lower(value)))
+    override fun allocationSize(value: CallbackInterface) = 8UL
+
+    override fun write(value: CallbackInterface, buf: ByteBuffer) {
+        buf.putLong(lower(value))
     }
 }
 
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceSyncListener {
+    internal object `onSyncStarted`: UniffiCallbackInterfaceSyncListenerMethod0 {
+        override fun callback(`uniffiHandle`: Long,`reason`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeSyncListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncStarted`(
+                    FfiConverterString.lift(`reason`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onSyncProgress`: UniffiCallbackInterfaceSyncListenerMethod1 {
+        override fun callback(`uniffiHandle`: Long,`phase`: RustBuffer.ByValue,`done`: Int,`total`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeSyncListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncProgress`(
+                    FfiConverterString.lift(`phase`),
+                    FfiConverterUInt.lift(`done`),
+                    FfiConverterUInt.lift(`total`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onSyncCompleted`: UniffiCallbackInterfaceSyncListenerMethod2 {
+        override fun callback(`uniffiHandle`: Long,`result`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeSyncListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncCompleted`(
+                    FfiConverterTypeSyncResult.lift(`result`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onSyncConflict`: UniffiCallbackInterfaceSyncListenerMethod3 {
+        override fun callback(`uniffiHandle`: Long,`conflict`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeSyncListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncConflict`(
+                    FfiConverterTypeSyncConflict.lift(`conflict`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onSyncRejected`: UniffiCallbackInterfaceSyncListenerMethod4 {
+        override fun callback(`uniffiHandle`: Long,`change`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeSyncListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncRejected`(
+                    FfiConverterTypePendingChange.lift(`change`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onSyncConnection`: UniffiCallbackInterfaceSyncListenerMethod5 {
+        override fun callback(`uniffiHandle`: Long,`connected`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeSyncListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onSyncConnection`(
+                    FfiConverterBoolean.lift(`connected`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
 
-
-data class VaultData (
-    var `items`: List<VaultItem>, 
-    var `updated`: kotlin.ULong
-) {
-    
-    companion object
-}
-
-/**
- * @suppress
- */
-public object FfiConverterTypeVaultData: FfiConverterRustBuffer<VaultData> {
-    override fun read(buf: ByteBuffer): VaultData {
-        return VaultData(
-            FfiConverterSequenceTypeVaultItem.read(buf),
-            FfiConverterULong.read(buf),
-        )
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeSyncListener.handleMap.remove(handle)
+        }
     }
 
-    override fun allocationSize(value: VaultData) = (
-            FfiConverterSequenceTypeVaultItem.allocationSize(value.`items`) +
-            FfiConverterULong.allocationSize(value.`updated`)
+    internal var vtable = UniffiVTableCallbackInterfaceSyncListener.UniffiByValue(
+        `onSyncStarted`,
+        `onSyncProgress`,
+        `onSyncCompleted`,
+        `onSyncConflict`,
+        `onSyncRejected`,
+        `onSyncConnection`,
+        uniffiFree,
     )
 
-    override fun write(value: VaultData, buf: ByteBuffer) {
-            FfiConverterSequenceTypeVaultItem.write(value.`items`, buf)
-            FfiConverterULong.write(value.`updated`, buf)
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_noro_mobile_core_fn_init_callback_vtable_synclistener(vtable)
     }
 }
 
-
-
-data class VaultItem (
-    var `id`: kotlin.String, 
-    var `itemType`: kotlin.String, 
-    var `title`: kotlin.String, 
-    var `data`: kotlin.ByteArray, 
-    var `revision`: kotlin.Int, 
-    var `favorite`: kotlin.Boolean, 
-    var `deleted`: kotlin.Boolean, 
-    var `tags`: List<kotlin.String>, 
-    var `created`: kotlin.ULong, 
-    var `updated`: kotlin.ULong
-) {
-    
-    companion object
-}
-
 /**
  * @suppress
  */
-public object FfiConverterTypeVaultItem: FfiConverterRustBuffer<VaultItem> {
-    override fun read(buf: ByteBuffer): VaultItem {
-        return VaultItem(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterByteArray.read(buf),
-            FfiConverterInt.read(buf),
-            FfiConverterBoolean.read(buf),
-            FfiConverterBoolean.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterULong.read(buf),
-            FfiConverterULong.read(buf),
-        )
-    }
-
-    override fun allocationSize(value: VaultItem) = (
-            FfiConverterString.allocationSize(value.`id`) +
-            FfiConverterString.allocationSize(value.`itemType`) +
-            FfiConverterString.allocationSize(value.`title`) +
-            FfiConverterByteArray.allocationSize(value.`data`) +
-            FfiConverterInt.allocationSize(value.`revision`) +
-            FfiConverterBoolean.allocationSize(value.`favorite`) +
-            FfiConverterBoolean.allocationSize(value.`deleted`) +
-            FfiConverterSequenceString.allocationSize(value.`tags`) +
-            FfiConverterULong.allocationSize(value.`created`) +
-            FfiConverterULong.allocationSize(value.`updated`)
-    )
+public object FfiConverterTypeSyncListener: FfiConverter<SyncListener, Pointer> {
+    internal val handleMap = UniffiHandleMap<SyncListener>()
 
-    override fun write(value: VaultItem, buf: ByteBuffer) {
-            FfiConverterString.write(value.`id`, buf)
-            FfiConverterString.write(value.`itemType`, buf)
-            FfiConverterString.write(value.`title`, buf)
-            FfiConverterByteArray.write(value.`data`, buf)
-            FfiConverterInt.write(value.`revision`, buf)
-            FfiConverterBoolean.write(value.`favorite`, buf)
-            FfiConverterBoolean.write(value.`deleted`, buf)
-            FfiConverterSequenceString.write(value.`tags`, buf)
-            FfiConverterULong.write(value.`created`, buf)
-            FfiConverterULong.write(value.`updated`, buf)
+    override fun lower(value: SyncListener): Pointer {
+        return Pointer(handleMap.insert(value))
     }
-}
-
-
 
-
-
-sealed class CryptoException: kotlin.Exception() {
-    
-    class Argon2(
-        ) : CryptoException() {
-        override val message
-            get() = ""
-    }
-    
-    class Encryption(
-        ) : CryptoException() {
-        override val message
-            get() = ""
-    }
-    
-    class Decryption(
-        ) : CryptoException() {
-        override val message
-            get() = ""
-    }
-    
-    class InvalidSecretKey(
-        ) : CryptoException() {
-        override val message
-            get() = ""
-    }
-    
-    class InvalidKeyLength(
-        ) : CryptoException() {
-        override val message
-            get() = ""
+    override fun lift(value: Pointer): SyncListener {
+        return SyncListenerImpl(value)
     }
-    
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<CryptoException> {
-        override fun lift(error_buf: RustBuffer.ByValue): CryptoException = FfiConverterTypeCryptoError.lift(error_buf)
+    override fun read(buf: ByteBuffer): SyncListener {
+        // The Rust code always writes pointers as 8 bytes, and will
+        // fail to compile if they don't fit.
+        return lift(Pointer(buf.getLong()))
     }
 
-    
-}
-
-/**
- * @suppress
- */
-public object FfiConverterTypeCryptoError : FfiConverterRustBuffer<CryptoException> {
-    override fun read(buf: ByteBuffer): CryptoException {
-        
+    override fun allocationSize(value: SyncListener) = 8UL
 
-        return when(buf.getInt()) {
-            1 -> CryptoException.Argon2()
-            2 -> CryptoException.Encryption()
-            3 -> CryptoException.Decryption()
-            4 -> CryptoException.InvalidSecretKey()
-            5 -> CryptoException.InvalidKeyLength()
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
-        }
+    override fun write(value: SyncListener, buf: ByteBuffer) {
+        // The Rust code always expects pointers written as 8 bytes,
+        // and will fail to compile if they don't fit.
+        buf.putLong(Pointer.nativeValue(lower(value)))
     }
+}
 
-    override fun allocationSize(value: CryptoException): ULong {
-        return when(value) {
-            is CryptoException.Argon2 -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is CryptoException.Encryption -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is CryptoException.Decryption -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is CryptoException.InvalidSecretKey -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is CryptoException.InvalidKeyLength -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-        }
-    }
 
-    override fun write(value: CryptoException, buf: ByteBuffer) {
-        when(value) {
-            is CryptoException.Argon2 -> {
-                buf.putInt(1)
-                Unit
-            }
-            is CryptoException.Encryption -> {
-                buf.putInt(2)
-                Unit
-            }
-            is CryptoException.Decryption -> {
-                buf.putInt(3)
-                Unit
+// This template implements a class for working with a Rust struct via a Pointer/Arc<T>
+// to the live Rust struct on the other side of the FFI.
+//
+// Each instance implements core operations for working with the Rust `Arc<T>` and the
+// Kotlin Pointer to work with the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque pointer to the underlying Rust struct.
+//     Method calls need to read this pointer from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its pointer should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the pointer, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the pointer, but is interrupted
+//      before it can pass the pointer over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read pointer value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface VaultInterface {
+    
+    fun `applyRemote`(`changed`: List<VaultItem>, `removed`: List<kotlin.String>)
+    
+    fun `audit`(): AuditReport
+    
+    fun `createFolder`(`name`: kotlin.String, `parentId`: kotlin.String?, `color`: kotlin.String, `icon`: kotlin.String): Folder
+    
+    fun `createItem`(`itemType`: kotlin.String, `title`: kotlin.String, `data`: kotlin.ByteArray, `tags`: List<kotlin.String>, `favorite`: kotlin.Boolean): VaultItem
+    
+    fun `deleteFolder`(`id`: kotlin.String): List<kotlin.String>
+    
+    fun `deleteItem`(`id`: kotlin.String)
+    
+    fun `emptyTrash`(): List<kotlin.String>
+    
+    fun `exportBackup`(`password`: kotlin.String, `attachments`: List<BackupAttachment>): kotlin.ByteArray
+    
+    fun `exportPlaintext`(`format`: PlainExportFormat, `password`: kotlin.String, `secretKey`: kotlin.String, `salt`: kotlin.ByteArray, `wrappedVaultKey`: kotlin.ByteArray): kotlin.String
+    
+    fun `folderChildren`(`parentId`: kotlin.String?): List<Folder>
+    
+    fun `folderDescendants`(`id`: kotlin.String): List<Folder>
+    
+    fun `folderItems`(`folderId`: kotlin.String?): List<VaultItem>
+    
+    fun `folderPath`(`id`: kotlin.String): List<Folder>
+    
+    fun `getItem`(`id`: kotlin.String): VaultItem?
+    
+    fun `importBackup`(`archive`: kotlin.ByteArray, `password`: kotlin.String): BackupImportResult
+    
+    fun `importOtp`(`text`: kotlin.String, `skipDuplicates`: kotlin.Boolean): OtpImportResult
+    
+    fun `listFolders`(): List<Folder>
+    
+    fun `listItems`(): List<VaultItem>
+    
+    fun `listTrash`(): List<VaultItem>
+    
+    fun `load`(`encrypted`: kotlin.ByteArray, `key`: kotlin.ByteArray)
+    
+    fun `lock`()
+    
+    fun `matchUrl`(`pageUrl`: kotlin.String): List<VaultItem>
+    
+    fun `moveFolder`(`id`: kotlin.String, `parentId`: kotlin.String?): Folder
+    
+    fun `moveItem`(`id`: kotlin.String, `folderId`: kotlin.String?): VaultItem
+    
+    fun `otpCode`(`id`: kotlin.String, `timestamp`: kotlin.ULong?): OtpCode?
+    
+    fun `previewOtpImport`(`text`: kotlin.String): OtpImportPreview
+    
+    fun `purgeExpired`(): List<kotlin.String>
+    
+    fun `purgeItem`(`id`: kotlin.String)
+    
+    fun `restoreItem`(`id`: kotlin.String): VaultItem
+    
+    fun `save`(`key`: kotlin.ByteArray): kotlin.ByteArray
+    
+    fun `search`(`query`: kotlin.String, `limit`: kotlin.UInt): List<VaultItem>
+    
+    fun `searchItems`(`query`: kotlin.String): List<VaultItem>
+    
+    fun `setFolders`(`folders`: List<Folder>)
+    
+    fun `updateFolder`(`id`: kotlin.String, `name`: kotlin.String?, `color`: kotlin.String?, `icon`: kotlin.String?, `order`: kotlin.Int?): Folder
+    
+    fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem
+    
+    companion object
+}
+
+open class Vault: Disposable, AutoCloseable, VaultInterface {
+
+    constructor(pointer: Pointer) {
+        this.pointer = pointer
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(pointer))
+    }
+
+    /**
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noPointer: NoPointer) {
+        this.pointer = null
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(pointer))
+    }
+    constructor() :
+        this(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_constructor_vault_new(
+        _status)
+}
+    )
+
+    protected val pointer: Pointer?
+    protected val cleanable: UniffiCleaner.Cleanable
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithPointer(block: (ptr: Pointer) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the pointer being freed concurrently.
+        try {
+            return block(this.uniffiClonePointer())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val pointer: Pointer?) : Runnable {
+        override fun run() {
+            pointer?.let { ptr ->
+                uniffiRustCall { status ->
+                    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_free_vault(ptr, status)
+                }
+            }
+        }
+    }
+
+    fun uniffiClonePointer(): Pointer {
+        return uniffiRustCall() { status ->
+            UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_clone_vault(pointer!!, status)
+        }
+    }
+
+    override fun `applyRemote`(`changed`: List<VaultItem>, `removed`: List<kotlin.String>)
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_apply_remote(
+        it, FfiConverterSequenceTypeVaultItem.lower(`changed`),FfiConverterSequenceString.lower(`removed`),_status)
+}
+    }
+    
+    
+
+    override fun `audit`(): AuditReport {
+            return FfiConverterTypeAuditReport.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_audit(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `createFolder`(`name`: kotlin.String, `parentId`: kotlin.String?, `color`: kotlin.String, `icon`: kotlin.String): Folder {
+            return FfiConverterTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_create_folder(
+        it, FfiConverterString.lower(`name`),FfiConverterOptionalString.lower(`parentId`),FfiConverterString.lower(`color`),FfiConverterString.lower(`icon`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `createItem`(`itemType`: kotlin.String, `title`: kotlin.String, `data`: kotlin.ByteArray, `tags`: List<kotlin.String>, `favorite`: kotlin.Boolean): VaultItem {
+            return FfiConverterTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_create_item(
+        it, FfiConverterString.lower(`itemType`),FfiConverterString.lower(`title`),FfiConverterByteArray.lower(`data`),FfiConverterSequenceString.lower(`tags`),FfiConverterBoolean.lower(`favorite`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `deleteFolder`(`id`: kotlin.String): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_delete_folder(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `deleteItem`(`id`: kotlin.String)
+        = 
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_delete_item(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    
+    
+
+    override fun `emptyTrash`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_empty_trash(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(BackupException::class)override fun `exportBackup`(`password`: kotlin.String, `attachments`: List<BackupAttachment>): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    callWithPointer {
+    uniffiRustCallWithError(BackupException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_export_backup(
+        it, FfiConverterString.lower(`password`),FfiConverterSequenceTypeBackupAttachment.lower(`attachments`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(BackupException::class)override fun `exportPlaintext`(`format`: PlainExportFormat, `password`: kotlin.String, `secretKey`: kotlin.String, `salt`: kotlin.ByteArray, `wrappedVaultKey`: kotlin.ByteArray): kotlin.String {
+            return FfiConverterString.lift(
+    callWithPointer {
+    uniffiRustCallWithError(BackupException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_export_plaintext(
+        it, FfiConverterTypePlainExportFormat.lower(`format`),FfiConverterString.lower(`password`),FfiConverterString.lower(`secretKey`),FfiConverterByteArray.lower(`salt`),FfiConverterByteArray.lower(`wrappedVaultKey`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `folderChildren`(`parentId`: kotlin.String?): List<Folder> {
+            return FfiConverterSequenceTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_folder_children(
+        it, FfiConverterOptionalString.lower(`parentId`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `folderDescendants`(`id`: kotlin.String): List<Folder> {
+            return FfiConverterSequenceTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_folder_descendants(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `folderItems`(`folderId`: kotlin.String?): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_folder_items(
+        it, FfiConverterOptionalString.lower(`folderId`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `folderPath`(`id`: kotlin.String): List<Folder> {
+            return FfiConverterSequenceTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_folder_path(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `getItem`(`id`: kotlin.String): VaultItem? {
+            return FfiConverterOptionalTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_get_item(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(BackupException::class)override fun `importBackup`(`archive`: kotlin.ByteArray, `password`: kotlin.String): BackupImportResult {
+            return FfiConverterTypeBackupImportResult.lift(
+    callWithPointer {
+    uniffiRustCallWithError(BackupException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_import_backup(
+        it, FfiConverterByteArray.lower(`archive`),FfiConverterString.lower(`password`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `importOtp`(`text`: kotlin.String, `skipDuplicates`: kotlin.Boolean): OtpImportResult {
+            return FfiConverterTypeOtpImportResult.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_import_otp(
+        it, FfiConverterString.lower(`text`),FfiConverterBoolean.lower(`skipDuplicates`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `listFolders`(): List<Folder> {
+            return FfiConverterSequenceTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_list_folders(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `listItems`(): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_list_items(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `listTrash`(): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_list_trash(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `load`(`encrypted`: kotlin.ByteArray, `key`: kotlin.ByteArray)
+        = 
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_load(
+        it, FfiConverterByteArray.lower(`encrypted`),FfiConverterByteArray.lower(`key`),_status)
+}
+    }
+    
+    
+
+    override fun `lock`()
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_lock(
+        it, _status)
+}
+    }
+    
+    
+
+    override fun `matchUrl`(`pageUrl`: kotlin.String): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_match_url(
+        it, FfiConverterString.lower(`pageUrl`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `moveFolder`(`id`: kotlin.String, `parentId`: kotlin.String?): Folder {
+            return FfiConverterTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_move_folder(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`parentId`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `moveItem`(`id`: kotlin.String, `folderId`: kotlin.String?): VaultItem {
+            return FfiConverterTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_move_item(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`folderId`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(OtpException::class)override fun `otpCode`(`id`: kotlin.String, `timestamp`: kotlin.ULong?): OtpCode? {
+            return FfiConverterOptionalTypeOtpCode.lift(
+    callWithPointer {
+    uniffiRustCallWithError(OtpException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_otp_code(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalULong.lower(`timestamp`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `previewOtpImport`(`text`: kotlin.String): OtpImportPreview {
+            return FfiConverterTypeOtpImportPreview.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_preview_otp_import(
+        it, FfiConverterString.lower(`text`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `purgeExpired`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_purge_expired(
+        it, _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `purgeItem`(`id`: kotlin.String)
+        = 
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_purge_item(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(VaultException::class)override fun `restoreItem`(`id`: kotlin.String): VaultItem {
+            return FfiConverterTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_restore_item(
+        it, FfiConverterString.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `save`(`key`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_save(
+        it, FfiConverterByteArray.lower(`key`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `search`(`query`: kotlin.String, `limit`: kotlin.UInt): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_search(
+        it, FfiConverterString.lower(`query`),FfiConverterUInt.lower(`limit`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `searchItems`(`query`: kotlin.String): List<VaultItem> {
+            return FfiConverterSequenceTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_search_items(
+        it, FfiConverterString.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `setFolders`(`folders`: List<Folder>)
+        = 
+    callWithPointer {
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_set_folders(
+        it, FfiConverterSequenceTypeFolder.lower(`folders`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(VaultException::class)override fun `updateFolder`(`id`: kotlin.String, `name`: kotlin.String?, `color`: kotlin.String?, `icon`: kotlin.String?, `order`: kotlin.Int?): Folder {
+            return FfiConverterTypeFolder.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_update_folder(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`name`),FfiConverterOptionalString.lower(`color`),FfiConverterOptionalString.lower(`icon`),FfiConverterOptionalInt.lower(`order`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(VaultException::class)override fun `updateItem`(`id`: kotlin.String, `title`: kotlin.String?, `data`: kotlin.ByteArray?, `tags`: List<kotlin.String>?, `favorite`: kotlin.Boolean?): VaultItem {
+            return FfiConverterTypeVaultItem.lift(
+    callWithPointer {
+    uniffiRustCallWithError(VaultException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_method_vault_update_item(
+        it, FfiConverterString.lower(`id`),FfiConverterOptionalString.lower(`title`),FfiConverterOptionalByteArray.lower(`data`),FfiConverterOptionalSequenceString.lower(`tags`),FfiConverterOptionalBoolean.lower(`favorite`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+    
+    companion object
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVault: FfiConverter<Vault, Pointer> {
+
+    override fun lower(value: Vault): Pointer {
+        return value.uniffiClonePointer()
+    }
+
+    override fun lift(value: Pointer): Vault {
+        return Vault(value)
+    }
+
+    override fun read(buf: ByteBuffer): Vault {
+        // The Rust code always writes pointers as 8 bytes, and will
+        // fail to compile if they don't fit.
+        return lift(Pointer(buf.getLong()))
+    }
+
+    override fun allocationSize(value: Vault) = 8UL
+
+    override fun write(value: Vault, buf: ByteBuffer) {
+        // The Rust code always expects pointers written as 8 bytes,
+        // and will fail to compile if they don't fit.
+        buf.putLong(Pointer.nativeValue(lower(value)))
+    }
+}
+
+
+
+data class AuditReport (
+    var `total`: kotlin.UInt, 
+    var `weak`: kotlin.UInt, 
+    var `reused`: kotlin.UInt, 
+    var `old`: kotlin.UInt, 
+    var `missingTotp`: kotlin.UInt, 
+    var `groups`: List<ReusedGroup>, 
+    var `items`: List<ItemAudit>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAuditReport: FfiConverterRustBuffer<AuditReport> {
+    override fun read(buf: ByteBuffer): AuditReport {
+        return AuditReport(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceTypeReusedGroup.read(buf),
+            FfiConverterSequenceTypeItemAudit.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AuditReport) = (
+            FfiConverterUInt.allocationSize(value.`total`) +
+            FfiConverterUInt.allocationSize(value.`weak`) +
+            FfiConverterUInt.allocationSize(value.`reused`) +
+            FfiConverterUInt.allocationSize(value.`old`) +
+            FfiConverterUInt.allocationSize(value.`missingTotp`) +
+            FfiConverterSequenceTypeReusedGroup.allocationSize(value.`groups`) +
+            FfiConverterSequenceTypeItemAudit.allocationSize(value.`items`)
+    )
+
+    override fun write(value: AuditReport, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`total`, buf)
+            FfiConverterUInt.write(value.`weak`, buf)
+            FfiConverterUInt.write(value.`reused`, buf)
+            FfiConverterUInt.write(value.`old`, buf)
+            FfiConverterUInt.write(value.`missingTotp`, buf)
+            FfiConverterSequenceTypeReusedGroup.write(value.`groups`, buf)
+            FfiConverterSequenceTypeItemAudit.write(value.`items`, buf)
+    }
+}
+
+
+
+data class BackupAttachment (
+    var `id`: kotlin.String, 
+    var `itemId`: kotlin.String, 
+    var `name`: kotlin.String, 
+    var `mime`: kotlin.String?, 
+    var `data`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBackupAttachment: FfiConverterRustBuffer<BackupAttachment> {
+    override fun read(buf: ByteBuffer): BackupAttachment {
+        return BackupAttachment(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupAttachment) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterString.allocationSize(value.`itemId`) +
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterOptionalString.allocationSize(value.`mime`) +
+            FfiConverterByteArray.allocationSize(value.`data`)
+    )
+
+    override fun write(value: BackupAttachment, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterString.write(value.`itemId`, buf)
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterOptionalString.write(value.`mime`, buf)
+            FfiConverterByteArray.write(value.`data`, buf)
+    }
+}
+
+
+
+data class BackupImportResult (
+    var `created`: kotlin.UInt, 
+    var `updated`: kotlin.UInt, 
+    var `unchanged`: kotlin.UInt, 
+    var `folders`: kotlin.UInt, 
+    var `attachments`: List<BackupAttachment>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBackupImportResult: FfiConverterRustBuffer<BackupImportResult> {
+    override fun read(buf: ByteBuffer): BackupImportResult {
+        return BackupImportResult(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceTypeBackupAttachment.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupImportResult) = (
+            FfiConverterUInt.allocationSize(value.`created`) +
+            FfiConverterUInt.allocationSize(value.`updated`) +
+            FfiConverterUInt.allocationSize(value.`unchanged`) +
+            FfiConverterUInt.allocationSize(value.`folders`) +
+            FfiConverterSequenceTypeBackupAttachment.allocationSize(value.`attachments`)
+    )
+
+    override fun write(value: BackupImportResult, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`created`, buf)
+            FfiConverterUInt.write(value.`updated`, buf)
+            FfiConverterUInt.write(value.`unchanged`, buf)
+            FfiConverterUInt.write(value.`folders`, buf)
+            FfiConverterSequenceTypeBackupAttachment.write(value.`attachments`, buf)
+    }
+}
+
+
+
+data class BackupManifest (
+    var `version`: kotlin.UInt, 
+    var `created`: kotlin.ULong, 
+    var `source`: kotlin.String, 
+    var `items`: kotlin.UInt, 
+    var `folders`: kotlin.UInt, 
+    var `attachments`: kotlin.UInt
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBackupManifest: FfiConverterRustBuffer<BackupManifest> {
+    override fun read(buf: ByteBuffer): BackupManifest {
+        return BackupManifest(
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupManifest) = (
+            FfiConverterUInt.allocationSize(value.`version`) +
+            FfiConverterULong.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`source`) +
+            FfiConverterUInt.allocationSize(value.`items`) +
+            FfiConverterUInt.allocationSize(value.`folders`) +
+            FfiConverterUInt.allocationSize(value.`attachments`)
+    )
+
+    override fun write(value: BackupManifest, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`version`, buf)
+            FfiConverterULong.write(value.`created`, buf)
+            FfiConverterString.write(value.`source`, buf)
+            FfiConverterUInt.write(value.`items`, buf)
+            FfiConverterUInt.write(value.`folders`, buf)
+            FfiConverterUInt.write(value.`attachments`, buf)
+    }
+}
+
+
+
+data class BatchItemResult (
+    var `id`: kotlin.String?, 
+    var `op`: kotlin.String, 
+    var `ok`: kotlin.Boolean, 
+    var `error`: kotlin.String?, 
+    var `item`: VaultItem?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBatchItemResult: FfiConverterRustBuffer<BatchItemResult> {
+    override fun read(buf: ByteBuffer): BatchItemResult {
+        return BatchItemResult(
+            FfiConverterOptionalString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalTypeVaultItem.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BatchItemResult) = (
+            FfiConverterOptionalString.allocationSize(value.`id`) +
+            FfiConverterString.allocationSize(value.`op`) +
+            FfiConverterBoolean.allocationSize(value.`ok`) +
+            FfiConverterOptionalString.allocationSize(value.`error`) +
+            FfiConverterOptionalTypeVaultItem.allocationSize(value.`item`)
+    )
+
+    override fun write(value: BatchItemResult, buf: ByteBuffer) {
+            FfiConverterOptionalString.write(value.`id`, buf)
+            FfiConverterString.write(value.`op`, buf)
+            FfiConverterBoolean.write(value.`ok`, buf)
+            FfiConverterOptionalString.write(value.`error`, buf)
+            FfiConverterOptionalTypeVaultItem.write(value.`item`, buf)
+    }
+}
+
+
+
+data class BatchResult (
+    var `results`: List<BatchItemResult>, 
+    var `succeeded`: kotlin.UInt, 
+    var `failed`: kotlin.UInt
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBatchResult: FfiConverterRustBuffer<BatchResult> {
+    override fun read(buf: ByteBuffer): BatchResult {
+        return BatchResult(
+            FfiConverterSequenceTypeBatchItemResult.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BatchResult) = (
+            FfiConverterSequenceTypeBatchItemResult.allocationSize(value.`results`) +
+            FfiConverterUInt.allocationSize(value.`succeeded`) +
+            FfiConverterUInt.allocationSize(value.`failed`)
+    )
+
+    override fun write(value: BatchResult, buf: ByteBuffer) {
+            FfiConverterSequenceTypeBatchItemResult.write(value.`results`, buf)
+            FfiConverterUInt.write(value.`succeeded`, buf)
+            FfiConverterUInt.write(value.`failed`, buf)
+    }
+}
+
+
+
+data class BreachResult (
+    var `breached`: kotlin.Boolean, 
+    var `count`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBreachResult: FfiConverterRustBuffer<BreachResult> {
+    override fun read(buf: ByteBuffer): BreachResult {
+        return BreachResult(
+            FfiConverterBoolean.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BreachResult) = (
+            FfiConverterBoolean.allocationSize(value.`breached`) +
+            FfiConverterULong.allocationSize(value.`count`)
+    )
+
+    override fun write(value: BreachResult, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`breached`, buf)
+            FfiConverterULong.write(value.`count`, buf)
+    }
+}
+
+
+
+data class CrackTime (
+    var `scenario`: kotlin.String, 
+    var `seconds`: kotlin.Double, 
+    var `display`: kotlin.String
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCrackTime: FfiConverterRustBuffer<CrackTime> {
+    override fun read(buf: ByteBuffer): CrackTime {
+        return CrackTime(
+            FfiConverterString.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CrackTime) = (
+            FfiConverterString.allocationSize(value.`scenario`) +
+            FfiConverterDouble.allocationSize(value.`seconds`) +
+            FfiConverterString.allocationSize(value.`display`)
+    )
+
+    override fun write(value: CrackTime, buf: ByteBuffer) {
+            FfiConverterString.write(value.`scenario`, buf)
+            FfiConverterDouble.write(value.`seconds`, buf)
+            FfiConverterString.write(value.`display`, buf)
+    }
+}
+
+
+
+data class Folder (
+    var `id`: kotlin.String, 
+    var `name`: kotlin.String, 
+    var `parentId`: kotlin.String?, 
+    var `color`: kotlin.String, 
+    var `icon`: kotlin.String, 
+    var `order`: kotlin.Int, 
+    var `created`: kotlin.ULong, 
+    var `updated`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFolder: FfiConverterRustBuffer<Folder> {
+    override fun read(buf: ByteBuffer): Folder {
+        return Folder(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Folder) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterOptionalString.allocationSize(value.`parentId`) +
+            FfiConverterString.allocationSize(value.`color`) +
+            FfiConverterString.allocationSize(value.`icon`) +
+            FfiConverterInt.allocationSize(value.`order`) +
+            FfiConverterULong.allocationSize(value.`created`) +
+            FfiConverterULong.allocationSize(value.`updated`)
+    )
+
+    override fun write(value: Folder, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterOptionalString.write(value.`parentId`, buf)
+            FfiConverterString.write(value.`color`, buf)
+            FfiConverterString.write(value.`icon`, buf)
+            FfiConverterInt.write(value.`order`, buf)
+            FfiConverterULong.write(value.`created`, buf)
+            FfiConverterULong.write(value.`updated`, buf)
+    }
+}
+
+
+
+data class GeneratorOptions (
+    var `mode`: GeneratorMode, 
+    var `length`: kotlin.UInt, 
+    var `uppercase`: kotlin.Boolean, 
+    var `lowercase`: kotlin.Boolean, 
+    var `numbers`: kotlin.Boolean, 
+    var `symbols`: kotlin.Boolean, 
+    var `minUppercase`: kotlin.UInt, 
+    var `minLowercase`: kotlin.UInt, 
+    var `minNumbers`: kotlin.UInt, 
+    var `minSymbols`: kotlin.UInt, 
+    var `excludeAmbiguous`: kotlin.Boolean, 
+    var `include`: kotlin.String, 
+    var `exclude`: kotlin.String, 
+    var `noRepeat`: kotlin.Boolean, 
+    var `noConsecutive`: kotlin.Boolean, 
+    var `words`: kotlin.UInt, 
+    var `separator`: kotlin.String, 
+    var `capitalize`: kotlin.Boolean, 
+    var `appendDigit`: kotlin.Boolean
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGeneratorOptions: FfiConverterRustBuffer<GeneratorOptions> {
+    override fun read(buf: ByteBuffer): GeneratorOptions {
+        return GeneratorOptions(
+            FfiConverterTypeGeneratorMode.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: GeneratorOptions) = (
+            FfiConverterTypeGeneratorMode.allocationSize(value.`mode`) +
+            FfiConverterUInt.allocationSize(value.`length`) +
+            FfiConverterBoolean.allocationSize(value.`uppercase`) +
+            FfiConverterBoolean.allocationSize(value.`lowercase`) +
+            FfiConverterBoolean.allocationSize(value.`numbers`) +
+            FfiConverterBoolean.allocationSize(value.`symbols`) +
+            FfiConverterUInt.allocationSize(value.`minUppercase`) +
+            FfiConverterUInt.allocationSize(value.`minLowercase`) +
+            FfiConverterUInt.allocationSize(value.`minNumbers`) +
+            FfiConverterUInt.allocationSize(value.`minSymbols`) +
+            FfiConverterBoolean.allocationSize(value.`excludeAmbiguous`) +
+            FfiConverterString.allocationSize(value.`include`) +
+            FfiConverterString.allocationSize(value.`exclude`) +
+            FfiConverterBoolean.allocationSize(value.`noRepeat`) +
+            FfiConverterBoolean.allocationSize(value.`noConsecutive`) +
+            FfiConverterUInt.allocationSize(value.`words`) +
+            FfiConverterString.allocationSize(value.`separator`) +
+            FfiConverterBoolean.allocationSize(value.`capitalize`) +
+            FfiConverterBoolean.allocationSize(value.`appendDigit`)
+    )
+
+    override fun write(value: GeneratorOptions, buf: ByteBuffer) {
+            FfiConverterTypeGeneratorMode.write(value.`mode`, buf)
+            FfiConverterUInt.write(value.`length`, buf)
+            FfiConverterBoolean.write(value.`uppercase`, buf)
+            FfiConverterBoolean.write(value.`lowercase`, buf)
+            FfiConverterBoolean.write(value.`numbers`, buf)
+            FfiConverterBoolean.write(value.`symbols`, buf)
+            FfiConverterUInt.write(value.`minUppercase`, buf)
+            FfiConverterUInt.write(value.`minLowercase`, buf)
+            FfiConverterUInt.write(value.`minNumbers`, buf)
+            FfiConverterUInt.write(value.`minSymbols`, buf)
+            FfiConverterBoolean.write(value.`excludeAmbiguous`, buf)
+            FfiConverterString.write(value.`include`, buf)
+            FfiConverterString.write(value.`exclude`, buf)
+            FfiConverterBoolean.write(value.`noRepeat`, buf)
+            FfiConverterBoolean.write(value.`noConsecutive`, buf)
+            FfiConverterUInt.write(value.`words`, buf)
+            FfiConverterString.write(value.`separator`, buf)
+            FfiConverterBoolean.write(value.`capitalize`, buf)
+            FfiConverterBoolean.write(value.`appendDigit`, buf)
+    }
+}
+
+
+
+data class GeneratorResult (
+    var `password`: kotlin.String, 
+    var `strength`: kotlin.String, 
+    var `entropy`: kotlin.Double
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGeneratorResult: FfiConverterRustBuffer<GeneratorResult> {
+    override fun read(buf: ByteBuffer): GeneratorResult {
+        return GeneratorResult(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterDouble.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: GeneratorResult) = (
+            FfiConverterString.allocationSize(value.`password`) +
+            FfiConverterString.allocationSize(value.`strength`) +
+            FfiConverterDouble.allocationSize(value.`entropy`)
+    )
+
+    override fun write(value: GeneratorResult, buf: ByteBuffer) {
+            FfiConverterString.write(value.`password`, buf)
+            FfiConverterString.write(value.`strength`, buf)
+            FfiConverterDouble.write(value.`entropy`, buf)
+    }
+}
+
+
+
+data class ItemAudit (
+    var `id`: kotlin.String, 
+    var `title`: kotlin.String, 
+    var `score`: kotlin.UByte, 
+    var `strength`: kotlin.String, 
+    var `warning`: kotlin.String?, 
+    var `ageDays`: kotlin.ULong, 
+    var `reused`: kotlin.UInt, 
+    var `weak`: kotlin.Boolean, 
+    var `old`: kotlin.Boolean, 
+    var `hasTotp`: kotlin.Boolean
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeItemAudit: FfiConverterRustBuffer<ItemAudit> {
+    override fun read(buf: ByteBuffer): ItemAudit {
+        return ItemAudit(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ItemAudit) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterString.allocationSize(value.`title`) +
+            FfiConverterUByte.allocationSize(value.`score`) +
+            FfiConverterString.allocationSize(value.`strength`) +
+            FfiConverterOptionalString.allocationSize(value.`warning`) +
+            FfiConverterULong.allocationSize(value.`ageDays`) +
+            FfiConverterUInt.allocationSize(value.`reused`) +
+            FfiConverterBoolean.allocationSize(value.`weak`) +
+            FfiConverterBoolean.allocationSize(value.`old`) +
+            FfiConverterBoolean.allocationSize(value.`hasTotp`)
+    )
+
+    override fun write(value: ItemAudit, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterUByte.write(value.`score`, buf)
+            FfiConverterString.write(value.`strength`, buf)
+            FfiConverterOptionalString.write(value.`warning`, buf)
+            FfiConverterULong.write(value.`ageDays`, buf)
+            FfiConverterUInt.write(value.`reused`, buf)
+            FfiConverterBoolean.write(value.`weak`, buf)
+            FfiConverterBoolean.write(value.`old`, buf)
+            FfiConverterBoolean.write(value.`hasTotp`, buf)
+    }
+}
+
+
+
+data class LoginUrl (
+    var `url`: kotlin.String, 
+    var `rule`: UrlMatchRule?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeLoginUrl: FfiConverterRustBuffer<LoginUrl> {
+    override fun read(buf: ByteBuffer): LoginUrl {
+        return LoginUrl(
+            FfiConverterString.read(buf),
+            FfiConverterOptionalTypeUrlMatchRule.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: LoginUrl) = (
+            FfiConverterString.allocationSize(value.`url`) +
+            FfiConverterOptionalTypeUrlMatchRule.allocationSize(value.`rule`)
+    )
+
+    override fun write(value: LoginUrl, buf: ByteBuffer) {
+            FfiConverterString.write(value.`url`, buf)
+            FfiConverterOptionalTypeUrlMatchRule.write(value.`rule`, buf)
+    }
+}
+
+
+
+data class LoginUrls (
+    var `id`: kotlin.String, 
+    var `urls`: List<LoginUrl>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeLoginUrls: FfiConverterRustBuffer<LoginUrls> {
+    override fun read(buf: ByteBuffer): LoginUrls {
+        return LoginUrls(
+            FfiConverterString.read(buf),
+            FfiConverterSequenceTypeLoginUrl.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: LoginUrls) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterSequenceTypeLoginUrl.allocationSize(value.`urls`)
+    )
+
+    override fun write(value: LoginUrls, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterSequenceTypeLoginUrl.write(value.`urls`, buf)
+    }
+}
+
+
+
+data class OtpCode (
+    var `code`: kotlin.String, 
+    var `counter`: kotlin.ULong, 
+    var `period`: kotlin.ULong, 
+    var `remaining`: kotlin.ULong, 
+    var `expires`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpCode: FfiConverterRustBuffer<OtpCode> {
+    override fun read(buf: ByteBuffer): OtpCode {
+        return OtpCode(
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: OtpCode) = (
+            FfiConverterString.allocationSize(value.`code`) +
+            FfiConverterULong.allocationSize(value.`counter`) +
+            FfiConverterULong.allocationSize(value.`period`) +
+            FfiConverterULong.allocationSize(value.`remaining`) +
+            FfiConverterULong.allocationSize(value.`expires`)
+    )
+
+    override fun write(value: OtpCode, buf: ByteBuffer) {
+            FfiConverterString.write(value.`code`, buf)
+            FfiConverterULong.write(value.`counter`, buf)
+            FfiConverterULong.write(value.`period`, buf)
+            FfiConverterULong.write(value.`remaining`, buf)
+            FfiConverterULong.write(value.`expires`, buf)
+    }
+}
+
+
+
+data class OtpConfig (
+    var `kind`: OtpKind, 
+    var `secret`: kotlin.String, 
+    var `issuer`: kotlin.String?, 
+    var `account`: kotlin.String?, 
+    var `algorithm`: OtpAlgorithm, 
+    var `digits`: kotlin.UInt, 
+    var `period`: kotlin.ULong, 
+    var `counter`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpConfig: FfiConverterRustBuffer<OtpConfig> {
+    override fun read(buf: ByteBuffer): OtpConfig {
+        return OtpConfig(
+            FfiConverterTypeOtpKind.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeOtpAlgorithm.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: OtpConfig) = (
+            FfiConverterTypeOtpKind.allocationSize(value.`kind`) +
+            FfiConverterString.allocationSize(value.`secret`) +
+            FfiConverterOptionalString.allocationSize(value.`issuer`) +
+            FfiConverterOptionalString.allocationSize(value.`account`) +
+            FfiConverterTypeOtpAlgorithm.allocationSize(value.`algorithm`) +
+            FfiConverterUInt.allocationSize(value.`digits`) +
+            FfiConverterULong.allocationSize(value.`period`) +
+            FfiConverterULong.allocationSize(value.`counter`)
+    )
+
+    override fun write(value: OtpConfig, buf: ByteBuffer) {
+            FfiConverterTypeOtpKind.write(value.`kind`, buf)
+            FfiConverterString.write(value.`secret`, buf)
+            FfiConverterOptionalString.write(value.`issuer`, buf)
+            FfiConverterOptionalString.write(value.`account`, buf)
+            FfiConverterTypeOtpAlgorithm.write(value.`algorithm`, buf)
+            FfiConverterUInt.write(value.`digits`, buf)
+            FfiConverterULong.write(value.`period`, buf)
+            FfiConverterULong.write(value.`counter`, buf)
+    }
+}
+
+
+
+data class OtpImportCandidate (
+    var `config`: OtpConfig, 
+    var `title`: kotlin.String, 
+    var `duplicateOf`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpImportCandidate: FfiConverterRustBuffer<OtpImportCandidate> {
+    override fun read(buf: ByteBuffer): OtpImportCandidate {
+        return OtpImportCandidate(
+            FfiConverterTypeOtpConfig.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: OtpImportCandidate) = (
+            FfiConverterTypeOtpConfig.allocationSize(value.`config`) +
+            FfiConverterString.allocationSize(value.`title`) +
+            FfiConverterOptionalString.allocationSize(value.`duplicateOf`)
+    )
+
+    override fun write(value: OtpImportCandidate, buf: ByteBuffer) {
+            FfiConverterTypeOtpConfig.write(value.`config`, buf)
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterOptionalString.write(value.`duplicateOf`, buf)
+    }
+}
+
+
+
+data class OtpImportPreview (
+    var `candidates`: List<OtpImportCandidate>, 
+    var `invalid`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpImportPreview: FfiConverterRustBuffer<OtpImportPreview> {
+    override fun read(buf: ByteBuffer): OtpImportPreview {
+        return OtpImportPreview(
+            FfiConverterSequenceTypeOtpImportCandidate.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: OtpImportPreview) = (
+            FfiConverterSequenceTypeOtpImportCandidate.allocationSize(value.`candidates`) +
+            FfiConverterSequenceString.allocationSize(value.`invalid`)
+    )
+
+    override fun write(value: OtpImportPreview, buf: ByteBuffer) {
+            FfiConverterSequenceTypeOtpImportCandidate.write(value.`candidates`, buf)
+            FfiConverterSequenceString.write(value.`invalid`, buf)
+    }
+}
+
+
+
+data class OtpImportResult (
+    var `created`: List<VaultItem>, 
+    var `duplicates`: kotlin.UInt, 
+    var `invalid`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpImportResult: FfiConverterRustBuffer<OtpImportResult> {
+    override fun read(buf: ByteBuffer): OtpImportResult {
+        return OtpImportResult(
+            FfiConverterSequenceTypeVaultItem.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: OtpImportResult) = (
+            FfiConverterSequenceTypeVaultItem.allocationSize(value.`created`) +
+            FfiConverterUInt.allocationSize(value.`duplicates`) +
+            FfiConverterSequenceString.allocationSize(value.`invalid`)
+    )
+
+    override fun write(value: OtpImportResult, buf: ByteBuffer) {
+            FfiConverterSequenceTypeVaultItem.write(value.`created`, buf)
+            FfiConverterUInt.write(value.`duplicates`, buf)
+            FfiConverterSequenceString.write(value.`invalid`, buf)
+    }
+}
+
+
+
+data class PasswordStrength (
+    var `guesses`: kotlin.Double, 
+    var `guessesLog10`: kotlin.Double, 
+    var `score`: kotlin.UByte, 
+    var `strength`: kotlin.String, 
+    var `crackTimes`: List<CrackTime>, 
+    var `warning`: kotlin.String?, 
+    var `suggestions`: List<kotlin.String>, 
+    var `sequence`: List<StrengthMatch>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePasswordStrength: FfiConverterRustBuffer<PasswordStrength> {
+    override fun read(buf: ByteBuffer): PasswordStrength {
+        return PasswordStrength(
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceTypeCrackTime.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceTypeStrengthMatch.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PasswordStrength) = (
+            FfiConverterDouble.allocationSize(value.`guesses`) +
+            FfiConverterDouble.allocationSize(value.`guessesLog10`) +
+            FfiConverterUByte.allocationSize(value.`score`) +
+            FfiConverterString.allocationSize(value.`strength`) +
+            FfiConverterSequenceTypeCrackTime.allocationSize(value.`crackTimes`) +
+            FfiConverterOptionalString.allocationSize(value.`warning`) +
+            FfiConverterSequenceString.allocationSize(value.`suggestions`) +
+            FfiConverterSequenceTypeStrengthMatch.allocationSize(value.`sequence`)
+    )
+
+    override fun write(value: PasswordStrength, buf: ByteBuffer) {
+            FfiConverterDouble.write(value.`guesses`, buf)
+            FfiConverterDouble.write(value.`guessesLog10`, buf)
+            FfiConverterUByte.write(value.`score`, buf)
+            FfiConverterString.write(value.`strength`, buf)
+            FfiConverterSequenceTypeCrackTime.write(value.`crackTimes`, buf)
+            FfiConverterOptionalString.write(value.`warning`, buf)
+            FfiConverterSequenceString.write(value.`suggestions`, buf)
+            FfiConverterSequenceTypeStrengthMatch.write(value.`sequence`, buf)
+    }
+}
+
+
+
+data class ReusedGroup (
+    var `ids`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeReusedGroup: FfiConverterRustBuffer<ReusedGroup> {
+    override fun read(buf: ByteBuffer): ReusedGroup {
+        return ReusedGroup(
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ReusedGroup) = (
+            FfiConverterSequenceString.allocationSize(value.`ids`)
+    )
+
+    override fun write(value: ReusedGroup, buf: ByteBuffer) {
+            FfiConverterSequenceString.write(value.`ids`, buf)
+    }
+}
+
+
+
+data class StrengthMatch (
+    var `pattern`: kotlin.String, 
+    var `token`: kotlin.String, 
+    var `guesses`: kotlin.Double
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeStrengthMatch: FfiConverterRustBuffer<StrengthMatch> {
+    override fun read(buf: ByteBuffer): StrengthMatch {
+        return StrengthMatch(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterDouble.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: StrengthMatch) = (
+            FfiConverterString.allocationSize(value.`pattern`) +
+            FfiConverterString.allocationSize(value.`token`) +
+            FfiConverterDouble.allocationSize(value.`guesses`)
+    )
+
+    override fun write(value: StrengthMatch, buf: ByteBuffer) {
+            FfiConverterString.write(value.`pattern`, buf)
+            FfiConverterString.write(value.`token`, buf)
+            FfiConverterDouble.write(value.`guesses`, buf)
+    }
+}
+
+
+
+data class SyncConflict (
+    var `id`: kotlin.String, 
+    var `localRevision`: kotlin.Int, 
+    var `serverRevision`: kotlin.Int, 
+    var `server`: VaultItem
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSyncConflict: FfiConverterRustBuffer<SyncConflict> {
+    override fun read(buf: ByteBuffer): SyncConflict {
+        return SyncConflict(
+            FfiConverterString.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterTypeVaultItem.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SyncConflict) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterInt.allocationSize(value.`localRevision`) +
+            FfiConverterInt.allocationSize(value.`serverRevision`) +
+            FfiConverterTypeVaultItem.allocationSize(value.`server`)
+    )
+
+    override fun write(value: SyncConflict, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterInt.write(value.`localRevision`, buf)
+            FfiConverterInt.write(value.`serverRevision`, buf)
+            FfiConverterTypeVaultItem.write(value.`server`, buf)
+    }
+}
+
+
+
+data class SyncResult (
+    var `changed`: List<VaultItem>, 
+    var `removed`: List<kotlin.String>, 
+    var `pushed`: kotlin.UInt, 
+    var `error`: kotlin.String?, 
+    var `timestamp`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSyncResult: FfiConverterRustBuffer<SyncResult> {
+    override fun read(buf: ByteBuffer): SyncResult {
+        return SyncResult(
+            FfiConverterSequenceTypeVaultItem.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SyncResult) = (
+            FfiConverterSequenceTypeVaultItem.allocationSize(value.`changed`) +
+            FfiConverterSequenceString.allocationSize(value.`removed`) +
+            FfiConverterUInt.allocationSize(value.`pushed`) +
+            FfiConverterOptionalString.allocationSize(value.`error`) +
+            FfiConverterULong.allocationSize(value.`timestamp`)
+    )
+
+    override fun write(value: SyncResult, buf: ByteBuffer) {
+            FfiConverterSequenceTypeVaultItem.write(value.`changed`, buf)
+            FfiConverterSequenceString.write(value.`removed`, buf)
+            FfiConverterUInt.write(value.`pushed`, buf)
+            FfiConverterOptionalString.write(value.`error`, buf)
+            FfiConverterULong.write(value.`timestamp`, buf)
+    }
+}
+
+
+
+data class UrlMatch (
+    var `id`: kotlin.String, 
+    var `url`: kotlin.String, 
+    var `rule`: UrlMatchRule, 
+    var `score`: kotlin.UByte
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeUrlMatch: FfiConverterRustBuffer<UrlMatch> {
+    override fun read(buf: ByteBuffer): UrlMatch {
+        return UrlMatch(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypeUrlMatchRule.read(buf),
+            FfiConverterUByte.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: UrlMatch) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterString.allocationSize(value.`url`) +
+            FfiConverterTypeUrlMatchRule.allocationSize(value.`rule`) +
+            FfiConverterUByte.allocationSize(value.`score`)
+    )
+
+    override fun write(value: UrlMatch, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterString.write(value.`url`, buf)
+            FfiConverterTypeUrlMatchRule.write(value.`rule`, buf)
+            FfiConverterUByte.write(value.`score`, buf)
+    }
+}
+
+
+
+data class VaultData (
+    var `items`: List<VaultItem>, 
+    var `folders`: List<Folder>, 
+    var `updated`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVaultData: FfiConverterRustBuffer<VaultData> {
+    override fun read(buf: ByteBuffer): VaultData {
+        return VaultData(
+            FfiConverterSequenceTypeVaultItem.read(buf),
+            FfiConverterSequenceTypeFolder.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: VaultData) = (
+            FfiConverterSequenceTypeVaultItem.allocationSize(value.`items`) +
+            FfiConverterSequenceTypeFolder.allocationSize(value.`folders`) +
+            FfiConverterULong.allocationSize(value.`updated`)
+    )
+
+    override fun write(value: VaultData, buf: ByteBuffer) {
+            FfiConverterSequenceTypeVaultItem.write(value.`items`, buf)
+            FfiConverterSequenceTypeFolder.write(value.`folders`, buf)
+            FfiConverterULong.write(value.`updated`, buf)
+    }
+}
+
+
+
+data class VaultItem (
+    var `id`: kotlin.String, 
+    var `itemType`: kotlin.String, 
+    var `title`: kotlin.String, 
+    var `data`: kotlin.ByteArray, 
+    var `revision`: kotlin.Int, 
+    var `favorite`: kotlin.Boolean, 
+    var `deleted`: kotlin.Boolean, 
+    var `deletedAt`: kotlin.ULong?, 
+    var `tags`: List<kotlin.String>, 
+    var `folderId`: kotlin.String?, 
+    var `created`: kotlin.ULong, 
+    var `updated`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVaultItem: FfiConverterRustBuffer<VaultItem> {
+    override fun read(buf: ByteBuffer): VaultItem {
+        return VaultItem(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: VaultItem) = (
+            FfiConverterString.allocationSize(value.`id`) +
+            FfiConverterString.allocationSize(value.`itemType`) +
+            FfiConverterString.allocationSize(value.`title`) +
+            FfiConverterByteArray.allocationSize(value.`data`) +
+            FfiConverterInt.allocationSize(value.`revision`) +
+            FfiConverterBoolean.allocationSize(value.`favorite`) +
+            FfiConverterBoolean.allocationSize(value.`deleted`) +
+            FfiConverterOptionalULong.allocationSize(value.`deletedAt`) +
+            FfiConverterSequenceString.allocationSize(value.`tags`) +
+            FfiConverterOptionalString.allocationSize(value.`folderId`) +
+            FfiConverterULong.allocationSize(value.`created`) +
+            FfiConverterULong.allocationSize(value.`updated`)
+    )
+
+    override fun write(value: VaultItem, buf: ByteBuffer) {
+            FfiConverterString.write(value.`id`, buf)
+            FfiConverterString.write(value.`itemType`, buf)
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterByteArray.write(value.`data`, buf)
+            FfiConverterInt.write(value.`revision`, buf)
+            FfiConverterBoolean.write(value.`favorite`, buf)
+            FfiConverterBoolean.write(value.`deleted`, buf)
+            FfiConverterOptionalULong.write(value.`deletedAt`, buf)
+            FfiConverterSequenceString.write(value.`tags`, buf)
+            FfiConverterOptionalString.write(value.`folderId`, buf)
+            FfiConverterULong.write(value.`created`, buf)
+            FfiConverterULong.write(value.`updated`, buf)
+    }
+}
+
+
+
+
+
+sealed class BackupException: kotlin.Exception() {
+    
+    class InvalidPassword(
+        ) : BackupException() {
+        override val message
+            get() = ""
+    }
+    
+    class Format(
+        ) : BackupException() {
+        override val message
+            get() = ""
+    }
+    
+    class Unsupported(
+        ) : BackupException() {
+        override val message
+            get() = ""
+    }
+    
+    class Crypto(
+        ) : BackupException() {
+        override val message
+            get() = ""
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<BackupException> {
+        override fun lift(error_buf: RustBuffer.ByValue): BackupException = FfiConverterTypeBackupError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBackupError : FfiConverterRustBuffer<BackupException> {
+    override fun read(buf: ByteBuffer): BackupException {
+        
+
+        return when(buf.getInt()) {
+            1 -> BackupException.InvalidPassword()
+            2 -> BackupException.Format()
+            3 -> BackupException.Unsupported()
+            4 -> BackupException.Crypto()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: BackupException): ULong {
+        return when(value) {
+            is BackupException.InvalidPassword -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is BackupException.Format -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is BackupException.Unsupported -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is BackupException.Crypto -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: BackupException, buf: ByteBuffer) {
+        when(value) {
+            is BackupException.InvalidPassword -> {
+                buf.putInt(1)
+                Unit
+            }
+            is BackupException.Format -> {
+                buf.putInt(2)
+                Unit
+            }
+            is BackupException.Unsupported -> {
+                buf.putInt(3)
+                Unit
+            }
+            is BackupException.Crypto -> {
+                buf.putInt(4)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+sealed class BatchOp {
+    
+    data class Create(
+        val `id`: kotlin.String, 
+        val `itemType`: kotlin.String, 
+        val `title`: kotlin.String, 
+        val `data`: kotlin.ByteArray, 
+        val `tags`: List<kotlin.String>, 
+        val `favorite`: kotlin.Boolean) : BatchOp() {
+        companion object
+    }
+    
+    data class Update(
+        val `id`: kotlin.String, 
+        val `title`: kotlin.String?, 
+        val `data`: kotlin.ByteArray?, 
+        val `tags`: List<kotlin.String>?, 
+        val `favorite`: kotlin.Boolean?) : BatchOp() {
+        companion object
+    }
+    
+    data class Delete(
+        val `id`: kotlin.String) : BatchOp() {
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBatchOp : FfiConverterRustBuffer<BatchOp>{
+    override fun read(buf: ByteBuffer): BatchOp {
+        return when(buf.getInt()) {
+            1 -> BatchOp.Create(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterByteArray.read(buf),
+                FfiConverterSequenceString.read(buf),
+                FfiConverterBoolean.read(buf),
+                )
+            2 -> BatchOp.Update(
+                FfiConverterString.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterOptionalByteArray.read(buf),
+                FfiConverterOptionalSequenceString.read(buf),
+                FfiConverterOptionalBoolean.read(buf),
+                )
+            3 -> BatchOp.Delete(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: BatchOp) = when(value) {
+        is BatchOp.Create -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`id`)
+                + FfiConverterString.allocationSize(value.`itemType`)
+                + FfiConverterString.allocationSize(value.`title`)
+                + FfiConverterByteArray.allocationSize(value.`data`)
+                + FfiConverterSequenceString.allocationSize(value.`tags`)
+                + FfiConverterBoolean.allocationSize(value.`favorite`)
+            )
+        }
+        is BatchOp.Update -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`id`)
+                + FfiConverterOptionalString.allocationSize(value.`title`)
+                + FfiConverterOptionalByteArray.allocationSize(value.`data`)
+                + FfiConverterOptionalSequenceString.allocationSize(value.`tags`)
+                + FfiConverterOptionalBoolean.allocationSize(value.`favorite`)
+            )
+        }
+        is BatchOp.Delete -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`id`)
+            )
+        }
+    }
+
+    override fun write(value: BatchOp, buf: ByteBuffer) {
+        when(value) {
+            is BatchOp.Create -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`id`, buf)
+                FfiConverterString.write(value.`itemType`, buf)
+                FfiConverterString.write(value.`title`, buf)
+                FfiConverterByteArray.write(value.`data`, buf)
+                FfiConverterSequenceString.write(value.`tags`, buf)
+                FfiConverterBoolean.write(value.`favorite`, buf)
+                Unit
+            }
+            is BatchOp.Update -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`id`, buf)
+                FfiConverterOptionalString.write(value.`title`, buf)
+                FfiConverterOptionalByteArray.write(value.`data`, buf)
+                FfiConverterOptionalSequenceString.write(value.`tags`, buf)
+                FfiConverterOptionalBoolean.write(value.`favorite`, buf)
+                Unit
+            }
+            is BatchOp.Delete -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`id`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+
+
+sealed class BreachException: kotlin.Exception() {
+    
+    class Io(
+        
+        val v1: kotlin.String
+        ) : BreachException() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class InvalidDataset(
+        
+        val v1: kotlin.String
+        ) : BreachException() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class Http(
+        
+        val v1: kotlin.String
+        ) : BreachException() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<BreachException> {
+        override fun lift(error_buf: RustBuffer.ByValue): BreachException = FfiConverterTypeBreachError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBreachError : FfiConverterRustBuffer<BreachException> {
+    override fun read(buf: ByteBuffer): BreachException {
+        
+
+        return when(buf.getInt()) {
+            1 -> BreachException.Io(
+                FfiConverterString.read(buf),
+                )
+            2 -> BreachException.InvalidDataset(
+                FfiConverterString.read(buf),
+                )
+            3 -> BreachException.Http(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: BreachException): ULong {
+        return when(value) {
+            is BreachException.Io -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is BreachException.InvalidDataset -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is BreachException.Http -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+        }
+    }
+
+    override fun write(value: BreachException, buf: ByteBuffer) {
+        when(value) {
+            is BreachException.Io -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is BreachException.InvalidDataset -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is BreachException.Http -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+sealed class BreachSource {
+    
+    data class File(
+        val `path`: kotlin.String) : BreachSource() {
+        companion object
+    }
+    
+    data class Directory(
+        val `path`: kotlin.String) : BreachSource() {
+        companion object
+    }
+    
+    data class Http(
+        val `baseUrl`: kotlin.String) : BreachSource() {
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBreachSource : FfiConverterRustBuffer<BreachSource>{
+    override fun read(buf: ByteBuffer): BreachSource {
+        return when(buf.getInt()) {
+            1 -> BreachSource.File(
+                FfiConverterString.read(buf),
+                )
+            2 -> BreachSource.Directory(
+                FfiConverterString.read(buf),
+                )
+            3 -> BreachSource.Http(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: BreachSource) = when(value) {
+        is BreachSource.File -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`path`)
+            )
+        }
+        is BreachSource.Directory -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`path`)
+            )
+        }
+        is BreachSource.Http -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`baseUrl`)
+            )
+        }
+    }
+
+    override fun write(value: BreachSource, buf: ByteBuffer) {
+        when(value) {
+            is BreachSource.File -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`path`, buf)
+                Unit
+            }
+            is BreachSource.Directory -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`path`, buf)
+                Unit
+            }
+            is BreachSource.Http -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`baseUrl`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+
+
+sealed class CryptoException: kotlin.Exception() {
+    
+    class Argon2(
+        ) : CryptoException() {
+        override val message
+            get() = ""
+    }
+    
+    class Encryption(
+        ) : CryptoException() {
+        override val message
+            get() = ""
+    }
+    
+    class Decryption(
+        ) : CryptoException() {
+        override val message
+            get() = ""
+    }
+    
+    class InvalidSecretKey(
+        ) : CryptoException() {
+        override val message
+            get() = ""
+    }
+    
+    class InvalidKeyLength(
+        ) : CryptoException() {
+        override val message
+            get() = ""
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<CryptoException> {
+        override fun lift(error_buf: RustBuffer.ByValue): CryptoException = FfiConverterTypeCryptoError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCryptoError : FfiConverterRustBuffer<CryptoException> {
+    override fun read(buf: ByteBuffer): CryptoException {
+        
+
+        return when(buf.getInt()) {
+            1 -> CryptoException.Argon2()
+            2 -> CryptoException.Encryption()
+            3 -> CryptoException.Decryption()
+            4 -> CryptoException.InvalidSecretKey()
+            5 -> CryptoException.InvalidKeyLength()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: CryptoException): ULong {
+        return when(value) {
+            is CryptoException.Argon2 -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is CryptoException.Encryption -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is CryptoException.Decryption -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is CryptoException.InvalidSecretKey -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is CryptoException.InvalidKeyLength -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: CryptoException, buf: ByteBuffer) {
+        when(value) {
+            is CryptoException.Argon2 -> {
+                buf.putInt(1)
+                Unit
+            }
+            is CryptoException.Encryption -> {
+                buf.putInt(2)
+                Unit
+            }
+            is CryptoException.Decryption -> {
+                buf.putInt(3)
+                Unit
+            }
+            is CryptoException.InvalidSecretKey -> {
+                buf.putInt(4)
+                Unit
+            }
+            is CryptoException.InvalidKeyLength -> {
+                buf.putInt(5)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+
+
+sealed class GeneratorException: kotlin.Exception() {
+    
+    class NoCharacters(
+        ) : GeneratorException() {
+        override val message
+            get() = ""
+    }
+    
+    class TooShort(
+        ) : GeneratorException() {
+        override val message
+            get() = ""
+    }
+    
+    class NotEnoughCharacters(
+        ) : GeneratorException() {
+        override val message
+            get() = ""
+    }
+    
+    class Exhausted(
+        ) : GeneratorException() {
+        override val message
+            get() = ""
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<GeneratorException> {
+        override fun lift(error_buf: RustBuffer.ByValue): GeneratorException = FfiConverterTypeGeneratorError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGeneratorError : FfiConverterRustBuffer<GeneratorException> {
+    override fun read(buf: ByteBuffer): GeneratorException {
+        
+
+        return when(buf.getInt()) {
+            1 -> GeneratorException.NoCharacters()
+            2 -> GeneratorException.TooShort()
+            3 -> GeneratorException.NotEnoughCharacters()
+            4 -> GeneratorException.Exhausted()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: GeneratorException): ULong {
+        return when(value) {
+            is GeneratorException.NoCharacters -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is GeneratorException.TooShort -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is GeneratorException.NotEnoughCharacters -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is GeneratorException.Exhausted -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: GeneratorException, buf: ByteBuffer) {
+        when(value) {
+            is GeneratorException.NoCharacters -> {
+                buf.putInt(1)
+                Unit
+            }
+            is GeneratorException.TooShort -> {
+                buf.putInt(2)
+                Unit
+            }
+            is GeneratorException.NotEnoughCharacters -> {
+                buf.putInt(3)
+                Unit
+            }
+            is GeneratorException.Exhausted -> {
+                buf.putInt(4)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+
+enum class GeneratorMode {
+    
+    RANDOM,
+    PASSPHRASE,
+    PRONOUNCEABLE,
+    PIN;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGeneratorMode: FfiConverterRustBuffer<GeneratorMode> {
+    override fun read(buf: ByteBuffer) = try {
+        GeneratorMode.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: GeneratorMode) = 4UL
+
+    override fun write(value: GeneratorMode, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class OtpAlgorithm {
+    
+    SHA1,
+    SHA256,
+    SHA512;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpAlgorithm: FfiConverterRustBuffer<OtpAlgorithm> {
+    override fun read(buf: ByteBuffer) = try {
+        OtpAlgorithm.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: OtpAlgorithm) = 4UL
+
+    override fun write(value: OtpAlgorithm, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+sealed class OtpException: kotlin.Exception() {
+    
+    class InvalidSecret(
+        ) : OtpException() {
+        override val message
+            get() = ""
+    }
+    
+    class InvalidUri(
+        ) : OtpException() {
+        override val message
+            get() = ""
+    }
+    
+    class Unsupported(
+        ) : OtpException() {
+        override val message
+            get() = ""
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<OtpException> {
+        override fun lift(error_buf: RustBuffer.ByValue): OtpException = FfiConverterTypeOtpError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpError : FfiConverterRustBuffer<OtpException> {
+    override fun read(buf: ByteBuffer): OtpException {
+        
+
+        return when(buf.getInt()) {
+            1 -> OtpException.InvalidSecret()
+            2 -> OtpException.InvalidUri()
+            3 -> OtpException.Unsupported()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: OtpException): ULong {
+        return when(value) {
+            is OtpException.InvalidSecret -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is OtpException.InvalidUri -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is OtpException.Unsupported -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: OtpException, buf: ByteBuffer) {
+        when(value) {
+            is OtpException.InvalidSecret -> {
+                buf.putInt(1)
+                Unit
+            }
+            is OtpException.InvalidUri -> {
+                buf.putInt(2)
+                Unit
+            }
+            is OtpException.Unsupported -> {
+                buf.putInt(3)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+
+enum class OtpKind {
+    
+    TOTP,
+    HOTP,
+    STEAM;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOtpKind: FfiConverterRustBuffer<OtpKind> {
+    override fun read(buf: ByteBuffer) = try {
+        OtpKind.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: OtpKind) = 4UL
+
+    override fun write(value: OtpKind, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class PendingChange {
+    
+    data class Create(
+        val `id`: kotlin.String, 
+        val `itemType`: kotlin.String, 
+        val `title`: kotlin.String, 
+        val `data`: kotlin.ByteArray, 
+        val `tags`: List<kotlin.String>, 
+        val `favorite`: kotlin.Boolean) : PendingChange() {
+        companion object
+    }
+    
+    data class Update(
+        val `id`: kotlin.String, 
+        val `revision`: kotlin.Int, 
+        val `title`: kotlin.String?, 
+        val `data`: kotlin.ByteArray?, 
+        val `tags`: List<kotlin.String>?, 
+        val `favorite`: kotlin.Boolean?) : PendingChange() {
+        companion object
+    }
+    
+    data class Delete(
+        val `id`: kotlin.String) : PendingChange() {
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePendingChange : FfiConverterRustBuffer<PendingChange>{
+    override fun read(buf: ByteBuffer): PendingChange {
+        return when(buf.getInt()) {
+            1 -> PendingChange.Create(
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterByteArray.read(buf),
+                FfiConverterSequenceString.read(buf),
+                FfiConverterBoolean.read(buf),
+                )
+            2 -> PendingChange.Update(
+                FfiConverterString.read(buf),
+                FfiConverterInt.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterOptionalByteArray.read(buf),
+                FfiConverterOptionalSequenceString.read(buf),
+                FfiConverterOptionalBoolean.read(buf),
+                )
+            3 -> PendingChange.Delete(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: PendingChange) = when(value) {
+        is PendingChange.Create -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`id`)
+                + FfiConverterString.allocationSize(value.`itemType`)
+                + FfiConverterString.allocationSize(value.`title`)
+                + FfiConverterByteArray.allocationSize(value.`data`)
+                + FfiConverterSequenceString.allocationSize(value.`tags`)
+                + FfiConverterBoolean.allocationSize(value.`favorite`)
+            )
+        }
+        is PendingChange.Update -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`id`)
+                + FfiConverterInt.allocationSize(value.`revision`)
+                + FfiConverterOptionalString.allocationSize(value.`title`)
+                + FfiConverterOptionalByteArray.allocationSize(value.`data`)
+                + FfiConverterOptionalSequenceString.allocationSize(value.`tags`)
+                + FfiConverterOptionalBoolean.allocationSize(value.`favorite`)
+            )
+        }
+        is PendingChange.Delete -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`id`)
+            )
+        }
+    }
+
+    override fun write(value: PendingChange, buf: ByteBuffer) {
+        when(value) {
+            is PendingChange.Create -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`id`, buf)
+                FfiConverterString.write(value.`itemType`, buf)
+                FfiConverterString.write(value.`title`, buf)
+                FfiConverterByteArray.write(value.`data`, buf)
+                FfiConverterSequenceString.write(value.`tags`, buf)
+                FfiConverterBoolean.write(value.`favorite`, buf)
+                Unit
+            }
+            is PendingChange.Update -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`id`, buf)
+                FfiConverterInt.write(value.`revision`, buf)
+                FfiConverterOptionalString.write(value.`title`, buf)
+                FfiConverterOptionalByteArray.write(value.`data`, buf)
+                FfiConverterOptionalSequenceString.write(value.`tags`, buf)
+                FfiConverterOptionalBoolean.write(value.`favorite`, buf)
+                Unit
+            }
+            is PendingChange.Delete -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`id`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+
+enum class PlainExportFormat {
+    
+    JSON,
+    CSV;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePlainExportFormat: FfiConverterRustBuffer<PlainExportFormat> {
+    override fun read(buf: ByteBuffer) = try {
+        PlainExportFormat.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: PlainExportFormat) = 4UL
+
+    override fun write(value: PlainExportFormat, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+sealed class SyncException: kotlin.Exception() {
+    
+    class Http(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+    class Auth(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+    class Conflict(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+    class Rejected(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+    class Parse(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+    class Crypto(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+    class Locked(
+        ) : SyncException() {
+        override val message
+            get() = ""
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<SyncException> {
+        override fun lift(error_buf: RustBuffer.ByValue): SyncException = FfiConverterTypeSyncError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSyncError : FfiConverterRustBuffer<SyncException> {
+    override fun read(buf: ByteBuffer): SyncException {
+        
+
+        return when(buf.getInt()) {
+            1 -> SyncException.Http()
+            2 -> SyncException.Auth()
+            3 -> SyncException.Conflict()
+            4 -> SyncException.Rejected()
+            5 -> SyncException.Parse()
+            6 -> SyncException.Crypto()
+            7 -> SyncException.Locked()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: SyncException): ULong {
+        return when(value) {
+            is SyncException.Http -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is SyncException.Auth -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is SyncException.Conflict -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is SyncException.Rejected -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is SyncException.Parse -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is SyncException.Crypto -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is SyncException.Locked -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: SyncException, buf: ByteBuffer) {
+        when(value) {
+            is SyncException.Http -> {
+                buf.putInt(1)
+                Unit
+            }
+            is SyncException.Auth -> {
+                buf.putInt(2)
+                Unit
+            }
+            is SyncException.Conflict -> {
+                buf.putInt(3)
+                Unit
+            }
+            is SyncException.Rejected -> {
+                buf.putInt(4)
+                Unit
+            }
+            is SyncException.Parse -> {
+                buf.putInt(5)
+                Unit
+            }
+            is SyncException.Crypto -> {
+                buf.putInt(6)
+                Unit
+            }
+            is SyncException.Locked -> {
+                buf.putInt(7)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+
+enum class UrlMatchRule {
+    
+    DOMAIN,
+    HOST,
+    STARTS_WITH,
+    REGEX,
+    NEVER;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeUrlMatchRule: FfiConverterRustBuffer<UrlMatchRule> {
+    override fun read(buf: ByteBuffer) = try {
+        UrlMatchRule.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: UrlMatchRule) = 4UL
+
+    override fun write(value: UrlMatchRule, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+sealed class VaultException: kotlin.Exception() {
+    
+    class NotFound(
+        ) : VaultException() {
+        override val message
+            get() = ""
+    }
+    
+    class Serialization(
+        ) : VaultException() {
+        override val message
+            get() = ""
+    }
+    
+    class Crypto(
+        ) : VaultException() {
+        override val message
+            get() = ""
+    }
+    
+    class Cycle(
+        ) : VaultException() {
+        override val message
+            get() = ""
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<VaultException> {
+        override fun lift(error_buf: RustBuffer.ByValue): VaultException = FfiConverterTypeVaultError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVaultError : FfiConverterRustBuffer<VaultException> {
+    override fun read(buf: ByteBuffer): VaultException {
+        
+
+        return when(buf.getInt()) {
+            1 -> VaultException.NotFound()
+            2 -> VaultException.Serialization()
+            3 -> VaultException.Crypto()
+            4 -> VaultException.Cycle()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: VaultException): ULong {
+        return when(value) {
+            is VaultException.NotFound -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is VaultException.Serialization -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is VaultException.Crypto -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is VaultException.Cycle -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
+    }
+
+    override fun write(value: VaultException, buf: ByteBuffer) {
+        when(value) {
+            is VaultException.NotFound -> {
+                buf.putInt(1)
+                Unit
             }
-            is CryptoException.InvalidSecretKey -> {
-                buf.putInt(4)
+            is VaultException.Serialization -> {
+                buf.putInt(2)
                 Unit
             }
-            is CryptoException.InvalidKeyLength -> {
-                buf.putInt(5)
+            is VaultException.Crypto -> {
+                buf.putInt(3)
+                Unit
+            }
+            is VaultException.Cycle -> {
+                buf.putInt(4)
                 Unit
             }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
 
-}
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalInt: FfiConverterRustBuffer<kotlin.Int?> {
+    override fun read(buf: ByteBuffer): kotlin.Int? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterInt.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Int?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterInt.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Int?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterInt.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalULong: FfiConverterRustBuffer<kotlin.ULong?> {
+    override fun read(buf: ByteBuffer): kotlin.ULong? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterULong.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ULong?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterULong.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ULong?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterULong.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalBoolean: FfiConverterRustBuffer<kotlin.Boolean?> {
+    override fun read(buf: ByteBuffer): kotlin.Boolean? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterBoolean.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Boolean?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterBoolean.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Boolean?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterBoolean.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
+    override fun read(buf: ByteBuffer): kotlin.String? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterString.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.String?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterString.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterString.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalByteArray: FfiConverterRustBuffer<kotlin.ByteArray?> {
+    override fun read(buf: ByteBuffer): kotlin.ByteArray? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterByteArray.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ByteArray?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterByteArray.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ByteArray?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterByteArray.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeSyncListener: FfiConverterRustBuffer<SyncListener?> {
+    override fun read(buf: ByteBuffer): SyncListener? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeSyncListener.read(buf)
+    }
+
+    override fun allocationSize(value: SyncListener?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSyncListener.allocationSize(value)
+        }
+    }
+
+    override fun write(value: SyncListener?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSyncListener.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeOtpCode: FfiConverterRustBuffer<OtpCode?> {
+    override fun read(buf: ByteBuffer): OtpCode? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeOtpCode.read(buf)
+    }
+
+    override fun allocationSize(value: OtpCode?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeOtpCode.allocationSize(value)
+        }
+    }
+
+    override fun write(value: OtpCode?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeOtpCode.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeOtpConfig: FfiConverterRustBuffer<OtpConfig?> {
+    override fun read(buf: ByteBuffer): OtpConfig? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeOtpConfig.read(buf)
+    }
+
+    override fun allocationSize(value: OtpConfig?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeOtpConfig.allocationSize(value)
+        }
+    }
+
+    override fun write(value: OtpConfig?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeOtpConfig.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeVaultItem: FfiConverterRustBuffer<VaultItem?> {
+    override fun read(buf: ByteBuffer): VaultItem? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeVaultItem.read(buf)
+    }
+
+    override fun allocationSize(value: VaultItem?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeVaultItem.allocationSize(value)
+        }
+    }
 
+    override fun write(value: VaultItem?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeVaultItem.write(value, buf)
+        }
+    }
+}
 
 
 
 
-sealed class SyncException: kotlin.Exception() {
-    
-    class Http(
-        ) : SyncException() {
-        override val message
-            get() = ""
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeUrlMatchRule: FfiConverterRustBuffer<UrlMatchRule?> {
+    override fun read(buf: ByteBuffer): UrlMatchRule? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeUrlMatchRule.read(buf)
     }
-    
-    class Auth(
-        ) : SyncException() {
-        override val message
-            get() = ""
+
+    override fun allocationSize(value: UrlMatchRule?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeUrlMatchRule.allocationSize(value)
+        }
     }
-    
-    class Conflict(
-        ) : SyncException() {
-        override val message
-            get() = ""
+
+    override fun write(value: UrlMatchRule?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeUrlMatchRule.write(value, buf)
+        }
     }
-    
-    class Parse(
-        ) : SyncException() {
-        override val message
-            get() = ""
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceString: FfiConverterRustBuffer<List<kotlin.String>?> {
+    override fun read(buf: ByteBuffer): List<kotlin.String>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceString.read(buf)
     }
-    
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<SyncException> {
-        override fun lift(error_buf: RustBuffer.ByValue): SyncException = FfiConverterTypeSyncError.lift(error_buf)
+    override fun allocationSize(value: List<kotlin.String>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceString.allocationSize(value)
+        }
     }
 
-    
+    override fun write(value: List<kotlin.String>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceString.write(value, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeSyncError : FfiConverterRustBuffer<SyncException> {
-    override fun read(buf: ByteBuffer): SyncException {
-        
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
+    override fun read(buf: ByteBuffer): List<kotlin.String> {
+        val len = buf.getInt()
+        return List<kotlin.String>(len) {
+            FfiConverterString.read(buf)
+        }
+    }
 
-        return when(buf.getInt()) {
-            1 -> SyncException.Http()
-            2 -> SyncException.Auth()
-            3 -> SyncException.Conflict()
-            4 -> SyncException.Parse()
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
         }
     }
+}
 
-    override fun allocationSize(value: SyncException): ULong {
-        return when(value) {
-            is SyncException.Http -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is SyncException.Auth -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is SyncException.Conflict -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is SyncException.Parse -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeBackupAttachment: FfiConverterRustBuffer<List<BackupAttachment>> {
+    override fun read(buf: ByteBuffer): List<BackupAttachment> {
+        val len = buf.getInt()
+        return List<BackupAttachment>(len) {
+            FfiConverterTypeBackupAttachment.read(buf)
         }
     }
 
-    override fun write(value: SyncException, buf: ByteBuffer) {
-        when(value) {
-            is SyncException.Http -> {
-                buf.putInt(1)
-                Unit
-            }
-            is SyncException.Auth -> {
-                buf.putInt(2)
-                Unit
-            }
-            is SyncException.Conflict -> {
-                buf.putInt(3)
-                Unit
-            }
-            is SyncException.Parse -> {
-                buf.putInt(4)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    override fun allocationSize(value: List<BackupAttachment>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBackupAttachment.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
+    override fun write(value: List<BackupAttachment>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBackupAttachment.write(it, buf)
+        }
+    }
 }
 
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeBatchItemResult: FfiConverterRustBuffer<List<BatchItemResult>> {
+    override fun read(buf: ByteBuffer): List<BatchItemResult> {
+        val len = buf.getInt()
+        return List<BatchItemResult>(len) {
+            FfiConverterTypeBatchItemResult.read(buf)
+        }
+    }
 
-sealed class VaultException: kotlin.Exception() {
-    
-    class NotFound(
-        ) : VaultException() {
-        override val message
-            get() = ""
+    override fun allocationSize(value: List<BatchItemResult>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBatchItemResult.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
-    class Serialization(
-        ) : VaultException() {
-        override val message
-            get() = ""
+
+    override fun write(value: List<BatchItemResult>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBatchItemResult.write(it, buf)
+        }
     }
-    
-    class Crypto(
-        ) : VaultException() {
-        override val message
-            get() = ""
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeCrackTime: FfiConverterRustBuffer<List<CrackTime>> {
+    override fun read(buf: ByteBuffer): List<CrackTime> {
+        val len = buf.getInt()
+        return List<CrackTime>(len) {
+            FfiConverterTypeCrackTime.read(buf)
+        }
     }
-    
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<VaultException> {
-        override fun lift(error_buf: RustBuffer.ByValue): VaultException = FfiConverterTypeVaultError.lift(error_buf)
+    override fun allocationSize(value: List<CrackTime>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeCrackTime.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    
+    override fun write(value: List<CrackTime>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeCrackTime.write(it, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeVaultError : FfiConverterRustBuffer<VaultException> {
-    override fun read(buf: ByteBuffer): VaultException {
-        
-
-        return when(buf.getInt()) {
-            1 -> VaultException.NotFound()
-            2 -> VaultException.Serialization()
-            3 -> VaultException.Crypto()
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+public object FfiConverterSequenceTypeFolder: FfiConverterRustBuffer<List<Folder>> {
+    override fun read(buf: ByteBuffer): List<Folder> {
+        val len = buf.getInt()
+        return List<Folder>(len) {
+            FfiConverterTypeFolder.read(buf)
         }
     }
 
-    override fun allocationSize(value: VaultException): ULong {
-        return when(value) {
-            is VaultException.NotFound -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is VaultException.Serialization -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
-            is VaultException.Crypto -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
+    override fun allocationSize(value: List<Folder>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeFolder.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Folder>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeFolder.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeItemAudit: FfiConverterRustBuffer<List<ItemAudit>> {
+    override fun read(buf: ByteBuffer): List<ItemAudit> {
+        val len = buf.getInt()
+        return List<ItemAudit>(len) {
+            FfiConverterTypeItemAudit.read(buf)
         }
     }
 
-    override fun write(value: VaultException, buf: ByteBuffer) {
-        when(value) {
-            is VaultException.NotFound -> {
-                buf.putInt(1)
-                Unit
-            }
-            is VaultException.Serialization -> {
-                buf.putInt(2)
-                Unit
-            }
-            is VaultException.Crypto -> {
-                buf.putInt(3)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    override fun allocationSize(value: List<ItemAudit>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeItemAudit.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
+    override fun write(value: List<ItemAudit>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeItemAudit.write(it, buf)
+        }
+    }
 }
 
 
@@ -2416,28 +6842,24 @@ public object FfiConverterTypeVaultError : FfiConverterRustBuffer<VaultException
 /**
  * @suppress
  */
-public object FfiConverterOptionalBoolean: FfiConverterRustBuffer<kotlin.Boolean?> {
-    override fun read(buf: ByteBuffer): kotlin.Boolean? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeLoginUrl: FfiConverterRustBuffer<List<LoginUrl>> {
+    override fun read(buf: ByteBuffer): List<LoginUrl> {
+        val len = buf.getInt()
+        return List<LoginUrl>(len) {
+            FfiConverterTypeLoginUrl.read(buf)
         }
-        return FfiConverterBoolean.read(buf)
     }
 
-    override fun allocationSize(value: kotlin.Boolean?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterBoolean.allocationSize(value)
-        }
+    override fun allocationSize(value: List<LoginUrl>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeLoginUrl.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: kotlin.Boolean?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterBoolean.write(value, buf)
+    override fun write(value: List<LoginUrl>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeLoginUrl.write(it, buf)
         }
     }
 }
@@ -2448,28 +6870,24 @@ public object FfiConverterOptionalBoolean: FfiConverterRustBuffer<kotlin.Boolean
 /**
  * @suppress
  */
-public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
-    override fun read(buf: ByteBuffer): kotlin.String? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeLoginUrls: FfiConverterRustBuffer<List<LoginUrls>> {
+    override fun read(buf: ByteBuffer): List<LoginUrls> {
+        val len = buf.getInt()
+        return List<LoginUrls>(len) {
+            FfiConverterTypeLoginUrls.read(buf)
         }
-        return FfiConverterString.read(buf)
     }
 
-    override fun allocationSize(value: kotlin.String?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterString.allocationSize(value)
-        }
+    override fun allocationSize(value: List<LoginUrls>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeLoginUrls.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: kotlin.String?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterString.write(value, buf)
+    override fun write(value: List<LoginUrls>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeLoginUrls.write(it, buf)
         }
     }
 }
@@ -2480,28 +6898,24 @@ public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?>
 /**
  * @suppress
  */
-public object FfiConverterOptionalByteArray: FfiConverterRustBuffer<kotlin.ByteArray?> {
-    override fun read(buf: ByteBuffer): kotlin.ByteArray? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeOtpImportCandidate: FfiConverterRustBuffer<List<OtpImportCandidate>> {
+    override fun read(buf: ByteBuffer): List<OtpImportCandidate> {
+        val len = buf.getInt()
+        return List<OtpImportCandidate>(len) {
+            FfiConverterTypeOtpImportCandidate.read(buf)
         }
-        return FfiConverterByteArray.read(buf)
     }
 
-    override fun allocationSize(value: kotlin.ByteArray?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterByteArray.allocationSize(value)
-        }
+    override fun allocationSize(value: List<OtpImportCandidate>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeOtpImportCandidate.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: kotlin.ByteArray?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterByteArray.write(value, buf)
+    override fun write(value: List<OtpImportCandidate>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeOtpImportCandidate.write(it, buf)
         }
     }
 }
@@ -2512,28 +6926,24 @@ public object FfiConverterOptionalByteArray: FfiConverterRustBuffer<kotlin.ByteA
 /**
  * @suppress
  */
-public object FfiConverterOptionalTypeVaultItem: FfiConverterRustBuffer<VaultItem?> {
-    override fun read(buf: ByteBuffer): VaultItem? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeReusedGroup: FfiConverterRustBuffer<List<ReusedGroup>> {
+    override fun read(buf: ByteBuffer): List<ReusedGroup> {
+        val len = buf.getInt()
+        return List<ReusedGroup>(len) {
+            FfiConverterTypeReusedGroup.read(buf)
         }
-        return FfiConverterTypeVaultItem.read(buf)
     }
 
-    override fun allocationSize(value: VaultItem?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterTypeVaultItem.allocationSize(value)
-        }
+    override fun allocationSize(value: List<ReusedGroup>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeReusedGroup.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: VaultItem?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterTypeVaultItem.write(value, buf)
+    override fun write(value: List<ReusedGroup>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeReusedGroup.write(it, buf)
         }
     }
 }
@@ -2544,28 +6954,24 @@ public object FfiConverterOptionalTypeVaultItem: FfiConverterRustBuffer<VaultIte
 /**
  * @suppress
  */
-public object FfiConverterOptionalSequenceString: FfiConverterRustBuffer<List<kotlin.String>?> {
-    override fun read(buf: ByteBuffer): List<kotlin.String>? {
-        if (buf.get().toInt() == 0) {
-            return null
+public object FfiConverterSequenceTypeStrengthMatch: FfiConverterRustBuffer<List<StrengthMatch>> {
+    override fun read(buf: ByteBuffer): List<StrengthMatch> {
+        val len = buf.getInt()
+        return List<StrengthMatch>(len) {
+            FfiConverterTypeStrengthMatch.read(buf)
         }
-        return FfiConverterSequenceString.read(buf)
     }
 
-    override fun allocationSize(value: List<kotlin.String>?): ULong {
-        if (value == null) {
-            return 1UL
-        } else {
-            return 1UL + FfiConverterSequenceString.allocationSize(value)
-        }
+    override fun allocationSize(value: List<StrengthMatch>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeStrengthMatch.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: List<kotlin.String>?, buf: ByteBuffer) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterSequenceString.write(value, buf)
+    override fun write(value: List<StrengthMatch>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeStrengthMatch.write(it, buf)
         }
     }
 }
@@ -2576,24 +6982,24 @@ public object FfiConverterOptionalSequenceString: FfiConverterRustBuffer<List<ko
 /**
  * @suppress
  */
-public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
-    override fun read(buf: ByteBuffer): List<kotlin.String> {
+public object FfiConverterSequenceTypeUrlMatch: FfiConverterRustBuffer<List<UrlMatch>> {
+    override fun read(buf: ByteBuffer): List<UrlMatch> {
         val len = buf.getInt()
-        return List<kotlin.String>(len) {
-            FfiConverterString.read(buf)
+        return List<UrlMatch>(len) {
+            FfiConverterTypeUrlMatch.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<kotlin.String>): ULong {
+    override fun allocationSize(value: List<UrlMatch>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeUrlMatch.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+    override fun write(value: List<UrlMatch>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterString.write(it, buf)
+            FfiConverterTypeUrlMatch.write(it, buf)
         }
     }
 }
@@ -2625,6 +7031,72 @@ public object FfiConverterSequenceTypeVaultItem: FfiConverterRustBuffer<List<Vau
         }
     }
 }
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeBatchOp: FfiConverterRustBuffer<List<BatchOp>> {
+    override fun read(buf: ByteBuffer): List<BatchOp> {
+        val len = buf.getInt()
+        return List<BatchOp>(len) {
+            FfiConverterTypeBatchOp.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<BatchOp>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBatchOp.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<BatchOp>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBatchOp.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypePendingChange: FfiConverterRustBuffer<List<PendingChange>> {
+    override fun read(buf: ByteBuffer): List<PendingChange> {
+        val len = buf.getInt()
+        return List<PendingChange>(len) {
+            FfiConverterTypePendingChange.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<PendingChange>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypePendingChange.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<PendingChange>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypePendingChange.write(it, buf)
+        }
+    }
+}
+    @Throws(BreachException::class) fun `checkBreachedPassword`(`source`: BreachSource, `password`: kotlin.String): BreachResult {
+            return FfiConverterTypeBreachResult.lift(
+    uniffiRustCallWithError(BreachException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_check_breached_password(
+        FfiConverterTypeBreachSource.lower(`source`),FfiConverterString.lower(`password`),_status)
+}
+    )
+    }
+    
+
     @Throws(CryptoException::class) fun `decrypt`(`ciphertext`: kotlin.ByteArray, `key`: kotlin.ByteArray): kotlin.ByteArray {
             return FfiConverterByteArray.lift(
     uniffiRustCallWithError(CryptoException) { _status ->
@@ -2664,6 +7136,15 @@ public object FfiConverterSequenceTypeVaultItem: FfiConverterRustBuffer<List<Vau
     )
     }
     
+ fun `estimatePasswordStrength`(`password`: kotlin.String, `userInputs`: List<kotlin.String>): PasswordStrength {
+            return FfiConverterTypePasswordStrength.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_estimate_password_strength(
+        FfiConverterString.lower(`password`),FfiConverterSequenceString.lower(`userInputs`),_status)
+}
+    )
+    }
+    
  fun `generateItemId`(): kotlin.String {
             return FfiConverterString.lift(
     uniffiRustCall() { _status ->
@@ -2673,6 +7154,16 @@ public object FfiConverterSequenceTypeVaultItem: FfiConverterRustBuffer<List<Vau
     )
     }
     
+
+    @Throws(GeneratorException::class) fun `generatePassword`(`options`: GeneratorOptions): GeneratorResult {
+            return FfiConverterTypeGeneratorResult.lift(
+    uniffiRustCallWithError(GeneratorException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_generate_password(
+        FfiConverterTypeGeneratorOptions.lower(`options`),_status)
+}
+    )
+    }
+    
  fun `generateSalt`(): kotlin.ByteArray {
             return FfiConverterByteArray.lift(
     uniffiRustCall() { _status ->
@@ -2700,6 +7191,100 @@ public object FfiConverterSequenceTypeVaultItem: FfiConverterRustBuffer<List<Vau
     )
     }
     
+ fun `matchUrl`(`pageUrl`: kotlin.String, `logins`: List<LoginUrls>): List<UrlMatch> {
+            return FfiConverterSequenceTypeUrlMatch.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_match_url(
+        FfiConverterString.lower(`pageUrl`),FfiConverterSequenceTypeLoginUrls.lower(`logins`),_status)
+}
+    )
+    }
+    
+
+    @Throws(OtpException::class) fun `otpCode`(`config`: OtpConfig, `timestamp`: kotlin.ULong?): OtpCode {
+            return FfiConverterTypeOtpCode.lift(
+    uniffiRustCallWithError(OtpException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_otp_code(
+        FfiConverterTypeOtpConfig.lower(`config`),FfiConverterOptionalULong.lower(`timestamp`),_status)
+}
+    )
+    }
+    
+
+    @Throws(OtpException::class) fun `otpFromItem`(`data`: kotlin.ByteArray): OtpConfig? {
+            return FfiConverterOptionalTypeOtpConfig.lift(
+    uniffiRustCallWithError(OtpException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_otp_from_item(
+        FfiConverterByteArray.lower(`data`),_status)
+}
+    )
+    }
+    
+ fun `otpItemData`(`config`: OtpConfig): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_otp_item_data(
+        FfiConverterTypeOtpConfig.lower(`config`),_status)
+}
+    )
+    }
+    
+ fun `otpUri`(`config`: OtpConfig): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_otp_uri(
+        FfiConverterTypeOtpConfig.lower(`config`),_status)
+}
+    )
+    }
+    
+
+    @Throws(OtpException::class) fun `parseOtp`(`input`: kotlin.String): OtpConfig {
+            return FfiConverterTypeOtpConfig.lift(
+    uniffiRustCallWithError(OtpException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_parse_otp(
+        FfiConverterString.lower(`input`),_status)
+}
+    )
+    }
+    
+ fun `parseOtpImport`(`text`: kotlin.String): OtpImportPreview {
+            return FfiConverterTypeOtpImportPreview.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_parse_otp_import(
+        FfiConverterString.lower(`text`),_status)
+}
+    )
+    }
+    
+
+    @Throws(BackupException::class) fun `readBackupManifest`(`archive`: kotlin.ByteArray): BackupManifest {
+            return FfiConverterTypeBackupManifest.lift(
+    uniffiRustCallWithError(BackupException) { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_read_backup_manifest(
+        FfiConverterByteArray.lower(`archive`),_status)
+}
+    )
+    }
+    
+ fun `registrableDomain`(`url`: kotlin.String): kotlin.String? {
+            return FfiConverterOptionalString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_registrable_domain(
+        FfiConverterString.lower(`url`),_status)
+}
+    )
+    }
+    
+ fun `trashRetentionDays`(): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_noro_mobile_core_fn_func_trash_retention_days(
+        _status)
+}
+    )
+    }
+    
 
     @Throws(CryptoException::class) fun `unwrapVaultKey`(`wrapped`: kotlin.ByteArray, `auk`: kotlin.ByteArray): kotlin.ByteArray {
             return FfiConverterByteArray.lift(
@@ -0,0 +1,284 @@
+use noro_common::otp;
+use noro_common::search::SearchDoc;
+use noro_common::strength;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+const WEAK_SCORE: u8 = 2;
+const OLD_DAYS: u64 = 365;
+const DAY: u64 = 86400;
+
+pub struct AuditItem<'a> {
+    pub id: &'a str,
+    pub item_type: &'a str,
+    pub title: &'a str,
+    pub data: &'a [u8],
+    pub updated: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemAudit {
+    pub id: String,
+    pub title: String,
+    pub score: u8,
+    pub strength: String,
+    pub warning: Option<String>,
+    pub age_days: u64,
+    pub reused: usize,
+    pub weak: bool,
+    pub old: bool,
+    pub has_totp: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReusedGroup {
+    pub ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AuditReport {
+    pub total: usize,
+    pub weak: usize,
+    pub reused: usize,
+    pub old: usize,
+    pub missing_totp: usize,
+    pub groups: Vec<ReusedGroup>,
+    pub items: Vec<ItemAudit>,
+}
+
+fn password(data: &[u8]) -> Option<String> {
+    let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(data) else {
+        return None;
+    };
+    map.get("password")
+        .and_then(Value::as_str)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+}
+
+pub fn audit<'a>(items: impl IntoIterator<Item = AuditItem<'a>>, now: u64) -> AuditReport {
+    let mut report = AuditReport::default();
+    let mut groups: HashMap<[u8; 32], Vec<String>> = HashMap::new();
+    let mut order: Vec<[u8; 32]> = Vec::new();
+
+    for item in items {
+        if !item.item_type.eq_ignore_ascii_case("login") {
+            continue;
+        }
+        let Some(password) = password(item.data) else {
+            continue;
+        };
+        let doc = SearchDoc::new(item.id, item.item_type, item.title, item.data, &[], false);
+        let mut inputs = vec![item.title.to_string()];
+        inputs.extend(doc.usernames);
+        inputs.extend(doc.urls);
+        let estimate = strength::estimate(&password, &inputs);
+        let age_days = now.saturating_sub(item.updated) / DAY;
+
+        let digest: [u8; 32] = Sha256::digest(password.as_bytes()).into();
+        let group = groups.entry(digest).or_default();
+        if group.is_empty() {
+            order.push(digest);
+        }
+        group.push(item.id.to_string());
+
+        report.items.push(ItemAudit {
+            id: item.id.to_string(),
+            title: item.title.to_string(),
+            score: estimate.score,
+            strength: estimate.strength,
+            warning: estimate.warning,
+            age_days,
+            reused: 0,
+            weak: estimate.score <= WEAK_SCORE,
+            old: age_days >= OLD_DAYS,
+            has_totp: otp::fromdata(item.data).is_some(),
+        });
+    }
+
+    for digest in order {
+        let ids = &groups[&digest];
+        if ids.len() < 2 {
+            continue;
+        }
+        for entry in report.items.iter_mut().filter(|e| ids.contains(&e.id)) {
+            entry.reused = ids.len() - 1;
+        }
+        report.groups.push(ReusedGroup { ids: ids.clone() });
+    }
+
+    report.total = report.items.len();
+    report.weak = report.items.iter().filter(|e| e.weak).count();
+    report.reused = report.items.iter().filter(|e| e.reused > 0).count();
+    report.old = report.items.iter().filter(|e| e.old).count();
+    report.missing_totp = report.items.iter().filter(|e| !e.has_totp).count();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_800_000_000;
+    const STRONG: &str = r#"{"username":"ann","password":"xK9#mQ2$vL7@pR4!wZ"}"#;
+    const WEAK: &str = r#"{"username":"ann","password":"Password1!Password1!"}"#;
+
+    fn run(items: &[(&str, &str, &str)]) -> AuditReport {
+        aged(
+            &items
+                .iter()
+                .map(|(id, item_type, data)| (*id, *item_type, *data, NOW))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn aged(items: &[(&str, &str, &str, u64)]) -> AuditReport {
+        audit(
+            items
+                .iter()
+                .map(|(id, item_type, data, updated)| AuditItem {
+                    id,
+                    item_type,
+                    title: id,
+                    data: data.as_bytes(),
+                    updated: *updated,
+                }),
+            NOW,
+        )
+    }
+
+    fn item<'a>(report: &'a AuditReport, id: &str) -> &'a ItemAudit {
+        report.items.iter().find(|e| e.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_empty_vault() {
+        assert_eq!(run(&[]), AuditReport::default());
+    }
+
+    #[test]
+    fn test_skips_items_without_password() {
+        let report = run(&[
+            ("note", "note", r#"{"password":"hunter2"}"#),
+            ("card", "card", r#"{"number":"4111"}"#),
+            ("missing", "login", r#"{"username":"ann"}"#),
+            ("empty", "login", r#"{"password":""}"#),
+            ("number", "login", r#"{"password":1234}"#),
+            ("array", "login", r#"["password"]"#),
+            ("broken", "login", "not json"),
+            ("upper", "LOGIN", STRONG),
+        ]);
+        assert_eq!(report.total, 1);
+        assert_eq!(report.items[0].id, "upper");
+    }
+
+    #[test]
+    fn test_reused_groups() {
+        let report = run(&[
+            ("a", "login", WEAK),
+            ("b", "login", STRONG),
+            ("c", "login", WEAK),
+            ("d", "login", r#"{"password":"unique-but-short"}"#),
+            ("e", "login", STRONG),
+            ("f", "login", WEAK),
+        ]);
+        let ids = |ids: &[&str]| ReusedGroup {
+            ids: ids.iter().map(|s| s.to_string()).collect(),
+        };
+        assert_eq!(report.groups, [ids(&["a", "c", "f"]), ids(&["b", "e"])]);
+        assert_eq!(report.reused, 5);
+        assert_eq!(item(&report, "a").reused, 2);
+        assert_eq!(item(&report, "e").reused, 1);
+        assert_eq!(item(&report, "d").reused, 0);
+    }
+
+    #[test]
+    fn test_similar_passwords_not_reused() {
+        let report = run(&[
+            ("a", "login", r#"{"password":"Correct-Horse-1"}"#),
+            ("b", "login", r#"{"password":"correct-horse-1"}"#),
+            ("c", "login", r#"{"password":"Correct-Horse-1 "}"#),
+        ]);
+        assert!(report.groups.is_empty());
+        assert_eq!(report.reused, 0);
+    }
+
+    #[test]
+    fn test_weak_passwords() {
+        let report = run(&[("weak", "login", WEAK), ("strong", "login", STRONG)]);
+        assert_eq!(report.weak, 1);
+        let weak = item(&report, "weak");
+        assert!(weak.weak && weak.score <= WEAK_SCORE);
+        assert_eq!(weak.strength, "weak");
+        assert!(weak.warning.is_some());
+        let strong = item(&report, "strong");
+        assert!(!strong.weak);
+        assert_eq!(strong.warning, None);
+    }
+
+    #[test]
+    fn test_personal_inputs() {
+        let report = run(&[
+            ("Zanzibar", "login", r#"{"password":"zanzibar"}"#),
+            (
+                "user",
+                "login",
+                r#"{"username":"quixotic","password":"Quixotic"}"#,
+            ),
+        ]);
+        for entry in &report.items {
+            assert_eq!(entry.score, 0, "{}", entry.id);
+            assert_eq!(
+                entry.warning.as_deref(),
+                Some("Avoid words related to you or this site"),
+                "{}",
+                entry.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_password_age() {
+        let report = aged(&[
+            ("fresh", "login", STRONG, NOW - 364 * DAY),
+            ("old", "login", STRONG, NOW - OLD_DAYS * DAY),
+            ("ancient", "login", STRONG, 0),
+            ("future", "login", STRONG, NOW + DAY),
+        ]);
+        assert_eq!(report.old, 2);
+        assert_eq!(item(&report, "fresh").age_days, 364);
+        assert!(!item(&report, "fresh").old);
+        assert!(item(&report, "old").old);
+        assert_eq!(item(&report, "ancient").age_days, NOW / DAY);
+        assert_eq!(item(&report, "future").age_days, 0);
+        assert!(!item(&report, "future").old);
+    }
+
+    #[test]
+    fn test_missing_totp() {
+        let report = run(&[
+            (
+                "secret",
+                "login",
+                r#"{"password":"xK9#mQ2$vL7@pR4!wZ","totp":"JBSWY3DPEHPK3PXP"}"#,
+            ),
+            (
+                "uri",
+                "login",
+                r#"{"password":"xK9#mQ2$vL7@pR4!wZ","totp":"otpauth://totp/x?secret=JBSWY3DP"}"#,
+            ),
+            ("none", "login", STRONG),
+            (
+                "blank",
+                "login",
+                r#"{"password":"xK9#mQ2$vL7@pR4!wZ","totp":""}"#,
+            ),
+        ]);
+        assert!(item(&report, "secret").has_totp);
+        assert!(item(&report, "uri").has_totp);
+        assert!(!item(&report, "none").has_totp);
+        assert!(!item(&report, "blank").has_totp);
+        assert_eq!(report.missing_totp, 2);
+    }
+}
@@ -0,0 +1,763 @@
+use noro_common::time::{formattimestamp, now};
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::events::{ChangeEvent, VaultEvent};
+use crate::sync::SyncClient;
+use crate::vault::VaultItem;
+use crate::{PendingChange, SyncListener};
+
+const MIN_INTERVAL: u64 = 15;
+const MAX_BACKOFF: u64 = 60;
+
+struct State {
+    running: bool,
+    locked: bool,
+    woken: bool,
+    generation: u64,
+    interval: Duration,
+    pending: Vec<PendingChange>,
+    revisions: HashMap<String, i32>,
+    conflicts: HashMap<String, i32>,
+    revision: Option<i64>,
+    cursor: Option<u64>,
+}
+
+impl State {
+    fn advance<'a>(&mut self, items: impl IntoIterator<Item = &'a VaultItem>) {
+        let latest = items.into_iter().map(|i| i.updated).max();
+        self.cursor = self.cursor.max(latest);
+    }
+
+    fn behind(&mut self, revision: i64) -> bool {
+        let known = self.revision.replace(revision);
+        known != Some(revision) && self.cursor.is_some()
+    }
+}
+
+struct Shared {
+    client: Arc<SyncClient>,
+    state: Mutex<State>,
+    wake: Condvar,
+}
+
+pub struct AutoSync {
+    shared: Arc<Shared>,
+}
+
+fn changeid(change: &PendingChange) -> &str {
+    match change {
+        PendingChange::Create { id, .. } => id,
+        PendingChange::Update { id, .. } => id,
+        PendingChange::Delete { id } => id,
+    }
+}
+
+impl AutoSync {
+    pub fn new(client: Arc<SyncClient>) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    running: false,
+                    locked: client.is_locked(),
+                    woken: false,
+                    generation: 0,
+                    interval: Duration::from_secs(MIN_INTERVAL),
+                    pending: Vec::new(),
+                    revisions: HashMap::new(),
+                    conflicts: HashMap::new(),
+                    revision: None,
+                    cursor: None,
+                }),
+                client,
+                wake: Condvar::new(),
+            }),
+        }
+    }
+
+    pub fn start(&self, listener: Arc<dyn SyncListener>, interval: u64) {
+        let generation = {
+            let mut state = self.shared.state.lock().unwrap();
+            state.running = true;
+            state.generation += 1;
+            state.interval = Duration::from_secs(interval.max(MIN_INTERVAL));
+            state.generation
+        };
+        self.shared.wake.notify_all();
+        let shared = self.shared.clone();
+        let livelistener = listener.clone();
+        thread::spawn(move || run(shared, listener, generation));
+        let shared = self.shared.clone();
+        thread::spawn(move || live(shared, livelistener, generation));
+    }
+
+    pub fn stop(&self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.running = false;
+        state.generation += 1;
+        state.pending.clear();
+        state.revisions.clear();
+        state.conflicts.clear();
+        state.revision = None;
+        state.cursor = None;
+        self.shared.wake.notify_all();
+    }
+
+    pub fn set_locked(&self, locked: bool) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.locked = locked;
+        if !locked {
+            state.woken = true;
+            self.shared.wake.notify_all();
+        }
+    }
+
+    pub fn request(&self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.woken = true;
+        self.shared.wake.notify_all();
+    }
+
+    pub fn enqueue(&self, change: PendingChange) {
+        let mut state = self.shared.state.lock().unwrap();
+        let pending = &mut state.pending;
+        match change {
+            PendingChange::Update {
+                ref id,
+                ref title,
+                ref data,
+                ref tags,
+                favorite,
+                ..
+            } => {
+                let created = pending
+                    .iter_mut()
+                    .find(|c| matches!(c, PendingChange::Create { id: cid, .. } if cid == id));
+                if let Some(PendingChange::Create {
+                    title: ctitle,
+                    data: cdata,
+                    tags: ctags,
+                    favorite: cfavorite,
+                    ..
+                }) = created
+                {
+                    if let Some(t) = title {
+                        *ctitle = t.clone();
+                    }
+                    if let Some(d) = data {
+                        *cdata = d.clone();
+                    }
+                    if let Some(t) = tags {
+                        *ctags = t.clone();
+                    }
+                    if let Some(f) = favorite {
+                        *cfavorite = f;
+                    }
+                } else {
+                    pending.push(change);
+                }
+            }
+            PendingChange::Delete { ref id } => {
+                let created = pending
+                    .iter()
+                    .any(|c| matches!(c, PendingChange::Create { id: cid, .. } if cid == id));
+                pending.retain(|c| changeid(c) != id);
+                if !created {
+                    pending.push(change);
+                }
+            }
+            PendingChange::Create { .. } => pending.push(change),
+        }
+        state.woken = true;
+        self.shared.wake.notify_all();
+    }
+
+    pub fn pending(&self) -> Vec<PendingChange> {
+        self.shared.state.lock().unwrap().pending.clone()
+    }
+
+    pub fn resolve(&self, id: &str, keep_local: bool) {
+        let mut state = self.shared.state.lock().unwrap();
+        let Some(server_revision) = state.conflicts.remove(id) else {
+            return;
+        };
+        if keep_local {
+            for change in state.pending.iter_mut() {
+                if let PendingChange::Update {
+                    id: uid, revision, ..
+                } = change
+                {
+                    if uid == id {
+                        *revision = server_revision;
+                    }
+                }
+            }
+        } else {
+            state
+                .pending
+                .retain(|c| changeid(c) != id || !matches!(c, PendingChange::Update { .. }));
+        }
+        state.woken = true;
+        self.shared.wake.notify_all();
+    }
+}
+
+impl Drop for AutoSync {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn conflict(
+    change: &PendingChange,
+    remote: &HashMap<String, VaultItem>,
+) -> Option<super::SyncConflict> {
+    let PendingChange::Update { id, revision, .. } = change else {
+        return None;
+    };
+    let server = remote.get(id).filter(|s| s.revision != *revision)?;
+    Some(super::SyncConflict {
+        id: id.clone(),
+        local_revision: *revision,
+        server_revision: server.revision,
+        server: server.clone().into(),
+    })
+}
+
+fn hold(shared: &Shared, conflict: &super::SyncConflict) -> bool {
+    let mut state = shared.state.lock().unwrap();
+    state
+        .conflicts
+        .insert(conflict.id.clone(), conflict.server_revision)
+        != Some(conflict.server_revision)
+}
+
+fn push(
+    shared: &Shared,
+    listener: &dyn SyncListener,
+    remote: &HashMap<String, VaultItem>,
+) -> Result<u32, super::SyncError> {
+    let client = &shared.client;
+    let changes: Vec<PendingChange> = shared.state.lock().unwrap().pending.drain(..).collect();
+    let total = changes.len() as u32;
+
+    let mut pushed = 0;
+    let mut kept = Vec::new();
+    for (i, change) in changes.iter().enumerate() {
+        if let Some(conflict) = conflict(change, remote) {
+            if hold(shared, &conflict) {
+                listener.on_sync_conflict(conflict);
+            }
+            kept.push(change.clone());
+            listener.on_sync_progress("push".into(), i as u32 + 1, total);
+            continue;
+        }
+
+        let result = match change.clone() {
+            PendingChange::Create {
+                id,
+                item_type,
+                title,
+                data,
+                tags,
+                favorite,
+            } => client
+                .create_item(id, item_type, title, data, tags, favorite)
+                .map(|_| ()),
+            PendingChange::Update {
+                id,
+                title,
+                data,
+                tags,
+                favorite,
+                ..
+            } => client
+                .update_item(id, title, data, tags, favorite)
+                .map(|_| ()),
+            PendingChange::Delete { id } => client.delete_item(id),
+        };
+
+        match result {
+            Ok(()) => pushed += 1,
+            Err(super::SyncError::Rejected) => listener.on_sync_rejected(change.clone()),
+            Err(e) => {
+                let mut state = shared.state.lock().unwrap();
+                kept.extend_from_slice(&changes[i..]);
+                state.pending.splice(0..0, kept);
+                return Err(e);
+            }
+        }
+        listener.on_sync_progress("push".into(), i as u32 + 1, total);
+    }
+    shared.state.lock().unwrap().pending.splice(0..0, kept);
+    Ok(pushed)
+}
+
+fn cycle(
+    shared: &Shared,
+    listener: &dyn SyncListener,
+) -> Result<super::SyncResult, super::SyncError> {
+    let hasupdates = shared
+        .state
+        .lock()
+        .unwrap()
+        .pending
+        .iter()
+        .any(|c| matches!(c, PendingChange::Update { .. }));
+    let remote: HashMap<String, VaultItem> = if hasupdates {
+        shared
+            .client
+            .fetch_items()?
+            .into_iter()
+            .map(|i| (i.id.clone(), i))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+    let pushed = push(shared, listener, &remote)?;
+
+    let items = shared.client.fetch_all()?;
+    let total = items.len() as u32;
+    listener.on_sync_progress("pull".into(), total, total);
+
+    let mut state = shared.state.lock().unwrap();
+    let changed: Vec<super::VaultItem> = items
+        .iter()
+        .filter(|i| state.revisions.get(&i.id) != Some(&i.revision))
+        .cloned()
+        .map(super::VaultItem::from)
+        .collect();
+    let removed: Vec<String> = state
+        .revisions
+        .keys()
+        .filter(|id| !items.iter().any(|i| &i.id == *id))
+        .cloned()
+        .collect();
+    state.revisions = items.iter().map(|i| (i.id.clone(), i.revision)).collect();
+    state.advance(&items);
+
+    Ok(super::SyncResult {
+        changed,
+        removed,
+        pushed,
+        error: None,
+        timestamp: now(),
+    })
+}
+
+fn run(shared: Arc<Shared>, listener: Arc<dyn SyncListener>, generation: u64) {
+    let mut reason = "start";
+    let mut retry = None;
+    loop {
+        let locked = {
+            let state = shared.state.lock().unwrap();
+            if !state.running || state.generation != generation {
+                return;
+            }
+            state.locked
+        };
+
+        if !locked {
+            listener.on_sync_started(reason.into());
+            let result = match cycle(&shared, listener.as_ref()) {
+                Ok(result) => {
+                    retry = None;
+                    result
+                }
+                Err(e) => {
+                    retry = Some(retry.map_or(1, |r: u64| (r * 2).min(MAX_BACKOFF)));
+                    super::SyncResult {
+                        changed: vec![],
+                        removed: vec![],
+                        pushed: 0,
+                        error: Some(e.to_string()),
+                        timestamp: now(),
+                    }
+                }
+            };
+            listener.on_sync_completed(result);
+        }
+
+        let state = shared.state.lock().unwrap();
+        let interval = retry.map_or(state.interval, |r| {
+            Duration::from_secs(r).min(state.interval)
+        });
+        let (mut state, timeout) = shared
+            .wake
+            .wait_timeout_while(state, interval, |s| {
+                !s.woken && s.running && s.generation == generation
+            })
+            .unwrap();
+        state.woken = false;
+        reason = if timeout.timed_out() {
+            "interval"
+        } else {
+            "change"
+        };
+    }
+}
+
+fn current(shared: &Shared, generation: u64) -> bool {
+    let state = shared.state.lock().unwrap();
+    state.running && state.generation == generation
+}
+
+fn delta(
+    shared: &Shared,
+    listener: &dyn SyncListener,
+    change: ChangeEvent,
+) -> Result<super::SyncResult, super::SyncError> {
+    let stale: Vec<_> = {
+        let state = shared.state.lock().unwrap();
+        change
+            .items
+            .into_iter()
+            .filter(|i| state.revisions.get(&i.id) != Some(&i.revision))
+            .collect()
+    };
+
+    let total = stale.len() as u32;
+    let mut changed = Vec::new();
+    for (i, item) in stale.into_iter().enumerate() {
+        let remote = match shared.client.fetch_item(&item.id) {
+            Ok(remote) => remote,
+            Err(super::SyncError::Http) if change.purged.contains(&item.id) => continue,
+            Err(e) => return Err(e),
+        };
+        {
+            let mut state = shared.state.lock().unwrap();
+            state.revisions.insert(remote.id.clone(), remote.revision);
+            state.advance([&remote]);
+        }
+        changed.push(remote.into());
+        listener.on_sync_progress("pull".into(), i as u32 + 1, total);
+    }
+
+    let mut removed = Vec::new();
+    for id in change.purged {
+        shared.state.lock().unwrap().revisions.remove(&id);
+        removed.push(id);
+    }
+
+    Ok(super::SyncResult {
+        changed,
+        removed,
+        pushed: 0,
+        error: None,
+        timestamp: now(),
+    })
+}
+
+fn catchup(shared: &Shared) -> Result<super::SyncResult, super::SyncError> {
+    let since = formattimestamp(shared.state.lock().unwrap().cursor.unwrap_or(0));
+    let (items, purged) = shared.client.fetch_since(&since)?;
+
+    let mut state = shared.state.lock().unwrap();
+    state.advance(&items);
+    let changed: Vec<super::VaultItem> = items
+        .into_iter()
+        .filter(|i| state.revisions.insert(i.id.clone(), i.revision) != Some(i.revision))
+        .map(super::VaultItem::from)
+        .collect();
+    let removed: Vec<String> = purged
+        .into_iter()
+        .filter(|id| state.revisions.remove(id).is_some())
+        .collect();
+
+    Ok(super::SyncResult {
+        changed,
+        removed,
+        pushed: 0,
+        error: None,
+        timestamp: now(),
+    })
+}
+
+fn live(shared: Arc<Shared>, listener: Arc<dyn SyncListener>, generation: u64) {
+    let mut backoff = 1;
+    while current(&shared, generation) {
+        match shared.client.events() {
+            Ok(mut stream) => {
+                backoff = 1;
+                listener.on_sync_connection(true);
+                while let Ok(Some(event)) = stream.next() {
+                    if !current(&shared, generation) {
+                        return;
+                    }
+                    let reason = {
+                        let mut state = shared.state.lock().unwrap();
+                        match &event {
+                            _ if state.locked => continue,
+                            VaultEvent::Ready { revision } if state.behind(*revision) => {
+                                "reconnect"
+                            }
+                            VaultEvent::Ready { .. } => continue,
+                            VaultEvent::Change(change) => {
+                                state.revision = Some(change.revision);
+                                "remote"
+                            }
+                        }
+                    };
+                    listener.on_sync_started(reason.into());
+                    let result = match event {
+                        VaultEvent::Ready { .. } => catchup(&shared),
+                        VaultEvent::Change(change) => delta(&shared, listener.as_ref(), change),
+                    };
+                    let result = result.unwrap_or_else(|e| super::SyncResult {
+                        changed: vec![],
+                        removed: vec![],
+                        pushed: 0,
+                        error: Some(e.to_string()),
+                        timestamp: now(),
+                    });
+                    listener.on_sync_completed(result);
+                }
+                listener.on_sync_connection(false);
+            }
+            Err(super::SyncError::Auth) => return,
+            Err(_) => {}
+        }
+
+        let state = shared.state.lock().unwrap();
+        let _ = shared
+            .wake
+            .wait_timeout_while(state, Duration::from_secs(backoff), |s| {
+                s.running && s.generation == generation
+            })
+            .unwrap();
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SyncConflict, SyncResult};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    #[derive(Default)]
+    struct Recorder {
+        rejected: Mutex<Vec<String>>,
+    }
+
+    impl SyncListener for Recorder {
+        fn on_sync_started(&self, _reason: String) {}
+        fn on_sync_progress(&self, _phase: String, _done: u32, _total: u32) {}
+        fn on_sync_completed(&self, _result: SyncResult) {}
+        fn on_sync_conflict(&self, _conflict: SyncConflict) {}
+        fn on_sync_rejected(&self, change: PendingChange) {
+            self.rejected.lock().unwrap().push(changeid(&change).into());
+        }
+        fn on_sync_connection(&self, _connected: bool) {}
+    }
+
+    fn autosync() -> AutoSync {
+        AutoSync::new(Arc::new(SyncClient::new("http://localhost".into())))
+    }
+
+    fn create(id: &str) -> PendingChange {
+        PendingChange::Create {
+            id: id.into(),
+            item_type: "login".into(),
+            title: "old".into(),
+            data: b"a".to_vec(),
+            tags: vec![],
+            favorite: false,
+        }
+    }
+
+    fn update(id: &str, revision: i32, title: &str) -> PendingChange {
+        PendingChange::Update {
+            id: id.into(),
+            revision,
+            title: Some(title.into()),
+            data: None,
+            tags: None,
+            favorite: Some(true),
+        }
+    }
+
+    fn remote(id: &str, revision: i32) -> HashMap<String, VaultItem> {
+        let mut item = VaultItem::new(
+            "login".into(),
+            "server".into(),
+            b"b".to_vec(),
+            vec![],
+            false,
+        );
+        item.id = id.into();
+        item.revision = revision;
+        HashMap::from([(id.to_string(), item)])
+    }
+
+    fn held(sync: &AutoSync, change: &PendingChange, remote: &HashMap<String, VaultItem>) -> bool {
+        hold(&sync.shared, &conflict(change, remote).unwrap())
+    }
+
+    #[test]
+    fn test_enqueue_merges_update_into_create() {
+        let sync = autosync();
+        sync.enqueue(create("a"));
+        sync.enqueue(update("a", 0, "new"));
+
+        let pending = sync.pending();
+        assert_eq!(pending.len(), 1);
+        let PendingChange::Create {
+            title,
+            data,
+            favorite,
+            ..
+        } = &pending[0]
+        else {
+            panic!("expected create");
+        };
+        assert_eq!(title, "new");
+        assert_eq!(data, b"a");
+        assert!(favorite);
+    }
+
+    #[test]
+    fn test_enqueue_delete_drops_unsent_create() {
+        let sync = autosync();
+        sync.enqueue(create("a"));
+        sync.enqueue(update("a", 0, "new"));
+        sync.enqueue(PendingChange::Delete { id: "a".into() });
+        assert!(sync.pending().is_empty());
+    }
+
+    #[test]
+    fn test_enqueue_delete_replaces_updates() {
+        let sync = autosync();
+        sync.enqueue(update("a", 1, "one"));
+        sync.enqueue(update("b", 1, "two"));
+        sync.enqueue(update("a", 1, "three"));
+        sync.enqueue(PendingChange::Delete { id: "a".into() });
+
+        let pending = sync.pending();
+        let ids: Vec<&str> = pending.iter().map(changeid).collect();
+        assert_eq!(ids, ["b", "a"]);
+        assert!(matches!(pending[1], PendingChange::Delete { .. }));
+    }
+
+    #[test]
+    fn test_stop_clears_pending() {
+        let sync = autosync();
+        sync.enqueue(create("a"));
+        sync.stop();
+        assert!(sync.pending().is_empty());
+    }
+
+    #[test]
+    fn test_push_drops_rejected_and_requeues_transient() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for status in ["404 Not Found", "503 Service Unavailable"] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0u8; 1024];
+                let n = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..n]).to_string();
+                requests.push(request.lines().next().unwrap().to_string());
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        let client = Arc::new(SyncClient::new(format!("http://{}", address)));
+        client.set_token("token".into());
+        let sync = AutoSync::new(client);
+        for id in ["a", "b", "c"] {
+            sync.enqueue(PendingChange::Delete { id: id.into() });
+        }
+        let recorder = Recorder::default();
+        let result = push(&sync.shared, &recorder, &HashMap::new());
+
+        assert!(matches!(result, Err(crate::SyncError::Http)));
+        assert_eq!(*recorder.rejected.lock().unwrap(), ["a"]);
+        let pending = sync.pending();
+        let ids: Vec<&str> = pending.iter().map(changeid).collect();
+        assert_eq!(ids, ["b", "c"]);
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "DELETE /api/v1/vault/items/a HTTP/1.1",
+                "DELETE /api/v1/vault/items/b HTTP/1.1"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_catchup_only_when_revision_moves() {
+        let sync = autosync();
+        let mut state = sync.shared.state.lock().unwrap();
+        assert!(!state.behind(3));
+
+        let mut item = remote("a", 1).remove("a").unwrap();
+        item.updated = 1741944413;
+        state.advance([&item]);
+        item.updated = 1741940000;
+        state.advance([&item]);
+        assert_eq!(state.cursor, Some(1741944413));
+
+        assert!(!state.behind(3));
+        assert!(state.behind(5));
+        assert!(!state.behind(5));
+    }
+
+    #[test]
+    fn test_conflict_on_revision_mismatch() {
+        let server = remote("a", 2);
+        assert!(conflict(&update("a", 2, "x"), &server).is_none());
+        assert!(conflict(&update("b", 1, "x"), &server).is_none());
+        assert!(conflict(&create("a"), &server).is_none());
+
+        let found = conflict(&update("a", 1, "x"), &server).unwrap();
+        assert_eq!(found.local_revision, 1);
+        assert_eq!(found.server_revision, 2);
+        assert_eq!(found.server.title, "server");
+    }
+
+    #[test]
+    fn test_conflict_held_until_resolved() {
+        let sync = autosync();
+        let server = remote("a", 2);
+        assert!(held(&sync, &update("a", 1, "x"), &server));
+        assert!(!held(&sync, &update("a", 1, "x"), &server));
+
+        sync.enqueue(update("a", 1, "x"));
+        sync.resolve("b", true);
+        assert!(matches!(
+            sync.pending()[0],
+            PendingChange::Update { revision: 1, .. }
+        ));
+
+        sync.resolve("a", true);
+        assert!(matches!(
+            sync.pending()[0],
+            PendingChange::Update { revision: 2, .. }
+        ));
+        assert!(sync.shared.state.lock().unwrap().conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_conflict_resolved_with_server() {
+        let sync = autosync();
+        sync.enqueue(update("a", 1, "x"));
+        sync.enqueue(update("b", 1, "y"));
+        held(&sync, &update("a", 1, "x"), &remote("a", 2));
+
+        sync.resolve("a", false);
+        let pending = sync.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(changeid(&pending[0]), "b");
+    }
+}
@@ -0,0 +1,608 @@
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::folders::Folder;
+use crate::BackupError;
+
+pub const FORMAT: &str = "noro-backup";
+pub const PLAIN_FORMAT: &str = "noro-export";
+pub const VERSION: u32 = 1;
+const CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "argon2id";
+const KDF_MEMORY: u32 = 65536;
+const KDF_ITERATIONS: u32 = 3;
+const KDF_PARALLELISM: u32 = 4;
+const MAX_MEMORY: u32 = 1 << 20;
+const MAX_ITERATIONS: u32 = 64;
+const MAX_PARALLELISM: u32 = 16;
+const NONCE_LEN: usize = 12;
+const CSV_COLUMNS: &[&str] = &[
+    "title", "type", "username", "password", "url", "totp", "notes", "folder", "tags", "favorite",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub algorithm: String,
+    pub memory: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    pub salt: String,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        let salt: [u8; 16] = rand::thread_rng().gen();
+        Self {
+            algorithm: KDF.into(),
+            memory: KDF_MEMORY,
+            iterations: KDF_ITERATIONS,
+            parallelism: KDF_PARALLELISM,
+            salt: STANDARD.encode(salt),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub format: String,
+    pub version: u32,
+    pub created: u64,
+    pub source: String,
+    pub kdf: KdfParams,
+    pub cipher: String,
+    pub nonce: String,
+    pub items: u32,
+    pub folders: u32,
+    pub attachments: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Archive {
+    manifest: Manifest,
+    payload: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupItem {
+    pub id: String,
+    pub item_type: String,
+    pub title: String,
+    pub data: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub favorite: bool,
+    #[serde(default)]
+    pub folder_id: Option<String>,
+    pub created: u64,
+    pub updated: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupFolder {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub color: String,
+    #[serde(default)]
+    pub icon: String,
+    #[serde(default)]
+    pub order: i32,
+    pub created: u64,
+    pub updated: u64,
+}
+
+impl From<&Folder> for BackupFolder {
+    fn from(folder: &Folder) -> Self {
+        Self {
+            id: folder.id.clone(),
+            name: folder.name.clone(),
+            parent_id: folder.parent_id.clone(),
+            color: folder.color.clone(),
+            icon: folder.icon.clone(),
+            order: folder.order,
+            created: folder.created,
+            updated: folder.updated,
+        }
+    }
+}
+
+impl From<BackupFolder> for Folder {
+    fn from(folder: BackupFolder) -> Self {
+        Self {
+            id: folder.id,
+            name: folder.name,
+            parent_id: folder.parent_id,
+            color: folder.color,
+            icon: folder.icon,
+            order: folder.order,
+            created: folder.created,
+            updated: folder.updated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupAttachment {
+    pub id: String,
+    pub item_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub mime: Option<String>,
+    pub data: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Backup {
+    pub items: Vec<BackupItem>,
+    pub folders: Vec<BackupFolder>,
+    #[serde(default)]
+    pub attachments: Vec<BackupAttachment>,
+}
+
+fn derivekey(password: &str, kdf: &KdfParams) -> Result<[u8; 32], BackupError> {
+    if kdf.algorithm != KDF {
+        return Err(BackupError::Unsupported);
+    }
+    if kdf.memory > MAX_MEMORY
+        || kdf.iterations > MAX_ITERATIONS
+        || kdf.parallelism > MAX_PARALLELISM
+    {
+        return Err(BackupError::Unsupported);
+    }
+    let salt = STANDARD
+        .decode(&kdf.salt)
+        .map_err(|_| BackupError::Format)?;
+    let params = Params::new(kdf.memory, kdf.iterations, kdf.parallelism, Some(32))
+        .map_err(|_| BackupError::Format)?;
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), &salt, &mut key)
+        .map_err(|_| BackupError::Crypto)?;
+    Ok(key)
+}
+
+fn aad(manifest: &Manifest) -> Result<Vec<u8>, BackupError> {
+    serde_json::to_vec(manifest).map_err(|_| BackupError::Format)
+}
+
+pub fn seal(
+    backup: &Backup,
+    password: &str,
+    kdf: KdfParams,
+    source: &str,
+    created: u64,
+) -> Result<Vec<u8>, BackupError> {
+    let noncebytes: [u8; NONCE_LEN] = rand::thread_rng().gen();
+    let manifest = Manifest {
+        format: FORMAT.into(),
+        version: VERSION,
+        created,
+        source: source.into(),
+        kdf,
+        cipher: CIPHER.into(),
+        nonce: STANDARD.encode(noncebytes),
+        items: backup.items.len() as u32,
+        folders: backup.folders.len() as u32,
+        attachments: backup.attachments.len() as u32,
+    };
+    let key = derivekey(password, &manifest.kdf)?;
+    let plaintext = serde_json::to_vec(backup).map_err(|_| BackupError::Format)?;
+    let ciphertext = Aes256Gcm::new_from_slice(&key)
+        .map_err(|_| BackupError::Crypto)?
+        .encrypt(
+            Nonce::from_slice(&noncebytes),
+            Payload {
+                msg: &plaintext,
+                aad: &aad(&manifest)?,
+            },
+        )
+        .map_err(|_| BackupError::Crypto)?;
+    let archive = Archive {
+        manifest,
+        payload: STANDARD.encode(ciphertext),
+    };
+    serde_json::to_vec_pretty(&archive).map_err(|_| BackupError::Format)
+}
+
+fn archive(bytes: &[u8]) -> Result<Archive, BackupError> {
+    let archive: Archive = serde_json::from_slice(bytes).map_err(|_| BackupError::Format)?;
+    if archive.manifest.format != FORMAT {
+        return Err(BackupError::Format);
+    }
+    if archive.manifest.version > VERSION || archive.manifest.cipher != CIPHER {
+        return Err(BackupError::Unsupported);
+    }
+    Ok(archive)
+}
+
+pub fn manifest(bytes: &[u8]) -> Result<Manifest, BackupError> {
+    Ok(archive(bytes)?.manifest)
+}
+
+pub fn open(bytes: &[u8], password: &str) -> Result<(Manifest, Backup), BackupError> {
+    let Archive { manifest, payload } = archive(bytes)?;
+    let key = derivekey(password, &manifest.kdf)?;
+    let noncebytes = STANDARD
+        .decode(&manifest.nonce)
+        .map_err(|_| BackupError::Format)?;
+    if noncebytes.len() != NONCE_LEN {
+        return Err(BackupError::Format);
+    }
+    let ciphertext = STANDARD.decode(payload).map_err(|_| BackupError::Format)?;
+    let plaintext = Aes256Gcm::new_from_slice(&key)
+        .map_err(|_| BackupError::Crypto)?
+        .decrypt(
+            Nonce::from_slice(&noncebytes),
+            Payload {
+                msg: &ciphertext,
+                aad: &aad(&manifest)?,
+            },
+        )
+        .map_err(|_| BackupError::InvalidPassword)?;
+    let backup: Backup = serde_json::from_slice(&plaintext).map_err(|_| BackupError::Format)?;
+    if backup.items.len() as u32 != manifest.items
+        || backup.folders.len() as u32 != manifest.folders
+        || backup.attachments.len() as u32 != manifest.attachments
+    {
+        return Err(BackupError::Format);
+    }
+    Ok((manifest, backup))
+}
+
+fn folderpath(folders: &[BackupFolder], id: Option<&str>) -> Option<String> {
+    let mut names = Vec::new();
+    let mut current = id;
+    while let Some(folder) = current.and_then(|id| folders.iter().find(|f| f.id == id)) {
+        if names.len() > folders.len() {
+            break;
+        }
+        names.push(folder.name.as_str());
+        current = folder.parent_id.as_deref();
+    }
+    if names.is_empty() {
+        return None;
+    }
+    names.reverse();
+    Some(names.join("/"))
+}
+
+#[derive(Serialize)]
+struct PlainItem<'a> {
+    id: &'a str,
+    item_type: &'a str,
+    title: &'a str,
+    data: Value,
+    tags: &'a [String],
+    favorite: bool,
+    folder: Option<String>,
+    created: u64,
+    updated: u64,
+}
+
+#[derive(Serialize)]
+struct PlainExport<'a> {
+    format: &'static str,
+    version: u32,
+    created: u64,
+    items: Vec<PlainItem<'a>>,
+    folders: &'a [BackupFolder],
+}
+
+pub fn tojson(backup: &Backup, created: u64) -> Result<String, BackupError> {
+    let items = backup
+        .items
+        .iter()
+        .map(|item| PlainItem {
+            id: &item.id,
+            item_type: &item.item_type,
+            title: &item.title,
+            data: serde_json::from_str(&item.data)
+                .unwrap_or_else(|_| Value::String(item.data.clone())),
+            tags: &item.tags,
+            favorite: item.favorite,
+            folder: folderpath(&backup.folders, item.folder_id.as_deref()),
+            created: item.created,
+            updated: item.updated,
+        })
+        .collect();
+    let export = PlainExport {
+        format: PLAIN_FORMAT,
+        version: VERSION,
+        created,
+        items,
+        folders: &backup.folders,
+    };
+    serde_json::to_string_pretty(&export).map_err(|_| BackupError::Format)
+}
+
+fn csvfield(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn tocsv(backup: &Backup) -> String {
+    let mut out = CSV_COLUMNS.join(",");
+    out.push_str("\r\n");
+    for item in &backup.items {
+        let data: Value = serde_json::from_str(&item.data).unwrap_or_default();
+        let text = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|k| data.get(*k).and_then(Value::as_str))
+                .unwrap_or_default()
+                .to_string()
+        };
+        let row = [
+            item.title.clone(),
+            item.item_type.clone(),
+            text(&["username"]),
+            text(&["password"]),
+            text(&["url"]),
+            text(&["totp"]),
+            text(&["notes", "content"]),
+            folderpath(&backup.folders, item.folder_id.as_deref()).unwrap_or_default(),
+            item.tags.join(";"),
+            if item.favorite { "1" } else { "" }.to_string(),
+        ];
+        out.push_str(
+            &row.iter()
+                .map(|v| csvfield(v))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        out.push_str("\r\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fastkdf() -> KdfParams {
+        KdfParams {
+            memory: 256,
+            iterations: 1,
+            parallelism: 1,
+            ..KdfParams::default()
+        }
+    }
+
+    fn folder(id: &str, name: &str, parent_id: Option<&str>) -> BackupFolder {
+        BackupFolder {
+            id: id.into(),
+            name: name.into(),
+            parent_id: parent_id.map(Into::into),
+            color: String::new(),
+            icon: String::new(),
+            order: 0,
+            created: 1,
+            updated: 1,
+        }
+    }
+
+    fn sample() -> Backup {
+        Backup {
+            items: vec![BackupItem {
+                id: "a".into(),
+                item_type: "login".into(),
+                title: "Mail, personal".into(),
+                data: r#"{"username":"alice","password":"p\"w","notes":"two\nlines"}"#.into(),
+                tags: vec!["x".into(), "y".into()],
+                favorite: true,
+                folder_id: Some("f2".into()),
+                created: 1,
+                updated: 2,
+            }],
+            folders: vec![
+                folder("f1", "Work", None),
+                folder("f2", "Email", Some("f1")),
+            ],
+            attachments: vec![BackupAttachment {
+                id: "att".into(),
+                item_id: "a".into(),
+                name: "key.txt".into(),
+                mime: None,
+                data: STANDARD.encode(b"hello"),
+            }],
+        }
+    }
+
+    fn sealed() -> Vec<u8> {
+        seal(&sample(), "hunter2", fastkdf(), "test", 10).unwrap()
+    }
+
+    fn edit(sealed: &[u8], change: impl FnOnce(&mut Value)) -> Vec<u8> {
+        let mut archive: Value = serde_json::from_slice(sealed).unwrap();
+        change(&mut archive);
+        serde_json::to_vec(&archive).unwrap()
+    }
+
+    #[test]
+    fn test_seal_open() {
+        let sealed = sealed();
+        let (manifest, opened) = open(&sealed, "hunter2").unwrap();
+        assert_eq!(manifest.format, FORMAT);
+        assert_eq!(manifest.source, "test");
+        assert_eq!(manifest.created, 10);
+        assert_eq!(
+            (manifest.items, manifest.folders, manifest.attachments),
+            (1, 2, 1)
+        );
+        assert_eq!(opened, sample());
+    }
+
+    #[test]
+    fn test_seal_open_empty() {
+        let sealed = seal(&Backup::default(), "", fastkdf(), "test", 10).unwrap();
+        let (manifest, opened) = open(&sealed, "").unwrap();
+        assert_eq!(manifest.items, 0);
+        assert_eq!(opened, Backup::default());
+    }
+
+    #[test]
+    fn test_manifest_without_password() {
+        let manifest = manifest(&sealed()).unwrap();
+        assert_eq!(manifest.items, 1);
+        assert_eq!(manifest.kdf.algorithm, KDF);
+        assert_eq!(manifest.cipher, CIPHER);
+    }
+
+    #[test]
+    fn test_open_wrong_password() {
+        let sealed = sealed();
+        for password in ["wrong", "", "hunter2 ", "Hunter2"] {
+            assert!(matches!(
+                open(&sealed, password),
+                Err(BackupError::InvalidPassword)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_open_tampered_manifest() {
+        let sealed = sealed();
+        for change in [
+            |a: &mut Value| a["manifest"]["items"] = 5.into(),
+            |a: &mut Value| a["manifest"]["source"] = "other".into(),
+            |a: &mut Value| a["manifest"]["created"] = 11.into(),
+        ] {
+            assert!(matches!(
+                open(&edit(&sealed, change), "hunter2"),
+                Err(BackupError::InvalidPassword)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_open_corrupt_archive() {
+        let sealed = sealed();
+        for bytes in [
+            Vec::new(),
+            b"not json".to_vec(),
+            b"{}".to_vec(),
+            sealed[..sealed.len() / 2].to_vec(),
+            edit(&sealed, |a| a["manifest"]["format"] = "other".into()),
+            edit(&sealed, |a| a["manifest"]["nonce"] = "AAAA".into()),
+            edit(&sealed, |a| a["manifest"]["nonce"] = "%%%".into()),
+            edit(&sealed, |a| a["manifest"]["kdf"]["salt"] = "%%%".into()),
+            edit(&sealed, |a| a["payload"] = "%%%".into()),
+            edit(&sealed, |a| {
+                a.as_object_mut().unwrap().remove("payload");
+            }),
+        ] {
+            assert!(matches!(open(&bytes, "hunter2"), Err(BackupError::Format)));
+        }
+    }
+
+    #[test]
+    fn test_open_corrupt_payload() {
+        let sealed = sealed();
+        let flipped = edit(&sealed, |a| {
+            let mut payload = STANDARD.decode(a["payload"].as_str().unwrap()).unwrap();
+            payload[0] ^= 1;
+            a["payload"] = STANDARD.encode(payload).into();
+        });
+        let truncated = edit(&sealed, |a| {
+            let payload = STANDARD.decode(a["payload"].as_str().unwrap()).unwrap();
+            a["payload"] = STANDARD.encode(&payload[..8]).into();
+        });
+        for bytes in [flipped, truncated] {
+            assert!(matches!(
+                open(&bytes, "hunter2"),
+                Err(BackupError::InvalidPassword)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_open_unsupported_archive() {
+        let sealed = sealed();
+        for bytes in [
+            edit(&sealed, |a| a["manifest"]["version"] = (VERSION + 1).into()),
+            edit(&sealed, |a| {
+                a["manifest"]["cipher"] = "chacha20-poly1305".into()
+            }),
+            edit(&sealed, |a| {
+                a["manifest"]["kdf"]["algorithm"] = "scrypt".into()
+            }),
+            edit(&sealed, |a| {
+                a["manifest"]["kdf"]["memory"] = (MAX_MEMORY + 1).into()
+            }),
+            edit(&sealed, |a| {
+                a["manifest"]["kdf"]["iterations"] = (MAX_ITERATIONS + 1).into()
+            }),
+            edit(&sealed, |a| {
+                a["manifest"]["kdf"]["parallelism"] = (MAX_PARALLELISM + 1).into()
+            }),
+        ] {
+            assert!(matches!(
+                open(&bytes, "hunter2"),
+                Err(BackupError::Unsupported)
+            ));
+        }
+        assert!(matches!(
+            manifest(&edit(&sealed, |a| a["manifest"]["version"] = 2.into())),
+            Err(BackupError::Unsupported)
+        ));
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        let csv = tocsv(&sample());
+        let mut lines = csv.split("\r\n");
+        assert_eq!(lines.next(), Some(CSV_COLUMNS.join(",").as_str()));
+        assert_eq!(
+            lines.next(),
+            Some("\"Mail, personal\",login,alice,\"p\"\"w\",,,\"two\nlines\",Work/Email,x;y,1")
+        );
+    }
+
+    #[test]
+    fn test_csv_missing_data() {
+        let mut backup = sample();
+        backup.items[0].data = "not json".into();
+        backup.items[0].folder_id = Some("gone".into());
+        backup.items[0].tags.clear();
+        backup.items[0].favorite = false;
+        let csv = tocsv(&backup);
+        assert_eq!(csv.lines().nth(1), Some("\"Mail, personal\",login,,,,,,,,"));
+        assert_eq!(tocsv(&Backup::default()), CSV_COLUMNS.join(",") + "\r\n");
+    }
+
+    #[test]
+    fn test_json_export() {
+        let mut backup = sample();
+        backup.items.push(BackupItem {
+            data: "plain text".into(),
+            folder_id: None,
+            ..backup.items[0].clone()
+        });
+        let json: Value = serde_json::from_str(&tojson(&backup, 10).unwrap()).unwrap();
+        assert_eq!(json["format"], PLAIN_FORMAT);
+        assert_eq!(json["items"][0]["data"]["username"], "alice");
+        assert_eq!(json["items"][0]["folder"], "Work/Email");
+        assert_eq!(json["items"][1]["data"], "plain text");
+        assert_eq!(json["items"][1]["folder"], Value::Null);
+    }
+
+    #[test]
+    fn test_folderpath_cycle() {
+        let folders = vec![folder("a", "A", Some("b")), folder("b", "B", Some("a"))];
+        assert!(folderpath(&folders, Some("a")).is_some());
+        assert_eq!(folderpath(&folders, Some("missing")), None);
+        assert_eq!(folderpath(&folders, None), None);
+    }
+}
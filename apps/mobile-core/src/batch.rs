@@ -0,0 +1,514 @@
+use noro_common::pool::pool;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::sync::{self, ItemMeta, RemoteItem, SyncClient};
+use crate::{BatchOp, SyncListener};
+
+const CHUNK_SIZE: usize = 100;
+const MAX_WORKERS: usize = 2;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum WireOp {
+    Create {
+        id: String,
+        #[serde(rename = "typeIndex")]
+        type_index: String,
+        title: String,
+        data: String,
+        meta: String,
+        tags: Vec<String>,
+    },
+    Update {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        meta: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tags: Option<Vec<String>>,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+fn opid(op: &BatchOp) -> &str {
+    match op {
+        BatchOp::Create { id, .. } => id,
+        BatchOp::Update { id, .. } => id,
+        BatchOp::Delete { id } => id,
+    }
+}
+
+fn opname(op: &BatchOp) -> &'static str {
+    match op {
+        BatchOp::Create { .. } => "create",
+        BatchOp::Update { .. } => "update",
+        BatchOp::Delete { .. } => "delete",
+    }
+}
+
+fn seal(
+    client: &SyncClient,
+    op: BatchOp,
+    current: &HashMap<String, RemoteItem>,
+) -> Result<(WireOp, Option<Vec<u8>>), super::SyncError> {
+    let sealed = match op {
+        BatchOp::Create {
+            id,
+            item_type,
+            title,
+            data,
+            tags,
+            favorite,
+        } => {
+            let key = client.item_key(&id)?;
+            let op = WireOp::Create {
+                type_index: client.blind_index("type", &item_type)?,
+                title: sync::seal(&key, title.as_bytes())?,
+                data: sync::seal(&key, &data)?,
+                tags: client.tag_indexes(&tags)?,
+                meta: sync::sealmeta(
+                    &key,
+                    &ItemMeta {
+                        item_type,
+                        tags,
+                        favorite,
+                    },
+                )?,
+                id,
+            };
+            (op, Some(key))
+        }
+        BatchOp::Update {
+            id,
+            title,
+            data,
+            tags,
+            favorite,
+        } => {
+            let key = client.item_key(&id)?;
+            let meta = if tags.is_some() || favorite.is_some() {
+                let existing = current.get(&id).ok_or(super::SyncError::Parse)?;
+                let existing = sync::openitem(existing, &key)?;
+                let meta = ItemMeta {
+                    item_type: existing.item_type,
+                    tags: tags.clone().unwrap_or(existing.tags),
+                    favorite: favorite.unwrap_or(existing.favorite),
+                };
+                Some(sync::sealmeta(&key, &meta)?)
+            } else {
+                None
+            };
+            let op = WireOp::Update {
+                title: title.map(|t| sync::seal(&key, t.as_bytes())).transpose()?,
+                data: data.map(|d| sync::seal(&key, &d)).transpose()?,
+                tags: tags.as_deref().map(|t| client.tag_indexes(t)).transpose()?,
+                meta,
+                id,
+            };
+            (op, Some(key))
+        }
+        BatchOp::Delete { id } => (WireOp::Delete { id }, None),
+    };
+    Ok(sealed)
+}
+
+fn currentitems(
+    client: &SyncClient,
+    ops: &[BatchOp],
+) -> Result<HashMap<String, RemoteItem>, super::SyncError> {
+    let mut ids: Vec<String> = ops
+        .iter()
+        .filter_map(|op| match op {
+            BatchOp::Update {
+                id, tags, favorite, ..
+            } if tags.is_some() || favorite.is_some() => Some(id.clone()),
+            _ => None,
+        })
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let fetched = pool(
+        ids,
+        MAX_WORKERS,
+        |id| client.fetch_sealed(&id).map(|item| (id, item)),
+        |_, _| {},
+    );
+    let mut current = HashMap::new();
+    for result in fetched {
+        match result {
+            Ok((id, item)) => {
+                current.insert(id, item);
+            }
+            Err(super::SyncError::Auth) => return Err(super::SyncError::Auth),
+            Err(_) => {}
+        }
+    }
+    Ok(current)
+}
+
+fn failed(id: &str, op: &str, error: String) -> super::BatchItemResult {
+    super::BatchItemResult {
+        id: Some(id.to_string()),
+        op: op.to_string(),
+        ok: false,
+        error: Some(error),
+        item: None,
+    }
+}
+
+pub fn run(
+    client: &SyncClient,
+    ops: Vec<BatchOp>,
+    listener: Option<&dyn SyncListener>,
+) -> Result<super::BatchResult, super::SyncError> {
+    let meta: Vec<(String, &'static str)> = ops
+        .iter()
+        .map(|o| (opid(o).to_string(), opname(o)))
+        .collect();
+
+    let current = currentitems(client, &ops)?;
+
+    let sealed = pool(
+        ops,
+        MAX_WORKERS,
+        |op| seal(client, op, &current),
+        |done, total| {
+            if let Some(l) = listener {
+                l.on_sync_progress("encrypt".into(), done as u32, total as u32);
+            }
+        },
+    );
+
+    let mut results: Vec<Option<super::BatchItemResult>> = (0..meta.len()).map(|_| None).collect();
+    let mut keys: HashMap<String, Vec<u8>> = HashMap::new();
+    let mut outgoing: Vec<(usize, WireOp)> = Vec::new();
+    for (index, sealed) in sealed.into_iter().enumerate() {
+        let (id, op) = &meta[index];
+        match sealed {
+            Ok((wire, key)) => {
+                if let Some(key) = key {
+                    keys.insert(id.clone(), key);
+                }
+                outgoing.push((index, wire));
+            }
+            Err(e) => results[index] = Some(failed(id, op, e.to_string())),
+        }
+    }
+
+    let total = outgoing.len() as u32;
+    let mut sent = 0;
+    for chunk in outgoing.chunks(CHUNK_SIZE) {
+        let wire: Vec<WireOp> = chunk.iter().map(|(_, w)| w.clone()).collect();
+        match client.send_batch(&wire) {
+            Ok(entries) => {
+                for ((index, _), entry) in chunk.iter().zip(entries) {
+                    let (id, _) = &meta[*index];
+                    let mut result = super::BatchItemResult {
+                        id: entry.id,
+                        op: entry.op,
+                        ok: entry.ok,
+                        error: entry.error,
+                        item: None,
+                    };
+                    if let (Some(item), Some(key)) = (entry.item, keys.get(id)) {
+                        match sync::openitem(&item, key) {
+                            Ok(item) => result.item = Some(item.into()),
+                            Err(e) => {
+                                result.ok = false;
+                                result.error = Some(e.to_string());
+                            }
+                        }
+                    }
+                    results[*index] = Some(result);
+                }
+            }
+            Err(super::SyncError::Auth) => return Err(super::SyncError::Auth),
+            Err(e) => {
+                for (index, _) in chunk {
+                    let (id, op) = &meta[*index];
+                    results[*index] = Some(failed(id, op, e.to_string()));
+                }
+            }
+        }
+        sent += chunk.len() as u32;
+        if let Some(l) = listener {
+            l.on_sync_progress("upload".into(), sent, total);
+        }
+    }
+
+    let results: Vec<super::BatchItemResult> = results
+        .into_iter()
+        .zip(meta)
+        .map(|(r, (id, op))| r.unwrap_or_else(|| failed(&id, op, "no result from server".into())))
+        .collect();
+    let succeeded = results.iter().filter(|r| r.ok).count() as u32;
+    let failed = results.len() as u32 - succeeded;
+
+    Ok(super::BatchResult {
+        results,
+        succeeded,
+        failed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::twoskd;
+    use base64::Engine;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    const KEY: [u8; 32] = [9u8; 32];
+
+    fn serve(routes: Vec<(&'static str, u16, String)>) -> (SyncClient, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = SyncClient::new(format!("http://{}", listener.local_addr().unwrap()));
+        client.unlock(KEY.to_vec()).unwrap();
+        client.set_token("token".into());
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for _ in 0..routes.len() {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut content = vec![0u8; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                let (_, status, body) = routes
+                    .iter()
+                    .find(|(route, _, _)| request.starts_with(route))
+                    .unwrap();
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+        (client, server)
+    }
+
+    fn update(id: &str, tags: Option<Vec<String>>) -> BatchOp {
+        BatchOp::Update {
+            id: id.into(),
+            title: Some("renamed".into()),
+            data: None,
+            tags,
+            favorite: None,
+        }
+    }
+
+    fn open(key: &[u8], sealed: &str) -> Vec<u8> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(sealed)
+            .unwrap();
+        twoskd::decryptitem(&bytes, key).unwrap()
+    }
+
+    #[test]
+    fn test_batch_seals_with_item_keys() {
+        let client = SyncClient::new("http://localhost".into());
+        client.unlock(KEY.to_vec()).unwrap();
+        let ops = vec![
+            BatchOp::Create {
+                id: "cm8a1login".into(),
+                item_type: "login".into(),
+                title: "github".into(),
+                data: br#"{"password":"hunter2"}"#.to_vec(),
+                tags: vec!["work".into()],
+                favorite: true,
+            },
+            BatchOp::Update {
+                id: "cm8a2note".into(),
+                title: Some("renamed".into()),
+                data: None,
+                tags: None,
+                favorite: None,
+            },
+            BatchOp::Delete {
+                id: "cm8a3gone".into(),
+            },
+        ];
+
+        let current = HashMap::new();
+        let sealed: Vec<_> = pool(
+            ops,
+            MAX_WORKERS,
+            |op| seal(&client, op, &current),
+            |_, _| {},
+        )
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+
+        let itemkey = twoskd::deriveitemkey(&KEY, "cm8a1login").unwrap();
+        let WireOp::Create { title, data, .. } = &sealed[0].0 else {
+            panic!("expected create");
+        };
+        assert_eq!(sealed[0].1.as_deref(), Some(&itemkey[..]));
+        assert_eq!(open(&itemkey, title), b"github");
+        assert_eq!(open(&itemkey, data), br#"{"password":"hunter2"}"#);
+
+        let itemkey = twoskd::deriveitemkey(&KEY, "cm8a2note").unwrap();
+        let WireOp::Update {
+            title, data, meta, ..
+        } = &sealed[1].0
+        else {
+            panic!("expected update");
+        };
+        assert_eq!(open(&itemkey, title.as_deref().unwrap()), b"renamed");
+        assert!(data.is_none() && meta.is_none());
+
+        assert!(matches!(&sealed[2].0, WireOp::Delete { id } if id == "cm8a3gone"));
+        assert!(sealed[2].1.is_none());
+    }
+
+    #[test]
+    fn test_batch_seal_locked() {
+        let client = SyncClient::new("http://localhost".into());
+        let op = BatchOp::Delete { id: "a".into() };
+        assert!(seal(&client, op, &HashMap::new()).is_ok());
+        let op = BatchOp::Update {
+            id: "a".into(),
+            title: Some("t".into()),
+            data: None,
+            tags: None,
+            favorite: None,
+        };
+        assert!(matches!(
+            seal(&client, op, &HashMap::new()),
+            Err(crate::SyncError::Locked)
+        ));
+    }
+
+    #[test]
+    fn test_batch_partial_failure() {
+        let itemkey = twoskd::deriveitemkey(&KEY, "cm8a7tagged").unwrap();
+        let meta = ItemMeta {
+            item_type: "login".into(),
+            tags: vec!["work".into()],
+            favorite: true,
+        };
+        let tagged = format!(
+            r#"{{"item":{{"id":"cm8a7tagged","type":null,"title":"{}","data":"{}","meta":"{}","revision":1,"favorite":false,"deleted":false,"createdAt":"2025-06-01T18:00:00.000Z","updatedAt":"2025-06-01T18:00:00.000Z","tags":[]}}}}"#,
+            sync::seal(&itemkey, b"github").unwrap(),
+            sync::seal(&itemkey, b"{}").unwrap(),
+            sync::sealmeta(&itemkey, &meta).unwrap(),
+        );
+        let (client, server) = serve(vec![
+            (
+                "GET /api/v1/vault/items/cm8a4missing ",
+                404,
+                r#"{"error":"item not found"}"#.into(),
+            ),
+            ("GET /api/v1/vault/items/cm8a7tagged ", 200, tagged),
+            (
+                "POST /api/v1/vault/items/batch ",
+                200,
+                r#"{"results":[
+                    {"id":"cm8a7tagged","op":"update","ok":true,"error":null,"item":null},
+                    {"id":"cm8a5gone","op":"delete","ok":true,"error":null,"item":null},
+                    {"id":"cm8a6kept","op":"delete","ok":false,"error":"item not found","item":null}
+                ]}"#
+                .into(),
+            ),
+        ]);
+        let ops = vec![
+            update("cm8a4missing", Some(vec!["work".into()])),
+            update("cm8a7tagged", Some(vec!["home".into()])),
+            BatchOp::Delete {
+                id: "cm8a5gone".into(),
+            },
+            BatchOp::Delete {
+                id: "cm8a6kept".into(),
+            },
+            update("cm8a2note", None),
+        ];
+
+        let result = run(&client, ops, None).unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!((result.succeeded, result.failed), (2, 3));
+        let errors: Vec<Option<&str>> = result
+            .results
+            .iter()
+            .map(|r| r.error.as_deref())
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                Some("parse error"),
+                None,
+                None,
+                Some("item not found"),
+                Some("no result from server"),
+            ]
+        );
+        assert!(result.results[1].ok && result.results[2].ok);
+
+        let batch = requests.iter().find(|r| r.starts_with("POST")).unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&batch[batch.find('{').unwrap()..]).unwrap();
+        let sent: Vec<&str> = body["ops"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|op| op["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(sent, ["cm8a7tagged", "cm8a5gone", "cm8a6kept", "cm8a2note"]);
+        let sealed = body["ops"][0]["meta"].as_str().unwrap();
+        let meta: ItemMeta = serde_json::from_slice(&open(&itemkey, sealed)).unwrap();
+        assert_eq!(meta.item_type, "login");
+        assert_eq!(meta.tags, ["home"]);
+        assert!(meta.favorite);
+    }
+
+    #[test]
+    fn test_batch_failed_chunk() {
+        let (client, server) = serve(vec![(
+            "POST /api/v1/vault/items/batch ",
+            500,
+            r#"{"error":"failed to process batch"}"#.into(),
+        )]);
+        let ops = vec![
+            BatchOp::Delete { id: "a".into() },
+            update("cm8a2note", None),
+        ];
+
+        let result = run(&client, ops, None).unwrap();
+        server.join().unwrap();
+
+        assert_eq!((result.succeeded, result.failed), (0, 2));
+        assert!(result
+            .results
+            .iter()
+            .all(|r| r.error.as_deref() == Some("http error")));
+        assert_eq!(result.results[1].id.as_deref(), Some("cm8a2note"));
+    }
+}
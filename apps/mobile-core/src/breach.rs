@@ -0,0 +1,266 @@
+use sha1::{Digest, Sha1};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::Path;
+
+use super::BreachError;
+
+const PREFIX_LENGTH: usize = 5;
+const HASH_LENGTH: usize = 40;
+
+fn ioerr(e: std::io::Error) -> BreachError {
+    BreachError::Io(e.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BreachSource {
+    File { path: String },
+    Directory { path: String },
+    Http { base_url: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreachResult {
+    pub breached: bool,
+    pub count: u64,
+}
+
+pub fn sha1hex(password: &str) -> String {
+    Sha1::digest(password.as_bytes())
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect()
+}
+
+fn parseline(line: &str) -> Option<(&str, u64)> {
+    let (hash, count) = line.trim().split_once(':')?;
+    Some((hash, count.trim().parse().ok()?))
+}
+
+pub fn parserange(body: &str, suffix: &str) -> u64 {
+    body.lines()
+        .filter_map(parseline)
+        .find(|(hash, _)| hash.eq_ignore_ascii_case(suffix))
+        .map(|(_, count)| count)
+        .unwrap_or(0)
+}
+
+fn readline(reader: &mut BufReader<File>, offset: u64) -> Result<(u64, String), BreachError> {
+    let mut start = offset;
+    let mut line = String::new();
+    if offset > 0 {
+        reader.seek(SeekFrom::Start(offset - 1)).map_err(ioerr)?;
+        start = offset - 1 + reader.read_line(&mut line).map_err(ioerr)? as u64;
+        line.clear();
+    } else {
+        reader.seek(SeekFrom::Start(0)).map_err(ioerr)?;
+    }
+    reader.read_line(&mut line).map_err(ioerr)?;
+    Ok((start, line))
+}
+
+pub fn lookupfile(path: &Path, hash: &str) -> Result<u64, BreachError> {
+    let file = File::open(path).map_err(ioerr)?;
+    let mut hi = file.metadata().map_err(ioerr)?.len();
+    let mut reader = BufReader::new(file);
+    let mut lo = 0;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, line) = readline(&mut reader, mid)?;
+        if start >= hi || line.is_empty() {
+            hi = mid;
+            continue;
+        }
+        let (candidate, count) = parseline(&line)
+            .filter(|(h, _)| h.len() == HASH_LENGTH)
+            .ok_or_else(|| BreachError::InvalidDataset(format!("bad line at {}", start)))?;
+        match candidate.to_ascii_uppercase().as_str().cmp(hash) {
+            std::cmp::Ordering::Equal => return Ok(count),
+            std::cmp::Ordering::Less => lo = start + line.len() as u64,
+            std::cmp::Ordering::Greater => hi = start,
+        }
+    }
+    Ok(0)
+}
+
+pub fn lookupdirectory(path: &Path, hash: &str) -> Result<u64, BreachError> {
+    let (prefix, suffix) = hash.split_at(PREFIX_LENGTH);
+    let file = [prefix.to_string(), format!("{}.txt", prefix)]
+        .into_iter()
+        .map(|name| path.join(name))
+        .find(|p| p.is_file());
+    match file {
+        Some(file) => {
+            let body = std::fs::read_to_string(file).map_err(ioerr)?;
+            Ok(parserange(&body, suffix))
+        }
+        None if path.is_dir() => Ok(0),
+        None => Err(BreachError::Io(format!(
+            "{} is not a directory",
+            path.display()
+        ))),
+    }
+}
+
+pub fn lookuphttp(base_url: &str, hash: &str) -> Result<u64, BreachError> {
+    let (prefix, suffix) = hash.split_at(PREFIX_LENGTH);
+    let url = format!("{}/range/{}", base_url.trim_end_matches('/'), prefix);
+    let body = ureq::get(&url)
+        .set("Add-Padding", "true")
+        .call()
+        .map_err(|e| BreachError::Http(e.to_string()))?
+        .into_string()
+        .map_err(|e| BreachError::Http(e.to_string()))?;
+    Ok(parserange(&body, suffix))
+}
+
+pub fn check(source: &BreachSource, password: &str) -> Result<BreachResult, BreachError> {
+    let hash = sha1hex(password);
+    let count = match source {
+        BreachSource::File { path } => lookupfile(Path::new(path), &hash)?,
+        BreachSource::Directory { path } => lookupdirectory(Path::new(path), &hash)?,
+        BreachSource::Http { base_url } => lookuphttp(base_url, &hash)?,
+    };
+    Ok(BreachResult {
+        breached: count > 0,
+        count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+
+    fn tempdir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("noro-breach-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn serve(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let n = stream.read(&mut request).unwrap();
+            let request = String::from_utf8_lossy(&request[..n]).to_string();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (format!("http://{}/", address), server)
+    }
+
+    #[test]
+    fn test_breach_file() {
+        let dir = tempdir("file");
+        let password = sha1hex("password");
+        assert_eq!(password, "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8");
+
+        let mut hashes: Vec<String> = (0..500).map(|n| sha1hex(&n.to_string())).collect();
+        hashes.push(password.to_lowercase());
+        hashes.sort_by_key(|h| h.to_uppercase());
+        let lines: Vec<String> = hashes
+            .iter()
+            .enumerate()
+            .map(|(n, h)| format!("{}:{}", h, n + 1))
+            .collect();
+        let file = dir.join("pwned.txt");
+        std::fs::write(&file, lines.join("\r\n")).unwrap();
+        let expected = hashes
+            .iter()
+            .position(|h| h.eq_ignore_ascii_case(&password))
+            .unwrap()
+            + 1;
+        let source = BreachSource::File {
+            path: file.display().to_string(),
+        };
+        let found = check(&source, "password").unwrap();
+        assert_eq!((found.breached, found.count), (true, expected as u64));
+        for (n, hash) in hashes.iter().enumerate() {
+            assert_eq!(
+                lookupfile(&file, &hash.to_uppercase()).unwrap(),
+                n as u64 + 1
+            );
+        }
+        assert!(!check(&source, "correct horse").unwrap().breached);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_breach_file_invalid_dataset() {
+        let dir = tempdir("invalid");
+        let file = dir.join("pwned.txt");
+        std::fs::write(&file, "not a hash:12\r\n").unwrap();
+        let result = lookupfile(&file, &sha1hex("password"));
+        assert!(matches!(result, Err(BreachError::InvalidDataset(_))));
+
+        let count = format!("{}:many\r\n", sha1hex("password"));
+        std::fs::write(&file, count).unwrap();
+        let result = lookupfile(&file, &sha1hex("password"));
+        assert!(matches!(result, Err(BreachError::InvalidDataset(_))));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_breach_directory() {
+        let dir = tempdir("directory");
+        std::fs::write(
+            dir.join("5BAA6.txt"),
+            "003D68EB55068C33ACE09247EE4C639306B:3\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824\r\n",
+        )
+        .unwrap();
+        let directory = BreachSource::Directory {
+            path: dir.display().to_string(),
+        };
+        assert_eq!(check(&directory, "password").unwrap().count, 9545824);
+        assert_eq!(check(&directory, "hunter2").unwrap().count, 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_breach_missing_directory() {
+        let dir = std::env::temp_dir().join("noro-breach-missing");
+        let result = lookupdirectory(&dir, &sha1hex("password"));
+        assert!(matches!(result, Err(BreachError::Io(e)) if e.ends_with("is not a directory")));
+    }
+
+    #[test]
+    fn test_breach_http() {
+        let (base_url, server) = serve(
+            "200 OK",
+            "1E4C9B93F3F0682250B6CF8331B7EE68FD8:42\r\nFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF:0\r\n",
+        );
+        let http = BreachSource::Http { base_url };
+        assert_eq!(check(&http, "password").unwrap().count, 42);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /range/5BAA6 HTTP/1.1"));
+        assert!(request.to_lowercase().contains("add-padding: true"));
+        assert!(!request.contains("1E4C9B93"));
+    }
+
+    #[test]
+    fn test_breach_http_error() {
+        let (base_url, server) = serve("503 Service Unavailable", "");
+        let http = BreachSource::Http { base_url };
+        let result = check(&http, "password");
+        server.join().unwrap();
+        assert!(matches!(result, Err(BreachError::Http(e)) if e.contains("503")));
+    }
+}
@@ -0,0 +1,88 @@
+use noro_common::sse::EventParser;
+use serde::Deserialize;
+use std::io::Read;
+use std::time::Duration;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangedItem {
+    pub id: String,
+    pub revision: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeEvent {
+    #[serde(default)]
+    pub revision: i64,
+    pub items: Vec<ChangedItem>,
+    #[serde(default)]
+    pub purged: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum VaultEvent {
+    Ready { revision: i64 },
+    Change(ChangeEvent),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ReadyEvent {
+    revision: i64,
+}
+
+pub struct EventStream {
+    reader: Box<dyn Read + Send + Sync>,
+    parser: EventParser,
+    queue: Vec<VaultEvent>,
+}
+
+impl EventStream {
+    pub fn connect(url: &str, cookie: &str) -> Result<Self, super::SyncError> {
+        let agent = ureq::AgentBuilder::new().timeout_read(READ_TIMEOUT).build();
+        let resp = agent
+            .get(url)
+            .set("cookie", cookie)
+            .set("accept", "text/event-stream")
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        Ok(Self {
+            reader: resp.into_reader(),
+            parser: EventParser::default(),
+            queue: Vec::new(),
+        })
+    }
+
+    pub fn next(&mut self) -> Result<Option<VaultEvent>, super::SyncError> {
+        let mut chunk = [0u8; 4096];
+        loop {
+            if !self.queue.is_empty() {
+                return Ok(Some(self.queue.remove(0)));
+            }
+
+            let n = self
+                .reader
+                .read(&mut chunk)
+                .map_err(|_| super::SyncError::Http)?;
+            if n == 0 {
+                return Ok(None);
+            }
+
+            for (event, data) in self.parser.feed(&chunk[..n]) {
+                let parsed = match event.as_str() {
+                    "ready" => serde_json::from_str::<ReadyEvent>(&data)
+                        .map(|ready| VaultEvent::Ready {
+                            revision: ready.revision,
+                        })
+                        .ok(),
+                    "change" => serde_json::from_str(&data).map(VaultEvent::Change).ok(),
+                    _ => None,
+                };
+                self.queue.extend(parsed);
+            }
+        }
+    }
+}
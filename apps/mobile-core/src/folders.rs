@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Folder {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+    pub color: String,
+    pub icon: String,
+    pub order: i32,
+    pub created: u64,
+    pub updated: u64,
+}
+
+pub fn children<'a>(folders: &'a [Folder], parent: Option<&str>) -> Vec<&'a Folder> {
+    let mut found: Vec<&Folder> = folders
+        .iter()
+        .filter(|f| f.parent_id.as_deref() == parent)
+        .collect();
+    found.sort_by_key(|f| f.order);
+    found
+}
+
+pub fn path<'a>(folders: &'a [Folder], id: &str) -> Vec<&'a Folder> {
+    let mut path = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = Some(id);
+    while let Some(id) = current {
+        if !seen.insert(id) {
+            break;
+        }
+        let Some(folder) = folders.iter().find(|f| f.id == id) else {
+            break;
+        };
+        path.push(folder);
+        current = folder.parent_id.as_deref();
+    }
+    path.reverse();
+    path
+}
+
+pub fn descendants<'a>(folders: &'a [Folder], id: &str) -> Vec<&'a Folder> {
+    let mut found = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::from([id]);
+    let mut queue = vec![id];
+    while let Some(parent) = queue.pop() {
+        for child in children(folders, Some(parent)) {
+            if seen.insert(&child.id) {
+                found.push(child);
+                queue.push(&child.id);
+            }
+        }
+    }
+    found
+}
+
+pub fn createscycle(folders: &[Folder], id: &str, parent: Option<&str>) -> bool {
+    match parent {
+        Some(parent) => parent == id || path(folders, parent).iter().any(|f| f.id == id),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(id: &str, parent: Option<&str>, order: i32) -> Folder {
+        Folder {
+            id: id.into(),
+            name: id.into(),
+            parent_id: parent.map(Into::into),
+            color: "default".into(),
+            icon: "folder".into(),
+            order,
+            created: 0,
+            updated: 0,
+        }
+    }
+
+    fn tree() -> Vec<Folder> {
+        vec![
+            folder("root", None, 0),
+            folder("personal", Some("root"), 1),
+            folder("work", Some("root"), 0),
+            folder("clients", Some("work"), 0),
+            folder("loop", Some("loop"), 0),
+        ]
+    }
+
+    fn ids(f: Vec<&Folder>) -> String {
+        f.iter()
+            .map(|f| f.id.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    #[test]
+    fn test_children_sorted_by_order() {
+        let folders = tree();
+        assert_eq!(ids(children(&folders, Some("root"))), "work,personal");
+        assert_eq!(ids(children(&folders, None)), "root");
+        assert_eq!(ids(children(&folders, Some("missing"))), "");
+    }
+
+    #[test]
+    fn test_path() {
+        let folders = tree();
+        assert_eq!(ids(path(&folders, "clients")), "root,work,clients");
+        assert_eq!(ids(path(&folders, "root")), "root");
+        assert_eq!(ids(path(&folders, "missing")), "");
+    }
+
+    #[test]
+    fn test_path_terminates_on_self_parent() {
+        assert_eq!(ids(path(&tree(), "loop")), "loop");
+    }
+
+    #[test]
+    fn test_descendants() {
+        let folders = tree();
+        assert_eq!(ids(descendants(&folders, "root")), "work,personal,clients");
+        assert_eq!(ids(descendants(&folders, "clients")), "");
+        assert_eq!(ids(descendants(&folders, "loop")), "");
+    }
+
+    #[test]
+    fn test_createscycle() {
+        let folders = tree();
+        assert!(createscycle(&folders, "root", Some("clients")));
+        assert!(createscycle(&folders, "work", Some("work")));
+        assert!(!createscycle(&folders, "clients", Some("personal")));
+        assert!(!createscycle(&folders, "work", None));
+        assert!(!createscycle(&folders, "new", Some("missing")));
+    }
+}
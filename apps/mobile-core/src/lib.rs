@@ -1,8 +1,22 @@
+mod audit;
+mod autosync;
+mod backup;
+mod batch;
+mod breach;
 mod crypto;
+mod events;
+mod folders;
 mod sync;
 mod twoskd;
 mod vault;
 
+use base64::{engine::general_purpose::STANDARD, Engine};
+use noro_common::generator;
+use noro_common::otp;
+use noro_common::otpimport;
+use noro_common::strength;
+use noro_common::time::now;
+use noro_common::urlmatch;
 use std::sync::Arc;
 
 uniffi::setup_scaffolding!();
@@ -29,6 +43,8 @@ pub enum VaultError {
     Serialization,
     #[error("crypto error")]
     Crypto,
+    #[error("folder cannot be moved into itself")]
+    Cycle,
 }
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
@@ -39,8 +55,79 @@ pub enum SyncError {
     Auth,
     #[error("conflict")]
     Conflict,
+    #[error("rejected")]
+    Rejected,
     #[error("parse error")]
     Parse,
+    #[error("crypto error")]
+    Crypto,
+    #[error("vault locked")]
+    Locked,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error, uniffi::Error)]
+pub enum OtpError {
+    #[error("invalid otp secret")]
+    InvalidSecret,
+    #[error("invalid otp uri")]
+    InvalidUri,
+    #[error("unsupported otp parameters")]
+    Unsupported,
+}
+
+impl From<otp::OtpError> for OtpError {
+    fn from(error: otp::OtpError) -> Self {
+        match error {
+            otp::OtpError::InvalidSecret => Self::InvalidSecret,
+            otp::OtpError::InvalidUri => Self::InvalidUri,
+            otp::OtpError::Unsupported => Self::Unsupported,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error, uniffi::Error)]
+pub enum BreachError {
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("invalid dataset: {0}")]
+    InvalidDataset(String),
+    #[error("http error: {0}")]
+    Http(String),
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum BackupError {
+    #[error("invalid password")]
+    InvalidPassword,
+    #[error("invalid backup archive")]
+    Format,
+    #[error("unsupported backup archive")]
+    Unsupported,
+    #[error("crypto error")]
+    Crypto,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error, uniffi::Error)]
+pub enum GeneratorError {
+    #[error("at least one character type required")]
+    NoCharacters,
+    #[error("minimum counts exceed password length")]
+    TooShort,
+    #[error("not enough distinct characters for a password without repeats")]
+    NotEnoughCharacters,
+    #[error("could not satisfy generator constraints")]
+    Exhausted,
+}
+
+impl From<generator::GeneratorError> for GeneratorError {
+    fn from(error: generator::GeneratorError) -> Self {
+        match error {
+            generator::GeneratorError::NoCharacters => Self::NoCharacters,
+            generator::GeneratorError::TooShort => Self::TooShort,
+            generator::GeneratorError::NotEnoughCharacters => Self::NotEnoughCharacters,
+            generator::GeneratorError::Exhausted => Self::Exhausted,
+        }
+    }
 }
 
 #[uniffi::export]
@@ -49,7 +136,11 @@ pub fn generate_secret_key() -> String {
 }
 
 #[uniffi::export]
-pub fn derive_auk(password: String, secret_key: String, salt: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+pub fn derive_auk(
+    password: String,
+    secret_key: String,
+    salt: Vec<u8>,
+) -> Result<Vec<u8>, CryptoError> {
     twoskd::deriveauk(&password, &secret_key, &salt)
 }
 
@@ -93,6 +184,569 @@ pub fn generate_item_id() -> String {
     uuid::Uuid::new_v4().to_string()
 }
 
+#[uniffi::export]
+pub fn trash_retention_days() -> u64 {
+    vault::TRASH_RETENTION_DAYS
+}
+
+#[derive(Clone, Copy, uniffi::Enum)]
+pub enum UrlMatchRule {
+    Domain,
+    Host,
+    StartsWith,
+    Regex,
+    Never,
+}
+
+impl From<urlmatch::MatchRule> for UrlMatchRule {
+    fn from(rule: urlmatch::MatchRule) -> Self {
+        match rule {
+            urlmatch::MatchRule::Domain => Self::Domain,
+            urlmatch::MatchRule::Host => Self::Host,
+            urlmatch::MatchRule::StartsWith => Self::StartsWith,
+            urlmatch::MatchRule::Regex => Self::Regex,
+            urlmatch::MatchRule::Never => Self::Never,
+        }
+    }
+}
+
+impl From<UrlMatchRule> for urlmatch::MatchRule {
+    fn from(rule: UrlMatchRule) -> Self {
+        match rule {
+            UrlMatchRule::Domain => Self::Domain,
+            UrlMatchRule::Host => Self::Host,
+            UrlMatchRule::StartsWith => Self::StartsWith,
+            UrlMatchRule::Regex => Self::Regex,
+            UrlMatchRule::Never => Self::Never,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct LoginUrl {
+    pub url: String,
+    pub rule: Option<UrlMatchRule>,
+}
+
+#[derive(uniffi::Record)]
+pub struct LoginUrls {
+    pub id: String,
+    pub urls: Vec<LoginUrl>,
+}
+
+#[derive(uniffi::Record)]
+pub struct UrlMatch {
+    pub id: String,
+    pub url: String,
+    pub rule: UrlMatchRule,
+    pub score: u8,
+}
+
+#[uniffi::export]
+pub fn match_url(page_url: String, logins: Vec<LoginUrls>) -> Vec<UrlMatch> {
+    let logins: Vec<urlmatch::Login> = logins
+        .into_iter()
+        .map(|l| urlmatch::Login {
+            id: l.id,
+            urls: l
+                .urls
+                .into_iter()
+                .map(|u| urlmatch::LoginUrl::new(u.url, u.rule.map(Into::into)))
+                .collect(),
+        })
+        .collect();
+    urlmatch::matchlogins(&page_url, &logins)
+        .into_iter()
+        .map(|m| UrlMatch {
+            id: m.id,
+            url: m.url,
+            rule: m.rule.into(),
+            score: m.score,
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, uniffi::Enum)]
+pub enum OtpAlgorithm {
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+#[derive(Clone, Copy, uniffi::Enum)]
+pub enum OtpKind {
+    Totp,
+    Hotp,
+    Steam,
+}
+
+#[derive(uniffi::Record)]
+pub struct OtpConfig {
+    pub kind: OtpKind,
+    pub secret: String,
+    pub issuer: Option<String>,
+    pub account: Option<String>,
+    pub algorithm: OtpAlgorithm,
+    pub digits: u32,
+    pub period: u64,
+    pub counter: u64,
+}
+
+impl From<otp::OtpConfig> for OtpConfig {
+    fn from(config: otp::OtpConfig) -> Self {
+        Self {
+            kind: match config.kind {
+                otp::OtpKind::Totp => OtpKind::Totp,
+                otp::OtpKind::Hotp => OtpKind::Hotp,
+                otp::OtpKind::Steam => OtpKind::Steam,
+            },
+            secret: config.secret,
+            issuer: config.issuer,
+            account: config.account,
+            algorithm: match config.algorithm {
+                otp::Algorithm::Sha1 => OtpAlgorithm::Sha1,
+                otp::Algorithm::Sha256 => OtpAlgorithm::Sha256,
+                otp::Algorithm::Sha512 => OtpAlgorithm::Sha512,
+            },
+            digits: config.digits,
+            period: config.period,
+            counter: config.counter,
+        }
+    }
+}
+
+impl From<OtpConfig> for otp::OtpConfig {
+    fn from(config: OtpConfig) -> Self {
+        Self {
+            kind: match config.kind {
+                OtpKind::Totp => otp::OtpKind::Totp,
+                OtpKind::Hotp => otp::OtpKind::Hotp,
+                OtpKind::Steam => otp::OtpKind::Steam,
+            },
+            secret: config.secret,
+            issuer: config.issuer,
+            account: config.account,
+            algorithm: match config.algorithm {
+                OtpAlgorithm::Sha1 => otp::Algorithm::Sha1,
+                OtpAlgorithm::Sha256 => otp::Algorithm::Sha256,
+                OtpAlgorithm::Sha512 => otp::Algorithm::Sha512,
+            },
+            digits: config.digits,
+            period: config.period,
+            counter: config.counter,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct OtpCode {
+    pub code: String,
+    pub counter: u64,
+    pub period: u64,
+    pub remaining: u64,
+    pub expires: u64,
+}
+
+impl From<otp::OtpCode> for OtpCode {
+    fn from(code: otp::OtpCode) -> Self {
+        Self {
+            code: code.code,
+            counter: code.counter,
+            period: code.period,
+            remaining: code.remaining,
+            expires: code.expires,
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn parse_otp(input: String) -> Result<OtpConfig, OtpError> {
+    Ok(otp::parse(&input)?.into())
+}
+
+#[uniffi::export]
+pub fn otp_uri(config: OtpConfig) -> String {
+    otp::OtpConfig::from(config).touri()
+}
+
+#[uniffi::export]
+pub fn otp_code(config: OtpConfig, timestamp: Option<u64>) -> Result<OtpCode, OtpError> {
+    Ok(otp::OtpConfig::from(config)
+        .generate(timestamp.unwrap_or_else(now))?
+        .into())
+}
+
+#[derive(uniffi::Record)]
+pub struct OtpImportCandidate {
+    pub config: OtpConfig,
+    pub title: String,
+    pub duplicate_of: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct OtpImportPreview {
+    pub candidates: Vec<OtpImportCandidate>,
+    pub invalid: Vec<String>,
+}
+
+impl From<otpimport::OtpImportPreview> for OtpImportPreview {
+    fn from(preview: otpimport::OtpImportPreview) -> Self {
+        Self {
+            candidates: preview
+                .candidates
+                .into_iter()
+                .map(|c| OtpImportCandidate {
+                    config: c.config.into(),
+                    title: c.title,
+                    duplicate_of: c.duplicate_of,
+                })
+                .collect(),
+            invalid: preview.invalid,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct OtpImportResult {
+    pub created: Vec<VaultItem>,
+    pub duplicates: u32,
+    pub invalid: Vec<String>,
+}
+
+#[uniffi::export]
+pub fn parse_otp_import(text: String) -> OtpImportPreview {
+    otpimport::preview(&text, &[]).into()
+}
+
+#[uniffi::export]
+pub fn otp_item_data(config: OtpConfig) -> Vec<u8> {
+    otpimport::itemdata(&config.into()).into_bytes()
+}
+
+#[uniffi::export]
+pub fn otp_from_item(data: Vec<u8>) -> Result<Option<OtpConfig>, OtpError> {
+    Ok(otp::fromdata(&data).transpose()?.map(OtpConfig::from))
+}
+
+#[derive(uniffi::Record)]
+pub struct BackupManifest {
+    pub version: u32,
+    pub created: u64,
+    pub source: String,
+    pub items: u32,
+    pub folders: u32,
+    pub attachments: u32,
+}
+
+impl From<backup::Manifest> for BackupManifest {
+    fn from(manifest: backup::Manifest) -> Self {
+        Self {
+            version: manifest.version,
+            created: manifest.created,
+            source: manifest.source,
+            items: manifest.items,
+            folders: manifest.folders,
+            attachments: manifest.attachments,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct BackupAttachment {
+    pub id: String,
+    pub item_id: String,
+    pub name: String,
+    pub mime: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl From<BackupAttachment> for backup::BackupAttachment {
+    fn from(attachment: BackupAttachment) -> Self {
+        Self {
+            id: attachment.id,
+            item_id: attachment.item_id,
+            name: attachment.name,
+            mime: attachment.mime,
+            data: STANDARD.encode(attachment.data),
+        }
+    }
+}
+
+impl TryFrom<backup::BackupAttachment> for BackupAttachment {
+    type Error = BackupError;
+
+    fn try_from(attachment: backup::BackupAttachment) -> Result<Self, BackupError> {
+        Ok(Self {
+            id: attachment.id,
+            item_id: attachment.item_id,
+            name: attachment.name,
+            mime: attachment.mime,
+            data: STANDARD
+                .decode(attachment.data)
+                .map_err(|_| BackupError::Format)?,
+        })
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct BackupImportResult {
+    pub created: u32,
+    pub updated: u32,
+    pub unchanged: u32,
+    pub folders: u32,
+    pub attachments: Vec<BackupAttachment>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum PlainExportFormat {
+    Json,
+    Csv,
+}
+
+#[uniffi::export]
+pub fn read_backup_manifest(archive: Vec<u8>) -> Result<BackupManifest, BackupError> {
+    backup::manifest(&archive).map(BackupManifest::from)
+}
+
+#[derive(Clone, Copy, uniffi::Enum)]
+pub enum GeneratorMode {
+    Random,
+    Passphrase,
+    Pronounceable,
+    Pin,
+}
+
+#[derive(uniffi::Record)]
+pub struct GeneratorOptions {
+    pub mode: GeneratorMode,
+    pub length: u32,
+    pub uppercase: bool,
+    pub lowercase: bool,
+    pub numbers: bool,
+    pub symbols: bool,
+    pub min_uppercase: u32,
+    pub min_lowercase: u32,
+    pub min_numbers: u32,
+    pub min_symbols: u32,
+    pub exclude_ambiguous: bool,
+    pub include: String,
+    pub exclude: String,
+    pub no_repeat: bool,
+    pub no_consecutive: bool,
+    pub words: u32,
+    pub separator: String,
+    pub capitalize: bool,
+    pub append_digit: bool,
+}
+
+impl From<GeneratorOptions> for generator::GeneratorOptions {
+    fn from(options: GeneratorOptions) -> Self {
+        Self {
+            mode: match options.mode {
+                GeneratorMode::Random => generator::GeneratorMode::Random,
+                GeneratorMode::Passphrase => generator::GeneratorMode::Passphrase,
+                GeneratorMode::Pronounceable => generator::GeneratorMode::Pronounceable,
+                GeneratorMode::Pin => generator::GeneratorMode::Pin,
+            },
+            length: options.length as usize,
+            uppercase: options.uppercase,
+            lowercase: options.lowercase,
+            numbers: options.numbers,
+            symbols: options.symbols,
+            min_uppercase: options.min_uppercase as usize,
+            min_lowercase: options.min_lowercase as usize,
+            min_numbers: options.min_numbers as usize,
+            min_symbols: options.min_symbols as usize,
+            exclude_ambiguous: options.exclude_ambiguous,
+            include: options.include,
+            exclude: options.exclude,
+            no_repeat: options.no_repeat,
+            no_consecutive: options.no_consecutive,
+            words: options.words as usize,
+            separator: options.separator,
+            capitalize: options.capitalize,
+            append_digit: options.append_digit,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct GeneratorResult {
+    pub password: String,
+    pub strength: String,
+    pub entropy: f64,
+}
+
+#[uniffi::export]
+pub fn generate_password(options: GeneratorOptions) -> Result<GeneratorResult, GeneratorError> {
+    let result = generator::generate(&options.into())?;
+    Ok(GeneratorResult {
+        password: result.password,
+        strength: result.strength,
+        entropy: result.entropy,
+    })
+}
+
+#[derive(uniffi::Record)]
+pub struct ItemAudit {
+    pub id: String,
+    pub title: String,
+    pub score: u8,
+    pub strength: String,
+    pub warning: Option<String>,
+    pub age_days: u64,
+    pub reused: u32,
+    pub weak: bool,
+    pub old: bool,
+    pub has_totp: bool,
+}
+
+#[derive(uniffi::Record)]
+pub struct ReusedGroup {
+    pub ids: Vec<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct AuditReport {
+    pub total: u32,
+    pub weak: u32,
+    pub reused: u32,
+    pub old: u32,
+    pub missing_totp: u32,
+    pub groups: Vec<ReusedGroup>,
+    pub items: Vec<ItemAudit>,
+}
+
+impl From<audit::AuditReport> for AuditReport {
+    fn from(report: audit::AuditReport) -> Self {
+        Self {
+            total: report.total as u32,
+            weak: report.weak as u32,
+            reused: report.reused as u32,
+            old: report.old as u32,
+            missing_totp: report.missing_totp as u32,
+            groups: report
+                .groups
+                .into_iter()
+                .map(|g| ReusedGroup { ids: g.ids })
+                .collect(),
+            items: report
+                .items
+                .into_iter()
+                .map(|i| ItemAudit {
+                    id: i.id,
+                    title: i.title,
+                    score: i.score,
+                    strength: i.strength,
+                    warning: i.warning,
+                    age_days: i.age_days,
+                    reused: i.reused as u32,
+                    weak: i.weak,
+                    old: i.old,
+                    has_totp: i.has_totp,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum BreachSource {
+    File { path: String },
+    Directory { path: String },
+    Http { base_url: String },
+}
+
+#[derive(uniffi::Record)]
+pub struct BreachResult {
+    pub breached: bool,
+    pub count: u64,
+}
+
+#[uniffi::export]
+pub fn check_breached_password(
+    source: BreachSource,
+    password: String,
+) -> Result<BreachResult, BreachError> {
+    let source = match source {
+        BreachSource::File { path } => breach::BreachSource::File { path },
+        BreachSource::Directory { path } => breach::BreachSource::Directory { path },
+        BreachSource::Http { base_url } => breach::BreachSource::Http { base_url },
+    };
+    breach::check(&source, &password).map(|result| BreachResult {
+        breached: result.breached,
+        count: result.count,
+    })
+}
+
+#[derive(uniffi::Record)]
+pub struct CrackTime {
+    pub scenario: String,
+    pub seconds: f64,
+    pub display: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct StrengthMatch {
+    pub pattern: String,
+    pub token: String,
+    pub guesses: f64,
+}
+
+#[derive(uniffi::Record)]
+pub struct PasswordStrength {
+    pub guesses: f64,
+    pub guesses_log10: f64,
+    pub score: u8,
+    pub strength: String,
+    pub crack_times: Vec<CrackTime>,
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+    pub sequence: Vec<StrengthMatch>,
+}
+
+impl From<strength::Estimate> for PasswordStrength {
+    fn from(estimate: strength::Estimate) -> Self {
+        Self {
+            guesses: estimate.guesses,
+            guesses_log10: estimate.guesses_log10,
+            score: estimate.score,
+            strength: estimate.strength,
+            crack_times: estimate
+                .crack_times
+                .into_iter()
+                .map(|t| CrackTime {
+                    scenario: t.scenario,
+                    seconds: t.seconds,
+                    display: t.display,
+                })
+                .collect(),
+            warning: estimate.warning,
+            suggestions: estimate.suggestions,
+            sequence: estimate
+                .sequence
+                .into_iter()
+                .map(|m| StrengthMatch {
+                    pattern: m.pattern,
+                    token: m.token,
+                    guesses: m.guesses,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn estimate_password_strength(password: String, user_inputs: Vec<String>) -> PasswordStrength {
+    strength::estimate(&password, &user_inputs).into()
+}
+
+#[uniffi::export]
+pub fn registrable_domain(url: String) -> Option<String> {
+    let url = urlmatch::parseurl(&url)?;
+    urlmatch::registrabledomain(url.host_str()?)
+}
+
 #[derive(uniffi::Record)]
 pub struct VaultItem {
     pub id: String,
@@ -102,7 +756,9 @@ pub struct VaultItem {
     pub revision: i32,
     pub favorite: bool,
     pub deleted: bool,
+    pub deleted_at: Option<u64>,
     pub tags: Vec<String>,
+    pub folder_id: Option<String>,
     pub created: u64,
     pub updated: u64,
 }
@@ -117,16 +773,80 @@ impl From<vault::VaultItem> for VaultItem {
             revision: item.revision,
             favorite: item.favorite,
             deleted: item.deleted,
+            deleted_at: item.deleted_at,
             tags: item.tags,
+            folder_id: item.folder_id,
             created: item.created,
             updated: item.updated,
         }
     }
 }
 
+impl From<VaultItem> for vault::VaultItem {
+    fn from(item: VaultItem) -> Self {
+        Self {
+            id: item.id,
+            item_type: item.item_type,
+            title: item.title,
+            data: item.data,
+            revision: item.revision,
+            favorite: item.favorite,
+            deleted: item.deleted,
+            deleted_at: item.deleted_at,
+            tags: item.tags,
+            folder_id: item.folder_id,
+            created: item.created,
+            updated: item.updated,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct Folder {
+    pub id: String,
+    pub name: String,
+    pub parent_id: Option<String>,
+    pub color: String,
+    pub icon: String,
+    pub order: i32,
+    pub created: u64,
+    pub updated: u64,
+}
+
+impl From<folders::Folder> for Folder {
+    fn from(folder: folders::Folder) -> Self {
+        Self {
+            id: folder.id,
+            name: folder.name,
+            parent_id: folder.parent_id,
+            color: folder.color,
+            icon: folder.icon,
+            order: folder.order,
+            created: folder.created,
+            updated: folder.updated,
+        }
+    }
+}
+
+impl From<Folder> for folders::Folder {
+    fn from(folder: Folder) -> Self {
+        Self {
+            id: folder.id,
+            name: folder.name,
+            parent_id: folder.parent_id,
+            color: folder.color,
+            icon: folder.icon,
+            order: folder.order,
+            created: folder.created,
+            updated: folder.updated,
+        }
+    }
+}
+
 #[derive(uniffi::Record)]
 pub struct VaultData {
     pub items: Vec<VaultItem>,
+    pub folders: Vec<Folder>,
     pub updated: u64,
 }
 
@@ -187,25 +907,358 @@ impl Vault {
     }
 
     pub fn list_items(&self) -> Vec<VaultItem> {
-        self.inner.list_items().into_iter().map(VaultItem::from).collect()
+        self.inner
+            .list_items()
+            .into_iter()
+            .map(VaultItem::from)
+            .collect()
     }
 
     pub fn search_items(&self, query: String) -> Vec<VaultItem> {
-        self.inner.search_items(query).into_iter().map(VaultItem::from).collect()
+        self.inner
+            .search_items(query)
+            .into_iter()
+            .map(VaultItem::from)
+            .collect()
+    }
+
+    pub fn search(&self, query: String, limit: u32) -> Vec<VaultItem> {
+        self.inner
+            .search(&query, limit as usize)
+            .into_iter()
+            .map(VaultItem::from)
+            .collect()
+    }
+
+    pub fn otp_code(
+        &self,
+        id: String,
+        timestamp: Option<u64>,
+    ) -> Result<Option<OtpCode>, OtpError> {
+        match self.inner.otp_config(&id) {
+            Some(config) => Ok(Some(config?.generate(timestamp.unwrap_or_else(now))?.into())),
+            None => Ok(None),
+        }
+    }
+
+    pub fn preview_otp_import(&self, text: String) -> OtpImportPreview {
+        self.inner.preview_otp_import(&text).into()
+    }
+
+    pub fn import_otp(
+        &self,
+        text: String,
+        skip_duplicates: bool,
+    ) -> Result<OtpImportResult, VaultError> {
+        let (created, preview) = self.inner.import_otp(&text, skip_duplicates)?;
+        Ok(OtpImportResult {
+            created: created.into_iter().map(VaultItem::from).collect(),
+            duplicates: preview
+                .candidates
+                .iter()
+                .filter(|c| c.duplicate_of.is_some())
+                .count() as u32,
+            invalid: preview.invalid,
+        })
+    }
+
+    pub fn export_backup(
+        &self,
+        password: String,
+        attachments: Vec<BackupAttachment>,
+    ) -> Result<Vec<u8>, BackupError> {
+        let mut data = self.inner.backup();
+        data.attachments = attachments
+            .into_iter()
+            .map(backup::BackupAttachment::from)
+            .collect();
+        backup::seal(
+            &data,
+            &password,
+            backup::KdfParams::default(),
+            "mobile",
+            now(),
+        )
+    }
+
+    pub fn import_backup(
+        &self,
+        archive: Vec<u8>,
+        password: String,
+    ) -> Result<BackupImportResult, BackupError> {
+        let (_, data) = backup::open(&archive, &password)?;
+        let attachments = data
+            .attachments
+            .iter()
+            .cloned()
+            .map(BackupAttachment::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let folders = data.folders.len() as u32;
+        let (created, updated, unchanged) = self.inner.restore(data);
+        Ok(BackupImportResult {
+            created,
+            updated,
+            unchanged,
+            folders,
+            attachments,
+        })
+    }
+
+    pub fn export_plaintext(
+        &self,
+        format: PlainExportFormat,
+        password: String,
+        secret_key: String,
+        salt: Vec<u8>,
+        wrapped_vault_key: Vec<u8>,
+    ) -> Result<String, BackupError> {
+        let auk = twoskd::deriveauk(&password, &secret_key, &salt)
+            .map_err(|_| BackupError::InvalidPassword)?;
+        twoskd::unwrapvaultkey(&wrapped_vault_key, &auk)
+            .map_err(|_| BackupError::InvalidPassword)?;
+        let data = self.inner.backup();
+        match format {
+            PlainExportFormat::Json => backup::tojson(&data, now()),
+            PlainExportFormat::Csv => Ok(backup::tocsv(&data)),
+        }
+    }
+
+    pub fn audit(&self) -> AuditReport {
+        self.inner.audit().into()
+    }
+
+    pub fn match_url(&self, page_url: String) -> Vec<VaultItem> {
+        self.inner
+            .match_url(&page_url)
+            .into_iter()
+            .map(VaultItem::from)
+            .collect()
+    }
+
+    pub fn lock(&self) {
+        self.inner.lock()
+    }
+
+    pub fn list_trash(&self) -> Vec<VaultItem> {
+        self.inner
+            .list_trash()
+            .into_iter()
+            .map(VaultItem::from)
+            .collect()
+    }
+
+    pub fn restore_item(&self, id: String) -> Result<VaultItem, VaultError> {
+        self.inner.restore_item(id).map(VaultItem::from)
+    }
+
+    pub fn purge_item(&self, id: String) -> Result<(), VaultError> {
+        self.inner.purge_item(id)
+    }
+
+    pub fn empty_trash(&self) -> Vec<String> {
+        self.inner.empty_trash()
+    }
+
+    pub fn purge_expired(&self) -> Vec<String> {
+        self.inner.purge_expired()
+    }
+
+    pub fn apply_remote(&self, changed: Vec<VaultItem>, removed: Vec<String>) {
+        self.inner.apply_remote(
+            changed.into_iter().map(vault::VaultItem::from).collect(),
+            removed,
+        )
+    }
+
+    pub fn create_folder(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: String,
+        icon: String,
+    ) -> Result<Folder, VaultError> {
+        self.inner
+            .create_folder(name, parent_id, color, icon)
+            .map(Folder::from)
+    }
+
+    pub fn update_folder(
+        &self,
+        id: String,
+        name: Option<String>,
+        color: Option<String>,
+        icon: Option<String>,
+        order: Option<i32>,
+    ) -> Result<Folder, VaultError> {
+        self.inner
+            .update_folder(id, name, color, icon, order)
+            .map(Folder::from)
+    }
+
+    pub fn move_folder(&self, id: String, parent_id: Option<String>) -> Result<Folder, VaultError> {
+        self.inner.move_folder(id, parent_id).map(Folder::from)
+    }
+
+    pub fn delete_folder(&self, id: String) -> Result<Vec<String>, VaultError> {
+        self.inner.delete_folder(id)
+    }
+
+    pub fn move_item(
+        &self,
+        id: String,
+        folder_id: Option<String>,
+    ) -> Result<VaultItem, VaultError> {
+        self.inner.move_item(id, folder_id).map(VaultItem::from)
+    }
+
+    pub fn set_folders(&self, folders: Vec<Folder>) {
+        self.inner
+            .set_folders(folders.into_iter().map(folders::Folder::from).collect())
+    }
+
+    pub fn list_folders(&self) -> Vec<Folder> {
+        self.inner
+            .list_folders()
+            .into_iter()
+            .map(Folder::from)
+            .collect()
+    }
+
+    pub fn folder_children(&self, parent_id: Option<String>) -> Vec<Folder> {
+        self.inner
+            .folder_children(parent_id)
+            .into_iter()
+            .map(Folder::from)
+            .collect()
+    }
+
+    pub fn folder_path(&self, id: String) -> Vec<Folder> {
+        self.inner
+            .folder_path(id)
+            .into_iter()
+            .map(Folder::from)
+            .collect()
     }
+
+    pub fn folder_descendants(&self, id: String) -> Vec<Folder> {
+        self.inner
+            .folder_descendants(id)
+            .into_iter()
+            .map(Folder::from)
+            .collect()
+    }
+
+    pub fn folder_items(&self, folder_id: Option<String>) -> Vec<VaultItem> {
+        self.inner
+            .folder_items(folder_id)
+            .into_iter()
+            .map(VaultItem::from)
+            .collect()
+    }
+}
+
+#[derive(Clone, uniffi::Enum)]
+pub enum PendingChange {
+    Create {
+        id: String,
+        item_type: String,
+        title: String,
+        data: Vec<u8>,
+        tags: Vec<String>,
+        favorite: bool,
+    },
+    Update {
+        id: String,
+        revision: i32,
+        title: Option<String>,
+        data: Option<Vec<u8>>,
+        tags: Option<Vec<String>>,
+        favorite: Option<bool>,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+#[derive(uniffi::Record)]
+pub struct SyncResult {
+    pub changed: Vec<VaultItem>,
+    pub removed: Vec<String>,
+    pub pushed: u32,
+    pub error: Option<String>,
+    pub timestamp: u64,
+}
+
+#[derive(uniffi::Record)]
+pub struct SyncConflict {
+    pub id: String,
+    pub local_revision: i32,
+    pub server_revision: i32,
+    pub server: VaultItem,
+}
+
+#[derive(Clone, uniffi::Enum)]
+pub enum BatchOp {
+    Create {
+        id: String,
+        item_type: String,
+        title: String,
+        data: Vec<u8>,
+        tags: Vec<String>,
+        favorite: bool,
+    },
+    Update {
+        id: String,
+        title: Option<String>,
+        data: Option<Vec<u8>>,
+        tags: Option<Vec<String>>,
+        favorite: Option<bool>,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+#[derive(uniffi::Record)]
+pub struct BatchItemResult {
+    pub id: Option<String>,
+    pub op: String,
+    pub ok: bool,
+    pub error: Option<String>,
+    pub item: Option<VaultItem>,
+}
+
+#[derive(uniffi::Record)]
+pub struct BatchResult {
+    pub results: Vec<BatchItemResult>,
+    pub succeeded: u32,
+    pub failed: u32,
+}
+
+#[uniffi::export(with_foreign)]
+pub trait SyncListener: Send + Sync {
+    fn on_sync_started(&self, reason: String);
+    fn on_sync_progress(&self, phase: String, done: u32, total: u32);
+    fn on_sync_completed(&self, result: SyncResult);
+    fn on_sync_conflict(&self, conflict: SyncConflict);
+    fn on_sync_rejected(&self, change: PendingChange);
+    fn on_sync_connection(&self, connected: bool);
 }
 
 #[derive(uniffi::Object)]
 pub struct SyncClient {
-    inner: sync::SyncClient,
+    inner: Arc<sync::SyncClient>,
+    background: autosync::AutoSync,
 }
 
 #[uniffi::export]
 impl SyncClient {
     #[uniffi::constructor]
     pub fn new(base_url: String) -> Arc<Self> {
+        let inner = Arc::new(sync::SyncClient::new(base_url));
         Arc::new(Self {
-            inner: sync::SyncClient::new(base_url),
+            background: autosync::AutoSync::new(inner.clone()),
+            inner,
         })
     }
 
@@ -217,8 +1270,41 @@ impl SyncClient {
         self.inner.login(email, password)
     }
 
+    pub fn unlock(
+        &self,
+        password: String,
+        secret_key: String,
+        salt: Vec<u8>,
+        wrapped_vault_key: Vec<u8>,
+    ) -> Result<(), SyncError> {
+        let auk =
+            twoskd::deriveauk(&password, &secret_key, &salt).map_err(|_| SyncError::Crypto)?;
+        let vault_key =
+            twoskd::unwrapvaultkey(&wrapped_vault_key, &auk).map_err(|_| SyncError::Crypto)?;
+        self.inner.unlock(vault_key)?;
+        self.background.set_locked(false);
+        Ok(())
+    }
+
+    pub fn unlock_with_key(&self, vault_key: Vec<u8>) -> Result<(), SyncError> {
+        self.inner.unlock(vault_key)?;
+        self.background.set_locked(false);
+        Ok(())
+    }
+
+    pub fn lock(&self) {
+        self.background.set_locked(true);
+        self.inner.lock();
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.inner.is_locked()
+    }
+
     pub fn fetch_items(&self) -> Result<Vec<VaultItem>, SyncError> {
-        self.inner.fetch_items().map(|v| v.into_iter().map(VaultItem::from).collect())
+        self.inner
+            .fetch_items()
+            .map(|v| v.into_iter().map(VaultItem::from).collect())
     }
 
     pub fn create_item(
@@ -229,8 +1315,9 @@ impl SyncClient {
         tags: Vec<String>,
         favorite: bool,
     ) -> Result<VaultItem, SyncError> {
+        let id = uuid::Uuid::new_v4().to_string();
         self.inner
-            .create_item(item_type, title, data, tags, favorite)
+            .create_item(id, item_type, title, data, tags, favorite)
             .map(VaultItem::from)
     }
 
@@ -250,4 +1337,102 @@ impl SyncClient {
     pub fn delete_item(&self, id: String) -> Result<(), SyncError> {
         self.inner.delete_item(id)
     }
+
+    pub fn fetch_trash(&self) -> Result<Vec<VaultItem>, SyncError> {
+        self.inner
+            .fetch_trash()
+            .map(|v| v.into_iter().map(VaultItem::from).collect())
+    }
+
+    pub fn restore_item(&self, id: String) -> Result<VaultItem, SyncError> {
+        self.inner.restore_item(id).map(VaultItem::from)
+    }
+
+    pub fn purge_item(&self, id: String) -> Result<(), SyncError> {
+        self.inner.purge_item(id)
+    }
+
+    pub fn empty_trash(&self) -> Result<Vec<String>, SyncError> {
+        self.inner.empty_trash()
+    }
+
+    pub fn fetch_folders(&self) -> Result<Vec<Folder>, SyncError> {
+        self.inner
+            .fetch_folders()
+            .map(|v| v.into_iter().map(Folder::from).collect())
+    }
+
+    pub fn create_folder(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<Folder, SyncError> {
+        let id = uuid::Uuid::new_v4().to_string();
+        self.inner
+            .create_folder(id, name, parent_id, color, icon)
+            .map(Folder::from)
+    }
+
+    pub fn update_folder(
+        &self,
+        id: String,
+        name: Option<String>,
+        color: Option<String>,
+        icon: Option<String>,
+        order: Option<i32>,
+    ) -> Result<Folder, SyncError> {
+        self.inner
+            .update_folder(id, name, color, icon, order)
+            .map(Folder::from)
+    }
+
+    pub fn move_folder(&self, id: String, parent_id: Option<String>) -> Result<Folder, SyncError> {
+        self.inner.move_folder(id, parent_id).map(Folder::from)
+    }
+
+    pub fn delete_folder(&self, id: String) -> Result<Vec<String>, SyncError> {
+        self.inner.delete_folder(id)
+    }
+
+    pub fn move_items(
+        &self,
+        folder_id: Option<String>,
+        item_ids: Vec<String>,
+    ) -> Result<u32, SyncError> {
+        self.inner.move_items(folder_id, item_ids)
+    }
+
+    pub fn batch(
+        &self,
+        ops: Vec<BatchOp>,
+        listener: Option<Arc<dyn SyncListener>>,
+    ) -> Result<BatchResult, SyncError> {
+        batch::run(&self.inner, ops, listener.as_deref())
+    }
+
+    pub fn start_background_sync(&self, listener: Arc<dyn SyncListener>, interval_secs: u64) {
+        self.background.start(listener, interval_secs)
+    }
+
+    pub fn stop_background_sync(&self) {
+        self.background.stop()
+    }
+
+    pub fn request_sync(&self) {
+        self.background.request()
+    }
+
+    pub fn queue_change(&self, change: PendingChange) {
+        self.background.enqueue(change)
+    }
+
+    pub fn pending_changes(&self) -> Vec<PendingChange> {
+        self.background.pending()
+    }
+
+    pub fn resolve_sync_conflict(&self, id: String, keep_local: bool) {
+        self.background.resolve(&id, keep_local)
+    }
 }
@@ -1,25 +1,61 @@
+use noro_common::time::parsetimestamp;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
 
+use crate::events::EventStream;
+use crate::folders::Folder;
 use crate::vault::VaultItem;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct RemoteTag {
-    id: String,
-    name: String,
+pub struct RemoteTag {
+    pub id: String,
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct RemoteItem {
-    id: String,
+pub struct RemoteItem {
+    pub id: String,
+    #[serde(rename = "type", default, deserialize_with = "nullable")]
+    pub item_type: String,
+    pub title: String,
+    pub data: String,
+    #[serde(default)]
+    pub meta: Option<String>,
+    pub revision: i32,
+    pub favorite: bool,
+    pub deleted: bool,
+    #[serde(rename = "deletedAt", default)]
+    pub deleted_at: Option<String>,
+    pub tags: Vec<RemoteTag>,
+    #[serde(rename = "folderId", default)]
+    pub folder_id: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteFolder {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<String>,
+    pub color: String,
+    pub icon: String,
+    pub order: i32,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemMeta {
     #[serde(rename = "type")]
-    item_type: String,
-    title: String,
-    data: String,
-    revision: i32,
-    favorite: bool,
-    deleted: bool,
-    tags: Vec<RemoteTag>,
+    pub item_type: String,
+    pub tags: Vec<String>,
+    pub favorite: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,33 +63,156 @@ struct ItemsResponse {
     items: Vec<RemoteItem>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct DeltaResponse {
+    items: Vec<RemoteItem>,
+    #[serde(default)]
+    purged: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ItemResponse {
     item: RemoteItem,
 }
 
-fn remotetolocal(remote: &RemoteItem) -> VaultItem {
+#[derive(Debug, Clone, Deserialize)]
+struct PurgeResponse {
+    purged: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FoldersResponse {
+    folders: Vec<RemoteFolder>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FolderResponse {
+    folder: RemoteFolder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DeleteFolderResponse {
+    deleted: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct MoveItemsResponse {
+    moved: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RemoteBatchResult {
+    id: Option<String>,
+    op: String,
+    ok: bool,
+    error: Option<String>,
+    item: Option<RemoteItem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BatchResponse {
+    results: Vec<RemoteBatchResult>,
+}
+
+pub struct BatchEntry {
+    pub id: Option<String>,
+    pub op: String,
+    pub ok: bool,
+    pub error: Option<String>,
+    pub item: Option<RemoteItem>,
+}
+
+fn nullable<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+pub fn seal(key: &[u8], plaintext: &[u8]) -> Result<String, super::SyncError> {
+    use base64::Engine;
+    let encrypted =
+        super::twoskd::encryptitem(plaintext, key).map_err(|_| super::SyncError::Crypto)?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(encrypted))
+}
+
+fn unseal(key: &[u8], sealed: &str) -> Result<Vec<u8>, super::SyncError> {
     use base64::Engine;
-    let data = base64::engine::general_purpose::STANDARD
-        .decode(&remote.data)
-        .unwrap_or_default();
-    VaultItem {
+    let encrypted = base64::engine::general_purpose::STANDARD
+        .decode(sealed)
+        .map_err(|_| super::SyncError::Parse)?;
+    super::twoskd::decryptitem(&encrypted, key).map_err(|_| super::SyncError::Crypto)
+}
+
+pub fn sealmeta(key: &[u8], meta: &ItemMeta) -> Result<String, super::SyncError> {
+    let json = serde_json::to_vec(meta).map_err(|_| super::SyncError::Parse)?;
+    seal(key, &json)
+}
+
+fn permanent(status: u16) -> bool {
+    (400..500).contains(&status) && status != 408 && status != 429
+}
+
+pub fn openitem(remote: &RemoteItem, key: &[u8]) -> Result<VaultItem, super::SyncError> {
+    let title =
+        String::from_utf8(unseal(key, &remote.title)?).map_err(|_| super::SyncError::Parse)?;
+    let data = unseal(key, &remote.data)?;
+    let meta = match remote.meta.as_deref() {
+        Some(sealed) => {
+            serde_json::from_slice(&unseal(key, sealed)?).map_err(|_| super::SyncError::Parse)?
+        }
+        None => ItemMeta {
+            item_type: remote.item_type.clone(),
+            tags: remote.tags.iter().map(|t| t.name.clone()).collect(),
+            favorite: remote.favorite,
+        },
+    };
+    if meta.item_type.is_empty() {
+        return Err(super::SyncError::Parse);
+    }
+    Ok(VaultItem {
         id: remote.id.clone(),
-        item_type: remote.item_type.clone(),
-        title: remote.title.clone(),
+        item_type: meta.item_type,
+        title,
         data,
         revision: remote.revision,
-        favorite: remote.favorite,
+        favorite: meta.favorite,
         deleted: remote.deleted,
-        tags: remote.tags.iter().map(|t| t.name.clone()).collect(),
-        created: 0,
-        updated: 0,
-    }
+        deleted_at: remote
+            .deleted_at
+            .as_deref()
+            .map(|t| parsetimestamp(t).ok_or(super::SyncError::Parse))
+            .transpose()?,
+        tags: meta.tags,
+        folder_id: remote.folder_id.clone(),
+        created: parsetimestamp(&remote.created_at).ok_or(super::SyncError::Parse)?,
+        updated: parsetimestamp(&remote.updated_at).ok_or(super::SyncError::Parse)?,
+    })
+}
+
+fn openname(key: &[u8], name: &str) -> Option<String> {
+    unseal(key, name)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+}
+
+pub fn openfolder(remote: &RemoteFolder, key: &[u8]) -> Result<Folder, super::SyncError> {
+    Ok(Folder {
+        id: remote.id.clone(),
+        name: openname(key, &remote.name).unwrap_or_else(|| remote.name.clone()),
+        parent_id: remote.parent_id.clone(),
+        color: remote.color.clone(),
+        icon: remote.icon.clone(),
+        order: remote.order,
+        created: parsetimestamp(&remote.created_at).ok_or(super::SyncError::Parse)?,
+        updated: parsetimestamp(&remote.updated_at).ok_or(super::SyncError::Parse)?,
+    })
 }
 
 pub struct SyncClient {
     base_url: String,
     token: Mutex<Option<String>>,
+    key: Mutex<Option<Vec<u8>>>,
 }
 
 impl SyncClient {
@@ -61,7 +220,89 @@ impl SyncClient {
         Self {
             base_url,
             token: Mutex::new(None),
+            key: Mutex::new(None),
+        }
+    }
+
+    pub fn unlock(&self, vault_key: Vec<u8>) -> Result<(), super::SyncError> {
+        if vault_key.len() != 32 {
+            return Err(super::SyncError::Crypto);
         }
+        *self.key.lock().unwrap() = Some(vault_key);
+        Ok(())
+    }
+
+    pub fn lock(&self) {
+        *self.key.lock().unwrap() = None;
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.key.lock().unwrap().is_none()
+    }
+
+    pub fn item_key(&self, id: &str) -> Result<Vec<u8>, super::SyncError> {
+        let guard = self.key.lock().unwrap();
+        let vaultkey = guard.as_ref().ok_or(super::SyncError::Locked)?;
+        super::twoskd::deriveitemkey(vaultkey, id).map_err(|_| super::SyncError::Crypto)
+    }
+
+    pub fn blind_index(&self, kind: &str, value: &str) -> Result<String, super::SyncError> {
+        let guard = self.key.lock().unwrap();
+        let vaultkey = guard.as_ref().ok_or(super::SyncError::Locked)?;
+        let indexkey =
+            super::twoskd::deriveindexkey(vaultkey).map_err(|_| super::SyncError::Crypto)?;
+        Ok(super::twoskd::blindindex(&indexkey, kind, value))
+    }
+
+    pub fn tag_indexes(&self, tags: &[String]) -> Result<Vec<String>, super::SyncError> {
+        tags.iter().map(|t| self.blind_index("tag", t)).collect()
+    }
+
+    fn open(&self, remote: &RemoteItem) -> Result<VaultItem, super::SyncError> {
+        openitem(remote, &self.item_key(&remote.id)?)
+    }
+
+    fn open_all(&self, remotes: &[RemoteItem]) -> Result<Vec<VaultItem>, super::SyncError> {
+        let items = remotes
+            .iter()
+            .map(|r| self.open(r))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (remote, item) in remotes.iter().zip(&items) {
+            if remote.meta.is_none() {
+                let _ = self.migrate_meta(item);
+            }
+        }
+        Ok(items)
+    }
+
+    fn migrate_meta(&self, item: &VaultItem) -> Result<(), super::SyncError> {
+        #[derive(Serialize)]
+        struct MigrateBody {
+            #[serde(rename = "typeIndex")]
+            type_index: String,
+            meta: String,
+            tags: Vec<String>,
+        }
+        let url = format!("{}/api/v1/vault/items/{}", self.base_url, item.id);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let key = self.item_key(&item.id)?;
+        let body = MigrateBody {
+            type_index: self.blind_index("type", &item.item_type)?,
+            meta: sealmeta(
+                &key,
+                &ItemMeta {
+                    item_type: item.item_type.clone(),
+                    tags: item.tags.clone(),
+                    favorite: item.favorite,
+                },
+            )?,
+            tags: self.tag_indexes(&item.tags)?,
+        };
+        ureq::put(&url)
+            .set("cookie", &auth)
+            .send_json(&body)
+            .map_err(|_| super::SyncError::Http)?;
+        Ok(())
     }
 
     pub fn set_token(&self, token: String) {
@@ -111,45 +352,159 @@ impl SyncClient {
                 _ => super::SyncError::Http,
             })?;
         let data: ItemsResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
-        Ok(data.items.iter().map(remotetolocal).collect())
+        self.open_all(&data.items)
+    }
+
+    pub fn fetch_all(&self) -> Result<Vec<VaultItem>, super::SyncError> {
+        let url = format!("{}/api/v1/vault/items?deleted=all", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::get(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: ItemsResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        self.open_all(&data.items)
+    }
+
+    pub fn fetch_since(
+        &self,
+        since: &str,
+    ) -> Result<(Vec<VaultItem>, Vec<String>), super::SyncError> {
+        let url = format!("{}/api/v1/vault/items", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::get(&url)
+            .query("since", since)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: DeltaResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        let items = data
+            .items
+            .iter()
+            .map(|r| self.open(r))
+            .collect::<Result<_, _>>()?;
+        Ok((items, data.purged))
+    }
+
+    pub fn fetch_trash(&self) -> Result<Vec<VaultItem>, super::SyncError> {
+        let url = format!("{}/api/v1/vault/trash", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::get(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: ItemsResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        self.open_all(&data.items)
+    }
+
+    pub fn fetch_item(&self, id: &str) -> Result<VaultItem, super::SyncError> {
+        self.open(&self.fetch_sealed(id)?)
+    }
+
+    pub fn fetch_sealed(&self, id: &str) -> Result<RemoteItem, super::SyncError> {
+        let url = format!("{}/api/v1/vault/items/{}", self.base_url, id);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::get(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: ItemResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        Ok(data.item)
+    }
+
+    pub fn events(&self) -> Result<EventStream, super::SyncError> {
+        let url = format!("{}/api/v1/vault/events", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        EventStream::connect(&url, &auth)
+    }
+
+    pub fn send_batch<T: Serialize>(&self, ops: &[T]) -> Result<Vec<BatchEntry>, super::SyncError> {
+        #[derive(Serialize)]
+        struct BatchBody<'a, T> {
+            ops: &'a [T],
+        }
+        let url = format!("{}/api/v1/vault/items/batch", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::post(&url)
+            .set("cookie", &auth)
+            .send_json(BatchBody { ops })
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: BatchResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        Ok(data
+            .results
+            .into_iter()
+            .map(|r| BatchEntry {
+                id: r.id,
+                op: r.op,
+                ok: r.ok,
+                error: r.error,
+                item: r.item,
+            })
+            .collect())
     }
 
     pub fn create_item(
         &self,
+        id: String,
         item_type: String,
         title: String,
         data: Vec<u8>,
         tags: Vec<String>,
         favorite: bool,
     ) -> Result<VaultItem, super::SyncError> {
-        use base64::Engine;
         #[derive(Serialize)]
         struct CreateBody {
-            #[serde(rename = "type")]
-            item_type: String,
+            id: String,
+            #[serde(rename = "typeIndex")]
+            type_index: String,
             title: String,
             data: String,
+            meta: String,
             tags: Vec<String>,
-            favorite: bool,
         }
         let url = format!("{}/api/v1/vault/items", self.base_url);
         let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let key = self.item_key(&id)?;
         let body = CreateBody {
-            item_type,
-            title,
-            data: base64::engine::general_purpose::STANDARD.encode(&data),
-            tags,
-            favorite,
+            type_index: self.blind_index("type", &item_type)?,
+            title: seal(&key, title.as_bytes())?,
+            data: seal(&key, &data)?,
+            tags: self.tag_indexes(&tags)?,
+            meta: sealmeta(
+                &key,
+                &ItemMeta {
+                    item_type,
+                    tags,
+                    favorite,
+                },
+            )?,
+            id,
         };
         let resp = ureq::post(&url)
             .set("cookie", &auth)
             .send_json(&body)
             .map_err(|e| match e {
                 ureq::Error::Status(401, _) => super::SyncError::Auth,
+                ureq::Error::Status(code, _) if permanent(code) => super::SyncError::Rejected,
                 _ => super::SyncError::Http,
             })?;
         let data: ItemResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
-        Ok(remotetolocal(&data.item))
+        openitem(&data.item, &key)
     }
 
     pub fn update_item(
@@ -160,7 +515,6 @@ impl SyncClient {
         tags: Option<Vec<String>>,
         favorite: Option<bool>,
     ) -> Result<VaultItem, super::SyncError> {
-        use base64::Engine;
         #[derive(Serialize)]
         struct UpdateBody {
             #[serde(skip_serializing_if = "Option::is_none")]
@@ -168,27 +522,40 @@ impl SyncClient {
             #[serde(skip_serializing_if = "Option::is_none")]
             data: Option<String>,
             #[serde(skip_serializing_if = "Option::is_none")]
-            tags: Option<Vec<String>>,
+            meta: Option<String>,
             #[serde(skip_serializing_if = "Option::is_none")]
-            favorite: Option<bool>,
+            tags: Option<Vec<String>>,
         }
         let url = format!("{}/api/v1/vault/items/{}", self.base_url, id);
         let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let key = self.item_key(&id)?;
+        let meta = if tags.is_some() || favorite.is_some() {
+            let current = self.fetch_item(&id)?;
+            let meta = ItemMeta {
+                item_type: current.item_type,
+                tags: tags.clone().unwrap_or(current.tags),
+                favorite: favorite.unwrap_or(current.favorite),
+            };
+            Some(sealmeta(&key, &meta)?)
+        } else {
+            None
+        };
         let body = UpdateBody {
-            title,
-            data: data.map(|d| base64::engine::general_purpose::STANDARD.encode(&d)),
-            tags,
-            favorite,
+            title: title.map(|t| seal(&key, t.as_bytes())).transpose()?,
+            data: data.map(|d| seal(&key, &d)).transpose()?,
+            meta,
+            tags: tags.as_deref().map(|t| self.tag_indexes(t)).transpose()?,
         };
         let resp = ureq::put(&url)
             .set("cookie", &auth)
             .send_json(&body)
             .map_err(|e| match e {
                 ureq::Error::Status(401, _) => super::SyncError::Auth,
+                ureq::Error::Status(code, _) if permanent(code) => super::SyncError::Rejected,
                 _ => super::SyncError::Http,
             })?;
         let data: ItemResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
-        Ok(remotetolocal(&data.item))
+        openitem(&data.item, &key)
     }
 
     pub fn delete_item(&self, id: String) -> Result<(), super::SyncError> {
@@ -199,8 +566,303 @@ impl SyncClient {
             .call()
             .map_err(|e| match e {
                 ureq::Error::Status(401, _) => super::SyncError::Auth,
+                ureq::Error::Status(code, _) if permanent(code) => super::SyncError::Rejected,
                 _ => super::SyncError::Http,
             })?;
         Ok(())
     }
+
+    pub fn restore_item(&self, id: String) -> Result<VaultItem, super::SyncError> {
+        #[derive(Serialize)]
+        struct RestoreBody {
+            deleted: bool,
+        }
+        let url = format!("{}/api/v1/vault/items/{}", self.base_url, id);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::patch(&url)
+            .set("cookie", &auth)
+            .send_json(RestoreBody { deleted: false })
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: ItemResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        self.open(&data.item)
+    }
+
+    pub fn purge_item(&self, id: String) -> Result<(), super::SyncError> {
+        let url = format!("{}/api/v1/vault/items/{}?permanent=true", self.base_url, id);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        ureq::delete(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        Ok(())
+    }
+
+    pub fn empty_trash(&self) -> Result<Vec<String>, super::SyncError> {
+        let url = format!("{}/api/v1/vault/trash", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::delete(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: PurgeResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        Ok(data.purged)
+    }
+
+    pub fn fetch_folders(&self) -> Result<Vec<Folder>, super::SyncError> {
+        let url = format!("{}/api/v1/folders", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::get(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: FoldersResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        let mut folders = Vec::with_capacity(data.folders.len());
+        for remote in &data.folders {
+            let key = self.item_key(&remote.id)?;
+            let folder = openfolder(remote, &key)?;
+            if openname(&key, &remote.name).is_some() {
+                folders.push(folder);
+                continue;
+            }
+            let migrated = self.update_folder(
+                remote.id.clone(),
+                Some(remote.name.clone()),
+                None,
+                None,
+                None,
+            );
+            folders.push(migrated.unwrap_or(folder));
+        }
+        Ok(folders)
+    }
+
+    pub fn create_folder(
+        &self,
+        id: String,
+        name: String,
+        parent_id: Option<String>,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<Folder, super::SyncError> {
+        #[derive(Serialize)]
+        struct CreateBody {
+            id: String,
+            name: String,
+            #[serde(rename = "parentId")]
+            parent_id: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            color: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            icon: Option<String>,
+        }
+        let url = format!("{}/api/v1/folders", self.base_url);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let key = self.item_key(&id)?;
+        let body = CreateBody {
+            name: seal(&key, name.as_bytes())?,
+            id,
+            parent_id,
+            color,
+            icon,
+        };
+        let resp = ureq::post(&url)
+            .set("cookie", &auth)
+            .send_json(&body)
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: FolderResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        openfolder(&data.folder, &key)
+    }
+
+    fn patch_folder<T: Serialize>(&self, id: &str, body: &T) -> Result<Folder, super::SyncError> {
+        let url = format!("{}/api/v1/folders/{}", self.base_url, id);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let key = self.item_key(id)?;
+        let resp = ureq::patch(&url)
+            .set("cookie", &auth)
+            .send_json(body)
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: FolderResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        openfolder(&data.folder, &key)
+    }
+
+    pub fn update_folder(
+        &self,
+        id: String,
+        name: Option<String>,
+        color: Option<String>,
+        icon: Option<String>,
+        order: Option<i32>,
+    ) -> Result<Folder, super::SyncError> {
+        #[derive(Serialize)]
+        struct UpdateBody {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            color: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            icon: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            order: Option<i32>,
+        }
+        let key = self.item_key(&id)?;
+        let body = UpdateBody {
+            name: name.map(|n| seal(&key, n.as_bytes())).transpose()?,
+            color,
+            icon,
+            order,
+        };
+        self.patch_folder(&id, &body)
+    }
+
+    pub fn move_folder(
+        &self,
+        id: String,
+        parent_id: Option<String>,
+    ) -> Result<Folder, super::SyncError> {
+        #[derive(Serialize)]
+        struct MoveBody {
+            #[serde(rename = "parentId")]
+            parent_id: Option<String>,
+        }
+        self.patch_folder(&id, &MoveBody { parent_id })
+    }
+
+    pub fn delete_folder(&self, id: String) -> Result<Vec<String>, super::SyncError> {
+        let url = format!("{}/api/v1/folders/{}", self.base_url, id);
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::delete(&url)
+            .set("cookie", &auth)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: DeleteFolderResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        Ok(data.deleted)
+    }
+
+    pub fn move_items(
+        &self,
+        folder_id: Option<String>,
+        item_ids: Vec<String>,
+    ) -> Result<u32, super::SyncError> {
+        #[derive(Serialize)]
+        struct MoveBody {
+            #[serde(rename = "itemIds")]
+            item_ids: Vec<String>,
+        }
+        let url = format!(
+            "{}/api/v1/folders/{}/items",
+            self.base_url,
+            folder_id.as_deref().unwrap_or("null")
+        );
+        let auth = self.auth_header().ok_or(super::SyncError::Auth)?;
+        let resp = ureq::post(&url)
+            .set("cookie", &auth)
+            .send_json(MoveBody { item_ids })
+            .map_err(|e| match e {
+                ureq::Error::Status(401, _) => super::SyncError::Auth,
+                _ => super::SyncError::Http,
+            })?;
+        let data: MoveItemsResponse = resp.into_json().map_err(|_| super::SyncError::Parse)?;
+        Ok(data.moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 32] = [9u8; 32];
+
+    const ITEMS_RESPONSE: &str = r#"{"items":[
+        {"id":"cm8a1login","vaultId":"cm8vault","folderId":"cm8folder","type":null,"typeIndex":"5d1e","title":"BxhMQL+dY/clJ3oJ+StKzoUw6bp0VZuq2+usCFvnTL5swQ==","data":"lBiIKqB5sKCubdENC6rMvFRYAXtWiWei36b8e7/C1fCRD8Uzf1hxt2tWvz67bU6WFSvvkeGZVfuY4LNJoNBzz+RjR7SVZms=","meta":"dJYZfLVvntmy/awMyuQFBOQB0W2r9P/WvclIBTG1CX+zkc1SAOHJKmJwhAVnozcATa61S3BhOWZXfS8wE2nBnydXjhVkpYJOJr7vlA==","revision":3,"favorite":false,"deleted":false,"createdAt":"2024-02-29T23:59:59.000Z","updatedAt":"2025-03-14T09:26:53.589Z","tags":[{"id":"cm8tag","name":"8c41","itemId":"cm8a1login"}]},
+        {"id":"cm8a2note","vaultId":"cm8vault","folderId":null,"type":"note","typeIndex":null,"title":"vBtc9P2iUtNZtp9thzdptamwTniJdCWcCbDxIPHdDDM=","data":"jJzYzm6aVJTwMGywm9oDWYpNtxubgRSR5fS9c3uGxBUKrlGzYk/DHU+xhybRn9noudvL0xKOIA==","meta":null,"revision":1,"favorite":true,"deleted":false,"createdAt":"2025-06-01T18:00:00.000Z","updatedAt":"2025-06-01T18:00:00.000Z","tags":[{"id":"cm8tag2","name":"home","itemId":"cm8a2note"}]}
+    ]}"#;
+
+    #[test]
+    fn test_recorded_items_response() {
+        let response: ItemsResponse = serde_json::from_str(ITEMS_RESPONSE).unwrap();
+        let items: Vec<VaultItem> = response
+            .items
+            .iter()
+            .map(|r| openitem(r, &KEY).unwrap())
+            .collect();
+
+        assert_eq!(items[0].item_type, "login");
+        assert_eq!(items[0].title, "github");
+        assert_eq!(items[0].tags, vec!["work".to_string()]);
+        assert!(items[0].favorite);
+        assert_eq!(items[0].folder_id.as_deref(), Some("cm8folder"));
+        assert_eq!(items[0].created, 1709251199);
+        assert_eq!(items[0].updated, 1741944413);
+
+        assert_eq!(items[1].item_type, "note");
+        assert_eq!(items[1].data, br#"{"content":"guest / guest"}"#);
+        assert_eq!(items[1].tags, vec!["home".to_string()]);
+        assert_eq!(items[1].folder_id, None);
+        assert_eq!(items[1].created, 1748800800);
+
+        let encoded = serde_json::to_string(&response).unwrap();
+        let again: ItemsResponse = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(again.items[0].created_at, "2024-02-29T23:59:59.000Z");
+        assert_eq!(again.items[0].updated_at, "2025-03-14T09:26:53.589Z");
+        assert_eq!(again.items[0].folder_id.as_deref(), Some("cm8folder"));
+        assert_eq!(openitem(&again.items[0], &KEY).unwrap().title, "github");
+
+        assert!(matches!(
+            openitem(&response.items[0], &[1u8; 32]),
+            Err(crate::SyncError::Crypto)
+        ));
+        let mut broken = response.items[1].clone();
+        broken.data = "not base64!".into();
+        assert!(matches!(
+            openitem(&broken, &KEY),
+            Err(crate::SyncError::Parse)
+        ));
+        let mut broken = response.items[1].clone();
+        broken.updated_at = "yesterday".into();
+        assert!(matches!(
+            openitem(&broken, &KEY),
+            Err(crate::SyncError::Parse)
+        ));
+    }
+
+    #[test]
+    fn test_openfolder_keeps_plaintext_name() {
+        let mut remote = RemoteFolder {
+            id: "cm8folder".into(),
+            name: seal(&KEY, b"work").unwrap(),
+            parent_id: None,
+            color: "default".into(),
+            icon: "folder".into(),
+            order: 0,
+            created_at: "2025-06-01T18:00:00.000Z".into(),
+            updated_at: "2025-06-01T18:00:00.000Z".into(),
+        };
+        assert_eq!(openfolder(&remote, &KEY).unwrap().name, "work");
+        assert_eq!(openname(&KEY, &remote.name).as_deref(), Some("work"));
+
+        remote.name = "personal".into();
+        assert_eq!(openfolder(&remote, &KEY).unwrap().name, "personal");
+        assert_eq!(openname(&KEY, &remote.name), None);
+    }
 }
@@ -1,9 +1,12 @@
+use aes_gcm::{Aes256Gcm, Nonce};
 use argon2::{Algorithm, Argon2, Params, Version};
 use chacha20poly1305::{
     aead::{Aead, KeyInit},
     XChaCha20Poly1305, XNonce,
 };
+use hmac::{Hmac, Mac};
 use rand::Rng;
+use sha2::Sha256;
 
 const ARGON_MEMORY: u32 = 65536;
 const ARGON_ITERATIONS: u32 = 3;
@@ -11,6 +14,8 @@ const ARGON_PARALLELISM: u32 = 4;
 const ARGON_OUTPUT_LEN: usize = 32;
 const SECRET_KEY_BYTES: usize = 20;
 const NONCE_LEN: usize = 24;
+const ITEM_NONCE_LEN: usize = 12;
+const INDEX_CONTEXT: &[u8] = b"noro-blind-index-v1";
 const BASE32_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
 
 fn base32encode(bytes: &[u8]) -> String {
@@ -137,6 +142,52 @@ pub fn deriveitemkey(vaultkey: &[u8], itemid: &str) -> Result<Vec<u8>, super::Cr
     Ok(itemkey)
 }
 
+pub fn encryptitem(data: &[u8], itemkey: &[u8]) -> Result<Vec<u8>, super::CryptoError> {
+    let cipher = Aes256Gcm::new_from_slice(itemkey).map_err(|_| super::CryptoError::InvalidKeyLength)?;
+    let mut rng = rand::thread_rng();
+    let nonce_bytes: [u8; ITEM_NONCE_LEN] = rng.gen();
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, data)
+        .map_err(|_| super::CryptoError::Encryption)?;
+    let mut result = Vec::with_capacity(ITEM_NONCE_LEN + ciphertext.len());
+    result.extend_from_slice(&nonce_bytes);
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+pub fn decryptitem(encrypted: &[u8], itemkey: &[u8]) -> Result<Vec<u8>, super::CryptoError> {
+    if encrypted.len() < ITEM_NONCE_LEN + 16 {
+        return Err(super::CryptoError::Decryption);
+    }
+    let cipher = Aes256Gcm::new_from_slice(itemkey).map_err(|_| super::CryptoError::InvalidKeyLength)?;
+    let nonce = Nonce::from_slice(&encrypted[..ITEM_NONCE_LEN]);
+    cipher
+        .decrypt(nonce, &encrypted[ITEM_NONCE_LEN..])
+        .map_err(|_| super::CryptoError::Decryption)
+}
+
+fn hmacsha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("hmac accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+pub fn deriveindexkey(vaultkey: &[u8]) -> Result<[u8; 32], super::CryptoError> {
+    if vaultkey.len() != 32 {
+        return Err(super::CryptoError::InvalidKeyLength);
+    }
+    Ok(hmacsha256(vaultkey, &[INDEX_CONTEXT]))
+}
+
+pub fn blindindex(indexkey: &[u8; 32], kind: &str, value: &str) -> String {
+    let normalized = value.trim().to_lowercase();
+    let digest = hmacsha256(indexkey, &[kind.as_bytes(), &[0], normalized.as_bytes()]);
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
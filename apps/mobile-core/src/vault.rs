@@ -1,6 +1,17 @@
+use noro_common::otp::{self, OtpConfig};
+use noro_common::otpimport::{self, OtpImportPreview};
+use noro_common::search::{SearchDoc, SearchIndex};
+use noro_common::time::now;
+use noro_common::urlmatch::{self, Login};
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::audit::{self, AuditItem, AuditReport};
+use crate::backup::{Backup, BackupFolder, BackupItem};
+use crate::folders::{self, Folder};
+
+pub const TRASH_RETENTION_DAYS: u64 = 30;
+
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VaultItem {
@@ -11,17 +22,18 @@ pub struct VaultItem {
     pub revision: i32,
     pub favorite: bool,
     pub deleted: bool,
+    #[serde(default)]
+    pub deleted_at: Option<u64>,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub folder_id: Option<String>,
     pub created: u64,
     pub updated: u64,
 }
 
 impl VaultItem {
     pub fn new(item_type: String, title: String, data: Vec<u8>, tags: Vec<String>, favorite: bool) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = now();
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             item_type,
@@ -30,7 +42,9 @@ impl VaultItem {
             revision: 1,
             favorite,
             deleted: false,
+            deleted_at: None,
             tags,
+            folder_id: None,
             created: now,
             updated: now,
         }
@@ -40,17 +54,48 @@ impl VaultItem {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct VaultData {
     pub items: Vec<VaultItem>,
+    #[serde(default)]
+    pub folders: Vec<Folder>,
     pub updated: u64,
 }
 
 pub struct Vault {
     data: Mutex<VaultData>,
+    index: Mutex<SearchIndex>,
+}
+
+fn searchdoc(item: &VaultItem) -> SearchDoc {
+    SearchDoc::new(
+        &item.id,
+        &item.item_type,
+        &item.title,
+        &item.data,
+        &item.tags,
+        item.favorite,
+    )
 }
 
 impl Vault {
     pub fn new() -> Self {
         Self {
             data: Mutex::new(VaultData::default()),
+            index: Mutex::new(SearchIndex::default()),
+        }
+    }
+
+    fn reindex(&self, item: &VaultItem) {
+        let mut index = self.index.lock().unwrap();
+        if item.deleted {
+            index.remove(&item.id);
+        } else {
+            index.upsert(searchdoc(item));
+        }
+    }
+
+    fn unindex(&self, ids: &[String]) {
+        let mut index = self.index.lock().unwrap();
+        for id in ids {
+            index.remove(id);
         }
     }
 
@@ -60,10 +105,18 @@ impl Vault {
         let data: VaultData =
             serde_json::from_slice(&decrypted).map_err(|_| super::VaultError::Serialization)?;
         let mut guard = self.data.lock().unwrap();
+        *self.index.lock().unwrap() =
+            SearchIndex::build(data.items.iter().filter(|i| !i.deleted).map(searchdoc));
         *guard = data;
         Ok(())
     }
 
+    pub fn lock(&self) {
+        let mut guard = self.data.lock().unwrap();
+        *guard = VaultData::default();
+        *self.index.lock().unwrap() = SearchIndex::default();
+    }
+
     pub fn save(&self, key: Vec<u8>) -> Result<Vec<u8>, super::VaultError> {
         let guard = self.data.lock().unwrap();
         let json = serde_json::to_vec(&*guard).map_err(|_| super::VaultError::Serialization)?;
@@ -83,10 +136,8 @@ impl Vault {
         let item = VaultItem::new(item_type, title, data, tags, favorite);
         let mut guard = self.data.lock().unwrap();
         guard.items.push(item.clone());
-        guard.updated = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        guard.updated = now();
+        self.reindex(&item);
         Ok(item)
     }
 
@@ -124,11 +175,9 @@ impl Vault {
             item.favorite = f;
         }
         item.revision += 1;
-        item.updated = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        item.updated = now();
         guard.updated = guard.items[idx].updated;
+        self.reindex(&guard.items[idx]);
         Ok(guard.items[idx].clone())
     }
 
@@ -139,28 +188,436 @@ impl Vault {
             .iter()
             .position(|i| i.id == id && !i.deleted)
             .ok_or(super::VaultError::NotFound)?;
+        let now = now();
         guard.items[idx].deleted = true;
+        guard.items[idx].deleted_at = Some(now);
         guard.items[idx].revision += 1;
-        guard.items[idx].updated = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        guard.updated = guard.items[idx].updated;
+        guard.items[idx].updated = now;
+        guard.updated = now;
+        self.unindex(std::slice::from_ref(&id));
+        Ok(())
+    }
+
+    pub fn list_trash(&self) -> Vec<VaultItem> {
+        let guard = self.data.lock().unwrap();
+        let mut trash: Vec<VaultItem> = guard.items.iter().filter(|i| i.deleted).cloned().collect();
+        trash.sort_by_key(|i| std::cmp::Reverse(i.deleted_at));
+        trash
+    }
+
+    pub fn restore_item(&self, id: String) -> Result<VaultItem, super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        let idx = guard
+            .items
+            .iter()
+            .position(|i| i.id == id && i.deleted)
+            .ok_or(super::VaultError::NotFound)?;
+        let now = now();
+        let item = &mut guard.items[idx];
+        item.deleted = false;
+        item.deleted_at = None;
+        item.revision += 1;
+        item.updated = now;
+        guard.updated = now;
+        self.reindex(&guard.items[idx]);
+        Ok(guard.items[idx].clone())
+    }
+
+    pub fn purge_item(&self, id: String) -> Result<(), super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        let idx = guard
+            .items
+            .iter()
+            .position(|i| i.id == id && i.deleted)
+            .ok_or(super::VaultError::NotFound)?;
+        guard.items.remove(idx);
+        guard.updated = now();
         Ok(())
     }
 
+    pub fn empty_trash(&self) -> Vec<String> {
+        self.purge(|_| true)
+    }
+
+    pub fn purge_expired(&self) -> Vec<String> {
+        let cutoff = now().saturating_sub(TRASH_RETENTION_DAYS * 24 * 60 * 60);
+        self.purge(|i| i.deleted_at.unwrap_or(i.updated) < cutoff)
+    }
+
+    fn purge<F: Fn(&VaultItem) -> bool>(&self, expired: F) -> Vec<String> {
+        let mut guard = self.data.lock().unwrap();
+        let purged: Vec<String> = guard
+            .items
+            .iter()
+            .filter(|i| i.deleted && expired(i))
+            .map(|i| i.id.clone())
+            .collect();
+        if !purged.is_empty() {
+            guard.items.retain(|i| !purged.contains(&i.id));
+            guard.updated = now();
+        }
+        purged
+    }
+
+    pub fn apply_remote(&self, changed: Vec<VaultItem>, removed: Vec<String>) {
+        let mut guard = self.data.lock().unwrap();
+        for item in changed {
+            let existing = guard.items.iter().position(|i| i.id == item.id);
+            if existing.is_some_and(|idx| guard.items[idx].revision > item.revision) {
+                continue;
+            }
+            self.reindex(&item);
+            match existing {
+                Some(idx) => guard.items[idx] = item,
+                None => guard.items.push(item),
+            }
+        }
+        guard.items.retain(|i| !removed.contains(&i.id));
+        self.unindex(&removed);
+        guard.updated = now();
+    }
+
     pub fn list_items(&self) -> Vec<VaultItem> {
         let guard = self.data.lock().unwrap();
         guard.items.iter().filter(|i| !i.deleted).cloned().collect()
     }
 
     pub fn search_items(&self, query: String) -> Vec<VaultItem> {
+        self.search(&query, usize::MAX)
+    }
+
+    pub fn search(&self, query: &str, limit: usize) -> Vec<VaultItem> {
+        let guard = self.data.lock().unwrap();
+        let hits = self.index.lock().unwrap().search(query, limit);
+        hits.into_iter()
+            .filter_map(|hit| guard.items.iter().find(|i| i.id == hit.id).cloned())
+            .collect()
+    }
+
+    pub fn match_url(&self, page_url: &str) -> Vec<VaultItem> {
+        let guard = self.data.lock().unwrap();
+        let logins: Vec<Login> = guard
+            .items
+            .iter()
+            .filter(|i| !i.deleted)
+            .map(|i| Login {
+                id: i.id.clone(),
+                urls: urlmatch::loginurls(&i.data),
+            })
+            .filter(|l| !l.urls.is_empty())
+            .collect();
+        urlmatch::matchlogins(page_url, &logins)
+            .into_iter()
+            .filter_map(|m| guard.items.iter().find(|i| i.id == m.id).cloned())
+            .collect()
+    }
+
+    pub fn audit(&self) -> AuditReport {
+        let guard = self.data.lock().unwrap();
+        audit::audit(
+            guard.items.iter().filter(|i| !i.deleted).map(|i| AuditItem {
+                id: &i.id,
+                item_type: &i.item_type,
+                title: &i.title,
+                data: &i.data,
+                updated: i.updated,
+            }),
+            now(),
+        )
+    }
+
+    pub fn otp_config(&self, id: &str) -> Option<Result<OtpConfig, otp::OtpError>> {
+        let guard = self.data.lock().unwrap();
+        let item = guard.items.iter().find(|i| i.id == id)?;
+        otp::fromdata(&item.data)
+    }
+
+    fn otp_configs(&self) -> Vec<(String, OtpConfig)> {
+        let guard = self.data.lock().unwrap();
+        guard
+            .items
+            .iter()
+            .filter(|i| !i.deleted)
+            .filter_map(|i| Some((i.id.clone(), otp::fromdata(&i.data)?.ok()?)))
+            .collect()
+    }
+
+    pub fn preview_otp_import(&self, text: &str) -> OtpImportPreview {
+        otpimport::preview(text, &self.otp_configs())
+    }
+
+    pub fn import_otp(
+        &self,
+        text: &str,
+        skip_duplicates: bool,
+    ) -> Result<(Vec<VaultItem>, OtpImportPreview), super::VaultError> {
+        let preview = self.preview_otp_import(text);
+        let mut created = Vec::new();
+        for candidate in &preview.candidates {
+            if skip_duplicates && candidate.duplicate_of.is_some() {
+                continue;
+            }
+            created.push(self.create_item(
+                "otp".into(),
+                candidate.title.clone(),
+                candidate.data.clone().into_bytes(),
+                Vec::new(),
+                false,
+            )?);
+        }
+        Ok((created, preview))
+    }
+
+    pub fn backup(&self) -> Backup {
+        let guard = self.data.lock().unwrap();
+        Backup {
+            items: guard
+                .items
+                .iter()
+                .filter(|i| !i.deleted)
+                .map(|i| BackupItem {
+                    id: i.id.clone(),
+                    item_type: i.item_type.clone(),
+                    title: i.title.clone(),
+                    data: String::from_utf8_lossy(&i.data).into_owned(),
+                    tags: i.tags.clone(),
+                    favorite: i.favorite,
+                    folder_id: i.folder_id.clone(),
+                    created: i.created,
+                    updated: i.updated,
+                })
+                .collect(),
+            folders: guard.folders.iter().map(BackupFolder::from).collect(),
+            attachments: Vec::new(),
+        }
+    }
+
+    pub fn restore(&self, backup: Backup) -> (u32, u32, u32) {
+        let mut guard = self.data.lock().unwrap();
+        for folder in backup.folders {
+            match guard.folders.iter().position(|f| f.id == folder.id) {
+                Some(idx) if guard.folders[idx].updated < folder.updated => {
+                    guard.folders[idx] = folder.into()
+                }
+                Some(_) => {}
+                None => guard.folders.push(folder.into()),
+            }
+        }
+        let (mut created, mut updated, mut unchanged) = (0, 0, 0);
+        for item in backup.items {
+            let existing = guard.items.iter().position(|i| i.id == item.id);
+            let revision = match existing {
+                Some(idx) if guard.items[idx].updated >= item.updated && !guard.items[idx].deleted => {
+                    unchanged += 1;
+                    continue;
+                }
+                Some(idx) => guard.items[idx].revision + 1,
+                None => 1,
+            };
+            let restored = VaultItem {
+                id: item.id,
+                item_type: item.item_type,
+                title: item.title,
+                data: item.data.into_bytes(),
+                revision,
+                favorite: item.favorite,
+                deleted: false,
+                deleted_at: None,
+                tags: item.tags,
+                folder_id: item.folder_id,
+                created: item.created,
+                updated: item.updated,
+            };
+            self.reindex(&restored);
+            match existing {
+                Some(idx) => {
+                    guard.items[idx] = restored;
+                    updated += 1;
+                }
+                None => {
+                    guard.items.push(restored);
+                    created += 1;
+                }
+            }
+        }
+        guard.updated = now();
+        (created, updated, unchanged)
+    }
+
+    pub fn create_folder(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: String,
+        icon: String,
+    ) -> Result<Folder, super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        if let Some(parent) = &parent_id {
+            if !guard.folders.iter().any(|f| &f.id == parent) {
+                return Err(super::VaultError::NotFound);
+            }
+        }
+        let order = folders::children(&guard.folders, parent_id.as_deref())
+            .iter()
+            .map(|f| f.order + 1)
+            .max()
+            .unwrap_or(0);
+        let now = now();
+        let folder = Folder {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            parent_id,
+            color,
+            icon,
+            order,
+            created: now,
+            updated: now,
+        };
+        guard.folders.push(folder.clone());
+        guard.updated = now;
+        Ok(folder)
+    }
+
+    pub fn update_folder(
+        &self,
+        id: String,
+        name: Option<String>,
+        color: Option<String>,
+        icon: Option<String>,
+        order: Option<i32>,
+    ) -> Result<Folder, super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        let idx = guard
+            .folders
+            .iter()
+            .position(|f| f.id == id)
+            .ok_or(super::VaultError::NotFound)?;
+        let folder = &mut guard.folders[idx];
+        if let Some(n) = name {
+            folder.name = n;
+        }
+        if let Some(c) = color {
+            folder.color = c;
+        }
+        if let Some(i) = icon {
+            folder.icon = i;
+        }
+        if let Some(o) = order {
+            folder.order = o;
+        }
+        folder.updated = now();
+        guard.updated = guard.folders[idx].updated;
+        Ok(guard.folders[idx].clone())
+    }
+
+    pub fn move_folder(
+        &self,
+        id: String,
+        parent_id: Option<String>,
+    ) -> Result<Folder, super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        let idx = guard
+            .folders
+            .iter()
+            .position(|f| f.id == id)
+            .ok_or(super::VaultError::NotFound)?;
+        if let Some(parent) = &parent_id {
+            if !guard.folders.iter().any(|f| &f.id == parent) {
+                return Err(super::VaultError::NotFound);
+            }
+        }
+        if folders::createscycle(&guard.folders, &id, parent_id.as_deref()) {
+            return Err(super::VaultError::Cycle);
+        }
+        guard.folders[idx].parent_id = parent_id;
+        guard.folders[idx].updated = now();
+        guard.updated = guard.folders[idx].updated;
+        Ok(guard.folders[idx].clone())
+    }
+
+    pub fn delete_folder(&self, id: String) -> Result<Vec<String>, super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        let parent = guard
+            .folders
+            .iter()
+            .find(|f| f.id == id)
+            .map(|f| f.parent_id.clone())
+            .ok_or(super::VaultError::NotFound)?;
+        let mut deleted: Vec<String> = folders::descendants(&guard.folders, &id)
+            .into_iter()
+            .map(|f| f.id.clone())
+            .collect();
+        deleted.insert(0, id);
+        let now = now();
+        guard.folders.retain(|f| !deleted.contains(&f.id));
+        for item in &mut guard.items {
+            if item.folder_id.as_ref().is_some_and(|f| deleted.contains(f)) {
+                item.folder_id = parent.clone();
+                item.revision += 1;
+                item.updated = now;
+            }
+        }
+        guard.updated = now;
+        Ok(deleted)
+    }
+
+    pub fn move_item(&self, id: String, folder_id: Option<String>) -> Result<VaultItem, super::VaultError> {
+        let mut guard = self.data.lock().unwrap();
+        if let Some(folder) = &folder_id {
+            if !guard.folders.iter().any(|f| &f.id == folder) {
+                return Err(super::VaultError::NotFound);
+            }
+        }
+        let idx = guard
+            .items
+            .iter()
+            .position(|i| i.id == id && !i.deleted)
+            .ok_or(super::VaultError::NotFound)?;
+        let item = &mut guard.items[idx];
+        item.folder_id = folder_id;
+        item.revision += 1;
+        item.updated = now();
+        guard.updated = guard.items[idx].updated;
+        Ok(guard.items[idx].clone())
+    }
+
+    pub fn set_folders(&self, folders: Vec<Folder>) {
+        let mut guard = self.data.lock().unwrap();
+        guard.folders = folders;
+    }
+
+    pub fn list_folders(&self) -> Vec<Folder> {
+        let guard = self.data.lock().unwrap();
+        guard.folders.clone()
+    }
+
+    pub fn folder_children(&self, parent_id: Option<String>) -> Vec<Folder> {
+        let guard = self.data.lock().unwrap();
+        folders::children(&guard.folders, parent_id.as_deref())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    pub fn folder_path(&self, id: String) -> Vec<Folder> {
+        let guard = self.data.lock().unwrap();
+        folders::path(&guard.folders, &id).into_iter().cloned().collect()
+    }
+
+    pub fn folder_descendants(&self, id: String) -> Vec<Folder> {
+        let guard = self.data.lock().unwrap();
+        folders::descendants(&guard.folders, &id)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    pub fn folder_items(&self, folder_id: Option<String>) -> Vec<VaultItem> {
         let guard = self.data.lock().unwrap();
-        let q = query.to_lowercase();
         guard
             .items
             .iter()
-            .filter(|i| !i.deleted && i.title.to_lowercase().contains(&q))
+            .filter(|i| !i.deleted && i.folder_id == folder_id)
             .cloned()
             .collect()
     }
@@ -171,3 +628,37 @@ impl Default for Vault {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trash_lifecycle() {
+        let vault = Vault::new();
+        let old = vault
+            .create_item("note".into(), "old".into(), vec![], vec![], false)
+            .unwrap();
+        let recent = vault
+            .create_item("note".into(), "recent".into(), vec![], vec![], false)
+            .unwrap();
+        vault.delete_item(old.id.clone()).unwrap();
+        vault.delete_item(recent.id.clone()).unwrap();
+
+        let mut expired = vault
+            .list_trash()
+            .into_iter()
+            .find(|i| i.id == old.id)
+            .unwrap();
+        expired.deleted_at = Some(now() - (TRASH_RETENTION_DAYS + 1) * 24 * 60 * 60);
+        vault.apply_remote(vec![expired], vec![]);
+
+        assert_eq!(vault.list_trash().len(), 2);
+        assert_eq!(vault.purge_expired(), vec![old.id.clone()]);
+
+        let restored = vault.restore_item(recent.id.clone()).unwrap();
+        assert!(!restored.deleted && restored.deleted_at.is_none());
+        assert!(vault.list_trash().is_empty());
+        assert_eq!(vault.list_items().len(), 1);
+    }
+}
@@ -335,3 +335,42 @@ describe("secrets", () => {
 		expect(getres.status).toBe(404);
 	});
 });
+
+describe("item validation", () => {
+	test("create requires title, data and type or meta", async () => {
+		const { validateitembody } = await import("@/lib/validate");
+
+		expect(validateitembody({ title: "t", data: "d", meta: "m" }, true).valid).toBe(true);
+		expect(validateitembody({ title: "t", data: "d", type: "note" }, true).valid).toBe(true);
+		expect(validateitembody({ title: "t", data: "d" }, true).valid).toBe(false);
+		expect(validateitembody({ data: "d", meta: "m" }, true).valid).toBe(false);
+		expect(validateitembody(null, true).valid).toBe(false);
+	});
+
+	test("rejects malformed fields without a type", async () => {
+		const { validateitembody } = await import("@/lib/validate");
+		const base = { title: "t", data: "d", meta: "m" };
+
+		expect(validateitembody({ ...base, meta: 1 }, true).valid).toBe(false);
+		expect(validateitembody({ ...base, typeIndex: {} }, true).valid).toBe(false);
+		expect(validateitembody({ ...base, tags: "work" }, true).valid).toBe(false);
+		expect(validateitembody({ ...base, tags: ["work", 2] }, true).valid).toBe(false);
+		expect(validateitembody({ ...base, favorite: "yes" }, true).valid).toBe(false);
+		expect(validateitembody({ ...base, type: "unknown" }, true).valid).toBe(false);
+		expect(validateitembody({ title: 5 }, false).valid).toBe(false);
+		expect(validateitembody({ meta: "m", tags: [] }, false).valid).toBe(true);
+	});
+
+	test("client ids must be well formed", async () => {
+		const { validateitembody, isvaliditemid } = await import("@/lib/validate");
+		const base = { title: "t", data: "d", meta: "m" };
+
+		expect(isvaliditemid("cm8a1login")).toBe(true);
+		expect(isvaliditemid(crypto.randomUUID())).toBe(true);
+		expect(isvaliditemid("")).toBe(false);
+		expect(isvaliditemid("../../etc")).toBe(false);
+		expect(isvaliditemid(42)).toBe(false);
+		expect(validateitembody({ ...base, id: "short" }, true).valid).toBe(false);
+		expect(validateitembody({ ...base, id: "cm8a1login" }, true).valid).toBe(true);
+	});
+});
@@ -257,9 +257,9 @@ export default function Vault() {
 		setSaving(true);
 		try {
 			if (folder === "trash") {
-				await store.remove(editingItem.id);
+				await store.purge(editingItem.id);
 			} else {
-				await store.softdelete(editingItem.id);
+				await store.remove(editingItem.id);
 			}
 			await reload();
 			setShowModal(false);
@@ -84,6 +84,13 @@ export async function remove(id: string): Promise<boolean> {
 	return res.ok;
 }
 
+export async function purge(id: string): Promise<boolean> {
+	const res = await fetch(`/api/v1/vault/items/${id}?permanent=true`, {
+		method: "DELETE",
+	});
+	return res.ok;
+}
+
 export async function softdelete(id: string): Promise<VaultItem | null> {
 	return update(id, { deleted: true });
 }
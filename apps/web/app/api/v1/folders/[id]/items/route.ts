@@ -43,9 +43,16 @@ export async function POST(
 				id: { in: itemIds },
 				vault: { userId: session.user.id },
 			},
-			data: { folderId: targetFolderId },
+			data: { folderId: targetFolderId, revision: { increment: 1 } },
 		});
 
+		if (result.count > 0) {
+			await db.vault.update({
+				where: { userId: session.user.id },
+				data: { revision: { increment: 1 } },
+			});
+		}
+
 		return NextResponse.json({ moved: result.count, folderId: targetFolderId });
 	} catch {
 		return NextResponse.json({ error: "failed to move items" }, { status: 500 });
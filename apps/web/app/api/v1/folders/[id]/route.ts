@@ -3,7 +3,7 @@ import { headers } from "next/headers";
 import { auth } from "@/lib/auth";
 import { db } from "@/lib/db";
 import type { FolderIcon } from "@/lib/types";
-import type { FolderColor } from "@/lib/folders";
+import { foldername, type FolderColor } from "@/lib/folders";
 
 interface UpdateBody {
 	name?: string;
@@ -46,6 +46,10 @@ export async function PATCH(req: Request, { params }: { params: Promise<{ id: st
 		const body: UpdateBody = await req.json();
 		const { name, parentId, color, icon, order } = body;
 
+		if (name !== undefined && (typeof name !== "string" || name.trim().length === 0)) {
+			return NextResponse.json({ error: "name required" }, { status: 400 });
+		}
+
 		if (parentId !== undefined && parentId === id) {
 			return NextResponse.json({ error: "cannot set folder as its own parent" }, { status: 400 });
 		}
@@ -59,7 +63,7 @@ export async function PATCH(req: Request, { params }: { params: Promise<{ id: st
 		}
 
 		const updates: Record<string, unknown> = {};
-		if (name !== undefined) updates.name = name.trim();
+		if (name !== undefined) updates.name = foldername(name);
 		if (parentId !== undefined) updates.parentId = parentId;
 		if (color !== undefined) updates.color = color;
 		if (icon !== undefined) updates.icon = icon;
@@ -85,11 +89,18 @@ export async function DELETE(req: Request, { params }: { params: Promise<{ id: s
 		const descendants = await collectDescendants(id, session.user.id);
 		const toDelete = [id, ...descendants];
 
-		await db.item.updateMany({
+		const moved = await db.item.updateMany({
 			where: { folderId: { in: toDelete }, vault: { userId: session.user.id } },
-			data: { folderId: folder.parentId },
+			data: { folderId: folder.parentId, revision: { increment: 1 } },
 		});
 
+		if (moved.count > 0) {
+			await db.vault.update({
+				where: { userId: session.user.id },
+				data: { revision: { increment: 1 } },
+			});
+		}
+
 		await db.folder.deleteMany({ where: { id: { in: toDelete }, userId: session.user.id } });
 		return NextResponse.json({ deleted: toDelete, movedItemsTo: folder.parentId });
 	} catch {
@@ -3,9 +3,10 @@ import { headers } from "next/headers";
 import { auth } from "@/lib/auth";
 import { db } from "@/lib/db";
 import type { FolderIcon } from "@/lib/types";
-import type { FolderColor } from "@/lib/folders";
+import { foldername, type FolderColor } from "@/lib/folders";
 
 interface CreateBody {
+	id?: string;
 	name: string;
 	parentId?: string | null;
 	color?: FolderColor;
@@ -55,12 +56,20 @@ export async function POST(req: Request) {
 
 	try {
 		const body: CreateBody = await req.json();
-		const { name, parentId, color = "default", icon = "folder" } = body;
+		const { id, name, parentId, color = "default", icon = "folder" } = body;
 
 		if (!name || typeof name !== "string" || name.trim().length === 0) {
 			return NextResponse.json({ error: "name required" }, { status: 400 });
 		}
 
+		if (id !== undefined && (typeof id !== "string" || id.length === 0)) {
+			return NextResponse.json({ error: "invalid id" }, { status: 400 });
+		}
+
+		if (id && (await db.folder.findUnique({ where: { id } }))) {
+			return NextResponse.json({ error: "folder already exists" }, { status: 409 });
+		}
+
 		if (parentId) {
 			const parent = await db.folder.findFirst({
 				where: { id: parentId, userId: session.user.id },
@@ -80,7 +89,8 @@ export async function POST(req: Request) {
 
 		const folder = await db.folder.create({
 			data: {
-				name: name.trim(),
+				...(id ? { id } : {}),
+				name: foldername(name),
 				parentId: parentId || null,
 				userId: session.user.id,
 				color,
@@ -0,0 +1,95 @@
+import { NextResponse } from "next/server";
+import { headers } from "next/headers";
+import { auth } from "@/lib/auth";
+import { db } from "@/lib/db";
+import { purgedsince } from "@/lib/trash";
+import { MAX_STREAMS, streamcount, watchvault } from "@/lib/vaultwatch";
+
+export const dynamic = "force-dynamic";
+
+const PING_INTERVAL = 25000;
+
+export async function GET(req: Request) {
+	const session = await auth.api.getSession({ headers: await headers() });
+	if (!session) {
+		return NextResponse.json({ error: "unauthorized" }, { status: 401 });
+	}
+
+	const userId = session.user.id;
+	if (streamcount(userId) >= MAX_STREAMS) {
+		return NextResponse.json({ error: "too many event streams" }, { status: 429 });
+	}
+
+	const initial = await db.vault.findUnique({ where: { userId } });
+	let revision = initial?.revision ?? 0;
+	let since = new Date();
+	let tombstones = new Date();
+
+	const encoder = new TextEncoder();
+	let stop: (() => void) | undefined;
+
+	const stream = new ReadableStream({
+		start(controller) {
+			const send = (event: string, data: unknown) => {
+				controller.enqueue(encoder.encode(`event: ${event}\ndata: ${JSON.stringify(data)}\n\n`));
+			};
+			const close = () => {
+				stop?.();
+				try {
+					controller.close();
+				} catch {}
+			};
+
+			send("ready", { revision });
+
+			let queue = Promise.resolve();
+			const unwatch = watchvault(userId, revision, (vaultId, latest) => {
+				queue = queue.then(async () => {
+					if (latest === revision) return;
+					try {
+						const items = await db.item.findMany({
+							where: { vaultId, updatedAt: { gt: since } },
+							select: { id: true, revision: true, deleted: true, deletedAt: true, updatedAt: true },
+							orderBy: { updatedAt: "asc" },
+						});
+						const purged = await purgedsince(vaultId, tombstones);
+						revision = latest;
+						if (items.length > 0) {
+							since = items[items.length - 1].updatedAt;
+						}
+						if (purged.length > 0) {
+							tombstones = purged[purged.length - 1].deletedAt;
+						}
+						send("change", { revision, items, purged: purged.map((t) => t.id) });
+					} catch {
+						close();
+					}
+				});
+			});
+			const ping = setInterval(() => {
+				try {
+					controller.enqueue(encoder.encode(": ping\n\n"));
+				} catch {
+					close();
+				}
+			}, PING_INTERVAL);
+			stop = () => {
+				unwatch();
+				clearInterval(ping);
+			};
+
+			req.signal.addEventListener("abort", close);
+		},
+		cancel() {
+			stop?.();
+		},
+	});
+
+	return new Response(stream, {
+		headers: {
+			"content-type": "text/event-stream",
+			"cache-control": "no-cache, no-transform",
+			connection: "keep-alive",
+		},
+	});
+}
@@ -2,7 +2,8 @@ import { NextResponse } from "next/server";
 import { headers } from "next/headers";
 import { auth } from "@/lib/auth";
 import { db } from "@/lib/db";
-import { validateitemdata } from "@/lib/validate";
+import { validateitemdata, validateitembody } from "@/lib/validate";
+import { purgeitems } from "@/lib/trash";
 import type { ItemType } from "@/lib/generated/prisma/enums";
 
 export async function GET(req: Request, { params }: { params: Promise<{ id: string }> }) {
@@ -47,9 +48,15 @@ export async function PUT(req: Request, { params }: { params: Promise<{ id: stri
 			return NextResponse.json({ error: "item not found" }, { status: 404 });
 		}
 
-		const { title, data, tags, favorite } = await req.json();
+		const body = await req.json();
+		const shape = validateitembody(body, false);
+		if (!shape.valid) {
+			return NextResponse.json({ error: shape.error }, { status: 400 });
+		}
+
+		const { title, data, meta, typeIndex, tags, favorite } = body;
 
-		if (data) {
+		if (data && existing.type) {
 			const validation = validateitemdata(existing.type as ItemType, data);
 			if (!validation.valid) {
 				return NextResponse.json({ error: validation.error }, { status: 400 });
@@ -65,7 +72,9 @@ export async function PUT(req: Request, { params }: { params: Promise<{ id: stri
 			data: {
 				title,
 				data,
-				favorite,
+				meta,
+				typeIndex,
+				...(meta !== undefined ? { type: null, favorite: false } : { favorite }),
 				revision: existing.revision + 1,
 				tags: tags
 					? {
@@ -106,18 +115,32 @@ export async function PATCH(req: Request, { params }: { params: Promise<{ id: st
 		}
 
 		const body = await req.json();
+		const shape = validateitembody(body, false);
+		if (!shape.valid) {
+			return NextResponse.json({ error: shape.error }, { status: 400 });
+		}
+
 		const updates: Record<string, unknown> = {};
 
 		if (body.title !== undefined) updates.title = body.title;
-		if (body.data !== undefined) {
+		if (body.data !== undefined && existing.type) {
 			const validation = validateitemdata(existing.type as ItemType, body.data);
 			if (!validation.valid) {
 				return NextResponse.json({ error: validation.error }, { status: 400 });
 			}
-			updates.data = body.data;
 		}
+		if (body.data !== undefined) updates.data = body.data;
 		if (body.favorite !== undefined) updates.favorite = body.favorite;
-		if (body.deleted !== undefined) updates.deleted = body.deleted;
+		if (body.typeIndex !== undefined) updates.typeIndex = body.typeIndex;
+		if (body.meta !== undefined) {
+			updates.meta = body.meta;
+			updates.type = null;
+			updates.favorite = false;
+		}
+		if (body.deleted !== undefined) {
+			updates.deleted = body.deleted;
+			updates.deletedAt = body.deleted ? (existing.deletedAt ?? new Date()) : null;
+		}
 		if (body.folderId !== undefined) updates.folderId = body.folderId;
 
 		if (body.tags !== undefined) {
@@ -162,9 +185,19 @@ export async function DELETE(req: Request, { params }: { params: Promise<{ id: s
 			return NextResponse.json({ error: "item not found" }, { status: 404 });
 		}
 
+		const permanent = new URL(req.url).searchParams.get("permanent") === "true";
+		if (permanent) {
+			await purgeitems(existing.vaultId, [id]);
+			return NextResponse.json({ success: true, purged: true });
+		}
+
+		if (existing.deleted) {
+			return NextResponse.json({ success: true });
+		}
+
 		await db.item.update({
 			where: { id },
-			data: { deleted: true },
+			data: { deleted: true, deletedAt: new Date(), revision: existing.revision + 1 },
 		});
 
 		await db.vault.update({
@@ -0,0 +1,150 @@
+import { NextResponse } from "next/server";
+import { headers } from "next/headers";
+import { auth } from "@/lib/auth";
+import { db } from "@/lib/db";
+import { validateitembody } from "@/lib/validate";
+import { idinuse } from "@/lib/trash";
+
+const MAX_OPS = 500;
+
+type BatchOp =
+	| {
+			op: "create";
+			id?: string;
+			type?: string;
+			typeIndex?: string;
+			title: string;
+			data: string;
+			meta?: string;
+			tags?: string[];
+			favorite?: boolean;
+	  }
+	| {
+			op: "update";
+			id: string;
+			typeIndex?: string;
+			title?: string;
+			data?: string;
+			meta?: string;
+			tags?: string[];
+			favorite?: boolean;
+	  }
+	| { op: "delete"; id: string };
+
+type BatchResult = {
+	id: string | null;
+	op: string;
+	ok: boolean;
+	error?: string;
+	item?: unknown;
+};
+
+export async function POST(req: Request) {
+	try {
+		const session = await auth.api.getSession({ headers: await headers() });
+		if (!session) {
+			return NextResponse.json({ error: "unauthorized" }, { status: 401 });
+		}
+
+		const vault = await db.vault.findUnique({ where: { userId: session.user.id } });
+		if (!vault) {
+			return NextResponse.json({ error: "vault not found" }, { status: 404 });
+		}
+
+		const { ops } = (await req.json()) as { ops: BatchOp[] };
+		if (!Array.isArray(ops) || ops.length === 0) {
+			return NextResponse.json({ error: "ops required" }, { status: 400 });
+		}
+		if (ops.length > MAX_OPS) {
+			return NextResponse.json({ error: `at most ${MAX_OPS} ops per batch` }, { status: 400 });
+		}
+
+		const results: BatchResult[] = [];
+		let changed = 0;
+
+		for (const op of ops) {
+			const id = op.op === "create" ? (op.id ?? null) : op.id;
+			try {
+				if (op.op === "create") {
+					const shape = validateitembody(op, true);
+					if (!shape.valid) {
+						results.push({ id, op: op.op, ok: false, error: shape.error });
+						continue;
+					}
+					if (op.id !== undefined && (await idinuse(op.id))) {
+						results.push({ id, op: op.op, ok: false, error: "item already exists" });
+						continue;
+					}
+					const item = await db.item.create({
+						data: {
+							...(op.id !== undefined ? { id: op.id } : {}),
+							vaultId: vault.id,
+							type: op.meta !== undefined ? null : (op.type as never),
+							typeIndex: op.typeIndex ?? null,
+							title: op.title,
+							data: op.data,
+							meta: op.meta ?? null,
+							favorite: op.meta !== undefined ? false : (op.favorite ?? false),
+							tags: op.tags ? { create: op.tags.map((name) => ({ name })) } : undefined,
+						},
+						include: { tags: true },
+					});
+					results.push({ id: item.id, op: op.op, ok: true, item });
+				} else {
+					const existing = await db.item.findUnique({ where: { id: op.id } });
+					if (!existing || existing.vaultId !== vault.id) {
+						results.push({ id, op: op.op, ok: false, error: "item not found" });
+						continue;
+					}
+					if (op.op === "update") {
+						const shape = validateitembody(op, false);
+						if (!shape.valid) {
+							results.push({ id, op: op.op, ok: false, error: shape.error });
+							continue;
+						}
+						if (op.tags) {
+							await db.tag.deleteMany({ where: { itemId: op.id } });
+						}
+						const item = await db.item.update({
+							where: { id: op.id },
+							data: {
+								title: op.title,
+								data: op.data,
+								meta: op.meta,
+								typeIndex: op.typeIndex,
+								...(op.meta !== undefined ? { type: null, favorite: false } : { favorite: op.favorite }),
+								revision: existing.revision + 1,
+								tags: op.tags ? { create: op.tags.map((name) => ({ name })) } : undefined,
+							},
+							include: { tags: true },
+						});
+						results.push({ id, op: op.op, ok: true, item });
+					} else if (op.op === "delete") {
+						await db.item.update({
+							where: { id: op.id },
+							data: { deleted: true, deletedAt: existing.deletedAt ?? new Date(), revision: existing.revision + 1 },
+						});
+						results.push({ id, op: op.op, ok: true });
+					} else {
+						results.push({ id, op: String((op as { op: unknown }).op), ok: false, error: "invalid op" });
+						continue;
+					}
+				}
+				changed++;
+			} catch {
+				results.push({ id, op: op.op, ok: false, error: "failed" });
+			}
+		}
+
+		if (changed > 0) {
+			await db.vault.update({
+				where: { id: vault.id },
+				data: { revision: { increment: 1 } },
+			});
+		}
+
+		return NextResponse.json({ results });
+	} catch {
+		return NextResponse.json({ error: "failed to process batch" }, { status: 500 });
+	}
+}
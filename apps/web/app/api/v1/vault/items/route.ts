@@ -3,7 +3,8 @@ import { headers } from "next/headers";
 import { auth } from "@/lib/auth";
 import { db } from "@/lib/db";
 import { vaultkey, createemptyvault } from "@/lib/r2";
-import { validateitemdata, isvaliditemtype } from "@/lib/validate";
+import { validateitemdata, validateitembody } from "@/lib/validate";
+import { idinuse, purgedsince } from "@/lib/trash";
 
 async function getorvault(userId: string) {
 	let vault = await db.vault.findUnique({
@@ -35,18 +36,29 @@ export async function GET(req: Request) {
 
 		const url = new URL(req.url);
 		const type = url.searchParams.get("type");
-		const deleted = url.searchParams.get("deleted") === "true";
+		const typeIndex = url.searchParams.get("typeIndex");
+		const tag = url.searchParams.get("tag");
+		const deletedParam = url.searchParams.get("deleted");
+		const deleted = deletedParam === "all" ? {} : { deleted: deletedParam === "true" };
+		const since = url.searchParams.get("since");
 
 		const items = await db.item.findMany({
 			where: {
 				vaultId: vault.id,
-				deleted,
+				...(since ? { updatedAt: { gt: new Date(since) } } : deleted),
 				...(type ? { type: type as never } : {}),
+				...(typeIndex ? { typeIndex } : {}),
+				...(tag ? { tags: { some: { name: tag } } } : {}),
 			},
 			include: { tags: true },
 			orderBy: { updatedAt: "desc" },
 		});
 
+		if (since) {
+			const purged = await purgedsince(vault.id, new Date(since));
+			return NextResponse.json({ items, purged: purged.map((t) => t.id) });
+		}
+
 		return NextResponse.json({ items });
 	} catch {
 		return NextResponse.json({ error: "failed to get items" }, { status: 500 });
@@ -62,28 +74,35 @@ export async function POST(req: Request) {
 
 		const vault = await getorvault(session.user.id);
 
-		const { type, title, data, tags, favorite } = await req.json();
-
-		if (!type || !title || !data) {
-			return NextResponse.json({ error: "type, title, and data required" }, { status: 400 });
+		const body = await req.json();
+		const shape = validateitembody(body, true);
+		if (!shape.valid) {
+			return NextResponse.json({ error: shape.error }, { status: 400 });
 		}
 
-		if (!isvaliditemtype(type)) {
-			return NextResponse.json({ error: "invalid item type" }, { status: 400 });
+		const { id, type, typeIndex, title, data, meta, tags, favorite } = body;
+
+		if (type) {
+			const validation = validateitemdata(type, data);
+			if (!validation.valid) {
+				return NextResponse.json({ error: validation.error }, { status: 400 });
+			}
 		}
 
-		const validation = validateitemdata(type, data);
-		if (!validation.valid) {
-			return NextResponse.json({ error: validation.error }, { status: 400 });
+		if (id !== undefined && (await idinuse(id))) {
+			return NextResponse.json({ error: "item already exists" }, { status: 409 });
 		}
 
 		const item = await db.item.create({
 			data: {
+				...(id !== undefined ? { id } : {}),
 				vaultId: vault.id,
-				type,
+				type: meta !== undefined ? null : type,
+				typeIndex: typeIndex ?? null,
 				title,
 				data,
-				favorite: favorite || false,
+				meta: meta ?? null,
+				favorite: meta !== undefined ? false : (favorite ?? false),
 				tags: tags
 					? {
 							create: tags.map((name: string) => ({ name })),
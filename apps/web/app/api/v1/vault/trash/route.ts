@@ -0,0 +1,48 @@
+import { NextResponse } from "next/server";
+import { headers } from "next/headers";
+import { auth } from "@/lib/auth";
+import { db } from "@/lib/db";
+import { TRASH_RETENTION_DAYS, emptytrash } from "@/lib/trash";
+
+export async function GET() {
+	try {
+		const session = await auth.api.getSession({ headers: await headers() });
+		if (!session) {
+			return NextResponse.json({ error: "unauthorized" }, { status: 401 });
+		}
+
+		const vault = await db.vault.findUnique({ where: { userId: session.user.id } });
+		if (!vault) {
+			return NextResponse.json({ items: [], retentionDays: TRASH_RETENTION_DAYS });
+		}
+
+		const items = await db.item.findMany({
+			where: { vaultId: vault.id, deleted: true },
+			include: { tags: true },
+			orderBy: { deletedAt: "desc" },
+		});
+
+		return NextResponse.json({ items, retentionDays: TRASH_RETENTION_DAYS });
+	} catch {
+		return NextResponse.json({ error: "failed to get trash" }, { status: 500 });
+	}
+}
+
+export async function DELETE() {
+	try {
+		const session = await auth.api.getSession({ headers: await headers() });
+		if (!session) {
+			return NextResponse.json({ error: "unauthorized" }, { status: 401 });
+		}
+
+		const vault = await db.vault.findUnique({ where: { userId: session.user.id } });
+		if (!vault) {
+			return NextResponse.json({ purged: [] });
+		}
+
+		const purged = await emptytrash(vault.id);
+		return NextResponse.json({ purged });
+	} catch {
+		return NextResponse.json({ error: "failed to empty trash" }, { status: 500 });
+	}
+}
@@ -0,0 +1,10 @@
+const PURGE_INTERVAL = 60 * 60 * 1000;
+
+export async function register() {
+	if (process.env.NEXT_RUNTIME !== "nodejs") return;
+
+	const { purgeallexpired } = await import("@/lib/trash");
+	const purge = () => purgeallexpired().catch(() => {});
+	purge();
+	setInterval(purge, PURGE_INTERVAL).unref();
+}
@@ -137,6 +137,12 @@ export function toFolderData(folder: Folder): FolderData {
 	return { id: folder.id, name: folder.name, parentId: folder.parentId, icon: folder.icon, createdAt: folder.createdAt, updatedAt: folder.updatedAt };
 }
 
+const SEALED = /^[A-Za-z0-9+/]{38,}={0,2}$/;
+
+export function foldername(name: string): string {
+	return SEALED.test(name) ? name : name.trim();
+}
+
 function checkCycle(folders: Folder[], id: string, newParentId: string | null): boolean {
 	if (!newParentId) return false;
 	let current: string | null = newParentId;
@@ -0,0 +1,83 @@
+import { db } from "./db";
+
+export const TRASH_RETENTION_DAYS = 30;
+
+export function trashcutoff(now = Date.now()): Date {
+	return new Date(now - TRASH_RETENTION_DAYS * 24 * 60 * 60 * 1000);
+}
+
+export async function purgeitems(vaultId: string, ids: string[]): Promise<string[]> {
+	if (ids.length === 0) return [];
+
+	const items = await db.item.findMany({
+		where: { vaultId, id: { in: ids } },
+		select: { id: true },
+	});
+	const purged = items.map((i) => i.id);
+	if (purged.length === 0) return [];
+
+	await db.$transaction([
+		db.tombstone.createMany({
+			data: purged.map((id) => ({ id, vaultId })),
+			skipDuplicates: true,
+		}),
+		db.item.deleteMany({ where: { vaultId, id: { in: purged } } }),
+		db.vault.update({
+			where: { id: vaultId },
+			data: { revision: { increment: 1 } },
+		}),
+	]);
+
+	return purged;
+}
+
+export async function purgeexpired(vaultId: string): Promise<string[]> {
+	const expired = await db.item.findMany({
+		where: { vaultId, deleted: true, deletedAt: { lt: trashcutoff() } },
+		select: { id: true },
+	});
+	return purgeitems(
+		vaultId,
+		expired.map((i) => i.id),
+	);
+}
+
+export async function purgeallexpired(): Promise<number> {
+	const vaults = await db.item.findMany({
+		where: { deleted: true, deletedAt: { lt: trashcutoff() } },
+		select: { vaultId: true },
+		distinct: ["vaultId"],
+	});
+	let purged = 0;
+	for (const { vaultId } of vaults) {
+		purged += (await purgeexpired(vaultId)).length;
+	}
+	return purged;
+}
+
+export async function emptytrash(vaultId: string): Promise<string[]> {
+	const trashed = await db.item.findMany({
+		where: { vaultId, deleted: true },
+		select: { id: true },
+	});
+	return purgeitems(
+		vaultId,
+		trashed.map((i) => i.id),
+	);
+}
+
+export async function purgedsince(vaultId: string, since: Date): Promise<{ id: string; deletedAt: Date }[]> {
+	return db.tombstone.findMany({
+		where: { vaultId, deletedAt: { gt: since } },
+		select: { id: true, deletedAt: true },
+		orderBy: { deletedAt: "asc" },
+	});
+}
+
+export async function idinuse(id: string): Promise<boolean> {
+	const [item, tombstone] = await Promise.all([
+		db.item.findUnique({ where: { id }, select: { id: true } }),
+		db.tombstone.findUnique({ where: { id }, select: { id: true } }),
+	]);
+	return item !== null || tombstone !== null;
+}
@@ -119,3 +119,47 @@ export function isvaliditemtype(type: string): type is ItemType {
 		"passkey",
 	].includes(type);
 }
+
+const ITEM_ID = /^[A-Za-z0-9_-]{8,64}$/;
+
+export function isvaliditemid(id: unknown): id is string {
+	return typeof id === "string" && ITEM_ID.test(id);
+}
+
+function optional(value: unknown, check: (v: unknown) => boolean): boolean {
+	return value === undefined || check(value);
+}
+
+const isstring = (v: unknown) => typeof v === "string";
+
+export function validateitembody(body: unknown, creating: boolean): ValidationResult {
+	if (typeof body !== "object" || body === null) {
+		return { valid: false, error: "body must be an object" };
+	}
+	const b = body as Record<string, unknown>;
+	if (creating && (typeof b.title !== "string" || typeof b.data !== "string" || !b.title || !b.data)) {
+		return { valid: false, error: "title and data required" };
+	}
+	if (!optional(b.title, isstring) || !optional(b.data, isstring)) {
+		return { valid: false, error: "title and data must be strings" };
+	}
+	if (!optional(b.meta, isstring) || !optional(b.typeIndex, isstring)) {
+		return { valid: false, error: "meta and typeIndex must be strings" };
+	}
+	if (!optional(b.type, (v) => typeof v === "string" && isvaliditemtype(v))) {
+		return { valid: false, error: "invalid item type" };
+	}
+	if (creating && b.type === undefined && b.meta === undefined) {
+		return { valid: false, error: "type or meta required" };
+	}
+	if (!optional(b.tags, (v) => Array.isArray(v) && v.every(isstring))) {
+		return { valid: false, error: "tags must be a list of strings" };
+	}
+	if (!optional(b.favorite, (v) => typeof v === "boolean")) {
+		return { valid: false, error: "favorite must be a boolean" };
+	}
+	if (creating && !optional(b.id, isvaliditemid)) {
+		return { valid: false, error: "invalid id" };
+	}
+	return { valid: true };
+}
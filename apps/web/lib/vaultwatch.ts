@@ -0,0 +1,53 @@
+import { db } from "./db";
+
+export const POLL_INTERVAL = 2000;
+export const MAX_STREAMS = 5;
+
+type Listener = (vaultId: string, revision: number) => void;
+
+interface Watch {
+	listeners: Set<Listener>;
+	revision: number;
+	timer: ReturnType<typeof setInterval>;
+}
+
+const watches = new Map<string, Watch>();
+
+export function streamcount(userId: string): number {
+	return watches.get(userId)?.listeners.size ?? 0;
+}
+
+export function watchvault(userId: string, revision: number, listener: Listener): () => void {
+	let watch = watches.get(userId);
+	if (!watch) {
+		const created: Watch = {
+			listeners: new Set(),
+			revision,
+			timer: setInterval(async () => {
+				try {
+					const vault = await db.vault.findUnique({
+						where: { userId },
+						select: { id: true, revision: true },
+					});
+					if (!vault || vault.revision === created.revision) return;
+					created.revision = vault.revision;
+					for (const notify of created.listeners) {
+						notify(vault.id, vault.revision);
+					}
+				} catch {}
+			}, POLL_INTERVAL),
+		};
+		watches.set(userId, created);
+		watch = created;
+	}
+
+	const current = watch;
+	current.listeners.add(listener);
+	return () => {
+		current.listeners.delete(listener);
+		if (current.listeners.size === 0 && watches.get(userId) === current) {
+			clearInterval(current.timer);
+			watches.delete(userId);
+		}
+	};
+}
@@ -0,0 +1,454 @@
+-- CreateEnum
+CREATE TYPE "EmergencyAccessStatus" AS ENUM ('pending', 'requested', 'approved', 'denied', 'expired');
+
+-- CreateEnum
+CREATE TYPE "ItemType" AS ENUM ('login', 'note', 'card', 'identity', 'ssh', 'api', 'otp', 'passkey');
+
+-- CreateEnum
+CREATE TYPE "EngineType" AS ENUM ('database', 'aws');
+
+-- CreateEnum
+CREATE TYPE "TeamRole" AS ENUM ('owner', 'admin', 'member', 'viewer');
+
+-- CreateEnum
+CREATE TYPE "InviteStatus" AS ENUM ('pending', 'accepted', 'declined');
+
+-- CreateTable
+CREATE TABLE "user" (
+    "id" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "email" TEXT NOT NULL,
+    "emailVerified" BOOLEAN NOT NULL DEFAULT false,
+    "image" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    "encryptedKey" TEXT,
+    "keyHash" TEXT,
+    "salt" TEXT,
+    CONSTRAINT "user_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "session" (
+    "id" TEXT NOT NULL,
+    "expiresAt" TIMESTAMP(3) NOT NULL,
+    "token" TEXT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    "ipAddress" TEXT,
+    "userAgent" TEXT,
+    "userId" TEXT NOT NULL,
+    CONSTRAINT "session_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "account" (
+    "id" TEXT NOT NULL,
+    "accountId" TEXT NOT NULL,
+    "providerId" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "accessToken" TEXT,
+    "refreshToken" TEXT,
+    "idToken" TEXT,
+    "accessTokenExpiresAt" TIMESTAMP(3),
+    "refreshTokenExpiresAt" TIMESTAMP(3),
+    "scope" TEXT,
+    "password" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "account_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "verification" (
+    "id" TEXT NOT NULL,
+    "identifier" TEXT NOT NULL,
+    "value" TEXT NOT NULL,
+    "expiresAt" TIMESTAMP(3) NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "verification_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "vault" (
+    "id" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "blobKey" TEXT NOT NULL,
+    "revision" INTEGER NOT NULL DEFAULT 0,
+    "size" INTEGER NOT NULL DEFAULT 0,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "vault_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "item" (
+    "id" TEXT NOT NULL,
+    "vaultId" TEXT NOT NULL,
+    "folderId" TEXT,
+    "type" "ItemType" NOT NULL,
+    "title" TEXT NOT NULL,
+    "data" TEXT NOT NULL,
+    "revision" INTEGER NOT NULL DEFAULT 0,
+    "favorite" BOOLEAN NOT NULL DEFAULT false,
+    "deleted" BOOLEAN NOT NULL DEFAULT false,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "item_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "tag" (
+    "id" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "itemId" TEXT NOT NULL,
+    CONSTRAINT "tag_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "folder" (
+    "id" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "parentId" TEXT,
+    "userId" TEXT NOT NULL,
+    "color" TEXT NOT NULL DEFAULT 'default',
+    "icon" TEXT NOT NULL DEFAULT 'folder',
+    "order" INTEGER NOT NULL DEFAULT 0,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "folder_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "api_key" (
+    "id" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "keyHash" TEXT NOT NULL,
+    "hint" TEXT NOT NULL,
+    "webhook" TEXT,
+    "expiresAt" TIMESTAMP(3),
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "lastUsed" TIMESTAMP(3),
+    CONSTRAINT "api_key_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "audit_log" (
+    "id" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "action" TEXT NOT NULL,
+    "details" TEXT,
+    "ip" TEXT,
+    "userAgent" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT "audit_log_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "recovery_code" (
+    "id" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "hash" TEXT NOT NULL,
+    "used" BOOLEAN NOT NULL DEFAULT false,
+    "usedAt" TIMESTAMP(3),
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT "recovery_code_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "share" (
+    "id" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "itemId" TEXT NOT NULL,
+    "encryptedData" TEXT NOT NULL,
+    "publicKey" TEXT NOT NULL,
+    "expiresAt" TIMESTAMP(3) NOT NULL,
+    "maxViews" INTEGER NOT NULL DEFAULT 1,
+    "viewCount" INTEGER NOT NULL DEFAULT 0,
+    "passwordHash" TEXT,
+    "revoked" BOOLEAN NOT NULL DEFAULT false,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "share_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "emergency_access" (
+    "id" TEXT NOT NULL,
+    "grantorId" TEXT NOT NULL,
+    "granteeId" TEXT NOT NULL,
+    "granteeEmail" TEXT,
+    "status" "EmergencyAccessStatus" NOT NULL DEFAULT 'pending',
+    "waitDays" INTEGER NOT NULL DEFAULT 7,
+    "requestedAt" TIMESTAMP(3),
+    "approvedAt" TIMESTAMP(3),
+    "deniedAt" TIMESTAMP(3),
+    "expiresAt" TIMESTAMP(3),
+    "grantorPublicKey" TEXT,
+    "grantorPrivateKey" TEXT,
+    "encryptedVaultKey" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "emergency_access_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "secret_engine" (
+    "id" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "type" "EngineType" NOT NULL,
+    "name" TEXT NOT NULL,
+    "config" TEXT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "secret_engine_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "secret_lease" (
+    "id" TEXT NOT NULL,
+    "engineId" TEXT NOT NULL,
+    "credentials" TEXT NOT NULL,
+    "expiresAt" TIMESTAMP(3) NOT NULL,
+    "revoked" BOOLEAN NOT NULL DEFAULT false,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT "secret_lease_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "team" (
+    "id" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "team_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "team_member" (
+    "id" TEXT NOT NULL,
+    "teamId" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "email" TEXT NOT NULL,
+    "role" "TeamRole" NOT NULL,
+    "invitedAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "joinedAt" TIMESTAMP(3),
+    "status" "InviteStatus" NOT NULL DEFAULT 'pending',
+    CONSTRAINT "team_member_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "team_vault" (
+    "id" TEXT NOT NULL,
+    "teamId" TEXT NOT NULL,
+    "blobKey" TEXT NOT NULL,
+    "revision" INTEGER NOT NULL DEFAULT 0,
+    "size" INTEGER NOT NULL DEFAULT 0,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "team_vault_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "team_item" (
+    "id" TEXT NOT NULL,
+    "vaultId" TEXT NOT NULL,
+    "type" "ItemType" NOT NULL,
+    "title" TEXT NOT NULL,
+    "data" TEXT NOT NULL,
+    "revision" INTEGER NOT NULL DEFAULT 0,
+    "createdBy" TEXT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updatedAt" TIMESTAMP(3) NOT NULL,
+    CONSTRAINT "team_item_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "team_audit_log" (
+    "id" TEXT NOT NULL,
+    "teamId" TEXT NOT NULL,
+    "userId" TEXT NOT NULL,
+    "action" TEXT NOT NULL,
+    "details" TEXT,
+    "ip" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT "team_audit_log_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "user_email_key" ON "user"("email");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "session_token_key" ON "session"("token");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "vault_userId_key" ON "vault"("userId");
+
+-- CreateIndex
+CREATE INDEX "item_vaultId_idx" ON "item"("vaultId");
+
+-- CreateIndex
+CREATE INDEX "item_folderId_idx" ON "item"("folderId");
+
+-- CreateIndex
+CREATE INDEX "item_type_idx" ON "item"("type");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "tag_itemId_name_key" ON "tag"("itemId", "name");
+
+-- CreateIndex
+CREATE INDEX "folder_userId_idx" ON "folder"("userId");
+
+-- CreateIndex
+CREATE INDEX "folder_parentId_idx" ON "folder"("parentId");
+
+-- CreateIndex
+CREATE INDEX "folder_order_idx" ON "folder"("order");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "api_key_keyHash_key" ON "api_key"("keyHash");
+
+-- CreateIndex
+CREATE INDEX "api_key_userId_idx" ON "api_key"("userId");
+
+-- CreateIndex
+CREATE INDEX "audit_log_userId_idx" ON "audit_log"("userId");
+
+-- CreateIndex
+CREATE INDEX "audit_log_action_idx" ON "audit_log"("action");
+
+-- CreateIndex
+CREATE INDEX "audit_log_createdAt_idx" ON "audit_log"("createdAt");
+
+-- CreateIndex
+CREATE INDEX "recovery_code_userId_idx" ON "recovery_code"("userId");
+
+-- CreateIndex
+CREATE INDEX "recovery_code_hash_idx" ON "recovery_code"("hash");
+
+-- CreateIndex
+CREATE INDEX "share_userId_idx" ON "share"("userId");
+
+-- CreateIndex
+CREATE INDEX "share_itemId_idx" ON "share"("itemId");
+
+-- CreateIndex
+CREATE INDEX "share_expiresAt_idx" ON "share"("expiresAt");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "emergency_access_grantorId_granteeId_key" ON "emergency_access"("grantorId", "granteeId");
+
+-- CreateIndex
+CREATE INDEX "emergency_access_grantorId_idx" ON "emergency_access"("grantorId");
+
+-- CreateIndex
+CREATE INDEX "emergency_access_granteeId_idx" ON "emergency_access"("granteeId");
+
+-- CreateIndex
+CREATE INDEX "emergency_access_status_idx" ON "emergency_access"("status");
+
+-- CreateIndex
+CREATE INDEX "secret_engine_userId_idx" ON "secret_engine"("userId");
+
+-- CreateIndex
+CREATE INDEX "secret_engine_type_idx" ON "secret_engine"("type");
+
+-- CreateIndex
+CREATE INDEX "secret_lease_engineId_idx" ON "secret_lease"("engineId");
+
+-- CreateIndex
+CREATE INDEX "secret_lease_expiresAt_idx" ON "secret_lease"("expiresAt");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "team_member_teamId_userId_key" ON "team_member"("teamId", "userId");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "team_member_teamId_email_key" ON "team_member"("teamId", "email");
+
+-- CreateIndex
+CREATE INDEX "team_member_teamId_idx" ON "team_member"("teamId");
+
+-- CreateIndex
+CREATE INDEX "team_member_userId_idx" ON "team_member"("userId");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "team_vault_teamId_key" ON "team_vault"("teamId");
+
+-- CreateIndex
+CREATE INDEX "team_item_vaultId_idx" ON "team_item"("vaultId");
+
+-- CreateIndex
+CREATE INDEX "team_audit_log_teamId_idx" ON "team_audit_log"("teamId");
+
+-- CreateIndex
+CREATE INDEX "team_audit_log_action_idx" ON "team_audit_log"("action");
+
+-- CreateIndex
+CREATE INDEX "team_audit_log_createdAt_idx" ON "team_audit_log"("createdAt");
+
+-- AddForeignKey
+ALTER TABLE "session" ADD CONSTRAINT "session_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "account" ADD CONSTRAINT "account_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "vault" ADD CONSTRAINT "vault_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "item" ADD CONSTRAINT "item_vaultId_fkey" FOREIGN KEY ("vaultId") REFERENCES "vault"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "item" ADD CONSTRAINT "item_folderId_fkey" FOREIGN KEY ("folderId") REFERENCES "folder"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "tag" ADD CONSTRAINT "tag_itemId_fkey" FOREIGN KEY ("itemId") REFERENCES "item"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "folder" ADD CONSTRAINT "folder_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "folder" ADD CONSTRAINT "folder_parentId_fkey" FOREIGN KEY ("parentId") REFERENCES "folder"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "api_key" ADD CONSTRAINT "api_key_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "audit_log" ADD CONSTRAINT "audit_log_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "recovery_code" ADD CONSTRAINT "recovery_code_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "share" ADD CONSTRAINT "share_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "share" ADD CONSTRAINT "share_itemId_fkey" FOREIGN KEY ("itemId") REFERENCES "item"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "emergency_access" ADD CONSTRAINT "emergency_access_grantorId_fkey" FOREIGN KEY ("grantorId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "emergency_access" ADD CONSTRAINT "emergency_access_granteeId_fkey" FOREIGN KEY ("granteeId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "secret_engine" ADD CONSTRAINT "secret_engine_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "secret_lease" ADD CONSTRAINT "secret_lease_engineId_fkey" FOREIGN KEY ("engineId") REFERENCES "secret_engine"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "team_member" ADD CONSTRAINT "team_member_teamId_fkey" FOREIGN KEY ("teamId") REFERENCES "team"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "team_member" ADD CONSTRAINT "team_member_userId_fkey" FOREIGN KEY ("userId") REFERENCES "user"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "team_vault" ADD CONSTRAINT "team_vault_teamId_fkey" FOREIGN KEY ("teamId") REFERENCES "team"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "team_item" ADD CONSTRAINT "team_item_vaultId_fkey" FOREIGN KEY ("vaultId") REFERENCES "team_vault"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "team_audit_log" ADD CONSTRAINT "team_audit_log_teamId_fkey" FOREIGN KEY ("teamId") REFERENCES "team"("id") ON DELETE CASCADE ON UPDATE CASCADE;
@@ -0,0 +1,7 @@
+-- AlterTable
+ALTER TABLE "item" ADD COLUMN     "meta" TEXT,
+ADD COLUMN     "typeIndex" TEXT,
+ALTER COLUMN "type" DROP NOT NULL;
+
+-- CreateIndex
+CREATE INDEX "item_typeIndex_idx" ON "item"("typeIndex");
@@ -0,0 +1,20 @@
+-- AlterTable
+ALTER TABLE "item" ADD COLUMN     "deletedAt" TIMESTAMP(3);
+
+-- CreateTable
+CREATE TABLE "tombstone" (
+    "id" TEXT NOT NULL,
+    "vaultId" TEXT NOT NULL,
+    "deletedAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "tombstone_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE INDEX "item_deleted_deletedAt_idx" ON "item"("deleted", "deletedAt");
+
+-- CreateIndex
+CREATE INDEX "tombstone_vaultId_deletedAt_idx" ON "tombstone"("vaultId", "deletedAt");
+
+-- AddForeignKey
+ALTER TABLE "tombstone" ADD CONSTRAINT "tombstone_vaultId_fkey" FOREIGN KEY ("vaultId") REFERENCES "vault"("id") ON DELETE CASCADE ON UPDATE CASCADE;
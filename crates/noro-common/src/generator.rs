@@ -0,0 +1,727 @@
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::strength;
+
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const NUMBERS: &str = "0123456789";
+const SYMBOLS: &str = "!@#$%^&*()_+-=[]{}|;:,.<>?";
+pub const AMBIGUOUS: &str = "Il1|O0o`'\";:,.";
+pub const MIN_LENGTH: usize = 4;
+pub const MAX_LENGTH: usize = 128;
+const MAX_ATTEMPTS: usize = 64;
+const CONSONANTS: &str = "bcdfghjklmnprstvwz";
+const VOWELS: &str = "aeiou";
+const MIN_WORDS: usize = 3;
+const MAX_WORDS: usize = 20;
+const MIN_PIN: usize = 4;
+const MAX_PIN: usize = 16;
+const WORDLIST: &str = include_str!("../data/eff_large.txt");
+
+static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum GeneratorError {
+    #[error("at least one character type required")]
+    NoCharacters,
+    #[error("minimum counts exceed password length")]
+    TooShort,
+    #[error("not enough distinct characters for a password without repeats")]
+    NotEnoughCharacters,
+    #[error("could not satisfy generator constraints")]
+    Exhausted,
+}
+
+impl Serialize for GeneratorError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeneratorMode {
+    #[default]
+    Random,
+    Passphrase,
+    Pronounceable,
+    Pin,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeneratorOptions {
+    pub mode: GeneratorMode,
+    pub length: usize,
+    pub uppercase: bool,
+    pub lowercase: bool,
+    pub numbers: bool,
+    pub symbols: bool,
+    pub min_uppercase: usize,
+    pub min_lowercase: usize,
+    pub min_numbers: usize,
+    pub min_symbols: usize,
+    pub exclude_ambiguous: bool,
+    pub include: String,
+    pub exclude: String,
+    pub no_repeat: bool,
+    pub no_consecutive: bool,
+    pub words: usize,
+    pub separator: String,
+    pub capitalize: bool,
+    pub append_digit: bool,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        Self {
+            mode: GeneratorMode::Random,
+            length: 20,
+            uppercase: true,
+            lowercase: true,
+            numbers: true,
+            symbols: true,
+            min_uppercase: 1,
+            min_lowercase: 1,
+            min_numbers: 1,
+            min_symbols: 1,
+            exclude_ambiguous: false,
+            include: String::new(),
+            exclude: String::new(),
+            no_repeat: false,
+            no_consecutive: false,
+            words: 5,
+            separator: "-".to_string(),
+            capitalize: false,
+            append_digit: false,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GeneratorResult {
+    pub password: String,
+    pub strength: String,
+    pub entropy: f64,
+}
+
+pub struct Class {
+    pub chars: Vec<char>,
+    pub min: usize,
+}
+
+pub fn uniform(rng: &mut impl RngCore, n: usize) -> usize {
+    let n = n as u64;
+    let limit = (1u64 << 32) / n * n;
+    loop {
+        let value = rng.next_u32() as u64;
+        if value < limit {
+            return (value % n) as usize;
+        }
+    }
+}
+
+fn shuffle<T>(rng: &mut impl RngCore, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, uniform(rng, i + 1));
+    }
+}
+
+fn classes(options: &GeneratorOptions) -> Vec<Class> {
+    let allowed = |c: &char| {
+        !(options.exclude.contains(*c) || options.exclude_ambiguous && AMBIGUOUS.contains(*c))
+    };
+    let mut classes: Vec<Class> = [
+        (options.uppercase, UPPERCASE, options.min_uppercase),
+        (options.lowercase, LOWERCASE, options.min_lowercase),
+        (options.numbers, NUMBERS, options.min_numbers),
+        (options.symbols, SYMBOLS, options.min_symbols),
+    ]
+    .into_iter()
+    .filter(|(enabled, _, _)| *enabled)
+    .map(|(_, chars, min)| Class {
+        chars: chars.chars().filter(allowed).collect(),
+        min: min.max(1),
+    })
+    .filter(|class| !class.chars.is_empty())
+    .collect();
+
+    let mut extra: Vec<char> = Vec::new();
+    for c in options.include.chars().filter(allowed) {
+        let known = classes.iter().any(|class| class.chars.contains(&c));
+        if !known && !extra.contains(&c) && !c.is_control() {
+            extra.push(c);
+        }
+    }
+    if !extra.is_empty() {
+        classes.push(Class {
+            chars: extra,
+            min: 0,
+        });
+    }
+    classes
+}
+
+fn pool(classes: &[Class]) -> Vec<char> {
+    classes
+        .iter()
+        .flat_map(|c| c.chars.iter().copied())
+        .collect()
+}
+
+fn unit(rng: &mut impl RngCore) -> f64 {
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn weighted(rng: &mut impl RngCore, weights: &[f64]) -> usize {
+    let mut target = unit(rng) * weights.iter().sum::<f64>();
+    for (i, weight) in weights.iter().enumerate() {
+        if target < *weight {
+            return i;
+        }
+        target -= weight;
+    }
+    weights.iter().rposition(|w| *w > 0.0).unwrap_or(0)
+}
+
+pub fn wordlist() -> &'static [&'static str] {
+    WORDS.get_or_init(|| WORDLIST.lines().filter(|w| !w.is_empty()).collect())
+}
+
+fn term(class: &Class, length: usize, distinct: bool) -> Vec<f64> {
+    let size = class.chars.len() as f64;
+    let mut term = vec![0.0f64; length + 1];
+    let mut value = 1.0f64;
+    for (k, slot) in term.iter_mut().enumerate() {
+        if k > 0 {
+            value *= if distinct {
+                (size - (k - 1) as f64).max(0.0) / k as f64
+            } else {
+                size / k as f64
+            };
+        }
+        if k >= class.min {
+            *slot = value;
+        }
+    }
+    term
+}
+
+fn polynomials(classes: &[Class], length: usize, distinct: bool) -> Vec<Vec<f64>> {
+    let mut poly = vec![0.0f64; length + 1];
+    poly[0] = 1.0;
+    let mut polys = vec![poly];
+    for class in classes {
+        let term = term(class, length, distinct);
+        let poly = polys.last().unwrap();
+        let mut next = vec![0.0f64; length + 1];
+        for (i, a) in poly.iter().enumerate().filter(|(_, a)| **a > 0.0) {
+            for (j, b) in term.iter().take(length + 1 - i).enumerate() {
+                next[i + j] += a * b;
+            }
+        }
+        polys.push(next);
+    }
+    polys
+}
+
+fn combinations(classes: &[Class], length: usize, distinct: bool) -> f64 {
+    let polys = polynomials(classes, length, distinct);
+    let factorial: f64 = (1..=length).map(|k| (k as f64).log2()).sum();
+    polys[classes.len()][length].log2() + factorial
+}
+
+fn counts(
+    rng: &mut impl RngCore,
+    classes: &[Class],
+    polys: &[Vec<f64>],
+    length: usize,
+    distinct: bool,
+) -> Vec<usize> {
+    let mut counts = vec![0; classes.len()];
+    let mut remaining = length;
+    for (i, class) in classes.iter().enumerate().rev() {
+        let term = term(class, length, distinct);
+        let weights: Vec<f64> = (0..=remaining)
+            .map(|k| term[k] * polys[i][remaining - k])
+            .collect();
+        counts[i] = weighted(rng, &weights);
+        remaining -= counts[i];
+    }
+    counts
+}
+
+fn result(password: String, entropy: f64) -> GeneratorResult {
+    let guesses = strength::estimate(&password, &[])
+        .guesses
+        .min(entropy.exp2());
+    GeneratorResult {
+        strength: strength::label(guesses).to_string(),
+        password,
+        entropy,
+    }
+}
+
+fn random(options: &GeneratorOptions) -> Result<GeneratorResult, GeneratorError> {
+    let length = options.length.clamp(MIN_LENGTH, MAX_LENGTH);
+    sample(&classes(options), length, options, |_| true)
+}
+
+pub fn sample(
+    classes: &[Class],
+    length: usize,
+    options: &GeneratorOptions,
+    accept: impl Fn(&[char]) -> bool,
+) -> Result<GeneratorResult, GeneratorError> {
+    if classes.is_empty() {
+        return Err(GeneratorError::NoCharacters);
+    }
+    if classes.iter().map(|c| c.min).sum::<usize>() > length {
+        return Err(GeneratorError::TooShort);
+    }
+    let all = pool(classes);
+    if options.no_repeat && (all.len() < length || classes.iter().any(|c| c.chars.len() < c.min)) {
+        return Err(GeneratorError::NotEnoughCharacters);
+    }
+    if options.no_consecutive && all.len() < 2 && length > 1 {
+        return Err(GeneratorError::NotEnoughCharacters);
+    }
+
+    let distinct = options.no_repeat;
+    let polys = polynomials(classes, length, distinct);
+    let mut rng = OsRng;
+    for _ in 0..MAX_ATTEMPTS {
+        let mut chars: Vec<char> = Vec::with_capacity(length);
+        for (class, count) in classes
+            .iter()
+            .zip(counts(&mut rng, classes, &polys, length, distinct))
+        {
+            if distinct {
+                let mut available = class.chars.clone();
+                shuffle(&mut rng, &mut available);
+                chars.extend(&available[..count]);
+            } else {
+                chars.extend((0..count).map(|_| class.chars[uniform(&mut rng, class.chars.len())]));
+            }
+        }
+        shuffle(&mut rng, &mut chars);
+        let repeats = options.no_consecutive && chars.windows(2).any(|w| w[0] == w[1]);
+        if repeats || !accept(&chars) {
+            continue;
+        }
+        let entropy = combinations(classes, length, distinct);
+        return Ok(result(chars.into_iter().collect(), entropy));
+    }
+    Err(GeneratorError::Exhausted)
+}
+
+fn passphrase(options: &GeneratorOptions) -> GeneratorResult {
+    let list = wordlist();
+    let count = options.words.clamp(MIN_WORDS, MAX_WORDS);
+    let mut rng = OsRng;
+    let mut words: Vec<String> = (0..count)
+        .map(|_| {
+            let word = list[uniform(&mut rng, list.len())];
+            if options.capitalize {
+                capitalize(word)
+            } else {
+                word.to_string()
+            }
+        })
+        .collect();
+    let mut entropy = count as f64 * (list.len() as f64).log2();
+    if options.append_digit {
+        let index = uniform(&mut rng, count);
+        words[index].push(char::from(b'0' + uniform(&mut rng, 10) as u8));
+        entropy += (count as f64).log2() + 10f64.log2();
+    }
+    result(words.join(&options.separator), entropy)
+}
+
+fn pronounceable(options: &GeneratorOptions) -> GeneratorResult {
+    let consonants: Vec<char> = CONSONANTS.chars().collect();
+    let vowels: Vec<char> = VOWELS.chars().collect();
+    let length = options.length.clamp(MIN_LENGTH, MAX_LENGTH);
+    let letters = if options.append_digit {
+        length - 1
+    } else {
+        length
+    };
+    let mut rng = OsRng;
+    let mut entropy = 0.0;
+    let mut password: String = (0..letters)
+        .map(|i| {
+            let set = if i % 2 == 0 { &consonants } else { &vowels };
+            entropy += (set.len() as f64).log2();
+            set[uniform(&mut rng, set.len())]
+        })
+        .collect();
+    if options.capitalize {
+        password = capitalize(&password);
+    }
+    if options.append_digit {
+        password.push(char::from(b'0' + uniform(&mut rng, 10) as u8));
+        entropy += 10f64.log2();
+    }
+    result(password, entropy)
+}
+
+fn pin(options: &GeneratorOptions) -> GeneratorResult {
+    let length = options.length.clamp(MIN_PIN, MAX_PIN);
+    let mut rng = OsRng;
+    let password = (0..length)
+        .map(|_| char::from(b'0' + uniform(&mut rng, 10) as u8))
+        .collect();
+    result(password, length as f64 * 10f64.log2())
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+pub fn generate(options: &GeneratorOptions) -> Result<GeneratorResult, GeneratorError> {
+    match options.mode {
+        GeneratorMode::Random => random(options),
+        GeneratorMode::Passphrase => Ok(passphrase(options)),
+        GeneratorMode::Pronounceable => Ok(pronounceable(options)),
+        GeneratorMode::Pin => Ok(pin(options)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(password: &str, set: &str) -> usize {
+        password.chars().filter(|c| set.contains(*c)).count()
+    }
+
+    #[test]
+    fn test_uniform() {
+        let mut counts = [0usize; 3];
+        let mut rng = OsRng;
+        for _ in 0..3000 {
+            counts[uniform(&mut rng, 3)] += 1;
+        }
+        assert!(counts.iter().all(|&n| n > 800));
+    }
+
+    #[test]
+    fn test_each_minimum() {
+        let base = GeneratorOptions {
+            length: 10,
+            min_uppercase: 0,
+            min_lowercase: 0,
+            min_numbers: 0,
+            min_symbols: 0,
+            ..Default::default()
+        };
+        let cases = [
+            (
+                UPPERCASE,
+                GeneratorOptions {
+                    min_uppercase: 7,
+                    ..base.clone()
+                },
+            ),
+            (
+                LOWERCASE,
+                GeneratorOptions {
+                    min_lowercase: 7,
+                    ..base.clone()
+                },
+            ),
+            (
+                NUMBERS,
+                GeneratorOptions {
+                    min_numbers: 7,
+                    ..base.clone()
+                },
+            ),
+            (
+                SYMBOLS,
+                GeneratorOptions {
+                    min_symbols: 7,
+                    ..base.clone()
+                },
+            ),
+        ];
+        for (set, options) in cases {
+            for _ in 0..50 {
+                let password = generate(&options).unwrap().password;
+                assert_eq!(password.chars().count(), 10);
+                assert!(count(&password, set) >= 7, "{password}");
+                for other in [UPPERCASE, LOWERCASE, NUMBERS, SYMBOLS] {
+                    assert!(count(&password, other) >= 1, "{password}");
+                }
+            }
+        }
+        assert_eq!(
+            generate(&GeneratorOptions {
+                length: 4,
+                min_numbers: 5,
+                ..Default::default()
+            })
+            .unwrap_err(),
+            GeneratorError::TooShort
+        );
+    }
+
+    #[test]
+    fn test_minimums_do_not_bias_counts() {
+        let options = GeneratorOptions {
+            length: 4,
+            uppercase: false,
+            symbols: false,
+            ..Default::default()
+        };
+        let valid = 36f64.powi(4) - 26f64.powi(4) - 10f64.powi(4);
+        assert!((generate(&options).unwrap().entropy - valid.log2()).abs() < 1e-9);
+
+        // Exactly one digit in 4 * 26^3 * 10 of the valid passwords; drawing the
+        // minimums first and filling from the pool would give (26/36)^2 instead.
+        let expected = 4.0 * 26f64.powi(3) * 10.0 / valid;
+        let runs = 4000;
+        let single = (0..runs)
+            .filter(|_| count(&generate(&options).unwrap().password, NUMBERS) == 1)
+            .count();
+        assert!((single as f64 / runs as f64 - expected).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_exclusion() {
+        let options = GeneratorOptions {
+            length: 12,
+            min_numbers: 2,
+            min_symbols: 3,
+            exclude_ambiguous: true,
+            exclude: "xyz".to_string(),
+            include: "~".to_string(),
+            no_consecutive: true,
+            ..Default::default()
+        };
+        let mut included = false;
+        for _ in 0..200 {
+            let password = generate(&options).unwrap().password;
+            assert_eq!(password.chars().count(), 12);
+            assert!(count(&password, NUMBERS) >= 2);
+            assert!(count(&password, SYMBOLS) >= 3);
+            assert!(!password
+                .chars()
+                .any(|c| AMBIGUOUS.contains(c) || "xyz".contains(c)));
+            let chars: Vec<char> = password.chars().collect();
+            assert!(chars.windows(2).all(|w| w[0] != w[1]));
+            included |= password.contains('~');
+        }
+        assert!(included);
+
+        let none = GeneratorOptions {
+            uppercase: false,
+            lowercase: false,
+            symbols: false,
+            exclude: NUMBERS.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(generate(&none).unwrap_err(), GeneratorError::NoCharacters);
+    }
+
+    #[test]
+    fn test_no_repeat() {
+        let digits = GeneratorOptions {
+            length: 10,
+            uppercase: false,
+            lowercase: false,
+            symbols: false,
+            no_repeat: true,
+            ..Default::default()
+        };
+        let mut password: Vec<char> = generate(&digits).unwrap().password.chars().collect();
+        password.sort();
+        assert_eq!(password.into_iter().collect::<String>(), NUMBERS);
+
+        let mixed = GeneratorOptions {
+            length: 40,
+            no_repeat: true,
+            ..Default::default()
+        };
+        for _ in 0..20 {
+            let mut chars: Vec<char> = generate(&mixed).unwrap().password.chars().collect();
+            chars.sort();
+            chars.dedup();
+            assert_eq!(chars.len(), 40);
+        }
+    }
+
+    #[test]
+    fn test_no_repeat_errors() {
+        let digits = GeneratorOptions {
+            length: 11,
+            uppercase: false,
+            lowercase: false,
+            symbols: false,
+            no_repeat: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            generate(&digits).unwrap_err(),
+            GeneratorError::NotEnoughCharacters
+        );
+        assert_eq!(
+            generate(&GeneratorOptions {
+                length: 20,
+                min_numbers: 11,
+                no_repeat: true,
+                ..Default::default()
+            })
+            .unwrap_err(),
+            GeneratorError::NotEnoughCharacters
+        );
+        assert_eq!(
+            generate(&GeneratorOptions {
+                length: 8,
+                exclude: "123456789".to_string(),
+                no_repeat: false,
+                no_consecutive: true,
+                ..digits
+            })
+            .unwrap_err(),
+            GeneratorError::NotEnoughCharacters
+        );
+    }
+
+    #[test]
+    fn test_wordlist() {
+        let list = wordlist();
+        assert!(list.len() > 7700);
+        assert!(list.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_random_entropy() {
+        let digits = GeneratorOptions {
+            length: 8,
+            uppercase: false,
+            lowercase: false,
+            symbols: false,
+            ..Default::default()
+        };
+        assert!((generate(&digits).unwrap().entropy - 8.0 * 10f64.log2()).abs() < 1e-9);
+
+        let mixed = GeneratorOptions {
+            uppercase: false,
+            symbols: false,
+            ..Default::default()
+        };
+        let exact = (2.0 * 10.0 * 26.0f64).log2();
+        assert!((combinations(&classes(&mixed), 2, false) - exact).abs() < 1e-9);
+
+        let unique = GeneratorOptions {
+            length: 4,
+            no_repeat: true,
+            ..digits
+        };
+        let expected = (10.0 * 9.0 * 8.0 * 7.0f64).log2();
+        assert!((generate(&unique).unwrap().entropy - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_passphrase_entropy() {
+        let list = wordlist();
+        let options = GeneratorOptions {
+            mode: GeneratorMode::Passphrase,
+            words: 6,
+            separator: " ".to_string(),
+            ..Default::default()
+        };
+        let phrase = generate(&options).unwrap();
+        let words: Vec<&str> = phrase.password.split(' ').collect();
+        assert_eq!(words.len(), 6);
+        assert!(words.iter().all(|w| list.contains(w)));
+        let bits = (list.len() as f64).log2();
+        assert!((phrase.entropy - 6.0 * bits).abs() < 1e-9);
+
+        let phrase = generate(&GeneratorOptions {
+            capitalize: true,
+            append_digit: true,
+            ..options
+        })
+        .unwrap();
+        let words: Vec<&str> = phrase.password.split(' ').collect();
+        assert!(words
+            .iter()
+            .all(|w| w.starts_with(|c: char| c.is_ascii_uppercase())));
+        assert_eq!(
+            phrase
+                .password
+                .chars()
+                .filter(|c| c.is_ascii_digit())
+                .count(),
+            1
+        );
+        let expected = 6.0 * bits + 6f64.log2() + 10f64.log2();
+        assert!((phrase.entropy - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pronounceable_entropy() {
+        let options = GeneratorOptions {
+            mode: GeneratorMode::Pronounceable,
+            length: 10,
+            ..Default::default()
+        };
+        let spoken = generate(&options).unwrap();
+        assert_eq!(spoken.password.len(), 10);
+        assert!(spoken
+            .password
+            .chars()
+            .step_by(2)
+            .all(|c| CONSONANTS.contains(c)));
+        assert!(spoken
+            .password
+            .chars()
+            .skip(1)
+            .step_by(2)
+            .all(|c| VOWELS.contains(c)));
+        assert!((spoken.entropy - (5.0 * 18f64.log2() + 5.0 * 5f64.log2())).abs() < 1e-9);
+
+        let spoken = generate(&GeneratorOptions {
+            append_digit: true,
+            ..options
+        })
+        .unwrap();
+        assert!(spoken.password.ends_with(|c: char| c.is_ascii_digit()));
+        let expected = 5.0 * 18f64.log2() + 4.0 * 5f64.log2() + 10f64.log2();
+        assert!((spoken.entropy - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pin_entropy() {
+        let code = generate(&GeneratorOptions {
+            mode: GeneratorMode::Pin,
+            length: 6,
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(code.password.len() == 6 && code.password.chars().all(|c| c.is_ascii_digit()));
+        assert!((code.entropy - 6.0 * 10f64.log2()).abs() < 1e-9);
+        assert!(matches!(code.strength.as_str(), "weak" | "fair"));
+
+        let clamped = generate(&GeneratorOptions {
+            mode: GeneratorMode::Pin,
+            length: 40,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(clamped.password.len(), MAX_PIN);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod generator;
+pub mod otp;
+pub mod otpimport;
+pub mod pool;
+pub mod search;
+pub mod sse;
+pub mod strength;
+pub mod time;
+pub mod urlmatch;
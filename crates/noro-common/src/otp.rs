@@ -0,0 +1,666 @@
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use url::{form_urlencoded, Url};
+
+const BASE32_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const STEAM_ALPHABET: &[u8] = b"23456789BCDFGHJKMNPQRTVWXY";
+const STEAM_DIGITS: u32 = 5;
+pub const DEFAULT_DIGITS: u32 = 6;
+pub const DEFAULT_PERIOD: u64 = 30;
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum OtpError {
+    #[error("invalid otp secret")]
+    InvalidSecret,
+    #[error("invalid otp uri")]
+    InvalidUri,
+    #[error("unsupported otp parameters")]
+    Unsupported,
+}
+
+impl Serialize for OtpError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Algorithm {
+    #[default]
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl Algorithm {
+    pub fn parse(name: &str) -> Option<Algorithm> {
+        match name.to_uppercase().replace('-', "").as_str() {
+            "SHA1" => Some(Algorithm::Sha1),
+            "SHA256" => Some(Algorithm::Sha256),
+            "SHA512" => Some(Algorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Algorithm::Sha1 => "SHA1",
+            Algorithm::Sha256 => "SHA256",
+            Algorithm::Sha512 => "SHA512",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OtpKind {
+    #[default]
+    Totp,
+    Hotp,
+    Steam,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtpConfig {
+    pub kind: OtpKind,
+    pub secret: String,
+    pub issuer: Option<String>,
+    pub account: Option<String>,
+    pub algorithm: Algorithm,
+    pub digits: u32,
+    pub period: u64,
+    pub counter: u64,
+}
+
+impl Default for OtpConfig {
+    fn default() -> Self {
+        OtpConfig {
+            kind: OtpKind::Totp,
+            secret: String::new(),
+            issuer: None,
+            account: None,
+            algorithm: Algorithm::Sha1,
+            digits: DEFAULT_DIGITS,
+            period: DEFAULT_PERIOD,
+            counter: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OtpCode {
+    pub code: String,
+    pub counter: u64,
+    pub period: u64,
+    pub remaining: u64,
+    pub expires: u64,
+}
+
+pub fn base32decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::new();
+    let mut bits = 0u32;
+    let mut bitcount = 0;
+    for c in encoded.chars() {
+        if c.is_whitespace() || c == '-' || c == '=' {
+            continue;
+        }
+        let c = c.to_ascii_uppercase() as u8;
+        let index = BASE32_ALPHABET.iter().position(|&x| x == c)?;
+        bits = (bits << 5) | index as u32;
+        bitcount += 5;
+        if bitcount >= 8 {
+            bitcount -= 8;
+            result.push((bits >> bitcount) as u8);
+        }
+    }
+    Some(result)
+}
+
+pub fn base32encode(bytes: &[u8]) -> String {
+    let mut result = String::new();
+    let mut bits = 0u32;
+    let mut bitcount = 0;
+    for &byte in bytes {
+        bits = (bits << 8) | byte as u32;
+        bitcount += 8;
+        while bitcount >= 5 {
+            bitcount -= 5;
+            result.push(BASE32_ALPHABET[((bits >> bitcount) & 0x1f) as usize] as char);
+        }
+    }
+    if bitcount > 0 {
+        result.push(BASE32_ALPHABET[((bits << (5 - bitcount)) & 0x1f) as usize] as char);
+    }
+    result
+}
+
+fn normalizesecret(secret: &str) -> Result<String, OtpError> {
+    let key = base32decode(secret).ok_or(OtpError::InvalidSecret)?;
+    if key.is_empty() {
+        return Err(OtpError::InvalidSecret);
+    }
+    Ok(base32encode(&key))
+}
+
+fn sign(algorithm: Algorithm, key: &[u8], message: &[u8]) -> Vec<u8> {
+    match algorithm {
+        Algorithm::Sha1 => {
+            let mut mac =
+                <Hmac<Sha1> as Mac>::new_from_slice(key).expect("hmac accepts any key length");
+            mac.update(message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        Algorithm::Sha256 => {
+            let mut mac =
+                <Hmac<Sha256> as Mac>::new_from_slice(key).expect("hmac accepts any key length");
+            mac.update(message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        Algorithm::Sha512 => {
+            let mut mac =
+                <Hmac<Sha512> as Mac>::new_from_slice(key).expect("hmac accepts any key length");
+            mac.update(message);
+            mac.finalize().into_bytes().to_vec()
+        }
+    }
+}
+
+fn truncate(hash: &[u8]) -> u32 {
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ])
+}
+
+pub fn hotp(key: &[u8], counter: u64, algorithm: Algorithm, digits: u32) -> String {
+    let binary = truncate(&sign(algorithm, key, &counter.to_be_bytes())) as u64;
+    format!(
+        "{:0width$}",
+        binary % 10u64.pow(digits),
+        width = digits as usize
+    )
+}
+
+pub fn steam(key: &[u8], counter: u64) -> String {
+    let mut binary = truncate(&sign(Algorithm::Sha1, key, &counter.to_be_bytes())) as usize;
+    let mut code = String::new();
+    for _ in 0..STEAM_DIGITS {
+        code.push(STEAM_ALPHABET[binary % STEAM_ALPHABET.len()] as char);
+        binary /= STEAM_ALPHABET.len();
+    }
+    code
+}
+
+impl OtpConfig {
+    pub fn generate(&self, now: u64) -> Result<OtpCode, OtpError> {
+        let key = base32decode(&self.secret)
+            .filter(|k| !k.is_empty())
+            .ok_or(OtpError::InvalidSecret)?;
+        if !(1..=10).contains(&self.digits) || self.period == 0 {
+            return Err(OtpError::Unsupported);
+        }
+        if self.kind == OtpKind::Hotp {
+            return Ok(OtpCode {
+                code: hotp(&key, self.counter, self.algorithm, self.digits),
+                counter: self.counter,
+                period: 0,
+                remaining: 0,
+                expires: 0,
+            });
+        }
+        let counter = now / self.period;
+        let remaining = self.period - now % self.period;
+        let code = match self.kind {
+            OtpKind::Steam => steam(&key, counter),
+            _ => hotp(&key, counter, self.algorithm, self.digits),
+        };
+        Ok(OtpCode {
+            code,
+            counter,
+            period: self.period,
+            remaining,
+            expires: now + remaining,
+        })
+    }
+
+    pub fn touri(&self) -> String {
+        let issuer = match self.kind {
+            OtpKind::Steam => self.issuer.clone().or_else(|| Some("Steam".into())),
+            _ => self.issuer.clone(),
+        };
+        let account = self.account.clone().unwrap_or_default();
+        let label = match &issuer {
+            Some(issuer) => format!("{}:{}", percentencode(issuer), percentencode(&account)),
+            None => percentencode(&account),
+        };
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("secret", &self.secret);
+        if let Some(issuer) = &issuer {
+            query.append_pair("issuer", issuer);
+        }
+        if self.kind == OtpKind::Steam {
+            query.append_pair("encoder", "steam");
+        } else {
+            if self.algorithm != Algorithm::Sha1 {
+                query.append_pair("algorithm", self.algorithm.name());
+            }
+            if self.digits != DEFAULT_DIGITS {
+                query.append_pair("digits", &self.digits.to_string());
+            }
+        }
+        match self.kind {
+            OtpKind::Hotp => {
+                query.append_pair("counter", &self.counter.to_string());
+            }
+            _ if self.period != DEFAULT_PERIOD => {
+                query.append_pair("period", &self.period.to_string());
+            }
+            _ => {}
+        }
+        let kind = if self.kind == OtpKind::Hotp {
+            "hotp"
+        } else {
+            "totp"
+        };
+        format!("otpauth://{kind}/{label}?{}", query.finish())
+    }
+}
+
+fn percentencode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~@".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn percentdecode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn nonempty(value: String) -> Option<String> {
+    let value = value.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+pub fn parseuri(uri: &str) -> Result<OtpConfig, OtpError> {
+    let uri = uri.trim();
+    if let Some(secret) = uri.strip_prefix("steam://") {
+        return Ok(OtpConfig {
+            kind: OtpKind::Steam,
+            secret: normalizesecret(secret)?,
+            issuer: Some("Steam".into()),
+            digits: STEAM_DIGITS,
+            ..Default::default()
+        });
+    }
+    let url = Url::parse(uri).map_err(|_| OtpError::InvalidUri)?;
+    if url.scheme() != "otpauth" {
+        return Err(OtpError::InvalidUri);
+    }
+    let mut config = OtpConfig {
+        kind: match url.host_str().map(|h| h.to_lowercase()).as_deref() {
+            Some("totp") => OtpKind::Totp,
+            Some("hotp") => OtpKind::Hotp,
+            Some("steam") => OtpKind::Steam,
+            _ => return Err(OtpError::InvalidUri),
+        },
+        ..Default::default()
+    };
+
+    let label = percentdecode(url.path().trim_start_matches('/'));
+    match label.split_once(':') {
+        Some((issuer, account)) => {
+            config.issuer = nonempty(issuer.to_string());
+            config.account = nonempty(account.to_string());
+        }
+        None => config.account = nonempty(label),
+    }
+
+    let mut secret = None;
+    let mut hascounter = false;
+    for (key, value) in url.query_pairs() {
+        match key.to_lowercase().as_str() {
+            "secret" => secret = Some(normalizesecret(&value)?),
+            "issuer" => config.issuer = nonempty(value.into_owned()).or(config.issuer),
+            "algorithm" => {
+                config.algorithm = Algorithm::parse(&value).ok_or(OtpError::Unsupported)?
+            }
+            "digits" => config.digits = value.parse().map_err(|_| OtpError::Unsupported)?,
+            "period" => config.period = value.parse().map_err(|_| OtpError::Unsupported)?,
+            "counter" => {
+                config.counter = value.parse().map_err(|_| OtpError::Unsupported)?;
+                hascounter = true;
+            }
+            "encoder" if value.eq_ignore_ascii_case("steam") => config.kind = OtpKind::Steam,
+            _ => {}
+        }
+    }
+    config.secret = secret.ok_or(OtpError::InvalidSecret)?;
+    if config.kind == OtpKind::Hotp && !hascounter {
+        return Err(OtpError::InvalidUri);
+    }
+    if config.kind == OtpKind::Steam {
+        config.digits = STEAM_DIGITS;
+        config.algorithm = Algorithm::Sha1;
+    }
+    if !(1..=10).contains(&config.digits) || config.period == 0 {
+        return Err(OtpError::Unsupported);
+    }
+    Ok(config)
+}
+
+pub fn parse(input: &str) -> Result<OtpConfig, OtpError> {
+    if input.contains("://") {
+        return parseuri(input);
+    }
+    Ok(OtpConfig {
+        secret: normalizesecret(input)?,
+        ..Default::default()
+    })
+}
+
+pub fn fromdata(data: &[u8]) -> Option<Result<OtpConfig, OtpError>> {
+    let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(data) else {
+        return None;
+    };
+    if let Some(uri) = ["totp", "otp", "otpauth"]
+        .iter()
+        .find_map(|k| map.get(*k).and_then(Value::as_str))
+        .filter(|v| !v.trim().is_empty())
+    {
+        return Some(parse(uri));
+    }
+    let secret = map.get("secret").and_then(Value::as_str)?;
+    if secret.contains("://") {
+        return Some(parseuri(secret));
+    }
+    let text = |key: &str| map.get(key).and_then(Value::as_str).map(String::from);
+    let number = |key: &str| {
+        map.get(key)
+            .and_then(|v| v.as_u64().or_else(|| v.as_str()?.parse().ok()))
+    };
+    let kind = match text("kind").or_else(|| text("otptype")).as_deref() {
+        Some("hotp") => OtpKind::Hotp,
+        Some("steam") => OtpKind::Steam,
+        _ => OtpKind::Totp,
+    };
+    let config = (|| {
+        Ok(OtpConfig {
+            kind,
+            secret: normalizesecret(secret)?,
+            issuer: text("issuer").and_then(nonempty),
+            account: text("account").and_then(nonempty),
+            algorithm: match text("algorithm") {
+                Some(name) => Algorithm::parse(&name).ok_or(OtpError::Unsupported)?,
+                None => Algorithm::Sha1,
+            },
+            digits: match kind {
+                OtpKind::Steam => STEAM_DIGITS,
+                _ => number("digits").map_or(DEFAULT_DIGITS, |d| d as u32),
+            },
+            period: number("period").unwrap_or(DEFAULT_PERIOD),
+            counter: number("counter").unwrap_or(0),
+        })
+    })();
+    Some(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA1_KEY: &[u8] = b"12345678901234567890";
+    const SHA256_KEY: &[u8] = b"12345678901234567890123456789012";
+    const SHA512_KEY: &[u8] = b"1234567890123456789012345678901234567890123456789012345678901234";
+
+    fn totp(key: &[u8], algorithm: Algorithm, digits: u32, period: u64) -> OtpConfig {
+        OtpConfig {
+            secret: base32encode(key),
+            algorithm,
+            digits,
+            period,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hotp_rfc4226_vectors() {
+        let expected = [
+            "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+            "399871", "520489",
+        ];
+        for (counter, code) in expected.iter().enumerate() {
+            assert_eq!(hotp(SHA1_KEY, counter as u64, Algorithm::Sha1, 6), *code);
+        }
+    }
+
+    #[test]
+    fn test_totp_rfc6238_vectors() {
+        let vectors = [
+            (59, "94287082", "46119246", "90693936"),
+            (1111111109, "07081804", "68084774", "25091201"),
+            (1234567890, "89005924", "91819424", "93441116"),
+            (20000000000, "65353130", "77737706", "47863826"),
+        ];
+        for (time, sha1, sha256, sha512) in vectors {
+            let code = |key, algorithm| totp(key, algorithm, 8, 30).generate(time).unwrap().code;
+            assert_eq!(code(SHA1_KEY, Algorithm::Sha1), sha1);
+            assert_eq!(code(SHA256_KEY, Algorithm::Sha256), sha256);
+            assert_eq!(code(SHA512_KEY, Algorithm::Sha512), sha512);
+        }
+    }
+
+    #[test]
+    fn test_totp_custom_period() {
+        let config = totp(SHA256_KEY, Algorithm::Sha256, 8, 60);
+        let code = config.generate(119).unwrap();
+        assert_eq!(code.code, "46119246");
+        assert_eq!(code.counter, 1);
+        assert_eq!(code.period, 60);
+        assert_eq!(code.remaining, 1);
+        assert_eq!(code.expires, 120);
+        assert_eq!(config.generate(120).unwrap().remaining, 60);
+    }
+
+    #[test]
+    fn test_totp_short_digits_keep_leading_zeros() {
+        let config = totp(SHA1_KEY, Algorithm::Sha1, 8, 30);
+        assert_eq!(config.generate(1111111109).unwrap().code, "07081804");
+        let config = totp(SHA1_KEY, Algorithm::Sha1, 7, 30);
+        assert_eq!(config.generate(1111111109).unwrap().code, "7081804");
+        assert_eq!(config.generate(1111111109).unwrap().code.len(), 7);
+    }
+
+    #[test]
+    fn test_hotp_config_ignores_time() {
+        let config = OtpConfig {
+            kind: OtpKind::Hotp,
+            counter: 9,
+            ..totp(SHA1_KEY, Algorithm::Sha1, 6, 30)
+        };
+        let code = config.generate(1700000000).unwrap();
+        assert_eq!(code.code, "520489");
+        assert_eq!((code.counter, code.period, code.remaining), (9, 0, 0));
+    }
+
+    #[test]
+    fn test_generate_rejects_bad_config() {
+        let config = totp(SHA1_KEY, Algorithm::Sha1, 6, 30);
+        let with = |digits, period| OtpConfig {
+            digits,
+            period,
+            ..config.clone()
+        };
+        assert_eq!(with(0, 30).generate(0), Err(OtpError::Unsupported));
+        assert_eq!(with(11, 30).generate(0), Err(OtpError::Unsupported));
+        assert_eq!(with(6, 0).generate(0), Err(OtpError::Unsupported));
+        let empty = OtpConfig {
+            secret: String::new(),
+            ..config
+        };
+        assert_eq!(empty.generate(0), Err(OtpError::InvalidSecret));
+    }
+
+    #[test]
+    fn test_parseuri_label_and_issuer() {
+        let config = parseuri(&format!(
+            "otpauth://totp/ACME%20Co:jane@example.com?secret={}&algorithm=SHA1&digits=8",
+            base32encode(SHA1_KEY)
+        ))
+        .unwrap();
+        assert_eq!(config.issuer.as_deref(), Some("ACME Co"));
+        assert_eq!(config.account.as_deref(), Some("jane@example.com"));
+        assert_eq!(config.generate(1111111109).unwrap().code, "07081804");
+
+        let config = parseuri("otpauth://totp/jane?secret=JBSWY3DP&issuer=Example").unwrap();
+        assert_eq!(config.issuer.as_deref(), Some("Example"));
+        assert_eq!(config.account.as_deref(), Some("jane"));
+    }
+
+    #[test]
+    fn test_parseuri_non_default_parameters() {
+        let config =
+            parseuri("otpauth://totp/x?secret=jbsw%20y3dp&algorithm=sha-512&digits=7&period=45")
+                .unwrap();
+        assert_eq!(config.secret, "JBSWY3DP");
+        assert_eq!(config.algorithm, Algorithm::Sha512);
+        assert_eq!(config.digits, 7);
+        assert_eq!(config.period, 45);
+        assert_eq!(config.generate(90).unwrap().counter, 2);
+    }
+
+    #[test]
+    fn test_uri_roundtrip() {
+        let configs = [
+            parseuri("otpauth://totp/Acme:jane?secret=JBSWY3DP").unwrap(),
+            parseuri("otpauth://totp/x?secret=JBSWY3DP&algorithm=SHA256&digits=8&period=60")
+                .unwrap(),
+            parseuri("otpauth://hotp/Acme:jane?secret=JBSWY3DP&counter=42").unwrap(),
+            parse("steam://JBSWY3DPEHPK3PXP").unwrap(),
+        ];
+        for config in configs {
+            assert_eq!(parseuri(&config.touri()).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn test_parseuri_rejects_invalid() {
+        let cases = [
+            ("otpauth://hotp/x?secret=JBSWY3DP", OtpError::InvalidUri),
+            ("otpauth://motp/x?secret=JBSWY3DP", OtpError::InvalidUri),
+            ("https://totp/x?secret=JBSWY3DP", OtpError::InvalidUri),
+            ("otpauth://totp/x", OtpError::InvalidSecret),
+            ("otpauth://totp/x?secret=", OtpError::InvalidSecret),
+            ("otpauth://totp/x?secret=JBSW1!", OtpError::InvalidSecret),
+            (
+                "otpauth://totp/x?secret=JBSWY3DP&algorithm=MD5",
+                OtpError::Unsupported,
+            ),
+            (
+                "otpauth://totp/x?secret=JBSWY3DP&digits=0",
+                OtpError::Unsupported,
+            ),
+            (
+                "otpauth://totp/x?secret=JBSWY3DP&digits=12",
+                OtpError::Unsupported,
+            ),
+            (
+                "otpauth://totp/x?secret=JBSWY3DP&period=0",
+                OtpError::Unsupported,
+            ),
+            (
+                "otpauth://totp/x?secret=JBSWY3DP&period=soon",
+                OtpError::Unsupported,
+            ),
+        ];
+        for (uri, error) in cases {
+            assert_eq!(parseuri(uri), Err(error), "{uri}");
+        }
+    }
+
+    #[test]
+    fn test_steam() {
+        let steam = parse("steam://JBSWY3DPEHPK3PXP").unwrap();
+        assert_eq!(steam.kind, OtpKind::Steam);
+        assert_eq!(steam.issuer.as_deref(), Some("Steam"));
+        let code = steam.generate(1700000000).unwrap();
+        assert_eq!(code.code.len(), 5);
+        assert!(code.code.bytes().all(|b| STEAM_ALPHABET.contains(&b)));
+
+        let encoder = parseuri(
+            "otpauth://totp/Steam:jane?secret=JBSWY3DPEHPK3PXP&encoder=steam&digits=8&algorithm=SHA256",
+        )
+        .unwrap();
+        assert_eq!(encoder.kind, OtpKind::Steam);
+        assert_eq!((encoder.digits, encoder.algorithm), (5, Algorithm::Sha1));
+        assert_eq!(encoder.generate(1700000000).unwrap().code, code.code);
+    }
+
+    #[test]
+    fn test_base32() {
+        assert_eq!(base32encode(b"Hello!"), "JBSWY3DPEE");
+        assert_eq!(base32decode("jbsw y3dp-ee==").unwrap(), b"Hello!");
+        assert_eq!(base32decode("").unwrap(), b"");
+        assert!(base32decode("JBSW0").is_none());
+        assert_eq!(parse(" jbsw y3dp ").unwrap().secret, "JBSWY3DP");
+        assert_eq!(parse("===="), Err(OtpError::InvalidSecret));
+    }
+
+    #[test]
+    fn test_fromdata() {
+        assert!(fromdata(b"not json").is_none());
+        assert!(fromdata(br#"{"username":"a"}"#).is_none());
+        assert!(fromdata(br#"{"totp":" ","username":"a"}"#).is_none());
+
+        let config = fromdata(br#"{"secret":"jbsw y3dp","digits":"8","period":60}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!((config.digits, config.period), (8, 60));
+
+        let config = fromdata(br#"{"totp":"otpauth://hotp/x?secret=JBSWY3DP&counter=3"}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!((config.kind, config.counter), (OtpKind::Hotp, 3));
+
+        let config = fromdata(br#"{"secret":"JBSWY3DP","kind":"steam","digits":8}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.digits, 5);
+
+        assert_eq!(
+            fromdata(br#"{"secret":"JBSWY3DP","algorithm":"md5"}"#).unwrap(),
+            Err(OtpError::Unsupported)
+        );
+    }
+}
@@ -0,0 +1,522 @@
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+use serde::Serialize;
+use url::Url;
+
+use crate::otp::{self, Algorithm, OtpConfig, OtpError, OtpKind};
+
+const MIGRATION_SCHEME: &str = "otpauth-migration";
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    fn done(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.buf.get(self.pos)?;
+            self.pos += 1;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.varint()? as usize;
+        let end = self.pos.checked_add(len)?;
+        let bytes = self.buf.get(self.pos..end)?;
+        self.pos = end;
+        Some(bytes)
+    }
+
+    fn field(&mut self) -> Option<(u64, u8)> {
+        let key = self.varint()?;
+        Some((key >> 3, (key & 0x7) as u8))
+    }
+
+    fn skip(&mut self, wire: u8) -> Option<()> {
+        match wire {
+            0 => self.varint().map(|_| ()),
+            1 => self.advance(8),
+            2 => self.bytes().map(|_| ()),
+            5 => self.advance(4),
+            _ => None,
+        }
+    }
+
+    fn advance(&mut self, n: usize) -> Option<()> {
+        let end = self.pos.checked_add(n)?;
+        (end <= self.buf.len()).then(|| self.pos = end)
+    }
+}
+
+#[derive(Default)]
+struct Parameters {
+    secret: Vec<u8>,
+    name: String,
+    issuer: String,
+    algorithm: u64,
+    digits: u64,
+    kind: u64,
+    counter: u64,
+}
+
+fn decodeparameters(buf: &[u8]) -> Option<Parameters> {
+    let mut params = Parameters::default();
+    let mut reader = Reader::new(buf);
+    while !reader.done() {
+        match reader.field()? {
+            (1, 2) => params.secret = reader.bytes()?.to_vec(),
+            (2, 2) => params.name = String::from_utf8_lossy(reader.bytes()?).into_owned(),
+            (3, 2) => params.issuer = String::from_utf8_lossy(reader.bytes()?).into_owned(),
+            (4, 0) => params.algorithm = reader.varint()?,
+            (5, 0) => params.digits = reader.varint()?,
+            (6, 0) => params.kind = reader.varint()?,
+            (7, 0) => params.counter = reader.varint()?,
+            (_, wire) => reader.skip(wire)?,
+        }
+    }
+    Some(params)
+}
+
+fn toconfig(params: Parameters) -> Result<OtpConfig, OtpError> {
+    if params.secret.is_empty() {
+        return Err(OtpError::InvalidSecret);
+    }
+    let (mut issuer, account) = match params.name.split_once(':') {
+        Some((issuer, account)) => (issuer.trim().to_string(), account.trim().to_string()),
+        None => (String::new(), params.name.trim().to_string()),
+    };
+    if !params.issuer.trim().is_empty() {
+        issuer = params.issuer.trim().to_string();
+    }
+    Ok(OtpConfig {
+        kind: match params.kind {
+            1 => OtpKind::Hotp,
+            _ => OtpKind::Totp,
+        },
+        secret: otp::base32encode(&params.secret),
+        issuer: (!issuer.is_empty()).then_some(issuer),
+        account: (!account.is_empty()).then_some(account),
+        algorithm: match params.algorithm {
+            0 | 1 => Algorithm::Sha1,
+            2 => Algorithm::Sha256,
+            3 => Algorithm::Sha512,
+            _ => return Err(OtpError::Unsupported),
+        },
+        digits: match params.digits {
+            2 => 8,
+            _ => otp::DEFAULT_DIGITS,
+        },
+        period: otp::DEFAULT_PERIOD,
+        counter: params.counter,
+    })
+}
+
+pub fn parsemigration(uri: &str) -> Result<Vec<Result<OtpConfig, OtpError>>, OtpError> {
+    let url = Url::parse(uri.trim()).map_err(|_| OtpError::InvalidUri)?;
+    if url.scheme() != MIGRATION_SCHEME {
+        return Err(OtpError::InvalidUri);
+    }
+    let data = url
+        .query_pairs()
+        .find(|(k, _)| k == "data")
+        .map(|(_, v)| v.into_owned())
+        .ok_or(OtpError::InvalidUri)?;
+    let data: String = data
+        .chars()
+        .filter(|c| *c != '=')
+        .map(|c| match c {
+            ' ' | '-' => '+',
+            '_' => '/',
+            c => c,
+        })
+        .collect();
+    let payload = STANDARD_NO_PAD
+        .decode(data)
+        .map_err(|_| OtpError::InvalidUri)?;
+
+    let mut configs = Vec::new();
+    let mut reader = Reader::new(&payload);
+    while !reader.done() {
+        match reader.field().ok_or(OtpError::InvalidUri)? {
+            (1, 2) => {
+                let buf = reader.bytes().ok_or(OtpError::InvalidUri)?;
+                let params = decodeparameters(buf).ok_or(OtpError::InvalidUri)?;
+                configs.push(toconfig(params));
+            }
+            (_, wire) => reader.skip(wire).ok_or(OtpError::InvalidUri)?,
+        }
+    }
+    Ok(configs)
+}
+
+#[derive(Debug, Default)]
+pub struct OtpImport {
+    pub configs: Vec<OtpConfig>,
+    pub invalid: Vec<String>,
+}
+
+pub fn parseimport(text: &str) -> OtpImport {
+    let mut import = OtpImport::default();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with(MIGRATION_SCHEME) {
+            match parsemigration(line) {
+                Ok(configs) => {
+                    for config in configs {
+                        match config {
+                            Ok(config) => import.configs.push(config),
+                            Err(_) => import.invalid.push(line.to_string()),
+                        }
+                    }
+                }
+                Err(_) => import.invalid.push(line.to_string()),
+            }
+            continue;
+        }
+        match otp::parseuri(line) {
+            Ok(config) => import.configs.push(config),
+            Err(_) => import.invalid.push(line.to_string()),
+        }
+    }
+    import
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OtpCandidate {
+    pub config: OtpConfig,
+    pub title: String,
+    pub data: String,
+    pub duplicate_of: Option<String>,
+}
+
+fn samesecret(a: &OtpConfig, b: &OtpConfig) -> bool {
+    a.kind == b.kind && a.secret == b.secret
+}
+
+pub fn candidates(configs: Vec<OtpConfig>, existing: &[(String, OtpConfig)]) -> Vec<OtpCandidate> {
+    let mut found: Vec<OtpCandidate> = Vec::new();
+    for config in configs {
+        if found.iter().any(|c| samesecret(&c.config, &config)) {
+            continue;
+        }
+        let duplicate_of = existing
+            .iter()
+            .find(|(_, e)| samesecret(e, &config))
+            .map(|(id, _)| id.clone());
+        found.push(OtpCandidate {
+            title: title(&config),
+            data: itemdata(&config),
+            config,
+            duplicate_of,
+        });
+    }
+    found
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct OtpImportPreview {
+    pub candidates: Vec<OtpCandidate>,
+    pub invalid: Vec<String>,
+}
+
+pub fn preview(text: &str, existing: &[(String, OtpConfig)]) -> OtpImportPreview {
+    let import = parseimport(text);
+    OtpImportPreview {
+        candidates: candidates(import.configs, existing),
+        invalid: import.invalid,
+    }
+}
+
+pub fn title(config: &OtpConfig) -> String {
+    config
+        .issuer
+        .clone()
+        .or_else(|| config.account.clone())
+        .unwrap_or_else(|| "One-time password".into())
+}
+
+#[derive(Serialize)]
+struct OtpData<'a> {
+    secret: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issuer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account: Option<&'a str>,
+    algorithm: &'static str,
+    digits: u32,
+    period: u64,
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counter: Option<u64>,
+}
+
+pub fn itemdata(config: &OtpConfig) -> String {
+    let data = OtpData {
+        secret: &config.secret,
+        issuer: config.issuer.as_deref(),
+        account: config.account.as_deref(),
+        algorithm: match config.algorithm {
+            Algorithm::Sha1 => "sha1",
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha512 => "sha512",
+        },
+        digits: config.digits,
+        period: config.period,
+        kind: match config.kind {
+            OtpKind::Totp => "totp",
+            OtpKind::Hotp => "hotp",
+            OtpKind::Steam => "steam",
+        },
+        counter: (config.kind == OtpKind::Hotp).then_some(config.counter),
+    };
+    serde_json::to_string(&data).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose::STANDARD;
+
+    const MIGRATION: &str = "otpauth-migration://offline?data=CjUKCkhlbGxvId6tvu8SGEV4YW1wbGU6YWxpY2VAZ29vZ2xlLmNvbRoHRXhhbXBsZSABKAEwAhABGAEgACjAxAc%3D";
+
+    fn varint(mut value: u64, out: &mut Vec<u8>) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn bytes(field: u64, value: &[u8], out: &mut Vec<u8>) {
+        varint(field << 3 | 2, out);
+        varint(value.len() as u64, out);
+        out.extend_from_slice(value);
+    }
+
+    fn number(field: u64, value: u64, out: &mut Vec<u8>) {
+        varint(field << 3, out);
+        varint(value, out);
+    }
+
+    struct Entry<'a> {
+        secret: &'a [u8],
+        name: &'a str,
+        issuer: &'a str,
+        algorithm: u64,
+        digits: u64,
+        kind: u64,
+        counter: u64,
+    }
+
+    impl Default for Entry<'_> {
+        fn default() -> Self {
+            Entry {
+                secret: b"Hello!",
+                name: "alice",
+                issuer: "",
+                algorithm: 1,
+                digits: 1,
+                kind: 2,
+                counter: 0,
+            }
+        }
+    }
+
+    fn migration(entries: &[Entry]) -> String {
+        let mut payload = Vec::new();
+        for entry in entries {
+            let mut params = Vec::new();
+            bytes(1, entry.secret, &mut params);
+            bytes(2, entry.name.as_bytes(), &mut params);
+            bytes(3, entry.issuer.as_bytes(), &mut params);
+            number(4, entry.algorithm, &mut params);
+            number(5, entry.digits, &mut params);
+            number(6, entry.kind, &mut params);
+            number(7, entry.counter, &mut params);
+            bytes(1, &params, &mut payload);
+        }
+        number(2, 1, &mut payload);
+        number(3, 1, &mut payload);
+        let data = STANDARD
+            .encode(payload)
+            .replace('+', "%2B")
+            .replace('/', "%2F")
+            .replace('=', "%3D");
+        format!("otpauth-migration://offline?data={data}")
+    }
+
+    #[test]
+    fn test_migration_google_export() {
+        let configs = parsemigration(MIGRATION).unwrap();
+        assert_eq!(configs.len(), 1);
+        let config = configs[0].as_ref().unwrap();
+        assert_eq!(config.secret, "JBSWY3DPEHPK3PXP");
+        assert_eq!(config.issuer.as_deref(), Some("Example"));
+        assert_eq!(config.account.as_deref(), Some("alice@google.com"));
+        assert_eq!(config.kind, OtpKind::Totp);
+        assert_eq!(config.digits, 6);
+    }
+
+    #[test]
+    fn test_migration_parameters() {
+        let uri = migration(&[
+            Entry {
+                name: "Acme:bob",
+                algorithm: 2,
+                digits: 2,
+                ..Default::default()
+            },
+            Entry {
+                issuer: "Bank",
+                algorithm: 3,
+                kind: 1,
+                counter: 7,
+                ..Default::default()
+            },
+        ]);
+        let configs: Vec<OtpConfig> = parsemigration(&uri)
+            .unwrap()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(configs[0].secret, "JBSWY3DPEE");
+        assert_eq!(configs[0].issuer.as_deref(), Some("Acme"));
+        assert_eq!(configs[0].account.as_deref(), Some("bob"));
+        assert_eq!(configs[0].algorithm, Algorithm::Sha256);
+        assert_eq!(configs[0].digits, 8);
+
+        assert_eq!(configs[1].issuer.as_deref(), Some("Bank"));
+        assert_eq!(configs[1].algorithm, Algorithm::Sha512);
+        assert_eq!(configs[1].kind, OtpKind::Hotp);
+        assert_eq!(configs[1].counter, 7);
+    }
+
+    #[test]
+    fn test_migration_invalid_entries() {
+        let uri = migration(&[
+            Entry {
+                algorithm: 4,
+                ..Default::default()
+            },
+            Entry {
+                secret: b"",
+                ..Default::default()
+            },
+            Entry::default(),
+        ]);
+        let configs = parsemigration(&uri).unwrap();
+        assert_eq!(configs[0], Err(OtpError::Unsupported));
+        assert_eq!(configs[1], Err(OtpError::InvalidSecret));
+        assert!(configs[2].is_ok());
+    }
+
+    #[test]
+    fn test_migration_unescaped_base64() {
+        let uri = migration(&[Entry {
+            secret: &[0xfb, 0xff, 0xbf],
+            ..Default::default()
+        }]);
+        assert!(uri.contains("%2B") || uri.contains("%2F"));
+        let raw = uri
+            .replace("%2B", "+")
+            .replace("%2F", "/")
+            .replace("%3D", "");
+        assert_eq!(
+            parsemigration(&raw).unwrap()[0].as_ref().unwrap().secret,
+            otp::base32encode(&[0xfb, 0xff, 0xbf])
+        );
+    }
+
+    #[test]
+    fn test_migration_rejects_malformed() {
+        for uri in [
+            "otpauth://totp/x?secret=JBSWY3DP",
+            "otpauth-migration://offline",
+            "otpauth-migration://offline?data=%%%",
+            "otpauth-migration://offline?data=CgUKAw",
+            "otpauth-migration://offline?data=Dw",
+            "not a uri",
+        ] {
+            assert_eq!(parsemigration(uri), Err(OtpError::InvalidUri), "{uri}");
+        }
+        assert_eq!(
+            parsemigration("otpauth-migration://offline?data=").unwrap(),
+            []
+        );
+    }
+
+    #[test]
+    fn test_parseimport_lines() {
+        let text = format!(
+            "{MIGRATION}\n\n# comment\n  otpauth://totp/Other:bob?secret=GEZDGNBV  \nnot a uri\notpauth-migration://offline?data=Dw\notpauth://totp/Dup?secret=jbswy3dpehpk3pxp"
+        );
+        let import = parseimport(&text);
+        assert_eq!(import.configs.len(), 3);
+        assert_eq!(
+            import.invalid,
+            ["not a uri", "otpauth-migration://offline?data=Dw"]
+        );
+        assert!(parseimport("").configs.is_empty());
+        assert!(parseimport("\n# only a comment\n").invalid.is_empty());
+    }
+
+    #[test]
+    fn test_candidates_skip_repeats_and_flag_existing() {
+        let import = parseimport(&format!(
+            "{MIGRATION}\notpauth://totp/Other:bob?secret=GEZDGNBV\notpauth://totp/Dup?secret=jbswy3dpehpk3pxp\notpauth://hotp/Counter?secret=JBSWY3DPEHPK3PXP&counter=1"
+        ));
+        let existing = vec![("item-1".to_string(), otp::parse("GEZDGNBV").unwrap())];
+        let found = candidates(import.configs, &existing);
+        let titles: Vec<&str> = found.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["Example", "Other", "Counter"]);
+        assert_eq!(found[0].duplicate_of, None);
+        assert_eq!(found[1].duplicate_of.as_deref(), Some("item-1"));
+        assert_eq!(found[2].duplicate_of, None);
+    }
+
+    #[test]
+    fn test_title_fallbacks() {
+        let mut config = otp::parse("JBSWY3DP").unwrap();
+        assert_eq!(title(&config), "One-time password");
+        config.account = Some("jane".into());
+        assert_eq!(title(&config), "jane");
+        config.issuer = Some("Acme".into());
+        assert_eq!(title(&config), "Acme");
+    }
+
+    #[test]
+    fn test_itemdata_roundtrip() {
+        for uri in [
+            MIGRATION.to_string(),
+            "otpauth://totp/x?secret=JBSWY3DP&algorithm=SHA512&digits=8&period=60".into(),
+            "otpauth://hotp/Acme:jane?secret=JBSWY3DP&counter=42".into(),
+            "otpauth://totp/Steam:jane?secret=JBSWY3DP&encoder=steam".into(),
+        ] {
+            for config in parseimport(&uri).configs {
+                let data = itemdata(&config);
+                assert_eq!(
+                    otp::fromdata(data.as_ref()).unwrap().unwrap(),
+                    config,
+                    "{uri}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+pub fn pool<T, U, F, P>(items: Vec<T>, workers: usize, work: F, progress: P) -> Vec<U>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> U + Sync,
+    P: Fn(usize, usize) + Sync,
+{
+    let total = items.len();
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(workers)
+        .min(total)
+        .max(1);
+    let queue: Mutex<Vec<(usize, T)>> = Mutex::new(items.into_iter().enumerate().rev().collect());
+    let results: Mutex<Vec<Option<U>>> = Mutex::new((0..total).map(|_| None).collect());
+    let done = AtomicUsize::new(0);
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().pop();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                let result = work(item);
+                results.lock().unwrap()[index] = Some(result);
+                progress(done.fetch_add(1, Ordering::Relaxed) + 1, total);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every item is processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_pool_preserves_order() {
+        let calls = AtomicUsize::new(0);
+        let out = pool(
+            (0..50).collect(),
+            4,
+            |n: u32| n * 2,
+            |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            },
+        );
+        assert_eq!(out, (0..50).map(|n| n * 2).collect::<Vec<_>>());
+        assert_eq!(calls.load(Ordering::Relaxed), 50);
+    }
+
+    #[test]
+    fn test_pool_caps_workers() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        pool(
+            (0..16).collect(),
+            2,
+            |_: u32| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(5));
+                running.fetch_sub(1, Ordering::SeqCst);
+            },
+            |_, _| {},
+        );
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[test]
+    fn test_pool_empty() {
+        let out: Vec<u32> = pool(Vec::new(), 0, |n: u32| n, |_, _| {});
+        assert!(out.is_empty());
+    }
+}
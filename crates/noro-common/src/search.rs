@@ -0,0 +1,539 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+const USERNAME_KEYS: &[&str] = &[
+    "username",
+    "email",
+    "account",
+    "issuer",
+    "holder",
+    "firstname",
+    "lastname",
+];
+const URL_KEYS: &[&str] = &["url", "urls", "endpoint", "origin", "rpid", "website"];
+const NOTES_KEYS: &[&str] = &["notes", "content"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title,
+    Username,
+    Url,
+    Tag,
+    Notes,
+    Custom,
+}
+
+impl Field {
+    const ALL: [Field; 6] = [
+        Field::Title,
+        Field::Username,
+        Field::Url,
+        Field::Tag,
+        Field::Notes,
+        Field::Custom,
+    ];
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    fn weight(self) -> u32 {
+        match self {
+            Field::Title => 8,
+            Field::Tag => 6,
+            Field::Username => 5,
+            Field::Url => 5,
+            Field::Custom => 3,
+            Field::Notes => 2,
+        }
+    }
+
+    fn parse(name: &str) -> Option<Field> {
+        match name {
+            "title" => Some(Field::Title),
+            "user" | "username" => Some(Field::Username),
+            "url" | "site" => Some(Field::Url),
+            "note" | "notes" => Some(Field::Notes),
+            "field" => Some(Field::Custom),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchDoc {
+    pub id: String,
+    pub item_type: String,
+    pub title: String,
+    pub usernames: Vec<String>,
+    pub urls: Vec<String>,
+    pub tags: Vec<String>,
+    pub notes: Vec<String>,
+    pub fields: Vec<String>,
+    pub favorite: bool,
+}
+
+impl SearchDoc {
+    pub fn new(
+        id: &str,
+        item_type: &str,
+        title: &str,
+        data: &[u8],
+        tags: &[String],
+        favorite: bool,
+    ) -> Self {
+        let mut doc = SearchDoc {
+            id: id.to_string(),
+            item_type: item_type.to_lowercase(),
+            title: title.to_string(),
+            tags: tags.to_vec(),
+            favorite,
+            ..Default::default()
+        };
+        if let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(data) {
+            for (key, value) in &map {
+                let key = key.to_lowercase();
+                if USERNAME_KEYS.contains(&key.as_str()) {
+                    strings(value, &mut doc.usernames);
+                } else if URL_KEYS.contains(&key.as_str()) {
+                    strings(value, &mut doc.urls);
+                } else if NOTES_KEYS.contains(&key.as_str()) {
+                    strings(value, &mut doc.notes);
+                } else if key == "fields" {
+                    fieldnames(value, &mut doc.fields);
+                }
+            }
+        }
+        doc
+    }
+
+    fn terms(&self) -> Vec<(String, u8)> {
+        let mut pairs: Vec<(String, Field)> = Vec::new();
+        let mut add = |text: &str, field: Field| {
+            pairs.extend(tokenize(text).into_iter().map(|t| (t, field)));
+        };
+        add(&self.title, Field::Title);
+        self.usernames.iter().for_each(|t| add(t, Field::Username));
+        self.tags.iter().for_each(|t| add(t, Field::Tag));
+        self.notes.iter().for_each(|t| add(t, Field::Notes));
+        self.fields.iter().for_each(|t| add(t, Field::Custom));
+        self.urls.iter().for_each(|t| add(t, Field::Url));
+        pairs.extend(
+            self.urls
+                .iter()
+                .filter_map(|u| host(u))
+                .map(|h| (h, Field::Url)),
+        );
+
+        let mut terms: HashMap<String, u8> = HashMap::new();
+        for (term, field) in pairs {
+            *terms.entry(term).or_default() |= field.bit();
+        }
+        terms.into_iter().collect()
+    }
+}
+
+fn strings(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) if !s.is_empty() => out.push(s.clone()),
+        Value::Array(values) => values.iter().for_each(|v| strings(v, out)),
+        _ => {}
+    }
+}
+
+fn fieldnames(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Array(fields) => {
+            for field in fields {
+                if let Some(name) = field
+                    .get("name")
+                    .or_else(|| field.get("label"))
+                    .and_then(Value::as_str)
+                {
+                    out.push(name.to_string());
+                }
+            }
+        }
+        Value::Object(map) => out.extend(map.keys().cloned()),
+        _ => {}
+    }
+}
+
+fn host(url: &str) -> Option<String> {
+    let rest = url.split_once("://").map_or(url, |(_, r)| r);
+    let host = rest.split(['/', '?', '#']).next()?;
+    let host = host.rsplit('@').next()?.split(':').next()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    (host.contains('.')).then(|| host.to_string())
+}
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn distance(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            row[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        if row.iter().min().is_some_and(|&m| m > max) {
+            return None;
+        }
+        prev = row;
+    }
+    let d = prev[b.len()];
+    (d <= max).then_some(d)
+}
+
+#[derive(Debug, Default)]
+pub struct Query {
+    pub terms: Vec<(Option<Field>, String)>,
+    pub tags: Vec<String>,
+    pub types: Vec<String>,
+    pub favorite: Option<bool>,
+}
+
+pub fn parsequery(query: &str) -> Query {
+    let mut parsed = Query::default();
+    for token in query.split_whitespace() {
+        let Some((key, value)) = token.split_once(':').filter(|(_, v)| !v.is_empty()) else {
+            parsed
+                .terms
+                .extend(tokenize(token).into_iter().map(|t| (None, t)));
+            continue;
+        };
+        let key = key.to_lowercase();
+        match key.as_str() {
+            "tag" => parsed.tags.push(value.to_lowercase()),
+            "type" => parsed.types.push(value.to_lowercase()),
+            "is" if matches!(value, "fav" | "favorite" | "starred") => parsed.favorite = Some(true),
+            _ => match Field::parse(&key) {
+                Some(field) => parsed
+                    .terms
+                    .extend(tokenize(value).into_iter().map(|t| (Some(field), t))),
+                None => parsed
+                    .terms
+                    .extend(tokenize(token).into_iter().map(|t| (None, t))),
+            },
+        }
+    }
+    parsed
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHit {
+    pub id: String,
+    pub score: u32,
+}
+
+#[derive(Debug)]
+struct Entry {
+    title: String,
+    item_type: String,
+    tags: Vec<String>,
+    favorite: bool,
+    terms: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    docs: HashMap<String, Entry>,
+    postings: BTreeMap<String, HashMap<String, u8>>,
+}
+
+impl SearchIndex {
+    pub fn build(docs: impl IntoIterator<Item = SearchDoc>) -> Self {
+        let mut index = SearchIndex::default();
+        for doc in docs {
+            index.upsert(doc);
+        }
+        index
+    }
+
+    pub fn upsert(&mut self, doc: SearchDoc) {
+        self.remove(&doc.id);
+        let terms = doc.terms();
+        for (term, fields) in &terms {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .insert(doc.id.clone(), *fields);
+        }
+        self.docs.insert(
+            doc.id,
+            Entry {
+                title: doc.title.to_lowercase(),
+                item_type: doc.item_type,
+                tags: doc.tags.iter().map(|t| t.to_lowercase()).collect(),
+                favorite: doc.favorite,
+                terms: terms.into_iter().map(|(t, _)| t).collect(),
+            },
+        );
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        let Some(entry) = self.docs.remove(id) else {
+            return;
+        };
+        for term in entry.terms {
+            if let Some(docs) = self.postings.get_mut(&term) {
+                docs.remove(id);
+                if docs.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+    }
+
+    fn score(fields: u8, only: Option<Field>, boost: u32) -> u32 {
+        Field::ALL
+            .iter()
+            .filter(|f| fields & f.bit() != 0 && only.map_or(true, |o| o == **f))
+            .map(|f| f.weight() * boost)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn collect<'a>(
+        scores: &mut HashMap<&'a str, u32>,
+        docs: &'a HashMap<String, u8>,
+        only: Option<Field>,
+        boost: u32,
+    ) {
+        for (id, fields) in docs {
+            let score = Self::score(*fields, only, boost);
+            if score > 0 {
+                let best = scores.entry(id.as_str()).or_default();
+                *best = (*best).max(score);
+            }
+        }
+    }
+
+    fn matchterm(&self, term: &str, only: Option<Field>) -> HashMap<&str, u32> {
+        let mut scores = HashMap::new();
+
+        for (candidate, docs) in self.postings.range(term.to_string()..) {
+            if !candidate.starts_with(term) {
+                break;
+            }
+            let boost = if candidate == term { 4 } else { 2 };
+            Self::collect(&mut scores, docs, only, boost);
+        }
+
+        let len = term.chars().count();
+        if len >= 4 {
+            let max = if len <= 6 { 1 } else { 2 };
+            for (candidate, docs) in &self.postings {
+                if !candidate.starts_with(term) && distance(term, candidate, max).is_some() {
+                    Self::collect(&mut scores, docs, only, 1);
+                }
+            }
+        }
+
+        scores
+    }
+
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let query = parsequery(query);
+
+        let mut scores: Option<HashMap<&str, u32>> = None;
+        for (field, term) in &query.terms {
+            let matched = self.matchterm(term, *field);
+            scores = Some(match scores {
+                None => matched,
+                Some(previous) => previous
+                    .into_iter()
+                    .filter_map(|(id, s)| matched.get(id).map(|m| (id, s + m)))
+                    .collect(),
+            });
+        }
+        let scores =
+            scores.unwrap_or_else(|| self.docs.keys().map(|id| (id.as_str(), 0)).collect());
+
+        let mut hits: Vec<(&str, u32, &Entry)> = scores
+            .into_iter()
+            .filter_map(|(id, score)| self.docs.get(id).map(|e| (id, score, e)))
+            .filter(|(_, _, e)| query.tags.iter().all(|t| e.tags.contains(t)))
+            .filter(|(_, _, e)| query.types.is_empty() || query.types.contains(&e.item_type))
+            .filter(|(_, _, e)| query.favorite.map_or(true, |f| e.favorite == f))
+            .collect();
+        hits.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then(b.2.favorite.cmp(&a.2.favorite))
+                .then(a.2.title.cmp(&b.2.title))
+        });
+
+        hits.into_iter()
+            .take(limit)
+            .map(|(id, score, _)| SearchHit {
+                id: id.to_string(),
+                score,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(id: &str, item_type: &str, title: &str, data: &str, tags: &[&str]) -> SearchDoc {
+        let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+        SearchDoc::new(id, item_type, title, data.as_bytes(), &tags, false)
+    }
+
+    fn index() -> SearchIndex {
+        SearchIndex::build([
+            doc(
+                "gh",
+                "login",
+                "GitHub",
+                r#"{"username":"octocat","password":"hunter2","url":"https://www.github.com/login"}"#,
+                &["work"],
+            ),
+            doc(
+                "gl",
+                "login",
+                "GitLab",
+                r#"{"username":"tanuki","url":"https://gitlab.com","fields":[{"name":"Recovery codes","value":"x"}]}"#,
+                &["personal"],
+            ),
+            doc(
+                "wifi",
+                "note",
+                "Home wifi",
+                r#"{"content":"router in the hallway"}"#,
+                &["home"],
+            ),
+        ])
+    }
+
+    fn ids(hits: Vec<SearchHit>) -> Vec<String> {
+        hits.into_iter().map(|h| h.id).collect()
+    }
+
+    #[test]
+    fn test_search_prefix() {
+        assert_eq!(ids(index().search("git", 10)), ["gh", "gl"]);
+        assert_eq!(ids(index().search("GITHUB", 10)), ["gh"]);
+    }
+
+    #[test]
+    fn test_search_typo() {
+        let index = index();
+        assert_eq!(ids(index.search("githib", 10)), ["gh"]);
+        assert!(index.search("gxtxub", 10).is_empty());
+        assert!(index.search("gti", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_url_host() {
+        let index = index();
+        assert_eq!(ids(index.search("github.com", 10)), ["gh"]);
+        assert_eq!(ids(index.search("url:gitlab", 10)), ["gl"]);
+    }
+
+    #[test]
+    fn test_search_filters() {
+        let index = index();
+        assert_eq!(ids(index.search("tag:work type:login", 10)), ["gh"]);
+        assert_eq!(ids(index.search("type:note", 10)), ["wifi"]);
+        assert!(index.search("tag:work type:note", 10).is_empty());
+        assert!(index.search("is:favorite", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_fields_and_notes() {
+        let index = index();
+        assert_eq!(ids(index.search("recovery", 10)), ["gl"]);
+        assert_eq!(ids(index.search("hallway", 10)), ["wifi"]);
+        assert!(index.search("title:octocat", 10).is_empty());
+        assert_eq!(ids(index.search("user:octocat", 10)), ["gh"]);
+    }
+
+    #[test]
+    fn test_search_skips_secrets() {
+        assert!(index().search("hunter2", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_all_terms_required() {
+        let index = index();
+        assert_eq!(ids(index.search("git octocat", 10)), ["gh"]);
+        assert!(index.search("git hallway", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_empty_query() {
+        let index = index();
+        assert_eq!(index.search("", 10).len(), 3);
+        assert_eq!(index.search("   ", 10).len(), 3);
+        assert_eq!(index.search("!!", 10).len(), 3);
+        assert_eq!(index.search("", 2).len(), 2);
+        assert!(index.search("", 0).is_empty());
+        assert!(SearchIndex::default().search("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_favorites_first() {
+        let tags: Vec<String> = vec![];
+        let index = SearchIndex::build([
+            SearchDoc::new("a", "login", "Bank", b"{}", &tags, false),
+            SearchDoc::new("b", "login", "Bank", b"{}", &tags, true),
+        ]);
+        assert_eq!(ids(index.search("bank", 10)), ["b", "a"]);
+        assert_eq!(ids(index.search("is:fav", 10)), ["b"]);
+    }
+
+    #[test]
+    fn test_remove_and_upsert() {
+        let mut index = index();
+        index.remove("gh");
+        index.remove("missing");
+        assert_eq!(ids(index.search("git", 10)), ["gl"]);
+        index.upsert(doc("gl", "login", "Codeberg", "{}", &[]));
+        assert!(index.search("gitlab", 10).is_empty());
+        assert!(index.search("tanuki", 10).is_empty());
+        assert_eq!(ids(index.search("codeberg", 10)), ["gl"]);
+        assert_eq!(index.search("", 10).len(), 2);
+    }
+
+    #[test]
+    fn test_parsequery() {
+        let query = parsequery("tag:Work TYPE:login is:starred title:git hub tag: nope:x");
+        assert_eq!(query.tags, ["work"]);
+        assert_eq!(query.types, ["login"]);
+        assert_eq!(query.favorite, Some(true));
+        assert_eq!(
+            query.terms,
+            [
+                (Some(Field::Title), "git".to_string()),
+                (None, "hub".to_string()),
+                (None, "tag".to_string()),
+                (None, "nope".to_string()),
+                (None, "x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("github", "github", 1), Some(0));
+        assert_eq!(distance("githib", "github", 1), Some(1));
+        assert_eq!(distance("gthb", "github", 1), None);
+        assert_eq!(distance("", "ab", 2), Some(2));
+    }
+}
@@ -0,0 +1,124 @@
+#[derive(Debug, Default)]
+pub struct EventParser {
+    buf: Vec<u8>,
+    event: String,
+    data: String,
+}
+
+impl EventParser {
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<(String, String)> {
+        self.buf.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(pos) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    let event = if self.event.is_empty() {
+                        "message".to_string()
+                    } else {
+                        std::mem::take(&mut self.event)
+                    };
+                    let mut data = std::mem::take(&mut self.data);
+                    data.pop();
+                    events.push((event, data));
+                }
+                self.event.clear();
+                continue;
+            }
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = match line.split_once(':') {
+                Some((f, v)) => (f, v.strip_prefix(' ').unwrap_or(v)),
+                None => (line, ""),
+            };
+            match field {
+                "event" => self.event = value.to_string(),
+                "data" => {
+                    self.data.push_str(value);
+                    self.data.push('\n');
+                }
+                _ => {}
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str, data: &str) -> (String, String) {
+        (name.to_string(), data.to_string())
+    }
+
+    #[test]
+    fn test_parse_split_chunks() {
+        let mut parser = EventParser::default();
+        let events = parser.feed(b"event: ready\ndata: {\"revision\":1}\n\n: ping\n\nevent: cha");
+        assert_eq!(events, [event("ready", "{\"revision\":1}")]);
+        assert!(parser.feed(b"nge\nda").is_empty());
+        assert!(parser.feed(b"ta: {\"items\":[]}\r").is_empty());
+        let events = parser.feed(b"\n\r\n");
+        assert_eq!(events, [event("change", "{\"items\":[]}")]);
+    }
+
+    #[test]
+    fn test_parse_byte_at_a_time() {
+        let mut parser = EventParser::default();
+        let input = "event: change\ndata: caf\u{e9}\n\n".as_bytes();
+        let events: Vec<_> = input.iter().flat_map(|b| parser.feed(&[*b])).collect();
+        assert_eq!(events, [event("change", "caf\u{e9}")]);
+    }
+
+    #[test]
+    fn test_parse_multiline_data() {
+        let mut parser = EventParser::default();
+        let events = parser.feed(b"data: {\"items\":\ndata:[]}\ndata\n\n");
+        assert_eq!(events, [event("message", "{\"items\":\n[]}\n")]);
+    }
+
+    #[test]
+    fn test_parse_empty_data() {
+        let mut parser = EventParser::default();
+        let events = parser.feed(b"data:\n\nevent: ping\ndata\n\ndata:\ndata: x\n\n");
+        assert_eq!(
+            events,
+            [
+                event("message", ""),
+                event("ping", ""),
+                event("message", "\nx")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_several_events_in_one_chunk() {
+        let mut parser = EventParser::default();
+        let events = parser.feed(b"event: a\ndata: 1\n\nevent: b\ndata: 2\n\ndata: 3\n\n");
+        assert_eq!(
+            events,
+            [event("a", "1"), event("b", "2"), event("message", "3")]
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_comments_and_empty_events() {
+        let mut parser = EventParser::default();
+        let events = parser.feed(b": ping\n\nevent: change\n\nid: 7\nretry: 10\ndata: x\n\n");
+        assert_eq!(events, [event("message", "x")]);
+    }
+
+    #[test]
+    fn test_parse_keeps_incomplete_event() {
+        let mut parser = EventParser::default();
+        assert!(parser.feed(b"event: change\ndata: x\n").is_empty());
+        assert!(parser.feed(b"").is_empty());
+        assert_eq!(parser.feed(b"\n"), [event("change", "x")]);
+    }
+}
@@ -0,0 +1,1261 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+use crate::generator;
+use crate::time::now;
+
+const PASSWORDS: &str = include_str!("../data/passwords.txt");
+const MAX_ANALYZED: usize = 100;
+const MAX_WORD: usize = 24;
+const MAX_SUBSTITUTIONS: usize = 64;
+const MAX_GUESSES: f64 = 1e300;
+const ENGLISH_RANK: usize = 2000;
+const BRUTEFORCE_CARDINALITY: f64 = 10.0;
+const MIN_GUESSES_BEFORE_GROWING_SEQUENCE: f64 = 10000.0;
+const MIN_SUBMATCH_GUESSES_SINGLE_CHAR: f64 = 10.0;
+const MIN_SUBMATCH_GUESSES_MULTI_CHAR: f64 = 50.0;
+const MIN_YEAR_SPACE: f64 = 20.0;
+const SEQUENCE_MAX_DELTA: i64 = 5;
+const DATE_SEPARATORS: &str = " /\\_.-";
+
+const QWERTY: [&str; 4] = [
+    "`~ 1! 2@ 3# 4$ 5% 6^ 7& 8* 9( 0) -_ =+",
+    "qQ wW eE rR tT yY uU iI oO pP [{ ]} \\|",
+    "aA sS dD fF gG hH jJ kK lL ;: '\"",
+    "zZ xX cC vV bB nN mM ,< .> /?",
+];
+const KEYPAD: [(char, i32, i32); 17] = [
+    ('/', 1, 0),
+    ('*', 2, 0),
+    ('-', 3, 0),
+    ('7', 0, 1),
+    ('8', 1, 1),
+    ('9', 2, 1),
+    ('+', 3, 1),
+    ('4', 0, 2),
+    ('5', 1, 2),
+    ('6', 2, 2),
+    ('1', 0, 3),
+    ('2', 1, 3),
+    ('3', 2, 3),
+    ('0', 1, 4),
+    ('.', 2, 4),
+    ('=', 0, 0),
+    ('#', 4, 0),
+];
+const SLANTED: [(i32, i32); 6] = [(-1, 0), (0, -1), (1, -1), (1, 0), (0, 1), (-1, 1)];
+const ALIGNED: [(i32, i32); 8] = [
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+const L33T: [(char, &str); 12] = [
+    ('a', "4@"),
+    ('b', "8"),
+    ('c', "({[<"),
+    ('e', "3"),
+    ('g', "69"),
+    ('i', "1!|"),
+    ('l', "1|7"),
+    ('o', "0"),
+    ('s', "$5"),
+    ('t', "+7"),
+    ('x', "%"),
+    ('z', "2"),
+];
+
+static RANKS: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+static ENGLISH: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+static GRAPHS: OnceLock<[Graph; 2]> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CrackTime {
+    pub scenario: String,
+    pub seconds: f64,
+    pub display: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PatternMatch {
+    pub pattern: String,
+    pub token: String,
+    pub guesses: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Estimate {
+    pub guesses: f64,
+    pub guesses_log10: f64,
+    pub score: u8,
+    pub strength: String,
+    pub crack_times: Vec<CrackTime>,
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+    pub sequence: Vec<PatternMatch>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Pattern {
+    Dictionary {
+        dictionary: &'static str,
+        rank: usize,
+        reversed: bool,
+        subs: Vec<(char, char)>,
+    },
+    Spatial {
+        graph: usize,
+        turns: usize,
+        shifted: usize,
+    },
+    Repeat {
+        base: String,
+        count: usize,
+    },
+    Sequence {
+        ascending: bool,
+    },
+    Year,
+    Date {
+        separator: bool,
+    },
+    Bruteforce,
+}
+
+#[derive(Debug, Clone)]
+struct Match {
+    i: usize,
+    j: usize,
+    token: Vec<char>,
+    pattern: Pattern,
+    guesses: f64,
+}
+
+struct Graph {
+    name: &'static str,
+    keys: HashMap<char, (i32, i32, bool)>,
+    grid: HashMap<(i32, i32), Vec<char>>,
+    directions: &'static [(i32, i32)],
+    degree: f64,
+}
+
+impl Graph {
+    fn build(
+        name: &'static str,
+        keys: Vec<(Vec<char>, i32, i32)>,
+        directions: &'static [(i32, i32)],
+    ) -> Self {
+        let mut graph = Graph {
+            name,
+            keys: HashMap::new(),
+            grid: HashMap::new(),
+            directions,
+            degree: 0.0,
+        };
+        for (chars, x, y) in keys {
+            for (n, c) in chars.iter().enumerate() {
+                graph.keys.insert(*c, (x, y, n > 0));
+            }
+            graph.grid.insert((x, y), chars);
+        }
+        let edges: usize = graph
+            .grid
+            .keys()
+            .map(|(x, y)| {
+                directions
+                    .iter()
+                    .filter(|(dx, dy)| graph.grid.contains_key(&(x + dx, y + dy)))
+                    .count()
+            })
+            .sum();
+        graph.degree = edges as f64 / graph.grid.len() as f64;
+        graph
+    }
+
+    fn direction(&self, from: char, to: char) -> Option<usize> {
+        let (x, y, _) = self.keys.get(&from)?;
+        self.directions.iter().position(|(dx, dy)| {
+            self.grid
+                .get(&(x + dx, y + dy))
+                .is_some_and(|chars| chars.contains(&to))
+        })
+    }
+
+    fn shifted(&self, c: char) -> bool {
+        self.keys.get(&c).is_some_and(|(_, _, shifted)| *shifted)
+    }
+}
+
+fn graphs() -> &'static [Graph; 2] {
+    GRAPHS.get_or_init(|| {
+        let qwerty = QWERTY
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.split(' ').enumerate().map(move |(x, key)| {
+                    let offset = if y > 0 { 1 } else { 0 };
+                    (key.chars().collect(), x as i32 + offset, y as i32)
+                })
+            })
+            .collect();
+        let keypad = KEYPAD.iter().map(|(c, x, y)| (vec![*c], *x, *y)).collect();
+        [
+            Graph::build("qwerty", qwerty, &SLANTED),
+            Graph::build("keypad", keypad, &ALIGNED),
+        ]
+    })
+}
+
+fn ranks() -> &'static HashMap<&'static str, usize> {
+    RANKS.get_or_init(|| {
+        let mut ranks = HashMap::new();
+        for (n, word) in PASSWORDS.lines().filter(|w| !w.is_empty()).enumerate() {
+            ranks.entry(word).or_insert(n + 1);
+        }
+        ranks
+    })
+}
+
+fn english() -> &'static HashMap<&'static str, usize> {
+    ENGLISH.get_or_init(|| {
+        generator::wordlist()
+            .iter()
+            .map(|w| (*w, ENGLISH_RANK))
+            .collect()
+    })
+}
+
+fn referenceyear() -> i64 {
+    1970 + (now() / 31_556_952) as i64
+}
+
+fn choose(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (1..=k).fold(1.0, |acc, i| acc * (n + 1 - i) as f64 / i as f64)
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).fold(1.0, |acc, i| acc * i as f64)
+}
+
+fn lower(chars: &[char]) -> Vec<char> {
+    chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect()
+}
+
+fn lookup(word: &str, inputs: &HashMap<String, usize>) -> Vec<(&'static str, usize)> {
+    let mut found = Vec::new();
+    if let Some(rank) = ranks().get(word) {
+        found.push(("passwords", *rank));
+    }
+    if let Some(rank) = english().get(word) {
+        found.push(("english", *rank));
+    }
+    if let Some(rank) = inputs.get(word) {
+        found.push(("user_inputs", *rank));
+    }
+    found
+}
+
+fn dictionarymatch(
+    chars: &[char],
+    original: &[char],
+    inputs: &HashMap<String, usize>,
+) -> Vec<Match> {
+    let folded = lower(chars);
+    let mut matches = Vec::new();
+    for i in 0..folded.len() {
+        for j in (i + 2)..folded.len().min(i + MAX_WORD) {
+            let word: String = folded[i..=j].iter().collect();
+            for (dictionary, rank) in lookup(&word, inputs) {
+                matches.push(Match {
+                    i,
+                    j,
+                    token: original[i..=j].to_vec(),
+                    pattern: Pattern::Dictionary {
+                        dictionary,
+                        rank,
+                        reversed: false,
+                        subs: Vec::new(),
+                    },
+                    guesses: 0.0,
+                });
+            }
+        }
+    }
+    matches
+}
+
+fn reversedmatch(chars: &[char], inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let n = chars.len();
+    let reversed: Vec<char> = chars.iter().rev().copied().collect();
+    dictionarymatch(&reversed, &reversed, inputs)
+        .into_iter()
+        .filter_map(|mut m| {
+            let (i, j) = (n - 1 - m.j, n - 1 - m.i);
+            if let Pattern::Dictionary { reversed, .. } = &mut m.pattern {
+                *reversed = true;
+            }
+            let token: Vec<char> = chars[i..=j].to_vec();
+            (token.iter().rev().ne(token.iter())).then_some(Match { i, j, token, ..m })
+        })
+        .collect()
+}
+
+fn substitutions(chars: &[char]) -> Vec<Vec<(char, char)>> {
+    let mut options: Vec<(char, Vec<char>)> = Vec::new();
+    for c in chars {
+        if options.iter().any(|(s, _)| s == c) {
+            continue;
+        }
+        let letters: Vec<char> = L33T
+            .iter()
+            .filter(|(_, subs)| subs.contains(*c))
+            .map(|(letter, _)| *letter)
+            .collect();
+        if !letters.is_empty() {
+            options.push((*c, letters));
+        }
+    }
+    let mut tables: Vec<Vec<(char, char)>> = vec![Vec::new()];
+    for (sub, letters) in options {
+        let mut next = Vec::new();
+        for table in &tables {
+            for letter in &letters {
+                let mut extended = table.clone();
+                extended.push((sub, *letter));
+                next.push(extended);
+            }
+        }
+        next.truncate(MAX_SUBSTITUTIONS);
+        tables = next;
+    }
+    tables.retain(|t| !t.is_empty());
+    tables
+}
+
+fn l33tmatch(chars: &[char], inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let mut matches: Vec<Match> = Vec::new();
+    for table in substitutions(chars) {
+        let translated: Vec<char> = chars
+            .iter()
+            .map(|c| {
+                table
+                    .iter()
+                    .find(|(s, _)| s == c)
+                    .map(|(_, l)| *l)
+                    .unwrap_or(*c)
+            })
+            .collect();
+        for mut m in dictionarymatch(&translated, chars, inputs) {
+            let used: Vec<(char, char)> = table
+                .iter()
+                .filter(|(s, _)| m.token.contains(s))
+                .copied()
+                .collect();
+            if used.is_empty() {
+                continue;
+            }
+            if let Pattern::Dictionary { subs, .. } = &mut m.pattern {
+                *subs = used;
+            }
+            if !matches
+                .iter()
+                .any(|e| e.i == m.i && e.j == m.j && e.pattern == m.pattern)
+            {
+                matches.push(m);
+            }
+        }
+    }
+    matches
+}
+
+fn spatialmatch(chars: &[char]) -> Vec<Match> {
+    let mut matches = Vec::new();
+    for (index, graph) in graphs().iter().enumerate() {
+        let mut i = 0;
+        while i + 1 < chars.len() {
+            let mut j = i + 1;
+            let mut last = None;
+            let mut turns = 0;
+            let mut shifted = usize::from(index == 0 && graph.shifted(chars[i]));
+            loop {
+                let direction = if j < chars.len() {
+                    graph.direction(chars[j - 1], chars[j])
+                } else {
+                    None
+                };
+                match direction {
+                    Some(d) => {
+                        if graph.shifted(chars[j]) {
+                            shifted += 1;
+                        }
+                        if last != Some(d) {
+                            turns += 1;
+                            last = Some(d);
+                        }
+                        j += 1;
+                    }
+                    None => {
+                        if j - i > 2 {
+                            matches.push(Match {
+                                i,
+                                j: j - 1,
+                                token: chars[i..j].to_vec(),
+                                pattern: Pattern::Spatial {
+                                    graph: index,
+                                    turns,
+                                    shifted,
+                                },
+                                guesses: 0.0,
+                            });
+                        }
+                        i = j;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    matches
+}
+
+fn repeatmatch(chars: &[char], inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let mut best: Option<(usize, usize)> = None;
+        for size in 1..=(chars.len() - i) / 2 {
+            let base = &chars[i..i + size];
+            let mut end = i + size;
+            while end + size <= chars.len() && &chars[end..end + size] == base {
+                end += size;
+            }
+            if end - i >= 2 * size && end > best.map_or(0, |(e, _)| e) {
+                best = Some((end, size));
+            }
+        }
+        match best {
+            Some((end, size)) => {
+                let base = &chars[i..i + size];
+                let guesses = mostguessable(base, &omnimatch(base, inputs), true).0;
+                matches.push(Match {
+                    i,
+                    j: end - 1,
+                    token: chars[i..end].to_vec(),
+                    pattern: Pattern::Repeat {
+                        base: base.iter().collect(),
+                        count: (end - i) / size,
+                    },
+                    guesses: guesses * ((end - i) / size) as f64,
+                });
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    matches
+}
+
+fn sequencematch(chars: &[char]) -> Vec<Match> {
+    let mut matches = Vec::new();
+    if chars.len() < 2 {
+        return matches;
+    }
+    let mut push = |i: usize, j: usize, delta: i64| {
+        if (j - i > 1 || delta.abs() == 1) && delta != 0 && delta.abs() <= SEQUENCE_MAX_DELTA {
+            matches.push(Match {
+                i,
+                j,
+                token: chars[i..=j].to_vec(),
+                pattern: Pattern::Sequence {
+                    ascending: delta > 0,
+                },
+                guesses: 0.0,
+            });
+        }
+    };
+    let mut i = 0;
+    let mut last: Option<i64> = None;
+    for k in 1..chars.len() {
+        let delta = chars[k] as i64 - chars[k - 1] as i64;
+        let previous = *last.get_or_insert(delta);
+        if delta == previous {
+            continue;
+        }
+        push(i, k - 1, previous);
+        i = k - 1;
+        last = Some(delta);
+    }
+    if let Some(delta) = last {
+        push(i, chars.len() - 1, delta);
+    }
+    matches
+}
+
+fn number(chars: &[char]) -> Option<i64> {
+    chars.iter().collect::<String>().parse().ok()
+}
+
+fn dmy(parts: [&[char]; 3], now: i64) -> Option<i64> {
+    let values = [number(parts[0])?, number(parts[1])?, number(parts[2])?];
+    let valid = |day: i64, month: i64| (1..=31).contains(&day) && (1..=12).contains(&month);
+    let mut best: Option<i64> = None;
+    for (year, rest) in [(0, [1, 2]), (2, [0, 1])] {
+        let raw = values[year];
+        let year = match parts[year].len() {
+            4 if (1000..=2050).contains(&raw) => raw,
+            2 | 1 if raw > 50 => 1900 + raw,
+            2 | 1 => 2000 + raw,
+            _ => continue,
+        };
+        let (a, b) = (values[rest[0]], values[rest[1]]);
+        if (valid(a, b) || valid(b, a))
+            && (year - now).abs() < best.map_or(i64::MAX, |y| (y - now).abs())
+        {
+            best = Some(year);
+        }
+    }
+    best
+}
+
+fn datematch(chars: &[char], now: i64) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let n = chars.len();
+    for i in 0..n {
+        for j in (i + 3)..n.min(i + 10) {
+            let token = &chars[i..=j];
+            let mut found: Option<(i64, bool)> = None;
+            if token.len() <= 8 && token.iter().all(|c| c.is_ascii_digit()) {
+                for a in 1..=4.min(token.len() - 2) {
+                    for b in 1..=4.min(token.len() - a - 1) {
+                        let c = token.len() - a - b;
+                        if c == 0 || c > 4 {
+                            continue;
+                        }
+                        let parts = [&token[..a], &token[a..a + b], &token[a + b..]];
+                        if let Some(year) = dmy(parts, now) {
+                            if (year - now).abs() < found.map_or(i64::MAX, |(y, _)| (y - now).abs())
+                            {
+                                found = Some((year, false));
+                            }
+                        }
+                    }
+                }
+            } else if token.len() >= 6 {
+                let separators: Vec<usize> = token
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| !c.is_ascii_digit())
+                    .map(|(k, _)| k)
+                    .collect();
+                if let [s1, s2] = separators[..] {
+                    let separator = token[s1];
+                    if DATE_SEPARATORS.contains(separator)
+                        && token[s2] == separator
+                        && s1 > 0
+                        && s2 > s1 + 1
+                        && s2 + 1 < token.len()
+                        && s2 - s1 <= 3
+                    {
+                        let parts = [&token[..s1], &token[s1 + 1..s2], &token[s2 + 1..]];
+                        if parts[0].len() <= 4 && parts[2].len() <= 4 {
+                            found = dmy(parts, now).map(|y| (y, true));
+                        }
+                    }
+                }
+            }
+            if let Some((year, separator)) = found {
+                matches.push(Match {
+                    i,
+                    j,
+                    token: token.to_vec(),
+                    pattern: Pattern::Date { separator },
+                    guesses: ((year - now).abs() as f64).max(MIN_YEAR_SPACE) * 365.0,
+                });
+            }
+        }
+    }
+    matches
+}
+
+fn yearmatch(chars: &[char], now: i64) -> Vec<Match> {
+    chars
+        .windows(4)
+        .enumerate()
+        .filter(|(_, w)| {
+            w.iter().all(|c| c.is_ascii_digit()) && matches!(w[..2], ['1', '9'] | ['2', '0'])
+        })
+        .filter_map(|(i, w)| {
+            let year = number(w)?;
+            Some(Match {
+                i,
+                j: i + 3,
+                token: w.to_vec(),
+                pattern: Pattern::Year,
+                guesses: ((year - now).abs() as f64).max(MIN_YEAR_SPACE),
+            })
+        })
+        .collect()
+}
+
+fn omnimatch(chars: &[char], inputs: &HashMap<String, usize>) -> Vec<Match> {
+    let now = referenceyear();
+    let mut matches = dictionarymatch(chars, chars, inputs);
+    matches.extend(reversedmatch(chars, inputs));
+    matches.extend(l33tmatch(chars, inputs));
+    matches.extend(spatialmatch(chars));
+    matches.extend(repeatmatch(chars, inputs));
+    matches.extend(sequencematch(chars));
+    matches.extend(yearmatch(chars, now));
+    matches.extend(datematch(chars, now));
+    matches.sort_by_key(|m| (m.i, m.j));
+    matches
+}
+
+fn uppercasevariations(token: &[char]) -> f64 {
+    let upper = token.iter().filter(|c| c.is_uppercase()).count();
+    let lower = token.iter().filter(|c| c.is_lowercase()).count();
+    if upper == 0 {
+        return 1.0;
+    }
+    let first = token[0].is_uppercase() && upper == 1;
+    let last = token[token.len() - 1].is_uppercase() && upper == 1;
+    if first || last || lower == 0 {
+        return 2.0;
+    }
+    (1..=upper.min(lower))
+        .map(|k| choose(upper + lower, k))
+        .sum()
+}
+
+fn l33tvariations(token: &[char], subs: &[(char, char)]) -> f64 {
+    let folded = lower(token);
+    subs.iter().fold(1.0, |acc, (sub, letter)| {
+        let s = folded.iter().filter(|c| *c == sub).count();
+        let u = folded.iter().filter(|c| *c == letter).count();
+        if s == 0 || u == 0 {
+            acc * 2.0
+        } else {
+            acc * (1..=s.min(u)).map(|k| choose(s + u, k)).sum::<f64>()
+        }
+    })
+}
+
+fn spatialguesses(graph: &Graph, length: usize, turns: usize, shifted: usize) -> f64 {
+    let start = graph.grid.len() as f64;
+    let mut guesses = 0.0;
+    for i in 2..=length {
+        for j in 1..=turns.min(i - 1) {
+            guesses += choose(i - 1, j - 1) * start * graph.degree.powi(j as i32);
+        }
+    }
+    if shifted > 0 {
+        let unshifted = length - shifted;
+        guesses *= if unshifted == 0 {
+            2.0
+        } else {
+            (1..=shifted.min(unshifted))
+                .map(|k| choose(shifted + unshifted, k))
+                .sum()
+        };
+    }
+    guesses
+}
+
+fn guesses(m: &Match, total: usize) -> f64 {
+    let floor = if m.token.len() == total {
+        1.0
+    } else if m.token.len() == 1 {
+        MIN_SUBMATCH_GUESSES_SINGLE_CHAR
+    } else {
+        MIN_SUBMATCH_GUESSES_MULTI_CHAR
+    };
+    let raw = match &m.pattern {
+        Pattern::Dictionary {
+            rank,
+            reversed,
+            subs,
+            ..
+        } => {
+            let reversal = if *reversed { 2.0 } else { 1.0 };
+            *rank as f64 * uppercasevariations(&m.token) * l33tvariations(&m.token, subs) * reversal
+        }
+        Pattern::Spatial {
+            graph,
+            turns,
+            shifted,
+        } => spatialguesses(&graphs()[*graph], m.token.len(), *turns, *shifted),
+        Pattern::Sequence { ascending } => {
+            let first = m.token[0];
+            let base = if "aAzZ019".contains(first) {
+                4.0
+            } else if first.is_ascii_digit() {
+                10.0
+            } else {
+                26.0
+            };
+            let direction = if *ascending { 1.0 } else { 2.0 };
+            base * direction * m.token.len() as f64
+        }
+        Pattern::Bruteforce => {
+            let minimum = if m.token.len() == 1 {
+                MIN_SUBMATCH_GUESSES_SINGLE_CHAR + 1.0
+            } else {
+                MIN_SUBMATCH_GUESSES_MULTI_CHAR + 1.0
+            };
+            BRUTEFORCE_CARDINALITY
+                .powi(m.token.len() as i32)
+                .max(minimum)
+        }
+        Pattern::Repeat { .. } | Pattern::Year => m.guesses,
+        Pattern::Date { separator } => m.guesses * if *separator { 4.0 } else { 1.0 },
+    };
+    raw.max(floor).min(MAX_GUESSES)
+}
+
+#[derive(Default, Clone)]
+struct Slot {
+    matches: BTreeMap<usize, Match>,
+    products: BTreeMap<usize, f64>,
+    totals: BTreeMap<usize, f64>,
+}
+
+fn mostguessable(chars: &[char], matches: &[Match], additive: bool) -> (f64, Vec<Match>) {
+    let n = chars.len();
+    if n == 0 {
+        return (1.0, Vec::new());
+    }
+    let mut slots: Vec<Slot> = vec![Slot::default(); n];
+    let mut byend: Vec<Vec<Match>> = vec![Vec::new(); n];
+    for m in matches {
+        let mut m = m.clone();
+        m.guesses = guesses(&m, n);
+        byend[m.j].push(m);
+    }
+
+    let update = |slots: &mut Vec<Slot>, m: &Match, l: usize| {
+        let k = m.j;
+        let mut product = m.guesses;
+        if l > 1 {
+            match slots[m.i - 1].products.get(&(l - 1)) {
+                Some(previous) => product *= previous,
+                None => return,
+            }
+        }
+        let mut total = factorial(l) * product;
+        if !additive {
+            total += MIN_GUESSES_BEFORE_GROWING_SEQUENCE.powi(l as i32 - 1);
+        }
+        if slots[k]
+            .totals
+            .iter()
+            .any(|(competing, g)| *competing <= l && *g <= total)
+        {
+            return;
+        }
+        slots[k].matches.insert(l, m.clone());
+        slots[k].products.insert(l, product);
+        slots[k].totals.insert(l, total);
+    };
+    let bruteforce = |i: usize, j: usize| {
+        let mut m = Match {
+            i,
+            j,
+            token: chars[i..=j].to_vec(),
+            pattern: Pattern::Bruteforce,
+            guesses: 0.0,
+        };
+        m.guesses = guesses(&m, n);
+        m
+    };
+
+    for (k, ending) in byend.iter().enumerate() {
+        for m in ending {
+            if m.i > 0 {
+                let lengths: Vec<usize> = slots[m.i - 1].matches.keys().copied().collect();
+                for l in lengths {
+                    update(&mut slots, m, l + 1);
+                }
+            } else {
+                update(&mut slots, m, 1);
+            }
+        }
+        update(&mut slots, &bruteforce(0, k), 1);
+        for i in 1..=k {
+            let m = bruteforce(i, k);
+            let previous: Vec<(usize, bool)> = slots[i - 1]
+                .matches
+                .iter()
+                .map(|(l, last)| (*l, last.pattern == Pattern::Bruteforce))
+                .collect();
+            for (l, isbruteforce) in previous {
+                if !isbruteforce {
+                    update(&mut slots, &m, l + 1);
+                }
+            }
+        }
+    }
+
+    let last = &slots[n - 1];
+    let Some((mut l, total)) = last
+        .totals
+        .iter()
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(l, g)| (*l, *g))
+    else {
+        return (MAX_GUESSES, Vec::new());
+    };
+    let mut sequence = Vec::new();
+    let mut k = n - 1;
+    while let Some(m) = slots[k].matches.get(&l) {
+        sequence.push(m.clone());
+        if m.i == 0 || l == 1 {
+            break;
+        }
+        k = m.i - 1;
+        l -= 1;
+    }
+    sequence.reverse();
+    (total.min(MAX_GUESSES), sequence)
+}
+
+pub fn score(guesses: f64) -> u8 {
+    const DELTA: f64 = 5.0;
+    if guesses < 1e3 + DELTA {
+        0
+    } else if guesses < 1e6 + DELTA {
+        1
+    } else if guesses < 1e8 + DELTA {
+        2
+    } else if guesses < 1e10 + DELTA {
+        3
+    } else {
+        4
+    }
+}
+
+pub fn label(guesses: f64) -> &'static str {
+    if guesses < 1e6 {
+        "weak"
+    } else if guesses < 1e8 {
+        "fair"
+    } else if guesses < 1e10 {
+        "good"
+    } else if guesses < 1e16 {
+        "strong"
+    } else {
+        "excellent"
+    }
+}
+
+fn display(seconds: f64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = MINUTE * 60.0;
+    const DAY: f64 = HOUR * 24.0;
+    const MONTH: f64 = DAY * 31.0;
+    const YEAR: f64 = MONTH * 12.0;
+    const CENTURY: f64 = YEAR * 100.0;
+    let (amount, unit) = if seconds < 1.0 {
+        return "less than a second".to_string();
+    } else if seconds < MINUTE {
+        (seconds, "second")
+    } else if seconds < HOUR {
+        (seconds / MINUTE, "minute")
+    } else if seconds < DAY {
+        (seconds / HOUR, "hour")
+    } else if seconds < MONTH {
+        (seconds / DAY, "day")
+    } else if seconds < YEAR {
+        (seconds / MONTH, "month")
+    } else if seconds < CENTURY {
+        (seconds / YEAR, "year")
+    } else {
+        return "centuries".to_string();
+    };
+    let amount = amount.round() as u64;
+    format!("{} {}{}", amount, unit, if amount == 1 { "" } else { "s" })
+}
+
+fn cracktimes(guesses: f64) -> Vec<CrackTime> {
+    [
+        ("online_throttling", 100.0 / 3600.0),
+        ("online_no_throttling", 10.0),
+        ("offline_slow_hashing", 1e4),
+        ("offline_fast_hashing", 1e10),
+    ]
+    .into_iter()
+    .map(|(scenario, rate)| CrackTime {
+        scenario: scenario.to_string(),
+        seconds: guesses / rate,
+        display: display(guesses / rate),
+    })
+    .collect()
+}
+
+fn feedback(score: u8, sequence: &[Match]) -> (Option<String>, Vec<String>) {
+    if sequence.is_empty() {
+        return (
+            None,
+            vec![
+                "Use a few words, avoid common phrases".to_string(),
+                "No need for symbols, digits, or uppercase letters".to_string(),
+            ],
+        );
+    }
+    if score > 2 {
+        return (None, Vec::new());
+    }
+    let longest = sequence
+        .iter()
+        .max_by_key(|m| m.token.len())
+        .expect("sequence is not empty");
+    let sole = sequence.len() == 1;
+    let mut suggestions = vec!["Add another word or two. Uncommon words are better.".to_string()];
+    let warning = match &longest.pattern {
+        Pattern::Dictionary {
+            dictionary,
+            rank,
+            reversed,
+            subs,
+        } => {
+            let warning = match *dictionary {
+                "passwords" if sole && !*reversed && subs.is_empty() => Some(if *rank <= 10 {
+                    "This is a top-10 common password"
+                } else if *rank <= 100 {
+                    "This is a top-100 common password"
+                } else {
+                    "This is a very common password"
+                }),
+                "passwords" => Some("This is similar to a commonly used password"),
+                "english" if sole => Some("A word by itself is easy to guess"),
+                "user_inputs" => Some("Avoid words related to you or this site"),
+                _ => None,
+            };
+            let token = &longest.token;
+            if token[0].is_uppercase() && token[1..].iter().all(|c| !c.is_uppercase()) {
+                suggestions.push("Capitalization doesn't help very much".to_string());
+            } else if token.iter().all(|c| !c.is_lowercase())
+                && token.iter().any(|c| c.is_uppercase())
+            {
+                suggestions
+                    .push("All-uppercase is almost as easy to guess as all-lowercase".to_string());
+            }
+            if *reversed && token.len() >= 4 {
+                suggestions.push("Reversed words aren't much harder to guess".to_string());
+            }
+            if !subs.is_empty() {
+                suggestions.push(
+                    "Predictable substitutions like '@' instead of 'a' don't help very much"
+                        .to_string(),
+                );
+            }
+            warning.map(str::to_string)
+        }
+        Pattern::Spatial { turns, .. } => {
+            suggestions.push("Use a longer keyboard pattern with more turns".to_string());
+            Some(if *turns == 1 {
+                "Straight rows of keys are easy to guess".to_string()
+            } else {
+                "Short keyboard patterns are easy to guess".to_string()
+            })
+        }
+        Pattern::Repeat { base, .. } => {
+            suggestions.push("Avoid repeated words and characters".to_string());
+            Some(if base.chars().count() == 1 {
+                "Repeats like \"aaa\" are easy to guess".to_string()
+            } else {
+                "Repeats like \"abcabcabc\" are only slightly harder to guess than \"abc\""
+                    .to_string()
+            })
+        }
+        Pattern::Sequence { .. } => {
+            suggestions.push("Avoid sequences".to_string());
+            Some("Sequences like abc or 6543 are easy to guess".to_string())
+        }
+        Pattern::Year => {
+            suggestions.push("Avoid recent years".to_string());
+            suggestions.push("Avoid years that are associated with you".to_string());
+            Some("Recent years are easy to guess".to_string())
+        }
+        Pattern::Date { .. } => {
+            suggestions.push("Avoid dates and years that are associated with you".to_string());
+            Some("Dates are often easy to guess".to_string())
+        }
+        Pattern::Bruteforce => None,
+    };
+    (warning, suggestions)
+}
+
+fn patternname(pattern: &Pattern) -> String {
+    match pattern {
+        Pattern::Dictionary { .. } => "dictionary".to_string(),
+        Pattern::Spatial { graph, .. } => format!("spatial:{}", graphs()[*graph].name),
+        Pattern::Repeat { .. } => "repeat".to_string(),
+        Pattern::Sequence { .. } => "sequence".to_string(),
+        Pattern::Year => "year".to_string(),
+        Pattern::Date { .. } => "date".to_string(),
+        Pattern::Bruteforce => "bruteforce".to_string(),
+    }
+}
+
+pub fn estimate(password: &str, inputs: &[String]) -> Estimate {
+    let chars: Vec<char> = password.chars().take(MAX_ANALYZED).collect();
+    let inputs: HashMap<String, usize> = inputs
+        .iter()
+        .map(|w| w.trim().to_lowercase())
+        .filter(|w| !w.is_empty())
+        .enumerate()
+        .map(|(n, w)| (w, n + 1))
+        .collect();
+    let (mut guesses, sequence) = mostguessable(&chars, &omnimatch(&chars, &inputs), false);
+    let extra = password.chars().count().saturating_sub(MAX_ANALYZED);
+    guesses = (guesses * BRUTEFORCE_CARDINALITY.powi(extra as i32)).min(MAX_GUESSES);
+    if chars.is_empty() {
+        guesses = 0.0;
+    }
+    let score = score(guesses);
+    let (warning, suggestions) = feedback(score, &sequence);
+    Estimate {
+        guesses,
+        guesses_log10: guesses.max(1.0).log10(),
+        score,
+        strength: label(guesses).to_string(),
+        crack_times: cracktimes(guesses),
+        warning,
+        suggestions,
+        sequence: sequence
+            .iter()
+            .map(|m| PatternMatch {
+                pattern: patternname(&m.pattern),
+                token: m.token.iter().collect(),
+                guesses: m.guesses,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(estimate: &Estimate) -> Vec<&str> {
+        estimate
+            .sequence
+            .iter()
+            .map(|m| m.pattern.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_password() {
+        let empty = estimate("", &[]);
+        assert_eq!(empty.guesses, 0.0);
+        assert_eq!(empty.guesses_log10, 0.0);
+        assert_eq!(empty.score, 0);
+        assert_eq!(empty.strength, "weak");
+        assert!(empty.sequence.is_empty());
+        assert_eq!(empty.warning, None);
+        assert_eq!(empty.suggestions.len(), 2);
+        assert!(empty
+            .crack_times
+            .iter()
+            .all(|t| t.display == "less than a second"));
+    }
+
+    #[test]
+    fn test_common_password() {
+        let common = estimate("password", &[]);
+        assert_eq!(common.score, 0);
+        assert_eq!(patterns(&common), ["dictionary"]);
+        assert_eq!(
+            common.warning.as_deref(),
+            Some("This is a top-10 common password")
+        );
+    }
+
+    #[test]
+    fn test_capitalized_word() {
+        let capital = estimate("Password", &[]);
+        assert_eq!(capital.score, 0);
+        assert!(capital
+            .suggestions
+            .contains(&"Capitalization doesn't help very much".to_string()));
+        let upper = estimate("PASSWORD", &[]);
+        assert!(upper
+            .suggestions
+            .contains(&"All-uppercase is almost as easy to guess as all-lowercase".to_string()));
+    }
+
+    #[test]
+    fn test_l33t_substitutions() {
+        let leet = estimate("P@ssw0rd", &[]);
+        assert!(leet.score <= 1);
+        assert_eq!(patterns(&leet), ["dictionary"]);
+        assert_eq!(
+            leet.warning.as_deref(),
+            Some("This is similar to a commonly used password")
+        );
+        assert!(leet.suggestions.contains(
+            &"Predictable substitutions like '@' instead of 'a' don't help very much".to_string()
+        ));
+        assert!(leet.guesses > estimate("password", &[]).guesses);
+    }
+
+    #[test]
+    fn test_reversed_word() {
+        let reversed = estimate("drowssap", &[]);
+        assert!(reversed.score <= 1);
+        assert!(reversed
+            .suggestions
+            .contains(&"Reversed words aren't much harder to guess".to_string()));
+    }
+
+    #[test]
+    fn test_repeats() {
+        let single = estimate("aaaaaaaaaa", &[]);
+        assert_eq!(patterns(&single), ["repeat"]);
+        assert_eq!(
+            single.warning.as_deref(),
+            Some("Repeats like \"aaa\" are easy to guess")
+        );
+        let repeated = estimate("Password1!Password1!", &[]);
+        assert!(repeated.score <= 1, "{:?}", repeated.sequence);
+        assert_eq!(repeated.strength, "weak");
+        assert_eq!(patterns(&repeated), ["repeat"]);
+        assert!(repeated
+            .warning
+            .unwrap()
+            .starts_with("Repeats like \"abcabcabc\""));
+    }
+
+    #[test]
+    fn test_keyboard_patterns() {
+        let row = estimate("qwertyuiop[]", &[]);
+        assert!(row.score <= 2, "{:?}", row.sequence);
+        let spatial = estimate("zxcdewsxc", &[]);
+        assert!(patterns(&spatial).contains(&"spatial:qwerty"));
+        let keypad = estimate("789632145", &[]);
+        assert!(
+            patterns(&keypad).contains(&"spatial:keypad"),
+            "{:?}",
+            keypad.sequence
+        );
+    }
+
+    #[test]
+    fn test_sequences() {
+        for password in ["abcdefgh", "98765432", "ACEGIK"] {
+            let sequence = estimate(password, &[]);
+            assert!(sequence.score <= 1, "{password}");
+            assert_eq!(patterns(&sequence), ["sequence"], "{password}");
+            assert_eq!(
+                sequence.warning.as_deref(),
+                Some("Sequences like abc or 6543 are easy to guess")
+            );
+        }
+    }
+
+    #[test]
+    fn test_dates_and_years() {
+        let dated = estimate("13/05/1987", &[]);
+        assert_eq!(patterns(&dated), ["date"]);
+        assert_eq!(
+            dated.warning.as_deref(),
+            Some("Dates are often easy to guess")
+        );
+        let year = estimate("1987", &[]);
+        assert_eq!(patterns(&year), ["year"]);
+        assert_eq!(
+            year.warning.as_deref(),
+            Some("Recent years are easy to guess")
+        );
+        assert!(!patterns(&estimate("1887", &[])).contains(&"year"));
+    }
+
+    #[test]
+    fn test_user_inputs() {
+        let personal = estimate("JaneDoe", &["  JANEDOE ".to_string(), String::new()]);
+        assert_eq!(personal.score, 0);
+        assert_eq!(
+            personal.warning.as_deref(),
+            Some("Avoid words related to you or this site")
+        );
+        assert!(estimate("JaneDoe", &[]).guesses > personal.guesses);
+    }
+
+    #[test]
+    fn test_strong_passwords() {
+        for password in ["xK9#mQ2$vL7@pR4!", "correct-horse-battery-staple-lantern"] {
+            let strong = estimate(password, &[]);
+            assert_eq!(strong.score, 4, "{password}");
+            assert_eq!(strong.warning, None);
+            assert!(strong.suggestions.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_long_and_unicode_passwords() {
+        let long = estimate(&"a".repeat(1000), &[]);
+        assert_eq!(long.guesses, MAX_GUESSES);
+        assert_eq!(long.score, 4);
+        assert!(
+            long.sequence
+                .iter()
+                .map(|m| m.token.chars().count())
+                .sum::<usize>()
+                <= MAX_ANALYZED
+        );
+
+        let unicode = estimate("пароль🔑🔑🔑", &[]);
+        assert!(unicode.guesses > 0.0);
+        assert_eq!(
+            unicode
+                .sequence
+                .iter()
+                .map(|m| m.token.as_str())
+                .collect::<String>(),
+            "пароль🔑🔑🔑"
+        );
+    }
+
+    #[test]
+    fn test_score_thresholds() {
+        assert_eq!(score(0.0), 0);
+        assert_eq!(score(1e3), 0);
+        assert_eq!(score(1e3 + 10.0), 1);
+        assert_eq!(score(1e6 + 10.0), 2);
+        assert_eq!(score(1e8 + 10.0), 3);
+        assert_eq!(score(1e10 + 10.0), 4);
+        assert_eq!(label(1e5), "weak");
+        assert_eq!(label(1e7), "fair");
+        assert_eq!(label(1e9), "good");
+        assert_eq!(label(1e12), "strong");
+        assert_eq!(label(1e16), "excellent");
+    }
+
+    #[test]
+    fn test_crack_time_display() {
+        assert_eq!(display(0.5), "less than a second");
+        assert_eq!(display(1.0), "1 second");
+        assert_eq!(display(90.0), "2 minutes");
+        assert_eq!(display(3.0 * 86400.0), "3 days");
+        assert_eq!(display(1e12), "centuries");
+
+        let times = cracktimes(1e6);
+        assert_eq!(times.len(), 4);
+        assert_eq!(times[0].scenario, "online_throttling");
+        assert_eq!(times[1].seconds, 1e5);
+        assert_eq!(times[3].display, "less than a second");
+        assert!(times.windows(2).all(|w| w[0].seconds > w[1].seconds));
+    }
+}
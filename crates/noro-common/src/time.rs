@@ -0,0 +1,136 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn daysfromcivil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civilfromdays(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+pub fn formattimestamp(secs: u64) -> String {
+    let secs = secs as i64;
+    let (year, month, day) = civilfromdays(secs.div_euclid(86400));
+    let time = secs.rem_euclid(86400);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+pub fn parsetimestamp(value: &str) -> Option<u64> {
+    let (date, time) = value.split_once('T')?;
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+        (clock, 0)
+    } else {
+        let split = time.rfind(['+', '-'])?;
+        let (clock, zone) = time.split_at(split);
+        let (hours, minutes) = zone[1..].split_once(':')?;
+        let minutes = hours.parse::<i64>().ok()? * 60 + minutes.parse::<i64>().ok()?;
+        (
+            clock,
+            if zone.starts_with('-') {
+                -minutes
+            } else {
+                minutes
+            },
+        )
+    };
+    let clock = clock.split('.').next()?;
+    let mut parts = clock.splitn(3, ':');
+    let hour: i64 = parts.next()?.parse().ok()?;
+    let minute: i64 = parts.next()?.parse().ok()?;
+    let second: i64 = parts.next()?.parse().ok()?;
+
+    let seconds =
+        daysfromcivil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset * 60;
+    u64::try_from(seconds).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsetimestamp_utc() {
+        assert_eq!(parsetimestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parsetimestamp("2025-01-01T00:00:00Z"), Some(1735689600));
+        assert_eq!(parsetimestamp("2025-03-14T09:26:53.589Z"), Some(1741944413));
+        assert_eq!(parsetimestamp("2024-02-29T23:59:59.000Z"), Some(1709251199));
+    }
+
+    #[test]
+    fn test_parsetimestamp_offsets() {
+        assert_eq!(
+            parsetimestamp("2025-01-01T02:30:00+02:30"),
+            Some(1735689600)
+        );
+        assert_eq!(
+            parsetimestamp("2024-12-31T19:00:00-05:00"),
+            Some(1735689600)
+        );
+    }
+
+    #[test]
+    fn test_parsetimestamp_invalid() {
+        for value in [
+            "",
+            "2025-01-01",
+            "2025-13-01T00:00:00Z",
+            "2025-01-32T00:00:00Z",
+            "2025-01-01T00:00Z",
+            "2025-01-01T00:00:00",
+            "2025-01-01T00:00:00+0200",
+            "1969-12-31T23:59:59Z",
+            "next tuesday",
+        ] {
+            assert_eq!(parsetimestamp(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_formattimestamp() {
+        assert_eq!(formattimestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(formattimestamp(1709251199), "2024-02-29T23:59:59Z");
+        assert_eq!(formattimestamp(1741944413), "2025-03-14T09:26:53Z");
+        for secs in [951782400, 1735689600, 4102444799] {
+            assert_eq!(parsetimestamp(&formattimestamp(secs)), Some(secs));
+        }
+    }
+
+    #[test]
+    fn test_now() {
+        assert!(now() > 1_700_000_000);
+    }
+}
@@ -0,0 +1,383 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+use url::{Host, Url};
+
+const PUBLIC_SUFFIX_LIST: &str = include_str!("../data/public_suffix_list.dat");
+const URL_KEYS: &[&str] = &["url", "urls", "website", "origin"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchRule {
+    #[default]
+    Domain,
+    Host,
+    StartsWith,
+    Regex,
+    Never,
+}
+
+impl MatchRule {
+    pub fn parse(name: &str) -> Option<MatchRule> {
+        match name.to_lowercase().replace(['_', '-'], "").as_str() {
+            "domain" | "basedomain" => Some(MatchRule::Domain),
+            "host" => Some(MatchRule::Host),
+            "startswith" | "prefix" => Some(MatchRule::StartsWith),
+            "regex" | "regexp" => Some(MatchRule::Regex),
+            "never" => Some(MatchRule::Never),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoginUrl {
+    pub url: String,
+    pub rule: Option<MatchRule>,
+    pub pattern: Option<Regex>,
+}
+
+impl LoginUrl {
+    pub fn new(url: impl Into<String>, rule: Option<MatchRule>) -> Self {
+        let url = url.into();
+        let pattern = match rule {
+            Some(MatchRule::Regex) => Regex::new(&format!("^(?:{url})$")).ok(),
+            _ => None,
+        };
+        LoginUrl { url, rule, pattern }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Login {
+    pub id: String,
+    pub urls: Vec<LoginUrl>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UrlMatch {
+    pub id: String,
+    pub url: String,
+    pub rule: MatchRule,
+    pub score: u8,
+}
+
+struct SuffixList {
+    rules: HashSet<String>,
+    wildcards: HashSet<String>,
+    exceptions: HashSet<String>,
+}
+
+fn suffixes() -> &'static SuffixList {
+    static LIST: OnceLock<SuffixList> = OnceLock::new();
+    LIST.get_or_init(|| {
+        let mut list = SuffixList {
+            rules: HashSet::new(),
+            wildcards: HashSet::new(),
+            exceptions: HashSet::new(),
+        };
+        for line in PUBLIC_SUFFIX_LIST.lines() {
+            let Some(rule) = line.split_whitespace().next() else {
+                continue;
+            };
+            if rule.starts_with("//") {
+                continue;
+            }
+            if let Some(rule) = rule.strip_prefix('!') {
+                list.exceptions.extend(asciirule(rule));
+            } else if let Some(rule) = rule.strip_prefix("*.") {
+                list.wildcards.extend(asciirule(rule));
+            } else {
+                list.rules.extend(asciirule(rule));
+            }
+        }
+        list
+    })
+}
+
+fn asciirule(rule: &str) -> Option<String> {
+    if rule.is_ascii() {
+        return Some(rule.to_ascii_lowercase());
+    }
+    match Host::parse(rule) {
+        Ok(Host::Domain(domain)) => Some(domain),
+        _ => None,
+    }
+}
+
+fn suffixlen(labels: &[&str]) -> usize {
+    let list = suffixes();
+    for i in 0..labels.len() {
+        let suffix = labels[i..].join(".");
+        if list.exceptions.contains(&suffix) {
+            return labels.len() - i - 1;
+        }
+        if list.rules.contains(&suffix) {
+            return labels.len() - i;
+        }
+        if i + 1 < labels.len() && list.wildcards.contains(&labels[i + 1..].join(".")) {
+            return labels.len() - i;
+        }
+    }
+    1
+}
+
+pub fn registrabledomain(host: &str) -> Option<String> {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.iter().any(|l| l.is_empty()) {
+        return None;
+    }
+    let n = suffixlen(&labels);
+    if labels.len() <= n {
+        return None;
+    }
+    Some(labels[labels.len() - n - 1..].join("."))
+}
+
+pub fn parseurl(raw: &str) -> Option<Url> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    if raw.contains("://") {
+        Url::parse(raw).ok()
+    } else {
+        Url::parse(&format!("https://{raw}")).ok()
+    }
+}
+
+fn isweb(url: &Url) -> bool {
+    matches!(url.scheme(), "http" | "https")
+}
+
+fn samescheme(a: &Url, b: &Url) -> bool {
+    (isweb(a) && isweb(b)) || a.scheme() == b.scheme()
+}
+
+fn site(url: &Url) -> Option<String> {
+    match url.host()? {
+        Host::Domain(domain) => registrabledomain(domain).or_else(|| Some(domain.to_string())),
+        Host::Ipv4(ip) => Some(ip.to_string()),
+        Host::Ipv6(ip) => Some(format!("[{ip}]")),
+    }
+}
+
+pub fn matchurl(page: &Url, login: &LoginUrl) -> Option<u8> {
+    match login.rule.unwrap_or_default() {
+        MatchRule::Never => None,
+        MatchRule::Regex => login.pattern.as_ref()?.is_match(page.as_str()).then_some(4),
+        MatchRule::StartsWith => {
+            let login = parseurl(&login.url)?;
+            page.as_str().starts_with(login.as_str()).then_some(4)
+        }
+        MatchRule::Host => {
+            let login = parseurl(&login.url)?;
+            (samescheme(page, &login)
+                && page.host_str().is_some()
+                && page.host_str() == login.host_str()
+                && page.port() == login.port())
+            .then_some(3)
+        }
+        MatchRule::Domain => {
+            let login = parseurl(&login.url)?;
+            if !samescheme(page, &login) || page.host_str().is_none() {
+                return None;
+            }
+            if page.host_str() == login.host_str() {
+                return Some(3);
+            }
+            (isweb(page) && site(page).is_some() && site(page) == site(&login)).then_some(1)
+        }
+    }
+}
+
+pub fn matchlogins(page: &str, logins: &[Login]) -> Vec<UrlMatch> {
+    let Some(page) = parseurl(page) else {
+        return Vec::new();
+    };
+    let mut found: Vec<UrlMatch> = logins
+        .iter()
+        .filter_map(|login| {
+            login
+                .urls
+                .iter()
+                .filter_map(|u| {
+                    matchurl(&page, u).map(|score| UrlMatch {
+                        id: login.id.clone(),
+                        url: u.url.clone(),
+                        rule: u.rule.unwrap_or_default(),
+                        score,
+                    })
+                })
+                .max_by_key(|m| m.score)
+        })
+        .collect();
+    found.sort_by_key(|m| Reverse(m.score));
+    found
+}
+
+pub fn loginurls(data: &[u8]) -> Vec<LoginUrl> {
+    let mut urls = Vec::new();
+    let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(data) else {
+        return urls;
+    };
+    let rule = map
+        .get("match")
+        .and_then(Value::as_str)
+        .and_then(MatchRule::parse);
+    for (key, value) in &map {
+        if URL_KEYS.contains(&key.to_lowercase().as_str()) {
+            collecturls(value, rule, &mut urls);
+        }
+    }
+    urls
+}
+
+fn collecturls(value: &Value, rule: Option<MatchRule>, out: &mut Vec<LoginUrl>) {
+    match value {
+        Value::String(s) if !s.is_empty() => out.push(LoginUrl::new(s.clone(), rule)),
+        Value::Array(values) => values.iter().for_each(|v| collecturls(v, rule, out)),
+        Value::Object(map) => {
+            let url = ["url", "uri", "href"]
+                .iter()
+                .find_map(|k| map.get(*k).and_then(Value::as_str));
+            let rule = map
+                .get("match")
+                .and_then(Value::as_str)
+                .and_then(MatchRule::parse)
+                .or(rule);
+            if let Some(url) = url.filter(|u| !u.is_empty()) {
+                out.push(LoginUrl::new(url, rule));
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn login(id: &str, url: &str, rule: Option<MatchRule>) -> Login {
+        Login {
+            id: id.into(),
+            urls: vec![LoginUrl::new(url, rule)],
+        }
+    }
+
+    fn ids(page: &str, logins: &[Login]) -> String {
+        matchlogins(page, logins)
+            .into_iter()
+            .map(|m| m.id)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    #[test]
+    fn test_registrable_domain() {
+        assert_eq!(
+            registrabledomain("accounts.google.co.uk").as_deref(),
+            Some("google.co.uk")
+        );
+        assert_eq!(
+            registrabledomain("user.github.io").as_deref(),
+            Some("user.github.io")
+        );
+        assert_eq!(registrabledomain("co.uk"), None);
+        assert_eq!(
+            registrabledomain("Example.COM.").as_deref(),
+            Some("example.com")
+        );
+        assert_eq!(registrabledomain("a..example.com"), None);
+    }
+
+    #[test]
+    fn test_wildcard_suffix() {
+        assert_eq!(registrabledomain("foo.bd"), None);
+        assert_eq!(
+            registrabledomain("shop.foo.bd").as_deref(),
+            Some("shop.foo.bd")
+        );
+        assert_eq!(
+            registrabledomain("www.shop.foo.bd").as_deref(),
+            Some("shop.foo.bd")
+        );
+
+        let logins = vec![login("pages", "https://other.github.io", None)];
+        assert_eq!(ids("https://user.github.io/", &logins), "");
+        assert_eq!(ids("https://other.github.io/login", &logins), "pages");
+    }
+
+    #[test]
+    fn test_exception_suffix() {
+        assert_eq!(registrabledomain("www.ck").as_deref(), Some("www.ck"));
+        assert_eq!(registrabledomain("mail.www.ck").as_deref(), Some("www.ck"));
+        assert_eq!(registrabledomain("other.ck"), None);
+        assert_eq!(
+            registrabledomain("city.kawasaki.jp").as_deref(),
+            Some("city.kawasaki.jp")
+        );
+        assert_eq!(registrabledomain("ward.kawasaki.jp"), None);
+    }
+
+    #[test]
+    fn test_ip_and_localhost() {
+        assert_eq!(registrabledomain("localhost"), None);
+
+        let logins = vec![
+            login("local", "http://localhost:3000", None),
+            login("ip", "192.168.1.10", None),
+            login("v6", "http://[::1]/", Some(MatchRule::Host)),
+        ];
+        assert_eq!(ids("http://localhost:3000/admin", &logins), "local");
+        assert_eq!(ids("http://localhost:8080/", &logins), "local");
+        assert_eq!(ids("https://192.168.1.10/", &logins), "ip");
+        assert_eq!(ids("https://192.168.1.11/", &logins), "");
+        assert_eq!(ids("http://[::1]/", &logins), "v6");
+    }
+
+    #[test]
+    fn test_port_and_path() {
+        let logins = vec![
+            login("domain", "example.com", None),
+            login("host", "https://login.example.com", Some(MatchRule::Host)),
+            login(
+                "prefix",
+                "https://login.example.com/sso",
+                Some(MatchRule::StartsWith),
+            ),
+            login(
+                "regex",
+                r"https://[a-z]+\.example\.com/.*",
+                Some(MatchRule::Regex),
+            ),
+            login("never", "https://login.example.com", Some(MatchRule::Never)),
+            login("partial", r"example\.com", Some(MatchRule::Regex)),
+            login("suffix", "https://example.co.uk", None),
+        ];
+
+        assert_eq!(
+            ids("https://login.example.com/sso/start", &logins),
+            "prefix,regex,host,domain"
+        );
+        assert_eq!(
+            ids("https://login.example.com/other", &logins),
+            "regex,host,domain"
+        );
+        assert_eq!(ids("https://login.example.com:8443/", &logins), "domain");
+        assert_eq!(ids("not a url", &logins), "");
+    }
+
+    #[test]
+    fn test_login_urls() {
+        let urls =
+            loginurls(br#"{"urls":[{"url":"a.com","match":"host"},"b.com"],"match":"never"}"#);
+        assert_eq!(urls[0].rule, Some(MatchRule::Host));
+        assert_eq!(urls[1].rule, Some(MatchRule::Never));
+        assert!(loginurls(b"not json").is_empty());
+    }
+}